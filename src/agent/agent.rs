@@ -4,7 +4,8 @@ use crate::agent::dispatcher::{
 use crate::agent::memory_loader::{DefaultMemoryLoader, MemoryLoader};
 use crate::agent::prompt::{PromptContext, SystemPromptBuilder};
 use crate::config::Config;
-use crate::memory::{self, Memory, MemoryCategory};
+use crate::memory::redaction::CLI_CHANNEL;
+use crate::memory::{self, Memory};
 use crate::observability::{self, Observer, ObserverEvent};
 use crate::providers::{self, ChatMessage, ChatRequest, ConversationMessage, Provider};
 use crate::runtime;
@@ -37,6 +38,7 @@ pub struct Agent {
     classification_config: crate::config::QueryClassificationConfig,
     available_hints: Vec<String>,
     route_model_by_hint: HashMap<String, String>,
+    watchdog: super::watchdog::Watchdog,
 }
 
 pub struct AgentBuilder {
@@ -185,6 +187,12 @@ impl AgentBuilder {
             .tools
             .ok_or_else(|| anyhow::anyhow!("tools are required"))?;
         let tool_specs = tools.iter().map(|tool| tool.spec()).collect();
+        let config = self.config.unwrap_or_default();
+        let workspace_dir = self
+            .workspace_dir
+            .unwrap_or_else(|| std::path::PathBuf::from("."));
+        let watchdog = super::watchdog::Watchdog::default();
+        watchdog.configure(&config.watchdog, &workspace_dir);
 
         Ok(Agent {
             provider: self
@@ -207,14 +215,12 @@ impl AgentBuilder {
             memory_loader: self
                 .memory_loader
                 .unwrap_or_else(|| Box::new(DefaultMemoryLoader::default())),
-            config: self.config.unwrap_or_default(),
+            config,
             model_name: self
                 .model_name
                 .unwrap_or_else(|| "anthropic/claude-sonnet-4-20250514".into()),
             temperature: self.temperature.unwrap_or(0.7),
-            workspace_dir: self
-                .workspace_dir
-                .unwrap_or_else(|| std::path::PathBuf::from(".")),
+            workspace_dir,
             identity_config: self.identity_config.unwrap_or_default(),
             skills: self.skills.unwrap_or_default(),
             skills_prompt_mode: self.skills_prompt_mode.unwrap_or_default(),
@@ -223,6 +229,7 @@ impl AgentBuilder {
             classification_config: self.classification_config.unwrap_or_default(),
             available_hints: self.available_hints.unwrap_or_default(),
             route_model_by_hint: self.route_model_by_hint.unwrap_or_default(),
+            watchdog,
         })
     }
 }
@@ -250,13 +257,16 @@ impl Agent {
             &config.workspace_dir,
         ));
 
-        let memory: Arc<dyn Memory> = Arc::from(memory::create_memory_with_storage_and_routes(
-            &config.memory,
-            &config.embedding_routes,
-            Some(&config.storage.provider.config),
-            &config.workspace_dir,
-            config.api_key.as_deref(),
-        )?);
+        let memory: Arc<dyn Memory> = Arc::from(memory::with_auto_save_policy(
+            memory::create_memory_with_storage_and_routes(
+                &config.memory,
+                &config.embedding_routes,
+                Some(&config.storage.provider.config),
+                &config.workspace_dir,
+                config.api_key.as_deref(),
+            )?,
+            config,
+        ));
 
         let composio_key = if config.composio.enabled {
             config.composio.api_key.as_deref()
@@ -269,7 +279,7 @@ impl Agent {
             None
         };
 
-        let tools = tools::all_tools_with_runtime(
+        let mut tools = tools::all_tools_with_runtime(
             Arc::new(config.clone()),
             &security,
             runtime,
@@ -284,6 +294,11 @@ impl Agent {
             config.api_key.as_deref(),
             config,
         );
+        tools = tools::limits::wrap_registry(tools, &config.agent.tool_limits);
+        if config.agent.dry_run {
+            let switch = tools::dry_run::DryRunSwitch::new(true);
+            tools = tools::dry_run::wrap_registry(tools, &switch);
+        }
 
         let provider_name = config.default_provider.as_deref().unwrap_or("openrouter");
 
@@ -293,13 +308,18 @@ impl Agent {
             .unwrap_or("anthropic/claude-sonnet-4-20250514")
             .to_string();
 
-        let provider: Box<dyn Provider> = providers::create_routed_provider(
+        let provider: Box<dyn Provider> = providers::create_routed_provider_with_options(
             provider_name,
             config.api_key.as_deref(),
             config.api_url.as_deref(),
             &config.reliability,
             &config.model_routes,
             &model_name,
+            &providers::ProviderRuntimeOptions {
+                connectivity: Some(crate::connectivity::Connectivity::new(config)),
+                recorder: Some(crate::providers::recorder::Recorder::new(config)),
+                ..providers::ProviderRuntimeOptions::default()
+            },
         )?;
 
         let dispatcher_choice = config.agent.tool_dispatcher.as_str();
@@ -323,10 +343,13 @@ impl Agent {
             .memory(memory)
             .observer(observer)
             .tool_dispatcher(tool_dispatcher)
-            .memory_loader(Box::new(DefaultMemoryLoader::new(
-                5,
-                config.memory.min_relevance_score,
-            )))
+            .memory_loader(Box::new(
+                DefaultMemoryLoader::new(
+                    crate::memory::packing::CONTEXT_CANDIDATES,
+                    config.memory.min_relevance_score,
+                )
+                .with_token_budget(config.memory.context_token_budget),
+            ))
             .prompt_builder(SystemPromptBuilder::with_defaults())
             .config(config.agent.clone())
             .model_name(model_name)
@@ -390,7 +413,12 @@ impl Agent {
         let start = Instant::now();
 
         let result = if let Some(tool) = self.tools.iter().find(|t| t.name() == call.name) {
-            match tool.execute(call.arguments.clone()).await {
+            let outcome = self
+                .watchdog
+                .guard_tool(&call.name, tool.execute(call.arguments.clone()))
+                .await
+                .unwrap_or_else(|timeout| Err(timeout.into()));
+            match outcome {
                 Ok(r) => {
                     self.observer.record_event(&ObserverEvent::ToolCall {
                         tool: call.name.clone(),
@@ -465,6 +493,10 @@ impl Agent {
     }
 
     pub async fn turn(&mut self, user_message: &str) -> Result<String> {
+        tools::limits::scope_turn(self.run_turn(user_message)).await
+    }
+
+    async fn run_turn(&mut self, user_message: &str) -> Result<String> {
         if self.history.is_empty() {
             let system_prompt = self.build_system_prompt()?;
             self.history
@@ -474,10 +506,17 @@ impl Agent {
         }
 
         if self.auto_save {
-            let _ = self
-                .memory
-                .store("user_msg", user_message, MemoryCategory::Conversation, None)
-                .await;
+            if let Some(content) = self.memory.redact_for_storage(CLI_CHANNEL, user_message) {
+                let _ = self
+                    .memory
+                    .store(
+                        "user_msg",
+                        &content,
+                        self.memory.auto_save_category(&content),
+                        None,
+                    )
+                    .await;
+            }
         }
 
         let context = self
@@ -0,0 +1,242 @@
+//! Frustration/urgency detection for channel messages (`[behavior.escalation]`).
+//!
+//! A cheap keyword and punctuation classifier scores each inbound message.
+//! When the score reaches the configured threshold the channel runtime can
+//! notify the owner, answer with a stronger model, and drop the persona. The
+//! channel runtime owns one [`Escalator`] with the settings and the owner
+//! notice cooldowns.
+
+use crate::config::{EscalationAction, EscalationConfig};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Appended to the system prompt when `drop_persona` fires.
+pub const TONE_OVERRIDE: &str = "## Tone override\n\n\
+The user appears frustrated or under time pressure. Drop jokes, emoji and any \
+playful persona for this reply. Acknowledge the problem in one sentence, then \
+give the most direct, concrete help you can.";
+
+const SHOUT_MIN_LETTERS: usize = 12;
+const SHOUT_UPPERCASE_PERCENT: usize = 70;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+    Frustration,
+    Urgency,
+}
+
+impl Signal {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Frustration => "frustration",
+            Self::Urgency => "urgency",
+        }
+    }
+}
+
+/// A message that crossed the escalation threshold.
+#[derive(Debug, Clone)]
+pub struct Escalation {
+    pub signals: Vec<Signal>,
+    pub score: u32,
+    pub cues: Vec<String>,
+    config: Arc<EscalationConfig>,
+}
+
+impl Escalation {
+    fn has_action(&self, action: EscalationAction) -> bool {
+        self.config.actions.contains(&action)
+    }
+
+    pub fn drops_persona(&self) -> bool {
+        self.has_action(EscalationAction::DropPersona)
+    }
+
+    /// `(provider, model)` to answer with, if `switch_model` is configured.
+    /// `provider` is `None` when the routed provider should be kept.
+    pub fn model_override(&self) -> Option<(Option<&str>, &str)> {
+        if !self.has_action(EscalationAction::SwitchModel) {
+            return None;
+        }
+        let model = self.config.model.as_deref()?;
+        Some((self.config.provider.as_deref(), model))
+    }
+
+    /// `(channel, recipient)` for the owner notice, if `notify_owner` is configured.
+    pub fn notify_target(&self) -> Option<(&str, &str)> {
+        if !self.has_action(EscalationAction::NotifyOwner) {
+            return None;
+        }
+        Some((
+            self.config.notify_channel.as_deref()?,
+            self.config.notify_to.as_deref()?,
+        ))
+    }
+
+    pub fn describe(&self) -> String {
+        let signals: Vec<&str> = self.signals.iter().map(|signal| signal.label()).collect();
+        format!(
+            "{} (score {}: {})",
+            signals.join(" + "),
+            self.score,
+            self.cues.join(", ")
+        )
+    }
+}
+
+/// `[behavior.escalation]` settings plus when each conversation last
+/// triggered an owner notice.
+#[derive(Debug, Default)]
+pub struct Escalator {
+    config: Arc<EscalationConfig>,
+    last_notified: Mutex<HashMap<String, Instant>>,
+}
+
+impl Escalator {
+    pub fn new(config: &EscalationConfig) -> Self {
+        Self {
+            config: Arc::new(config.clone()),
+            last_notified: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Score `message`; `None` below the threshold or when disabled.
+    pub fn detect(&self, message: &str) -> Option<Escalation> {
+        detect_with(&self.config, message)
+    }
+
+    /// Whether an owner notice for `conversation` is due, honouring
+    /// `notify_cooldown_minutes`. Marks it sent when it is.
+    pub fn notify_due(&self, conversation: &str) -> bool {
+        let cooldown = Duration::from_secs(u64::from(self.config.notify_cooldown_minutes) * 60);
+        let mut sent = self.last_notified.lock();
+        let now = Instant::now();
+        if sent
+            .get(conversation)
+            .is_some_and(|last| now.duration_since(*last) < cooldown)
+        {
+            return false;
+        }
+        sent.insert(conversation.to_string(), now);
+        true
+    }
+}
+
+/// Score `message` against `config`; `None` below the threshold or when disabled.
+pub fn detect_with(config: &Arc<EscalationConfig>, message: &str) -> Option<Escalation> {
+    if !config.enabled {
+        return None;
+    }
+
+    let lower = message.to_lowercase();
+    let mut score = 0;
+    let mut cues = Vec::new();
+    let mut signals = Vec::new();
+    let mut hit = |signal: Signal, cue: String| {
+        score += 1;
+        cues.push(cue);
+        if !signals.contains(&signal) {
+            signals.push(signal);
+        }
+    };
+
+    for (signal, keywords) in [
+        (Signal::Frustration, &config.frustration_keywords),
+        (Signal::Urgency, &config.urgency_keywords),
+    ] {
+        for keyword in keywords {
+            let keyword = keyword.trim().to_lowercase();
+            if !keyword.is_empty() && lower.contains(&keyword) {
+                hit(signal, format!("\"{keyword}\""));
+            }
+        }
+    }
+    if is_shouting(message) {
+        hit(Signal::Frustration, "all caps".into());
+    }
+    if message.contains("!!!") || message.contains("???") || message.contains("?!") {
+        hit(Signal::Frustration, "repeated punctuation".into());
+    }
+
+    (score >= config.threshold).then(|| Escalation {
+        signals,
+        score,
+        cues,
+        config: Arc::clone(config),
+    })
+}
+
+fn is_shouting(message: &str) -> bool {
+    let letters: Vec<char> = message.chars().filter(|c| c.is_alphabetic()).collect();
+    if letters.len() < SHOUT_MIN_LETTERS {
+        return false;
+    }
+    let upper = letters.iter().filter(|c| c.is_uppercase()).count();
+    upper * 100 >= letters.len() * SHOUT_UPPERCASE_PERCENT
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled(actions: Vec<EscalationAction>) -> Arc<EscalationConfig> {
+        Arc::new(EscalationConfig {
+            enabled: true,
+            actions,
+            notify_channel: Some("telegram".into()),
+            notify_to: Some("12345".into()),
+            model: Some("anthropic/claude-opus-4".into()),
+            ..EscalationConfig::default()
+        })
+    }
+
+    #[test]
+    fn calm_messages_do_not_escalate() {
+        let config = enabled(vec![EscalationAction::DropPersona]);
+        assert!(detect_with(&config, "Could you summarize this PDF for me?").is_none());
+        assert!(detect_with(&config, "this is urgent").is_none());
+        assert!(detect_with(&Arc::new(EscalationConfig::default()), "WTF!!! URGENT").is_none());
+    }
+
+    #[test]
+    fn keywords_caps_and_punctuation_add_up() {
+        let config = enabled(vec![EscalationAction::DropPersona]);
+        let escalation =
+            detect_with(&config, "THE DEPLOY IS STILL BROKEN AND IT IS URGENT!!!").unwrap();
+        assert_eq!(escalation.score, 4);
+        assert_eq!(
+            escalation.signals,
+            vec![Signal::Frustration, Signal::Urgency]
+        );
+        assert!(escalation
+            .describe()
+            .starts_with("frustration + urgency (score 4:"));
+        assert!(escalation.drops_persona());
+        assert!(escalation.model_override().is_none());
+        assert!(escalation.notify_target().is_none());
+    }
+
+    #[test]
+    fn actions_expose_targets_and_notices_are_rate_limited() {
+        let config = enabled(vec![
+            EscalationAction::NotifyOwner,
+            EscalationAction::SwitchModel,
+        ]);
+        let escalation = detect_with(&config, "outage right now, fix it asap").unwrap();
+        assert!(!escalation.drops_persona());
+        assert_eq!(
+            escalation.model_override(),
+            Some((None, "anthropic/claude-opus-4"))
+        );
+        assert_eq!(escalation.notify_target(), Some(("telegram", "12345")));
+
+        let escalator = Escalator::new(&config);
+        assert!(escalator.detect("outage right now, fix it asap").is_some());
+        assert!(escalator.notify_due("alice"));
+        assert!(!escalator.notify_due("alice"));
+        assert!(escalator.notify_due("bob"));
+        assert!(Escalator::new(&config).notify_due("alice"));
+    }
+}
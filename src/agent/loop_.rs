@@ -1,5 +1,6 @@
 use crate::approval::{ApprovalManager, ApprovalRequest, ApprovalResponse};
 use crate::config::Config;
+use crate::memory::redaction::CLI_CHANNEL;
 use crate::memory::{self, Memory, MemoryCategory};
 use crate::multimodal;
 use crate::observability::{self, runtime_trace, Observer, ObserverEvent};
@@ -14,7 +15,7 @@ use anyhow::Result;
 use regex::{Regex, RegexSet};
 use std::collections::HashSet;
 use std::fmt::Write;
-use std::io::Write as _;
+use std::io::{IsTerminal as _, Write as _};
 use std::sync::{Arc, LazyLock};
 use std::time::{Duration, Instant};
 use tokio_util::sync::CancellationToken;
@@ -238,37 +239,33 @@ async fn auto_compact_history(
 
 /// Build context preamble by searching memory for relevant entries.
 /// Entries with a hybrid score below `min_relevance_score` are dropped to
-/// prevent unrelated memories from bleeding into the conversation.
-async fn build_context(mem: &dyn Memory, user_msg: &str, min_relevance_score: f64) -> String {
-    let mut context = String::new();
-
+/// prevent unrelated memories from bleeding into the conversation; the rest
+/// are packed by relevance under `token_budget` estimated tokens.
+async fn build_context(
+    mem: &dyn Memory,
+    user_msg: &str,
+    min_relevance_score: f64,
+    token_budget: usize,
+) -> String {
     // Pull relevant memories for this message
-    if let Ok(entries) = mem.recall(user_msg, 5, None).await {
-        let relevant: Vec<_> = entries
-            .iter()
-            .filter(|e| match e.score {
-                Some(score) => score >= min_relevance_score,
-                None => true,
-            })
-            .collect();
+    let Ok(entries) = mem
+        .recall(user_msg, memory::packing::CONTEXT_CANDIDATES, None)
+        .await
+    else {
+        return String::new();
+    };
 
-        if !relevant.is_empty() {
-            context.push_str("[Memory context]\n");
-            for entry in &relevant {
-                if memory::is_assistant_autosave_key(&entry.key) {
-                    continue;
-                }
-                let _ = writeln!(context, "- {}: {}", entry.key, entry.content);
-            }
-            if context == "[Memory context]\n" {
-                context.clear();
-            } else {
-                context.push('\n');
-            }
-        }
-    }
+    let candidates = entries
+        .iter()
+        .filter(|e| match e.score {
+            Some(score) => score >= min_relevance_score,
+            None => true,
+        })
+        .filter(|e| !memory::is_assistant_autosave_key(&e.key))
+        .map(|e| memory::packing::ContextCandidate::new(&e.key, &e.content, e.score))
+        .collect();
 
-    context
+    memory::packing::pack_context(candidates, token_budget)
 }
 
 /// Build hardware datasheet context from RAG when peripherals are enabled.
@@ -1920,10 +1917,15 @@ async fn execute_one_tool(
             success: false,
             error_reason: Some(scrub_credentials(&reason)),
             duration,
+            timed_out: false,
         });
     };
 
-    let tool_future = tool.execute(call_arguments);
+    let tool_future = async {
+        super::watchdog::guard_tool(call_name, tool.execute(call_arguments))
+            .await
+            .unwrap_or_else(|timeout| Err(timeout.into()))
+    };
     let tool_result = if let Some(token) = cancellation_token {
         tokio::select! {
             () = token.cancelled() => return Err(ToolLoopCancelled.into()),
@@ -1947,6 +1949,7 @@ async fn execute_one_tool(
                     success: true,
                     error_reason: None,
                     duration,
+                    timed_out: false,
                 })
             } else {
                 let reason = r.error.unwrap_or(r.output);
@@ -1955,6 +1958,7 @@ async fn execute_one_tool(
                     success: false,
                     error_reason: Some(scrub_credentials(&reason)),
                     duration,
+                    timed_out: false,
                 })
             }
         }
@@ -1965,12 +1969,14 @@ async fn execute_one_tool(
                 duration,
                 success: false,
             });
+            let timed_out = e.is::<super::watchdog::WatchdogTimeout>();
             let reason = format!("Error executing {call_name}: {e}");
             Ok(ToolExecutionOutcome {
                 output: reason.clone(),
                 success: false,
                 error_reason: Some(scrub_credentials(&reason)),
                 duration,
+                timed_out,
             })
         }
     }
@@ -1981,6 +1987,8 @@ struct ToolExecutionOutcome {
     success: bool,
     error_reason: Option<String>,
     duration: Duration,
+    /// Cancelled by the `[agent.watchdog]` tool limit.
+    timed_out: bool,
 }
 
 fn should_execute_tools_in_parallel(
@@ -2081,6 +2089,47 @@ pub(crate) async fn run_tool_call_loop(
     on_delta: Option<tokio::sync::mpsc::Sender<String>>,
     hooks: Option<&crate::hooks::HookRunner>,
     excluded_tools: &[String],
+) -> Result<String> {
+    // Per-turn tool budgets (`[agent.tool_limits]`) are counted within this scope.
+    tools::limits::scope_turn(run_tool_call_loop_turn(
+        provider,
+        history,
+        tools_registry,
+        observer,
+        provider_name,
+        model,
+        temperature,
+        silent,
+        approval,
+        channel_name,
+        multimodal_config,
+        max_tool_iterations,
+        cancellation_token,
+        on_delta,
+        hooks,
+        excluded_tools,
+    ))
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run_tool_call_loop_turn(
+    provider: &dyn Provider,
+    history: &mut Vec<ChatMessage>,
+    tools_registry: &[Box<dyn Tool>],
+    observer: &dyn Observer,
+    provider_name: &str,
+    model: &str,
+    temperature: f64,
+    silent: bool,
+    approval: Option<&ApprovalManager>,
+    channel_name: &str,
+    multimodal_config: &crate::config::MultimodalConfig,
+    max_tool_iterations: usize,
+    cancellation_token: Option<CancellationToken>,
+    on_delta: Option<tokio::sync::mpsc::Sender<String>>,
+    hooks: Option<&crate::hooks::HookRunner>,
+    excluded_tools: &[String],
 ) -> Result<String> {
     let max_iterations = if max_tool_iterations == 0 {
         DEFAULT_MAX_TOOL_ITERATIONS
@@ -2164,14 +2213,22 @@ pub(crate) async fn run_tool_call_loop(
             None
         };
 
-        let chat_future = provider.chat(
-            ChatRequest {
-                messages: &prepared_messages.messages,
-                tools: request_tools,
-            },
-            model,
-            temperature,
-        );
+        let chat_future = async {
+            super::watchdog::guard_generation(
+                provider_name,
+                model,
+                crate::providers::progress::wait(provider.chat(
+                    ChatRequest {
+                        messages: &prepared_messages.messages,
+                        tools: request_tools,
+                    },
+                    model,
+                    temperature,
+                )),
+            )
+            .await
+            .unwrap_or_else(|timeout| Err(timeout.into()))
+        };
 
         let chat_result = if let Some(token) = cancellation_token.as_ref() {
             tokio::select! {
@@ -2202,6 +2259,11 @@ pub(crate) async fn run_tool_call_loop(
                     });
 
                     let response_text = resp.text_or_empty().to_string();
+                    crate::providers::progress::record_usage(
+                        resp_input_tokens,
+                        resp_output_tokens,
+                        response_text.len(),
+                    );
                     // First try native structured tool calls (OpenAI-format).
                     // Fall back to text-based parsing (XML tags, markdown blocks,
                     // GLM format) only if the provider returned no native calls —
@@ -2432,6 +2494,7 @@ pub(crate) async fn run_tool_call_loop(
                                 success: false,
                                 error_reason: Some(scrub_credentials(&reason)),
                                 duration: Duration::ZERO,
+                                timed_out: false,
                             },
                         ));
                         continue;
@@ -2484,6 +2547,7 @@ pub(crate) async fn run_tool_call_loop(
                                 success: false,
                                 error_reason: Some(denied),
                                 duration: Duration::ZERO,
+                                timed_out: false,
                             },
                         ));
                         continue;
@@ -2519,6 +2583,7 @@ pub(crate) async fn run_tool_call_loop(
                         success: false,
                         error_reason: Some(duplicate),
                         duration: Duration::ZERO,
+                        timed_out: false,
                     },
                 ));
                 continue;
@@ -2598,6 +2663,14 @@ pub(crate) async fn run_tool_call_loop(
                 }),
             );
 
+            crate::agent::tool_blocks::record(crate::agent::tool_blocks::ToolCallRecord {
+                name: call.name.clone(),
+                arguments: call.arguments.clone(),
+                duration: outcome.duration,
+                success: outcome.success,
+                output: scrub_credentials(&outcome.output),
+            });
+
             // ── Hook: after_tool_call (void) ─────────────────
             if let Some(hooks) = hooks {
                 let tool_result_obj = crate::tools::ToolResult {
@@ -2619,7 +2692,15 @@ pub(crate) async fn run_tool_call_loop(
                     "\u{274c}"
                 };
                 tracing::debug!(tool = %call.name, secs, "Sending progress complete to draft");
-                let _ = tx.send(format!("{icon} {} ({secs}s)\n", call.name)).await;
+                let line = if outcome.timed_out {
+                    format!(
+                        "\u{23f1}\u{fe0f} Watchdog cancelled {} after {secs}s\n",
+                        call.name
+                    )
+                } else {
+                    format!("{icon} {} ({secs}s)\n", call.name)
+                };
+                let _ = tx.send(line).await;
             }
 
             ordered_results[*idx] = Some((call.name.clone(), call.tool_call_id.clone(), outcome));
@@ -2722,6 +2803,53 @@ pub(crate) fn build_tool_instructions(tools_registry: &[Box<dyn Tool>]) -> Strin
 // interactive REPL mode. The interactive loop manages history compaction
 // and hard trimming to keep the context window bounded.
 
+/// Redraws one stderr line with the turn's running stats; the final snapshot
+/// stays on screen above the reply. When stderr is not a terminal only the
+/// final snapshot is written, as a plain line.
+fn generation_status_line() -> crate::providers::progress::ProgressCallback {
+    let redraw = std::io::stderr().is_terminal();
+    Arc::new(move |stats| {
+        let mut stderr = std::io::stderr();
+        if !redraw {
+            if stats.done {
+                let _ = writeln!(stderr, "{}", stats.status_line());
+            }
+        } else if stats.done {
+            let _ = writeln!(stderr, "\r\x1b[2K{}", stats.status_line());
+        } else {
+            let _ = write!(stderr, "\r\x1b[2K{}", stats.status_line());
+            let _ = stderr.flush();
+        }
+    })
+}
+
+/// Render a turn's progress lines and streamed answer as they arrive in
+/// the interactive REPL. Returns whether the answer itself was streamed.
+/// Line-clearing escapes are only written when stdout is a terminal.
+async fn print_deltas(mut rx: tokio::sync::mpsc::Receiver<String>) -> bool {
+    let clear_line = if std::io::stdout().is_terminal() {
+        "\r\x1b[2K"
+    } else {
+        ""
+    };
+    let mut answering = false;
+    while let Some(delta) = rx.recv().await {
+        let mut stdout = std::io::stdout();
+        if delta == DRAFT_CLEAR_SENTINEL {
+            // Progress is done; the answer follows on a fresh line.
+            answering = true;
+            let _ = writeln!(stdout, "{clear_line}");
+        } else if answering {
+            let _ = write!(stdout, "{delta}");
+        } else {
+            // Clear any generation status line before a progress line.
+            let _ = write!(stdout, "{clear_line}{delta}");
+        }
+        let _ = stdout.flush();
+    }
+    answering
+}
+
 #[allow(clippy::too_many_lines)]
 pub async fn run(
     config: Config,
@@ -2732,8 +2860,13 @@ pub async fn run(
     peripheral_overrides: Vec<String>,
     interactive: bool,
 ) -> Result<String> {
+    let watchdog = crate::agent::watchdog::Watchdog::new(&config);
+
     // ── Wire up agnostic subsystems ──────────────────────────────
-    let base_observer = observability::create_observer(&config.observability);
+    let base_observer = crate::cost::usage::with_recording(
+        observability::create_observer(&config.observability),
+        &config,
+    );
     let observer: Arc<dyn Observer> = Arc::from(base_observer);
     let runtime: Arc<dyn runtime::RuntimeAdapter> =
         Arc::from(runtime::create_runtime(&config.runtime)?);
@@ -2743,12 +2876,15 @@ pub async fn run(
     ));
 
     // ── Memory (the brain) ────────────────────────────────────────
-    let mem: Arc<dyn Memory> = Arc::from(memory::create_memory_with_storage(
-        &config.memory,
-        Some(&config.storage.provider.config),
-        &config.workspace_dir,
-        config.api_key.as_deref(),
-    )?);
+    let mem: Arc<dyn Memory> = Arc::from(memory::with_auto_save_policy(
+        memory::create_memory_with_storage(
+            &config.memory,
+            Some(&config.storage.provider.config),
+            &config.workspace_dir,
+            config.api_key.as_deref(),
+        )?,
+        &config,
+    ));
     tracing::info!(backend = mem.name(), "Memory initialized");
 
     // ── Peripherals (merge peripheral tools into registry) ─
@@ -2790,6 +2926,9 @@ pub async fn run(
         tracing::info!(count = peripheral_tools.len(), "Peripheral tools added");
         tools_registry.extend(peripheral_tools);
     }
+    let tools_registry = tools::limits::wrap_registry(tools_registry, &config.agent.tool_limits);
+    let dry_run = tools::dry_run::DryRunSwitch::new(config.agent.dry_run);
+    let tools_registry = tools::dry_run::wrap_registry(tools_registry, &dry_run);
 
     // ── Resolve provider ─────────────────────────────────────────
     let provider_name = provider_override
@@ -2808,6 +2947,8 @@ pub async fn run(
         zeroclaw_dir: config.config_path.parent().map(std::path::PathBuf::from),
         secrets_encrypt: config.secrets.encrypt,
         reasoning_enabled: config.runtime.reasoning_enabled,
+        connectivity: Some(crate::connectivity::Connectivity::new(&config)),
+        recorder: Some(crate::providers::recorder::Recorder::new(&config)),
     };
 
     let provider: Box<dyn Provider> = providers::create_routed_provider_with_options(
@@ -2979,7 +3120,10 @@ pub async fn run(
 
     // ── Approval manager (supervised mode) ───────────────────────
     let approval_manager = if interactive {
-        Some(ApprovalManager::from_config(&config.autonomy))
+        Some(
+            ApprovalManager::from_config(&config.autonomy)
+                .with_notifications(config.ui.notifications.clone()),
+        )
     } else {
         None
     };
@@ -2993,15 +3137,22 @@ pub async fn run(
     if let Some(msg) = message {
         // Auto-save user message to memory (skip short/trivial messages)
         if config.memory.auto_save && msg.chars().count() >= AUTOSAVE_MIN_MESSAGE_CHARS {
-            let user_key = autosave_memory_key("user_msg");
-            let _ = mem
-                .store(&user_key, &msg, MemoryCategory::Conversation, None)
-                .await;
+            if let Some(content) = mem.redact_for_storage(CLI_CHANNEL, &msg) {
+                let user_key = autosave_memory_key("user_msg");
+                let _ = mem
+                    .store(&user_key, &content, mem.auto_save_category(&content), None)
+                    .await;
+            }
         }
 
         // Inject memory + hardware RAG context into user message
-        let mem_context =
-            build_context(mem.as_ref(), &msg, config.memory.min_relevance_score).await;
+        let mem_context = build_context(
+            mem.as_ref(),
+            &msg,
+            config.memory.min_relevance_score,
+            config.memory.context_token_budget,
+        )
+        .await;
         let rag_limit = if config.agent.compact_context { 2 } else { 5 };
         let hw_context = hardware_rag
             .as_ref()
@@ -3020,50 +3171,48 @@ pub async fn run(
             ChatMessage::user(&enriched),
         ];
 
-        let response = run_tool_call_loop(
-            provider.as_ref(),
-            &mut history,
-            &tools_registry,
-            observer.as_ref(),
-            provider_name,
-            model_name,
-            temperature,
-            false,
-            approval_manager.as_ref(),
-            channel_name,
-            &config.multimodal,
-            config.agent.max_tool_iterations,
-            None,
-            None,
-            None,
-            &[],
-        )
-        .await?;
+        let response = watchdog
+            .scope(run_tool_call_loop(
+                provider.as_ref(),
+                &mut history,
+                &tools_registry,
+                observer.as_ref(),
+                provider_name,
+                model_name,
+                temperature,
+                false,
+                approval_manager.as_ref(),
+                channel_name,
+                &config.multimodal,
+                config.agent.max_tool_iterations,
+                None,
+                None,
+                None,
+                &[],
+            ))
+            .await?;
         final_output = response.clone();
         println!("{response}");
         observer.record_event(&ObserverEvent::TurnComplete);
     } else {
         println!("🦀 ZeroClaw Interactive Mode");
+        if dry_run.is_enabled() {
+            println!("🧪 Dry-run mode: tools describe what they would do without executing.");
+        }
         println!("Type /help for commands.\n");
         let cli = crate::channels::CliChannel::new();
 
         // Persistent conversation history across turns
         let mut history = vec![ChatMessage::system(&system_prompt)];
-
-        loop {
-            print!("> ");
-            let _ = std::io::stdout().flush();
-
-            let mut input = String::new();
-            match std::io::stdin().read_line(&mut input) {
-                Ok(0) => break,
-                Ok(_) => {}
-                Err(e) => {
-                    eprintln!("\nError reading input: {e}\n");
-                    break;
-                }
-            }
-
+        let mut last_tool_calls = Vec::new();
+        let mut show_stats = config.ui.generation_stats;
+        let mut session_model = model_name.to_string();
+        let cached_models =
+            crate::channels::load_cached_models(&config.workspace_dir, provider_name);
+        let mut reader =
+            crate::agent::repl::LineReader::new(&config.workspace_dir, cached_models.clone());
+
+        while let Some(input) = reader.read() {
             let user_input = input.trim().to_string();
             if user_input.is_empty() {
                 continue;
@@ -3071,10 +3220,135 @@ pub async fn run(
             match user_input.as_str() {
                 "/quit" | "/exit" => break,
                 "/help" => {
-                    println!("Available commands:");
-                    println!("  /help        Show this help message");
-                    println!("  /clear /new  Clear conversation history");
-                    println!("  /quit /exit  Exit interactive mode\n");
+                    crate::agent::repl::print_help();
+                    continue;
+                }
+                command if command == "/model" || command.starts_with("/model ") => {
+                    let requested = command.trim_start_matches("/model").trim();
+                    if requested.is_empty() {
+                        println!("Current model: {provider_name}/{session_model}\n");
+                    } else {
+                        session_model = requested.to_string();
+                        println!("Model switched to {session_model} for this session.\n");
+                    }
+                    continue;
+                }
+                "/models" => {
+                    if cached_models.is_empty() {
+                        println!(
+                            "No cached models for {provider_name}. Run `zeroclaw models refresh` to fetch them.\n"
+                        );
+                    } else {
+                        println!("Cached models for {provider_name}:");
+                        for model in &cached_models {
+                            let marker = if *model == session_model { "*" } else { " " };
+                            println!(" {marker} {model}");
+                        }
+                        println!();
+                    }
+                    continue;
+                }
+                command if command == "/memory" || command.starts_with("/memory ") => {
+                    let query = command.trim_start_matches("/memory").trim();
+                    if query.is_empty() {
+                        match mem.count().await {
+                            Ok(count) => {
+                                println!("Memory ({}): {count} entries.\n", mem.name());
+                            }
+                            Err(e) => println!("Memory count failed: {e}\n"),
+                        }
+                    } else {
+                        match mem.recall(query, 5, None).await {
+                            Ok(entries) if entries.is_empty() => {
+                                println!("No memories match \"{query}\".\n");
+                            }
+                            Ok(entries) => {
+                                for entry in &entries {
+                                    println!(
+                                        "- [{}] {}: {}",
+                                        entry.category,
+                                        entry.key,
+                                        truncate_with_ellipsis(&entry.content, 120)
+                                    );
+                                }
+                                println!();
+                            }
+                            Err(e) => println!("Memory search failed: {e}\n"),
+                        }
+                    }
+                    continue;
+                }
+                "/session" => {
+                    println!("Provider:  {provider_name}");
+                    println!("Model:     {session_model}");
+                    println!("Messages:  {}", history.len().saturating_sub(1));
+                    println!(
+                        "Dry-run:   {}",
+                        if dry_run.is_enabled() { "on" } else { "off" }
+                    );
+                    println!("Stats:     {}\n", if show_stats { "on" } else { "off" });
+                    continue;
+                }
+                command if command == "/tools" || command.starts_with("/tools ") => {
+                    let arg = command.trim_start_matches("/tools").trim();
+                    let only = if arg.is_empty() {
+                        None
+                    } else if let Ok(n) = arg.parse::<usize>() {
+                        Some(n)
+                    } else {
+                        println!("Usage: /tools [n]\n");
+                        continue;
+                    };
+                    if last_tool_calls.is_empty() {
+                        println!("No tool calls in the last reply.\n");
+                    } else if only.is_some_and(|n| n == 0 || n > last_tool_calls.len()) {
+                        println!(
+                            "No tool call #{arg}; the last reply made {}.\n",
+                            last_tool_calls.len()
+                        );
+                    } else {
+                        println!(
+                            "{}",
+                            crate::agent::tool_blocks::render_expanded(&last_tool_calls, only)
+                        );
+                    }
+                    continue;
+                }
+                command if command == "/stats" || command.starts_with("/stats ") => {
+                    show_stats = match command.trim_start_matches("/stats").trim() {
+                        "" => !show_stats,
+                        "on" => true,
+                        "off" => false,
+                        other => {
+                            println!("Unknown /stats option '{other}'; use on or off.\n");
+                            continue;
+                        }
+                    };
+                    println!(
+                        "Generation stats {}.\n",
+                        if show_stats { "on" } else { "off" }
+                    );
+                    continue;
+                }
+                command if command == "/dry-run" || command.starts_with("/dry-run ") => {
+                    let enabled = match command.trim_start_matches("/dry-run").trim() {
+                        "" => !dry_run.is_enabled(),
+                        "on" => true,
+                        "off" => false,
+                        other => {
+                            println!("Unknown /dry-run option '{other}'; use on or off.\n");
+                            continue;
+                        }
+                    };
+                    dry_run.set(enabled);
+                    println!(
+                        "Dry-run mode {}.\n",
+                        if enabled {
+                            "on: tools will only describe their actions"
+                        } else {
+                            "off: tools execute normally"
+                        }
+                    );
                     continue;
                 }
                 "/clear" | "/new" => {
@@ -3118,15 +3392,22 @@ pub async fn run(
 
             // Auto-save conversation turns (skip short/trivial messages)
             if config.memory.auto_save && user_input.chars().count() >= AUTOSAVE_MIN_MESSAGE_CHARS {
-                let user_key = autosave_memory_key("user_msg");
-                let _ = mem
-                    .store(&user_key, &user_input, MemoryCategory::Conversation, None)
-                    .await;
+                if let Some(content) = mem.redact_for_storage(CLI_CHANNEL, &user_input) {
+                    let user_key = autosave_memory_key("user_msg");
+                    let _ = mem
+                        .store(&user_key, &content, mem.auto_save_category(&content), None)
+                        .await;
+                }
             }
 
             // Inject memory + hardware RAG context into user message
-            let mem_context =
-                build_context(mem.as_ref(), &user_input, config.memory.min_relevance_score).await;
+            let mem_context = build_context(
+                mem.as_ref(),
+                &user_input,
+                config.memory.min_relevance_score,
+                config.memory.context_token_budget,
+            )
+            .await;
             let rag_limit = if config.agent.compact_context { 2 } else { 5 };
             let hw_context = hardware_rag
                 .as_ref()
@@ -3142,48 +3423,72 @@ pub async fn run(
 
             history.push(ChatMessage::user(&enriched));
 
-            let response = match run_tool_call_loop(
-                provider.as_ref(),
-                &mut history,
-                &tools_registry,
-                observer.as_ref(),
-                provider_name,
-                model_name,
-                temperature,
-                false,
-                approval_manager.as_ref(),
-                channel_name,
-                &config.multimodal,
-                config.agent.max_tool_iterations,
-                None,
-                None,
-                None,
-                &[],
-            )
-            .await
-            {
-                Ok(resp) => resp,
+            let (delta_tx, delta_rx) = tokio::sync::mpsc::channel::<String>(64);
+            let printer = tokio::spawn(print_deltas(delta_rx));
+            let turn = Box::pin(crate::agent::tool_blocks::capture(watchdog.scope(
+                run_tool_call_loop(
+                    provider.as_ref(),
+                    &mut history,
+                    &tools_registry,
+                    observer.as_ref(),
+                    provider_name,
+                    &session_model,
+                    temperature,
+                    false,
+                    approval_manager.as_ref(),
+                    channel_name,
+                    &config.multimodal,
+                    config.agent.max_tool_iterations,
+                    None,
+                    Some(delta_tx),
+                    None,
+                    &[],
+                ),
+            )));
+            let (response, tool_calls) = if show_stats {
+                crate::providers::progress::observe(generation_status_line(), turn).await
+            } else {
+                turn.await
+            };
+            let streamed = printer.await.unwrap_or(false);
+            let response = match response {
+                Ok(resp) => {
+                    if streamed {
+                        println!("\n");
+                    } else if let Err(e) = crate::channels::Channel::send(
+                        &cli,
+                        &crate::channels::traits::SendMessage::new(format!("\n{resp}\n"), "user"),
+                    )
+                    .await
+                    {
+                        eprintln!("\nError sending CLI response: {e}\n");
+                    }
+                    Some(resp)
+                }
                 Err(e) => {
                     eprintln!("\nError: {e}\n");
-                    continue;
+                    None
                 }
             };
-            final_output = response.clone();
-            if let Err(e) = crate::channels::Channel::send(
-                &cli,
-                &crate::channels::traits::SendMessage::new(format!("\n{response}\n"), "user"),
-            )
-            .await
-            {
-                eprintln!("\nError sending CLI response: {e}\n");
+            // Tool blocks go below the reply, whether it was streamed or not.
+            if !tool_calls.is_empty() {
+                println!(
+                    "{}",
+                    crate::agent::tool_blocks::render_collapsed(&tool_calls)
+                );
+                last_tool_calls = tool_calls;
             }
+            let Some(response) = response else {
+                continue;
+            };
+            final_output = response;
             observer.record_event(&ObserverEvent::TurnComplete);
 
             // Auto-compaction before hard trimming to preserve long-context signal.
             if let Ok(compacted) = auto_compact_history(
                 &mut history,
                 provider.as_ref(),
-                model_name,
+                &session_model,
                 config.agent.max_history_messages,
             )
             .await
@@ -3212,21 +3517,32 @@ pub async fn run(
 
 /// Process a single message through the full agent (with tools, peripherals, memory).
 /// Used by channels (Telegram, Discord, etc.) to enable hardware and tool use.
-pub async fn process_message(config: Config, message: &str) -> Result<String> {
+/// Token usage and provider errors are counted into `metrics`.
+pub async fn process_message(
+    config: Config,
+    message: &str,
+    metrics: &crate::health::metrics::RuntimeMetrics,
+) -> Result<String> {
     let observer: Arc<dyn Observer> =
-        Arc::from(observability::create_observer(&config.observability));
+        Arc::from(metrics.observe(crate::cost::usage::with_recording(
+            observability::create_observer(&config.observability),
+            &config,
+        )));
     let runtime: Arc<dyn runtime::RuntimeAdapter> =
         Arc::from(runtime::create_runtime(&config.runtime)?);
     let security = Arc::new(SecurityPolicy::from_config(
         &config.autonomy,
         &config.workspace_dir,
     ));
-    let mem: Arc<dyn Memory> = Arc::from(memory::create_memory_with_storage(
-        &config.memory,
-        Some(&config.storage.provider.config),
-        &config.workspace_dir,
-        config.api_key.as_deref(),
-    )?);
+    let mem: Arc<dyn Memory> = Arc::from(memory::with_auto_save_policy(
+        memory::create_memory_with_storage(
+            &config.memory,
+            Some(&config.storage.provider.config),
+            &config.workspace_dir,
+            config.api_key.as_deref(),
+        )?,
+        &config,
+    ));
 
     let (composio_key, composio_entity_id) = if config.composio.enabled {
         (
@@ -3254,6 +3570,11 @@ pub async fn process_message(config: Config, message: &str) -> Result<String> {
     let peripheral_tools: Vec<Box<dyn Tool>> =
         crate::peripherals::create_peripheral_tools(&config.peripherals).await?;
     tools_registry.extend(peripheral_tools);
+    tools_registry = tools::limits::wrap_registry(tools_registry, &config.agent.tool_limits);
+    if config.agent.dry_run {
+        let switch = tools::dry_run::DryRunSwitch::new(true);
+        tools_registry = tools::dry_run::wrap_registry(tools_registry, &switch);
+    }
 
     let provider_name = config.default_provider.as_deref().unwrap_or("openrouter");
     let model_name = config
@@ -3266,6 +3587,8 @@ pub async fn process_message(config: Config, message: &str) -> Result<String> {
         zeroclaw_dir: config.config_path.parent().map(std::path::PathBuf::from),
         secrets_encrypt: config.secrets.encrypt,
         reasoning_enabled: config.runtime.reasoning_enabled,
+        connectivity: Some(crate::connectivity::Connectivity::new(&config)),
+        recorder: Some(crate::providers::recorder::Recorder::new(&config)),
     };
     let provider: Box<dyn Provider> = providers::create_routed_provider_with_options(
         provider_name,
@@ -3360,7 +3683,13 @@ pub async fn process_message(config: Config, message: &str) -> Result<String> {
         system_prompt.push_str(&build_tool_instructions(&tools_registry));
     }
 
-    let mem_context = build_context(mem.as_ref(), message, config.memory.min_relevance_score).await;
+    let mem_context = build_context(
+        mem.as_ref(),
+        message,
+        config.memory.min_relevance_score,
+        config.memory.context_token_budget,
+    )
+    .await;
     let rag_limit = if config.agent.compact_context { 2 } else { 5 };
     let hw_context = hardware_rag
         .as_ref()
@@ -3379,19 +3708,20 @@ pub async fn process_message(config: Config, message: &str) -> Result<String> {
         ChatMessage::user(&enriched),
     ];
 
-    agent_turn(
-        provider.as_ref(),
-        &mut history,
-        &tools_registry,
-        observer.as_ref(),
-        provider_name,
-        &model_name,
-        config.default_temperature,
-        true,
-        &config.multimodal,
-        config.agent.max_tool_iterations,
-    )
-    .await
+    crate::agent::watchdog::Watchdog::new(&config)
+        .scope(agent_turn(
+            provider.as_ref(),
+            &mut history,
+            &tools_registry,
+            observer.as_ref(),
+            provider_name,
+            &model_name,
+            config.default_temperature,
+            true,
+            &config.multimodal,
+            config.agent.max_tool_iterations,
+        ))
+        .await
 }
 
 #[cfg(test)]
@@ -4754,7 +5084,7 @@ Tail"#;
         .await
         .unwrap();
 
-        let context = build_context(&mem, "status updates", 0.0).await;
+        let context = build_context(&mem, "status updates", 0.0, 1_000).await;
         assert!(context.contains("user_msg_real"));
         assert!(!context.contains("assistant_resp_poisoned"));
         assert!(!context.contains("fabricated event"));
@@ -1,6 +1,6 @@
+use crate::memory::packing::{pack_context, ContextCandidate};
 use crate::memory::{self, Memory};
 use async_trait::async_trait;
-use std::fmt::Write;
 
 #[async_trait]
 pub trait MemoryLoader: Send + Sync {
@@ -11,6 +11,7 @@ pub trait MemoryLoader: Send + Sync {
 pub struct DefaultMemoryLoader {
     limit: usize,
     min_relevance_score: f64,
+    token_budget: usize,
 }
 
 impl Default for DefaultMemoryLoader {
@@ -18,6 +19,7 @@ impl Default for DefaultMemoryLoader {
         Self {
             limit: 5,
             min_relevance_score: 0.4,
+            token_budget: 0,
         }
     }
 }
@@ -27,8 +29,15 @@ impl DefaultMemoryLoader {
         Self {
             limit: limit.max(1),
             min_relevance_score,
+            token_budget: 0,
         }
     }
+
+    /// Pack recalled memories under an estimated token budget (`0` = no limit).
+    pub fn with_token_budget(mut self, token_budget: usize) -> Self {
+        self.token_budget = token_budget;
+        self
+    }
 }
 
 #[async_trait]
@@ -39,30 +48,19 @@ impl MemoryLoader for DefaultMemoryLoader {
         user_message: &str,
     ) -> anyhow::Result<String> {
         let entries = memory.recall(user_message, self.limit, None).await?;
-        if entries.is_empty() {
-            return Ok(String::new());
-        }
-
-        let mut context = String::from("[Memory context]\n");
-        for entry in entries {
-            if memory::is_assistant_autosave_key(&entry.key) {
-                continue;
-            }
-            if let Some(score) = entry.score {
-                if score < self.min_relevance_score {
-                    continue;
-                }
-            }
-            let _ = writeln!(context, "- {}: {}", entry.key, entry.content);
-        }
-
-        // If all entries were below threshold, return empty
-        if context == "[Memory context]\n" {
-            return Ok(String::new());
-        }
-
-        context.push('\n');
-        Ok(context)
+        let candidates = entries
+            .iter()
+            .filter(|entry| !memory::is_assistant_autosave_key(&entry.key))
+            .filter(|entry| {
+                entry
+                    .score
+                    .is_none_or(|score| score >= self.min_relevance_score)
+            })
+            .map(|entry| ContextCandidate::new(&entry.key, &entry.content, entry.score))
+            .collect();
+
+        // Empty when every entry was filtered out or nothing fits the budget
+        Ok(pack_context(candidates, self.token_budget))
     }
 }
 
@@ -227,4 +225,29 @@ mod tests {
         assert!(!context.contains("assistant_resp_legacy"));
         assert!(!context.contains("fabricated detail"));
     }
+
+    #[tokio::test]
+    async fn token_budget_keeps_most_relevant_and_notes_the_rest() {
+        let entry = |key: &str, content: String, score: f64| MemoryEntry {
+            id: key.into(),
+            key: key.into(),
+            content,
+            category: MemoryCategory::Core,
+            timestamp: "now".into(),
+            session_id: None,
+            score: Some(score),
+        };
+        let memory = MockMemoryWithEntries {
+            entries: Arc::new(vec![
+                entry("verbose", "z".repeat(2_000), 0.7),
+                entry("pin", "LED on pin 13".into(), 0.9),
+            ]),
+        };
+
+        let loader = DefaultMemoryLoader::new(20, 0.0).with_token_budget(100);
+        let context = loader.load_context(&memory, "led").await.unwrap();
+        assert!(context.contains("- pin: LED on pin 13"));
+        assert!(!context.contains("verbose"));
+        assert!(context.contains("[1 more related memory omitted]"));
+    }
 }
@@ -2,9 +2,13 @@
 pub mod agent;
 pub mod classifier;
 pub mod dispatcher;
+pub mod escalation;
 pub mod loop_;
 pub mod memory_loader;
 pub mod prompt;
+pub mod repl;
+pub mod tool_blocks;
+pub mod watchdog;
 
 #[cfg(test)]
 mod tests;
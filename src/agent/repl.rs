@@ -0,0 +1,295 @@
+//! Line editing for `zeroclaw agent` interactive mode.
+//!
+//! Input history persists in `<workspace>/state/cli_history`, Tab completes
+//! slash commands and (after `/model `) cached model names, and a message can
+//! span several lines by ending a line with `\` or wrapping it in a ``` fence.
+//! When stdin is not a terminal, lines are read plainly so piped input works.
+
+use dialoguer::theme::Theme;
+use dialoguer::{Completion, History, Input};
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{IsTerminal as _, Write as _};
+use std::path::{Path, PathBuf};
+
+const HISTORY_FILE: &str = "state/cli_history";
+const HISTORY_LIMIT: usize = 1_000;
+const FENCE: &str = "```";
+
+/// Slash commands shown by `/help` and offered by Tab completion.
+pub const SLASH_COMMANDS: &[(&str, &str)] = &[
+    ("/help", "Show this help message"),
+    ("/model [name]", "Show or switch the model for this session"),
+    ("/models", "List cached models for the current provider"),
+    (
+        "/memory [query]",
+        "Search memory, or show how many entries it holds",
+    ),
+    (
+        "/session",
+        "Show this session's provider, model and history size",
+    ),
+    ("/clear /new", "Clear conversation history"),
+    (
+        "/dry-run [on|off]",
+        "Toggle describing tool calls instead of running them",
+    ),
+    ("/tools [n]", "Expand tool calls from the last reply"),
+    ("/stats [on|off]", "Toggle the live tokens/sec status line"),
+    ("/quit /exit", "Exit interactive mode"),
+];
+
+pub fn print_help() {
+    println!("Available commands:");
+    for (usage, help) in SLASH_COMMANDS {
+        println!("  {usage:<18} {help}");
+    }
+    println!("\nEnd a line with \\ or open a ``` fence to write several lines.\n");
+}
+
+fn command_names() -> impl Iterator<Item = &'static str> {
+    SLASH_COMMANDS
+        .iter()
+        .flat_map(|(usage, _)| usage.split_whitespace())
+        .filter(|word| word.starts_with('/'))
+}
+
+/// Input history backed by a file, newest entry last.
+struct FileHistory {
+    path: PathBuf,
+    entries: VecDeque<String>,
+}
+
+impl FileHistory {
+    fn load(workspace_dir: &Path) -> Self {
+        let path = workspace_dir.join(HISTORY_FILE);
+        let mut entries: VecDeque<String> = std::fs::read_to_string(&path)
+            .unwrap_or_default()
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(str::to_string)
+            .collect();
+        if entries.len() > HISTORY_LIMIT {
+            entries.drain(..entries.len() - HISTORY_LIMIT);
+            let mut contents = entries.iter().cloned().collect::<Vec<_>>().join("\n");
+            contents.push('\n');
+            let _ = std::fs::write(&path, contents);
+        }
+        Self { path, entries }
+    }
+}
+
+impl History<String> for FileHistory {
+    fn read(&self, pos: usize) -> Option<String> {
+        let index = self.entries.len().checked_sub(pos + 1)?;
+        self.entries.get(index).cloned()
+    }
+
+    fn write(&mut self, val: &String) {
+        let entry = val.trim();
+        if entry.is_empty() || self.entries.back().is_some_and(|last| last == entry) {
+            return;
+        }
+        self.entries.push_back(entry.to_string());
+        if self.entries.len() > HISTORY_LIMIT {
+            self.entries.pop_front();
+        }
+        if let Some(parent) = self.path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        if let Ok(mut file) = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+        {
+            let _ = writeln!(file, "{entry}");
+        }
+    }
+}
+
+/// Tab completion for slash commands and `/model <name>`.
+struct SlashCompletion {
+    models: Vec<String>,
+}
+
+impl Completion for SlashCompletion {
+    fn get(&self, input: &str) -> Option<String> {
+        if let Some(partial) = input.strip_prefix("/model ") {
+            let model = complete(self.models.iter().map(String::as_str), partial.trim_start())?;
+            return Some(format!("/model {model}"));
+        }
+        if input.starts_with('/') && !input.contains(char::is_whitespace) {
+            return complete(command_names(), input);
+        }
+        None
+    }
+}
+
+/// Longest common prefix of the candidates starting with `prefix`, when it
+/// extends `prefix`.
+fn complete<'a>(candidates: impl Iterator<Item = &'a str>, prefix: &str) -> Option<String> {
+    let mut common: Option<&str> = None;
+    for candidate in candidates.filter(|candidate| candidate.starts_with(prefix)) {
+        common = Some(match common {
+            None => candidate,
+            Some(common) => {
+                let len = common
+                    .char_indices()
+                    .zip(candidate.chars())
+                    .take_while(|((_, a), b)| a == b)
+                    .last()
+                    .map_or(0, |((i, a), _)| i + a.len_utf8());
+                &common[..len]
+            }
+        });
+    }
+    common
+        .filter(|common| common.len() > prefix.len())
+        .map(str::to_string)
+}
+
+/// Whether `buffer` continues on the next line: a trailing `\` or an open
+/// code fence.
+fn needs_more(buffer: &str) -> bool {
+    let open_fences = buffer
+        .lines()
+        .filter(|line| line.trim_start().starts_with(FENCE))
+        .count();
+    buffer.ends_with('\\') || open_fences % 2 == 1
+}
+
+struct ReplTheme;
+
+impl Theme for ReplTheme {
+    fn format_input_prompt(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        _default: Option<&str>,
+    ) -> fmt::Result {
+        write!(f, "{prompt} ")
+    }
+
+    fn format_input_prompt_selection(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        sel: &str,
+    ) -> fmt::Result {
+        write!(f, "{prompt} {sel}")
+    }
+}
+
+pub struct LineReader {
+    history: FileHistory,
+    completion: SlashCompletion,
+    terminal: bool,
+}
+
+impl LineReader {
+    /// `models` are offered for `/model <Tab>`.
+    pub fn new(workspace_dir: &Path, models: Vec<String>) -> Self {
+        Self {
+            history: FileHistory::load(workspace_dir),
+            completion: SlashCompletion { models },
+            terminal: std::io::stdin().is_terminal() && std::io::stdout().is_terminal(),
+        }
+    }
+
+    fn read_line(&mut self, prompt: &str, first: bool) -> Option<String> {
+        if !self.terminal {
+            print!("{prompt} ");
+            let _ = std::io::stdout().flush();
+            let mut line = String::new();
+            return match std::io::stdin().read_line(&mut line) {
+                Ok(0) => None,
+                Ok(_) => Some(line.trim_end_matches(['\r', '\n']).to_string()),
+                Err(e) => {
+                    eprintln!("\nError reading input: {e}\n");
+                    None
+                }
+            };
+        }
+
+        let input = Input::<String>::with_theme(&ReplTheme)
+            .with_prompt(prompt)
+            .allow_empty(true);
+        // Continuation lines are not recorded or completed on their own.
+        let result = if first {
+            input
+                .history_with(&mut self.history)
+                .completion_with(&self.completion)
+                .interact_text()
+        } else {
+            input.interact_text()
+        };
+        result.ok()
+    }
+
+    /// Read one message, joining continuation lines. `None` on end of input.
+    pub fn read(&mut self) -> Option<String> {
+        let mut buffer = self.read_line(">", true)?;
+        while needs_more(&buffer) {
+            if let Some(stripped) = buffer.strip_suffix('\\') {
+                buffer.truncate(stripped.len());
+            }
+            let Some(line) = self.read_line("...", false) else {
+                break;
+            };
+            buffer.push('\n');
+            buffer.push_str(&line);
+        }
+        Some(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn completes_commands_and_models() {
+        let completion = SlashCompletion {
+            models: vec![
+                "anthropic/claude-sonnet-4".into(),
+                "anthropic/claude-opus-4".into(),
+                "openai/gpt-5".into(),
+            ],
+        };
+        assert_eq!(completion.get("/he").as_deref(), Some("/help"));
+        assert_eq!(completion.get("/mo").as_deref(), Some("/model"));
+        assert_eq!(completion.get("/mem").as_deref(), Some("/memory"));
+        assert_eq!(
+            completion.get("/model anth").as_deref(),
+            Some("/model anthropic/claude-")
+        );
+        assert_eq!(
+            completion.get("/model op").as_deref(),
+            Some("/model openai/gpt-5")
+        );
+        assert_eq!(completion.get("/model anthropic/claude-"), None);
+        assert_eq!(completion.get("hello"), None);
+    }
+
+    #[test]
+    fn continuation_on_backslash_or_open_fence() {
+        assert!(needs_more("first line \\"));
+        assert!(needs_more("```rust\nfn main() {}"));
+        assert!(!needs_more("```rust\nfn main() {}\n```"));
+        assert!(!needs_more("plain message"));
+    }
+
+    #[test]
+    fn history_persists_newest_last_without_repeats() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut history = FileHistory::load(tmp.path());
+        history.write(&"first".to_string());
+        history.write(&"second".to_string());
+        history.write(&"second".to_string());
+
+        let reloaded = FileHistory::load(tmp.path());
+        assert_eq!(reloaded.read(0).as_deref(), Some("second"));
+        assert_eq!(reloaded.read(1).as_deref(), Some("first"));
+        assert_eq!(reloaded.read(2), None);
+    }
+}
@@ -0,0 +1,165 @@
+//! Tool-call blocks for the interactive CLI chat.
+//!
+//! [`capture`] collects every tool call made during one agent turn. The chat
+//! prints them collapsed (one header line plus a short result summary) below
+//! the reply, and `/tools` expands the last turn's calls with full arguments
+//! and output.
+
+use crate::util::truncate_with_ellipsis;
+use parking_lot::Mutex;
+use std::fmt::Write;
+use std::future::Future;
+use std::time::Duration;
+
+const SUMMARY_CHARS: usize = 100;
+const EXPANDED_OUTPUT_CHARS: usize = 4000;
+
+tokio::task_local! {
+    static CAPTURED: Mutex<Vec<ToolCallRecord>>;
+}
+
+/// One executed tool call, with credentials already scrubbed.
+#[derive(Debug, Clone)]
+pub struct ToolCallRecord {
+    pub name: String,
+    pub arguments: serde_json::Value,
+    pub duration: Duration,
+    pub success: bool,
+    pub output: String,
+}
+
+/// Run `future` and return its output along with the tool calls made inside it.
+pub async fn capture<F: Future>(future: F) -> (F::Output, Vec<ToolCallRecord>) {
+    CAPTURED
+        .scope(Mutex::new(Vec::new()), async {
+            let output = future.await;
+            let calls = CAPTURED.with(|calls| std::mem::take(&mut *calls.lock()));
+            (output, calls)
+        })
+        .await
+}
+
+/// Record a finished call. A no-op outside [`capture`].
+pub fn record(call: ToolCallRecord) {
+    let _ = CAPTURED.try_with(|calls| calls.lock().push(call));
+}
+
+fn header(index: usize, call: &ToolCallRecord) -> String {
+    format!(
+        "{} [{}] {} ({:.1}s)",
+        if call.success { "✅" } else { "❌" },
+        index + 1,
+        call.name,
+        call.duration.as_secs_f64()
+    )
+}
+
+fn first_line(text: &str) -> &str {
+    text.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or("")
+}
+
+/// Collapsed blocks: header, one-line arguments and result summary.
+pub fn render_collapsed(calls: &[ToolCallRecord]) -> String {
+    let mut out = String::new();
+    for (index, call) in calls.iter().enumerate() {
+        let lines = call
+            .output
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .count();
+        let _ = writeln!(out, "┌ {}", header(index, call));
+        let _ = writeln!(
+            out,
+            "│ args: {}",
+            truncate_with_ellipsis(&call.arguments.to_string(), SUMMARY_CHARS)
+        );
+        let _ = writeln!(
+            out,
+            "└ {}{}",
+            truncate_with_ellipsis(first_line(&call.output), SUMMARY_CHARS),
+            if lines > 1 {
+                format!(" (+{} more lines)", lines - 1)
+            } else {
+                String::new()
+            }
+        );
+    }
+    if !calls.is_empty() {
+        out.push_str("  /tools to expand, /tools <n> for one call\n");
+    }
+    out
+}
+
+/// Expanded blocks with pretty-printed arguments and the full output.
+/// `only` selects a single 1-based call.
+pub fn render_expanded(calls: &[ToolCallRecord], only: Option<usize>) -> String {
+    let mut out = String::new();
+    for (index, call) in calls.iter().enumerate() {
+        if only.is_some_and(|n| n != index + 1) {
+            continue;
+        }
+        let _ = writeln!(out, "┌ {}", header(index, call));
+        let args = serde_json::to_string_pretty(&call.arguments)
+            .unwrap_or_else(|_| call.arguments.to_string());
+        out.push_str("│ arguments:\n");
+        for line in args.lines() {
+            let _ = writeln!(out, "│   {line}");
+        }
+        out.push_str("│ output:\n");
+        for line in truncate_with_ellipsis(&call.output, EXPANDED_OUTPUT_CHARS).lines() {
+            let _ = writeln!(out, "│   {line}");
+        }
+        out.push_str("└\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn call(name: &str, success: bool, output: &str) -> ToolCallRecord {
+        ToolCallRecord {
+            name: name.into(),
+            arguments: json!({"command": "ls -la"}),
+            duration: Duration::from_millis(1300),
+            success,
+            output: output.into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn capture_collects_calls_recorded_inside_the_scope() {
+        record(call("outside", true, ""));
+        let ((), calls) = capture(async {
+            record(call("shell", true, "a\nb"));
+        })
+        .await;
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].name, "shell");
+    }
+
+    #[test]
+    fn collapsed_and_expanded_views() {
+        let calls = vec![
+            call("shell", true, "\nfile_a\nfile_b\nfile_c"),
+            call("http_request", false, "timeout"),
+        ];
+
+        let collapsed = render_collapsed(&calls);
+        assert!(collapsed.contains("┌ ✅ [1] shell (1.3s)"));
+        assert!(collapsed.contains("└ file_a (+2 more lines)"));
+        assert!(collapsed.contains("┌ ❌ [2] http_request"));
+        assert!(!collapsed.contains("file_c"));
+
+        let expanded = render_expanded(&calls, Some(1));
+        assert!(expanded.contains("\"command\": \"ls -la\""));
+        assert!(expanded.contains("│   file_c"));
+        assert!(!expanded.contains("http_request"));
+        assert!(render_collapsed(&[]).is_empty());
+    }
+}
@@ -0,0 +1,422 @@
+//! Watchdog for stalled generations and tool calls (`[agent.watchdog]`).
+//!
+//! A guarded future that makes no progress for its limit is cancelled by
+//! dropping it. Progress is reported with [`touch`]: every streamed chunk
+//! pushes the deadline back, so a long answer that keeps streaming is never
+//! cut off, while a request that goes quiet is. A non-streamed request or a
+//! tool reports nothing until it finishes, so for those the limit covers the
+//! whole call. A stalled generation fails the turn with a
+//! [`WatchdogTimeout`], which channels relay to the user like any other turn
+//! error; a stalled tool returns a failed result to the model and a progress
+//! notice. Every incident is logged and appended to `state/watchdog.jsonl`
+//! in the workspace.
+//!
+//! Limits live on a [`Watchdog`] handle owned by each runtime (CLI session,
+//! channel runtime, agent). [`guard_generation`] and [`guard_tool`] apply the
+//! handle the caller entered with [`Watchdog::scope`]; outside a scope they
+//! impose no limit.
+
+use crate::config::{Config, WatchdogConfig};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::sync::Notify;
+
+const LOG_FILE: &str = "watchdog.jsonl";
+
+tokio::task_local! {
+    static ACTIVITY: Arc<Notify>;
+    static WATCHDOG: Watchdog;
+}
+
+struct Settings {
+    config: WatchdogConfig,
+    workspace_dir: PathBuf,
+}
+
+/// `[agent.watchdog]` limits for one runtime. Clones share the limits, so a
+/// [`Watchdog::configure`] after a config reload reaches every holder.
+#[derive(Clone, Default)]
+pub struct Watchdog {
+    settings: Arc<RwLock<Option<Settings>>>,
+}
+
+impl Watchdog {
+    /// Limits from `config`; disarmed unless `[agent.watchdog]` is enabled.
+    pub fn new(config: &Config) -> Self {
+        let watchdog = Self::default();
+        watchdog.configure(&config.agent.watchdog, &config.workspace_dir);
+        watchdog
+    }
+
+    /// Replace the limits, e.g. after a config reload. Disabling it takes
+    /// effect for the next guarded call.
+    pub fn configure(&self, config: &WatchdogConfig, workspace_dir: &Path) {
+        let settings = config.enabled.then(|| Settings {
+            config: config.clone(),
+            workspace_dir: workspace_dir.to_path_buf(),
+        });
+        *self.settings.write().unwrap_or_else(|e| e.into_inner()) = settings;
+    }
+
+    /// Run `future` with these limits applied to the guarded calls inside it.
+    pub async fn scope<F: Future>(&self, future: F) -> F::Output {
+        WATCHDOG.scope(self.clone(), future).await
+    }
+
+    fn limit(&self, limit: impl FnOnce(&WatchdogConfig) -> Option<Duration>) -> Option<Duration> {
+        self.settings
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .and_then(|settings| limit(&settings.config))
+    }
+
+    fn workspace_dir(&self) -> Option<PathBuf> {
+        self.settings
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .map(|settings| settings.workspace_dir.clone())
+    }
+
+    /// Await a provider request under the generation limit.
+    pub async fn guard_generation<F: Future>(
+        &self,
+        provider: &str,
+        model: &str,
+        future: F,
+    ) -> Result<F::Output, WatchdogTimeout> {
+        let limit = self.limit(|config| generation_limit(config, provider));
+        guard(
+            self.workspace_dir(),
+            IncidentKind::Generation,
+            format!("{provider}/{model}"),
+            limit,
+            future,
+        )
+        .await
+    }
+
+    /// Await a tool call under the tool limit.
+    pub async fn guard_tool<F: Future>(
+        &self,
+        tool: &str,
+        future: F,
+    ) -> Result<F::Output, WatchdogTimeout> {
+        let limit = self.limit(|config| tool_limit(config, tool));
+        guard(
+            self.workspace_dir(),
+            IncidentKind::Tool,
+            tool.to_string(),
+            limit,
+            future,
+        )
+        .await
+    }
+}
+
+/// The watchdog entered with [`Watchdog::scope`], if any.
+fn current() -> Watchdog {
+    WATCHDOG.try_with(Clone::clone).unwrap_or_default()
+}
+
+/// Report progress in the guarded call this task is running, pushing its
+/// stall deadline back. Does nothing outside a guarded call.
+pub fn touch() {
+    let _ = ACTIVITY.try_with(|activity| activity.notify_one());
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IncidentKind {
+    Generation,
+    Tool,
+}
+
+/// A request or tool call the watchdog cancelled.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Incident {
+    pub kind: IncidentKind,
+    /// `provider/model` for generations, the tool name for tools.
+    pub subject: String,
+    pub limit_secs: u64,
+    pub at: DateTime<Utc>,
+}
+
+impl Incident {
+    pub fn describe(&self) -> String {
+        let what = match self.kind {
+            IncidentKind::Generation => format!("generation {}", self.subject),
+            IncidentKind::Tool => format!("tool {}", self.subject),
+        };
+        format!(
+            "{} · {what} cancelled after {}s without progress",
+            self.at
+                .with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M"),
+            self.limit_secs
+        )
+    }
+}
+
+/// Returned when the watchdog cancels a generation or tool call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchdogTimeout {
+    pub kind: IncidentKind,
+    pub subject: String,
+    pub limit: Duration,
+}
+
+impl std::fmt::Display for WatchdogTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let secs = self.limit.as_secs();
+        match self.kind {
+            IncidentKind::Generation => write!(
+                f,
+                "Watchdog: {} made no progress for {secs}s; the request was cancelled",
+                self.subject
+            ),
+            IncidentKind::Tool => write!(
+                f,
+                "Watchdog: tool '{}' made no progress for {secs}s and was cancelled",
+                self.subject
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WatchdogTimeout {}
+
+/// Per-key override, else the default; `0` disables the limit.
+fn limit_for(overrides: &HashMap<String, u64>, key: &str, default: u64) -> Option<Duration> {
+    let secs = overrides.get(key).copied().unwrap_or(default);
+    (secs > 0).then(|| Duration::from_secs(secs))
+}
+
+fn generation_limit(config: &WatchdogConfig, provider: &str) -> Option<Duration> {
+    limit_for(
+        &config.provider_timeouts,
+        provider,
+        config.generation_timeout_secs,
+    )
+}
+
+fn tool_limit(config: &WatchdogConfig, tool: &str) -> Option<Duration> {
+    limit_for(&config.tool_timeouts, tool, config.tool_timeout_secs)
+}
+
+async fn guard<F: Future>(
+    workspace_dir: Option<PathBuf>,
+    kind: IncidentKind,
+    subject: String,
+    limit: Option<Duration>,
+    future: F,
+) -> Result<F::Output, WatchdogTimeout> {
+    let Some(limit) = limit else {
+        return Ok(future.await);
+    };
+    let activity = Arc::new(Notify::new());
+    let future = ACTIVITY.scope(Arc::clone(&activity), future);
+    tokio::pin!(future);
+    let stall = tokio::time::sleep(limit);
+    tokio::pin!(stall);
+    loop {
+        tokio::select! {
+            output = &mut future => return Ok(output),
+            () = activity.notified() => stall.as_mut().reset(tokio::time::Instant::now() + limit),
+            () = &mut stall => break,
+        }
+    }
+
+    let timeout = WatchdogTimeout {
+        kind,
+        subject,
+        limit,
+    };
+    tracing::warn!("{timeout}");
+    if let Some(workspace_dir) = workspace_dir {
+        if let Err(error) = append_log(&workspace_dir, &timeout) {
+            tracing::debug!("Failed to record watchdog incident: {error}");
+        }
+    }
+    Err(timeout)
+}
+
+/// Await a provider request under the scoped watchdog's generation limit.
+pub async fn guard_generation<F: Future>(
+    provider: &str,
+    model: &str,
+    future: F,
+) -> Result<F::Output, WatchdogTimeout> {
+    current().guard_generation(provider, model, future).await
+}
+
+/// Await a tool call under the scoped watchdog's tool limit.
+pub async fn guard_tool<F: Future>(tool: &str, future: F) -> Result<F::Output, WatchdogTimeout> {
+    current().guard_tool(tool, future).await
+}
+
+fn log_path(workspace_dir: &Path) -> PathBuf {
+    workspace_dir.join("state").join(LOG_FILE)
+}
+
+fn append_log(workspace_dir: &Path, timeout: &WatchdogTimeout) -> anyhow::Result<()> {
+    let path = log_path(workspace_dir);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    let incident = Incident {
+        kind: timeout.kind,
+        subject: timeout.subject.clone(),
+        limit_secs: timeout.limit.as_secs(),
+        at: Utc::now(),
+    };
+    writeln!(file, "{}", serde_json::to_string(&incident)?)?;
+    Ok(())
+}
+
+/// The most recent `limit` incidents, oldest first.
+pub fn recent_incidents(workspace_dir: &Path, limit: usize) -> Vec<Incident> {
+    let Ok(contents) = std::fs::read_to_string(log_path(workspace_dir)) else {
+        return Vec::new();
+    };
+    let mut incidents: Vec<Incident> = contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    let skip = incidents.len().saturating_sub(limit);
+    incidents.drain(..skip);
+    incidents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_win_and_zero_disables() {
+        let config = WatchdogConfig {
+            enabled: true,
+            generation_timeout_secs: 120,
+            tool_timeout_secs: 300,
+            provider_timeouts: HashMap::from([("ollama".into(), 600)]),
+            tool_timeouts: HashMap::from([("shell".into(), 30), ("browser".into(), 0)]),
+        };
+        assert_eq!(
+            generation_limit(&config, "openrouter"),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            generation_limit(&config, "ollama"),
+            Some(Duration::from_secs(600))
+        );
+        assert_eq!(tool_limit(&config, "shell"), Some(Duration::from_secs(30)));
+        assert_eq!(tool_limit(&config, "browser"), None);
+        assert_eq!(
+            tool_limit(&config, "file_read"),
+            Some(Duration::from_secs(300))
+        );
+    }
+
+    #[test]
+    fn configure_replaces_earlier_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = WatchdogConfig {
+            enabled: true,
+            generation_timeout_secs: 600,
+            ..WatchdogConfig::default()
+        };
+        let watchdog = Watchdog::default();
+        let other = Watchdog::default();
+        watchdog.configure(&config, dir.path());
+        assert_eq!(
+            watchdog
+                .clone()
+                .limit(|config| generation_limit(config, "openrouter")),
+            Some(Duration::from_secs(600))
+        );
+        assert_eq!(
+            other.limit(|config| generation_limit(config, "openrouter")),
+            None
+        );
+
+        config.enabled = false;
+        watchdog.configure(&config, dir.path());
+        assert_eq!(
+            watchdog.limit(|config| generation_limit(config, "openrouter")),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn free_guards_use_the_scoped_watchdog() {
+        let dir = tempfile::tempdir().unwrap();
+        let watchdog = Watchdog::default();
+        watchdog.configure(
+            &WatchdogConfig {
+                enabled: true,
+                tool_timeouts: HashMap::from([("shell".into(), 1)]),
+                ..WatchdogConfig::default()
+            },
+            dir.path(),
+        );
+        let slow = || tokio::time::sleep(Duration::from_millis(1500));
+
+        assert!(guard_tool("shell", slow()).await.is_ok());
+        assert!(watchdog.scope(guard_tool("shell", slow())).await.is_err());
+        assert_eq!(recent_incidents(dir.path(), 5).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn guard_cancels_slow_futures_and_logs_the_incident() {
+        let dir = tempfile::tempdir().unwrap();
+        let slow = tokio::time::sleep(Duration::from_secs(5));
+        let error = guard(
+            None,
+            IncidentKind::Tool,
+            "shell".into(),
+            Some(Duration::from_millis(10)),
+            slow,
+        )
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains("tool 'shell'"));
+
+        let fast = guard(None, IncidentKind::Tool, "shell".into(), None, async { 7 }).await;
+        assert_eq!(fast, Ok(7));
+
+        // Progress inside the limit keeps a call alive past it.
+        let streaming = async {
+            for _ in 0..6 {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                touch();
+            }
+            "done"
+        };
+        let streamed = guard(
+            None,
+            IncidentKind::Generation,
+            "openrouter/model".into(),
+            Some(Duration::from_millis(60)),
+            streaming,
+        )
+        .await;
+        assert_eq!(streamed, Ok("done"));
+
+        append_log(dir.path(), &error).unwrap();
+        let incidents = recent_incidents(dir.path(), 5);
+        assert_eq!(incidents.len(), 1);
+        assert_eq!(incidents[0].kind, IncidentKind::Tool);
+        assert_eq!(incidents[0].subject, "shell");
+    }
+}
@@ -3,7 +3,7 @@
 //! Provides a pre-execution hook that prompts the user before tool calls,
 //! with session-scoped "Always" allowlists and audit logging.
 
-use crate::config::AutonomyConfig;
+use crate::config::{AutonomyConfig, UiNotificationsConfig};
 use crate::security::AutonomyLevel;
 use chrono::Utc;
 use parking_lot::Mutex;
@@ -60,6 +60,8 @@ pub struct ApprovalManager {
     session_allowlist: Mutex<HashSet<String>>,
     /// Audit trail of approval decisions.
     audit_log: Mutex<Vec<ApprovalLogEntry>>,
+    /// Bell/desktop settings for the approval-requested signal.
+    notifications: UiNotificationsConfig,
 }
 
 impl ApprovalManager {
@@ -71,9 +73,16 @@ impl ApprovalManager {
             autonomy_level: config.level,
             session_allowlist: Mutex::new(HashSet::new()),
             audit_log: Mutex::new(Vec::new()),
+            notifications: UiNotificationsConfig::default(),
         }
     }
 
+    /// Signal prompts as configured by `[ui.notifications]`.
+    pub fn with_notifications(mut self, notifications: UiNotificationsConfig) -> Self {
+        self.notifications = notifications;
+        self
+    }
+
     /// Check whether a tool call requires interactive approval.
     ///
     /// Returns `true` if the call needs a prompt, `false` if it can proceed.
@@ -150,15 +159,23 @@ impl ApprovalManager {
     /// For non-CLI channels, returns `Yes` automatically (interactive
     /// approval is only supported on CLI for now).
     pub fn prompt_cli(&self, request: &ApprovalRequest) -> ApprovalResponse {
-        prompt_cli_interactive(request)
+        prompt_cli_interactive(request, &self.notifications)
     }
 }
 
 // ── CLI prompt ───────────────────────────────────────────────────
 
 /// Display the approval prompt and read user input from stdin.
-fn prompt_cli_interactive(request: &ApprovalRequest) -> ApprovalResponse {
+fn prompt_cli_interactive(
+    request: &ApprovalRequest,
+    notifications: &UiNotificationsConfig,
+) -> ApprovalResponse {
     let summary = summarize_args(&request.arguments);
+    crate::notifications::notify(
+        notifications,
+        crate::notifications::NotifyEvent::ApprovalRequested,
+        &format!("{} is waiting for approval", request.tool_name),
+    );
     eprintln!();
     eprintln!("🔧 Agent wants to execute: {}", request.tool_name);
     eprintln!("   {summary}");
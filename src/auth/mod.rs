@@ -122,6 +122,40 @@ impl AuthService {
         Ok(profile_id)
     }
 
+    /// Replace the token of an existing token profile, keeping its active
+    /// state and creation time. OAuth profiles are renewed by logging in again.
+    pub async fn rotate_token(
+        &self,
+        provider: &str,
+        requested_profile: &str,
+        token: &str,
+    ) -> Result<AuthProfile> {
+        let provider = normalize_provider(provider)?;
+        let profile_id = resolve_requested_profile_id(&provider, requested_profile);
+        let data = self.store.load().await?;
+        let mut profile = data
+            .profiles
+            .get(&profile_id)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Auth profile not found: {profile_id}"))?;
+        if profile.kind == AuthProfileKind::OAuth {
+            anyhow::bail!(
+                "{profile_id} is an OAuth profile; renew it with `zeroclaw auth login --provider {provider} --profile {}`",
+                profile.profile_name
+            );
+        }
+
+        let current_kind = profile.metadata.get("auth_kind").map(String::as_str);
+        let kind = anthropic_token::detect_auth_kind(token, current_kind);
+        profile.token = Some(token.to_string());
+        profile.metadata.insert(
+            "auth_kind".to_string(),
+            kind.as_metadata_value().to_string(),
+        );
+        self.store.upsert_profile(profile.clone(), false).await?;
+        Ok(profile)
+    }
+
     pub async fn remove_profile(&self, provider: &str, requested_profile: &str) -> Result<bool> {
         let provider = normalize_provider(provider)?;
         let profile_id = resolve_requested_profile_id(&provider, requested_profile);
@@ -520,6 +554,35 @@ mod tests {
         assert_eq!(normalize_provider("openai").unwrap(), "openai");
     }
 
+    #[tokio::test]
+    async fn rotate_token_replaces_token_and_keeps_active_profile() {
+        let dir = tempfile::tempdir().unwrap();
+        let service = AuthService::new(dir.path(), false);
+        let metadata = HashMap::new();
+        service
+            .store_provider_token("anthropic", "work", "old-key", metadata.clone(), true)
+            .await
+            .unwrap();
+        service
+            .store_provider_token("anthropic", "personal", "other", metadata, false)
+            .await
+            .unwrap();
+
+        let rotated = service
+            .rotate_token("anthropic", "work", "new-key")
+            .await
+            .unwrap();
+        assert_eq!(rotated.token.as_deref(), Some("new-key"));
+
+        let data = service.load_profiles().await.unwrap();
+        let work = profile_id("anthropic", "work");
+        assert_eq!(data.active_profiles.get("anthropic"), Some(&work));
+        assert_eq!(data.profiles[&work].token.as_deref(), Some("new-key"));
+
+        let missing = service.rotate_token("anthropic", "nope", "x").await;
+        assert!(missing.is_err());
+    }
+
     #[test]
     fn select_profile_prefers_override_then_active_then_default() {
         let mut data = AuthProfilesData::default();
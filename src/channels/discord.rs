@@ -10,12 +10,15 @@ use tokio_tungstenite::tungstenite::Message;
 use uuid::Uuid;
 
 /// Discord channel — connects via Gateway WebSocket for real-time messages
+#[allow(clippy::struct_excessive_bools)]
 pub struct DiscordChannel {
     bot_token: String,
     guild_id: Option<String>,
     allowed_users: Vec<String>,
     listen_to_bots: bool,
     mention_only: bool,
+    quote_replies: bool,
+    lightweight_acks: bool,
     typing_handles: Mutex<HashMap<String, tokio::task::JoinHandle<()>>>,
 }
 
@@ -33,10 +36,33 @@ impl DiscordChannel {
             allowed_users,
             listen_to_bots,
             mention_only,
+            quote_replies: false,
+            lightweight_acks: false,
             typing_handles: Mutex::new(HashMap::new()),
         }
     }
 
+    /// Send replies as Discord replies to the triggering message.
+    pub fn with_quote_replies(mut self, quote_replies: bool) -> Self {
+        self.quote_replies = quote_replies;
+        self
+    }
+
+    /// Send bare acknowledgment replies as reactions.
+    pub fn with_lightweight_acks(mut self, lightweight_acks: bool) -> Self {
+        self.lightweight_acks = lightweight_acks;
+        self
+    }
+
+    /// Discord message ID `message` answers, when replies are quoted.
+    /// Inbound IDs have the form `discord_<message_id>`.
+    fn referenced_message_id<'a>(&self, message: &'a SendMessage) -> Option<&'a str> {
+        if !self.quote_replies {
+            return None;
+        }
+        message.in_response_to.as_deref()?.strip_prefix("discord_")
+    }
+
     fn http_client(&self) -> reqwest::Client {
         crate::config::build_runtime_proxy_client("channel.discord")
     }
@@ -230,19 +256,31 @@ fn with_inline_attachment_urls(
     lines.join("\n")
 }
 
+/// Message body, replying to `quoted_id` when set. A deleted original does
+/// not fail the send.
+fn message_payload(content: &str, quoted_id: Option<&str>) -> serde_json::Value {
+    let mut payload = json!({ "content": content });
+    if let Some(message_id) = quoted_id {
+        payload["message_reference"] = json!({
+            "message_id": message_id,
+            "fail_if_not_exists": false,
+        });
+    }
+    payload
+}
+
 async fn send_discord_message_json(
     client: &reqwest::Client,
     bot_token: &str,
     recipient: &str,
-    content: &str,
+    body: &serde_json::Value,
 ) -> anyhow::Result<()> {
     let url = format!("https://discord.com/api/v10/channels/{recipient}/messages");
-    let body = json!({ "content": content });
 
     let resp = client
         .post(&url)
         .header("Authorization", format!("Bot {bot_token}"))
-        .json(&body)
+        .json(body)
         .send()
         .await?;
 
@@ -262,12 +300,12 @@ async fn send_discord_message_with_files(
     client: &reqwest::Client,
     bot_token: &str,
     recipient: &str,
-    content: &str,
+    payload: &serde_json::Value,
     files: &[PathBuf],
 ) -> anyhow::Result<()> {
     let url = format!("https://discord.com/api/v10/channels/{recipient}/messages");
 
-    let mut form = Form::new().text("payload_json", json!({ "content": content }).to_string());
+    let mut form = Form::new().text("payload_json", payload.to_string());
 
     for (idx, path) in files.iter().enumerate() {
         let bytes = tokio::fs::read(path).await.map_err(|error| {
@@ -513,17 +551,23 @@ impl Channel for DiscordChannel {
         let client = self.http_client();
 
         for (i, chunk) in chunks.iter().enumerate() {
+            let reply_to = if i == 0 {
+                self.referenced_message_id(message)
+            } else {
+                None
+            };
+            let payload = message_payload(chunk, reply_to);
             if i == 0 && !local_files.is_empty() {
                 send_discord_message_with_files(
                     &client,
                     &self.bot_token,
                     &message.recipient,
-                    chunk,
+                    &payload,
                     &local_files,
                 )
                 .await?;
             } else {
-                send_discord_message_json(&client, &self.bot_token, &message.recipient, chunk)
+                send_discord_message_json(&client, &self.bot_token, &message.recipient, &payload)
                     .await?;
             }
 
@@ -854,6 +898,20 @@ impl Channel for DiscordChannel {
         Ok(())
     }
 
+    fn sends_lightweight_acks(&self) -> bool {
+        self.lightweight_acks
+    }
+
+    async fn send_lightweight_ack(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+        emoji: &str,
+    ) -> anyhow::Result<bool> {
+        self.add_reaction(channel_id, message_id, emoji).await?;
+        Ok(true)
+    }
+
     async fn remove_reaction(
         &self,
         channel_id: &str,
@@ -892,6 +950,22 @@ mod tests {
         assert_eq!(ch.name(), "discord");
     }
 
+    #[test]
+    fn quoted_replies_reference_the_triggering_message() {
+        let message = SendMessage::new("hi", "chan").replying_to("discord_987");
+        let ch = DiscordChannel::new("fake".into(), None, vec![], false, false);
+        assert_eq!(ch.referenced_message_id(&message), None);
+
+        let ch = ch.with_quote_replies(true);
+        let reply_to = ch.referenced_message_id(&message);
+        assert_eq!(reply_to, Some("987"));
+        let payload = message_payload("hi", reply_to);
+        assert_eq!(payload["message_reference"]["message_id"], "987");
+        assert!(message_payload("hi", None)
+            .get("message_reference")
+            .is_none());
+    }
+
     #[test]
     fn base64_decode_bot_id() {
         // "MTIzNDU2" decodes to "123456"
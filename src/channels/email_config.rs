@@ -0,0 +1,84 @@
+//! Email channel configuration.
+//!
+//! Kept apart from the IMAP/SMTP implementation so `[channels_config.email]`
+//! still parses in builds compiled without the `channel-email` feature.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Email channel configuration
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EmailConfig {
+    /// IMAP server hostname
+    pub imap_host: String,
+    /// IMAP server port (default: 993 for TLS)
+    #[serde(default = "default_imap_port")]
+    pub imap_port: u16,
+    /// IMAP folder to poll (default: INBOX)
+    #[serde(default = "default_imap_folder")]
+    pub imap_folder: String,
+    /// SMTP server hostname
+    pub smtp_host: String,
+    /// SMTP server port (default: 465 for TLS)
+    #[serde(default = "default_smtp_port")]
+    pub smtp_port: u16,
+    /// Use TLS for SMTP (default: true)
+    #[serde(default = "default_true")]
+    pub smtp_tls: bool,
+    /// Email username for authentication
+    pub username: String,
+    /// Email password for authentication
+    pub password: String,
+    /// From address for outgoing emails
+    pub from_address: String,
+    /// IDLE timeout in seconds before re-establishing connection (default: 1740 = 29 minutes)
+    /// RFC 2177 recommends clients restart IDLE every 29 minutes
+    #[serde(default = "default_idle_timeout", alias = "poll_interval_secs")]
+    pub idle_timeout_secs: u64,
+    /// Allowed sender addresses/domains (empty = deny all, `["*"]` = allow all)
+    #[serde(default)]
+    pub allowed_senders: Vec<String>,
+}
+
+impl crate::config::traits::ChannelConfig for EmailConfig {
+    fn name() -> &'static str {
+        "Email"
+    }
+    fn desc() -> &'static str {
+        "Email over IMAP/SMTP"
+    }
+}
+
+pub(super) fn default_imap_port() -> u16 {
+    993
+}
+pub(super) fn default_smtp_port() -> u16 {
+    465
+}
+pub(super) fn default_imap_folder() -> String {
+    "INBOX".into()
+}
+pub(super) fn default_idle_timeout() -> u64 {
+    1740 // 29 minutes per RFC 2177
+}
+pub(super) fn default_true() -> bool {
+    true
+}
+
+impl Default for EmailConfig {
+    fn default() -> Self {
+        Self {
+            imap_host: String::new(),
+            imap_port: default_imap_port(),
+            imap_folder: default_imap_folder(),
+            smtp_host: String::new(),
+            smtp_port: default_smtp_port(),
+            smtp_tls: true,
+            username: String::new(),
+            password: String::new(),
+            from_address: String::new(),
+            idle_timeout_secs: default_idle_timeout(),
+            allowed_senders: Vec::new(),
+        }
+    }
+}
@@ -13,28 +13,48 @@
 //!
 //! To add a new channel, implement [`Channel`] in a new submodule and wire it into
 //! [`start_channels`]. See `AGENTS.md` §7.2 for the full change playbook.
+//!
+//! Out-of-tree connectors can instead implement [`registry::ChannelPlugin`]
+//! and call [`registry::register_channel_plugin`]; they are configured under
+//! `[channels_config.plugins.<key>]` and appear in the onboarding wizard
+//! without changes to `ChannelsConfig`.
 
 pub mod clawdtalk;
 pub mod cli;
+#[cfg(feature = "channel-dingtalk")]
 pub mod dingtalk;
+#[cfg(feature = "channel-discord")]
 pub mod discord;
+#[cfg(feature = "channel-email")]
 pub mod email_channel;
+pub mod email_config;
+#[cfg(feature = "channel-imessage")]
 pub mod imessage;
+#[cfg(feature = "channel-irc")]
 pub mod irc;
 #[cfg(feature = "channel-lark")]
 pub mod lark;
 pub mod linq;
 #[cfg(feature = "channel-matrix")]
 pub mod matrix;
+#[cfg(feature = "channel-mattermost")]
 pub mod mattermost;
 pub mod nextcloud_talk;
+#[cfg(feature = "channel-nostr")]
 pub mod nostr;
+mod profiles;
+#[cfg(feature = "channel-qq")]
 pub mod qq;
+pub mod registry;
+#[cfg(feature = "channel-signal")]
 pub mod signal;
+#[cfg(feature = "channel-slack")]
 pub mod slack;
+#[cfg(feature = "channel-telegram")]
 pub mod telegram;
 pub mod traits;
 pub mod transcription;
+mod translation;
 pub mod wati;
 pub mod whatsapp;
 #[cfg(feature = "whatsapp-web")]
@@ -44,22 +64,33 @@ pub mod whatsapp_web;
 
 pub use clawdtalk::{ClawdTalkChannel, ClawdTalkConfig};
 pub use cli::CliChannel;
+#[cfg(feature = "channel-dingtalk")]
 pub use dingtalk::DingTalkChannel;
+#[cfg(feature = "channel-discord")]
 pub use discord::DiscordChannel;
+#[cfg(feature = "channel-email")]
 pub use email_channel::EmailChannel;
+#[cfg(feature = "channel-imessage")]
 pub use imessage::IMessageChannel;
+#[cfg(feature = "channel-irc")]
 pub use irc::IrcChannel;
 #[cfg(feature = "channel-lark")]
 pub use lark::LarkChannel;
 pub use linq::LinqChannel;
 #[cfg(feature = "channel-matrix")]
 pub use matrix::MatrixChannel;
+#[cfg(feature = "channel-mattermost")]
 pub use mattermost::MattermostChannel;
 pub use nextcloud_talk::NextcloudTalkChannel;
+#[cfg(feature = "channel-nostr")]
 pub use nostr::NostrChannel;
+#[cfg(feature = "channel-qq")]
 pub use qq::QQChannel;
+#[cfg(feature = "channel-signal")]
 pub use signal::SignalChannel;
+#[cfg(feature = "channel-slack")]
 pub use slack::SlackChannel;
+#[cfg(feature = "channel-telegram")]
 pub use telegram::TelegramChannel;
 pub use traits::{Channel, SendMessage};
 pub use wati::WatiChannel;
@@ -71,7 +102,7 @@ use crate::agent::loop_::{build_tool_instructions, run_tool_call_loop, scrub_cre
 use crate::config::Config;
 use crate::identity;
 use crate::memory::{self, Memory};
-use crate::observability::{self, runtime_trace, Observer};
+use crate::observability::{self, runtime_trace, Observer, ObserverEvent};
 use crate::providers::{self, ChatMessage, Provider};
 use crate::runtime;
 use crate::security::SecurityPolicy;
@@ -115,7 +146,6 @@ const CHANNEL_TYPING_REFRESH_INTERVAL_SECS: u64 = 4;
 const CHANNEL_HEALTH_HEARTBEAT_SECS: u64 = 30;
 const MODEL_CACHE_FILE: &str = "models_cache.json";
 const MODEL_CACHE_PREVIEW_LIMIT: usize = 10;
-const MEMORY_CONTEXT_MAX_ENTRIES: usize = 4;
 const MEMORY_CONTEXT_ENTRY_MAX_CHARS: usize = 800;
 const MEMORY_CONTEXT_MAX_CHARS: usize = 4_000;
 const CHANNEL_HISTORY_COMPACT_KEEP_MESSAGES: usize = 12;
@@ -152,6 +182,8 @@ enum ChannelRuntimeCommand {
     ShowModel,
     SetModel(String),
     NewSession,
+    Workspace(Option<String>),
+    Profile(String),
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -211,6 +243,7 @@ struct ChannelRuntimeContext {
     auto_save_memory: bool,
     max_tool_iterations: usize,
     min_relevance_score: f64,
+    memory_token_budget: usize,
     conversation_histories: ConversationHistoryMap,
     provider_cache: ProviderCacheMap,
     route_overrides: RouteSelectionMap,
@@ -224,6 +257,19 @@ struct ChannelRuntimeContext {
     multimodal: crate::config::MultimodalConfig,
     hooks: Option<Arc<crate::hooks::HookRunner>>,
     non_cli_excluded_tools: Arc<Vec<String>>,
+    /// `[channels_config.translation]`, keyed by channel name.
+    translation: Arc<translation::TranslationMap>,
+    escalation: Arc<crate::agent::escalation::Escalator>,
+    debug_command: Arc<crate::observability::debug_mode::DebugCommand>,
+    /// Model downgrade policy; `None` when no budget is configured.
+    downgrade: Option<Arc<crate::cost::downgrade::DowngradePolicy>>,
+    drain: crate::daemon::shutdown::Drain,
+    /// Config for the estop chat trigger; `None` when no phrase is armed.
+    estop_trigger: Option<Arc<Config>>,
+    /// `[agent.watchdog]` limits, updated on config reload.
+    watchdog: crate::agent::watchdog::Watchdog,
+    /// Dashboard toasts, shared with the gateway under the daemon.
+    toasts: crate::notifications::Toasts,
 }
 
 #[derive(Clone)]
@@ -415,6 +461,80 @@ fn channel_delivery_instructions(channel_name: &str) -> Option<&'static str> {
     }
 }
 
+/// Told to the model when `lightweight_acks` is on.
+const LIGHTWEIGHT_ACK_INSTRUCTIONS: &str = "When a message only needs an acknowledgment \
+     (a confirmation, thanks, or a finished task with nothing to report), reply with just \
+     \"Done\", \"Got it\", or a single emoji; it is delivered as a reaction.";
+
+const ACK_DONE_PHRASES: &[&str] = &["done", "all done", "completed", "finished", "all set"];
+const ACK_PHRASES: &[&str] = &[
+    "ok",
+    "okay",
+    "got it",
+    "noted",
+    "sure",
+    "will do",
+    "on it",
+    "thanks",
+    "thank you",
+    "you're welcome",
+    "no problem",
+];
+
+/// Whether `c` is an emoji pictograph: the emoji blocks of the
+/// Extended_Pictographic property, leaving out CJK, full-width punctuation,
+/// box drawing and other symbols that happen to sit above ASCII.
+fn is_emoji_pictograph(c: char) -> bool {
+    matches!(
+        u32::from(c),
+        0x00A9 | 0x00AE | 0x203C | 0x2049 | 0x2122 | 0x2139
+            | 0x2194..=0x2199 | 0x21A9..=0x21AA
+            | 0x231A..=0x231B | 0x2328 | 0x23CF | 0x23E9..=0x23F3 | 0x23F8..=0x23FA
+            | 0x24C2 | 0x25AA..=0x25AB | 0x25B6 | 0x25C0 | 0x25FB..=0x25FE
+            | 0x2600..=0x27BF
+            | 0x2934..=0x2935 | 0x2B05..=0x2B07 | 0x2B1B..=0x2B1C | 0x2B50 | 0x2B55
+            | 0x3030 | 0x303D | 0x3297 | 0x3299
+            | 0x1F000..=0x1F0FF | 0x1F10D..=0x1F10F | 0x1F12F | 0x1F16C..=0x1F171
+            | 0x1F17E..=0x1F17F | 0x1F18E | 0x1F191..=0x1F19A | 0x1F1E6..=0x1F1FF
+            | 0x1F201..=0x1F202 | 0x1F21A | 0x1F22F | 0x1F232..=0x1F23A
+            | 0x1F250..=0x1F251 | 0x1F300..=0x1F6FF | 0x1F7E0..=0x1F7FF
+            | 0x1F90C..=0x1F9FF | 0x1FA70..=0x1FAFF
+    )
+}
+
+/// Joiners and modifiers that only appear inside an emoji sequence: ZWJ,
+/// the emoji presentation selector, the keycap mark and tag characters.
+fn is_emoji_joiner(c: char) -> bool {
+    matches!(u32::from(c), 0x200D | 0xFE0F | 0x20E3 | 0xE0020..=0xE007F)
+}
+
+/// Reaction for a reply that is only an acknowledgment: ✅ for "done"
+/// phrases, 👍 for other acknowledgments, or the reply itself when it is a
+/// lone emoji.
+fn lightweight_ack_emoji(reply: &str) -> Option<String> {
+    let reply = reply.trim();
+    if reply.is_empty() {
+        return None;
+    }
+    let is_emoji = |c: char| is_emoji_pictograph(c) || is_emoji_joiner(c);
+    if reply.chars().count() <= 4
+        && reply.chars().all(is_emoji)
+        && reply.chars().any(is_emoji_pictograph)
+    {
+        return Some(reply.to_string());
+    }
+    let phrase = reply
+        .trim_end_matches(|c: char| is_emoji(c) || c.is_whitespace() || matches!(c, '.' | '!'))
+        .to_lowercase();
+    if ACK_DONE_PHRASES.contains(&phrase.as_str()) {
+        Some("\u{2705}".to_string())
+    } else if ACK_PHRASES.contains(&phrase.as_str()) {
+        Some("\u{1F44D}".to_string())
+    } else {
+        None
+    }
+}
+
 fn build_channel_system_prompt(
     base_prompt: &str,
     channel_name: &str,
@@ -518,6 +638,12 @@ fn parse_runtime_command(channel_name: &str, content: &str) -> Option<ChannelRun
             }
         }
         "/new" => Some(ChannelRuntimeCommand::NewSession),
+        "/workspace" => Some(ChannelRuntimeCommand::Workspace(
+            parts.next().map(|name| name.trim().to_string()),
+        )),
+        "/profile" => Some(ChannelRuntimeCommand::Profile(
+            parts.collect::<Vec<_>>().join(" "),
+        )),
         _ => None,
     }
 }
@@ -621,7 +747,7 @@ fn decrypt_optional_secret_for_runtime_reload(
     Ok(())
 }
 
-async fn load_runtime_defaults_from_config_file(path: &Path) -> Result<ChannelRuntimeDefaults> {
+async fn load_runtime_config_file(path: &Path) -> Result<Config> {
     let contents = tokio::fs::read_to_string(path)
         .await
         .with_context(|| format!("Failed to read {}", path.display()))?;
@@ -635,7 +761,7 @@ async fn load_runtime_defaults_from_config_file(path: &Path) -> Result<ChannelRu
     }
 
     parsed.apply_env_overrides();
-    Ok(runtime_defaults_from_config(&parsed))
+    Ok(parsed)
 }
 
 async fn maybe_apply_runtime_config_update(ctx: &ChannelRuntimeContext) -> Result<()> {
@@ -658,7 +784,8 @@ async fn maybe_apply_runtime_config_update(ctx: &ChannelRuntimeContext) -> Resul
         }
     }
 
-    let next_defaults = load_runtime_defaults_from_config_file(&config_path).await?;
+    let next_config = load_runtime_config_file(&config_path).await?;
+    let next_defaults = runtime_defaults_from_config(&next_config);
     let next_default_provider = providers::create_resilient_provider_with_options(
         &next_defaults.default_provider,
         next_defaults.api_key.as_deref(),
@@ -697,6 +824,9 @@ async fn maybe_apply_runtime_config_update(ctx: &ChannelRuntimeContext) -> Resul
         );
     }
 
+    ctx.watchdog
+        .configure(&next_config.agent.watchdog, &ctx.workspace_dir);
+
     tracing::info!(
         path = %config_path.display(),
         provider = %next_defaults.default_provider,
@@ -820,7 +950,7 @@ fn rollback_orphan_user_turn(
 }
 
 fn should_skip_memory_context_entry(key: &str, content: &str) -> bool {
-    if memory::is_assistant_autosave_key(key) {
+    if memory::is_assistant_autosave_key(key) || profiles::is_profile_key(key) {
         return true;
     }
 
@@ -847,7 +977,8 @@ fn is_context_window_overflow_error(err: &anyhow::Error) -> bool {
     .any(|hint| lower.contains(hint))
 }
 
-fn load_cached_model_preview(workspace_dir: &Path, provider_name: &str) -> Vec<String> {
+/// Models cached for `provider_name` by `zeroclaw models refresh`.
+pub(crate) fn load_cached_models(workspace_dir: &Path, provider_name: &str) -> Vec<String> {
     let cache_path = workspace_dir.join("state").join(MODEL_CACHE_FILE);
     let Ok(raw) = std::fs::read_to_string(cache_path) else {
         return Vec::new();
@@ -860,16 +991,17 @@ fn load_cached_model_preview(workspace_dir: &Path, provider_name: &str) -> Vec<S
         .entries
         .into_iter()
         .find(|entry| entry.provider == provider_name)
-        .map(|entry| {
-            entry
-                .models
-                .into_iter()
-                .take(MODEL_CACHE_PREVIEW_LIMIT)
-                .collect::<Vec<_>>()
-        })
+        .map(|entry| entry.models)
         .unwrap_or_default()
 }
 
+fn load_cached_model_preview(workspace_dir: &Path, provider_name: &str) -> Vec<String> {
+    load_cached_models(workspace_dir, provider_name)
+        .into_iter()
+        .take(MODEL_CACHE_PREVIEW_LIMIT)
+        .collect()
+}
+
 async fn get_or_create_provider(
     ctx: &ChannelRuntimeContext,
     provider_name: &str,
@@ -1059,10 +1191,20 @@ async fn handle_runtime_command_if_needed(
             clear_sender_history(ctx, &sender_key);
             "Conversation history cleared. Starting fresh.".to_string()
         }
+        ChannelRuntimeCommand::Workspace(target) => {
+            handle_workspace_command(ctx, &msg.sender, target.as_deref()).await
+        }
+        ChannelRuntimeCommand::Profile(args) => {
+            profiles::handle_command(ctx.memory.as_ref(), &msg.channel, &msg.sender, &args).await
+        }
     };
 
     if let Err(err) = channel
-        .send(&SendMessage::new(response, &msg.reply_target).in_thread(msg.thread_ts.clone()))
+        .send(
+            &SendMessage::new(response, &msg.reply_target)
+                .in_thread(msg.thread_ts.clone())
+                .replying_to(msg.id.clone()),
+        )
         .await
     {
         tracing::warn!(
@@ -1074,56 +1216,222 @@ async fn handle_runtime_command_if_needed(
     true
 }
 
-async fn build_memory_context(
-    mem: &dyn Memory,
-    user_msg: &str,
-    min_relevance_score: f64,
+const DEFAULT_WORKSPACE_NAME: &str = "default";
+
+fn workspace_name(config_dir: &Path) -> String {
+    if crate::config::schema::is_profile_config_dir(config_dir) {
+        if let Some(name) = config_dir.file_name() {
+            return name.to_string_lossy().into_owned();
+        }
+    }
+    DEFAULT_WORKSPACE_NAME.to_string()
+}
+
+async fn load_workspaces_config(path: &Path) -> Result<crate::config::WorkspacesConfig> {
+    let contents = tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let parsed: Config =
+        toml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))?;
+    Ok(parsed.workspaces)
+}
+
+/// `/workspace` lists the named workspaces; `/workspace <name>` makes one the
+/// active workspace. Each has its own config and memory, so the switch takes
+/// effect when the daemon next starts rather than mid-conversation.
+async fn handle_workspace_command(
+    ctx: &ChannelRuntimeContext,
+    sender: &str,
+    target: Option<&str>,
 ) -> String {
-    let mut context = String::new();
+    let Some(config_dir) = ctx.provider_runtime_options.zeroclaw_dir.clone() else {
+        return "Workspaces are unavailable: the config directory is unknown.".to_string();
+    };
+    let base_dir = crate::config::schema::profile_base_dir(&config_dir).to_path_buf();
+    let current = workspace_name(&config_dir);
+    let mut available = vec![DEFAULT_WORKSPACE_NAME.to_string()];
+    available.extend(crate::config::schema::list_profiles(&base_dir));
 
-    if let Ok(entries) = mem.recall(user_msg, 5, None).await {
-        let mut included = 0usize;
-        let mut used_chars = 0usize;
+    let Some(target) = target else {
+        let list = available
+            .iter()
+            .map(|name| {
+                if *name == current {
+                    format!("- `{name}` (active)")
+                } else {
+                    format!("- `{name}`")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        return format!(
+            "Workspaces:\n{list}\nUse `/workspace <name>` to pick the workspace loaded on the next restart."
+        );
+    };
 
-        for entry in entries.iter().filter(|e| match e.score {
-            Some(score) => score >= min_relevance_score,
-            None => true, // keep entries without a score (e.g. non-vector backends)
-        }) {
-            if included >= MEMORY_CONTEXT_MAX_ENTRIES {
-                break;
+    let allowed = match runtime_config_path(ctx) {
+        Some(path) => match load_workspaces_config(&path).await {
+            Ok(workspaces) => workspaces.can_switch(sender),
+            Err(err) => {
+                tracing::warn!("Failed to read [workspaces] for /workspace: {err:#}");
+                false
             }
+        },
+        None => false,
+    };
+    if !allowed {
+        return "You are not allowed to select workspaces. Ask an operator to add you to `[workspaces].switch_users`.".to_string();
+    }
+    if !available.iter().any(|name| name == target) {
+        return format!(
+            "Unknown workspace `{target}`. Available: {}.",
+            available
+                .iter()
+                .map(|name| format!("`{name}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    if target == current {
+        return format!("Workspace `{target}` is already active.");
+    }
 
-            if should_skip_memory_context_entry(&entry.key, &entry.content) {
-                continue;
-            }
+    let target_dir = if target == DEFAULT_WORKSPACE_NAME {
+        base_dir
+    } else {
+        crate::config::schema::profile_config_dir(&base_dir, target)
+    };
+    if let Err(err) = crate::config::schema::persist_active_workspace_config_dir(&target_dir).await
+    {
+        return format!("Failed to select workspace: {err}");
+    }
 
-            let content = if entry.content.chars().count() > MEMORY_CONTEXT_ENTRY_MAX_CHARS {
-                truncate_with_ellipsis(&entry.content, MEMORY_CONTEXT_ENTRY_MAX_CHARS)
-            } else {
-                entry.content.clone()
-            };
+    let mut response = format!(
+        "Workspace `{target}` will be loaded when the daemon restarts. Until then this daemon keeps using `{current}` (its config and memory)."
+    );
+    if crate::config::schema::active_profile().is_some() {
+        response.push_str(
+            "\nNote: this daemon was started with `--workspace`, which overrides the active workspace.",
+        );
+    }
+    response
+}
 
-            let line = format!("- {}: {}\n", entry.key, content);
-            let line_chars = line.chars().count();
-            if used_chars + line_chars > MEMORY_CONTEXT_MAX_CHARS {
-                break;
+/// Where and how to translate a reply back into the sender's language.
+struct ReplyTranslation {
+    provider: Arc<dyn Provider>,
+    model: String,
+    language: String,
+}
+
+impl ReplyTranslation {
+    async fn translate(&self, response: String) -> String {
+        match translation::translate_outbound(
+            self.provider.as_ref(),
+            &self.model,
+            &self.language,
+            &response,
+        )
+        .await
+        {
+            Ok(translated) => translated,
+            Err(err) => {
+                tracing::warn!("Reply translation to {} failed: {err:#}", self.language);
+                response
             }
+        }
+    }
+}
 
-            if included == 0 {
-                context.push_str("[Memory context]\n");
+/// Translate `msg.content` into the channel's working language in place.
+/// Returns how to translate the reply back, or `None` when the message was
+/// already in that language or translation failed.
+async fn translate_inbound_message(
+    ctx: &ChannelRuntimeContext,
+    settings: &crate::config::ChannelTranslationConfig,
+    route: &ChannelRouteSelection,
+    active_provider: &Arc<dyn Provider>,
+    msg: &mut traits::ChannelMessage,
+) -> Option<ReplyTranslation> {
+    let provider = match settings.provider.as_deref() {
+        Some(name) if name != route.provider => match get_or_create_provider(ctx, name).await {
+            Ok(provider) => provider,
+            Err(err) => {
+                tracing::warn!("Translation provider `{name}` unavailable: {err}");
+                Arc::clone(active_provider)
             }
+        },
+        _ => Arc::clone(active_provider),
+    };
+    let model = settings
+        .model
+        .clone()
+        .unwrap_or_else(|| route.model.clone());
 
-            context.push_str(&line);
-            used_chars += line_chars;
-            included += 1;
+    match translation::translate_inbound(
+        provider.as_ref(),
+        &model,
+        &settings.translate_to,
+        &msg.content,
+    )
+    .await
+    {
+        Ok((translated, Some(language))) => {
+            tracing::debug!(
+                channel = %msg.channel,
+                from = %language,
+                to = %settings.translate_to,
+                "Translated inbound message"
+            );
+            msg.content = translated;
+            Some(ReplyTranslation {
+                provider,
+                model,
+                language,
+            })
         }
-
-        if included > 0 {
-            context.push('\n');
+        Ok((_, None)) => None,
+        Err(err) => {
+            tracing::warn!(
+                "Inbound translation on {} failed; using original text: {err:#}",
+                msg.channel
+            );
+            None
         }
     }
+}
+
+async fn build_memory_context(
+    mem: &dyn Memory,
+    user_msg: &str,
+    min_relevance_score: f64,
+    token_budget: usize,
+) -> String {
+    let Ok(entries) = mem
+        .recall(user_msg, memory::packing::CONTEXT_CANDIDATES, None)
+        .await
+    else {
+        return String::new();
+    };
+
+    let candidates = entries
+        .iter()
+        .filter(|e| match e.score {
+            Some(score) => score >= min_relevance_score,
+            None => true, // keep entries without a score (e.g. non-vector backends)
+        })
+        .filter(|e| !should_skip_memory_context_entry(&e.key, &e.content))
+        .map(|e| {
+            let content = if e.content.chars().count() > MEMORY_CONTEXT_ENTRY_MAX_CHARS {
+                truncate_with_ellipsis(&e.content, MEMORY_CONTEXT_ENTRY_MAX_CHARS)
+            } else {
+                e.content.clone()
+            };
+            memory::packing::ContextCandidate::new(&e.key, &content, e.score)
+        })
+        .collect();
 
-    context
+    memory::packing::pack_context(candidates, token_budget)
 }
 
 /// Extract a compact summary of tool interactions from history messages added
@@ -1389,6 +1697,7 @@ fn spawn_supervised_listener(
     tx: tokio::sync::mpsc::Sender<traits::ChannelMessage>,
     initial_backoff_secs: u64,
     max_backoff_secs: u64,
+    toasts: crate::notifications::Toasts,
 ) -> tokio::task::JoinHandle<()> {
     spawn_supervised_listener_with_health_interval(
         ch,
@@ -1396,6 +1705,7 @@ fn spawn_supervised_listener(
         initial_backoff_secs,
         max_backoff_secs,
         Duration::from_secs(CHANNEL_HEALTH_HEARTBEAT_SECS),
+        toasts,
     )
 }
 
@@ -1405,6 +1715,7 @@ fn spawn_supervised_listener_with_health_interval(
     initial_backoff_secs: u64,
     max_backoff_secs: u64,
     health_interval: Duration,
+    toasts: crate::notifications::Toasts,
 ) -> tokio::task::JoinHandle<()> {
     let health_interval = if health_interval.is_zero() {
         Duration::from_secs(1)
@@ -1416,9 +1727,16 @@ fn spawn_supervised_listener_with_health_interval(
         let component = format!("channel:{}", ch.name());
         let mut backoff = initial_backoff_secs.max(1);
         let max_backoff = max_backoff_secs.max(backoff);
+        let mut restarting = false;
 
         loop {
             crate::health::mark_component_ok(&component);
+            if restarting {
+                toasts.publish(
+                    crate::notifications::ToastLevel::Success,
+                    format!("Channel {} reconnected", ch.name()),
+                );
+            }
             let mut health = tokio::time::interval(health_interval);
             health.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
             let result = {
@@ -1452,6 +1770,14 @@ fn spawn_supervised_listener_with_health_interval(
                 }
             }
 
+            toasts.publish(
+                crate::notifications::ToastLevel::Warning,
+                format!(
+                    "Channel {} disconnected; reconnecting in {backoff}s",
+                    ch.name()
+                ),
+            );
+            restarting = true;
             crate::health::bump_component_restart(&component);
             tokio::time::sleep(Duration::from_secs(backoff)).await;
             // Double backoff AFTER sleeping so first error uses initial_backoff
@@ -1505,6 +1831,60 @@ fn spawn_scoped_typing_task(
     handle
 }
 
+/// Send the `[behavior.escalation]` owner notice, at most once per
+/// conversation per cooldown window.
+async fn notify_owner_of_escalation(
+    ctx: &ChannelRuntimeContext,
+    msg: &traits::ChannelMessage,
+    history_key: &str,
+    escalation: &crate::agent::escalation::Escalation,
+) {
+    let Some((channel_name, recipient)) = escalation.notify_target() else {
+        return;
+    };
+    if !ctx.escalation.notify_due(history_key) {
+        return;
+    }
+    let Some(channel) = ctx.channels_by_name.get(channel_name) else {
+        tracing::warn!("Escalation notify_channel '{channel_name}' is not running");
+        return;
+    };
+    let notice = format!(
+        "⚠️ Escalation on {} from {}: {}\n> {}",
+        msg.channel,
+        msg.sender,
+        escalation.describe(),
+        truncate_with_ellipsis(&msg.content, 200)
+    );
+    if let Err(err) = channel.send(&SendMessage::new(notice, recipient)).await {
+        tracing::warn!("Failed to send escalation notice via {channel_name}: {err}");
+    }
+}
+
+/// Tell the owner the first time each month that replies were downgraded.
+async fn notify_owner_of_downgrade(
+    ctx: &ChannelRuntimeContext,
+    policy: &crate::cost::downgrade::DowngradePolicy,
+    downgrade: &crate::cost::downgrade::Downgrade,
+    from_model: &str,
+) {
+    let Some((channel_name, recipient)) = policy.notify_target() else {
+        return;
+    };
+    let Some(channel) = ctx.channels_by_name.get(channel_name) else {
+        tracing::warn!("cost.downgrade notify_channel '{channel_name}' is not running");
+        return;
+    };
+    let notice = format!(
+        "💸 {}\nPermitted users can send `{}` to keep the original model.",
+        downgrade.describe(from_model),
+        crate::cost::downgrade::FORCE_MODEL_COMMAND
+    );
+    if let Err(err) = channel.send(&SendMessage::new(notice, recipient)).await {
+        tracing::warn!("Failed to send budget downgrade notice via {channel_name}: {err}");
+    }
+}
+
 async fn process_channel_message(
     ctx: Arc<ChannelRuntimeContext>,
     msg: traits::ChannelMessage,
@@ -1513,6 +1893,10 @@ async fn process_channel_message(
     if cancellation_token.is_cancelled() {
         return;
     }
+    ctx.observer.record_event(&ObserverEvent::ChannelMessage {
+        channel: msg.channel.clone(),
+        direction: "inbound".into(),
+    });
 
     println!(
         "  💬 [{}] from {}: {}",
@@ -1550,6 +1934,26 @@ async fn process_channel_message(
     };
 
     let target_channel = ctx.channels_by_name.get(&msg.channel).cloned();
+    let source = format!("chat trigger ({} from {})", msg.channel, msg.sender);
+    let estop_reply = match ctx.estop_trigger.as_deref() {
+        Some(config) => {
+            crate::security::estop::handle_chat_trigger(config, &msg.content, &source, &ctx.toasts)
+                .await
+        }
+        None => None,
+    };
+    if let Some(reply) = estop_reply {
+        if let Some(channel) = target_channel.as_ref() {
+            let _ = channel
+                .send(
+                    &SendMessage::new(reply, &msg.reply_target)
+                        .in_thread(msg.thread_ts.clone())
+                        .replying_to(msg.id.clone()),
+                )
+                .await;
+        }
+        return;
+    }
     if let Err(err) = maybe_apply_runtime_config_update(ctx.as_ref()).await {
         tracing::warn!("Failed to apply runtime config update: {err}");
     }
@@ -1557,8 +1961,72 @@ async fn process_channel_message(
         return;
     }
 
+    if let Some(reply) = ctx
+        .debug_command
+        .handle(&msg.channel, &msg.sender, &msg.content)
+    {
+        if let Some(channel) = target_channel.as_ref() {
+            let _ = channel
+                .send(
+                    &SendMessage::new(reply, &msg.reply_target)
+                        .in_thread(msg.thread_ts.clone())
+                        .replying_to(msg.id.clone()),
+                )
+                .await;
+        }
+        return;
+    }
+
     let history_key = conversation_history_key(&msg);
-    let route = get_route_selection(ctx.as_ref(), &history_key);
+    if let Some(policy) = ctx.downgrade.as_deref() {
+        if let Some(reply) = policy.handle_force_model(&msg.sender, &history_key, &msg.content) {
+            if let Some(channel) = target_channel.as_ref() {
+                let _ = channel
+                    .send(
+                        &SendMessage::new(reply, &msg.reply_target)
+                            .in_thread(msg.thread_ts.clone())
+                            .replying_to(msg.id.clone()),
+                    )
+                    .await;
+            }
+            return;
+        }
+    }
+    let mut route = get_route_selection(ctx.as_ref(), &history_key);
+    let escalation = ctx.escalation.detect(&msg.content);
+    if let Some(escalation) = escalation.as_ref() {
+        tracing::info!(
+            channel = %msg.channel,
+            sender = %msg.sender,
+            "Message escalated: {}",
+            escalation.describe()
+        );
+        if let Some((provider, model)) = escalation.model_override() {
+            if let Some(provider) = provider {
+                route.provider = provider.to_string();
+            }
+            route.model = model.to_string();
+        }
+        notify_owner_of_escalation(ctx.as_ref(), &msg, &history_key, escalation).await;
+    }
+    // The budget cap wins over escalation's model switch.
+    if let Some(policy) = ctx.downgrade.as_deref() {
+        if let Some(downgrade) = policy.check(&history_key, &route.model) {
+            tracing::info!(
+                channel = %msg.channel,
+                sender = %msg.sender,
+                "{}",
+                downgrade.describe(&route.model)
+            );
+            if downgrade.newly_triggered {
+                notify_owner_of_downgrade(ctx.as_ref(), policy, &downgrade, &route.model).await;
+            }
+            if let Some(provider) = downgrade.provider {
+                route.provider = provider;
+            }
+            route.model = downgrade.model;
+        }
+    }
     let runtime_defaults = runtime_defaults_snapshot(ctx.as_ref());
     let active_provider = match get_or_create_provider(ctx.as_ref(), &route.provider).await {
         Ok(provider) => provider,
@@ -1572,24 +2040,37 @@ async fn process_channel_message(
                 let _ = channel
                     .send(
                         &SendMessage::new(message, &msg.reply_target)
-                            .in_thread(msg.thread_ts.clone()),
+                            .in_thread(msg.thread_ts.clone())
+                            .replying_to(msg.id.clone()),
                     )
                     .await;
             }
             return;
         }
     };
+    let mut msg = msg;
+    let reply_translation = match ctx.translation.get(&msg.channel) {
+        Some(settings) => {
+            translate_inbound_message(ctx.as_ref(), settings, &route, &active_provider, &mut msg)
+                .await
+        }
+        None => None,
+    };
     if ctx.auto_save_memory && msg.content.chars().count() >= AUTOSAVE_MIN_MESSAGE_CHARS {
-        let autosave_key = conversation_memory_key(&msg);
-        let _ = ctx
-            .memory
-            .store(
-                &autosave_key,
-                &msg.content,
-                crate::memory::MemoryCategory::Conversation,
-                None,
-            )
-            .await;
+        if let Some(content) = ctx.memory.redact_for_storage(&msg.channel, &msg.content) {
+            let autosave_key = conversation_memory_key(&msg);
+            // Tag with the sender so `privacy forget` matches exactly.
+            let identity = format!("{}:{}", msg.channel, msg.sender);
+            let _ = ctx
+                .memory
+                .store(
+                    &autosave_key,
+                    &content,
+                    ctx.memory.auto_save_category(&content),
+                    Some(&identity),
+                )
+                .await;
+        }
     }
 
     println!("  ⏳ Processing message...");
@@ -1618,8 +2099,13 @@ async fn process_channel_message(
     // Only enrich with memory context when there is no prior conversation
     // history. Follow-up turns already include context from previous messages.
     if !had_prior_history {
-        let memory_context =
-            build_memory_context(ctx.memory.as_ref(), &msg.content, ctx.min_relevance_score).await;
+        let memory_context = build_memory_context(
+            ctx.memory.as_ref(),
+            &msg.content,
+            ctx.min_relevance_score,
+            ctx.memory_token_budget,
+        )
+        .await;
         if let Some(last_turn) = prior_turns.last_mut() {
             if last_turn.role == "user" && !memory_context.is_empty() {
                 last_turn.content = format!("{memory_context}{}", msg.content);
@@ -1627,13 +2113,37 @@ async fn process_channel_message(
         }
     }
 
-    let system_prompt =
+    let mut system_prompt =
         build_channel_system_prompt(ctx.system_prompt.as_str(), &msg.channel, &msg.reply_target);
+    if target_channel
+        .as_ref()
+        .is_some_and(|channel| channel.sends_lightweight_acks())
+    {
+        system_prompt.push_str("\n\n");
+        system_prompt.push_str(LIGHTWEIGHT_ACK_INSTRUCTIONS);
+    }
+    if escalation
+        .as_ref()
+        .is_some_and(crate::agent::escalation::Escalation::drops_persona)
+    {
+        system_prompt.push_str("\n\n");
+        system_prompt.push_str(crate::agent::escalation::TONE_OVERRIDE);
+    }
+    // Profiles are per sender, so only the person being replied to shapes this turn.
+    if let Some(section) = profiles::load(ctx.memory.as_ref(), &msg.channel, &msg.sender)
+        .await
+        .and_then(|profile| profile.prompt_section())
+    {
+        system_prompt.push_str("\n\n");
+        system_prompt.push_str(&section);
+    }
     let mut history = vec![ChatMessage::system(system_prompt)];
     history.extend(prior_turns);
-    let use_streaming = target_channel
-        .as_ref()
-        .is_some_and(|ch| ch.supports_draft_updates());
+    // Drafts would show the untranslated reply, so translated turns send once.
+    let use_streaming = reply_translation.is_none()
+        && target_channel
+            .as_ref()
+            .is_some_and(|ch| ch.supports_draft_updates());
 
     tracing::debug!(
         channel = %msg.channel,
@@ -1654,7 +2164,9 @@ async fn process_channel_message(
         if let Some(channel) = target_channel.as_ref() {
             match channel
                 .send_draft(
-                    &SendMessage::new("...", &msg.reply_target).in_thread(msg.thread_ts.clone()),
+                    &SendMessage::new("...", &msg.reply_target)
+                        .in_thread(msg.thread_ts.clone())
+                        .replying_to(msg.id.clone()),
                 )
                 .await
             {
@@ -1733,7 +2245,7 @@ async fn process_channel_message(
         () = cancellation_token.cancelled() => LlmExecutionResult::Cancelled,
         result = tokio::time::timeout(
             Duration::from_secs(timeout_budget_secs),
-            run_tool_call_loop(
+            ctx.watchdog.scope(run_tool_call_loop(
                 active_provider.as_ref(),
                 &mut history,
                 ctx.tools_registry.as_ref(),
@@ -1754,7 +2266,7 @@ async fn process_channel_message(
                 } else {
                     ctx.non_cli_excluded_tools.as_ref()
                 },
-            ),
+            )),
         ) => LlmExecutionResult::Completed(result),
     };
 
@@ -1900,12 +2412,35 @@ async fn process_channel_message(
                 &history_key,
                 ChatMessage::assistant(&history_response),
             );
+            let delivered_response = match reply_translation.as_ref() {
+                Some(reply) => reply.translate(delivered_response).await,
+                None => delivered_response,
+            };
             println!(
                 "  🤖 Reply ({}ms): {}",
                 started_at.elapsed().as_millis(),
                 truncate_with_ellipsis(&delivered_response, 80)
             );
-            if let Some(channel) = target_channel.as_ref() {
+            let ack_emoji = if target_channel
+                .as_ref()
+                .is_some_and(|channel| channel.sends_lightweight_acks())
+                && draft_message_id.is_none()
+            {
+                lightweight_ack_emoji(&delivered_response)
+            } else {
+                None
+            };
+            let acked = match (target_channel.as_ref(), ack_emoji) {
+                (Some(channel), Some(emoji)) => channel
+                    .send_lightweight_ack(&msg.reply_target, &msg.id, &emoji)
+                    .await
+                    .unwrap_or_else(|e| {
+                        tracing::debug!("Failed to send lightweight ack: {e}");
+                        false
+                    }),
+                _ => false,
+            };
+            if let Some(channel) = target_channel.as_ref().filter(|_| !acked) {
                 if let Some(ref draft_id) = draft_message_id {
                     if let Err(e) = channel
                         .finalize_draft(&msg.reply_target, draft_id, &delivered_response)
@@ -1915,14 +2450,16 @@ async fn process_channel_message(
                         let _ = channel
                             .send(
                                 &SendMessage::new(&delivered_response, &msg.reply_target)
-                                    .in_thread(msg.thread_ts.clone()),
+                                    .in_thread(msg.thread_ts.clone())
+                                    .replying_to(msg.id.clone()),
                             )
                             .await;
                     }
                 } else if let Err(e) = channel
                     .send(
                         &SendMessage::new(delivered_response, &msg.reply_target)
-                            .in_thread(msg.thread_ts.clone()),
+                            .in_thread(msg.thread_ts.clone())
+                            .replying_to(msg.id.clone()),
                     )
                     .await
                 {
@@ -1993,7 +2530,8 @@ async fn process_channel_message(
                         let _ = channel
                             .send(
                                 &SendMessage::new(error_text, &msg.reply_target)
-                                    .in_thread(msg.thread_ts.clone()),
+                                    .in_thread(msg.thread_ts.clone())
+                                    .replying_to(msg.id.clone()),
                             )
                             .await;
                     }
@@ -2041,7 +2579,8 @@ async fn process_channel_message(
                         let _ = channel
                             .send(
                                 &SendMessage::new(format!("⚠️ Error: {e}"), &msg.reply_target)
-                                    .in_thread(msg.thread_ts.clone()),
+                                    .in_thread(msg.thread_ts.clone())
+                                    .replying_to(msg.id.clone()),
                             )
                             .await;
                     }
@@ -2089,7 +2628,8 @@ async fn process_channel_message(
                     let _ = channel
                         .send(
                             &SendMessage::new(error_text, &msg.reply_target)
-                                .in_thread(msg.thread_ts.clone()),
+                                .in_thread(msg.thread_ts.clone())
+                                .replying_to(msg.id.clone()),
                         )
                         .await;
                 }
@@ -2122,16 +2662,26 @@ async fn run_message_dispatch_loop(
     let task_sequence = Arc::new(AtomicU64::new(1));
 
     while let Some(msg) = rx.recv().await {
+        if ctx.drain.is_draining() {
+            tracing::info!(
+                channel = %msg.channel,
+                sender = %msg.sender,
+                "Shutting down; not taking new message"
+            );
+            continue;
+        }
         let permit = match Arc::clone(&semaphore).acquire_owned().await {
             Ok(permit) => permit,
             Err(_) => break,
         };
+        let in_flight_guard = ctx.drain.track();
 
         let worker_ctx = Arc::clone(&ctx);
         let in_flight = Arc::clone(&in_flight_by_sender);
         let task_sequence = Arc::clone(&task_sequence);
         workers.spawn(async move {
             let _permit = permit;
+            let _in_flight = in_flight_guard;
             let interrupt_enabled =
                 worker_ctx.interrupt_on_new_message && msg.channel == "telegram";
             let sender_scope_key = interruption_scope_key(&msg);
@@ -2163,7 +2713,13 @@ async fn run_message_dispatch_loop(
                 }
             }
 
-            process_channel_message(worker_ctx, msg, cancellation_token).await;
+            // Session and hourly tool budgets are counted per conversation.
+            let conversation = conversation_history_key(&msg);
+            tools::limits::scope_conversation(
+                conversation,
+                Box::pin(process_channel_message(worker_ctx, msg, cancellation_token)),
+            )
+            .await;
 
             if interrupt_enabled {
                 let mut active = in_flight.lock().await;
@@ -2613,7 +3169,7 @@ pub(crate) async fn handle_command(command: crate::ChannelCommands, config: &Con
         crate::ChannelCommands::Start => {
             anyhow::bail!("Start must be handled in main.rs (requires async runtime)")
         }
-        crate::ChannelCommands::Doctor => {
+        crate::ChannelCommands::Doctor { .. } => {
             anyhow::bail!("Doctor must be handled in main.rs (requires async runtime)")
         }
         crate::ChannelCommands::List => {
@@ -2626,14 +3182,11 @@ pub(crate) async fn handle_command(command: crate::ChannelCommands, config: &Con
                     channel.name()
                 );
             }
-            if !cfg!(feature = "channel-matrix") {
-                println!(
-                    "  ℹ️ Matrix channel support is disabled in this build (enable `channel-matrix`)."
-                );
-            }
-            if !cfg!(feature = "channel-lark") {
+            for builtin in registry::compiled_out_builtins() {
                 println!(
-                    "  ℹ️ Lark/Feishu channel support is disabled in this build (enable `channel-lark`)."
+                    "  ℹ️ {} channel support is disabled in this build (enable `{}`).",
+                    builtin.display_name,
+                    builtin.feature.unwrap_or_default()
                 );
             }
             println!("\nTo start channels: zeroclaw channel start");
@@ -2658,13 +3211,21 @@ pub(crate) async fn handle_command(command: crate::ChannelCommands, config: &Con
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum ChannelHealthState {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChannelHealthState {
     Healthy,
     Unhealthy,
     Timeout,
 }
 
+/// Health of one configured channel, as reported by `channel doctor`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ChannelHealth {
+    pub channel: String,
+    pub state: ChannelHealthState,
+}
+
 fn classify_health_result(
     result: &std::result::Result<bool, tokio::time::error::Elapsed>,
 ) -> ChannelHealthState {
@@ -2680,12 +3241,21 @@ struct ConfiguredChannel {
     channel: Arc<dyn Channel>,
 }
 
-fn collect_configured_channels(
-    config: &Config,
-    _matrix_skip_context: &str,
-) -> Vec<ConfiguredChannel> {
+fn collect_configured_channels(config: &Config, skip_context: &str) -> Vec<ConfiguredChannel> {
     let mut channels = Vec::new();
 
+    for builtin in registry::compiled_out_builtins() {
+        if (builtin.is_configured)(&config.channels_config) {
+            tracing::warn!(
+                "{} channel is configured but this build was compiled without `{}`; skipping {} {skip_context}.",
+                builtin.display_name,
+                builtin.feature.unwrap_or_default(),
+                builtin.display_name
+            );
+        }
+    }
+
+    #[cfg(feature = "channel-telegram")]
     if let Some(ref tg) = config.channels_config.telegram {
         channels.push(ConfiguredChannel {
             display_name: "Telegram",
@@ -2697,35 +3267,47 @@ fn collect_configured_channels(
                 )
                 .with_streaming(tg.stream_mode, tg.draft_update_interval_ms)
                 .with_transcription(config.transcription.clone())
-                .with_workspace_dir(config.workspace_dir.clone()),
+                .with_workspace_dir(config.workspace_dir.clone())
+                .with_quote_replies(tg.quote_replies)
+                .with_lightweight_acks(tg.lightweight_acks),
             ),
         });
     }
 
+    #[cfg(feature = "channel-discord")]
     if let Some(ref dc) = config.channels_config.discord {
         channels.push(ConfiguredChannel {
             display_name: "Discord",
-            channel: Arc::new(DiscordChannel::new(
-                dc.bot_token.clone(),
-                dc.guild_id.clone(),
-                dc.allowed_users.clone(),
-                dc.listen_to_bots,
-                dc.mention_only,
-            )),
+            channel: Arc::new(
+                DiscordChannel::new(
+                    dc.bot_token.clone(),
+                    dc.guild_id.clone(),
+                    dc.allowed_users.clone(),
+                    dc.listen_to_bots,
+                    dc.mention_only,
+                )
+                .with_quote_replies(dc.quote_replies)
+                .with_lightweight_acks(dc.lightweight_acks),
+            ),
         });
     }
 
+    #[cfg(feature = "channel-slack")]
     if let Some(ref sl) = config.channels_config.slack {
         channels.push(ConfiguredChannel {
             display_name: "Slack",
-            channel: Arc::new(SlackChannel::new(
-                sl.bot_token.clone(),
-                sl.channel_id.clone(),
-                sl.allowed_users.clone(),
-            )),
+            channel: Arc::new(
+                SlackChannel::new(
+                    sl.bot_token.clone(),
+                    sl.channel_id.clone(),
+                    sl.allowed_users.clone(),
+                )
+                .with_quote_replies(sl.quote_replies),
+            ),
         });
     }
 
+    #[cfg(feature = "channel-mattermost")]
     if let Some(ref mm) = config.channels_config.mattermost {
         channels.push(ConfiguredChannel {
             display_name: "Mattermost",
@@ -2740,6 +3322,7 @@ fn collect_configured_channels(
         });
     }
 
+    #[cfg(feature = "channel-imessage")]
     if let Some(ref im) = config.channels_config.imessage {
         channels.push(ConfiguredChannel {
             display_name: "iMessage",
@@ -2763,14 +3346,7 @@ fn collect_configured_channels(
         });
     }
 
-    #[cfg(not(feature = "channel-matrix"))]
-    if config.channels_config.matrix.is_some() {
-        tracing::warn!(
-            "Matrix channel is configured but this build was compiled without `channel-matrix`; skipping Matrix {}.",
-            _matrix_skip_context
-        );
-    }
-
+    #[cfg(feature = "channel-signal")]
     if let Some(ref sig) = config.channels_config.signal {
         channels.push(ConfiguredChannel {
             display_name: "Signal",
@@ -2870,6 +3446,7 @@ fn collect_configured_channels(
         });
     }
 
+    #[cfg(feature = "channel-email")]
     if let Some(ref email_cfg) = config.channels_config.email {
         channels.push(ConfiguredChannel {
             display_name: "Email",
@@ -2877,6 +3454,7 @@ fn collect_configured_channels(
         });
     }
 
+    #[cfg(feature = "channel-irc")]
     if let Some(ref irc) = config.channels_config.irc {
         channels.push(ConfiguredChannel {
             display_name: "IRC",
@@ -2927,13 +3505,7 @@ fn collect_configured_channels(
         });
     }
 
-    #[cfg(not(feature = "channel-lark"))]
-    if config.channels_config.lark.is_some() || config.channels_config.feishu.is_some() {
-        tracing::warn!(
-            "Lark/Feishu channel is configured but this build was compiled without `channel-lark`; skipping Lark/Feishu health check."
-        );
-    }
-
+    #[cfg(feature = "channel-dingtalk")]
     if let Some(ref dt) = config.channels_config.dingtalk {
         channels.push(ConfiguredChannel {
             display_name: "DingTalk",
@@ -2945,6 +3517,7 @@ fn collect_configured_channels(
         });
     }
 
+    #[cfg(feature = "channel-qq")]
     if let Some(ref qq) = config.channels_config.qq {
         channels.push(ConfiguredChannel {
             display_name: "QQ",
@@ -2967,66 +3540,295 @@ fn collect_configured_channels(
 }
 
 /// Run health checks for configured channels.
-pub async fn doctor_channels(config: Config) -> Result<()> {
-    let mut channels = collect_configured_channels(&config, "health check");
+/// Print `zeroclaw channel doctor` to stdout. On a terminal the operator can
+/// pick one channel, the checks run in the background with live progress
+/// (Esc cancels), and failures offer a re-run or the fix suggestions.
+pub async fn doctor_channels(config: Config, channel: Option<String>) -> Result<()> {
+    use std::io::IsTerminal;
+    if !std::io::stdout().is_terminal() || !std::io::stdin().is_terminal() {
+        return write_channel_doctor(&config, channel.as_deref(), &mut std::io::stdout()).await;
+    }
+
+    let only = match channel {
+        Some(channel) => Some(channel),
+        None => pick_doctor_channel(&config)?,
+    };
 
+    println!("🩺 ZeroClaw Channel Doctor");
+    loop {
+        println!();
+        let task_config = config.clone();
+        let task_only = only.clone();
+        let report = crate::onboard::task::run_with_progress(
+            "Checking channels...",
+            move |progress| async move {
+                check_channels_with(&task_config, task_only.as_deref(), |probe| match probe {
+                    ChannelProbe::Started {
+                        channel,
+                        index,
+                        total,
+                    } => progress.status(format!(
+                        "Probing {channel} ({index}/{total}): health check, 10s timeout"
+                    )),
+                    ChannelProbe::Finished(health) => progress.line(health_line(health)),
+                })
+                .await
+            },
+        )
+        .await?;
+        let Some(report) = report else {
+            println!("Channel doctor cancelled.");
+            return Ok(());
+        };
+        if report.is_empty() {
+            println!("No real-time channels configured. Run `zeroclaw onboard` first.");
+            return Ok(());
+        }
+        write_channel_summary(&config, &report, &mut std::io::stdout())?;
+
+        let failed: Vec<&ChannelHealth> = report
+            .iter()
+            .filter(|health| health.state != ChannelHealthState::Healthy)
+            .collect();
+        if failed.is_empty() {
+            return Ok(());
+        }
+        loop {
+            let choice = crate::onboard::prompt::Select::new()
+                .with_prompt("  Next")
+                .items(["Re-run the check", "Show fix suggestions", "Done"])
+                .default(0)
+                .interact()?;
+            match choice {
+                0 => break,
+                1 => {
+                    println!();
+                    for health in &failed {
+                        println!("  {}: {}", health.channel, fix_suggestion(health));
+                    }
+                    println!();
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Ask which configured channel to probe; `None` probes all of them.
+fn pick_doctor_channel(config: &Config) -> Result<Option<String>> {
+    let names: Vec<&'static str> = config
+        .channels_config
+        .channels_except_webhook()
+        .iter()
+        .filter_map(|(channel, configured)| configured.then_some(channel.name()))
+        .collect();
+    if names.len() < 2 {
+        return Ok(None);
+    }
+    let mut items = vec!["All channels"];
+    items.extend(names.iter().copied());
+    let choice = crate::onboard::prompt::Select::new()
+        .with_prompt("  Channel to probe")
+        .items(&items)
+        .default(0)
+        .interact()?;
+    Ok(choice.checked_sub(1).map(|index| names[index].to_string()))
+}
+
+/// Progress reported by [`check_channels_with`].
+enum ChannelProbe<'a> {
+    /// The health check for `channel` (the `index`-th of `total`) started.
+    Started {
+        channel: &'a str,
+        index: usize,
+        total: usize,
+    },
+    Finished(&'a ChannelHealth),
+}
+
+/// Health-check configured real-time channels (10s timeout each): all of
+/// them, or only the one named `only` (case-insensitive).
+pub async fn check_channels(config: &Config, only: Option<&str>) -> Result<Vec<ChannelHealth>> {
+    check_channels_with(config, only, |_| {}).await
+}
+
+/// [`check_channels`], reporting each check as it starts and finishes.
+async fn check_channels_with(
+    config: &Config,
+    only: Option<&str>,
+    mut on_probe: impl FnMut(ChannelProbe<'_>),
+) -> Result<Vec<ChannelHealth>> {
+    let wanted = |name: &str| only.is_none_or(|only| only.eq_ignore_ascii_case(name));
+    let mut channels = collect_configured_channels(config, "health check");
+
+    #[cfg(feature = "channel-nostr")]
     if let Some(ref ns) = config.channels_config.nostr {
+        if wanted("Nostr") {
+            channels.push(ConfiguredChannel {
+                display_name: "Nostr",
+                channel: Arc::new(
+                    NostrChannel::new(&ns.private_key, ns.relays.clone(), &ns.allowed_pubkeys)
+                        .await?,
+                ),
+            });
+        }
+    }
+
+    for (display_name, channel) in registry::build_plugin_channels(config).await? {
         channels.push(ConfiguredChannel {
-            display_name: "Nostr",
-            channel: Arc::new(
-                NostrChannel::new(&ns.private_key, ns.relays.clone(), &ns.allowed_pubkeys).await?,
-            ),
+            display_name,
+            channel,
         });
     }
 
-    if channels.is_empty() {
-        println!("No real-time channels configured. Run `zeroclaw onboard` first.");
-        return Ok(());
+    let configured: Vec<&str> = channels.iter().map(|c| c.display_name).collect();
+    channels.retain(|configured| wanted(configured.display_name));
+    if let (Some(only), true) = (only, channels.is_empty()) {
+        anyhow::bail!(
+            "No configured channel named '{only}'. Configured: {}",
+            if configured.is_empty() {
+                "none".to_string()
+            } else {
+                configured.join(", ")
+            }
+        );
     }
 
-    println!("🩺 ZeroClaw Channel Doctor");
-    println!();
-
-    let mut healthy = 0_u32;
-    let mut unhealthy = 0_u32;
-    let mut timeout = 0_u32;
-
-    for configured in channels {
+    let total = channels.len();
+    let mut report = Vec::with_capacity(total);
+    for (index, configured) in channels.into_iter().enumerate() {
+        on_probe(ChannelProbe::Started {
+            channel: configured.display_name,
+            index: index + 1,
+            total,
+        });
         let result =
             tokio::time::timeout(Duration::from_secs(10), configured.channel.health_check()).await;
-        let state = classify_health_result(&result);
+        let health = ChannelHealth {
+            channel: configured.display_name.to_string(),
+            state: classify_health_result(&result),
+        };
+        on_probe(ChannelProbe::Finished(&health));
+        report.push(health);
+    }
+    Ok(report)
+}
 
-        match state {
-            ChannelHealthState::Healthy => {
-                healthy += 1;
-                println!("  ✅ {:<9} healthy", configured.display_name);
-            }
-            ChannelHealthState::Unhealthy => {
-                unhealthy += 1;
-                println!(
-                    "  ❌ {:<9} unhealthy (auth/config/network)",
-                    configured.display_name
-                );
-            }
-            ChannelHealthState::Timeout => {
-                timeout += 1;
-                println!("  ⏱️  {:<9} timed out (>10s)", configured.display_name);
-            }
+/// Probe every configured channel (10s each), as `channel doctor` does.
+pub(crate) async fn check_all_channels(config: &Config) -> Result<Vec<ChannelHealth>> {
+    check_channels_with(config, None, |_| {}).await
+}
+
+fn health_line(health: &ChannelHealth) -> String {
+    match health.state {
+        ChannelHealthState::Healthy => format!("  ✅ {:<9} healthy", health.channel),
+        ChannelHealthState::Unhealthy => {
+            format!("  ❌ {:<9} unhealthy (auth/config/network)", health.channel)
+        }
+        ChannelHealthState::Timeout => format!("  ⏱️  {:<9} timed out (>10s)", health.channel),
+    }
+}
+
+/// What to try when a channel's health check fails.
+pub fn fix_suggestion(health: &ChannelHealth) -> String {
+    let recheck = format!(
+        "re-check with `zeroclaw channel doctor --channel {}`",
+        health.channel.to_lowercase()
+    );
+    if health.state == ChannelHealthState::Timeout {
+        return format!(
+            "the API did not answer within 10s. Check network access and any [proxy] settings, then {recheck}."
+        );
+    }
+    let credentials = match health.channel.as_str() {
+        "Telegram" => "The bot token may be revoked; get a new one from @BotFather",
+        "Discord" => {
+            "The bot token may be reset; copy it again from the Developer Portal (Bot tab)"
+        }
+        "Slack" => {
+            "The xoxb- bot token may be revoked, or the app is not installed in the workspace"
+        }
+        "Matrix" => "The access token may have expired or the homeserver URL is wrong",
+        "Mattermost" => "The bot token or server URL may be wrong",
+        "Email" => "The IMAP/SMTP host, port or password may be wrong",
+        _ => "The credentials may be wrong or revoked",
+    };
+    format!("{credentials}. Update them with `zeroclaw onboard --channels-only`, then {recheck}.")
+}
+
+/// Write the `channel doctor` report to `out`, for all channels or only `only`.
+pub async fn write_channel_doctor(
+    config: &Config,
+    only: Option<&str>,
+    out: &mut dyn std::io::Write,
+) -> Result<()> {
+    let report = check_channels(config, only).await?;
+    if report.is_empty() {
+        writeln!(
+            out,
+            "No real-time channels configured. Run `zeroclaw onboard` first."
+        )?;
+        return Ok(());
+    }
+
+    writeln!(out, "🩺 ZeroClaw Channel Doctor")?;
+    writeln!(out)?;
+    for health in &report {
+        writeln!(out, "{}", health_line(health))?;
+    }
+    write_channel_summary(config, &report, out)?;
+
+    let failed: Vec<&ChannelHealth> = report
+        .iter()
+        .filter(|health| health.state != ChannelHealthState::Healthy)
+        .collect();
+    if !failed.is_empty() {
+        writeln!(out)?;
+        writeln!(out, "Fix suggestions:")?;
+        for health in failed {
+            writeln!(out, "  {}: {}", health.channel, fix_suggestion(health))?;
         }
     }
+    Ok(())
+}
+
+/// Webhook hint and healthy/unhealthy/timed-out counts after the per-channel lines.
+fn write_channel_summary(
+    config: &Config,
+    report: &[ChannelHealth],
+    out: &mut dyn std::io::Write,
+) -> Result<()> {
+    let count = |state: ChannelHealthState| report.iter().filter(|h| h.state == state).count();
+    let healthy = count(ChannelHealthState::Healthy);
+    let unhealthy = count(ChannelHealthState::Unhealthy);
+    let timeout = count(ChannelHealthState::Timeout);
 
     if config.channels_config.webhook.is_some() {
-        println!("  ℹ️  Webhook   check via `zeroclaw gateway` then GET /health");
+        writeln!(
+            out,
+            "  ℹ️  Webhook   check via `zeroclaw gateway` then GET /health"
+        )?;
     }
 
-    println!();
-    println!("Summary: {healthy} healthy, {unhealthy} unhealthy, {timeout} timed out");
+    writeln!(out)?;
+    writeln!(
+        out,
+        "Summary: {healthy} healthy, {unhealthy} unhealthy, {timeout} timed out"
+    )?;
     Ok(())
 }
 
-/// Start all configured channels and route messages to the agent
+/// Start all configured channels and route messages to the agent. Message,
+/// token and error counts go to `metrics` and reconnect notices to `toasts`
+/// for the dashboard; `!debug on` records LLM traffic through `recorder`.
 #[allow(clippy::too_many_lines)]
-pub async fn start_channels(config: Config) -> Result<()> {
+pub async fn start_channels(
+    config: Config,
+    drain: crate::daemon::shutdown::Drain,
+    metrics: crate::health::metrics::RuntimeMetrics,
+    toasts: crate::notifications::Toasts,
+    recorder: crate::providers::recorder::Recorder,
+) -> Result<()> {
     let provider_name = resolved_default_provider(&config);
     let provider_runtime_options = providers::ProviderRuntimeOptions {
         auth_profile_override: None,
@@ -3034,6 +3836,8 @@ pub async fn start_channels(config: Config) -> Result<()> {
         zeroclaw_dir: config.config_path.parent().map(std::path::PathBuf::from),
         secrets_encrypt: config.secrets.encrypt,
         reasoning_enabled: config.runtime.reasoning_enabled,
+        connectivity: Some(crate::connectivity::Connectivity::new(&config)),
+        recorder: Some(recorder.clone()),
     };
     let provider: Arc<dyn Provider> = Arc::from(
         create_resilient_provider_nonblocking(
@@ -3067,7 +3871,10 @@ pub async fn start_channels(config: Config) -> Result<()> {
     }
 
     let observer: Arc<dyn Observer> =
-        Arc::from(observability::create_observer(&config.observability));
+        Arc::from(metrics.observe(crate::cost::usage::with_recording(
+            observability::create_observer(&config.observability),
+            &config,
+        )));
     let runtime: Arc<dyn runtime::RuntimeAdapter> =
         Arc::from(runtime::create_runtime(&config.runtime)?);
     let security = Arc::new(SecurityPolicy::from_config(
@@ -3076,12 +3883,15 @@ pub async fn start_channels(config: Config) -> Result<()> {
     ));
     let model = resolved_default_model(&config);
     let temperature = config.default_temperature;
-    let mem: Arc<dyn Memory> = Arc::from(memory::create_memory_with_storage(
-        &config.memory,
-        Some(&config.storage.provider.config),
-        &config.workspace_dir,
-        config.api_key.as_deref(),
-    )?);
+    let mem: Arc<dyn Memory> = Arc::from(memory::with_auto_save_policy(
+        memory::create_memory_with_storage(
+            &config.memory,
+            Some(&config.storage.provider.config),
+            &config.workspace_dir,
+            config.api_key.as_deref(),
+        )?,
+        &config,
+    ));
     let (composio_key, composio_entity_id) = if config.composio.enabled {
         (
             config.composio.api_key.as_deref(),
@@ -3092,7 +3902,7 @@ pub async fn start_channels(config: Config) -> Result<()> {
     };
     // Build system prompt from workspace identity files + skills
     let workspace = config.workspace_dir.clone();
-    let tools_registry = Arc::new(tools::all_tools_with_runtime(
+    let mut tools_registry = tools::all_tools_with_runtime(
         Arc::new(config.clone()),
         &security,
         runtime,
@@ -3106,7 +3916,16 @@ pub async fn start_channels(config: Config) -> Result<()> {
         &config.agents,
         config.api_key.as_deref(),
         &config,
-    ));
+    );
+    tools_registry = tools::limits::wrap_registry(tools_registry, &config.agent.tool_limits);
+    if config.agent.dry_run {
+        let switch = tools::dry_run::DryRunSwitch::new(true);
+        tools_registry = tools::dry_run::wrap_registry(tools_registry, &switch);
+    }
+    let tools_registry = Arc::new(tools_registry);
+    let downgrade = crate::cost::downgrade::DowngradePolicy::new(&config.cost, &workspace)
+        .context("Failed to open usage ledger for [cost.downgrade]")?
+        .map(Arc::new);
 
     let skills = crate::skills::load_skills_with_config(&workspace, &config);
 
@@ -3210,11 +4029,19 @@ pub async fn start_channels(config: Config) -> Result<()> {
             .map(|configured| configured.channel)
             .collect();
 
+    #[cfg(feature = "channel-nostr")]
     if let Some(ref ns) = config.channels_config.nostr {
         channels.push(Arc::new(
             NostrChannel::new(&ns.private_key, ns.relays.clone(), &ns.allowed_pubkeys).await?,
         ));
     }
+
+    channels.extend(
+        registry::build_plugin_channels(&config)
+            .await?
+            .into_iter()
+            .map(|(_, channel)| channel),
+    );
     if channels.is_empty() {
         println!("No channels configured. Run `zeroclaw onboard` to set up channels.");
         return Ok(());
@@ -3265,6 +4092,7 @@ pub async fn start_channels(config: Config) -> Result<()> {
             tx.clone(),
             initial_backoff_secs,
             max_backoff_secs,
+            toasts.clone(),
         ));
     }
     drop(tx); // Drop our copy so rx closes when all channels stop
@@ -3302,6 +4130,7 @@ pub async fn start_channels(config: Config) -> Result<()> {
         auto_save_memory: config.memory.auto_save,
         max_tool_iterations: config.agent.max_tool_iterations,
         min_relevance_score: config.memory.min_relevance_score,
+        memory_token_budget: config.memory.context_token_budget,
         conversation_histories: Arc::new(Mutex::new(HashMap::new())),
         provider_cache: Arc::new(Mutex::new(provider_cache_seed)),
         route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -3323,6 +4152,20 @@ pub async fn start_channels(config: Config) -> Result<()> {
             None
         },
         non_cli_excluded_tools: Arc::new(config.autonomy.non_cli_excluded_tools.clone()),
+        translation: Arc::new(config.channels_config.translation.clone()),
+        escalation: Arc::new(crate::agent::escalation::Escalator::new(
+            &config.behavior.escalation,
+        )),
+        debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::new(
+            &config, recorder,
+        )),
+        downgrade,
+        drain,
+        estop_trigger: (config.security.estop.enabled
+            && config.security.estop.trigger_phrase.is_some())
+        .then(|| Arc::new(config.clone())),
+        watchdog: crate::agent::watchdog::Watchdog::new(&config),
+        toasts,
     });
 
     run_message_dispatch_loop(rx, runtime_ctx, max_in_flight_messages).await;
@@ -3368,6 +4211,30 @@ mod tests {
         tmp
     }
 
+    #[test]
+    fn lightweight_ack_emoji_matches_only_bare_acknowledgments() {
+        assert_eq!(lightweight_ack_emoji("Done!").as_deref(), Some("\u{2705}"));
+        assert_eq!(
+            lightweight_ack_emoji("Got it 👍").as_deref(),
+            Some("\u{1F44D}")
+        );
+        assert_eq!(lightweight_ack_emoji(" 🎉 ").as_deref(), Some("🎉"));
+        assert_eq!(lightweight_ack_emoji("❤️").as_deref(), Some("❤️"));
+        assert_eq!(lightweight_ack_emoji("👍🏽").as_deref(), Some("👍🏽"));
+        // CJK, full-width punctuation and box drawing are not emoji.
+        assert_eq!(lightweight_ack_emoji("好的"), None);
+        assert_eq!(lightweight_ack_emoji("。"), None);
+        assert_eq!(lightweight_ack_emoji("！？"), None);
+        assert_eq!(lightweight_ack_emoji("──"), None);
+        assert_eq!(lightweight_ack_emoji("→"), None);
+        assert_eq!(
+            lightweight_ack_emoji("Done. I also renamed the file."),
+            None
+        );
+        assert_eq!(lightweight_ack_emoji("ok so here is the plan"), None);
+        assert_eq!(lightweight_ack_emoji(""), None);
+    }
+
     #[test]
     fn effective_channel_message_timeout_secs_clamps_to_minimum() {
         assert_eq!(
@@ -3523,6 +4390,7 @@ mod tests {
             auto_save_memory: false,
             max_tool_iterations: 5,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(histories)),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -3536,6 +4404,14 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         };
 
         assert!(compact_sender_history(&ctx, &sender));
@@ -3572,6 +4448,7 @@ mod tests {
             auto_save_memory: false,
             max_tool_iterations: 5,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -3585,6 +4462,14 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         };
 
         append_sender_turn(&ctx, &sender, ChatMessage::user("hello"));
@@ -3624,6 +4509,7 @@ mod tests {
             auto_save_memory: false,
             max_tool_iterations: 5,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(histories)),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -3637,6 +4523,14 @@ mod tests {
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         };
 
         assert!(rollback_orphan_user_turn(&ctx, &sender, "pending"));
@@ -4099,6 +4993,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 10,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -4110,6 +5005,14 @@ BTC is currently around $65,000 based on latest tool output."#
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             interrupt_on_new_message: false,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
         });
@@ -4158,6 +5061,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 10,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -4169,6 +5073,14 @@ BTC is currently around $65,000 based on latest tool output."#
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
             interrupt_on_new_message: false,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
         });
@@ -4231,6 +5143,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 10,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -4244,6 +5157,14 @@ BTC is currently around $65,000 based on latest tool output."#
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         process_channel_message(
@@ -4290,6 +5211,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 10,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -4303,6 +5225,14 @@ BTC is currently around $65,000 based on latest tool output."#
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         process_channel_message(
@@ -4358,6 +5288,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 5,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(provider_cache_seed)),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -4371,6 +5302,14 @@ BTC is currently around $65,000 based on latest tool output."#
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         process_channel_message(
@@ -4407,6 +5346,67 @@ BTC is currently around $65,000 based on latest tool output."#
         assert_eq!(fallback_provider_impl.call_count.load(Ordering::SeqCst), 0);
     }
 
+    #[tokio::test]
+    async fn workspace_command_lists_workspaces_and_requires_permission() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("config.toml"), "").unwrap();
+        let lab = crate::config::schema::profile_config_dir(tmp.path(), "lab");
+        std::fs::create_dir_all(&lab).unwrap();
+        std::fs::write(lab.join("config.toml"), "").unwrap();
+
+        let provider: Arc<dyn Provider> = Arc::new(ModelCaptureProvider::default());
+        let ctx = ChannelRuntimeContext {
+            channels_by_name: Arc::new(HashMap::new()),
+            provider: Arc::clone(&provider),
+            default_provider: Arc::new("test-provider".to_string()),
+            memory: Arc::new(NoopMemory),
+            tools_registry: Arc::new(vec![]),
+            observer: Arc::new(NoopObserver),
+            system_prompt: Arc::new("test-system-prompt".to_string()),
+            model: Arc::new("default-model".to_string()),
+            temperature: 0.0,
+            auto_save_memory: false,
+            max_tool_iterations: 5,
+            min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
+            conversation_histories: Arc::new(Mutex::new(HashMap::new())),
+            provider_cache: Arc::new(Mutex::new(HashMap::new())),
+            route_overrides: Arc::new(Mutex::new(HashMap::new())),
+            api_key: None,
+            api_url: None,
+            reliability: Arc::new(crate::config::ReliabilityConfig::default()),
+            provider_runtime_options: providers::ProviderRuntimeOptions {
+                zeroclaw_dir: Some(tmp.path().to_path_buf()),
+                ..providers::ProviderRuntimeOptions::default()
+            },
+            workspace_dir: Arc::new(tmp.path().join("workspace")),
+            message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            interrupt_on_new_message: false,
+            multimodal: crate::config::MultimodalConfig::default(),
+            hooks: None,
+            non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
+        };
+
+        let listing = handle_workspace_command(&ctx, "alice", None).await;
+        assert!(listing.contains("- `default` (active)"));
+        assert!(listing.contains("- `lab`"));
+
+        let denied = handle_workspace_command(&ctx, "alice", Some("lab")).await;
+        assert!(denied.contains("not allowed"));
+        assert!(matches!(
+            parse_runtime_command("telegram", "/workspace lab"),
+            Some(ChannelRuntimeCommand::Workspace(Some(name))) if name == "lab"
+        ));
+    }
+
     #[tokio::test]
     async fn process_channel_message_uses_route_override_provider_and_model() {
         let channel_impl = Arc::new(TelegramRecordingChannel::default());
@@ -4447,6 +5447,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 5,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(provider_cache_seed)),
             route_overrides: Arc::new(Mutex::new(route_overrides)),
@@ -4460,6 +5461,14 @@ BTC is currently around $65,000 based on latest tool output."#
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         process_channel_message(
@@ -4518,6 +5527,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 5,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(provider_cache_seed)),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -4531,6 +5541,14 @@ BTC is currently around $65,000 based on latest tool output."#
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         process_channel_message(
@@ -4601,6 +5619,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 5,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(provider_cache_seed)),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -4617,6 +5636,14 @@ BTC is currently around $65,000 based on latest tool output."#
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         process_channel_message(
@@ -4675,6 +5702,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 12,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -4688,6 +5716,14 @@ BTC is currently around $65,000 based on latest tool output."#
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         process_channel_message(
@@ -4735,6 +5771,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 3,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -4748,6 +5785,14 @@ BTC is currently around $65,000 based on latest tool output."#
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         process_channel_message(
@@ -4906,6 +5951,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 10,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -4919,6 +5965,14 @@ BTC is currently around $65,000 based on latest tool output."#
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         let (tx, rx) = tokio::sync::mpsc::channel::<traits::ChannelMessage>(4);
@@ -4986,6 +6040,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 10,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -4999,6 +6054,14 @@ BTC is currently around $65,000 based on latest tool output."#
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         let (tx, rx) = tokio::sync::mpsc::channel::<traits::ChannelMessage>(8);
@@ -5078,6 +6141,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 10,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -5091,6 +6155,14 @@ BTC is currently around $65,000 based on latest tool output."#
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         let (tx, rx) = tokio::sync::mpsc::channel::<traits::ChannelMessage>(8);
@@ -5152,6 +6224,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 10,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -5165,6 +6238,14 @@ BTC is currently around $65,000 based on latest tool output."#
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         process_channel_message(
@@ -5211,6 +6292,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 10,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -5224,6 +6306,14 @@ BTC is currently around $65,000 based on latest tool output."#
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         process_channel_message(
@@ -5699,7 +6789,7 @@ BTC is currently around $65,000 based on latest tool output."#
             .await
             .unwrap();
 
-        let context = build_memory_context(&mem, "age", 0.0).await;
+        let context = build_memory_context(&mem, "age", 0.0, 1_000).await;
         assert!(context.contains("[Memory context]"));
         assert!(context.contains("Age is 45"));
     }
@@ -5727,6 +6817,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 5,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -5740,6 +6831,14 @@ BTC is currently around $65,000 based on latest tool output."#
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         process_channel_message(
@@ -5812,6 +6911,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 5,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -5825,6 +6925,14 @@ BTC is currently around $65,000 based on latest tool output."#
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         process_channel_message(
@@ -5897,6 +7005,7 @@ BTC is currently around $65,000 based on latest tool output."#
             auto_save_memory: false,
             max_tool_iterations: 5,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(histories)),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -5910,6 +7019,14 @@ BTC is currently around $65,000 based on latest tool output."#
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         process_channel_message(
@@ -6215,6 +7332,31 @@ This is an example JSON object for profile settings."#;
         assert_eq!(state, ChannelHealthState::Timeout);
     }
 
+    #[tokio::test]
+    async fn channel_doctor_writes_to_the_given_writer() {
+        let config = Config::default();
+        assert!(check_channels(&config, None).await.unwrap().is_empty());
+        let error = check_channels(&config, Some("telegram")).await.unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("No configured channel named 'telegram'"));
+        let mut out = Vec::new();
+        write_channel_doctor(&config, None, &mut out).await.unwrap();
+        assert!(String::from_utf8(out)
+            .unwrap()
+            .contains("No real-time channels configured"));
+
+        let health = ChannelHealth {
+            channel: "Telegram".into(),
+            state: ChannelHealthState::Timeout,
+        };
+        assert_eq!(
+            serde_json::to_value(&health).unwrap(),
+            serde_json::json!({"channel": "Telegram", "state": "timeout"})
+        );
+        assert!(fix_suggestion(&health).contains("--channel telegram"));
+    }
+
     #[test]
     fn collect_configured_channels_includes_mattermost_when_configured() {
         let mut config = Config::default();
@@ -6295,7 +7437,8 @@ This is an example JSON object for profile settings."#;
         });
 
         let (tx, rx) = tokio::sync::mpsc::channel::<traits::ChannelMessage>(1);
-        let handle = spawn_supervised_listener(channel, tx, 1, 1);
+        let handle =
+            spawn_supervised_listener(channel, tx, 1, 1, crate::notifications::Toasts::default());
 
         tokio::time::sleep(Duration::from_millis(80)).await;
         drop(rx);
@@ -6330,6 +7473,7 @@ This is an example JSON object for profile settings."#;
             1,
             1,
             Duration::from_millis(20),
+            crate::notifications::Toasts::default(),
         );
 
         tokio::time::sleep(Duration::from_millis(35)).await;
@@ -6446,6 +7590,7 @@ This is an example JSON object for profile settings."#;
             auto_save_memory: false,
             max_tool_iterations: 5,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -6459,6 +7604,14 @@ This is an example JSON object for profile settings."#;
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         // Simulate a photo attachment message with [IMAGE:] marker.
@@ -6512,6 +7665,7 @@ This is an example JSON object for profile settings."#;
             auto_save_memory: false,
             max_tool_iterations: 5,
             min_relevance_score: 0.0,
+            memory_token_budget: 1_000,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -6525,6 +7679,14 @@ This is an example JSON object for profile settings."#;
             multimodal: crate::config::MultimodalConfig::default(),
             hooks: None,
             non_cli_excluded_tools: Arc::new(Vec::new()),
+            translation: Arc::new(translation::TranslationMap::new()),
+            escalation: Arc::new(crate::agent::escalation::Escalator::default()),
+            debug_command: Arc::new(crate::observability::debug_mode::DebugCommand::default()),
+            downgrade: None,
+            drain: crate::daemon::shutdown::Drain::default(),
+            estop_trigger: None,
+            watchdog: crate::agent::watchdog::Watchdog::default(),
+            toasts: crate::notifications::Toasts::default(),
         });
 
         process_channel_message(
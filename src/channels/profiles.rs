@@ -0,0 +1,180 @@
+//! Per-user preference profiles for shared channels.
+//!
+//! Each sender on each channel can keep a small profile (name, tone,
+//! language) with `/profile`. Profiles live in memory under their own key
+//! prefix and category, are kept out of shared memory recall, and are added
+//! to the system prompt only for turns that reply to that sender.
+
+use crate::memory::{Memory, MemoryCategory};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+
+const PROFILE_KEY_PREFIX: &str = "user_profile_";
+const PROFILE_CATEGORY: &str = "user_profile";
+const MAX_FIELD_CHARS: usize = 120;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct UserProfile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tone: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+impl UserProfile {
+    fn is_empty(&self) -> bool {
+        self.name.is_none() && self.tone.is_none() && self.language.is_none()
+    }
+
+    /// System prompt lines describing the person being replied to.
+    pub(crate) fn prompt_section(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut section = String::from(
+            "## About the person you are replying to\n\n\
+             These preferences apply to this person only; do not mention them to others.\n",
+        );
+        if let Some(name) = &self.name {
+            let _ = writeln!(section, "- Name: {name}");
+        }
+        if let Some(tone) = &self.tone {
+            let _ = writeln!(section, "- Preferred tone: {tone}");
+        }
+        if let Some(language) = &self.language {
+            let _ = writeln!(section, "- Reply in: {language}");
+        }
+        Some(section)
+    }
+
+    fn describe(&self) -> String {
+        if self.is_empty() {
+            return "No profile saved yet.".to_string();
+        }
+        let field = |value: &Option<String>| value.clone().unwrap_or_else(|| "(not set)".into());
+        format!(
+            "Your profile:\n- name: {}\n- tone: {}\n- language: {}",
+            field(&self.name),
+            field(&self.tone),
+            field(&self.language)
+        )
+    }
+}
+
+/// Whether `key` holds a user profile, so shared recall can skip it.
+pub(crate) fn is_profile_key(key: &str) -> bool {
+    key.trim()
+        .to_ascii_lowercase()
+        .starts_with(PROFILE_KEY_PREFIX)
+}
+
+/// Channel and sender are hex-encoded so distinct pairs never share a key.
+fn profile_key(channel: &str, sender: &str) -> String {
+    format!(
+        "{PROFILE_KEY_PREFIX}{}_{}",
+        hex::encode(channel),
+        hex::encode(sender)
+    )
+}
+
+/// The saved profile for `sender` on `channel`, if any.
+pub(crate) async fn load(mem: &dyn Memory, channel: &str, sender: &str) -> Option<UserProfile> {
+    let entry = mem.get(&profile_key(channel, sender)).await.ok()??;
+    serde_json::from_str(&entry.content).ok()
+}
+
+async fn save(mem: &dyn Memory, channel: &str, sender: &str, profile: &UserProfile) -> Result<()> {
+    let key = profile_key(channel, sender);
+    if profile.is_empty() {
+        mem.forget(&key).await?;
+        return Ok(());
+    }
+    mem.store(
+        &key,
+        &serde_json::to_string(profile)?,
+        MemoryCategory::Custom(PROFILE_CATEGORY.into()),
+        // Tagged like channel memories so `zeroclaw privacy forget` finds it.
+        Some(&format!("{channel}:{sender}")),
+    )
+    .await
+}
+
+/// Handle `/profile [name|tone|language <value>|clear]` and return the reply.
+pub(crate) async fn handle_command(
+    mem: &dyn Memory,
+    channel: &str,
+    sender: &str,
+    args: &str,
+) -> String {
+    let mut profile = load(mem, channel, sender).await.unwrap_or_default();
+    let (field, value) = args.trim().split_once(' ').unwrap_or((args.trim(), ""));
+    let value = value.trim();
+    let value: Option<String> =
+        (!value.is_empty()).then(|| value.chars().take(MAX_FIELD_CHARS).collect());
+
+    let slot = match field.to_ascii_lowercase().as_str() {
+        "" => return profile.describe(),
+        "clear" => {
+            profile = UserProfile::default();
+            None
+        }
+        "name" => Some(&mut profile.name),
+        "tone" => Some(&mut profile.tone),
+        "language" | "lang" => Some(&mut profile.language),
+        _ => {
+            return "Usage: `/profile` to show, `/profile name|tone|language <value>` to set \
+                    (no value unsets it), `/profile clear` to remove everything."
+                .to_string()
+        }
+    };
+    if let Some(slot) = slot {
+        *slot = value;
+    }
+
+    match save(mem, channel, sender, &profile).await {
+        Ok(()) => format!("Saved. {}", profile.describe()),
+        Err(err) => format!("Failed to save your profile: {err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::SqliteMemory;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn profiles_are_per_sender_and_hidden_from_shared_recall() {
+        let tmp = TempDir::new().unwrap();
+        let mem = SqliteMemory::new(tmp.path()).unwrap();
+
+        handle_command(&mem, "discord", "alice", "name Alice").await;
+        let reply = handle_command(&mem, "discord", "alice", "tone  short and dry ").await;
+        assert!(reply.contains("tone: short and dry"));
+
+        let alice = load(&mem, "discord", "alice").await.unwrap();
+        let section = alice.prompt_section().unwrap();
+        assert!(section.contains("- Name: Alice"));
+        assert!(section.contains("- Preferred tone: short and dry"));
+        assert!(load(&mem, "discord", "bob").await.is_none());
+        assert!(is_profile_key(&profile_key("discord", "alice")));
+
+        let stored = mem
+            .get(&profile_key("discord", "alice"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.session_id.as_deref(), Some("discord:alice"));
+        assert_ne!(profile_key("a_b", "c"), profile_key("a", "b_c"));
+        assert_ne!(profile_key("discord", "a.b"), profile_key("discord", "a_b"));
+
+        handle_command(&mem, "discord", "alice", "clear").await;
+        assert!(load(&mem, "discord", "alice").await.is_none());
+        assert!(handle_command(&mem, "discord", "alice", "shoe size 9")
+            .await
+            .starts_with("Usage"));
+    }
+}
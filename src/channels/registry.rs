@@ -0,0 +1,589 @@
+//! Channel registry.
+//!
+//! Built-in connectors are described by [`BUILTIN_CHANNELS`]; out-of-tree
+//! connectors implement [`ChannelPlugin`] and call [`register_channel_plugin`]
+//! before channels start. Plugins read their settings from
+//! `[channels_config.plugins.<key>]`, so adding one never requires touching
+//! `ChannelsConfig` or the onboarding wizard — the wizard's channel menu is
+//! built from [`wizard_entries`].
+//!
+//! Built-ins other than the gateway-hosted webhook channels sit behind
+//! `channel-*` cargo features; [`BuiltinChannel::compiled`] records whether
+//! this build has them.
+
+use super::traits::Channel;
+use crate::config::{ChannelsConfig, Config};
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// An out-of-tree channel connector.
+#[async_trait]
+pub trait ChannelPlugin: Send + Sync {
+    /// Stable key; also the `[channels_config.plugins.<key>]` table name.
+    fn key(&self) -> &'static str;
+
+    /// Human-readable name shown in the wizard, doctor and startup output.
+    fn display_name(&self) -> &'static str;
+
+    /// Short hint shown next to the name in the wizard menu.
+    fn wizard_hint(&self) -> &'static str {
+        "— channel plugin"
+    }
+
+    /// Wizard section the plugin is listed under.
+    fn group(&self) -> ChannelGroup {
+        ChannelGroup::Developer
+    }
+
+    /// One-line description shown in the wizard.
+    fn description(&self) -> &'static str {
+        "Out-of-tree channel plugin"
+    }
+
+    /// Whether the plugin receives messages as webhooks on a public URL.
+    fn needs_public_url(&self) -> bool {
+        false
+    }
+
+    /// Build the channel from its plugin config table.
+    async fn build(
+        &self,
+        settings: &serde_json::Value,
+        config: &Config,
+    ) -> Result<Arc<dyn Channel>>;
+
+    /// Interactive onboarding. Return the table to store under
+    /// `[channels_config.plugins.<key>]`, or `None` to skip.
+    fn wizard_setup(&self) -> Result<Option<serde_json::Value>> {
+        Ok(None)
+    }
+}
+
+/// Section of the wizard's channel list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChannelGroup {
+    Mainstream,
+    Privacy,
+    China,
+    Developer,
+}
+
+impl ChannelGroup {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Mainstream => "Mainstream",
+            Self::Privacy => "Privacy-focused",
+            Self::China => "China ecosystem",
+            Self::Developer => "Developer/Other",
+        }
+    }
+}
+
+/// Static description of a channel compiled into this binary.
+#[derive(Clone, Copy)]
+pub struct BuiltinChannel {
+    pub key: &'static str,
+    pub display_name: &'static str,
+    /// Cargo feature that compiles this channel in; `None` for always-on channels.
+    pub feature: Option<&'static str>,
+    /// Whether `feature` is enabled in this build.
+    pub compiled: bool,
+    /// Wizard menu hint; `None` means the channel has no interactive setup.
+    pub wizard_hint: Option<&'static str>,
+    pub configured_label: &'static str,
+    pub is_configured: fn(&ChannelsConfig) -> bool,
+    pub group: ChannelGroup,
+    /// One-line description shown in the wizard.
+    pub description: &'static str,
+    /// Whether inbound messages arrive as webhooks, so the gateway must be
+    /// reachable from the internet (tunnel or public host).
+    pub needs_public_url: bool,
+}
+
+/// Built-in channels, in wizard menu order within each [`ChannelGroup`].
+pub const BUILTIN_CHANNELS: &[BuiltinChannel] = &[
+    BuiltinChannel {
+        key: "telegram",
+        display_name: "Telegram",
+        feature: Some("channel-telegram"),
+        compiled: cfg!(feature = "channel-telegram"),
+        wizard_hint: Some("— connect your bot"),
+        configured_label: "✅ connected",
+        is_configured: |c| c.telegram.is_some(),
+        group: ChannelGroup::Mainstream,
+        description: "Bot API over long polling; works behind NAT",
+        needs_public_url: false,
+    },
+    BuiltinChannel {
+        key: "discord",
+        display_name: "Discord",
+        feature: Some("channel-discord"),
+        compiled: cfg!(feature = "channel-discord"),
+        wizard_hint: Some("— connect your bot"),
+        configured_label: "✅ connected",
+        is_configured: |c| c.discord.is_some(),
+        group: ChannelGroup::Mainstream,
+        description: "Bot in your servers and DMs",
+        needs_public_url: false,
+    },
+    BuiltinChannel {
+        key: "slack",
+        display_name: "Slack",
+        feature: Some("channel-slack"),
+        compiled: cfg!(feature = "channel-slack"),
+        wizard_hint: Some("— connect your bot"),
+        configured_label: "✅ connected",
+        is_configured: |c| c.slack.is_some(),
+        group: ChannelGroup::Mainstream,
+        description: "Bot in your workspace channels",
+        needs_public_url: false,
+    },
+    BuiltinChannel {
+        key: "imessage",
+        display_name: "iMessage",
+        feature: Some("channel-imessage"),
+        compiled: cfg!(feature = "channel-imessage"),
+        wizard_hint: Some("— macOS only"),
+        configured_label: "✅ configured",
+        is_configured: |c| c.imessage.is_some(),
+        group: ChannelGroup::Mainstream,
+        description: "Messages.app on this Mac",
+        needs_public_url: false,
+    },
+    BuiltinChannel {
+        key: "matrix",
+        display_name: "Matrix",
+        feature: Some("channel-matrix"),
+        compiled: cfg!(feature = "channel-matrix"),
+        wizard_hint: Some("— self-hosted chat"),
+        configured_label: "✅ connected",
+        is_configured: |c| c.matrix.is_some(),
+        group: ChannelGroup::Privacy,
+        description: "Any homeserver, including end-to-end encrypted rooms",
+        needs_public_url: false,
+    },
+    BuiltinChannel {
+        key: "signal",
+        display_name: "Signal",
+        feature: Some("channel-signal"),
+        compiled: cfg!(feature = "channel-signal"),
+        wizard_hint: Some("— signal-cli daemon bridge"),
+        configured_label: "✅ connected",
+        is_configured: |c| c.signal.is_some(),
+        group: ChannelGroup::Privacy,
+        description: "Private chats through a local signal-cli daemon",
+        needs_public_url: false,
+    },
+    BuiltinChannel {
+        key: "whatsapp",
+        display_name: "WhatsApp",
+        feature: None,
+        compiled: true,
+        wizard_hint: Some("— Business Cloud API"),
+        configured_label: "✅ connected",
+        is_configured: |c| c.whatsapp.is_some(),
+        group: ChannelGroup::Mainstream,
+        description: "Meta Business Cloud API webhooks",
+        needs_public_url: true,
+    },
+    BuiltinChannel {
+        key: "linq",
+        display_name: "Linq",
+        feature: None,
+        compiled: true,
+        wizard_hint: Some("— iMessage/RCS/SMS via Linq API"),
+        configured_label: "✅ connected",
+        is_configured: |c| c.linq.is_some(),
+        group: ChannelGroup::Mainstream,
+        description: "iMessage, RCS and SMS through the Linq API",
+        needs_public_url: true,
+    },
+    BuiltinChannel {
+        key: "irc",
+        display_name: "IRC",
+        feature: Some("channel-irc"),
+        compiled: cfg!(feature = "channel-irc"),
+        wizard_hint: Some("— IRC over TLS"),
+        configured_label: "✅ configured",
+        is_configured: |c| c.irc.is_some(),
+        group: ChannelGroup::Developer,
+        description: "Classic IRC networks over TLS",
+        needs_public_url: false,
+    },
+    BuiltinChannel {
+        key: "webhook",
+        display_name: "Webhook",
+        feature: None,
+        compiled: true,
+        wizard_hint: Some("— HTTP endpoint"),
+        configured_label: "✅ configured",
+        is_configured: |c| c.webhook.is_some(),
+        group: ChannelGroup::Developer,
+        description: "Generic HTTP endpoint for your own integrations",
+        needs_public_url: true,
+    },
+    BuiltinChannel {
+        key: "nextcloud_talk",
+        display_name: "Nextcloud",
+        feature: None,
+        compiled: true,
+        wizard_hint: Some("— Talk webhook + OCS API"),
+        configured_label: "✅ connected",
+        is_configured: |c| c.nextcloud_talk.is_some(),
+        group: ChannelGroup::Privacy,
+        description: "Self-hosted Nextcloud Talk rooms",
+        needs_public_url: true,
+    },
+    BuiltinChannel {
+        key: "dingtalk",
+        display_name: "DingTalk",
+        feature: Some("channel-dingtalk"),
+        compiled: cfg!(feature = "channel-dingtalk"),
+        wizard_hint: Some("— DingTalk Stream Mode"),
+        configured_label: "✅ connected",
+        is_configured: |c| c.dingtalk.is_some(),
+        group: ChannelGroup::China,
+        description: "DingTalk bots over Stream Mode",
+        needs_public_url: false,
+    },
+    BuiltinChannel {
+        key: "qq",
+        display_name: "QQ Official",
+        feature: Some("channel-qq"),
+        compiled: cfg!(feature = "channel-qq"),
+        wizard_hint: Some("— Tencent QQ Bot"),
+        configured_label: "✅ connected",
+        is_configured: |c| c.qq.is_some(),
+        group: ChannelGroup::China,
+        description: "Tencent QQ official bot platform",
+        needs_public_url: false,
+    },
+    BuiltinChannel {
+        key: "lark",
+        display_name: "Lark",
+        feature: Some("channel-lark"),
+        compiled: cfg!(feature = "channel-lark"),
+        wizard_hint: Some("— Lark Bot"),
+        configured_label: "✅ connected",
+        is_configured: |c| c.lark.as_ref().is_some_and(|cfg| !cfg.use_feishu),
+        group: ChannelGroup::China,
+        description: "Lark bots over a WebSocket connection",
+        needs_public_url: false,
+    },
+    BuiltinChannel {
+        key: "feishu",
+        display_name: "Feishu",
+        feature: Some("channel-lark"),
+        compiled: cfg!(feature = "channel-lark"),
+        wizard_hint: Some("— Feishu Bot"),
+        configured_label: "✅ connected",
+        is_configured: |c| c.feishu.is_some() || c.lark.as_ref().is_some_and(|cfg| cfg.use_feishu),
+        group: ChannelGroup::China,
+        description: "Feishu bots over a WebSocket connection",
+        needs_public_url: false,
+    },
+    BuiltinChannel {
+        key: "nostr",
+        display_name: "Nostr",
+        feature: Some("channel-nostr"),
+        compiled: cfg!(feature = "channel-nostr"),
+        wizard_hint: Some("— Nostr DMs"),
+        configured_label: "✅ connected",
+        is_configured: |c| c.nostr.is_some(),
+        group: ChannelGroup::Privacy,
+        description: "Encrypted DMs over Nostr relays",
+        needs_public_url: false,
+    },
+    BuiltinChannel {
+        key: "mattermost",
+        display_name: "Mattermost",
+        feature: Some("channel-mattermost"),
+        compiled: cfg!(feature = "channel-mattermost"),
+        wizard_hint: None,
+        configured_label: "✅ connected",
+        is_configured: |c| c.mattermost.is_some(),
+        group: ChannelGroup::Privacy,
+        description: "Self-hosted Mattermost channels",
+        needs_public_url: false,
+    },
+    BuiltinChannel {
+        key: "wati",
+        display_name: "WATI",
+        feature: None,
+        compiled: true,
+        wizard_hint: None,
+        configured_label: "✅ connected",
+        is_configured: |c| c.wati.is_some(),
+        group: ChannelGroup::Mainstream,
+        description: "WhatsApp through the WATI API",
+        needs_public_url: true,
+    },
+    BuiltinChannel {
+        key: "email",
+        display_name: "Email",
+        feature: Some("channel-email"),
+        compiled: cfg!(feature = "channel-email"),
+        wizard_hint: None,
+        configured_label: "✅ configured",
+        is_configured: |c| c.email.is_some(),
+        group: ChannelGroup::Mainstream,
+        description: "IMAP inbox with SMTP replies",
+        needs_public_url: false,
+    },
+    BuiltinChannel {
+        key: "clawdtalk",
+        display_name: "ClawdTalk",
+        feature: None,
+        compiled: true,
+        wizard_hint: None,
+        configured_label: "✅ connected",
+        is_configured: |c| c.clawdtalk.is_some(),
+        group: ChannelGroup::Developer,
+        description: "Voice calls over Telnyx SIP",
+        needs_public_url: true,
+    },
+];
+
+/// One row of the wizard's channel menu, built-in or plugin.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChannelEntry {
+    pub key: &'static str,
+    pub display_name: &'static str,
+    pub wizard_hint: &'static str,
+    pub configured_label: &'static str,
+    pub group: ChannelGroup,
+    pub description: &'static str,
+    pub needs_public_url: bool,
+    pub builtin: bool,
+    /// Cargo feature the channel needs when it is not compiled into this build.
+    pub missing_feature: Option<&'static str>,
+}
+
+static PLUGINS: OnceLock<RwLock<Vec<Arc<dyn ChannelPlugin>>>> = OnceLock::new();
+
+fn plugin_store() -> &'static RwLock<Vec<Arc<dyn ChannelPlugin>>> {
+    PLUGINS.get_or_init(|| RwLock::new(Vec::new()))
+}
+
+/// Register an out-of-tree channel. Keys must be unique across built-ins and plugins.
+pub fn register_channel_plugin(plugin: Arc<dyn ChannelPlugin>) -> Result<()> {
+    let key = plugin.key();
+    if key.trim().is_empty() {
+        bail!("channel plugin key must not be empty");
+    }
+    if BUILTIN_CHANNELS.iter().any(|builtin| builtin.key == key) {
+        bail!("channel plugin key '{key}' collides with a built-in channel");
+    }
+
+    let mut plugins = plugin_store()
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if plugins.iter().any(|existing| existing.key() == key) {
+        bail!("channel plugin '{key}' is already registered");
+    }
+    plugins.push(plugin);
+    Ok(())
+}
+
+/// All registered plugins, in registration order.
+pub fn registered_plugins() -> Vec<Arc<dyn ChannelPlugin>> {
+    plugin_store()
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+}
+
+pub fn find_plugin(key: &str) -> Option<Arc<dyn ChannelPlugin>> {
+    registered_plugins()
+        .into_iter()
+        .find(|plugin| plugin.key() == key)
+}
+
+/// Channels offered by the onboarding wizard, ordered by [`ChannelGroup`]:
+/// built-ins with interactive setup, then registered plugins, within each
+/// group. Built-ins compiled out of this build stay listed (their config is
+/// still accepted) but carry `missing_feature`.
+pub fn wizard_entries() -> Vec<ChannelEntry> {
+    let builtins = BUILTIN_CHANNELS.iter().filter_map(|builtin| {
+        builtin.wizard_hint.map(|hint| ChannelEntry {
+            key: builtin.key,
+            display_name: builtin.display_name,
+            wizard_hint: hint,
+            configured_label: builtin.configured_label,
+            group: builtin.group,
+            description: builtin.description,
+            needs_public_url: builtin.needs_public_url,
+            builtin: true,
+            missing_feature: builtin.feature.filter(|_| !builtin.compiled),
+        })
+    });
+    let plugins = registered_plugins().into_iter().map(|plugin| ChannelEntry {
+        key: plugin.key(),
+        display_name: plugin.display_name(),
+        wizard_hint: plugin.wizard_hint(),
+        configured_label: "✅ configured",
+        group: plugin.group(),
+        description: plugin.description(),
+        needs_public_url: plugin.needs_public_url(),
+        builtin: false,
+        missing_feature: None,
+    });
+    let mut entries: Vec<ChannelEntry> = builtins.chain(plugins).collect();
+    // Stable, so registry order is kept within a group.
+    entries.sort_by_key(|entry| entry.group);
+    entries
+}
+
+/// Built-in channels whose cargo feature is disabled in this build.
+pub fn compiled_out_builtins() -> impl Iterator<Item = &'static BuiltinChannel> {
+    BUILTIN_CHANNELS.iter().filter(|builtin| !builtin.compiled)
+}
+
+/// Whether the channel with `key` is configured. Unknown built-in keys are
+/// treated as plugin keys.
+pub fn is_configured(key: &str, channels: &ChannelsConfig) -> bool {
+    match BUILTIN_CHANNELS.iter().find(|builtin| builtin.key == key) {
+        Some(builtin) => (builtin.is_configured)(channels),
+        None => channels.plugins.contains_key(key),
+    }
+}
+
+/// Build every plugin channel configured under `[channels_config.plugins]`.
+/// Tables without a registered plugin are skipped with a warning.
+pub async fn build_plugin_channels(
+    config: &Config,
+) -> Result<Vec<(&'static str, Arc<dyn Channel>)>> {
+    let mut channels = Vec::new();
+    for (key, settings) in &config.channels_config.plugins {
+        let Some(plugin) = find_plugin(key) else {
+            tracing::warn!(
+                "[channels_config.plugins.{key}] is configured but no channel plugin named '{key}' is registered in this build; skipping."
+            );
+            continue;
+        };
+        let channel = plugin
+            .build(settings, config)
+            .await
+            .with_context(|| format!("failed to build channel plugin '{key}'"))?;
+        channels.push((plugin.display_name(), channel));
+    }
+    Ok(channels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::channels::traits::{ChannelMessage, SendMessage};
+
+    struct EchoChannel;
+
+    #[async_trait]
+    impl Channel for EchoChannel {
+        fn name(&self) -> &str {
+            "echo"
+        }
+
+        async fn send(&self, _message: &SendMessage) -> Result<()> {
+            Ok(())
+        }
+
+        async fn listen(&self, _tx: tokio::sync::mpsc::Sender<ChannelMessage>) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    struct EchoPlugin(&'static str);
+
+    #[async_trait]
+    impl ChannelPlugin for EchoPlugin {
+        fn key(&self) -> &'static str {
+            self.0
+        }
+
+        fn display_name(&self) -> &'static str {
+            "Echo"
+        }
+
+        async fn build(
+            &self,
+            settings: &serde_json::Value,
+            _config: &Config,
+        ) -> Result<Arc<dyn Channel>> {
+            if settings.get("fail").is_some() {
+                bail!("bad settings");
+            }
+            Ok(Arc::new(EchoChannel))
+        }
+    }
+
+    #[test]
+    fn builtin_keys_are_unique() {
+        let mut keys: Vec<_> = BUILTIN_CHANNELS.iter().map(|b| b.key).collect();
+        keys.sort_unstable();
+        keys.dedup();
+        assert_eq!(keys.len(), BUILTIN_CHANNELS.len());
+    }
+
+    #[test]
+    fn register_rejects_builtin_and_duplicate_keys() {
+        assert!(register_channel_plugin(Arc::new(EchoPlugin("telegram"))).is_err());
+        register_channel_plugin(Arc::new(EchoPlugin("registry-test-dup"))).unwrap();
+        assert!(register_channel_plugin(Arc::new(EchoPlugin("registry-test-dup"))).is_err());
+    }
+
+    #[test]
+    fn feature_gated_builtins_track_their_cfg() {
+        for builtin in BUILTIN_CHANNELS {
+            if builtin.feature.is_none() {
+                assert!(builtin.compiled, "{} has no feature gate", builtin.key);
+            }
+        }
+        let email = BUILTIN_CHANNELS.iter().find(|b| b.key == "email").unwrap();
+        assert_eq!(email.compiled, cfg!(feature = "channel-email"));
+        assert_eq!(
+            compiled_out_builtins().any(|b| b.key == "nostr"),
+            !cfg!(feature = "channel-nostr")
+        );
+    }
+
+    #[tokio::test]
+    async fn plugins_show_in_wizard_and_build_from_config_table() {
+        register_channel_plugin(Arc::new(EchoPlugin("registry-test-echo"))).unwrap();
+
+        let entries = wizard_entries();
+        assert_eq!(entries.first().map(|e| e.key), Some("telegram"));
+        assert!(!entries.iter().any(|e| e.key == "mattermost"));
+        let plugin = entries
+            .iter()
+            .find(|e| e.key == "registry-test-echo")
+            .unwrap();
+        assert!(!plugin.builtin);
+
+        let mut config = Config::default();
+        assert!(!is_configured(
+            "registry-test-echo",
+            &config.channels_config
+        ));
+        config.channels_config.plugins.insert(
+            "registry-test-echo".into(),
+            serde_json::json!({"token": "x"}),
+        );
+        config
+            .channels_config
+            .plugins
+            .insert("registry-test-missing".into(), serde_json::json!({}));
+        assert!(is_configured("registry-test-echo", &config.channels_config));
+
+        let built = build_plugin_channels(&config).await.unwrap();
+        assert_eq!(built.len(), 1);
+        assert_eq!(built[0].0, "Echo");
+        assert_eq!(built[0].1.name(), "echo");
+
+        config.channels_config.plugins.insert(
+            "registry-test-echo".into(),
+            serde_json::json!({"fail": true}),
+        );
+        assert!(build_plugin_channels(&config).await.is_err());
+    }
+}
@@ -8,6 +8,7 @@ pub struct SlackChannel {
     bot_token: String,
     channel_id: Option<String>,
     allowed_users: Vec<String>,
+    quote_replies: bool,
 }
 
 impl SlackChannel {
@@ -16,9 +17,18 @@ impl SlackChannel {
             bot_token,
             channel_id,
             allowed_users,
+            quote_replies: true,
         }
     }
 
+    /// Thread replies to top-level messages under the triggering message
+    /// (default). When off, only messages already in a thread get threaded
+    /// replies.
+    pub fn with_quote_replies(mut self, quote_replies: bool) -> Self {
+        self.quote_replies = quote_replies;
+        self
+    }
+
     fn http_client(&self) -> reqwest::Client {
         crate::config::build_runtime_proxy_client("channel.slack")
     }
@@ -368,7 +378,10 @@ impl Channel for SlackChannel {
                                 .duration_since(std::time::UNIX_EPOCH)
                                 .unwrap_or_default()
                                 .as_secs(),
-                            thread_ts: Self::inbound_thread_ts(msg, ts),
+                            thread_ts: Self::inbound_thread_ts(
+                                msg,
+                                if self.quote_replies { ts } else { "" },
+                            ),
                         };
 
                         if tx.send(channel_msg).await.is_err() {
@@ -109,6 +109,23 @@ fn random_telegram_ack_reaction() -> &'static str {
     TELEGRAM_ACK_REACTIONS[pick_uniform_index(TELEGRAM_ACK_REACTIONS.len())]
 }
 
+/// Reactions bots may set with `setMessageReaction` (a subset of Telegram's
+/// fixed list), compared without the emoji variation selector.
+const TELEGRAM_REACTION_EMOJI: &[&str] = &[
+    "👍", "👎", "❤", "🔥", "🥰", "👏", "😁", "🤔", "🎉", "🙏", "👌", "💯", "🤣", "⚡", "🏆", "👀",
+    "🤝", "✍", "🫡", "😎", "🤗", "😇",
+];
+
+/// `emoji` when Telegram accepts it as a reaction, otherwise 👍.
+fn telegram_reaction_emoji(emoji: &str) -> &str {
+    let bare = emoji.trim_end_matches('\u{fe0f}');
+    TELEGRAM_REACTION_EMOJI
+        .iter()
+        .find(|allowed| **allowed == bare)
+        .copied()
+        .unwrap_or("👍")
+}
+
 fn build_telegram_ack_reaction_request(
     chat_id: &str,
     message_id: i64,
@@ -311,6 +328,8 @@ pub struct TelegramChannel {
     transcription: Option<crate::config::TranscriptionConfig>,
     voice_transcriptions: Mutex<std::collections::HashMap<String, String>>,
     workspace_dir: Option<std::path::PathBuf>,
+    quote_replies: bool,
+    lightweight_acks: bool,
 }
 
 impl TelegramChannel {
@@ -342,6 +361,8 @@ impl TelegramChannel {
             transcription: None,
             voice_transcriptions: Mutex::new(std::collections::HashMap::new()),
             workspace_dir: None,
+            quote_replies: false,
+            lightweight_acks: false,
         }
     }
 
@@ -362,6 +383,33 @@ impl TelegramChannel {
         self
     }
 
+    /// Quote the triggering message in replies (`reply_to_message_id`).
+    pub fn with_quote_replies(mut self, quote_replies: bool) -> Self {
+        self.quote_replies = quote_replies;
+        self
+    }
+
+    /// Send bare acknowledgment replies as reactions.
+    pub fn with_lightweight_acks(mut self, lightweight_acks: bool) -> Self {
+        self.lightweight_acks = lightweight_acks;
+        self
+    }
+
+    /// Telegram message ID `message` answers, when replies are quoted.
+    /// Inbound IDs have the form `telegram_<chat_id>_<message_id>`.
+    fn quoted_message_id(&self, message: &SendMessage) -> Option<i64> {
+        if !self.quote_replies {
+            return None;
+        }
+        message
+            .in_response_to
+            .as_deref()?
+            .rsplit_once('_')?
+            .1
+            .parse()
+            .ok()
+    }
+
     /// Override the Telegram Bot API base URL.
     /// Useful for local Bot API servers or testing.
     pub fn with_api_base(mut self, api_base: String) -> Self {
@@ -1519,6 +1567,7 @@ Allowlist Telegram username (without '@') or numeric user ID.",
         message: &str,
         chat_id: &str,
         thread_id: Option<&str>,
+        quoted_id: Option<i64>,
     ) -> anyhow::Result<()> {
         let chunks = split_message_for_telegram(message);
 
@@ -1545,6 +1594,12 @@ Allowlist Telegram username (without '@') or numeric user ID.",
             if let Some(tid) = thread_id {
                 markdown_body["message_thread_id"] = serde_json::Value::String(tid.to_string());
             }
+            // Quote the triggering message on the first chunk only
+            let reply_to = quoted_id.filter(|_| index == 0);
+            if let Some(id) = reply_to {
+                markdown_body["reply_to_message_id"] = serde_json::json!(id);
+                markdown_body["allow_sending_without_reply"] = serde_json::Value::Bool(true);
+            }
 
             let markdown_resp = self
                 .http_client()
@@ -1576,6 +1631,10 @@ Allowlist Telegram username (without '@') or numeric user ID.",
             if let Some(tid) = thread_id {
                 plain_body["message_thread_id"] = serde_json::Value::String(tid.to_string());
             }
+            if let Some(id) = reply_to {
+                plain_body["reply_to_message_id"] = serde_json::json!(id);
+                plain_body["allow_sending_without_reply"] = serde_json::Value::Bool(true);
+            }
             let plain_resp = self
                 .http_client()
                 .post(self.api_url("sendMessage"))
@@ -1690,7 +1749,7 @@ Allowlist Telegram username (without '@') or numeric user ID.",
                     TelegramAttachmentKind::Voice => "Voice",
                 };
                 let fallback_text = format!("{kind_label}: {target}");
-                self.send_text_chunks(&fallback_text, chat_id, thread_id)
+                self.send_text_chunks(&fallback_text, chat_id, thread_id, None)
                     .await?;
             }
 
@@ -2147,6 +2206,41 @@ impl Channel for TelegramChannel {
         self.stream_mode != StreamMode::Off
     }
 
+    fn sends_lightweight_acks(&self) -> bool {
+        self.lightweight_acks
+    }
+
+    async fn send_lightweight_ack(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+        emoji: &str,
+    ) -> anyhow::Result<bool> {
+        let (chat_id, _) = Self::parse_reply_target(channel_id);
+        let Some(message_id) = message_id
+            .rsplit_once('_')
+            .and_then(|(_, id)| id.parse::<i64>().ok())
+        else {
+            return Ok(false);
+        };
+        let body = build_telegram_ack_reaction_request(
+            &chat_id,
+            message_id,
+            telegram_reaction_emoji(emoji),
+        );
+        let resp = self
+            .http_client()
+            .post(self.api_url("setMessageReaction"))
+            .json(&body)
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            let err = resp.text().await.unwrap_or_default();
+            anyhow::bail!("Telegram setMessageReaction failed: {err}");
+        }
+        Ok(true)
+    }
+
     async fn send_draft(&self, message: &SendMessage) -> anyhow::Result<Option<String>> {
         if self.stream_mode == StreamMode::Off {
             return Ok(None);
@@ -2166,6 +2260,10 @@ impl Channel for TelegramChannel {
         if let Some(tid) = thread_id {
             body["message_thread_id"] = serde_json::Value::String(tid.to_string());
         }
+        if let Some(id) = self.quoted_message_id(message) {
+            body["reply_to_message_id"] = serde_json::json!(id);
+            body["allow_sending_without_reply"] = serde_json::Value::Bool(true);
+        }
 
         let resp = self
             .client
@@ -2303,7 +2401,7 @@ impl Channel for TelegramChannel {
 
             // Send text without markers
             if !text_without_markers.is_empty() {
-                self.send_text_chunks(&text_without_markers, &chat_id, thread_id.as_deref())
+                self.send_text_chunks(&text_without_markers, &chat_id, thread_id.as_deref(), None)
                     .await?;
             }
 
@@ -2332,13 +2430,13 @@ impl Channel for TelegramChannel {
 
             // Fall back to chunked send
             return self
-                .send_text_chunks(text, &chat_id, thread_id.as_deref())
+                .send_text_chunks(text, &chat_id, thread_id.as_deref(), None)
                 .await;
         }
 
         let Some(id) = msg_id else {
             return self
-                .send_text_chunks(text, &chat_id, thread_id.as_deref())
+                .send_text_chunks(text, &chat_id, thread_id.as_deref(), None)
                 .await;
         };
 
@@ -2381,7 +2479,7 @@ impl Channel for TelegramChannel {
 
         // Edit failed entirely — fall back to new message
         tracing::warn!("Telegram finalize_draft edit failed; falling back to sendMessage");
-        self.send_text_chunks(text, &chat_id, thread_id.as_deref())
+        self.send_text_chunks(text, &chat_id, thread_id.as_deref(), None)
             .await
     }
 
@@ -2426,11 +2524,12 @@ impl Channel for TelegramChannel {
             None => (message.recipient.as_str(), None),
         };
 
+        let reply_to = self.quoted_message_id(message);
         let (text_without_markers, attachments) = parse_attachment_markers(&content);
 
         if !attachments.is_empty() {
             if !text_without_markers.is_empty() {
-                self.send_text_chunks(&text_without_markers, chat_id, thread_id)
+                self.send_text_chunks(&text_without_markers, chat_id, thread_id, reply_to)
                     .await?;
             }
 
@@ -2447,7 +2546,8 @@ impl Channel for TelegramChannel {
             return Ok(());
         }
 
-        self.send_text_chunks(&content, chat_id, thread_id).await
+        self.send_text_chunks(&content, chat_id, thread_id, reply_to)
+            .await
     }
 
     async fn listen(&self, tx: tokio::sync::mpsc::Sender<ChannelMessage>) -> anyhow::Result<()> {
@@ -2795,6 +2895,27 @@ mod tests {
         assert_eq!(partial.draft_update_interval_ms, 750);
     }
 
+    #[test]
+    fn lightweight_ack_reactions_fall_back_to_thumbs_up() {
+        assert_eq!(telegram_reaction_emoji("🎉"), "🎉");
+        assert_eq!(telegram_reaction_emoji("❤\u{fe0f}"), "❤");
+        assert_eq!(telegram_reaction_emoji("✅"), "👍");
+    }
+
+    #[test]
+    fn quoted_message_id_parses_inbound_ids_when_enabled() {
+        let message = SendMessage::new("hi", "-100123").replying_to("telegram_-100123_42");
+        let ch = TelegramChannel::new("fake-token".into(), vec!["*".into()], false);
+        assert_eq!(ch.quoted_message_id(&message), None);
+
+        let ch = ch.with_quote_replies(true);
+        assert_eq!(ch.quoted_message_id(&message), Some(42));
+        assert_eq!(
+            ch.quoted_message_id(&SendMessage::new("hi", "-100123")),
+            None
+        );
+    }
+
     #[tokio::test]
     async fn send_draft_returns_none_when_stream_mode_off() {
         let ch = TelegramChannel::new("fake-token".into(), vec!["*".into()], false);
@@ -22,6 +22,9 @@ pub struct SendMessage {
     pub subject: Option<String>,
     /// Platform thread identifier for threaded replies (e.g. Slack `thread_ts`).
     pub thread_ts: Option<String>,
+    /// [`ChannelMessage::id`] of the message being answered. Channels with
+    /// `quote_replies` enabled attach the reply to it.
+    pub in_response_to: Option<String>,
 }
 
 impl SendMessage {
@@ -32,6 +35,7 @@ impl SendMessage {
             recipient: recipient.into(),
             subject: None,
             thread_ts: None,
+            in_response_to: None,
         }
     }
 
@@ -46,6 +50,7 @@ impl SendMessage {
             recipient: recipient.into(),
             subject: Some(subject.into()),
             thread_ts: None,
+            in_response_to: None,
         }
     }
 
@@ -54,6 +59,12 @@ impl SendMessage {
         self.thread_ts = thread_ts;
         self
     }
+
+    /// Mark this message as the answer to an inbound message.
+    pub fn replying_to(mut self, message_id: impl Into<String>) -> Self {
+        self.in_response_to = Some(message_id.into());
+        self
+    }
 }
 
 /// Core channel trait — implement for any messaging platform
@@ -89,6 +100,12 @@ pub trait Channel: Send + Sync {
         false
     }
 
+    /// Whether short acknowledgment replies should go out as reactions
+    /// (`lightweight_acks` in the channel's config).
+    fn sends_lightweight_acks(&self) -> bool {
+        false
+    }
+
     /// Send an initial draft message. Returns a platform-specific message ID for later edits.
     async fn send_draft(&self, _message: &SendMessage) -> anyhow::Result<Option<String>> {
         Ok(None)
@@ -133,6 +150,18 @@ pub trait Channel: Send + Sync {
         Ok(())
     }
 
+    /// React to `message_id` with `emoji` in place of a short text reply
+    /// (see [`Channel::sends_lightweight_acks`]). Returns `false` when the
+    /// channel has no native reactions; the caller then sends the text.
+    async fn send_lightweight_ack(
+        &self,
+        _channel_id: &str,
+        _message_id: &str,
+        _emoji: &str,
+    ) -> anyhow::Result<bool> {
+        Ok(false)
+    }
+
     /// Remove a reaction (emoji) from a message previously added by this bot.
     async fn remove_reaction(
         &self,
@@ -0,0 +1,110 @@
+//! Per-channel translation mode (`[channels_config.translation.<channel>]`).
+//!
+//! An inbound message is translated into the channel's `translate_to`
+//! language before the agent sees it; the reply is translated back into the
+//! language the sender wrote in. Translation failures fall back to the
+//! untranslated text so a flaky model never drops a message.
+
+use crate::config::ChannelTranslationConfig;
+use crate::providers::Provider;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+const TRANSLATION_TEMPERATURE: f64 = 0.0;
+
+/// Per-channel settings, keyed by channel name. Channels without an entry are
+/// not translated.
+pub(crate) type TranslationMap = BTreeMap<String, ChannelTranslationConfig>;
+
+#[derive(Debug, Deserialize)]
+struct InboundTranslation {
+    language: String,
+    translation: String,
+}
+
+/// Whether two language codes name the same language (`en` == `en-US`).
+fn same_language(a: &str, b: &str) -> bool {
+    let base = |code: &str| {
+        code.trim()
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase()
+    };
+    base(a) == base(b)
+}
+
+fn parse_inbound(raw: &str) -> Result<InboundTranslation> {
+    let trimmed = raw.trim();
+    let json = match (trimmed.find('{'), trimmed.rfind('}')) {
+        (Some(start), Some(end)) if start < end => &trimmed[start..=end],
+        _ => trimmed,
+    };
+    serde_json::from_str(json).context("translation reply was not the expected JSON")
+}
+
+/// Translate `text` into `target`. Returns the translated text and the
+/// sender's language, or `None` when the message is already in `target`.
+pub(crate) async fn translate_inbound(
+    provider: &dyn Provider,
+    model: &str,
+    target: &str,
+    text: &str,
+) -> Result<(String, Option<String>)> {
+    let system = format!(
+        "You translate chat messages. Detect the language of the user's message and \
+         translate it into the language with ISO 639-1 code `{target}`. Reply with JSON \
+         only: {{\"language\": \"<ISO 639-1 code of the original>\", \"translation\": \
+         \"<translated message>\"}}. If the message is already in `{target}`, copy it \
+         unchanged. Keep code, URLs and names as they are."
+    );
+    let raw = provider
+        .chat_with_system(Some(&system), text, model, TRANSLATION_TEMPERATURE)
+        .await?;
+    let parsed = parse_inbound(&raw)?;
+    if same_language(&parsed.language, target) || parsed.translation.trim().is_empty() {
+        return Ok((text.to_string(), None));
+    }
+    Ok((parsed.translation, Some(parsed.language.trim().to_string())))
+}
+
+/// Translate an agent reply back into the sender's `language`.
+pub(crate) async fn translate_outbound(
+    provider: &dyn Provider,
+    model: &str,
+    language: &str,
+    text: &str,
+) -> Result<String> {
+    let system = format!(
+        "Translate the user's message into the language with ISO 639-1 code `{language}`. \
+         Reply with the translation only. Keep Markdown formatting, code blocks, URLs \
+         and names unchanged."
+    );
+    let translated = provider
+        .chat_with_system(Some(&system), text, model, TRANSLATION_TEMPERATURE)
+        .await?;
+    if translated.trim().is_empty() {
+        anyhow::bail!("translation reply was empty");
+    }
+    Ok(translated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inbound_reply_parses_with_fences_and_matches_regional_codes() {
+        let parsed =
+            parse_inbound("```json\n{\"language\": \"ja\", \"translation\": \"Hello\"}\n```")
+                .unwrap();
+        assert_eq!(parsed.language, "ja");
+        assert_eq!(parsed.translation, "Hello");
+        assert!(parse_inbound("Hello").is_err());
+
+        assert!(same_language("en-US", "en"));
+        assert!(same_language("ZH_cn", "zh"));
+        assert!(!same_language("es", "en"));
+    }
+}
@@ -4,19 +4,30 @@ pub mod traits;
 #[allow(unused_imports)]
 pub use schema::{
     apply_runtime_proxy_to_builder, build_runtime_proxy_client,
-    build_runtime_proxy_client_with_timeouts, runtime_proxy_config, set_runtime_proxy_config,
-    AgentConfig, AuditConfig, AutonomyConfig, BrowserComputerUseConfig, BrowserConfig,
-    BuiltinHooksConfig, ChannelsConfig, ClassificationRule, ComposioConfig, Config, CostConfig,
-    CronConfig, DelegateAgentConfig, DiscordConfig, DockerRuntimeConfig, EmbeddingRouteConfig,
-    EstopConfig, FeishuConfig, GatewayConfig, HardwareConfig, HardwareTransport, HeartbeatConfig,
-    HooksConfig, HttpRequestConfig, IMessageConfig, IdentityConfig, LarkConfig, MatrixConfig,
-    MemoryConfig, ModelRouteConfig, MultimodalConfig, NextcloudTalkConfig, ObservabilityConfig,
-    OtpConfig, OtpMethod, PeripheralBoardConfig, PeripheralsConfig, ProxyConfig, ProxyScope,
-    QdrantConfig, QueryClassificationConfig, ReliabilityConfig, ResourceLimitsConfig,
-    RuntimeConfig, SandboxBackend, SandboxConfig, SchedulerConfig, SecretsConfig, SecurityConfig,
-    SkillsConfig, SkillsPromptInjectionMode, SlackConfig, StorageConfig, StorageProviderConfig,
-    StorageProviderSection, StreamMode, TelegramConfig, TranscriptionConfig, TunnelConfig,
-    WebFetchConfig, WebSearchConfig, WebhookConfig,
+    build_runtime_proxy_client_with_timeouts, config_json_schema, provider_request_query,
+    runtime_proxy_config, set_runtime_provider_requests, set_runtime_proxy_config, AgentConfig,
+    AuditConfig, AutonomyConfig, BackupConfig, BackupS3Config, BackupTargetKind,
+    BackupWebdavConfig, BehaviorConfig, BrowserComputerUseConfig, BrowserConfig,
+    BudgetDowngradeConfig, BuiltinHooksConfig, ChannelTranslationConfig, ChannelsConfig,
+    ClassificationRule, ComposioConfig, Config, ConnectivityConfig, CostConfig, CronConfig,
+    CustomRedactionPattern, DeadmanAction, DeadmanSwitchConfig, DelegateAgentConfig, DiscordConfig,
+    DockerRuntimeConfig, EmbeddingRouteConfig, EscalationAction, EscalationConfig, EstopConfig,
+    EstopNotifyTarget, FeishuConfig, GatewayConfig, GatewaySelftestConfig, HardwareConfig,
+    HardwareTransport, HeartbeatConfig, HooksConfig, HttpRequestConfig, IMessageConfig,
+    IdentityConfig, LarkConfig, MatrixConfig, MemoryCategoryConfig, MemoryClassificationConfig,
+    MemoryConfig, ModelRouteConfig, MultimodalConfig, NextcloudTalkConfig, NotifyEvent,
+    ObservabilityConfig, OtpConfig, OtpMethod, PeripheralBoardConfig, PeripheralNodeConfig,
+    PeripheralsConfig, PiiDetector, PowerConfig, PowerSource, ProviderProxyConfig,
+    ProviderRequestConfig, ProxyConfig, ProxyScope, QdrantConfig, QdrantTransport,
+    QueryClassificationConfig, RedactionConfig, RedactionPolicy, ReliabilityConfig,
+    RemoteNodeConfig, ResourceLimitsConfig, RuntimeConfig, SandboxBackend, SandboxConfig,
+    ScheduledJobConfig, SchedulerConfig, SchedulesConfig, SecretsConfig, SecurityConfig,
+    SelftestFailurePolicy, SensorAlertRule, SensorCalibration, SensorRecorderConfig,
+    ShutdownConfig, SkillsConfig, SkillsPromptInjectionMode, SlackConfig, SqliteConfig,
+    SqliteJournalMode, SqliteSynchronous, StorageConfig, StorageProviderConfig,
+    StorageProviderSection, StreamMode, TelegramConfig, ToolLimitConfig, TranscriptionConfig,
+    TunnelConfig, UiConfig, UiLocale, UiNotificationsConfig, UiTheme, WatchdogConfig,
+    WebFetchConfig, WebSearchConfig, WebhookConfig, WorkspaceSyncConfig, WorkspacesConfig,
 };
 
 pub fn name_and_presence<T: traits::ChannelConfig>(channel: &Option<T>) -> (&'static str, bool) {
@@ -45,6 +56,8 @@ mod tests {
             draft_update_interval_ms: 1000,
             interrupt_on_new_message: false,
             mention_only: false,
+            quote_replies: false,
+            lightweight_acks: false,
         };
 
         let discord = DiscordConfig {
@@ -53,6 +66,8 @@ mod tests {
             allowed_users: vec![],
             listen_to_bots: false,
             mention_only: false,
+            quote_replies: false,
+            lightweight_acks: false,
         };
 
         let lark = LarkConfig {
@@ -5,7 +5,7 @@ use anyhow::{Context, Result};
 use directories::UserDirs;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 use std::sync::{OnceLock, RwLock};
 #[cfg(unix)]
@@ -15,6 +15,7 @@ use tokio::io::AsyncWriteExt;
 
 const SUPPORTED_PROXY_SERVICE_KEYS: &[&str] = &[
     "provider.anthropic",
+    "provider.bedrock",
     "provider.compatible",
     "provider.copilot",
     "provider.gemini",
@@ -39,6 +40,7 @@ const SUPPORTED_PROXY_SERVICE_KEYS: &[&str] = &[
     "tool.composio",
     "tool.http_request",
     "tool.pushover",
+    "memory.backup",
     "memory.embeddings",
     "tunnel.custom",
     "transcription.groq",
@@ -54,6 +56,8 @@ const SUPPORTED_PROXY_SERVICE_SELECTORS: &[&str] = &[
 ];
 
 static RUNTIME_PROXY_CONFIG: OnceLock<RwLock<ProxyConfig>> = OnceLock::new();
+static RUNTIME_PROVIDER_REQUESTS: OnceLock<RwLock<HashMap<String, ProviderRequestConfig>>> =
+    OnceLock::new();
 static RUNTIME_PROXY_CLIENT_CACHE: OnceLock<RwLock<HashMap<String, reqwest::Client>>> =
     OnceLock::new();
 
@@ -83,6 +87,10 @@ pub struct Config {
     /// Optional named provider profiles keyed by id (Codex app-server compatible layout).
     #[serde(default)]
     pub model_providers: HashMap<String, ModelProviderConfig>,
+    /// Extra headers and query parameters sent with provider API requests,
+    /// keyed by provider HTTP service (`[provider_requests.openrouter]`).
+    #[serde(default)]
+    pub provider_requests: HashMap<String, ProviderRequestConfig>,
     /// Default model temperature (0.0–2.0). Default: `0.7`.
     pub default_temperature: f64,
 
@@ -138,6 +146,14 @@ pub struct Config {
     #[serde(default)]
     pub cron: CronConfig,
 
+    /// Recurring agent jobs declared in config (`[schedules]`).
+    #[serde(default)]
+    pub schedules: SchedulesConfig,
+
+    /// Named workspace selection from chat (`[workspaces]`).
+    #[serde(default)]
+    pub workspaces: WorkspacesConfig,
+
     /// Channel configurations: Telegram, Discord, Slack, etc. (`[channels_config]`).
     #[serde(default)]
     pub channels_config: ChannelsConfig,
@@ -217,6 +233,47 @@ pub struct Config {
     /// Voice transcription configuration (Whisper API via Groq).
     #[serde(default)]
     pub transcription: TranscriptionConfig,
+
+    /// Git-backed workspace sync (`[workspace_sync]`).
+    #[serde(default)]
+    pub workspace_sync: WorkspaceSyncConfig,
+
+    /// Graceful daemon shutdown on SIGTERM/SIGINT (`[shutdown]`).
+    #[serde(default)]
+    pub shutdown: ShutdownConfig,
+
+    /// Encrypted backups of config, workspace, and memory (`[backup]`).
+    #[serde(default)]
+    pub backup: BackupConfig,
+
+    /// UPS / battery monitoring and power-loss behaviour (`[power]`).
+    #[serde(default)]
+    pub power: PowerConfig,
+
+    /// Internet connectivity monitoring and offline behaviour (`[connectivity]`).
+    #[serde(default)]
+    pub connectivity: ConnectivityConfig,
+
+    /// Interactive UI behaviour such as attention signals (`[ui]`).
+    #[serde(default)]
+    pub ui: UiConfig,
+
+    /// PII redaction applied before conversation memory is persisted (`[redaction]`).
+    #[serde(default)]
+    pub redaction: RedactionConfig,
+
+    /// Conversation behavior adjustments (`[behavior]`).
+    #[serde(default)]
+    pub behavior: BehaviorConfig,
+
+    /// `env:NAME` references resolved on load, keyed by TOML path. `save`
+    /// writes the reference back instead of the resolved secret.
+    #[serde(skip)]
+    pub env_secret_refs: BTreeMap<Vec<String>, String>,
+
+    /// `--record-llm` directory for this run. Never read from or saved to the file.
+    #[serde(skip)]
+    pub record_llm_dir: Option<PathBuf>,
 }
 
 /// Named provider profile definition compatible with Codex app-server style config.
@@ -236,6 +293,90 @@ pub struct ModelProviderConfig {
     pub requires_openai_auth: bool,
 }
 
+/// Extra request data for one provider HTTP service (`[provider_requests.<service>]`).
+///
+/// `<service>` is the provider part of a `provider.*` proxy service key
+/// (`openrouter`, `anthropic`, `openai`, `compatible`, ...). Every
+/// OpenAI-compatible provider, including `custom:` URLs, shares `compatible`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ProviderRequestConfig {
+    /// Headers added to every request (e.g. `OpenAI-Organization`, `HTTP-Referer`,
+    /// `Proxy-Authorization`). Values may use `env:NAME`.
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// Query parameters appended to every request URL (e.g. Azure `api-version`).
+    #[serde(default)]
+    pub query: BTreeMap<String, String>,
+}
+
+/// Headers that carry provider credentials and must stay under `api_key` control.
+const RESERVED_PROVIDER_HEADERS: &[&str] = &["authorization", "x-api-key", "api-key"];
+
+impl ProviderRequestConfig {
+    fn validate(&self, service: &str) -> Result<()> {
+        if provider_request_service_key(service).is_none() {
+            let known: Vec<&str> = SUPPORTED_PROXY_SERVICE_KEYS
+                .iter()
+                .filter_map(|key| key.strip_prefix("provider."))
+                .collect();
+            anyhow::bail!(
+                "provider_requests.{service} is not a provider service; expected one of: {}",
+                known.join(", ")
+            );
+        }
+        for (name, value) in &self.headers {
+            let header = reqwest::header::HeaderName::from_bytes(name.trim().as_bytes())
+                .with_context(|| {
+                    format!(
+                        "provider_requests.{service}.headers: `{name}` is not a valid header name"
+                    )
+                })?;
+            if RESERVED_PROVIDER_HEADERS.contains(&header.as_str()) {
+                anyhow::bail!(
+                    "provider_requests.{service}.headers must not set `{name}`; \
+                     provider credentials come from api_key"
+                );
+            }
+            if reqwest::header::HeaderValue::from_str(value).is_err() {
+                anyhow::bail!(
+                    "provider_requests.{service}.headers.{name} is not a valid header value"
+                );
+            }
+        }
+        if self.query.keys().any(|key| key.trim().is_empty()) {
+            anyhow::bail!("provider_requests.{service}.query contains an empty parameter name");
+        }
+        Ok(())
+    }
+
+    fn header_map(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        for (name, value) in &self.headers {
+            if let (Ok(name), Ok(value)) = (
+                reqwest::header::HeaderName::from_bytes(name.trim().as_bytes()),
+                reqwest::header::HeaderValue::from_str(value),
+            ) {
+                headers.insert(name, value);
+            }
+        }
+        headers
+    }
+}
+
+/// `openrouter` or `provider.openrouter` → `provider.openrouter`, if it is a
+/// known provider service.
+fn provider_request_service_key(raw: &str) -> Option<String> {
+    let raw = raw.trim().to_ascii_lowercase();
+    let key = if raw.starts_with("provider.") {
+        raw
+    } else {
+        format!("provider.{raw}")
+    };
+    SUPPORTED_PROXY_SERVICE_KEYS
+        .contains(&key.as_str())
+        .then_some(key)
+}
+
 // ── Delegate Agents ──────────────────────────────────────────────
 
 /// Configuration for a delegate sub-agent used by the `delegate` tool.
@@ -308,6 +449,12 @@ pub struct HardwareConfig {
     /// Transport mode
     #[serde(default)]
     pub transport: HardwareTransport,
+    /// Board preset the wizard started from (e.g. "rpi5", "esp32-serial")
+    #[serde(default)]
+    pub board: Option<String>,
+    /// GPIO character device for native transport (e.g. "gpiochip0")
+    #[serde(default)]
+    pub gpio_chip: Option<String>,
     /// Serial port path (e.g. "/dev/ttyACM0")
     #[serde(default)]
     pub serial_port: Option<String>,
@@ -338,6 +485,8 @@ impl Default for HardwareConfig {
         Self {
             enabled: false,
             transport: HardwareTransport::None,
+            board: None,
+            gpio_chip: None,
             serial_port: None,
             baud_rate: default_baud_rate(),
             probe_target: None,
@@ -411,6 +560,86 @@ pub struct AgentConfig {
     /// Tool dispatch strategy (e.g. `"auto"`). Default: `"auto"`.
     #[serde(default = "default_agent_tool_dispatcher")]
     pub tool_dispatcher: String,
+    /// Dry-run mode: tools describe what they would do instead of executing.
+    /// Default: `false`.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Per-tool execution budgets and cooldowns, keyed by tool name
+    /// (`[agent.tool_limits.<tool>]`). Tools without an entry are unlimited.
+    #[serde(default)]
+    pub tool_limits: HashMap<String, ToolLimitConfig>,
+    /// Cancel stalled provider requests and tool calls (`[agent.watchdog]`).
+    #[serde(default)]
+    pub watchdog: WatchdogConfig,
+}
+
+/// Execution budget for a single tool (`[agent.tool_limits.<tool>]`).
+///
+/// Unset fields impose no limit. Refused calls return an error result to the
+/// model instead of running the tool.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ToolLimitConfig {
+    /// Maximum calls within one user turn.
+    #[serde(default)]
+    pub max_per_turn: Option<u32>,
+    /// Maximum calls over the lifetime of the session: the CLI session, or
+    /// one channel conversation for as long as the daemon runs.
+    #[serde(default)]
+    pub max_per_session: Option<u32>,
+    /// Maximum calls within any rolling 60-minute window, across all
+    /// conversations.
+    #[serde(default)]
+    pub max_per_hour: Option<u32>,
+    /// Minimum seconds between two calls, across all conversations.
+    #[serde(default)]
+    pub cooldown_secs: Option<u64>,
+}
+
+/// Per-turn watchdog timers (`[agent.watchdog]`).
+///
+/// A provider request or tool call that makes no progress for its limit is
+/// cancelled, the user is told on the channel, and the incident is appended
+/// to `state/watchdog.jsonl` in the workspace. Streamed chunks count as
+/// progress. A limit of `0` disables it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct WatchdogConfig {
+    /// Enable the watchdog. Default: `false`.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Seconds a provider request may go without progress before it is
+    /// cancelled. Default: `180`.
+    #[serde(default = "default_watchdog_generation_timeout_secs")]
+    pub generation_timeout_secs: u64,
+    /// Seconds a tool call may go without progress before it is cancelled.
+    /// Default: `300`.
+    #[serde(default = "default_watchdog_tool_timeout_secs")]
+    pub tool_timeout_secs: u64,
+    /// Generation limits by provider name, overriding `generation_timeout_secs`.
+    #[serde(default)]
+    pub provider_timeouts: HashMap<String, u64>,
+    /// Limits by tool name, overriding `tool_timeout_secs`.
+    #[serde(default)]
+    pub tool_timeouts: HashMap<String, u64>,
+}
+
+fn default_watchdog_generation_timeout_secs() -> u64 {
+    180
+}
+
+fn default_watchdog_tool_timeout_secs() -> u64 {
+    300
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            generation_timeout_secs: default_watchdog_generation_timeout_secs(),
+            tool_timeout_secs: default_watchdog_tool_timeout_secs(),
+            provider_timeouts: HashMap::new(),
+            tool_timeouts: HashMap::new(),
+        }
+    }
 }
 
 fn default_agent_max_tool_iterations() -> usize {
@@ -433,6 +662,9 @@ impl Default for AgentConfig {
             max_history_messages: default_agent_max_history_messages(),
             parallel_tools: false,
             tool_dispatcher: default_agent_tool_dispatcher(),
+            dry_run: false,
+            tool_limits: HashMap::new(),
+            watchdog: WatchdogConfig::default(),
         }
     }
 }
@@ -584,6 +816,62 @@ pub struct CostConfig {
     /// Per-model pricing (USD per 1M tokens)
     #[serde(default)]
     pub prices: std::collections::HashMap<String, ModelPricing>,
+
+    /// Switch channel replies to a cheaper model near the monthly limit
+    /// (`[cost.downgrade]`).
+    #[serde(default)]
+    pub downgrade: BudgetDowngradeConfig,
+}
+
+/// Budget-driven model downgrade (`[cost.downgrade]`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BudgetDowngradeConfig {
+    /// Enable the downgrade policy. Requires `[cost] enabled = true`. Default: `false`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Percentage of `monthly_limit_usd` at which replies switch models. Default: `80`.
+    #[serde(default = "default_downgrade_threshold_percent")]
+    pub threshold_percent: u8,
+
+    /// Provider for the cheaper model. Unset keeps the routed provider.
+    #[serde(default)]
+    pub provider: Option<String>,
+
+    /// Cheaper model to answer with once the threshold is crossed.
+    #[serde(default)]
+    pub model: Option<String>,
+
+    /// Channel that receives the owner notice (e.g. `"telegram"`).
+    #[serde(default)]
+    pub notify_channel: Option<String>,
+
+    /// Recipient on `notify_channel` (chat ID, user ID, or channel ID).
+    #[serde(default)]
+    pub notify_to: Option<String>,
+
+    /// Sender IDs allowed to bypass the downgrade with `!force-model`, matched
+    /// exactly. `"*"` is rejected.
+    #[serde(default)]
+    pub force_model_users: Vec<String>,
+}
+
+fn default_downgrade_threshold_percent() -> u8 {
+    80
+}
+
+impl Default for BudgetDowngradeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold_percent: default_downgrade_threshold_percent(),
+            provider: None,
+            model: None,
+            notify_channel: None,
+            notify_to: None,
+            force_model_users: Vec::new(),
+        }
+    }
 }
 
 /// Per-model pricing entry (USD per 1M tokens).
@@ -619,6 +907,7 @@ impl Default for CostConfig {
             warn_at_percent: default_warn_percent(),
             allow_override: false,
             prices: get_default_pricing(),
+            downgrade: BudgetDowngradeConfig::default(),
         }
     }
 }
@@ -716,6 +1005,120 @@ pub struct PeripheralsConfig {
     /// Place .md/.txt files named by board (e.g. nucleo-f401re.md, rpi-gpio.md).
     #[serde(default)]
     pub datasheet_dir: Option<String>,
+    /// Time-series recording of calibrated sensor readings.
+    #[serde(default)]
+    pub recorder: SensorRecorderConfig,
+    /// Serve this instance's boards to other ZeroClaw instances.
+    #[serde(default)]
+    pub node: PeripheralNodeConfig,
+}
+
+/// Expose local boards as a remote node (`[peripherals.node]`).
+///
+/// Another ZeroClaw adds this instance as a board with `transport = "http"`
+/// (via the gateway's `POST /api/peripherals/rpc`) or `transport = "mqtt"`
+/// (requests on `<topic>/request`, replies on `<topic>/response`).
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
+pub struct PeripheralNodeConfig {
+    /// Answer peripheral requests on the gateway (needs pairing)
+    #[serde(default)]
+    pub serve_http: bool,
+    /// MQTT broker to serve requests from, e.g. "mqtt://broker.local:1883"
+    #[serde(default)]
+    pub mqtt_broker: Option<String>,
+    /// MQTT topic prefix for this node (default "zeroclaw/node")
+    #[serde(default)]
+    pub mqtt_topic: Option<String>,
+    /// Broker password for the node's MQTT login, if the broker needs one
+    #[serde(default)]
+    pub mqtt_password: Option<String>,
+}
+
+/// Sensor time-series recorder (`[peripherals.recorder]`).
+///
+/// When enabled, the daemon samples every `[[peripherals.boards.sensors]]`
+/// pin on serial boards each `interval_secs` into
+/// `workspace/sensors/readings.db`. Query with `zeroclaw sensors query`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SensorRecorderConfig {
+    /// Enable periodic sampling under `zeroclaw daemon`
+    #[serde(default)]
+    pub enabled: bool,
+    /// Seconds between samples
+    #[serde(default = "default_sensor_interval_secs")]
+    pub interval_secs: u64,
+    /// Readings older than this many days are deleted
+    #[serde(default = "default_sensor_retention_days")]
+    pub retention_days: u32,
+    /// Alert rules evaluated after every sample
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alerts: Vec<SensorAlertRule>,
+}
+
+/// An alert on one recorded sensor (`[[peripherals.recorder.alerts]]`).
+///
+/// The alarm fires when any configured condition holds, notifies once, and
+/// clears only after the value is back inside the limit by `hysteresis`.
+/// Unacknowledged alarms are repeated every `renotify_mins`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct SensorAlertRule {
+    /// Unique rule name, used by `zeroclaw sensors ack`
+    pub name: String,
+    /// Board the sensor belongs to (any board when unset)
+    #[serde(default)]
+    pub board: Option<String>,
+    /// Sensor pin
+    pub pin: u32,
+    /// Fire when the calibrated value rises above this
+    #[serde(default)]
+    pub above: Option<f64>,
+    /// Fire when the calibrated value falls below this
+    #[serde(default)]
+    pub below: Option<f64>,
+    /// Fire when the value changes faster than this many units per minute
+    #[serde(default)]
+    pub max_rate_per_min: Option<f64>,
+    /// Fire when no reading has arrived for this many seconds
+    #[serde(default)]
+    pub stale_after_secs: Option<u64>,
+    /// How far back inside a limit the value must come before clearing
+    #[serde(default)]
+    pub hysteresis: f64,
+    /// Repeat an unacknowledged alarm after this many minutes (0 = never)
+    #[serde(default = "default_alert_renotify_mins")]
+    pub renotify_mins: u64,
+    /// Delivery channel (falls back to `[heartbeat].target`)
+    #[serde(default)]
+    pub channel: Option<String>,
+    /// Delivery recipient (falls back to `[heartbeat].to`)
+    #[serde(default)]
+    pub to: Option<String>,
+    /// Cron job id to run when the alarm fires
+    #[serde(default)]
+    pub run_job: Option<String>,
+}
+
+fn default_alert_renotify_mins() -> u64 {
+    30
+}
+
+fn default_sensor_interval_secs() -> u64 {
+    60
+}
+
+fn default_sensor_retention_days() -> u32 {
+    30
+}
+
+impl Default for SensorRecorderConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_secs: default_sensor_interval_secs(),
+            retention_days: default_sensor_retention_days(),
+            alerts: Vec::new(),
+        }
+    }
 }
 
 /// Configuration for a single peripheral board (e.g. STM32, RPi GPIO).
@@ -732,6 +1135,71 @@ pub struct PeripheralBoardConfig {
     /// Baud rate for serial (default: 115200)
     #[serde(default = "default_peripheral_baud")]
     pub baud: u32,
+    /// Calibration for analog sensors on this board, one entry per pin.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sensors: Vec<SensorCalibration>,
+    /// Connection to a remote node, for `transport = "http"` or `"mqtt"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<RemoteNodeConfig>,
+}
+
+/// A board reached through another ZeroClaw or a networked firmware
+/// (`[peripherals.boards.remote]`).
+///
+/// Requests use the serial JSON protocol (`{"id","cmd","args"}`); the node's
+/// tools are registered locally as `<board>_<tool>`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct RemoteNodeConfig {
+    /// Node base URL ("http://kitchen.local:42617") or MQTT broker
+    /// ("mqtt://broker.local:1883")
+    pub url: String,
+    /// Bearer token (HTTP, the node's paired gateway token) or broker
+    /// password (MQTT)
+    #[serde(default)]
+    pub token: Option<String>,
+    /// MQTT topic prefix of the node (default "zeroclaw/node")
+    #[serde(default)]
+    pub topic: Option<String>,
+    /// Seconds to wait for each reply
+    #[serde(default = "default_remote_node_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+fn default_remote_node_timeout_secs() -> u64 {
+    10
+}
+
+/// Calibration for one analog sensor pin (`[[peripherals.boards.sensors]]`).
+///
+/// A raw ADC reading `x` becomes `offset + scale * x`, or the polynomial
+/// `c0 + c1*x + c2*x^2 + ...` when `polynomial` is set. The result is in
+/// `unit`, then converted to `display_unit` when both are known units.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct SensorCalibration {
+    /// ADC pin/channel the sensor is wired to
+    pub pin: u32,
+    /// Human-readable name (e.g. "greenhouse temperature")
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Added after scaling
+    #[serde(default)]
+    pub offset: f64,
+    /// Multiplier applied to the raw reading
+    #[serde(default = "default_sensor_scale")]
+    pub scale: f64,
+    /// Polynomial coefficients, lowest order first; overrides offset/scale
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub polynomial: Vec<f64>,
+    /// Unit of the calibrated value (e.g. "C", "kPa", "V")
+    #[serde(default)]
+    pub unit: Option<String>,
+    /// Unit to report instead of `unit` (e.g. "F")
+    #[serde(default)]
+    pub display_unit: Option<String>,
+}
+
+fn default_sensor_scale() -> f64 {
+    1.0
 }
 
 fn default_peripheral_transport() -> String {
@@ -749,6 +1217,8 @@ impl Default for PeripheralBoardConfig {
             transport: default_peripheral_transport(),
             path: None,
             baud: default_peripheral_baud(),
+            sensors: Vec::new(),
+            remote: None,
         }
     }
 }
@@ -800,6 +1270,53 @@ pub struct GatewayConfig {
     /// Maximum distinct idempotency keys retained in memory.
     #[serde(default = "default_gateway_idempotency_max_keys")]
     pub idempotency_max_keys: usize,
+
+    /// Startup self-test run by `zeroclaw gateway` and `zeroclaw daemon`
+    /// (`[gateway.selftest]`).
+    #[serde(default)]
+    pub selftest: GatewaySelftestConfig,
+}
+
+/// What to do when a startup self-test check fails.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SelftestFailurePolicy {
+    /// Start anyway and report the failed checks as degraded.
+    #[default]
+    Degrade,
+    /// Refuse to start.
+    Abort,
+}
+
+/// Startup self-test (`[gateway.selftest]`): config validity, memory
+/// backend, provider auth, and each channel token, checked once before the
+/// gateway starts. Skip a run with `--skip-selftest`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GatewaySelftestConfig {
+    /// Run the self-test on start. Default: `true`.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// `degrade` (default) starts with failures reported; `abort` refuses to start.
+    #[serde(default)]
+    pub on_failure: SelftestFailurePolicy,
+    /// Channel that receives the startup summary (e.g. `"telegram"`).
+    /// Unset: the `[[security.estop.notify]]` owner targets.
+    #[serde(default)]
+    pub notify_channel: Option<String>,
+    /// Recipient on `notify_channel` (chat ID, channel ID, ...).
+    #[serde(default)]
+    pub notify_to: Option<String>,
+}
+
+impl Default for GatewaySelftestConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            on_failure: SelftestFailurePolicy::default(),
+            notify_channel: None,
+            notify_to: None,
+        }
+    }
 }
 
 fn default_gateway_port() -> u16 {
@@ -848,6 +1365,7 @@ impl Default for GatewayConfig {
             rate_limit_max_keys: default_gateway_rate_limit_max_keys(),
             idempotency_ttl_secs: default_idempotency_ttl_secs(),
             idempotency_max_keys: default_gateway_idempotency_max_keys(),
+            selftest: GatewaySelftestConfig::default(),
         }
     }
 }
@@ -1025,6 +1543,11 @@ pub struct HttpRequestConfig {
     /// Request timeout in seconds (default: 30)
     #[serde(default = "default_http_timeout_secs")]
     pub timeout_secs: u64,
+    /// Response content types whose bodies are returned to the model
+    /// (`text/*`, `application/*+json`, exact types; `["*"]` = any).
+    /// Other bodies are withheld.
+    #[serde(default = "default_http_allowed_content_types")]
+    pub allowed_content_types: Vec<String>,
 }
 
 impl Default for HttpRequestConfig {
@@ -1034,6 +1557,7 @@ impl Default for HttpRequestConfig {
             allowed_domains: vec![],
             max_response_size: default_http_max_response_size(),
             timeout_secs: default_http_timeout_secs(),
+            allowed_content_types: default_http_allowed_content_types(),
         }
     }
 }
@@ -1046,6 +1570,20 @@ fn default_http_timeout_secs() -> u64 {
     30
 }
 
+fn default_http_allowed_content_types() -> Vec<String> {
+    [
+        "text/*",
+        "application/json",
+        "application/*+json",
+        "application/xml",
+        "application/*+xml",
+        "application/x-ndjson",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect()
+}
+
 // ── Web fetch ────────────────────────────────────────────────────
 
 /// Web fetch tool configuration (`[web_fetch]` section).
@@ -1178,6 +1716,19 @@ pub struct ProxyConfig {
     /// Service selectors used when scope = "services".
     #[serde(default)]
     pub services: Vec<String>,
+    /// PEM CA bundle trusted in addition to the built-in roots (TLS-inspecting
+    /// corporate proxies, self-hosted inference). Applies even when `enabled = false`.
+    #[serde(default)]
+    pub ca_bundle: Option<String>,
+    /// PEM client certificate presented for mutual TLS. Requires `client_key`.
+    #[serde(default)]
+    pub client_cert: Option<String>,
+    /// PEM private key matching `client_cert`.
+    #[serde(default)]
+    pub client_key: Option<String>,
+    /// Per-provider overrides keyed by provider id (`[proxy.providers.<id>]`).
+    #[serde(default)]
+    pub providers: BTreeMap<String, ProviderProxyConfig>,
 }
 
 impl Default for ProxyConfig {
@@ -1190,10 +1741,38 @@ impl Default for ProxyConfig {
             no_proxy: Vec::new(),
             scope: ProxyScope::Zeroclaw,
             services: Vec::new(),
+            ca_bundle: None,
+            client_cert: None,
+            client_key: None,
+            providers: BTreeMap::new(),
         }
     }
 }
 
+/// Network override for one provider client family (`[proxy.providers.<id>]`),
+/// matched against the `provider.<id>` service key. OpenAI-compatible providers
+/// (groq, mistral, ...) share the `compatible` client. Unset fields fall back
+/// to `[proxy]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ProviderProxyConfig {
+    /// Proxy URL for this provider only (http, https, socks5, socks5h).
+    /// Used even when the global proxy is disabled.
+    #[serde(default)]
+    pub proxy: Option<String>,
+    /// Connect directly, bypassing every configured proxy.
+    #[serde(default)]
+    pub direct: bool,
+    /// PEM CA bundle for this provider; replaces the global `ca_bundle`.
+    #[serde(default)]
+    pub ca_bundle: Option<String>,
+    /// PEM client certificate for mutual TLS with this provider.
+    #[serde(default)]
+    pub client_cert: Option<String>,
+    /// PEM private key matching `client_cert`.
+    #[serde(default)]
+    pub client_key: Option<String>,
+}
+
 impl ProxyConfig {
     pub fn supported_service_keys() -> &'static [&'static str] {
         SUPPORTED_PROXY_SERVICE_KEYS
@@ -1236,6 +1815,29 @@ impl ProxyConfig {
             }
         }
 
+        validate_client_identity_pair(
+            "proxy",
+            self.client_cert.as_deref(),
+            self.client_key.as_deref(),
+        )?;
+        for (provider, entry) in &self.providers {
+            let section = format!("proxy.providers.{provider}");
+            if !provider_proxy_families().any(|family| family.eq_ignore_ascii_case(provider)) {
+                anyhow::bail!(
+                    "Unsupported provider override [{section}]. Overrides apply per client family: {}. OpenAI-compatible providers such as groq use [proxy.providers.compatible]",
+                    provider_proxy_families().collect::<Vec<_>>().join(", ")
+                );
+            }
+            if let Some(url) = normalize_proxy_url_option(entry.proxy.as_deref()) {
+                validate_proxy_url(&format!("{section}.proxy"), &url)?;
+            }
+            validate_client_identity_pair(
+                &section,
+                entry.client_cert.as_deref(),
+                entry.client_key.as_deref(),
+            )?;
+        }
+
         if self.enabled && !self.has_any_proxy_url() {
             anyhow::bail!(
                 "Proxy is enabled but no proxy URL is configured. Set at least one of http_proxy, https_proxy, or all_proxy"
@@ -1275,13 +1877,48 @@ impl ProxyConfig {
         }
     }
 
+    /// Override for `service_key` when it names a provider with a
+    /// `[proxy.providers.<id>]` entry.
+    pub fn provider_override(&self, service_key: &str) -> Option<&ProviderProxyConfig> {
+        let provider = service_key.trim().strip_prefix("provider.")?;
+        self.providers
+            .iter()
+            .find(|(id, _)| id.eq_ignore_ascii_case(provider))
+            .map(|(_, entry)| entry)
+    }
+
+    /// Apply TLS material and the proxy selected for `service_key`. Fails when
+    /// a configured CA bundle or client certificate cannot be loaded.
     pub fn apply_to_reqwest_builder(
         &self,
         mut builder: reqwest::ClientBuilder,
         service_key: &str,
-    ) -> reqwest::ClientBuilder {
+    ) -> Result<reqwest::ClientBuilder> {
+        let provider_override = self.provider_override(service_key);
+        builder = self.apply_tls_to_reqwest_builder(builder, provider_override)?;
+
+        if let Some(entry) = provider_override {
+            if entry.direct {
+                return Ok(builder.no_proxy());
+            }
+            if let Some(url) = normalize_proxy_url_option(entry.proxy.as_deref()) {
+                match reqwest::Proxy::all(&url) {
+                    Ok(proxy) => {
+                        return Ok(builder.proxy(apply_no_proxy(proxy, self.no_proxy_value())));
+                    }
+                    Err(error) => {
+                        tracing::warn!(
+                            proxy_url = %url,
+                            service_key,
+                            "Ignoring invalid provider proxy URL: {error}"
+                        );
+                    }
+                }
+            }
+        }
+
         if !self.should_apply_to_service(service_key) {
-            return builder;
+            return Ok(builder);
         }
 
         let no_proxy = self.no_proxy_value();
@@ -1331,7 +1968,41 @@ impl ProxyConfig {
             }
         }
 
-        builder
+        Ok(builder)
+    }
+
+    fn apply_tls_to_reqwest_builder(
+        &self,
+        mut builder: reqwest::ClientBuilder,
+        provider_override: Option<&ProviderProxyConfig>,
+    ) -> Result<reqwest::ClientBuilder> {
+        let ca_bundle = provider_override
+            .and_then(|entry| normalize_proxy_url_option(entry.ca_bundle.as_deref()))
+            .or_else(|| normalize_proxy_url_option(self.ca_bundle.as_deref()));
+        if let Some(path) = ca_bundle {
+            let certificates = load_ca_bundle(&path)
+                .with_context(|| format!("Failed to load CA bundle '{path}'"))?;
+            for certificate in certificates {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+
+        let (cert, key) = match provider_override {
+            Some(entry) if normalize_proxy_url_option(entry.client_cert.as_deref()).is_some() => {
+                (entry.client_cert.as_deref(), entry.client_key.as_deref())
+            }
+            _ => (self.client_cert.as_deref(), self.client_key.as_deref()),
+        };
+        if let (Some(cert), Some(key)) = (
+            normalize_proxy_url_option(cert),
+            normalize_proxy_url_option(key),
+        ) {
+            let identity = load_client_identity(&cert, &key)
+                .with_context(|| format!("Failed to load client certificate '{cert}'"))?;
+            builder = builder.identity(identity);
+        }
+
+        Ok(builder)
     }
 
     pub fn apply_to_process_env(&self) {
@@ -1449,18 +2120,64 @@ fn validate_proxy_url(field: &str, url: &str) -> Result<()> {
     Ok(())
 }
 
-fn set_proxy_env_pair(key: &str, value: Option<&str>) {
-    let lowercase_key = key.to_ascii_lowercase();
-    if let Some(value) = value.and_then(|candidate| normalize_proxy_url_option(Some(candidate))) {
-        std::env::set_var(key, &value);
-        std::env::set_var(lowercase_key, value);
-    } else {
-        std::env::remove_var(key);
-        std::env::remove_var(lowercase_key);
+/// Provider ids that `[proxy.providers.<id>]` can name: the client families
+/// behind the `provider.*` service keys.
+fn provider_proxy_families() -> impl Iterator<Item = &'static str> {
+    SUPPORTED_PROXY_SERVICE_KEYS
+        .iter()
+        .filter_map(|key| key.strip_prefix("provider."))
+}
+
+fn validate_client_identity_pair(
+    section: &str,
+    cert: Option<&str>,
+    key: Option<&str>,
+) -> Result<()> {
+    match (
+        normalize_proxy_url_option(cert),
+        normalize_proxy_url_option(key),
+    ) {
+        (Some(_), None) => anyhow::bail!("{section}.client_cert is set but client_key is missing"),
+        (None, Some(_)) => anyhow::bail!("{section}.client_key is set but client_cert is missing"),
+        _ => Ok(()),
     }
 }
 
-fn clear_proxy_env_pair(key: &str) {
+fn read_pem_file(path: &str) -> Result<Vec<u8>> {
+    let expanded = shellexpand::tilde(path);
+    std::fs::read(expanded.as_ref()).with_context(|| format!("Failed to read {expanded}"))
+}
+
+fn load_ca_bundle(path: &str) -> Result<Vec<reqwest::Certificate>> {
+    let pem = read_pem_file(path)?;
+    let certificates =
+        reqwest::Certificate::from_pem_bundle(&pem).context("Invalid PEM CA bundle")?;
+    if certificates.is_empty() {
+        anyhow::bail!("no certificates found");
+    }
+    Ok(certificates)
+}
+
+/// rustls expects the key and certificate chain in a single PEM buffer.
+fn load_client_identity(cert_path: &str, key_path: &str) -> Result<reqwest::Identity> {
+    let mut pem = read_pem_file(key_path)?;
+    pem.push(b'\n');
+    pem.extend(read_pem_file(cert_path)?);
+    reqwest::Identity::from_pem(&pem).context("Invalid PEM client certificate or key")
+}
+
+fn set_proxy_env_pair(key: &str, value: Option<&str>) {
+    let lowercase_key = key.to_ascii_lowercase();
+    if let Some(value) = value.and_then(|candidate| normalize_proxy_url_option(Some(candidate))) {
+        std::env::set_var(key, &value);
+        std::env::set_var(lowercase_key, value);
+    } else {
+        std::env::remove_var(key);
+        std::env::remove_var(lowercase_key);
+    }
+}
+
+fn clear_proxy_env_pair(key: &str) {
     std::env::remove_var(key);
     std::env::remove_var(key.to_ascii_lowercase());
 }
@@ -1539,10 +2256,59 @@ pub fn runtime_proxy_config() -> ProxyConfig {
     }
 }
 
+fn runtime_provider_requests() -> &'static RwLock<HashMap<String, ProviderRequestConfig>> {
+    RUNTIME_PROVIDER_REQUESTS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Install `[provider_requests]` for the shared provider HTTP clients.
+/// Invalid entries are skipped with a warning.
+pub fn set_runtime_provider_requests<S: std::hash::BuildHasher>(
+    requests: &HashMap<String, ProviderRequestConfig, S>,
+) {
+    let mut normalized = HashMap::new();
+    for (service, request) in requests {
+        if let Err(error) = request.validate(service) {
+            tracing::warn!("Ignoring invalid provider request config: {error}");
+            continue;
+        }
+        if let Some(key) = provider_request_service_key(service) {
+            normalized.insert(key, request.clone());
+        }
+    }
+    match runtime_provider_requests().write() {
+        Ok(mut guard) => *guard = normalized,
+        Err(poisoned) => *poisoned.into_inner() = normalized,
+    }
+
+    clear_runtime_proxy_client_cache();
+}
+
+fn runtime_provider_request(service_key: &str) -> Option<ProviderRequestConfig> {
+    let key = service_key.trim().to_ascii_lowercase();
+    match runtime_provider_requests().read() {
+        Ok(guard) => guard.get(&key).cloned(),
+        Err(poisoned) => poisoned.into_inner().get(&key).cloned(),
+    }
+}
+
+/// Query parameters from `[provider_requests]` for `service_key`, for
+/// `RequestBuilder::query`. Empty when none are configured.
+pub fn provider_request_query(service_key: &str) -> Vec<(String, String)> {
+    runtime_provider_request(service_key)
+        .map(|request| request.query.into_iter().collect())
+        .unwrap_or_default()
+}
+
 pub fn apply_runtime_proxy_to_builder(
     builder: reqwest::ClientBuilder,
     service_key: &str,
-) -> reqwest::ClientBuilder {
+) -> Result<reqwest::ClientBuilder> {
+    let builder = match runtime_provider_request(service_key) {
+        Some(request) if !request.headers.is_empty() => {
+            builder.default_headers(request.header_map())
+        }
+        _ => builder,
+    };
     runtime_proxy_config().apply_to_reqwest_builder(builder, service_key)
 }
 
@@ -1552,13 +2318,19 @@ pub fn build_runtime_proxy_client(service_key: &str) -> reqwest::Client {
         return client;
     }
 
-    let builder = apply_runtime_proxy_to_builder(reqwest::Client::builder(), service_key);
-    let client = builder.build().unwrap_or_else(|error| {
-        tracing::warn!(service_key, "Failed to build proxied client: {error}");
-        reqwest::Client::new()
-    });
-    set_runtime_proxy_cached_client(cache_key, client.clone());
-    client
+    let client = apply_runtime_proxy_to_builder(reqwest::Client::builder(), service_key)
+        .and_then(|builder| Ok(builder.build()?));
+    match client {
+        Ok(client) => {
+            set_runtime_proxy_cached_client(cache_key, client.clone());
+            client
+        }
+        Err(error) => {
+            // Not cached, so the next call retries once the TLS files are fixed.
+            tracing::error!(service_key, "Failed to build proxied client: {error:#}");
+            reqwest::Client::new()
+        }
+    }
 }
 
 pub fn build_runtime_proxy_client_with_timeouts(
@@ -1575,16 +2347,21 @@ pub fn build_runtime_proxy_client_with_timeouts(
     let builder = reqwest::Client::builder()
         .timeout(std::time::Duration::from_secs(timeout_secs))
         .connect_timeout(std::time::Duration::from_secs(connect_timeout_secs));
-    let builder = apply_runtime_proxy_to_builder(builder, service_key);
-    let client = builder.build().unwrap_or_else(|error| {
-        tracing::warn!(
-            service_key,
-            "Failed to build proxied timeout client: {error}"
-        );
-        reqwest::Client::new()
-    });
-    set_runtime_proxy_cached_client(cache_key, client.clone());
-    client
+    let client = apply_runtime_proxy_to_builder(builder, service_key)
+        .and_then(|builder| Ok(builder.build()?));
+    match client {
+        Ok(client) => {
+            set_runtime_proxy_cached_client(cache_key, client.clone());
+            client
+        }
+        Err(error) => {
+            tracing::error!(
+                service_key,
+                "Failed to build proxied timeout client: {error:#}"
+            );
+            reqwest::Client::new()
+        }
+    }
 }
 
 fn parse_proxy_scope(raw: &str) -> Option<ProxyScope> {
@@ -1671,10 +2448,6 @@ impl Default for StorageProviderConfig {
     }
 }
 
-/// Memory backend configuration (`[memory]` section).
-///
-/// Controls conversation memory storage, embeddings, hybrid search, response caching,
-/// and memory snapshot/hydration.
 /// Configuration for Qdrant vector database backend (`[memory.qdrant]`).
 /// Used when `[memory].backend = "qdrant"`.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -1691,22 +2464,271 @@ pub struct QdrantConfig {
     /// Falls back to `QDRANT_API_KEY` env var if not set.
     #[serde(default)]
     pub api_key: Option<String>,
+    /// Transport for upserts: "rest" (default) | "grpc".
+    /// Reads, deletes, and collection management always use REST.
+    #[serde(default)]
+    pub transport: QdrantTransport,
+    /// gRPC endpoint (e.g. "http://localhost:6334").
+    /// Defaults to `url` with the port switched to 6334.
+    #[serde(default)]
+    pub grpc_url: Option<String>,
+    /// Points per upsert request in batch writes (default: 64)
+    #[serde(default = "default_qdrant_upsert_batch_size")]
+    pub upsert_batch_size: usize,
+    /// Max upsert batches in flight at once (default: 4)
+    #[serde(default = "default_qdrant_upsert_parallelism")]
+    pub upsert_parallelism: usize,
 }
 
 fn default_qdrant_collection() -> String {
     "zeroclaw_memories".into()
 }
 
+fn default_qdrant_upsert_batch_size() -> usize {
+    64
+}
+
+fn default_qdrant_upsert_parallelism() -> usize {
+    4
+}
+
 impl Default for QdrantConfig {
     fn default() -> Self {
         Self {
             url: None,
             collection: default_qdrant_collection(),
             api_key: None,
+            transport: QdrantTransport::default(),
+            grpc_url: None,
+            upsert_batch_size: default_qdrant_upsert_batch_size(),
+            upsert_parallelism: default_qdrant_upsert_parallelism(),
+        }
+    }
+}
+
+/// Per-category retention override (`[memory.categories.<name>]`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct MemoryCategoryConfig {
+    /// Hours after the last update before entries expire (0 = never expire).
+    /// Enforced by the hygiene pass, which runs at most every 12 hours.
+    #[serde(default)]
+    pub ttl_hours: u64,
+}
+
+/// Category assignment for auto-saved memories (`[memory.classification]`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct MemoryClassificationConfig {
+    /// File auto-saved messages under the taxonomy category they match instead
+    /// of `conversation`. Messages matching nothing stay in `conversation`.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Category name → case-insensitive keywords or phrases matched on word
+    /// boundaries. The category with the most matches wins. Replaces the
+    /// built-in taxonomy (`preference`, `fact`, `task`, `hardware`, `project`).
+    #[serde(default = "default_memory_taxonomy")]
+    pub taxonomy: BTreeMap<String, Vec<String>>,
+}
+
+fn default_memory_taxonomy() -> BTreeMap<String, Vec<String>> {
+    let taxonomy: [(&str, &[&str]); 5] = [
+        (
+            "preference",
+            &[
+                "i prefer",
+                "i like",
+                "i love",
+                "i hate",
+                "i dislike",
+                "i don't like",
+                "i'd rather",
+                "my favorite",
+                "my favourite",
+                "always use",
+                "never use",
+            ],
+        ),
+        (
+            "fact",
+            &[
+                "my name is",
+                "i live in",
+                "i work at",
+                "i work as",
+                "years old",
+                "my birthday",
+                "i was born",
+                "my email",
+                "my phone",
+                "my timezone",
+            ],
+        ),
+        (
+            "task",
+            &[
+                "remind me",
+                "todo",
+                "to-do",
+                "don't forget",
+                "deadline",
+                "due",
+                "follow up",
+                "by tomorrow",
+                "need to",
+            ],
+        ),
+        (
+            "hardware",
+            &[
+                "gpio",
+                "arduino",
+                "raspberry pi",
+                "esp32",
+                "stm32",
+                "sensor",
+                "pin",
+                "led",
+                "firmware",
+                "serial port",
+            ],
+        ),
+        (
+            "project",
+            &[
+                "project",
+                "repo",
+                "repository",
+                "codebase",
+                "milestone",
+                "release",
+                "roadmap",
+                "sprint",
+                "pull request",
+            ],
+        ),
+    ];
+    taxonomy
+        .into_iter()
+        .map(|(category, keywords)| {
+            (
+                category.to_string(),
+                keywords.iter().map(|keyword| keyword.to_string()).collect(),
+            )
+        })
+        .collect()
+}
+
+impl Default for MemoryClassificationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            taxonomy: default_memory_taxonomy(),
+        }
+    }
+}
+
+/// SQLite journal mode (`PRAGMA journal_mode`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SqliteJournalMode {
+    /// Write-ahead log: concurrent reads during writes (default).
+    #[default]
+    Wal,
+    /// Rollback journal deleted after each transaction. Use on NFS/SMB mounts,
+    /// where WAL shared memory is unreliable.
+    Delete,
+    /// Rollback journal truncated instead of deleted.
+    Truncate,
+    /// Rollback journal header zeroed instead of deleted.
+    Persist,
+    /// Journal kept in RAM; a crash mid-write may corrupt the database.
+    Memory,
+    /// No journal; atomic commit and rollback are disabled.
+    Off,
+}
+
+impl SqliteJournalMode {
+    pub fn as_pragma(self) -> &'static str {
+        match self {
+            Self::Wal => "WAL",
+            Self::Delete => "DELETE",
+            Self::Truncate => "TRUNCATE",
+            Self::Persist => "PERSIST",
+            Self::Memory => "MEMORY",
+            Self::Off => "OFF",
+        }
+    }
+}
+
+/// SQLite synchronous level (`PRAGMA synchronous`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SqliteSynchronous {
+    /// Hand writes to the OS without syncing. Fastest, least durable.
+    Off,
+    /// Sync at critical moments; durable in WAL mode (default).
+    #[default]
+    Normal,
+    /// Sync after every transaction.
+    Full,
+    /// Like `full`, and also sync the directory after unlinking the journal.
+    Extra,
+}
+
+impl SqliteSynchronous {
+    pub fn as_pragma(self) -> &'static str {
+        match self {
+            Self::Off => "OFF",
+            Self::Normal => "NORMAL",
+            Self::Full => "FULL",
+            Self::Extra => "EXTRA",
         }
     }
 }
 
+/// SQLite storage tuning (`[memory.sqlite]`).
+/// Used when `[memory].backend` is `"sqlite"` or `"lucid"`.
+///
+/// Defaults suit local SSD storage. On SD cards (Raspberry Pi) consider a smaller
+/// `mmap_size_bytes`; on network filesystems use `journal_mode = "delete"`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SqliteConfig {
+    /// Journal mode: "wal" | "delete" | "truncate" | "persist" | "memory" | "off"
+    #[serde(default)]
+    pub journal_mode: SqliteJournalMode,
+    /// Synchronous level: "off" | "normal" | "full" | "extra"
+    #[serde(default)]
+    pub synchronous: SqliteSynchronous,
+    /// In-process page cache size in KiB (default: 2000)
+    #[serde(default = "default_sqlite_cache_size_kib")]
+    pub cache_size_kib: u32,
+    /// Memory-mapped I/O size in bytes; 0 disables mmap (default: 8 MiB)
+    #[serde(default = "default_sqlite_mmap_size_bytes")]
+    pub mmap_size_bytes: u64,
+}
+
+fn default_sqlite_cache_size_kib() -> u32 {
+    2000
+}
+
+fn default_sqlite_mmap_size_bytes() -> u64 {
+    8 * 1024 * 1024
+}
+
+impl Default for SqliteConfig {
+    fn default() -> Self {
+        Self {
+            journal_mode: SqliteJournalMode::default(),
+            synchronous: SqliteSynchronous::default(),
+            cache_size_kib: default_sqlite_cache_size_kib(),
+            mmap_size_bytes: default_sqlite_mmap_size_bytes(),
+        }
+    }
+}
+
+/// Memory backend configuration (`[memory]` section).
+///
+/// Controls conversation memory storage, embeddings, hybrid search, response caching,
+/// and memory snapshot/hydration.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct MemoryConfig {
@@ -1729,6 +2751,14 @@ pub struct MemoryConfig {
     /// For sqlite backend: prune conversation rows older than this many days
     #[serde(default = "default_conversation_retention_days")]
     pub conversation_retention_days: u32,
+    /// For sqlite backend: per-category retention overrides, keyed by category name
+    /// (e.g. `[memory.categories.scratch] ttl_hours = 24`). An entry for
+    /// `conversation` replaces `conversation_retention_days` for auto-saved turns.
+    #[serde(default)]
+    pub categories: HashMap<String, MemoryCategoryConfig>,
+    /// Keyword classification of auto-saved messages into categories
+    #[serde(default)]
+    pub classification: MemoryClassificationConfig,
     /// Embedding provider: "none" | "openai" | "custom:URL"
     #[serde(default = "default_embedding_provider")]
     pub embedding_provider: String,
@@ -1749,6 +2779,10 @@ pub struct MemoryConfig {
     /// context from bleeding into conversations. Default: 0.4
     #[serde(default = "default_min_relevance_score")]
     pub min_relevance_score: f64,
+    /// Estimated token budget for recalled memories in the prompt context.
+    /// The most relevant memories that fit are included; `0` disables the limit.
+    #[serde(default = "default_context_token_budget")]
+    pub context_token_budget: usize,
     /// Max embedding cache entries before LRU eviction
     #[serde(default = "default_cache_size")]
     pub embedding_cache_size: usize,
@@ -1781,8 +2815,12 @@ pub struct MemoryConfig {
     // ── SQLite backend options ─────────────────────────────────
     /// For sqlite backend: max seconds to wait when opening the DB (e.g. file locked).
     /// None = wait indefinitely (default). Recommended max: 300.
+    /// When set, lock contention is also retried for this long before failing.
     #[serde(default)]
     pub sqlite_open_timeout_secs: Option<u64>,
+    /// SQLite pragma tuning (journal mode, synchronous level, cache, mmap).
+    #[serde(default)]
+    pub sqlite: SqliteConfig,
 
     // ── Qdrant backend options ─────────────────────────────────
     /// Configuration for Qdrant vector database backend.
@@ -1791,6 +2829,17 @@ pub struct MemoryConfig {
     pub qdrant: QdrantConfig,
 }
 
+/// Transport used for Qdrant point writes (`[memory.qdrant].transport`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum QdrantTransport {
+    /// REST/JSON over HTTP (default, port 6333).
+    #[default]
+    Rest,
+    /// gRPC/protobuf over HTTP/2 (port 6334). Requires the `memory-qdrant-grpc` feature.
+    Grpc,
+}
+
 fn default_embedding_provider() -> String {
     "none".into()
 }
@@ -1815,50 +2864,667 @@ fn default_embedding_dims() -> usize {
 fn default_vector_weight() -> f64 {
     0.7
 }
-fn default_keyword_weight() -> f64 {
-    0.3
+fn default_keyword_weight() -> f64 {
+    0.3
+}
+fn default_min_relevance_score() -> f64 {
+    0.4
+}
+fn default_context_token_budget() -> usize {
+    1_000
+}
+fn default_cache_size() -> usize {
+    10_000
+}
+fn default_chunk_size() -> usize {
+    512
+}
+fn default_response_cache_ttl() -> u32 {
+    60
+}
+fn default_response_cache_max() -> usize {
+    5_000
+}
+
+impl Default for MemoryConfig {
+    fn default() -> Self {
+        Self {
+            backend: "sqlite".into(),
+            auto_save: true,
+            hygiene_enabled: default_hygiene_enabled(),
+            archive_after_days: default_archive_after_days(),
+            purge_after_days: default_purge_after_days(),
+            conversation_retention_days: default_conversation_retention_days(),
+            categories: HashMap::new(),
+            classification: MemoryClassificationConfig::default(),
+            embedding_provider: default_embedding_provider(),
+            embedding_model: default_embedding_model(),
+            embedding_dimensions: default_embedding_dims(),
+            vector_weight: default_vector_weight(),
+            keyword_weight: default_keyword_weight(),
+            min_relevance_score: default_min_relevance_score(),
+            context_token_budget: default_context_token_budget(),
+            embedding_cache_size: default_cache_size(),
+            chunk_max_tokens: default_chunk_size(),
+            response_cache_enabled: false,
+            response_cache_ttl_minutes: default_response_cache_ttl(),
+            response_cache_max_entries: default_response_cache_max(),
+            snapshot_enabled: false,
+            snapshot_on_hygiene: false,
+            auto_hydrate: true,
+            sqlite_open_timeout_secs: None,
+            sqlite: SqliteConfig::default(),
+            qdrant: QdrantConfig::default(),
+        }
+    }
+}
+
+// ── Workspace sync ────────────────────────────────────────────────
+
+/// Git-backed workspace sync configuration (`[workspace_sync]` section).
+///
+/// Commits workspace Markdown (identity/persona files, daily notes, memory
+/// snapshots) into a git repository inside the workspace, rebases onto the
+/// remote, and pushes — so several machines can share one agent workspace.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WorkspaceSyncConfig {
+    /// Run sync periodically from the daemon. `zeroclaw workspace sync` works regardless.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Git remote URL to pull from and push to. Unset = local commits only.
+    #[serde(default)]
+    pub remote: Option<String>,
+    /// Branch to sync (default: "main")
+    #[serde(default = "default_workspace_sync_branch")]
+    pub branch: String,
+    /// Minutes between daemon sync runs (default: 60, minimum: 5)
+    #[serde(default = "default_workspace_sync_interval_minutes")]
+    pub interval_minutes: u32,
+    /// Workspace-relative glob patterns to commit (default: top-level and memory Markdown)
+    #[serde(default = "default_workspace_sync_paths")]
+    pub paths: Vec<String>,
+}
+
+fn default_workspace_sync_branch() -> String {
+    "main".into()
+}
+
+fn default_workspace_sync_interval_minutes() -> u32 {
+    60
+}
+
+fn default_workspace_sync_paths() -> Vec<String> {
+    vec!["*.md".into(), "memory/*.md".into()]
+}
+
+impl Default for WorkspaceSyncConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            remote: None,
+            branch: default_workspace_sync_branch(),
+            interval_minutes: default_workspace_sync_interval_minutes(),
+            paths: default_workspace_sync_paths(),
+        }
+    }
+}
+
+// ── Shutdown ──────────────────────────────────────────────────────
+
+/// Graceful shutdown configuration (`[shutdown]` section).
+///
+/// On SIGTERM or SIGINT the daemon stops taking new channel and webhook
+/// messages, lets in-flight replies finish, sends held announcements,
+/// flushes memory, and only then stops its components.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ShutdownConfig {
+    /// Seconds to wait for in-flight replies before stopping anyway (default: 30).
+    #[serde(default = "default_shutdown_drain_timeout_secs")]
+    pub drain_timeout_secs: u64,
+    /// Export the memory snapshot before exiting (default: true).
+    #[serde(default = "default_true")]
+    pub flush_memory: bool,
+    /// Planned-downtime notice sent when shutdown starts. Unset = no notice.
+    #[serde(default)]
+    pub notice: Option<String>,
+    /// Channel that receives the notice (e.g. `"telegram"`).
+    /// Unset: the `[[security.estop.notify]]` owner targets.
+    #[serde(default)]
+    pub notify_channel: Option<String>,
+    /// Recipient on `notify_channel` (chat ID, channel ID, ...).
+    #[serde(default)]
+    pub notify_to: Option<String>,
+}
+
+fn default_shutdown_drain_timeout_secs() -> u64 {
+    30
+}
+
+impl Default for ShutdownConfig {
+    fn default() -> Self {
+        Self {
+            drain_timeout_secs: default_shutdown_drain_timeout_secs(),
+            flush_memory: true,
+            notice: None,
+            notify_channel: None,
+            notify_to: None,
+        }
+    }
+}
+
+// ── Backup ────────────────────────────────────────────────────────
+
+/// Backup storage target kind.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BackupTargetKind {
+    /// Local (or mounted) directory
+    #[default]
+    Local,
+    /// S3-compatible object storage (AWS S3, MinIO, R2, B2, ...)
+    S3,
+    /// WebDAV server (Nextcloud, ownCloud, Synology, ...)
+    Webdav,
+}
+
+/// Encrypted backup configuration (`[backup]` section).
+///
+/// Archives contain the redacted config, workspace Markdown, and a memory
+/// snapshot, encrypted with a passphrase-derived key before upload.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BackupConfig {
+    /// Run backups periodically from the daemon. `zeroclaw backup run` works regardless.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Hours between daemon backup runs (default: 24, minimum: 1)
+    #[serde(default = "default_backup_interval_hours")]
+    pub interval_hours: u32,
+    /// Number of most recent archives to keep on the target; 0 keeps everything (default: 7)
+    #[serde(default = "default_backup_keep_last")]
+    pub keep_last: usize,
+    /// Encryption passphrase. Falls back to `ZEROCLAW_BACKUP_PASSPHRASE`.
+    /// Stored encrypted when `secrets.encrypt = true`.
+    #[serde(default)]
+    pub passphrase: Option<String>,
+    /// Where archives are stored (default: "local")
+    #[serde(default)]
+    pub target: BackupTargetKind,
+    /// Directory for the local target (default: `~/.zeroclaw/backups`)
+    #[serde(default)]
+    pub local_dir: Option<String>,
+    /// S3-compatible target settings (`[backup.s3]`)
+    #[serde(default)]
+    pub s3: BackupS3Config,
+    /// WebDAV target settings (`[backup.webdav]`)
+    #[serde(default)]
+    pub webdav: BackupWebdavConfig,
+}
+
+fn default_backup_interval_hours() -> u32 {
+    24
+}
+
+fn default_backup_keep_last() -> usize {
+    7
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_hours: default_backup_interval_hours(),
+            keep_last: default_backup_keep_last(),
+            passphrase: None,
+            target: BackupTargetKind::default(),
+            local_dir: None,
+            s3: BackupS3Config::default(),
+            webdav: BackupWebdavConfig::default(),
+        }
+    }
+}
+
+/// S3-compatible backup target (`[backup.s3]` section).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BackupS3Config {
+    /// Endpoint URL, e.g. `https://s3.us-east-1.amazonaws.com` or `http://minio:9000`
+    #[serde(default)]
+    pub endpoint: String,
+    /// Bucket name (path-style addressing is used)
+    #[serde(default)]
+    pub bucket: String,
+    /// Signing region (default: "us-east-1")
+    #[serde(default = "default_backup_s3_region")]
+    pub region: String,
+    /// Key prefix for archives (default: "zeroclaw/")
+    #[serde(default = "default_backup_prefix")]
+    pub prefix: String,
+    /// Access key ID. Falls back to `AWS_ACCESS_KEY_ID`.
+    #[serde(default)]
+    pub access_key_id: Option<String>,
+    /// Secret access key. Falls back to `AWS_SECRET_ACCESS_KEY`.
+    /// Stored encrypted when `secrets.encrypt = true`.
+    #[serde(default)]
+    pub secret_access_key: Option<String>,
+}
+
+fn default_backup_s3_region() -> String {
+    "us-east-1".into()
+}
+
+fn default_backup_prefix() -> String {
+    "zeroclaw/".into()
+}
+
+impl Default for BackupS3Config {
+    fn default() -> Self {
+        Self {
+            endpoint: String::new(),
+            bucket: String::new(),
+            region: default_backup_s3_region(),
+            prefix: default_backup_prefix(),
+            access_key_id: None,
+            secret_access_key: None,
+        }
+    }
+}
+
+/// WebDAV backup target (`[backup.webdav]` section).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct BackupWebdavConfig {
+    /// Collection URL archives are stored under, e.g. `https://cloud.example.com/remote.php/dav/files/me/zeroclaw/`
+    #[serde(default)]
+    pub url: String,
+    /// Basic auth username
+    #[serde(default)]
+    pub username: Option<String>,
+    /// Basic auth password. Stored encrypted when `secrets.encrypt = true`.
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+// ── Power ────────────────────────────────────────────────────────
+
+/// Where the daemon reads mains/battery state from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum PowerSource {
+    /// NUT when `nut_address` answers, else `/sys/class/power_supply`
+    #[default]
+    Auto,
+    /// Linux `/sys/class/power_supply` (laptops, Pi UPS HATs with a kernel driver)
+    Sysfs,
+    /// Network UPS Tools server (`upsd`)
+    Nut,
+}
+
+/// UPS and battery monitoring (`[power]` section).
+///
+/// The daemon polls the power source and reacts when mains power is lost,
+/// the battery runs low, or power comes back.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PowerConfig {
+    /// Run the power monitor in the daemon.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Power state source (default: "auto")
+    #[serde(default)]
+    pub source: PowerSource,
+    /// NUT `upsd` address (default: "127.0.0.1:3493")
+    #[serde(default = "default_power_nut_address")]
+    pub nut_address: String,
+    /// UPS name as configured in NUT (default: "ups")
+    #[serde(default = "default_power_nut_ups")]
+    pub nut_ups: String,
+    /// Seconds between polls (default: 30, minimum: 5)
+    #[serde(default = "default_power_poll_secs")]
+    pub poll_secs: u64,
+    /// Battery charge (percent) treated as low when the source does not
+    /// report its own low-battery flag (default: 20)
+    #[serde(default = "default_power_low_battery_percent")]
+    pub low_battery_percent: u8,
+    /// Channel for power notices; falls back to `heartbeat.target`
+    #[serde(default)]
+    pub channel: Option<String>,
+    /// Recipient for power notices; falls back to `heartbeat.to`
+    #[serde(default)]
+    pub to: Option<String>,
+    /// Export the memory snapshot when running on battery (default: true)
+    #[serde(default = "default_true")]
+    pub flush_memory: bool,
+    /// Engage the emergency stop (kill-all) when mains power is lost, so
+    /// actuators are not driven on a failing supply (default: false)
+    #[serde(default)]
+    pub estop_on_battery: bool,
+}
+
+fn default_power_nut_address() -> String {
+    "127.0.0.1:3493".into()
+}
+
+fn default_power_nut_ups() -> String {
+    "ups".into()
+}
+
+fn default_power_poll_secs() -> u64 {
+    30
+}
+
+fn default_power_low_battery_percent() -> u8 {
+    20
+}
+
+impl Default for PowerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            source: PowerSource::default(),
+            nut_address: default_power_nut_address(),
+            nut_ups: default_power_nut_ups(),
+            poll_secs: default_power_poll_secs(),
+            low_battery_percent: default_power_low_battery_percent(),
+            channel: None,
+            to: None,
+            flush_memory: true,
+            estop_on_battery: false,
+        }
+    }
+}
+
+// ── Connectivity ─────────────────────────────────────────────────
+
+/// Internet connectivity monitor (`[connectivity]` section).
+///
+/// The daemon probes a few well-known hosts; after enough consecutive
+/// failures it treats the host as offline until a probe succeeds again.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ConnectivityConfig {
+    /// Run the connectivity monitor in the daemon.
+    #[serde(default)]
+    pub enabled: bool,
+    /// `host:port` addresses probed with a TCP connect; any success counts
+    /// as online (default: 1.1.1.1:443, 8.8.8.8:53)
+    #[serde(default = "default_connectivity_probe_hosts")]
+    pub probe_hosts: Vec<String>,
+    /// Seconds between probes (default: 30, minimum: 5)
+    #[serde(default = "default_connectivity_interval_secs")]
+    pub interval_secs: u64,
+    /// Consecutive failed probes before the host counts as offline (default: 2)
+    #[serde(default = "default_connectivity_failures_before_offline")]
+    pub failures_before_offline: u32,
+    /// Hold outbound announcements (scheduled results, alerts) while offline
+    /// and send them once connectivity returns (default: true)
+    #[serde(default = "default_true")]
+    pub queue_outbound: bool,
+    /// Most announcements held while offline; the oldest are dropped first (default: 200)
+    #[serde(default = "default_connectivity_max_queued")]
+    pub max_queued: usize,
+    /// Local provider used while offline, e.g. "ollama"; unset keeps the
+    /// configured provider
+    #[serde(default)]
+    pub offline_provider: Option<String>,
+    /// Model for `offline_provider`; unset passes the requested model through
+    #[serde(default)]
+    pub offline_model: Option<String>,
+}
+
+fn default_connectivity_probe_hosts() -> Vec<String> {
+    vec!["1.1.1.1:443".into(), "8.8.8.8:53".into()]
+}
+
+fn default_connectivity_interval_secs() -> u64 {
+    30
+}
+
+fn default_connectivity_failures_before_offline() -> u32 {
+    2
+}
+
+fn default_connectivity_max_queued() -> usize {
+    200
+}
+
+impl Default for ConnectivityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            probe_hosts: default_connectivity_probe_hosts(),
+            interval_secs: default_connectivity_interval_secs(),
+            failures_before_offline: default_connectivity_failures_before_offline(),
+            queue_outbound: true,
+            max_queued: default_connectivity_max_queued(),
+            offline_provider: None,
+            offline_model: None,
+        }
+    }
+}
+
+// ── PII redaction ────────────────────────────────────────────────
+
+/// Built-in PII detectors for the `[redaction]` section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PiiDetector {
+    /// Email addresses.
+    Email,
+    /// Phone numbers with separators or in E.164 form (`+14155552671`).
+    Phone,
+    /// Payment card numbers (13–19 digits, Luhn-checked).
+    CreditCard,
+}
+
+/// How a detected PII value is handled before the entry is persisted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RedactionPolicy {
+    /// Replace the value with `[REDACTED:<KIND>]`.
+    #[default]
+    Mask,
+    /// Replace the value with `[<KIND>:<sha256 prefix>]`, so repeated values
+    /// stay correlatable without being readable.
+    Hash,
+    /// Skip persisting the entry entirely when any PII is found.
+    Drop,
+}
+
+/// User-defined redaction pattern (`[[redaction.custom]]`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CustomRedactionPattern {
+    /// Label used in the replacement marker (e.g. `employee_id`).
+    pub name: String,
+    /// Regular expression matched against the stored content.
+    pub pattern: String,
+}
+
+/// PII redaction configuration (`[redaction]` section).
+///
+/// Applies to auto-saved conversation turns and agent `memory_store` calls.
+/// Disabled by default.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RedactionConfig {
+    /// Enable redaction before memory writes.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Built-in detectors to run. Default: `["email", "phone", "credit_card"]`.
+    #[serde(default = "default_pii_detectors")]
+    pub detectors: Vec<PiiDetector>,
+    /// Additional regex detectors.
+    #[serde(default)]
+    pub custom: Vec<CustomRedactionPattern>,
+    /// Policy used when a channel has no override. Default: `mask`.
+    #[serde(default)]
+    pub policy: RedactionPolicy,
+    /// Per-channel policy overrides keyed by channel name (e.g. `telegram = "drop"`).
+    /// CLI turns use `cli`, gateway webhooks use `webhook`, agent
+    /// `memory_store` calls use `agent`, and `zeroclaw import` uses `import`.
+    #[serde(default)]
+    pub channels: HashMap<String, RedactionPolicy>,
+}
+
+fn default_pii_detectors() -> Vec<PiiDetector> {
+    vec![
+        PiiDetector::Email,
+        PiiDetector::Phone,
+        PiiDetector::CreditCard,
+    ]
+}
+
+impl Default for RedactionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            detectors: default_pii_detectors(),
+            custom: Vec::new(),
+            policy: RedactionPolicy::default(),
+            channels: HashMap::new(),
+        }
+    }
+}
+
+impl RedactionConfig {
+    /// Policy that applies to entries coming from `channel`.
+    pub fn policy_for(&self, channel: &str) -> RedactionPolicy {
+        self.channels.get(channel).copied().unwrap_or(self.policy)
+    }
+}
+
+// ── UI ────────────────────────────────────────────────────────────
+
+/// Interactive UI configuration (`[ui]` section).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct UiConfig {
+    /// Color theme for the onboarding wizard and other interactive screens.
+    /// Overridden per run by `zeroclaw onboard --theme`.
+    #[serde(default)]
+    pub theme: UiTheme,
+    /// Language of the onboarding wizard. Overridden per run by
+    /// `zeroclaw onboard --locale`.
+    #[serde(default)]
+    pub locale: UiLocale,
+    /// Terminal bell and desktop notifications (`[ui.notifications]`).
+    #[serde(default)]
+    pub notifications: UiNotificationsConfig,
+    /// Show a live elapsed-time / token count / tokens-per-second status line
+    /// while interactive `zeroclaw agent` generates. Toggle per session with `/stats`.
+    #[serde(default)]
+    pub generation_stats: bool,
+}
+
+/// Terminal color theme (`[ui].theme`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum UiTheme {
+    /// Cyan/green accents tuned for dark terminal backgrounds.
+    #[default]
+    Dark,
+    /// Darker accents that stay readable on light backgrounds.
+    Light,
+    /// Bright, bold colors without dimmed text.
+    HighContrast,
+    /// Plain text; no ANSI colors or styles.
+    NoColor,
 }
-fn default_min_relevance_score() -> f64 {
-    0.4
+
+impl UiTheme {
+    pub const NAMES: [&'static str; 4] = ["dark", "light", "high-contrast", "no-color"];
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().replace('_', "-").as_str() {
+            "dark" => Some(Self::Dark),
+            "light" => Some(Self::Light),
+            "high-contrast" => Some(Self::HighContrast),
+            "no-color" | "none" => Some(Self::NoColor),
+            _ => None,
+        }
+    }
 }
-fn default_cache_size() -> usize {
-    10_000
+
+/// Onboarding wizard language (`[ui].locale`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum UiLocale {
+    #[default]
+    En,
+    Zh,
+    Ja,
+    Es,
+}
+
+impl UiLocale {
+    pub const ALL: [Self; 4] = [Self::En, Self::Zh, Self::Ja, Self::Es];
+    pub const NAMES: [&'static str; 4] = ["en", "zh", "ja", "es"];
+
+    /// Accepts a bare code or a POSIX locale such as `zh_CN.UTF-8`.
+    pub fn parse(raw: &str) -> Option<Self> {
+        let code = raw
+            .trim()
+            .split(['_', '-', '.'])
+            .next()?
+            .to_ascii_lowercase();
+        match code.as_str() {
+            "en" => Some(Self::En),
+            "zh" => Some(Self::Zh),
+            "ja" => Some(Self::Ja),
+            "es" => Some(Self::Es),
+            _ => None,
+        }
+    }
+
+    /// The language's own name, as shown in the language picker.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Self::En => "English",
+            Self::Zh => "简体中文",
+            Self::Ja => "日本語",
+            Self::Es => "Español",
+        }
+    }
 }
-fn default_chunk_size() -> usize {
-    512
+
+/// Runtime events that can raise an attention signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NotifyEvent {
+    /// A supervised daemon component (channels, gateway, ...) failed or exited.
+    ChannelDown,
+    /// A tool call is blocked on interactive approval.
+    ApprovalRequested,
+    /// A scheduled cron job or reminder fired.
+    ReminderFired,
 }
-fn default_response_cache_ttl() -> u32 {
-    60
+
+fn default_notify_events() -> Vec<NotifyEvent> {
+    vec![
+        NotifyEvent::ChannelDown,
+        NotifyEvent::ApprovalRequested,
+        NotifyEvent::ReminderFired,
+    ]
 }
-fn default_response_cache_max() -> usize {
-    5_000
+
+/// Attention signals for important runtime events (`[ui.notifications]` section).
+///
+/// Both delivery methods are off by default; `events` only matters once
+/// `bell` or `desktop` is enabled.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UiNotificationsConfig {
+    /// Ring the terminal bell (BEL) when an important event fires.
+    #[serde(default)]
+    pub bell: bool,
+    /// Show a desktop notification (`notify-send` on Linux, `osascript` on macOS,
+    /// a PowerShell balloon tip on Windows).
+    #[serde(default)]
+    pub desktop: bool,
+    /// Events that notify: `channel_down`, `approval_requested`,
+    /// `reminder_fired`. All three by default.
+    #[serde(default = "default_notify_events")]
+    pub events: Vec<NotifyEvent>,
 }
 
-impl Default for MemoryConfig {
+impl Default for UiNotificationsConfig {
     fn default() -> Self {
         Self {
-            backend: "sqlite".into(),
-            auto_save: true,
-            hygiene_enabled: default_hygiene_enabled(),
-            archive_after_days: default_archive_after_days(),
-            purge_after_days: default_purge_after_days(),
-            conversation_retention_days: default_conversation_retention_days(),
-            embedding_provider: default_embedding_provider(),
-            embedding_model: default_embedding_model(),
-            embedding_dimensions: default_embedding_dims(),
-            vector_weight: default_vector_weight(),
-            keyword_weight: default_keyword_weight(),
-            min_relevance_score: default_min_relevance_score(),
-            embedding_cache_size: default_cache_size(),
-            chunk_max_tokens: default_chunk_size(),
-            response_cache_enabled: false,
-            response_cache_ttl_minutes: default_response_cache_ttl(),
-            response_cache_max_entries: default_response_cache_max(),
-            snapshot_enabled: false,
-            snapshot_on_hygiene: false,
-            auto_hydrate: true,
-            sqlite_open_timeout_secs: None,
-            qdrant: QdrantConfig::default(),
+            bell: false,
+            desktop: false,
+            events: default_notify_events(),
         }
     }
 }
@@ -1891,6 +3557,13 @@ pub struct ObservabilityConfig {
     /// Maximum entries retained when runtime_trace_mode = "rolling".
     #[serde(default = "default_runtime_trace_max_entries")]
     pub runtime_trace_max_entries: usize,
+
+    /// Senders allowed to switch debug mode with `!debug on|off` in chat, as
+    /// `<channel>:<sender>` (e.g. `"telegram:123456789"`); the sender ID is
+    /// matched exactly. Empty (default) disables the chat command.
+    /// `"*"` is rejected: debug mode records full prompts to disk.
+    #[serde(default)]
+    pub debug_command_users: Vec<String>,
 }
 
 impl Default for ObservabilityConfig {
@@ -1902,6 +3575,7 @@ impl Default for ObservabilityConfig {
             runtime_trace_mode: default_runtime_trace_mode(),
             runtime_trace_path: default_runtime_trace_path(),
             runtime_trace_max_entries: default_runtime_trace_max_entries(),
+            debug_command_users: Vec::new(),
         }
     }
 }
@@ -2356,6 +4030,130 @@ pub struct EmbeddingRouteConfig {
     pub api_key: Option<String>,
 }
 
+// ── Behavior ─────────────────────────────────────────────────────
+
+/// Conversation behavior configuration (`[behavior]` section).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct BehaviorConfig {
+    /// Frustration/urgency detection and escalation (`[behavior.escalation]`).
+    #[serde(default)]
+    pub escalation: EscalationConfig,
+}
+
+/// What to do when a channel message looks frustrated or urgent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EscalationAction {
+    /// Send a heads-up to `notify_channel`/`notify_to`.
+    NotifyOwner,
+    /// Answer with `provider`/`model` instead of the routed model.
+    SwitchModel,
+    /// Tell the model to drop the playful persona and be direct.
+    DropPersona,
+}
+
+/// Escalation on frustrated or urgent messages (`[behavior.escalation]`).
+///
+/// A lightweight classifier scores each channel message: one point per
+/// matched keyword, plus one for shouting (mostly capitals) and one for
+/// repeated `!`/`?`. At `threshold` points the configured actions run.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EscalationConfig {
+    /// Enable escalation detection. Default: `false`.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Score at which a message escalates. Default: `2`.
+    #[serde(default = "default_escalation_threshold")]
+    pub threshold: u32,
+    /// Case-insensitive phrases that signal frustration.
+    #[serde(default = "default_frustration_keywords")]
+    pub frustration_keywords: Vec<String>,
+    /// Case-insensitive phrases that signal urgency.
+    #[serde(default = "default_urgency_keywords")]
+    pub urgency_keywords: Vec<String>,
+    /// Actions to run on escalation. Default: `["drop_persona"]`.
+    #[serde(default = "default_escalation_actions")]
+    pub actions: Vec<EscalationAction>,
+    /// Channel that receives owner notifications (e.g. `"telegram"`).
+    #[serde(default)]
+    pub notify_channel: Option<String>,
+    /// Recipient on `notify_channel` (chat ID, channel ID, ...).
+    #[serde(default)]
+    pub notify_to: Option<String>,
+    /// Minimum minutes between owner notifications for one conversation. Default: `30`.
+    #[serde(default = "default_escalation_notify_cooldown_minutes")]
+    pub notify_cooldown_minutes: u32,
+    /// Provider for `switch_model`; defaults to the conversation's provider.
+    #[serde(default)]
+    pub provider: Option<String>,
+    /// Model for `switch_model`.
+    #[serde(default)]
+    pub model: Option<String>,
+}
+
+fn default_escalation_threshold() -> u32 {
+    2
+}
+
+fn default_frustration_keywords() -> Vec<String> {
+    [
+        "wtf",
+        "ridiculous",
+        "useless",
+        "not working",
+        "doesn't work",
+        "still broken",
+        "frustrated",
+        "annoying",
+        "unacceptable",
+        "waste of time",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect()
+}
+
+fn default_urgency_keywords() -> Vec<String> {
+    [
+        "urgent",
+        "asap",
+        "emergency",
+        "immediately",
+        "right now",
+        "critical",
+        "outage",
+        "production is down",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect()
+}
+
+fn default_escalation_actions() -> Vec<EscalationAction> {
+    vec![EscalationAction::DropPersona]
+}
+
+fn default_escalation_notify_cooldown_minutes() -> u32 {
+    30
+}
+
+impl Default for EscalationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold: default_escalation_threshold(),
+            frustration_keywords: default_frustration_keywords(),
+            urgency_keywords: default_urgency_keywords(),
+            actions: default_escalation_actions(),
+            notify_channel: None,
+            notify_to: None,
+            notify_cooldown_minutes: default_escalation_notify_cooldown_minutes(),
+            provider: None,
+            model: None,
+        }
+    }
+}
+
 // ── Query Classification ─────────────────────────────────────────
 
 /// Automatic query classification — classifies user messages by keyword/pattern
@@ -2395,7 +4193,10 @@ pub struct ClassificationRule {
 // ── Heartbeat ────────────────────────────────────────────────────
 
 /// Heartbeat configuration for periodic health pings (`[heartbeat]` section).
+///
+/// Every key is optional so a config can set up `[heartbeat.deadman]` alone.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
 pub struct HeartbeatConfig {
     /// Enable periodic heartbeat pings. Default: `false`.
     pub enabled: bool,
@@ -2410,6 +4211,9 @@ pub struct HeartbeatConfig {
     /// Optional delivery recipient/chat identifier (required when `target` is set).
     #[serde(default, alias = "recipient")]
     pub to: Option<String>,
+    /// Dead-man's switch monitoring (`[heartbeat.deadman]`).
+    #[serde(default)]
+    pub deadman: DeadmanSwitchConfig,
 }
 
 impl Default for HeartbeatConfig {
@@ -2420,6 +4224,70 @@ impl Default for HeartbeatConfig {
             message: None,
             target: None,
             to: None,
+            deadman: DeadmanSwitchConfig::default(),
+        }
+    }
+}
+
+/// Action taken when the dead-man's switch trips.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DeadmanAction {
+    /// Send a notice to the heartbeat delivery target and desktop notifications.
+    #[default]
+    Notify,
+    /// Notify, then engage estop `kill-all` (requires `[security.estop].enabled`).
+    Estop,
+    /// Notify, then stop the daemon's channel listeners.
+    StopChannels,
+}
+
+/// Dead-man's switch configuration (`[heartbeat.deadman]` section).
+///
+/// Pings an external monitor (healthchecks.io-style) on every beat and/or
+/// expects periodic owner check-ins via `POST /api/checkin`. When pings keep
+/// failing or a check-in is overdue, `action` runs once until beats recover.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DeadmanSwitchConfig {
+    /// Enable the dead-man's switch worker in the daemon. Default: `false`.
+    #[serde(default)]
+    pub enabled: bool,
+    /// URL pinged with `GET` on every beat (for example a healthchecks.io check URL).
+    #[serde(default)]
+    pub ping_url: Option<String>,
+    /// Minutes between beats. Default: `5`.
+    #[serde(default = "default_deadman_interval_minutes")]
+    pub interval_minutes: u32,
+    /// Consecutive failed pings that count as a missed beat. Default: `3`.
+    #[serde(default = "default_deadman_max_failed_pings")]
+    pub max_failed_pings: u32,
+    /// Hours the owner may go without checking in before the switch trips.
+    /// Unset disables check-in monitoring.
+    #[serde(default)]
+    pub checkin_timeout_hours: Option<u32>,
+    /// Action to run on a missed beat. Default: `notify`.
+    #[serde(default)]
+    pub action: DeadmanAction,
+}
+
+fn default_deadman_interval_minutes() -> u32 {
+    5
+}
+
+fn default_deadman_max_failed_pings() -> u32 {
+    3
+}
+
+impl Default for DeadmanSwitchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ping_url: None,
+            interval_minutes: default_deadman_interval_minutes(),
+            max_failed_pings: default_deadman_max_failed_pings(),
+            checkin_timeout_hours: None,
+            action: DeadmanAction::default(),
         }
     }
 }
@@ -2450,6 +4318,63 @@ impl Default for CronConfig {
     }
 }
 
+// ── Workspaces ──────────────────────────────────────────────────
+
+/// Named workspace selection (`[workspaces]` section).
+///
+/// Named workspaces are the profiles under `<config-dir>/profiles/`, each with
+/// its own `config.toml` and `workspace/` (and so its own memory). Pick one
+/// per command with `--workspace NAME`, or from chat with `/workspace NAME`,
+/// which selects the workspace the daemon loads on its next start.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct WorkspacesConfig {
+    /// Sender ids allowed to select the active workspace from chat. Anyone on
+    /// the channel allowlist may list workspaces. Empty (the default) disables
+    /// selecting from chat; `"*"` is rejected.
+    #[serde(default)]
+    pub switch_users: Vec<String>,
+}
+
+impl WorkspacesConfig {
+    pub fn can_switch(&self, sender: &str) -> bool {
+        self.switch_users.iter().any(|user| user == sender)
+    }
+}
+
+// ── Schedules ───────────────────────────────────────────────────
+
+/// Recurring agent jobs declared in config (`[schedules]` section).
+///
+/// The scheduler registers these in the cron store when it starts, next to
+/// jobs added with `zeroclaw cron add`. Removing an entry here removes the job.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct SchedulesConfig {
+    /// Jobs to keep registered (`[[schedules.jobs]]`).
+    #[serde(default)]
+    pub jobs: Vec<ScheduledJobConfig>,
+}
+
+/// One recurring agent job (`[[schedules.jobs]]`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ScheduledJobConfig {
+    /// Unique job name, e.g. `"daily-summary"`.
+    pub name: String,
+    /// Cron expression (5 fields, or 6/7 with seconds), e.g. `"0 9 * * *"`.
+    pub cron: String,
+    /// IANA timezone the expression is evaluated in. Default: UTC.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tz: Option<String>,
+    /// Prompt the agent runs on every tick.
+    pub prompt: String,
+    /// Channel that receives the reply (`"telegram"`, `"discord"`, `"slack"`, …).
+    /// Omit to only record the run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    /// Recipient on `channel` (chat, user, or channel ID). Required with `channel`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
 // ── Tunnel ──────────────────────────────────────────────────────
 
 /// Tunnel configuration for exposing the gateway publicly (`[tunnel]` section).
@@ -2575,7 +4500,7 @@ pub struct ChannelsConfig {
     /// Nextcloud Talk bot channel configuration.
     pub nextcloud_talk: Option<NextcloudTalkConfig>,
     /// Email channel configuration.
-    pub email: Option<crate::channels::email_channel::EmailConfig>,
+    pub email: Option<crate::channels::email_config::EmailConfig>,
     /// IRC channel configuration.
     pub irc: Option<IrcConfig>,
     /// Lark channel configuration.
@@ -2589,6 +4514,14 @@ pub struct ChannelsConfig {
     pub nostr: Option<NostrConfig>,
     /// ClawdTalk voice channel configuration.
     pub clawdtalk: Option<crate::channels::clawdtalk::ClawdTalkConfig>,
+    /// Out-of-tree channel plugins, keyed by plugin name (`[channels_config.plugins.<name>]`).
+    /// Each table is handed to the plugin registered under that name; see
+    /// `channels::registry::register_channel_plugin`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub plugins: BTreeMap<String, serde_json::Value>,
+    /// Translation mode, keyed by channel name (`[channels_config.translation.<channel>]`).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub translation: BTreeMap<String, ChannelTranslationConfig>,
     /// Base timeout in seconds for processing a single channel message (LLM + tools).
     /// Runtime uses this as a per-turn budget that scales with tool-loop depth
     /// (up to 4x, capped) so one slow/retried model call does not consume the
@@ -2692,6 +4625,24 @@ impl ChannelsConfig {
     }
 }
 
+/// Translation mode for one channel (`[channels_config.translation.<channel>]`).
+///
+/// Incoming messages in other languages are translated into `translate_to`
+/// before the agent sees them, and replies are translated back into the
+/// sender's language.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ChannelTranslationConfig {
+    /// Language the agent works in, as an ISO 639-1 code (e.g. `"en"`).
+    pub translate_to: String,
+    /// Provider for translation calls. Defaults to the sender's active provider.
+    #[serde(default)]
+    pub provider: Option<String>,
+    /// Model for translation calls, typically a cheap one. Defaults to the
+    /// sender's active model.
+    #[serde(default)]
+    pub model: Option<String>,
+}
+
 fn default_channel_message_timeout_secs() -> u64 {
     300
 }
@@ -2720,6 +4671,8 @@ impl Default for ChannelsConfig {
             qq: None,
             nostr: None,
             clawdtalk: None,
+            plugins: BTreeMap::new(),
+            translation: BTreeMap::new(),
             message_timeout_secs: default_channel_message_timeout_secs(),
         }
     }
@@ -2742,6 +4695,7 @@ fn default_draft_update_interval_ms() -> u64 {
 
 /// Telegram bot channel configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct TelegramConfig {
     /// Telegram Bot API token (from @BotFather).
     pub bot_token: String,
@@ -2761,6 +4715,14 @@ pub struct TelegramConfig {
     /// Direct messages are always processed.
     #[serde(default)]
     pub mention_only: bool,
+    /// When true, replies quote the triggering message
+    /// (`reply_to_message_id`) so they stay attached in busy groups.
+    #[serde(default)]
+    pub quote_replies: bool,
+    /// When true, replies that are only a short acknowledgment ("Done",
+    /// "Got it") or a single emoji are sent as a reaction instead.
+    #[serde(default)]
+    pub lightweight_acks: bool,
 }
 
 impl ChannelConfig for TelegramConfig {
@@ -2774,6 +4736,7 @@ impl ChannelConfig for TelegramConfig {
 
 /// Discord bot channel configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct DiscordConfig {
     /// Discord bot token (from Discord Developer Portal).
     pub bot_token: String,
@@ -2790,6 +4753,14 @@ pub struct DiscordConfig {
     /// Other messages in the guild are silently ignored.
     #[serde(default)]
     pub mention_only: bool,
+    /// When true, replies reference the triggering message so Discord shows
+    /// them as replies.
+    #[serde(default)]
+    pub quote_replies: bool,
+    /// When true, replies that are only a short acknowledgment ("Done",
+    /// "Got it") or a single emoji are sent as a reaction instead.
+    #[serde(default)]
+    pub lightweight_acks: bool,
 }
 
 impl ChannelConfig for DiscordConfig {
@@ -2814,6 +4785,11 @@ pub struct SlackConfig {
     /// Allowed Slack user IDs. Empty = deny all.
     #[serde(default)]
     pub allowed_users: Vec<String>,
+    /// When true (default), replies to top-level messages are posted in a
+    /// thread under the triggering message. When false they go to the
+    /// channel; messages already in a thread are still answered there.
+    #[serde(default = "default_true")]
+    pub quote_replies: bool,
 }
 
 impl ChannelConfig for SlackConfig {
@@ -3355,6 +5331,35 @@ pub struct EstopConfig {
     /// Require a valid OTP before resume operations.
     #[serde(default = "default_true")]
     pub require_otp_to_resume: bool,
+
+    /// Accept `POST /api/estop` on the gateway so an external monitor can
+    /// engage `kill-all` or `network-kill` remotely. Requires gateway pairing.
+    #[serde(default)]
+    pub remote_trigger: bool,
+
+    /// Owner channels notified whenever estop is engaged.
+    #[serde(default)]
+    pub notify: Vec<EstopNotifyTarget>,
+
+    /// Chat message that engages `kill-all` when any channel receives it
+    /// (case-insensitive, surrounding whitespace ignored).
+    #[serde(default)]
+    pub trigger_phrase: Option<String>,
+
+    /// BCM GPIO pin wired to a normally-open kill switch. Pulling the pin
+    /// low engages `kill-all` (Raspberry Pi with the `peripheral-rpi` feature).
+    #[serde(default)]
+    pub kill_switch_pin: Option<u8>,
+}
+
+/// Delivery target for estop engagement notices (`[[security.estop.notify]]`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct EstopNotifyTarget {
+    /// Channel name (`telegram`, `discord`, `slack`, or `mattermost`).
+    pub channel: String,
+    /// Recipient chat/channel identifier on that channel.
+    pub to: String,
 }
 
 fn default_estop_state_file() -> String {
@@ -3367,6 +5372,10 @@ impl Default for EstopConfig {
             enabled: false,
             state_file: default_estop_state_file(),
             require_otp_to_resume: true,
+            remote_trigger: false,
+            notify: Vec::new(),
+            trigger_phrase: None,
+            kill_switch_pin: None,
         }
     }
 }
@@ -3595,6 +5604,7 @@ impl Default for Config {
             default_provider: Some("openrouter".to_string()),
             default_model: Some("anthropic/claude-sonnet-4.6".to_string()),
             model_providers: HashMap::new(),
+            provider_requests: HashMap::new(),
             default_temperature: 0.7,
             observability: ObservabilityConfig::default(),
             autonomy: AutonomyConfig::default(),
@@ -3608,6 +5618,8 @@ impl Default for Config {
             embedding_routes: Vec::new(),
             heartbeat: HeartbeatConfig::default(),
             cron: CronConfig::default(),
+            schedules: SchedulesConfig::default(),
+            workspaces: WorkspacesConfig::default(),
             channels_config: ChannelsConfig::default(),
             memory: MemoryConfig::default(),
             storage: StorageConfig::default(),
@@ -3629,6 +5641,16 @@ impl Default for Config {
             hardware: HardwareConfig::default(),
             query_classification: QueryClassificationConfig::default(),
             transcription: TranscriptionConfig::default(),
+            workspace_sync: WorkspaceSyncConfig::default(),
+            shutdown: ShutdownConfig::default(),
+            backup: BackupConfig::default(),
+            power: PowerConfig::default(),
+            connectivity: ConnectivityConfig::default(),
+            ui: UiConfig::default(),
+            redaction: RedactionConfig::default(),
+            behavior: BehaviorConfig::default(),
+            env_secret_refs: BTreeMap::new(),
+            record_llm_dir: None,
         }
     }
 }
@@ -3813,7 +5835,8 @@ pub(crate) fn resolve_config_dir_for_workspace(workspace_dir: &Path) -> (PathBuf
 /// Resolve the current runtime config/workspace directories for onboarding flows.
 ///
 /// This mirrors the same precedence used by `Config::load_or_init()`:
-/// `ZEROCLAW_CONFIG_DIR` > `ZEROCLAW_WORKSPACE` > active workspace marker > defaults.
+/// `ZEROCLAW_CONFIG_DIR` > `ZEROCLAW_WORKSPACE` > active workspace marker > defaults,
+/// then `profiles/<name>` below that when a profile is active.
 pub(crate) async fn resolve_runtime_dirs_for_onboarding() -> Result<(PathBuf, PathBuf)> {
     let (default_zeroclaw_dir, default_workspace_dir) = default_config_and_workspace_dirs()?;
     let (config_dir, workspace_dir, _) =
@@ -3827,6 +5850,7 @@ enum ConfigResolutionSource {
     EnvWorkspace,
     ActiveWorkspaceMarker,
     DefaultConfigDir,
+    Profile,
 }
 
 impl ConfigResolutionSource {
@@ -3836,13 +5860,98 @@ impl ConfigResolutionSource {
             Self::EnvWorkspace => "ZEROCLAW_WORKSPACE",
             Self::ActiveWorkspaceMarker => "active_workspace.toml",
             Self::DefaultConfigDir => "default",
+            Self::Profile => "ZEROCLAW_PROFILE",
+        }
+    }
+}
+
+const PROFILES_DIR: &str = "profiles";
+
+/// Check a profile name for `--profile` / `ZEROCLAW_PROFILE`: 1-64 ASCII
+/// letters, digits, `-` or `_`, so it is always a single path component.
+pub fn validate_profile_name(name: &str) -> Result<()> {
+    if name.is_empty()
+        || name.len() > 64
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        anyhow::bail!("Invalid profile name '{name}': use 1-64 letters, digits, '-' or '_'");
+    }
+    Ok(())
+}
+
+/// Profile selected with `--profile` (exported as `ZEROCLAW_PROFILE`).
+pub(crate) fn active_profile() -> Option<String> {
+    std::env::var("ZEROCLAW_PROFILE")
+        .ok()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+/// Config directory of profile `name` under `base_config_dir`.
+pub(crate) fn profile_config_dir(base_config_dir: &Path, name: &str) -> PathBuf {
+    base_config_dir.join(PROFILES_DIR).join(name)
+}
+
+/// Whether `config_dir` is a named profile (`<base>/profiles/<name>`).
+pub(crate) fn is_profile_config_dir(config_dir: &Path) -> bool {
+    config_dir
+        .parent()
+        .and_then(Path::file_name)
+        .is_some_and(|name| name == PROFILES_DIR)
+}
+
+/// Config directory the profiles of `config_dir` live under: its grandparent
+/// when `config_dir` is itself a profile, otherwise `config_dir`.
+pub(crate) fn profile_base_dir(config_dir: &Path) -> &Path {
+    if is_profile_config_dir(config_dir) {
+        if let Some(base) = config_dir.parent().and_then(Path::parent) {
+            return base;
         }
     }
+    config_dir
 }
 
+/// Names of the profiles under `base_config_dir` that have a `config.toml`,
+/// sorted. These double as the named workspaces offered by `/workspace`.
+pub(crate) fn list_profiles(base_config_dir: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(base_config_dir.join(PROFILES_DIR)) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().join("config.toml").is_file())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| validate_profile_name(name).is_ok())
+        .collect();
+    names.sort();
+    names
+}
+
+/// Resolve the base config directory, then narrow it to the active profile.
+/// When the active workspace marker already points at a profile, `--profile`
+/// picks a sibling rather than nesting below it.
 async fn resolve_runtime_config_dirs(
     default_zeroclaw_dir: &Path,
     default_workspace_dir: &Path,
+) -> Result<(PathBuf, PathBuf, ConfigResolutionSource)> {
+    let resolved = resolve_base_config_dirs(default_zeroclaw_dir, default_workspace_dir).await?;
+    let Some(profile) = active_profile() else {
+        return Ok(resolved);
+    };
+    validate_profile_name(&profile)?;
+    let config_dir = profile_config_dir(profile_base_dir(&resolved.0), &profile);
+    Ok((
+        config_dir.clone(),
+        config_dir.join("workspace"),
+        ConfigResolutionSource::Profile,
+    ))
+}
+
+async fn resolve_base_config_dirs(
+    default_zeroclaw_dir: &Path,
+    default_workspace_dir: &Path,
 ) -> Result<(PathBuf, PathBuf, ConfigResolutionSource)> {
     if let Ok(custom_config_dir) = std::env::var("ZEROCLAW_CONFIG_DIR") {
         let custom_config_dir = custom_config_dir.trim();
@@ -3945,6 +6054,90 @@ fn encrypt_secret(
     Ok(())
 }
 
+/// Prefix for config strings read from an environment variable instead of
+/// being stored in config.toml (e.g. `bot_token = "env:TELEGRAM_BOT_TOKEN"`).
+pub const ENV_SECRET_PREFIX: &str = "env:";
+
+/// Variable name of an `env:NAME` reference, if `value` is one.
+pub fn parse_env_secret_ref(value: &str) -> Option<&str> {
+    value
+        .strip_prefix(ENV_SECRET_PREFIX)
+        .filter(|name| is_valid_env_var_name(name))
+}
+
+/// Replace every `env:NAME` string in `table` with the variable's value,
+/// recording the TOML path of each replacement in `refs`.
+fn resolve_env_secret_refs_in_table(
+    table: &mut toml::Table,
+    path: &mut Vec<String>,
+    refs: &mut BTreeMap<Vec<String>, String>,
+) {
+    for (key, value) in table.iter_mut() {
+        path.push(key.clone());
+        match value {
+            toml::Value::String(raw) => {
+                if let Some(name) = parse_env_secret_ref(raw).map(str::to_string) {
+                    *raw = std::env::var(&name).unwrap_or_else(|_| {
+                        tracing::warn!(
+                            "Config key {} references ${name}, which is not set",
+                            path.join(".")
+                        );
+                        String::new()
+                    });
+                    refs.insert(path.clone(), name);
+                }
+            }
+            toml::Value::Table(child) => resolve_env_secret_refs_in_table(child, path, refs),
+            _ => {}
+        }
+        path.pop();
+    }
+}
+
+/// Whether the value at `path` still comes from `$name`, i.e. it was not
+/// replaced with a different literal after the reference was resolved.
+fn env_secret_ref_still_applies(table: &toml::Table, path: &[String], name: &str) -> bool {
+    let Some((leaf, parents)) = path.split_last() else {
+        return false;
+    };
+    let mut current = table;
+    for segment in parents {
+        match current.get(segment) {
+            Some(toml::Value::Table(child)) => current = child,
+            _ => return false,
+        }
+    }
+    match current.get(leaf).and_then(toml::Value::as_str) {
+        Some(value) => {
+            parse_env_secret_ref(value) == Some(name)
+                || std::env::var(name).is_ok_and(|resolved| resolved == value)
+        }
+        None => false,
+    }
+}
+
+/// Write `env:NAME` references back over resolved values before saving.
+fn restore_env_secret_refs(table: &mut toml::Table, refs: &BTreeMap<Vec<String>, String>) {
+    'refs: for (path, name) in refs {
+        let Some((leaf, parents)) = path.split_last() else {
+            continue;
+        };
+        let mut current = &mut *table;
+        for segment in parents {
+            match current.get_mut(segment) {
+                Some(toml::Value::Table(child)) => current = child,
+                _ => continue 'refs,
+            }
+        }
+        if current.contains_key(leaf) {
+            current.insert(
+                leaf.clone(),
+                toml::Value::String(format!("{ENV_SECRET_PREFIX}{name}")),
+            );
+        }
+    }
+}
+
 fn config_dir_creation_error(path: &Path) -> String {
     format!(
         "Failed to create config directory: {}. If running as an OpenRC service, \
@@ -4039,10 +6232,21 @@ impl Config {
                 }
             }
 
-            let contents = fs::read_to_string(&config_path)
+            let mut contents = fs::read_to_string(&config_path)
                 .await
                 .context("Failed to read config file")?;
 
+            // Resolve `env:NAME` secret references before typed deserialization.
+            let mut env_secret_refs = BTreeMap::new();
+            if contents.contains(ENV_SECRET_PREFIX) {
+                let mut table: toml::Table =
+                    toml::from_str(&contents).context("Failed to parse config file")?;
+                resolve_env_secret_refs_in_table(&mut table, &mut Vec::new(), &mut env_secret_refs);
+                if !env_secret_refs.is_empty() {
+                    contents = toml::to_string(&table).context("Failed to parse config file")?;
+                }
+            }
+
             // Track ignored/unknown config keys to warn users about silent misconfigurations
             // (e.g., using [providers.ollama] which doesn't exist instead of top-level api_url)
             let mut ignored_paths: Vec<String> = Vec::new();
@@ -4064,6 +6268,7 @@ impl Config {
             // Set computed paths that are skipped during serialization
             config.config_path = config_path.clone();
             config.workspace_dir = workspace_dir;
+            config.env_secret_refs = env_secret_refs;
             let store = crate::security::SecretStore::new(&zeroclaw_dir, config.secrets.encrypt);
             decrypt_optional_secret(&store, &mut config.api_key, "config.api_key")?;
             decrypt_optional_secret(
@@ -4094,6 +6299,22 @@ impl Config {
                 decrypt_optional_secret(&store, &mut agent.api_key, "config.agents.*.api_key")?;
             }
 
+            decrypt_optional_secret(
+                &store,
+                &mut config.backup.passphrase,
+                "config.backup.passphrase",
+            )?;
+            decrypt_optional_secret(
+                &store,
+                &mut config.backup.s3.secret_access_key,
+                "config.backup.s3.secret_access_key",
+            )?;
+            decrypt_optional_secret(
+                &store,
+                &mut config.backup.webdav.password,
+                "config.backup.webdav.password",
+            )?;
+
             if let Some(ref mut ns) = config.channels_config.nostr {
                 decrypt_secret(
                     &store,
@@ -4244,6 +6465,124 @@ impl Config {
             }
         }
 
+        // Agent tool limits
+        for (tool, limit) in &self.agent.tool_limits {
+            if tool.trim().is_empty() {
+                anyhow::bail!("agent.tool_limits keys must be non-empty tool names");
+            }
+            for (key, value) in [
+                ("max_per_turn", limit.max_per_turn),
+                ("max_per_session", limit.max_per_session),
+                ("max_per_hour", limit.max_per_hour),
+            ] {
+                if value == Some(0) {
+                    anyhow::bail!(
+                        "agent.tool_limits.{tool}.{key} must be greater than 0; exclude the tool instead of budgeting it to zero"
+                    );
+                }
+            }
+        }
+
+        // Schedules
+        let mut schedule_names = std::collections::HashSet::new();
+        for (i, job) in self.schedules.jobs.iter().enumerate() {
+            let name = job.name.trim();
+            if name.is_empty() {
+                anyhow::bail!("schedules.jobs[{i}].name must not be empty");
+            }
+            if !schedule_names.insert(name) {
+                anyhow::bail!("schedules.jobs[{i}].name `{name}` is used more than once");
+            }
+            if job.cron.trim().is_empty() || job.prompt.trim().is_empty() {
+                anyhow::bail!("schedules.jobs[{i}] (`{name}`) needs both cron and prompt");
+            }
+            if job.channel.is_some() != job.to.is_some() {
+                anyhow::bail!("schedules.jobs[{i}] (`{name}`) must set channel and to together");
+            }
+        }
+
+        // Channel translation
+        for (channel, translation) in &self.channels_config.translation {
+            if translation.translate_to.trim().is_empty() {
+                anyhow::bail!(
+                    "channels_config.translation.{channel}.translate_to must not be empty"
+                );
+            }
+        }
+
+        // Budget downgrade
+        let downgrade = &self.cost.downgrade;
+        if downgrade.enabled {
+            if downgrade.threshold_percent == 0 || downgrade.threshold_percent > 100 {
+                anyhow::bail!("cost.downgrade.threshold_percent must be between 1 and 100");
+            }
+            if downgrade
+                .model
+                .as_deref()
+                .map_or(true, |model| model.trim().is_empty())
+            {
+                anyhow::bail!("cost.downgrade.model must be set when the downgrade is enabled");
+            }
+            if downgrade
+                .force_model_users
+                .iter()
+                .any(|user| user.trim() == "*")
+            {
+                anyhow::bail!(
+                    "cost.downgrade.force_model_users must list sender IDs; \"*\" is not allowed"
+                );
+            }
+            if downgrade.notify_channel.is_some() != downgrade.notify_to.is_some() {
+                anyhow::bail!("cost.downgrade.notify_channel and notify_to must be set together");
+            }
+        }
+
+        // Behavior escalation
+        let escalation = &self.behavior.escalation;
+        if escalation.enabled {
+            if escalation.threshold == 0 {
+                anyhow::bail!("behavior.escalation.threshold must be greater than 0");
+            }
+            if escalation.actions.contains(&EscalationAction::NotifyOwner)
+                && (escalation.notify_channel.is_none() || escalation.notify_to.is_none())
+            {
+                anyhow::bail!(
+                    "behavior.escalation action notify_owner requires notify_channel and notify_to"
+                );
+            }
+            if escalation.actions.contains(&EscalationAction::SwitchModel)
+                && escalation
+                    .model
+                    .as_deref()
+                    .map_or(true, |model| model.trim().is_empty())
+            {
+                anyhow::bail!("behavior.escalation action switch_model requires model");
+            }
+        }
+
+        for user in &self.observability.debug_command_users {
+            match user.split_once(':') {
+                Some((channel, sender))
+                    if !channel.trim().is_empty()
+                        && !sender.trim().is_empty()
+                        && channel.trim() != "*"
+                        && sender.trim() != "*" => {}
+                _ => anyhow::bail!(
+                    "observability.debug_command_users entries must be <channel>:<sender> \
+                     owner IDs (got {user:?}); \"*\" is not allowed"
+                ),
+            }
+        }
+
+        if self
+            .workspaces
+            .switch_users
+            .iter()
+            .any(|user| user.trim() == "*")
+        {
+            anyhow::bail!("workspaces.switch_users must list sender IDs; \"*\" is not allowed");
+        }
+
         // Security OTP / estop
         if self.security.otp.token_ttl_secs == 0 {
             anyhow::bail!("security.otp.token_ttl_secs must be greater than 0");
@@ -4277,9 +6616,38 @@ impl Config {
         .with_context(|| {
             "Invalid security.otp.gated_domains or security.otp.gated_domain_categories"
         })?;
+        let deadman = &self.heartbeat.deadman;
+        if deadman.enabled {
+            if deadman.ping_url.is_none() && deadman.checkin_timeout_hours.is_none() {
+                anyhow::bail!(
+                    "heartbeat.deadman requires ping_url and/or checkin_timeout_hours when enabled"
+                );
+            }
+            if let Some(url) = &deadman.ping_url {
+                let parsed = reqwest::Url::parse(url)
+                    .with_context(|| format!("heartbeat.deadman.ping_url is invalid: {url}"))?;
+                if !matches!(parsed.scheme(), "http" | "https") {
+                    anyhow::bail!("heartbeat.deadman.ping_url must use http or https");
+                }
+            }
+            if deadman.interval_minutes == 0 || deadman.max_failed_pings == 0 {
+                anyhow::bail!(
+                    "heartbeat.deadman.interval_minutes and max_failed_pings must be greater than 0"
+                );
+            }
+            if deadman.checkin_timeout_hours == Some(0) {
+                anyhow::bail!("heartbeat.deadman.checkin_timeout_hours must be greater than 0");
+            }
+        }
+
         if self.security.estop.state_file.trim().is_empty() {
             anyhow::bail!("security.estop.state_file must not be empty");
         }
+        for (i, target) in self.security.estop.notify.iter().enumerate() {
+            if target.channel.trim().is_empty() || target.to.trim().is_empty() {
+                anyhow::bail!("security.estop.notify[{i}] requires non-empty channel and to");
+            }
+        }
 
         // Scheduler
         if self.scheduler.max_concurrent == 0 {
@@ -4360,6 +6728,24 @@ impl Config {
             }
         }
 
+        for (service, request) in &self.provider_requests {
+            request.validate(service)?;
+        }
+
+        // Memory classification taxonomy
+        for (category, keywords) in &self.memory.classification.taxonomy {
+            if category.trim().is_empty() || category.chars().any(char::is_whitespace) {
+                anyhow::bail!(
+                    "memory.classification.taxonomy category `{category}` must be a single word"
+                );
+            }
+            if keywords.iter().any(|keyword| keyword.trim().is_empty()) {
+                anyhow::bail!(
+                    "memory.classification.taxonomy.{category} contains an empty keyword"
+                );
+            }
+        }
+
         // Ollama cloud-routing safety checks
         if self
             .default_provider
@@ -4383,6 +6769,16 @@ impl Config {
             }
         }
 
+        // Redaction
+        for (i, custom) in self.redaction.custom.iter().enumerate() {
+            if custom.name.trim().is_empty() {
+                anyhow::bail!("redaction.custom[{i}].name must not be empty");
+            }
+            if let Err(error) = regex::Regex::new(&custom.pattern) {
+                anyhow::bail!("redaction.custom[{i}].pattern is not a valid regex: {error}");
+            }
+        }
+
         // Proxy (delegate to existing validation)
         self.proxy.validate()?;
 
@@ -4676,6 +7072,42 @@ impl Config {
         }
 
         set_runtime_proxy_config(self.proxy.clone());
+        set_runtime_provider_requests(&self.provider_requests);
+    }
+
+    /// Resolve `env:NAME` values set in memory (e.g. by the onboarding wizard)
+    /// and remember them, so the running process sees the secret while
+    /// `save` keeps only the reference in config.toml.
+    pub fn resolve_env_secret_refs(&mut self) -> Result<()> {
+        let mut table = toml::Table::try_from(&*self).context("Failed to serialize config")?;
+
+        // Forget references whose value was since replaced with a literal secret.
+        self.env_secret_refs
+            .retain(|path, name| env_secret_ref_still_applies(&table, path, name));
+
+        let mut refs = BTreeMap::new();
+        resolve_env_secret_refs_in_table(&mut table, &mut Vec::new(), &mut refs);
+        if refs.is_empty() {
+            return Ok(());
+        }
+
+        let mut resolved: Config = table
+            .try_into()
+            .context("Failed to apply env secret references")?;
+        resolved.workspace_dir = std::mem::take(&mut self.workspace_dir);
+        resolved.config_path = std::mem::take(&mut self.config_path);
+        resolved.env_secret_refs = std::mem::take(&mut self.env_secret_refs);
+        resolved.env_secret_refs.extend(refs);
+        *self = resolved;
+        Ok(())
+    }
+
+    /// The TOML table `save()` writes, before secret encryption: `env:NAME`
+    /// references are restored but other secrets are plaintext.
+    pub fn to_toml_table(&self) -> Result<toml::Table> {
+        let mut table = toml::Table::try_from(self).context("Failed to serialize config")?;
+        restore_env_secret_refs(&mut table, &self.env_secret_refs);
+        Ok(table)
     }
 
     pub async fn save(&self) -> Result<()> {
@@ -4716,6 +7148,22 @@ impl Config {
             encrypt_optional_secret(&store, &mut agent.api_key, "config.agents.*.api_key")?;
         }
 
+        encrypt_optional_secret(
+            &store,
+            &mut config_to_save.backup.passphrase,
+            "config.backup.passphrase",
+        )?;
+        encrypt_optional_secret(
+            &store,
+            &mut config_to_save.backup.s3.secret_access_key,
+            "config.backup.s3.secret_access_key",
+        )?;
+        encrypt_optional_secret(
+            &store,
+            &mut config_to_save.backup.webdav.password,
+            "config.backup.webdav.password",
+        )?;
+
         if let Some(ref mut ns) = config_to_save.channels_config.nostr {
             encrypt_secret(
                 &store,
@@ -4724,8 +7172,14 @@ impl Config {
             )?;
         }
 
-        let toml_str =
-            toml::to_string_pretty(&config_to_save).context("Failed to serialize config")?;
+        let toml_str = if self.env_secret_refs.is_empty() {
+            toml::to_string_pretty(&config_to_save).context("Failed to serialize config")?
+        } else {
+            let mut table =
+                toml::Table::try_from(&config_to_save).context("Failed to serialize config")?;
+            restore_env_secret_refs(&mut table, &self.env_secret_refs);
+            toml::to_string_pretty(&table).context("Failed to serialize config")?
+        };
 
         let parent_dir = self
             .config_path
@@ -4833,6 +7287,23 @@ async fn sync_directory(path: &Path) -> Result<()> {
     }
 }
 
+/// JSON Schema for `config.toml` as accepted by this build. Channel sections
+/// whose cargo feature is compiled out are dropped from `channels_config`, so
+/// editors only offer what the binary can run.
+pub fn config_json_schema() -> serde_json::Value {
+    let schema = schemars::schema_for!(Config);
+    let mut value = serde_json::to_value(&schema).expect("failed to serialize JSON Schema");
+    if let Some(channels) = value
+        .pointer_mut("/$defs/ChannelsConfig/properties")
+        .and_then(serde_json::Value::as_object_mut)
+    {
+        for builtin in crate::channels::registry::compiled_out_builtins() {
+            channels.remove(builtin.key);
+        }
+    }
+    value
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4913,6 +7384,30 @@ mod tests {
         );
     }
 
+    #[test]
+    async fn config_json_schema_matches_compiled_channel_features() {
+        let schema = config_json_schema();
+        let channels = schema
+            .pointer("/$defs/ChannelsConfig/properties")
+            .and_then(serde_json::Value::as_object)
+            .expect("schema should describe channels_config");
+
+        assert!(channels.contains_key("plugins"));
+        assert!(channels.contains_key("webhook"));
+        assert_eq!(
+            channels.contains_key("telegram"),
+            cfg!(feature = "channel-telegram")
+        );
+        assert_eq!(
+            channels.contains_key("matrix"),
+            cfg!(feature = "channel-matrix")
+        );
+        assert_eq!(
+            channels.contains_key("email"),
+            cfg!(feature = "channel-email")
+        );
+    }
+
     #[cfg(unix)]
     #[test]
     async fn save_sets_config_permissions_on_new_file() {
@@ -4995,6 +7490,36 @@ recipient = "42"
         assert_eq!(parsed.message.as_deref(), Some("Ping"));
         assert_eq!(parsed.target.as_deref(), Some("telegram"));
         assert_eq!(parsed.to.as_deref(), Some("42"));
+        assert!(!parsed.deadman.enabled);
+    }
+
+    #[test]
+    async fn heartbeat_deadman_parses_and_validates() {
+        let mut config: Config = toml::from_str(
+            r#"
+default_provider = "openrouter"
+default_model = "anthropic/claude-sonnet-4.6"
+default_temperature = 0.7
+
+[heartbeat.deadman]
+enabled = true
+ping_url = "https://hc-ping.com/abc"
+checkin_timeout_hours = 24
+action = "stop_channels"
+"#,
+        )
+        .unwrap();
+        let deadman = &config.heartbeat.deadman;
+        assert_eq!(deadman.action, DeadmanAction::StopChannels);
+        assert_eq!(deadman.interval_minutes, 5);
+        assert_eq!(deadman.max_failed_pings, 3);
+        config.validate().unwrap();
+
+        config.heartbeat.deadman.ping_url = Some("ftp://example.com".into());
+        assert!(config.validate().is_err());
+        config.heartbeat.deadman.ping_url = None;
+        config.heartbeat.deadman.checkin_timeout_hours = None;
+        assert!(config.validate().is_err());
     }
 
     #[test]
@@ -5029,6 +7554,119 @@ default_temperature = 0.7
         assert_eq!(parsed.cron.max_run_history, 50);
     }
 
+    #[test]
+    async fn ui_notifications_parse_partial_section() {
+        let toml_str = r#"
+workspace_dir = "/tmp/workspace"
+config_path = "/tmp/config.toml"
+default_temperature = 0.7
+
+[ui.notifications]
+bell = true
+events = ["channel_down", "approval_requested"]
+"#;
+
+        let parsed: Config = toml::from_str(toml_str).unwrap();
+        let notifications = &parsed.ui.notifications;
+        assert!(notifications.bell);
+        assert!(!notifications.desktop);
+        assert_eq!(
+            notifications.events,
+            vec![NotifyEvent::ChannelDown, NotifyEvent::ApprovalRequested]
+        );
+
+        let defaults: Config = toml::from_str(
+            "workspace_dir = \"/tmp/workspace\"\nconfig_path = \"/tmp/config.toml\"\ndefault_temperature = 0.7\n",
+        )
+        .unwrap();
+        assert_eq!(defaults.ui.notifications.events.len(), 3);
+    }
+
+    #[test]
+    async fn redaction_section_parses_channel_overrides_and_custom_patterns() {
+        let toml_str = r#"
+workspace_dir = "/tmp/workspace"
+config_path = "/tmp/config.toml"
+default_temperature = 0.7
+
+[redaction]
+enabled = true
+policy = "hash"
+
+[redaction.channels]
+telegram = "drop"
+
+[[redaction.custom]]
+name = "employee_id"
+pattern = "EMP-\\d{5}"
+"#;
+
+        let parsed: Config = toml::from_str(toml_str).unwrap();
+        let redaction = &parsed.redaction;
+        assert!(redaction.enabled);
+        assert_eq!(redaction.detectors.len(), 3);
+        assert_eq!(redaction.policy_for("telegram"), RedactionPolicy::Drop);
+        assert_eq!(redaction.policy_for("discord"), RedactionPolicy::Hash);
+        parsed.validate().unwrap();
+
+        let mut invalid = parsed.clone();
+        invalid.redaction.custom[0].pattern = "(".into();
+        let err = invalid.validate().unwrap_err().to_string();
+        assert!(err.contains("redaction.custom[0].pattern"));
+    }
+
+    #[test]
+    async fn env_secret_refs_resolve_and_restore_by_toml_path() {
+        std::env::set_var("ZEROCLAW_TEST_ENV_SECRET_REF", "secret-value");
+        let mut table: toml::Table = toml::from_str(
+            r#"
+api_key = "env:ZEROCLAW_TEST_ENV_SECRET_REF"
+default_model = "env:not a reference"
+
+[channels_config.telegram]
+bot_token = "env:ZEROCLAW_TEST_ENV_SECRET_UNSET"
+"#,
+        )
+        .unwrap();
+
+        let mut refs = BTreeMap::new();
+        resolve_env_secret_refs_in_table(&mut table, &mut Vec::new(), &mut refs);
+        std::env::remove_var("ZEROCLAW_TEST_ENV_SECRET_REF");
+
+        assert_eq!(table["api_key"].as_str(), Some("secret-value"));
+        assert_eq!(table["default_model"].as_str(), Some("env:not a reference"));
+        assert_eq!(
+            table["channels_config"]["telegram"]["bot_token"].as_str(),
+            Some("")
+        );
+        let telegram_path = vec![
+            "channels_config".to_string(),
+            "telegram".to_string(),
+            "bot_token".to_string(),
+        ];
+        assert_eq!(refs.len(), 2);
+        assert_eq!(
+            refs.get(&telegram_path).map(String::as_str),
+            Some("ZEROCLAW_TEST_ENV_SECRET_UNSET")
+        );
+
+        restore_env_secret_refs(&mut table, &refs);
+        assert_eq!(
+            table["api_key"].as_str(),
+            Some("env:ZEROCLAW_TEST_ENV_SECRET_REF")
+        );
+        assert!(env_secret_ref_still_applies(
+            &table,
+            &telegram_path,
+            "ZEROCLAW_TEST_ENV_SECRET_UNSET"
+        ));
+        assert!(!env_secret_ref_still_applies(
+            &table,
+            &telegram_path,
+            "SOME_OTHER_VAR"
+        ));
+    }
+
     #[test]
     async fn memory_config_default_hygiene_settings() {
         let m = MemoryConfig::default();
@@ -5041,6 +7679,80 @@ default_temperature = 0.7
         assert!(m.sqlite_open_timeout_secs.is_none());
     }
 
+    #[test]
+    async fn memory_config_parses_category_ttls() {
+        let raw = r#"
+backend = "sqlite"
+auto_save = true
+
+[categories.scratch]
+ttl_hours = 24
+
+[categories.preferences]
+"#;
+        let parsed: MemoryConfig = toml::from_str(raw).unwrap();
+        assert_eq!(parsed.categories["scratch"].ttl_hours, 24);
+        assert_eq!(parsed.categories["preferences"].ttl_hours, 0);
+        assert!(MemoryConfig::default().categories.is_empty());
+    }
+
+    #[test]
+    async fn memory_classification_taxonomy_replaces_defaults_and_validates() {
+        let raw = r#"
+backend = "sqlite"
+auto_save = true
+
+[classification]
+enabled = true
+
+[classification.taxonomy]
+errand = ["buy", "pick up"]
+"#;
+        let parsed: MemoryConfig = toml::from_str(raw).unwrap();
+        assert!(parsed.classification.enabled);
+        assert_eq!(
+            parsed.classification.taxonomy.keys().collect::<Vec<_>>(),
+            vec!["errand"]
+        );
+        assert!(MemoryConfig::default()
+            .classification
+            .taxonomy
+            .contains_key("preference"));
+
+        let mut config = Config::default();
+        config.memory.classification.taxonomy =
+            BTreeMap::from([("two words".to_string(), vec!["x".to_string()])]);
+        assert!(config.validate().is_err());
+        config.memory.classification.taxonomy =
+            BTreeMap::from([("task".to_string(), vec![" ".to_string()])]);
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    async fn memory_config_parses_sqlite_tuning() {
+        let raw = r#"
+backend = "sqlite"
+auto_save = true
+sqlite_open_timeout_secs = 15
+
+[sqlite]
+journal_mode = "delete"
+synchronous = "full"
+mmap_size_bytes = 0
+"#;
+        let parsed: MemoryConfig = toml::from_str(raw).unwrap();
+        assert_eq!(parsed.sqlite_open_timeout_secs, Some(15));
+        assert_eq!(parsed.sqlite.journal_mode, SqliteJournalMode::Delete);
+        assert_eq!(parsed.sqlite.synchronous, SqliteSynchronous::Full);
+        assert_eq!(parsed.sqlite.mmap_size_bytes, 0);
+        assert_eq!(parsed.sqlite.cache_size_kib, 2000);
+
+        let defaults = MemoryConfig::default().sqlite;
+        assert_eq!(defaults.journal_mode, SqliteJournalMode::Wal);
+        assert_eq!(defaults.synchronous, SqliteSynchronous::Normal);
+        assert_eq!(defaults.mmap_size_bytes, 8 * 1024 * 1024);
+    }
+
     #[test]
     async fn storage_provider_config_defaults() {
         let storage = StorageConfig::default();
@@ -5071,6 +7783,7 @@ default_temperature = 0.7
             default_provider: Some("openrouter".into()),
             default_model: Some("gpt-4o".into()),
             model_providers: HashMap::new(),
+            provider_requests: HashMap::new(),
             default_temperature: 0.5,
             observability: ObservabilityConfig {
                 backend: "log".into(),
@@ -5108,8 +7821,11 @@ default_temperature = 0.7
                 message: Some("Check London time".into()),
                 target: Some("telegram".into()),
                 to: Some("123456".into()),
+                deadman: DeadmanSwitchConfig::default(),
             },
             cron: CronConfig::default(),
+            schedules: SchedulesConfig::default(),
+            workspaces: WorkspacesConfig::default(),
             channels_config: ChannelsConfig {
                 cli: true,
                 telegram: Some(TelegramConfig {
@@ -5119,6 +7835,8 @@ default_temperature = 0.7
                     draft_update_interval_ms: default_draft_update_interval_ms(),
                     interrupt_on_new_message: false,
                     mention_only: false,
+                    quote_replies: false,
+                    lightweight_acks: false,
                 }),
                 discord: None,
                 slack: None,
@@ -5139,6 +7857,8 @@ default_temperature = 0.7
                 qq: None,
                 nostr: None,
                 clawdtalk: None,
+                plugins: BTreeMap::new(),
+                translation: BTreeMap::new(),
                 message_timeout_secs: 300,
             },
             memory: MemoryConfig::default(),
@@ -5161,6 +7881,16 @@ default_temperature = 0.7
             hooks: HooksConfig::default(),
             hardware: HardwareConfig::default(),
             transcription: TranscriptionConfig::default(),
+            workspace_sync: WorkspaceSyncConfig::default(),
+            shutdown: ShutdownConfig::default(),
+            backup: BackupConfig::default(),
+            power: PowerConfig::default(),
+            connectivity: ConnectivityConfig::default(),
+            ui: UiConfig::default(),
+            redaction: RedactionConfig::default(),
+            behavior: BehaviorConfig::default(),
+            env_secret_refs: BTreeMap::new(),
+            record_llm_dir: None,
         };
 
         let toml_str = toml::to_string_pretty(&config).unwrap();
@@ -5309,6 +8039,7 @@ tool_dispatcher = "xml"
             default_provider: Some("openrouter".into()),
             default_model: Some("test-model".into()),
             model_providers: HashMap::new(),
+            provider_requests: HashMap::new(),
             default_temperature: 0.9,
             observability: ObservabilityConfig::default(),
             autonomy: AutonomyConfig::default(),
@@ -5322,6 +8053,8 @@ tool_dispatcher = "xml"
             query_classification: QueryClassificationConfig::default(),
             heartbeat: HeartbeatConfig::default(),
             cron: CronConfig::default(),
+            schedules: SchedulesConfig::default(),
+            workspaces: WorkspacesConfig::default(),
             channels_config: ChannelsConfig::default(),
             memory: MemoryConfig::default(),
             storage: StorageConfig::default(),
@@ -5343,6 +8076,16 @@ tool_dispatcher = "xml"
             hooks: HooksConfig::default(),
             hardware: HardwareConfig::default(),
             transcription: TranscriptionConfig::default(),
+            workspace_sync: WorkspaceSyncConfig::default(),
+            shutdown: ShutdownConfig::default(),
+            backup: BackupConfig::default(),
+            power: PowerConfig::default(),
+            connectivity: ConnectivityConfig::default(),
+            ui: UiConfig::default(),
+            redaction: RedactionConfig::default(),
+            behavior: BehaviorConfig::default(),
+            env_secret_refs: BTreeMap::new(),
+            record_llm_dir: None,
         };
 
         config.save().await.unwrap();
@@ -5490,6 +8233,8 @@ tool_dispatcher = "xml"
             draft_update_interval_ms: 500,
             interrupt_on_new_message: true,
             mention_only: false,
+            quote_replies: false,
+            lightweight_acks: false,
         };
         let json = serde_json::to_string(&tc).unwrap();
         let parsed: TelegramConfig = serde_json::from_str(&json).unwrap();
@@ -5517,6 +8262,8 @@ tool_dispatcher = "xml"
             allowed_users: vec![],
             listen_to_bots: false,
             mention_only: false,
+            quote_replies: false,
+            lightweight_acks: false,
         };
         let json = serde_json::to_string(&dc).unwrap();
         let parsed: DiscordConfig = serde_json::from_str(&json).unwrap();
@@ -5532,6 +8279,8 @@ tool_dispatcher = "xml"
             allowed_users: vec![],
             listen_to_bots: false,
             mention_only: false,
+            quote_replies: false,
+            lightweight_acks: false,
         };
         let json = serde_json::to_string(&dc).unwrap();
         let parsed: DiscordConfig = serde_json::from_str(&json).unwrap();
@@ -5703,6 +8452,8 @@ allowed_users = ["@ops:matrix.org"]
             qq: None,
             nostr: None,
             clawdtalk: None,
+            plugins: BTreeMap::new(),
+            translation: BTreeMap::new(),
             message_timeout_secs: 300,
         };
         let toml_str = toml::to_string_pretty(&c).unwrap();
@@ -5917,6 +8668,8 @@ channel_id = "C123"
             qq: None,
             nostr: None,
             clawdtalk: None,
+            plugins: BTreeMap::new(),
+            translation: BTreeMap::new(),
             message_timeout_secs: 300,
         };
         let toml_str = toml::to_string_pretty(&c).unwrap();
@@ -6002,6 +8755,7 @@ channel_id = "C123"
             rate_limit_max_keys: 2048,
             idempotency_ttl_secs: 600,
             idempotency_max_keys: 4096,
+            selftest: GatewaySelftestConfig::default(),
         };
         let toml_str = toml::to_string(&g).unwrap();
         let parsed: GatewayConfig = toml::from_str(&toml_str).unwrap();
@@ -6724,6 +9478,49 @@ requires_openai_auth = true
         let _ = fs::remove_dir_all(default_config_dir).await;
     }
 
+    #[test]
+    async fn resolve_runtime_config_dirs_nests_active_profile() {
+        let _env_guard = env_override_lock().await;
+        let default_config_dir = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+        let default_workspace_dir = default_config_dir.join("workspace");
+
+        std::env::remove_var("ZEROCLAW_WORKSPACE");
+        std::env::set_var("ZEROCLAW_PROFILE", "work");
+        let (config_dir, resolved_workspace_dir, source) =
+            resolve_runtime_config_dirs(&default_config_dir, &default_workspace_dir)
+                .await
+                .unwrap();
+        std::env::set_var("ZEROCLAW_PROFILE", "../escape");
+        let invalid =
+            resolve_runtime_config_dirs(&default_config_dir, &default_workspace_dir).await;
+        std::env::remove_var("ZEROCLAW_PROFILE");
+
+        assert_eq!(source, ConfigResolutionSource::Profile);
+        assert_eq!(config_dir, default_config_dir.join("profiles").join("work"));
+        assert_eq!(resolved_workspace_dir, config_dir.join("workspace"));
+        assert!(is_profile_config_dir(&config_dir));
+        assert!(!is_profile_config_dir(&default_config_dir));
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    async fn profiles_are_listed_from_the_base_config_dir() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path();
+        for name in ["lab", "client-a"] {
+            let dir = profile_config_dir(base, name);
+            fs::create_dir_all(&dir).await.unwrap();
+            fs::write(dir.join("config.toml"), "").await.unwrap();
+        }
+        fs::create_dir_all(profile_config_dir(base, "empty"))
+            .await
+            .unwrap();
+
+        assert_eq!(list_profiles(base), vec!["client-a", "lab"]);
+        assert_eq!(profile_base_dir(&profile_config_dir(base, "lab")), base);
+        assert_eq!(profile_base_dir(base), base);
+    }
+
     #[test]
     async fn load_or_init_workspace_override_uses_workspace_root_for_config() {
         let _env_guard = env_override_lock().await;
@@ -7143,12 +9940,93 @@ default_model = "legacy-model"
             no_proxy: Vec::new(),
             scope: ProxyScope::Services,
             services: Vec::new(),
+            ..ProxyConfig::default()
         };
 
         let error = proxy.validate().unwrap_err().to_string();
         assert!(error.contains("proxy.scope='services'"));
     }
 
+    #[test]
+    async fn proxy_config_parses_provider_overrides_and_tls() {
+        let raw = r#"
+enabled = true
+https_proxy = "http://corp-proxy:3128"
+ca_bundle = "/etc/ssl/corp-ca.pem"
+
+[providers.ollama]
+direct = true
+
+[providers.compatible]
+proxy = "socks5h://127.0.0.1:1080"
+client_cert = "/etc/zeroclaw/client.pem"
+client_key = "/etc/zeroclaw/client.key"
+"#;
+        let proxy: ProxyConfig = toml::from_str(raw).unwrap();
+        proxy.validate().unwrap();
+
+        assert_eq!(proxy.ca_bundle.as_deref(), Some("/etc/ssl/corp-ca.pem"));
+        assert!(proxy.provider_override("provider.ollama").unwrap().direct);
+        assert_eq!(
+            proxy
+                .provider_override("provider.Compatible")
+                .and_then(|entry| entry.proxy.as_deref()),
+            Some("socks5h://127.0.0.1:1080")
+        );
+        assert!(proxy.provider_override("provider.openai").is_none());
+        assert!(proxy.provider_override("channel.ollama").is_none());
+    }
+
+    #[test]
+    async fn proxy_config_rejects_unpaired_client_certificate() {
+        let mut proxy = ProxyConfig {
+            client_cert: Some("/tmp/client.pem".into()),
+            ..ProxyConfig::default()
+        };
+        let error = proxy.validate().unwrap_err().to_string();
+        assert!(error.contains("proxy.client_cert"));
+
+        proxy.client_cert = None;
+        proxy.providers.insert(
+            "openai".into(),
+            ProviderProxyConfig {
+                client_key: Some("/tmp/client.key".into()),
+                ..ProviderProxyConfig::default()
+            },
+        );
+        let error = proxy.validate().unwrap_err().to_string();
+        assert!(error.contains("proxy.providers.openai.client_key"));
+    }
+
+    #[test]
+    async fn proxy_config_missing_ca_bundle_is_an_error() {
+        let proxy = ProxyConfig {
+            ca_bundle: Some("/nonexistent/zeroclaw-ca.pem".into()),
+            ..ProxyConfig::default()
+        };
+        let error = proxy
+            .apply_to_reqwest_builder(reqwest::Client::builder(), "provider.openai")
+            .unwrap_err();
+        assert!(format!("{error:#}").contains("/nonexistent/zeroclaw-ca.pem"));
+    }
+
+    #[test]
+    async fn proxy_config_rejects_provider_ids_without_a_client_family() {
+        let mut proxy = ProxyConfig::default();
+        proxy
+            .providers
+            .insert("groq".into(), ProviderProxyConfig::default());
+        let error = proxy.validate().unwrap_err().to_string();
+        assert!(error.contains("proxy.providers.groq"));
+        assert!(error.contains("compatible"));
+
+        proxy.providers.clear();
+        proxy
+            .providers
+            .insert("Bedrock".into(), ProviderProxyConfig::default());
+        proxy.validate().unwrap();
+    }
+
     #[test]
     async fn env_override_proxy_scope_services() {
         let _env_guard = env_override_lock().await;
@@ -7255,6 +10133,65 @@ default_model = "legacy-model"
         assert!(!runtime_proxy_cache_contains(&cache_key));
     }
 
+    #[test]
+    async fn provider_requests_validate_service_and_headers() {
+        let mut config = Config::default();
+        config.provider_requests.insert(
+            "openrouter".into(),
+            ProviderRequestConfig {
+                headers: BTreeMap::from([
+                    ("HTTP-Referer".into(), "https://example.com".into()),
+                    ("X-Title".into(), "ZeroClaw".into()),
+                ]),
+                query: BTreeMap::new(),
+            },
+        );
+        config.provider_requests.insert(
+            "provider.compatible".into(),
+            ProviderRequestConfig {
+                headers: BTreeMap::new(),
+                query: BTreeMap::from([("api-version".into(), "2024-10-21".into())]),
+            },
+        );
+        assert!(config.validate().is_ok());
+
+        let unknown = ProviderRequestConfig::default();
+        let error = unknown.validate("not-a-provider").unwrap_err();
+        assert!(error.to_string().contains("not a provider service"));
+
+        let credential = ProviderRequestConfig {
+            headers: BTreeMap::from([("Authorization".into(), "Bearer x".into())]),
+            query: BTreeMap::new(),
+        };
+        let error = credential.validate("openai").unwrap_err();
+        assert!(error.to_string().contains("must not set"));
+
+        let bad_name = ProviderRequestConfig {
+            headers: BTreeMap::from([("bad header".into(), "x".into())]),
+            query: BTreeMap::new(),
+        };
+        assert!(bad_name.validate("openai").is_err());
+    }
+
+    #[test]
+    async fn provider_request_query_follows_runtime_config() {
+        set_runtime_provider_requests(&HashMap::from([(
+            "glm".to_string(),
+            ProviderRequestConfig {
+                headers: BTreeMap::new(),
+                query: BTreeMap::from([("tenant".into(), "acme".into())]),
+            },
+        )]));
+        assert_eq!(
+            provider_request_query("provider.glm"),
+            vec![("tenant".to_string(), "acme".to_string())]
+        );
+        assert!(provider_request_query("provider.openai").is_empty());
+
+        set_runtime_provider_requests(&HashMap::new());
+        assert!(provider_request_query("provider.glm").is_empty());
+    }
+
     #[test]
     async fn gateway_config_default_values() {
         let g = GatewayConfig::default();
@@ -7295,8 +10232,12 @@ default_model = "legacy-model"
                 transport: "serial".into(),
                 path: Some("/dev/ttyACM0".into()),
                 baud: 115_200,
+                sensors: Vec::new(),
+                remote: None,
             }],
             datasheet_dir: None,
+            recorder: SensorRecorderConfig::default(),
+            node: PeripheralNodeConfig::default(),
         };
         let toml_str = toml::to_string(&p).unwrap();
         let parsed: PeripheralsConfig = toml::from_str(&toml_str).unwrap();
@@ -7603,17 +10544,70 @@ gated_domain_categories = ["banking"]
 enabled = true
 state_file = "~/.zeroclaw/estop-state.json"
 require_otp_to_resume = true
+remote_trigger = true
+
+[[security.estop.notify]]
+channel = "telegram"
+to = "123456"
 "#,
         )
         .unwrap();
 
         assert!(parsed.security.otp.enabled);
         assert!(parsed.security.estop.enabled);
+        assert!(parsed.security.estop.remote_trigger);
+        assert_eq!(parsed.security.estop.notify[0].channel, "telegram");
         assert_eq!(parsed.security.otp.gated_actions.len(), 2);
         assert_eq!(parsed.security.otp.gated_domains.len(), 2);
         parsed.validate().unwrap();
     }
 
+    #[test]
+    async fn validation_rejects_wildcard_debug_command_users() {
+        let mut config = Config::default();
+        config.observability.debug_command_users = vec!["*".into()];
+
+        let err = config
+            .validate()
+            .expect_err("expected wildcard debug users to be rejected");
+        assert!(err.to_string().contains("debug_command_users"));
+
+        for invalid in ["alice", "telegram:*", ":alice", "telegram:"] {
+            config.observability.debug_command_users = vec![invalid.into()];
+            assert!(config.validate().is_err(), "{invalid} should be rejected");
+        }
+        config.observability.debug_command_users = vec!["telegram:123456789".into()];
+        config.validate().unwrap();
+    }
+
+    #[test]
+    async fn validation_rejects_wildcard_force_model_users() {
+        let mut config = Config::default();
+        config.cost.downgrade.enabled = true;
+        config.cost.downgrade.model = Some("cheap/model".into());
+        config.cost.downgrade.force_model_users = vec!["*".into()];
+
+        let err = config
+            .validate()
+            .expect_err("expected wildcard force-model users to be rejected");
+        assert!(err.to_string().contains("force_model_users"));
+    }
+
+    #[test]
+    async fn validation_rejects_wildcard_workspace_switch_users() {
+        let mut config = Config::default();
+        config.workspaces.switch_users = vec!["*".into()];
+        let err = config
+            .validate()
+            .expect_err("expected wildcard switch users to be rejected");
+        assert!(err.to_string().contains("switch_users"));
+
+        config.workspaces.switch_users = vec!["Alice".into()];
+        assert!(config.workspaces.can_switch("Alice"));
+        assert!(!config.workspaces.can_switch("alice"));
+        assert!(!config.workspaces.can_switch("*"));
+    }
+
     #[test]
     async fn security_validation_rejects_invalid_domain_glob() {
         let mut config = Config::default();
@@ -0,0 +1,500 @@
+//! Internet connectivity monitoring.
+//!
+//! The daemon probes `[connectivity].probe_hosts` with TCP connects. After
+//! `failures_before_offline` failed rounds the host counts as offline:
+//! outbound announcements are held (see [`try_queue`]), providers built
+//! with an `offline_provider` route to it, and the outage is logged to
+//! `state/connectivity.jsonl`. The first successful probe ends the outage
+//! and sends the held announcements.
+//!
+//! Offline state lives in the workspace's `state/` directory (the manual
+//! `offline_mode` flag, the monitor's `offline_detected` flag and the
+//! `offline_queue.jsonl` of held announcements), so every component built
+//! from the same config sees it through its own [`Connectivity`] handle. `zeroclaw status` and `zeroclaw
+//! doctor` report recent outage windows from the log.
+//!
+//! `zeroclaw offline on` forces offline mode by writing
+//! `state/offline_mode`: cloud providers refuse requests unless a local
+//! `offline_provider` can take them, announcements are held, and the
+//! daemon starts without channel listeners. `zeroclaw offline off`
+//! removes the flag; a running daemon notices within one probe interval
+//! and sends what it held.
+
+use crate::config::Config;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const LOG_FILE: &str = "connectivity.jsonl";
+const MANUAL_FLAG_FILE: &str = "offline_mode";
+const DETECTED_FLAG_FILE: &str = "offline_detected";
+const QUEUE_FILE: &str = "offline_queue.jsonl";
+const MIN_INTERVAL_SECS: u64 = 5;
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// `[connectivity]` settings for one workspace. Cheap to build from a
+/// config; the offline state it reads is shared through `state/` files.
+#[derive(Debug, Clone)]
+pub struct Connectivity {
+    workspace_dir: PathBuf,
+    /// Whether the daemon's monitor runs, so its flag can be trusted.
+    monitored: bool,
+    queue_outbound: bool,
+    max_queued: usize,
+    offline_provider: Option<(String, Option<String>)>,
+}
+
+impl Connectivity {
+    pub fn new(config: &Config) -> Self {
+        let connectivity = &config.connectivity;
+        let offline_provider = connectivity
+            .offline_provider
+            .as_deref()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| (name.to_string(), connectivity.offline_model.clone()));
+        Self {
+            workspace_dir: config.workspace_dir.clone(),
+            monitored: connectivity.enabled,
+            queue_outbound: connectivity.queue_outbound,
+            max_queued: connectivity.max_queued.max(1),
+            offline_provider,
+        }
+    }
+
+    /// Whether the workspace is offline: forced by `zeroclaw offline on`, or
+    /// detected by the daemon's monitor.
+    pub fn is_offline(&self) -> bool {
+        self.is_forced_offline() || (self.monitored && detected_offline(&self.workspace_dir))
+    }
+
+    /// Whether offline mode was switched on by hand.
+    pub fn is_forced_offline(&self) -> bool {
+        manual_offline(&self.workspace_dir)
+    }
+
+    /// The local provider and model to use while offline, when configured.
+    pub fn offline_route(&self) -> Option<(&str, Option<&str>)> {
+        let (name, model) = self.offline_provider.as_ref()?;
+        Some((name.as_str(), model.as_deref()))
+    }
+
+    /// Hold an announcement while offline. Returns false (send now) when
+    /// online or when queueing is off.
+    pub fn try_queue(&self, channel: &str, target: &str, text: &str) -> bool {
+        if !self.queue_outbound || !self.is_offline() {
+            return false;
+        }
+        let held = Held {
+            channel: channel.to_string(),
+            target: target.to_string(),
+            text: text.to_string(),
+        };
+        match push_held(&self.workspace_dir, &held, self.max_queued) {
+            Ok(()) => true,
+            Err(error) => {
+                tracing::warn!("Could not hold announcement while offline: {error}");
+                false
+            }
+        }
+    }
+}
+
+/// An announcement held back while offline.
+#[derive(Debug, Serialize, Deserialize)]
+struct Held {
+    channel: String,
+    target: String,
+    text: String,
+}
+
+fn state_path(workspace_dir: &Path, file: &str) -> PathBuf {
+    workspace_dir.join("state").join(file)
+}
+
+fn manual_flag_path(workspace_dir: &Path) -> PathBuf {
+    state_path(workspace_dir, MANUAL_FLAG_FILE)
+}
+
+/// Whether `zeroclaw offline on` is in effect for this workspace.
+pub fn manual_offline(workspace_dir: &Path) -> bool {
+    manual_flag_path(workspace_dir).exists()
+}
+
+/// Switch manual offline mode on or off for this workspace.
+pub fn set_manual_offline(workspace_dir: &Path, on: bool) -> Result<()> {
+    set_flag(&manual_flag_path(workspace_dir), on)
+}
+
+fn detected_offline(workspace_dir: &Path) -> bool {
+    state_path(workspace_dir, DETECTED_FLAG_FILE).exists()
+}
+
+fn set_flag(path: &Path, on: bool) -> Result<()> {
+    if on {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, format!("{}\n", Utc::now().to_rfc3339()))?;
+    } else if path.exists() {
+        std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// Append `held` to the queue, dropping the oldest entries past `max`.
+fn push_held(workspace_dir: &Path, held: &Held, max: usize) -> Result<()> {
+    let path = state_path(workspace_dir, QUEUE_FILE);
+    let mut lines: Vec<String> = std::fs::read_to_string(&path)
+        .map(|contents| contents.lines().map(str::to_string).collect())
+        .unwrap_or_default();
+    if lines.len() >= max {
+        let dropped = lines.len() + 1 - max;
+        lines.drain(..dropped);
+        tracing::warn!("Offline queue full; dropped the oldest held announcement");
+    }
+    lines.push(serde_json::to_string(held)?);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, lines.join("\n") + "\n")?;
+    Ok(())
+}
+
+/// Take every held announcement, emptying the queue.
+fn take_held(workspace_dir: &Path) -> Vec<Held> {
+    let path = state_path(workspace_dir, QUEUE_FILE);
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        return Vec::new();
+    };
+    if let Err(error) = std::fs::remove_file(&path) {
+        tracing::warn!("Could not clear the offline queue: {error}");
+    }
+    contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+pub fn handle_command(command: crate::OfflineCommands, config: &Config) -> Result<()> {
+    let workspace_dir = &config.workspace_dir;
+    match command {
+        crate::OfflineCommands::On => {
+            set_manual_offline(workspace_dir, true)?;
+            println!("Offline mode on.");
+            match config.connectivity.offline_provider.as_deref() {
+                Some(local) => println!("Requests go to the local provider '{local}'."),
+                None => println!(
+                    "No [connectivity].offline_provider is set; only a local default provider will answer."
+                ),
+            }
+            println!("Announcements are held until `zeroclaw offline off`.");
+        }
+        crate::OfflineCommands::Off => {
+            set_manual_offline(workspace_dir, false)?;
+            println!("Offline mode off. A running daemon sends held announcements within one probe interval.");
+        }
+        crate::OfflineCommands::Status => {
+            if manual_offline(workspace_dir) {
+                println!("Offline mode: on (manual)");
+            } else {
+                println!("Offline mode: off");
+            }
+            println!(
+                "Monitor:      {}",
+                if config.connectivity.enabled {
+                    "enabled"
+                } else {
+                    "disabled"
+                }
+            );
+            if let Some(outage) = recent_outages(workspace_dir, 1).last() {
+                println!("Last outage:  {}", outage.describe());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Counts failed probe rounds and reports when the state flips.
+#[derive(Debug, Default)]
+struct Monitor {
+    failures: u32,
+    offline: bool,
+}
+
+impl Monitor {
+    /// Record one probe round; `Some(offline)` when the state changes.
+    fn observe(&mut self, reachable: bool, failures_before_offline: u32) -> Option<bool> {
+        if reachable {
+            self.failures = 0;
+            if self.offline {
+                self.offline = false;
+                return Some(false);
+            }
+            return None;
+        }
+        self.failures = self.failures.saturating_add(1);
+        if !self.offline && self.failures >= failures_before_offline.max(1) {
+            self.offline = true;
+            return Some(true);
+        }
+        None
+    }
+}
+
+/// One offline period from the log; `ended` is `None` while ongoing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outage {
+    pub started: DateTime<Utc>,
+    pub ended: Option<DateTime<Utc>>,
+}
+
+impl Outage {
+    /// "2026-03-01 14:02 UTC, 12m" or "since 2026-03-01 14:02 UTC".
+    pub fn describe(&self) -> String {
+        let started = self.started.format("%Y-%m-%d %H:%M UTC");
+        match self.ended {
+            Some(ended) => {
+                let minutes = (ended - self.started).num_minutes();
+                if minutes < 1 {
+                    format!("{started}, <1m")
+                } else {
+                    format!("{started}, {minutes}m")
+                }
+            }
+            None => format!("since {started} (ongoing)"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LogEntry {
+    offline: bool,
+    at: DateTime<Utc>,
+}
+
+fn log_path(workspace_dir: &Path) -> PathBuf {
+    workspace_dir.join("state").join(LOG_FILE)
+}
+
+fn append_log(workspace_dir: &Path, offline: bool) -> Result<()> {
+    let path = log_path(workspace_dir);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    let entry = LogEntry {
+        offline,
+        at: Utc::now(),
+    };
+    writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+    Ok(())
+}
+
+/// The most recent `limit` outages, oldest first.
+pub fn recent_outages(workspace_dir: &Path, limit: usize) -> Vec<Outage> {
+    let Ok(contents) = std::fs::read_to_string(log_path(workspace_dir)) else {
+        return Vec::new();
+    };
+    let mut outages = parse_outages(&contents);
+    let skip = outages.len().saturating_sub(limit);
+    outages.drain(..skip);
+    outages
+}
+
+fn parse_outages(contents: &str) -> Vec<Outage> {
+    let mut outages: Vec<Outage> = Vec::new();
+    for entry in contents
+        .lines()
+        .filter_map(|line| serde_json::from_str::<LogEntry>(line).ok())
+    {
+        let open = outages.last_mut().filter(|outage| outage.ended.is_none());
+        match (entry.offline, open) {
+            (true, None) => outages.push(Outage {
+                started: entry.at,
+                ended: None,
+            }),
+            (false, Some(outage)) => outage.ended = Some(entry.at),
+            // Repeated or unmatched entries (e.g. after a crash) are ignored.
+            _ => {}
+        }
+    }
+    outages
+}
+
+async fn probe(hosts: &[String]) -> bool {
+    for host in hosts {
+        let connect = tokio::net::TcpStream::connect(host.as_str());
+        if let Ok(Ok(_)) = tokio::time::timeout(PROBE_TIMEOUT, connect).await {
+            return true;
+        }
+    }
+    false
+}
+
+/// Send every held announcement now (back online, or daemon shutdown).
+pub async fn flush_queue(config: &Config) {
+    let held = take_held(&config.workspace_dir);
+    if held.is_empty() {
+        return;
+    }
+    tracing::info!("Sending {} held announcements", held.len());
+    for item in held {
+        if let Err(error) = crate::cron::scheduler::deliver_announcement(
+            config,
+            &item.channel,
+            &item.target,
+            &item.text,
+        )
+        .await
+        {
+            tracing::warn!(channel = %item.channel, "Held announcement failed: {error}");
+        }
+    }
+}
+
+/// Watch the manual offline flag and, when `[connectivity].enabled`, probe
+/// the network.
+pub async fn run_worker(config: Config) -> Result<()> {
+    let connectivity = &config.connectivity;
+    if connectivity.enabled && connectivity.probe_hosts.is_empty() {
+        anyhow::bail!("[connectivity].probe_hosts is empty");
+    }
+    let mut interval = tokio::time::interval(Duration::from_secs(
+        connectivity.interval_secs.max(MIN_INTERVAL_SECS),
+    ));
+    // An outage left open by a previous run is closed by the first
+    // successful probe.
+    let mut monitor = Monitor {
+        failures: 0,
+        offline: connectivity.enabled
+            && recent_outages(&config.workspace_dir, 1)
+                .last()
+                .is_some_and(|outage| outage.ended.is_none()),
+    };
+    let detected_flag = state_path(&config.workspace_dir, DETECTED_FLAG_FILE);
+    set_flag(&detected_flag, monitor.offline)?;
+    let mut forced = manual_offline(&config.workspace_dir);
+    // Announcements held by a previous run, or by another process while
+    // this daemon was down.
+    if !forced && !monitor.offline {
+        flush_queue(&config).await;
+    }
+
+    loop {
+        interval.tick().await;
+        let was_forced = std::mem::replace(&mut forced, manual_offline(&config.workspace_dir));
+        if was_forced != forced {
+            tracing::info!("Manual offline mode {}", if forced { "on" } else { "off" });
+            if !forced && !monitor.offline {
+                flush_queue(&config).await;
+            }
+        }
+        if !connectivity.enabled {
+            crate::health::mark_component_ok("connectivity");
+            continue;
+        }
+        let reachable = probe(&connectivity.probe_hosts).await;
+        if let Some(offline) = monitor.observe(reachable, connectivity.failures_before_offline) {
+            if let Err(error) = set_flag(&detected_flag, offline) {
+                tracing::warn!("Could not record connectivity state: {error}");
+            }
+            if let Err(error) = append_log(&config.workspace_dir, offline) {
+                tracing::warn!("Could not record connectivity change: {error}");
+            }
+            if offline {
+                tracing::warn!("Internet unreachable; holding announcements until it returns");
+            } else {
+                tracing::info!("Internet reachable again");
+                if !forced {
+                    flush_queue(&config).await;
+                }
+            }
+        }
+        if monitor.offline {
+            crate::health::mark_component_error("connectivity", "offline");
+        } else {
+            crate::health::mark_component_ok("connectivity");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn monitor_flips_after_consecutive_failures_and_first_success() {
+        let mut monitor = Monitor::default();
+        assert_eq!(monitor.observe(false, 2), None);
+        assert_eq!(monitor.observe(true, 2), None);
+        assert_eq!(monitor.observe(false, 2), None);
+        assert_eq!(monitor.observe(false, 2), Some(true));
+        assert_eq!(monitor.observe(false, 2), None);
+        assert_eq!(monitor.observe(true, 2), Some(false));
+        assert_eq!(monitor.observe(true, 2), None);
+    }
+
+    #[test]
+    fn manual_offline_flag_round_trips() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        assert!(!manual_offline(tmp.path()));
+        set_manual_offline(tmp.path(), true).unwrap();
+        assert!(manual_offline(tmp.path()));
+        set_manual_offline(tmp.path(), false).unwrap();
+        set_manual_offline(tmp.path(), false).unwrap();
+        assert!(!manual_offline(tmp.path()));
+    }
+
+    #[test]
+    fn held_announcements_follow_the_workspace_flags() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.workspace_dir = tmp.path().to_path_buf();
+        config.connectivity.max_queued = 2;
+        let connectivity = Connectivity::new(&config);
+        let other = tempfile::TempDir::new().unwrap();
+        config.workspace_dir = other.path().to_path_buf();
+        let elsewhere = Connectivity::new(&config);
+
+        assert!(!connectivity.try_queue("telegram", "123", "first"));
+        set_manual_offline(tmp.path(), true).unwrap();
+        assert!(connectivity.is_offline());
+        assert!(!elsewhere.is_offline());
+        for text in ["first", "second", "third"] {
+            assert!(connectivity.try_queue("telegram", "123", text));
+        }
+
+        let held = take_held(tmp.path());
+        let texts: Vec<&str> = held.iter().map(|held| held.text.as_str()).collect();
+        assert_eq!(texts, ["second", "third"]);
+        assert!(take_held(tmp.path()).is_empty());
+    }
+
+    #[test]
+    fn outage_log_pairs_offline_and_online_entries() {
+        let log = [
+            r#"{"offline":true,"at":"2026-03-01T14:00:00Z"}"#,
+            r#"{"offline":false,"at":"2026-03-01T14:12:00Z"}"#,
+            r#"{"offline":false,"at":"2026-03-01T15:00:00Z"}"#,
+            "not json",
+            r#"{"offline":true,"at":"2026-03-02T09:30:00Z"}"#,
+        ]
+        .join("\n");
+        let outages = parse_outages(&log);
+        assert_eq!(outages.len(), 2);
+        assert_eq!(outages[0].describe(), "2026-03-01 14:00 UTC, 12m");
+        assert_eq!(outages[1].ended, None);
+        assert_eq!(
+            outages[1].describe(),
+            "since 2026-03-02 09:30 UTC (ongoing)"
+        );
+    }
+}
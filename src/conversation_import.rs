@@ -0,0 +1,504 @@
+//! `zeroclaw import`: bring conversation history over from other assistants.
+//!
+//! Reads the official ChatGPT and Claude data exports (the `.zip`, its
+//! extracted folder, or `conversations.json` itself) and stores every
+//! conversation as a `conversation` memory tagged with its source, so recall
+//! can surface earlier discussions after migrating.
+
+use crate::config::Config;
+use crate::memory::{self, Memory, MemoryCategory, MemoryInput};
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, TimeZone, Utc};
+use serde_json::Value;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::process::Command;
+
+const CONVERSATIONS_FILE: &str = "conversations.json";
+/// Longer transcripts are cut so one conversation cannot dominate recall.
+const MAX_TRANSCRIPT_CHARS: usize = 16_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+    Chatgpt,
+    Claude,
+}
+
+impl ExportFormat {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Chatgpt => "ChatGPT",
+            Self::Claude => "Claude",
+        }
+    }
+
+    fn tag(self) -> &'static str {
+        match self {
+            Self::Chatgpt => "chatgpt",
+            Self::Claude => "claude",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ImportedConversation {
+    id: String,
+    title: String,
+    created: Option<DateTime<Utc>>,
+    /// `(from_user, text)` in conversation order.
+    messages: Vec<(bool, String)>,
+}
+
+fn read_conversations_json(path: &Path) -> Result<String> {
+    if path.is_dir() {
+        let file = path.join(CONVERSATIONS_FILE);
+        return std::fs::read_to_string(&file)
+            .with_context(|| format!("No {CONVERSATIONS_FILE} in {}", path.display()));
+    }
+    if path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("zip"))
+    {
+        let output = Command::new("unzip")
+            .arg("-p")
+            .arg(path)
+            .arg(CONVERSATIONS_FILE)
+            .output()
+            .context("Failed to run `unzip`; extract the export and pass the folder instead")?;
+        if !output.status.success() {
+            bail!(
+                "Could not read {CONVERSATIONS_FILE} from {}: {}",
+                path.display(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        return String::from_utf8(output.stdout)
+            .with_context(|| format!("{CONVERSATIONS_FILE} is not valid UTF-8"));
+    }
+    std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+fn detect_format(conversations: &[Value]) -> Option<ExportFormat> {
+    conversations.iter().find_map(|conversation| {
+        if conversation.get("mapping").is_some() {
+            Some(ExportFormat::Chatgpt)
+        } else if conversation.get("chat_messages").is_some() {
+            Some(ExportFormat::Claude)
+        } else {
+            None
+        }
+    })
+}
+
+fn str_field<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+}
+
+/// ChatGPT stores a tree of message nodes; the visible thread is the path
+/// from `current_node` back to the root, so edited-away branches are dropped.
+fn parse_chatgpt(conversation: &Value) -> Option<ImportedConversation> {
+    let mapping = conversation.get("mapping")?.as_object()?;
+    let mut messages = Vec::new();
+    let mut node_id = str_field(conversation, "current_node");
+    while let Some(node) = node_id.and_then(|id| mapping.get(id)) {
+        if let Some(message) = node.get("message") {
+            let role = message
+                .pointer("/author/role")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            let text = message
+                .pointer("/content/parts")
+                .and_then(Value::as_array)
+                .map(|parts| {
+                    parts
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                })
+                .unwrap_or_default();
+            if matches!(role, "user" | "assistant") && !text.trim().is_empty() {
+                messages.push((role == "user", text.trim().to_string()));
+            }
+        }
+        node_id = str_field(node, "parent");
+    }
+    messages.reverse();
+
+    #[allow(clippy::cast_possible_truncation)]
+    let created = conversation
+        .get("create_time")
+        .and_then(Value::as_f64)
+        .and_then(|secs| Utc.timestamp_opt(secs as i64, 0).single());
+
+    Some(ImportedConversation {
+        id: str_field(conversation, "conversation_id")
+            .or_else(|| str_field(conversation, "id"))?
+            .to_string(),
+        title: str_field(conversation, "title")
+            .unwrap_or("Untitled")
+            .to_string(),
+        created,
+        messages,
+    })
+}
+
+fn parse_claude(conversation: &Value) -> Option<ImportedConversation> {
+    let messages = conversation
+        .get("chat_messages")?
+        .as_array()?
+        .iter()
+        .filter_map(|message| {
+            let text = str_field(message, "text").map(str::to_string).or_else(|| {
+                let parts: Vec<&str> = message
+                    .get("content")?
+                    .as_array()?
+                    .iter()
+                    .filter(|part| part.get("type").and_then(Value::as_str) == Some("text"))
+                    .filter_map(|part| str_field(part, "text"))
+                    .collect();
+                (!parts.is_empty()).then(|| parts.join("\n"))
+            })?;
+            Some((str_field(message, "sender")? == "human", text))
+        })
+        .collect();
+
+    Some(ImportedConversation {
+        id: str_field(conversation, "uuid")?.to_string(),
+        title: str_field(conversation, "name")
+            .unwrap_or("Untitled")
+            .to_string(),
+        created: str_field(conversation, "created_at")
+            .and_then(|raw| DateTime::parse_from_rfc3339(raw).ok())
+            .map(|created| created.with_timezone(&Utc)),
+        messages,
+    })
+}
+
+fn parse_export(
+    raw: &str,
+    format: Option<ExportFormat>,
+) -> Result<(ExportFormat, Vec<ImportedConversation>)> {
+    let value: Value = serde_json::from_str(raw).context("conversations.json is not valid JSON")?;
+    let Some(items) = value.as_array() else {
+        bail!("Expected conversations.json to hold a list of conversations");
+    };
+    let Some(format) = format.or_else(|| detect_format(items)) else {
+        bail!("Unrecognized export format; pass --format chatgpt or --format claude");
+    };
+    let conversations = items
+        .iter()
+        .filter_map(|item| match format {
+            ExportFormat::Chatgpt => parse_chatgpt(item),
+            ExportFormat::Claude => parse_claude(item),
+        })
+        .filter(|conversation| !conversation.messages.is_empty())
+        .collect();
+    Ok((format, conversations))
+}
+
+/// Redaction channel key for imported transcripts (`[redaction.channels]`).
+const IMPORT_CHANNEL: &str = "import";
+
+/// Write every changed conversation in one `store_batch` call and return
+/// `(imported, unchanged, dropped)`. Transcripts go through the backend's
+/// redaction policy first; `dropped` counts those the policy rejected.
+async fn store_conversations(
+    memory: &dyn Memory,
+    format: ExportFormat,
+    conversations: &[ImportedConversation],
+) -> Result<(usize, usize, usize)> {
+    let mut pending = Vec::new();
+    let mut unchanged = 0;
+    let mut dropped = 0;
+    for conversation in conversations {
+        let key = memory_key(format, &conversation.id);
+        let Some(content) =
+            memory.redact_for_storage(IMPORT_CHANNEL, &transcript(format, conversation))
+        else {
+            dropped += 1;
+            continue;
+        };
+        if memory
+            .get(&key)
+            .await?
+            .is_some_and(|existing| existing.content == content)
+        {
+            unchanged += 1;
+            continue;
+        }
+        pending.push(MemoryInput {
+            key,
+            content,
+            category: MemoryCategory::Conversation,
+            session_id: Some(format!("import:{}:{}", format.tag(), conversation.id)),
+        });
+    }
+    let imported = memory.store_batch(&pending).await?;
+    Ok((imported, unchanged, dropped))
+}
+
+fn memory_key(format: ExportFormat, id: &str) -> String {
+    let id: String = id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("import_{}_{id}", format.tag())
+}
+
+fn transcript(format: ExportFormat, conversation: &ImportedConversation) -> String {
+    let mut text = format!("[source: {}] {}", format.tag(), conversation.title);
+    if let Some(created) = conversation.created {
+        let _ = write!(text, " ({})", created.format("%Y-%m-%d"));
+    }
+    for (from_user, message) in &conversation.messages {
+        let speaker = if *from_user { "User" } else { format.label() };
+        let _ = write!(text, "\n\n{speaker}: {message}");
+    }
+    if text.chars().count() > MAX_TRANSCRIPT_CHARS {
+        text = text.chars().take(MAX_TRANSCRIPT_CHARS).collect();
+        text.push_str("\n\n[… truncated on import]");
+    }
+    text
+}
+
+pub async fn run(
+    config: &Config,
+    path: &Path,
+    format: Option<ExportFormat>,
+    dry_run: bool,
+) -> Result<()> {
+    let raw = read_conversations_json(path)?;
+    let (format, conversations) = parse_export(&raw, format)?;
+    if conversations.is_empty() {
+        println!("No conversations with messages found in {}", path.display());
+        return Ok(());
+    }
+
+    let message_count: usize = conversations.iter().map(|c| c.messages.len()).sum();
+    if dry_run {
+        println!("🔎 Dry run: {} export preview", format.label());
+        println!("  Source:        {}", path.display());
+        println!("  Conversations: {}", conversations.len());
+        println!("  Messages:      {message_count}");
+        for conversation in conversations.iter().take(5) {
+            println!(
+                "    - {} ({} messages)",
+                conversation.title,
+                conversation.messages.len()
+            );
+        }
+        println!();
+        println!("Run without --dry-run to import them into memory.");
+        return Ok(());
+    }
+
+    let memory = memory::with_auto_save_policy(memory::cli::create_import_memory(config)?, config);
+    let (imported, unchanged, dropped) =
+        store_conversations(memory.as_ref(), format, &conversations).await?;
+
+    println!("✅ {} history import complete", format.label());
+    println!("  Source:            {}", path.display());
+    println!("  Imported:          {imported}");
+    println!("  Skipped unchanged: {unchanged}");
+    if dropped > 0 {
+        println!("  Dropped (redaction policy): {dropped}");
+    }
+    println!("  Messages:          {message_count}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chatgpt_export_follows_the_current_branch() {
+        let raw = r#"[{
+            "id": "c1", "title": "Trip", "create_time": 1700000000.5, "current_node": "a2",
+            "mapping": {
+                "root": {"message": null, "parent": null},
+                "sys": {"message": {"author": {"role": "system"}, "content": {"parts": ["be nice"]}}, "parent": "root"},
+                "u1": {"message": {"author": {"role": "user"}, "content": {"parts": ["Plan Lisbon"]}}, "parent": "sys"},
+                "old": {"message": {"author": {"role": "assistant"}, "content": {"parts": ["draft"]}}, "parent": "u1"},
+                "a2": {"message": {"author": {"role": "assistant"}, "content": {"parts": ["Day 1: Alfama"]}}, "parent": "u1"}
+            }
+        }]"#;
+        let (format, conversations) = parse_export(raw, None).unwrap();
+        assert_eq!(format, ExportFormat::Chatgpt);
+        assert_eq!(
+            conversations[0].messages,
+            vec![
+                (true, "Plan Lisbon".to_string()),
+                (false, "Day 1: Alfama".to_string())
+            ]
+        );
+
+        let text = transcript(format, &conversations[0]);
+        assert!(text.starts_with("[source: chatgpt] Trip (2023-11-14)"));
+        assert!(text.contains("User: Plan Lisbon\n\nChatGPT: Day 1: Alfama"));
+        assert!(!text.contains("draft"));
+        assert_eq!(memory_key(format, "c1/x"), "import_chatgpt_c1_x");
+    }
+
+    #[test]
+    fn claude_export_reads_text_and_content_blocks() {
+        let raw = r#"[
+            {"uuid": "u-1", "name": "Rust", "created_at": "2024-05-01T10:00:00Z", "chat_messages": [
+                {"sender": "human", "text": "What is a trait?"},
+                {"sender": "assistant", "text": "", "content": [{"type": "text", "text": "An interface."}]}
+            ]},
+            {"uuid": "u-2", "name": "Empty", "chat_messages": []}
+        ]"#;
+        let (format, conversations) = parse_export(raw, None).unwrap();
+        assert_eq!(format, ExportFormat::Claude);
+        assert_eq!(conversations.len(), 1);
+        assert_eq!(
+            conversations[0].messages[1],
+            (false, "An interface.".into())
+        );
+        assert!(parse_export("[{}]", None).is_err());
+    }
+
+    /// Sqlite-backed memory that records which write path the import used.
+    struct BatchRecordingMemory {
+        inner: crate::memory::SqliteMemory,
+        batches: parking_lot::Mutex<Vec<usize>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Memory for BatchRecordingMemory {
+        fn name(&self) -> &str {
+            "batch-recording"
+        }
+
+        async fn store(
+            &self,
+            _key: &str,
+            _content: &str,
+            _category: MemoryCategory,
+            _session_id: Option<&str>,
+        ) -> Result<()> {
+            panic!("imports should write through store_batch");
+        }
+
+        async fn store_batch(&self, entries: &[MemoryInput]) -> Result<usize> {
+            self.batches.lock().push(entries.len());
+            self.inner.store_batch(entries).await
+        }
+
+        async fn recall(
+            &self,
+            query: &str,
+            limit: usize,
+            session_id: Option<&str>,
+        ) -> Result<Vec<crate::memory::MemoryEntry>> {
+            self.inner.recall(query, limit, session_id).await
+        }
+
+        async fn get(&self, key: &str) -> Result<Option<crate::memory::MemoryEntry>> {
+            self.inner.get(key).await
+        }
+
+        async fn list(
+            &self,
+            category: Option<&MemoryCategory>,
+            session_id: Option<&str>,
+        ) -> Result<Vec<crate::memory::MemoryEntry>> {
+            self.inner.list(category, session_id).await
+        }
+
+        async fn forget(&self, key: &str) -> Result<bool> {
+            self.inner.forget(key).await
+        }
+
+        async fn count(&self) -> Result<usize> {
+            self.inner.count().await
+        }
+
+        async fn health_check(&self) -> bool {
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn import_writes_changed_conversations_in_one_batch() {
+        let tmp = tempfile::tempdir().unwrap();
+        let memory = BatchRecordingMemory {
+            inner: crate::memory::SqliteMemory::new(tmp.path()).unwrap(),
+            batches: parking_lot::Mutex::new(Vec::new()),
+        };
+        let raw = r#"[
+            {"uuid": "u-1", "name": "Rust", "chat_messages": [{"sender": "human", "text": "traits?"}]},
+            {"uuid": "u-2", "name": "Go", "chat_messages": [{"sender": "human", "text": "channels?"}]}
+        ]"#;
+        let (format, conversations) = parse_export(raw, None).unwrap();
+
+        let first = store_conversations(&memory, format, &conversations)
+            .await
+            .unwrap();
+        assert_eq!(first, (2, 0, 0));
+        let again = store_conversations(&memory, format, &conversations)
+            .await
+            .unwrap();
+        assert_eq!(again, (0, 2, 0));
+        assert_eq!(*memory.batches.lock(), vec![2, 0]);
+
+        let stored = memory.get("import_claude_u-1").await.unwrap().unwrap();
+        assert_eq!(stored.category, MemoryCategory::Conversation);
+        assert_eq!(stored.session_id.as_deref(), Some("import:claude:u-1"));
+    }
+
+    #[tokio::test]
+    async fn import_redacts_transcripts_and_drops_rejected_ones() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut redaction = crate::config::RedactionConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let memory = crate::memory::autosave::AutoSaveMemory::new(
+            Box::new(crate::memory::SqliteMemory::new(tmp.path()).unwrap()),
+            &redaction,
+            &crate::config::MemoryClassificationConfig::default(),
+        );
+        let raw = r#"[
+            {"uuid": "u-1", "name": "Billing", "chat_messages": [
+                {"sender": "human", "text": "Card 4111 1111 1111 1111, mail me at ana@example.com"}
+            ]}
+        ]"#;
+        let (format, conversations) = parse_export(raw, None).unwrap();
+
+        let stored = store_conversations(&memory, format, &conversations)
+            .await
+            .unwrap();
+        assert_eq!(stored, (1, 0, 0));
+        let entry = memory.get("import_claude_u-1").await.unwrap().unwrap();
+        assert!(!entry.content.contains("4111 1111 1111 1111"));
+        assert!(!entry.content.contains("ana@example.com"));
+
+        redaction
+            .channels
+            .insert(IMPORT_CHANNEL.into(), crate::config::RedactionPolicy::Drop);
+        let tmp = tempfile::tempdir().unwrap();
+        let memory = crate::memory::autosave::AutoSaveMemory::new(
+            Box::new(crate::memory::SqliteMemory::new(tmp.path()).unwrap()),
+            &redaction,
+            &crate::config::MemoryClassificationConfig::default(),
+        );
+        let stored = store_conversations(&memory, format, &conversations)
+            .await
+            .unwrap();
+        assert_eq!(stored, (0, 0, 1));
+        assert!(memory.get("import_claude_u-1").await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,234 @@
+//! Budget-driven model downgrade (`[cost.downgrade]`).
+//!
+//! Once month-to-date spend in the usage ledger crosses `threshold_percent` of
+//! `monthly_limit_usd`, channel replies switch to the configured cheaper
+//! model. The first downgrade of each month is written to the ledger and
+//! announced to the owner. Permitted senders can keep the routed model for
+//! their conversation with `!force-model`.
+
+use super::tracker::CostTracker;
+use crate::config::schema::{BudgetDowngradeConfig, CostConfig};
+use anyhow::Result;
+use chrono::{Datelike, Utc};
+use parking_lot::Mutex;
+use std::collections::HashSet;
+use std::path::Path;
+
+pub const FORCE_MODEL_COMMAND: &str = "!force-model";
+
+/// A reply that should use the cheaper model.
+#[derive(Debug, Clone)]
+pub struct Downgrade {
+    pub provider: Option<String>,
+    pub model: String,
+    pub spent_usd: f64,
+    pub limit_usd: f64,
+    /// True for the first downgrade of the month; the caller notifies the owner.
+    pub newly_triggered: bool,
+}
+
+impl Downgrade {
+    pub fn describe(&self, from_model: &str) -> String {
+        format!(
+            "Monthly spend ${:.2} of ${:.2} reached the downgrade threshold; \
+             replies switched from `{from_model}` to `{}`.",
+            self.spent_usd, self.limit_usd, self.model
+        )
+    }
+}
+
+pub struct DowngradePolicy {
+    tracker: CostTracker,
+    config: BudgetDowngradeConfig,
+    monthly_limit_usd: f64,
+    triggered_month: Mutex<Option<(i32, u32)>>,
+    forced: Mutex<HashSet<String>>,
+}
+
+impl DowngradePolicy {
+    /// Build the policy, or `None` when cost tracking or the downgrade is off.
+    pub fn new(cost: &CostConfig, workspace_dir: &Path) -> Result<Option<Self>> {
+        if !cost.enabled || !cost.downgrade.enabled {
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            tracker: CostTracker::new(cost.clone(), workspace_dir)?,
+            config: cost.downgrade.clone(),
+            monthly_limit_usd: cost.monthly_limit_usd,
+            triggered_month: Mutex::new(None),
+            forced: Mutex::new(HashSet::new()),
+        }))
+    }
+
+    /// The model to use instead of `current_model` for `conversation`, if the
+    /// budget threshold has been crossed and the conversation is not forced.
+    pub fn check(&self, conversation: &str, current_model: &str) -> Option<Downgrade> {
+        let model = self.config.model.as_deref()?;
+        if model == current_model || self.forced.lock().contains(conversation) {
+            return None;
+        }
+        let spent_usd = match self.tracker.get_summary() {
+            Ok(summary) => summary.monthly_cost_usd,
+            Err(err) => {
+                tracing::warn!("Budget downgrade skipped: failed to read usage ledger: {err}");
+                return None;
+            }
+        };
+        let threshold = self.monthly_limit_usd * f64::from(self.config.threshold_percent) / 100.0;
+        if spent_usd < threshold {
+            return None;
+        }
+
+        let now = Utc::now();
+        let month = Some((now.year(), now.month()));
+        let newly_triggered = {
+            let mut triggered = self.triggered_month.lock();
+            let first = *triggered != month;
+            *triggered = month;
+            first
+        };
+        let downgrade = Downgrade {
+            provider: self.config.provider.clone(),
+            model: model.to_string(),
+            spent_usd,
+            limit_usd: self.monthly_limit_usd,
+            newly_triggered,
+        };
+        if newly_triggered {
+            if let Err(err) = self
+                .tracker
+                .record_event(model, downgrade.describe(current_model))
+            {
+                tracing::warn!("Failed to record budget downgrade in usage ledger: {err}");
+            }
+        }
+        Some(downgrade)
+    }
+
+    /// `(channel, recipient)` for the owner notice, if configured.
+    pub fn notify_target(&self) -> Option<(&str, &str)> {
+        Some((
+            self.config.notify_channel.as_deref()?,
+            self.config.notify_to.as_deref()?,
+        ))
+    }
+
+    fn may_force(&self, sender: &str) -> bool {
+        self.config
+            .force_model_users
+            .iter()
+            .any(|user| user != "*" && user == sender)
+    }
+
+    /// Handle `!force-model [off]`. Returns the reply, or `None` when
+    /// `content` is not the command.
+    pub fn handle_force_model(
+        &self,
+        sender: &str,
+        conversation: &str,
+        content: &str,
+    ) -> Option<String> {
+        let mut parts = content.split_whitespace();
+        if !parts.next()?.eq_ignore_ascii_case(FORCE_MODEL_COMMAND) {
+            return None;
+        }
+        if !self.may_force(sender) {
+            return Some(format!(
+                "`{FORCE_MODEL_COMMAND}` is not permitted for `{sender}`. \
+                 Ask the owner to add you to `[cost.downgrade].force_model_users`."
+            ));
+        }
+        let reply = if parts
+            .next()
+            .is_some_and(|arg| arg.eq_ignore_ascii_case("off"))
+        {
+            self.forced.lock().remove(conversation);
+            "Budget downgrade re-enabled for this conversation."
+        } else {
+            self.forced.lock().insert(conversation.to_string());
+            "Budget downgrade bypassed for this conversation. \
+             Send `!force-model off` to go back to the cheaper model."
+        };
+        Some(reply.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cost::TokenUsage;
+    use tempfile::TempDir;
+
+    fn policy(tmp: &TempDir) -> DowngradePolicy {
+        let cost = CostConfig {
+            enabled: true,
+            monthly_limit_usd: 1.0,
+            downgrade: BudgetDowngradeConfig {
+                enabled: true,
+                model: Some("cheap/model".into()),
+                force_model_users: vec!["alice".into()],
+                ..BudgetDowngradeConfig::default()
+            },
+            ..CostConfig::default()
+        };
+        DowngradePolicy::new(&cost, tmp.path()).unwrap().unwrap()
+    }
+
+    #[test]
+    fn downgrades_past_threshold_and_records_once_per_month() {
+        let tmp = TempDir::new().unwrap();
+        let policy = policy(&tmp);
+        assert!(policy.check("chat", "pricey/model").is_none());
+
+        // $0.85 of a $1.00 budget.
+        policy
+            .tracker
+            .record_usage(TokenUsage::new("pricey/model", 850_000, 0, 1.0, 0.0))
+            .unwrap();
+        let first = policy.check("chat", "pricey/model").unwrap();
+        assert_eq!(first.model, "cheap/model");
+        assert!(first.newly_triggered);
+        assert!(
+            !policy
+                .check("chat", "pricey/model")
+                .unwrap()
+                .newly_triggered
+        );
+        assert!(policy.check("chat", "cheap/model").is_none());
+
+        let ledger = std::fs::read_to_string(tmp.path().join("state").join("costs.jsonl")).unwrap();
+        assert_eq!(ledger.matches("replies switched from").count(), 1);
+    }
+
+    #[test]
+    fn force_model_is_limited_to_permitted_senders() {
+        let tmp = TempDir::new().unwrap();
+        let policy = policy(&tmp);
+        policy
+            .tracker
+            .record_usage(TokenUsage::new("pricey/model", 900_000, 0, 1.0, 0.0))
+            .unwrap();
+
+        assert!(policy
+            .handle_force_model("alice", "chat", "hello")
+            .is_none());
+        let denied = policy
+            .handle_force_model("bob", "chat", "!force-model")
+            .unwrap();
+        assert!(denied.contains("not permitted"));
+        assert!(policy
+            .handle_force_model("Alice", "chat", "!force-model")
+            .unwrap()
+            .contains("not permitted"));
+        assert!(policy.check("chat", "pricey/model").is_some());
+
+        policy
+            .handle_force_model("alice", "chat", "!force-model")
+            .unwrap();
+        assert!(policy.check("chat", "pricey/model").is_none());
+        policy
+            .handle_force_model("alice", "chat", "!force-model off")
+            .unwrap();
+        assert!(policy.check("chat", "pricey/model").is_some());
+    }
+}
@@ -1,5 +1,7 @@
+pub mod downgrade;
 pub mod tracker;
 pub mod types;
+pub mod usage;
 
 // Re-exported for potential external use (public API)
 #[allow(unused_imports)]
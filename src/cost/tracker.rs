@@ -1,7 +1,7 @@
 use super::types::{BudgetCheck, CostRecord, CostSummary, ModelStats, TokenUsage, UsagePeriod};
 use crate::config::schema::CostConfig;
 use anyhow::{anyhow, Context, Result};
-use chrono::{Datelike, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
 use parking_lot::{Mutex, MutexGuard};
 use std::collections::HashMap;
 use std::fs::{self, File, OpenOptions};
@@ -133,6 +133,17 @@ impl CostTracker {
         Ok(())
     }
 
+    /// Record a zero-cost policy event (such as a model downgrade) in the ledger.
+    pub fn record_event(&self, model: &str, note: impl Into<String>) -> Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        let mut record = CostRecord::new(&self.session_id, TokenUsage::new(model, 0, 0, 0.0, 0.0));
+        record.note = Some(note.into());
+        self.lock_storage().add_record(record)
+    }
+
     /// Get the current cost summary.
     pub fn get_summary(&self) -> Result<CostSummary> {
         let (daily_cost, monthly_cost) = {
@@ -173,6 +184,11 @@ impl CostTracker {
         let storage = self.lock_storage();
         storage.get_cost_for_month(year, month)
     }
+
+    /// Ledger records at or after `since`, oldest first.
+    pub fn records_since(&self, since: DateTime<Utc>) -> Result<Vec<CostRecord>> {
+        self.lock_storage().records_since(since)
+    }
 }
 
 fn resolve_storage_path(workspace_dir: &Path) -> Result<PathBuf> {
@@ -233,6 +249,9 @@ struct CostStorage {
     cached_day: NaiveDate,
     cached_year: i32,
     cached_month: u32,
+    /// Ledger length the aggregates were built from; a different length means
+    /// another tracker (channels, gateway, CLI) appended to the shared file.
+    synced_len: u64,
 }
 
 impl CostStorage {
@@ -251,6 +270,7 @@ impl CostStorage {
             cached_day: now.date_naive(),
             cached_year: now.year(),
             cached_month: now.month(),
+            synced_len: 0,
         };
 
         storage.rebuild_aggregates(
@@ -324,17 +344,26 @@ impl CostStorage {
         self.cached_day = day;
         self.cached_year = year;
         self.cached_month = month;
+        self.synced_len = self.file_len();
 
         Ok(())
     }
 
+    fn file_len(&self) -> u64 {
+        fs::metadata(&self.path).map_or(0, |meta| meta.len())
+    }
+
     fn ensure_period_cache_current(&mut self) -> Result<()> {
         let now = Utc::now();
         let day = now.date_naive();
         let year = now.year();
         let month = now.month();
 
-        if day != self.cached_day || year != self.cached_year || month != self.cached_month {
+        if day != self.cached_day
+            || year != self.cached_year
+            || month != self.cached_month
+            || self.file_len() != self.synced_len
+        {
             self.rebuild_aggregates(day, year, month)?;
         }
 
@@ -343,6 +372,8 @@ impl CostStorage {
 
     /// Add a new record.
     fn add_record(&mut self, record: CostRecord) -> Result<()> {
+        self.ensure_period_cache_current()?;
+
         let mut file = OpenOptions::new()
             .create(true)
             .append(true)
@@ -353,8 +384,7 @@ impl CostStorage {
             .with_context(|| format!("Failed to write cost record to {}", self.path.display()))?;
         file.sync_all()
             .with_context(|| format!("Failed to sync cost storage at {}", self.path.display()))?;
-
-        self.ensure_period_cache_current()?;
+        self.synced_len = self.file_len();
 
         let timestamp = record.usage.timestamp.naive_utc();
         if timestamp.date() == self.cached_day {
@@ -386,6 +416,18 @@ impl CostStorage {
         Ok(cost)
     }
 
+    /// Records at or after `since`, oldest first.
+    fn records_since(&self, since: DateTime<Utc>) -> Result<Vec<CostRecord>> {
+        let mut records = Vec::new();
+        self.for_each_record(|record| {
+            if record.usage.timestamp >= since {
+                records.push(record);
+            }
+        })?;
+        records.sort_by_key(|record| record.usage.timestamp);
+        Ok(records)
+    }
+
     /// Get cost for a specific month.
     fn get_cost_for_month(&self, year: i32, month: u32) -> Result<f64> {
         let mut cost = 0.0;
@@ -523,6 +565,32 @@ mod tests {
         assert!((today_cost - valid_usage.cost_usd).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn trackers_sharing_a_ledger_see_each_others_spend() {
+        let tmp = TempDir::new().unwrap();
+        let channels = CostTracker::new(enabled_config(), tmp.path()).unwrap();
+        let gateway = CostTracker::new(enabled_config(), tmp.path()).unwrap();
+        assert!(gateway.get_summary().unwrap().daily_cost_usd.abs() < f64::EPSILON);
+
+        channels
+            .record_usage(TokenUsage::new("test/model", 1_000_000, 0, 2.0, 0.0))
+            .unwrap();
+        gateway
+            .record_usage(TokenUsage::new("test/model", 1_000_000, 0, 1.0, 0.0))
+            .unwrap();
+
+        let summary = gateway.get_summary().unwrap();
+        assert!((summary.daily_cost_usd - 3.0).abs() < 1e-9);
+        assert!((summary.session_cost_usd - 1.0).abs() < 1e-9);
+        assert_eq!(
+            channels
+                .records_since(Utc::now() - chrono::Duration::hours(1))
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
     #[test]
     fn invalid_budget_estimate_is_rejected() {
         let tmp = TempDir::new().unwrap();
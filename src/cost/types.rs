@@ -5,6 +5,9 @@ use serde::{Deserialize, Serialize};
 pub struct TokenUsage {
     /// Model identifier (e.g., "anthropic/claude-sonnet-4-20250514")
     pub model: String,
+    /// Provider that served the request (e.g., "openrouter")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
     /// Input/prompt tokens
     pub input_tokens: u64,
     /// Output/completion tokens
@@ -46,6 +49,7 @@ impl TokenUsage {
 
         Self {
             model,
+            provider: None,
             input_tokens,
             output_tokens,
             total_tokens,
@@ -54,6 +58,12 @@ impl TokenUsage {
         }
     }
 
+    /// Attribute the usage to `provider`.
+    pub fn with_provider(mut self, provider: impl Into<String>) -> Self {
+        self.provider = Some(provider.into());
+        self
+    }
+
     /// Get the total cost.
     pub fn cost(&self) -> f64 {
         self.cost_usd
@@ -77,6 +87,9 @@ pub struct CostRecord {
     pub usage: TokenUsage,
     /// Session identifier (for grouping)
     pub session_id: String,
+    /// Policy event attached to this record (e.g. a budget downgrade)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
 }
 
 impl CostRecord {
@@ -86,6 +99,7 @@ impl CostRecord {
             id: uuid::Uuid::new_v4().to_string(),
             usage,
             session_id: session_id.into(),
+            note: None,
         }
     }
 }
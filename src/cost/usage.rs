@@ -0,0 +1,517 @@
+//! Per-request usage recording and reporting.
+//!
+//! Runtimes that call providers (agent loop, channels, gateway, delegated
+//! sub-agents) add a [`UsageRecorder`] to their observer with
+//! [`with_recording`]; every provider response is then recorded: token counts
+//! and an estimated cost (from `[cost.prices]`) are appended to the usage ledger
+//! (`state/costs.jsonl`) tagged with provider and model. `zeroclaw usage` and
+//! the dashboard's Usage view aggregate the ledger by day or week and show
+//! how close spend is to the `[cost]` limits.
+
+use super::tracker::CostTracker;
+use super::types::{BudgetCheck, CostRecord, TokenUsage, UsagePeriod};
+use crate::config::schema::{CostConfig, ModelPricing};
+use crate::config::Config;
+use crate::observability::traits::{Observer, ObserverEvent, ObserverMetric};
+use crate::observability::MultiObserver;
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+const UNKNOWN_PROVIDER: &str = "unknown";
+
+/// Appends provider responses to the usage ledger and warns once per period
+/// when spend crosses the `[cost]` limits.
+pub struct UsageRecorder {
+    tracker: CostTracker,
+    prices: HashMap<String, ModelPricing>,
+    /// Budget warnings already logged, keyed by period and level.
+    warned: Mutex<HashSet<String>>,
+}
+
+impl UsageRecorder {
+    /// A recorder for `config`, or `None` unless `[cost] enabled = true`.
+    pub fn from_config(config: &Config) -> Option<Self> {
+        if !config.cost.enabled {
+            return None;
+        }
+        match CostTracker::new(config.cost.clone(), &config.workspace_dir) {
+            Ok(tracker) => Some(Self {
+                tracker,
+                prices: config.cost.prices.clone(),
+                warned: Mutex::new(HashSet::new()),
+            }),
+            Err(error) => {
+                tracing::warn!("Usage recording disabled: {error:#}");
+                None
+            }
+        }
+    }
+}
+
+/// `observer` plus usage recording when `[cost] enabled = true`.
+pub fn with_recording(observer: Box<dyn Observer>, config: &Config) -> Box<dyn Observer> {
+    match UsageRecorder::from_config(config) {
+        Some(recorder) => Box::new(MultiObserver::new(vec![observer, Box::new(recorder)])),
+        None => observer,
+    }
+}
+
+/// Price for `model` served by `provider`. Keys are tried as the bare model,
+/// `provider/model`, then any `vendor/model` key with the same model name.
+pub fn price_for<'a>(
+    prices: &'a HashMap<String, ModelPricing>,
+    provider: &str,
+    model: &str,
+) -> Option<&'a ModelPricing> {
+    prices
+        .get(model)
+        .or_else(|| prices.get(&format!("{provider}/{model}")))
+        .or_else(|| {
+            let name = model.rsplit('/').next().unwrap_or(model);
+            let mut matches: Vec<(&String, &ModelPricing)> = prices
+                .iter()
+                .filter(|(key, _)| key.rsplit('/').next() == Some(name))
+                .collect();
+            matches.sort_by(|a, b| a.0.cmp(b.0));
+            matches.first().map(|(_, price)| *price)
+        })
+}
+
+impl Observer for UsageRecorder {
+    fn record_event(&self, event: &ObserverEvent) {
+        if let ObserverEvent::LlmResponse {
+            provider,
+            model,
+            success: true,
+            input_tokens,
+            output_tokens,
+            ..
+        } = event
+        {
+            self.record(provider, model, *input_tokens, *output_tokens);
+        }
+    }
+
+    fn record_metric(&self, _metric: &ObserverMetric) {}
+
+    fn name(&self) -> &str {
+        "usage"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl UsageRecorder {
+    /// Record one provider response.
+    fn record(
+        &self,
+        provider: &str,
+        model: &str,
+        input_tokens: Option<u64>,
+        output_tokens: Option<u64>,
+    ) {
+        let (input, output) = (input_tokens.unwrap_or(0), output_tokens.unwrap_or(0));
+        if input == 0 && output == 0 {
+            return;
+        }
+        let (input_price, output_price) = price_for(&self.prices, provider, model)
+            .map_or((0.0, 0.0), |price| (price.input, price.output));
+        let usage = TokenUsage::new(model, input, output, input_price, output_price)
+            .with_provider(provider);
+        if let Err(error) = self.tracker.record_usage(usage) {
+            tracing::warn!("Failed to record usage: {error:#}");
+            return;
+        }
+        self.warn_on_budget();
+    }
+
+    fn warn_on_budget(&self) {
+        let (level, current_usd, limit_usd, period) = match self.tracker.check_budget(0.0) {
+            Ok(BudgetCheck::Warning {
+                current_usd,
+                limit_usd,
+                period,
+            }) => ("warning", current_usd, limit_usd, period),
+            Ok(BudgetCheck::Exceeded {
+                current_usd,
+                limit_usd,
+                period,
+            }) => ("exceeded", current_usd, limit_usd, period),
+            Ok(BudgetCheck::Allowed) => return,
+            Err(error) => {
+                tracing::debug!("Budget check after usage failed: {error:#}");
+                return;
+            }
+        };
+        let now = Utc::now();
+        let (label, key) = match period {
+            UsagePeriod::Month => ("monthly", format!("{}-{:02}", now.year(), now.month())),
+            _ => ("daily", now.date_naive().to_string()),
+        };
+        if self.warned.lock().insert(format!("{label}:{key}:{level}")) {
+            tracing::warn!(
+                "Usage budget {level}: ${current_usd:.2} of the ${limit_usd:.2} {label} limit spent"
+            );
+        }
+    }
+}
+
+/// Bucket size for usage reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Granularity {
+    Daily,
+    Weekly,
+}
+
+impl Granularity {
+    /// Start of the bucket containing `date` (weeks start on Monday).
+    fn bucket_start(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Self::Daily => date,
+            Self::Weekly => date - Duration::days(i64::from(date.weekday().num_days_from_monday())),
+        }
+    }
+
+    fn step(self) -> Duration {
+        match self {
+            Self::Daily => Duration::days(1),
+            Self::Weekly => Duration::weeks(1),
+        }
+    }
+}
+
+/// Usage of one provider/model within one bucket.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UsageRow {
+    /// First day of the bucket (`YYYY-MM-DD`, UTC).
+    pub period: String,
+    pub provider: String,
+    pub model: String,
+    pub requests: u64,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cost_usd: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BudgetLevel {
+    Ok,
+    Warning,
+    Exceeded,
+}
+
+/// Spend against one `[cost]` limit.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BudgetStatus {
+    /// `"day"` or `"month"`.
+    pub period: String,
+    pub spent_usd: f64,
+    pub limit_usd: f64,
+    pub percent: f64,
+    pub level: BudgetLevel,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UsageReport {
+    pub granularity: Granularity,
+    pub since: String,
+    pub tracking_enabled: bool,
+    pub rows: Vec<UsageRow>,
+    pub budget: Vec<BudgetStatus>,
+}
+
+/// Group usage records into per-bucket, per-provider/model rows. Policy
+/// events (zero-token records with a note) are left out. Rows are ordered by
+/// bucket, then by cost (highest first).
+pub fn aggregate(records: &[CostRecord], granularity: Granularity) -> Vec<UsageRow> {
+    let mut buckets: BTreeMap<(NaiveDate, String, String), UsageRow> = BTreeMap::new();
+    for record in records {
+        let usage = &record.usage;
+        if record.note.is_some() && usage.total_tokens == 0 {
+            continue;
+        }
+        let start = granularity.bucket_start(usage.timestamp.date_naive());
+        let provider = usage
+            .provider
+            .clone()
+            .unwrap_or_else(|| UNKNOWN_PROVIDER.to_string());
+        let row = buckets
+            .entry((start, provider.clone(), usage.model.clone()))
+            .or_insert_with(|| UsageRow {
+                period: start.to_string(),
+                provider,
+                model: usage.model.clone(),
+                requests: 0,
+                input_tokens: 0,
+                output_tokens: 0,
+                cost_usd: 0.0,
+            });
+        row.requests += 1;
+        row.input_tokens += usage.input_tokens;
+        row.output_tokens += usage.output_tokens;
+        row.cost_usd += usage.cost_usd;
+    }
+
+    let mut rows: Vec<UsageRow> = buckets.into_values().collect();
+    rows.sort_by(|a, b| {
+        a.period
+            .cmp(&b.period)
+            .then(b.cost_usd.total_cmp(&a.cost_usd))
+            .then(a.provider.cmp(&b.provider))
+            .then(a.model.cmp(&b.model))
+    });
+    rows
+}
+
+fn budget_entry(period: &str, spent_usd: f64, limit_usd: f64, warn_at_percent: u8) -> BudgetStatus {
+    let percent = if limit_usd > 0.0 {
+        spent_usd / limit_usd * 100.0
+    } else {
+        0.0
+    };
+    let level = if limit_usd > 0.0 && spent_usd >= limit_usd {
+        BudgetLevel::Exceeded
+    } else if percent >= f64::from(warn_at_percent.min(100)) {
+        BudgetLevel::Warning
+    } else {
+        BudgetLevel::Ok
+    };
+    BudgetStatus {
+        period: period.to_string(),
+        spent_usd,
+        limit_usd,
+        percent,
+        level,
+    }
+}
+
+/// Today's and this month's spend against the `[cost]` limits.
+pub fn budget_status(cost: &CostConfig, daily_usd: f64, monthly_usd: f64) -> Vec<BudgetStatus> {
+    vec![
+        budget_entry("day", daily_usd, cost.daily_limit_usd, cost.warn_at_percent),
+        budget_entry(
+            "month",
+            monthly_usd,
+            cost.monthly_limit_usd,
+            cost.warn_at_percent,
+        ),
+    ]
+}
+
+/// Aggregate the last `periods` days or weeks (including the current one).
+pub fn report(config: &Config, granularity: Granularity, periods: u32) -> Result<UsageReport> {
+    let today = Utc::now().date_naive();
+    let first = granularity.bucket_start(today) - granularity.step() * (periods.max(1) as i32 - 1);
+    let since: DateTime<Utc> =
+        Utc.from_utc_datetime(&first.and_hms_opt(0, 0, 0).unwrap_or_default());
+
+    let tracker = CostTracker::new(config.cost.clone(), &config.workspace_dir)?;
+    let records = tracker.records_since(since)?;
+    let summary = tracker.get_summary()?;
+
+    Ok(UsageReport {
+        granularity,
+        since: first.to_string(),
+        tracking_enabled: config.cost.enabled,
+        rows: aggregate(&records, granularity),
+        budget: budget_status(
+            &config.cost,
+            summary.daily_cost_usd,
+            summary.monthly_cost_usd,
+        ),
+    })
+}
+
+/// `zeroclaw usage`: print the report as a table, or as JSON.
+pub fn run_cli(config: &Config, granularity: Granularity, periods: u32, json: bool) -> Result<()> {
+    let report = report(config, granularity, periods)?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    let unit = match granularity {
+        Granularity::Daily => "day",
+        Granularity::Weekly => "week",
+    };
+    println!("Usage by {unit} since {} (UTC)", report.since);
+    if !report.tracking_enabled {
+        println!("  Cost tracking is off; set [cost] enabled = true to record usage.");
+    }
+    println!();
+
+    if report.rows.is_empty() {
+        println!("  No usage recorded.");
+    }
+    let mut current: Option<&str> = None;
+    for row in &report.rows {
+        if current != Some(row.period.as_str()) {
+            if current.is_some() {
+                println!();
+            }
+            match granularity {
+                Granularity::Daily => println!("{}", row.period),
+                Granularity::Weekly => println!("Week of {}", row.period),
+            }
+            current = Some(&row.period);
+        }
+        println!(
+            "  {:<14} {:<40} {:>5} req {:>10} in {:>9} out  ${:.4}",
+            row.provider,
+            row.model,
+            row.requests,
+            row.input_tokens,
+            row.output_tokens,
+            row.cost_usd
+        );
+    }
+
+    println!();
+    println!("Budget");
+    for status in &report.budget {
+        let label = match status.period.as_str() {
+            "day" => "today",
+            _ => "this month",
+        };
+        let marker = match status.level {
+            BudgetLevel::Ok => "",
+            BudgetLevel::Warning => "  ⚠️ nearing limit",
+            BudgetLevel::Exceeded => "  🛑 limit reached",
+        };
+        println!(
+            "  {label:<11} ${:.2} of ${:.2} ({:.0}%){marker}",
+            status.spent_usd, status.limit_usd, status.percent
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record_at(day: &str, provider: Option<&str>, model: &str, tokens: u64) -> CostRecord {
+        let mut usage = TokenUsage::new(model, tokens, tokens, 1.0, 1.0);
+        usage.provider = provider.map(str::to_string);
+        usage.timestamp = Utc.from_utc_datetime(
+            &NaiveDate::parse_from_str(day, "%Y-%m-%d")
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap(),
+        );
+        CostRecord::new("session", usage)
+    }
+
+    #[test]
+    fn aggregates_by_day_and_week_per_provider_and_model() {
+        let mut downgrade = record_at("2026-10-13", Some("openrouter"), "cheap", 0);
+        downgrade.note = Some("downgraded".into());
+        let records = vec![
+            record_at("2026-10-12", Some("openrouter"), "big", 1_000_000),
+            record_at("2026-10-12", Some("openrouter"), "big", 1_000_000),
+            record_at("2026-10-13", Some("openrouter"), "big", 500_000),
+            record_at("2026-10-13", None, "local", 10),
+            downgrade,
+            record_at("2026-10-19", Some("openrouter"), "big", 1_000),
+        ];
+
+        let daily = aggregate(&records, Granularity::Daily);
+        assert_eq!(daily.len(), 4);
+        assert_eq!(daily[0].period, "2026-10-12");
+        assert_eq!(daily[0].requests, 2);
+        assert!((daily[0].cost_usd - 4.0).abs() < 1e-9);
+        assert_eq!(daily[1].model, "big");
+        assert_eq!(daily[2].provider, UNKNOWN_PROVIDER);
+
+        let weekly = aggregate(&records, Granularity::Weekly);
+        let periods: Vec<&str> = weekly.iter().map(|row| row.period.as_str()).collect();
+        assert_eq!(periods, vec!["2026-10-12", "2026-10-12", "2026-10-19"]);
+        assert_eq!(weekly[0].requests, 3);
+        assert_eq!(weekly[0].input_tokens, 2_500_000);
+    }
+
+    #[test]
+    fn recorder_appends_successful_responses_to_the_ledger() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut config = Config {
+            workspace_dir: tmp.path().to_path_buf(),
+            ..Config::default()
+        };
+        assert!(UsageRecorder::from_config(&config).is_none());
+        config.cost.enabled = true;
+
+        let observer = with_recording(Box::new(crate::observability::NoopObserver), &config);
+        for (success, tokens) in [(true, Some(100)), (false, None), (true, None)] {
+            observer.record_event(&ObserverEvent::LlmResponse {
+                provider: "openrouter".into(),
+                model: "big".into(),
+                duration: std::time::Duration::from_millis(5),
+                success,
+                error_message: None,
+                input_tokens: tokens,
+                output_tokens: tokens,
+            });
+        }
+
+        let recorder = UsageRecorder::from_config(&config).unwrap();
+        let records = recorder
+            .tracker
+            .records_since(Utc::now() - Duration::hours(1))
+            .unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].usage.provider.as_deref(), Some("openrouter"));
+    }
+
+    #[test]
+    fn prices_resolve_by_model_provider_or_vendor_suffix() {
+        let mut prices = HashMap::new();
+        prices.insert(
+            "anthropic/claude-sonnet-4".to_string(),
+            ModelPricing {
+                input: 3.0,
+                output: 15.0,
+            },
+        );
+        prices.insert(
+            "ollama/llama3".to_string(),
+            ModelPricing {
+                input: 0.0,
+                output: 0.0,
+            },
+        );
+
+        assert_eq!(
+            price_for(&prices, "openrouter", "anthropic/claude-sonnet-4").map(|p| p.input),
+            Some(3.0)
+        );
+        assert_eq!(
+            price_for(&prices, "anthropic", "claude-sonnet-4").map(|p| p.output),
+            Some(15.0)
+        );
+        assert!(price_for(&prices, "ollama", "llama3").is_some());
+        assert!(price_for(&prices, "openai", "gpt-4o").is_none());
+    }
+
+    #[test]
+    fn budget_levels_follow_warn_percent_and_limits() {
+        let cost = CostConfig {
+            daily_limit_usd: 10.0,
+            monthly_limit_usd: 100.0,
+            warn_at_percent: 80,
+            ..CostConfig::default()
+        };
+        let status = budget_status(&cost, 8.5, 120.0);
+        assert_eq!(status[0].level, BudgetLevel::Warning);
+        assert!((status[0].percent - 85.0).abs() < 1e-9);
+        assert_eq!(status[1].level, BudgetLevel::Exceeded);
+        assert_eq!(budget_status(&cost, 1.0, 1.0)[0].level, BudgetLevel::Ok);
+    }
+}
@@ -4,6 +4,7 @@ use anyhow::{bail, Result};
 
 mod schedule;
 mod store;
+mod sync;
 mod types;
 
 pub mod scheduler;
@@ -17,6 +18,7 @@ pub use store::{
     add_agent_job, add_job, add_shell_job, due_jobs, get_job, list_jobs, list_runs,
     record_last_run, record_run, remove_job, reschedule_after_run, update_job,
 };
+pub use sync::sync_config_schedules;
 pub use types::{CronJob, CronJobPatch, CronRun, DeliveryConfig, JobType, Schedule, SessionTarget};
 
 #[allow(clippy::needless_pass_by_value)]
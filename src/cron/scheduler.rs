@@ -1,10 +1,17 @@
-use crate::channels::{
-    Channel, DiscordChannel, MattermostChannel, SendMessage, SlackChannel, TelegramChannel,
-};
+#[cfg(feature = "channel-discord")]
+use crate::channels::DiscordChannel;
+#[cfg(feature = "channel-mattermost")]
+use crate::channels::MattermostChannel;
+#[cfg(feature = "channel-slack")]
+use crate::channels::SlackChannel;
+#[cfg(feature = "channel-telegram")]
+use crate::channels::TelegramChannel;
+use crate::channels::{Channel, SendMessage};
 use crate::config::Config;
 use crate::cron::{
     due_jobs, next_run_for_schedule, record_last_run, record_run, remove_job, reschedule_after_run,
-    update_job, CronJob, CronJobPatch, DeliveryConfig, JobType, Schedule, SessionTarget,
+    sync_config_schedules, update_job, CronJob, CronJobPatch, DeliveryConfig, JobType, Schedule,
+    SessionTarget,
 };
 use crate::security::SecurityPolicy;
 use anyhow::Result;
@@ -28,6 +35,12 @@ pub async fn run(config: Config) -> Result<()> {
         &config.workspace_dir,
     ));
 
+    match sync_config_schedules(&config) {
+        Ok(0) => {}
+        Ok(count) => tracing::info!("Registered {count} job(s) from [schedules]"),
+        Err(e) => tracing::warn!("Failed to sync [schedules] into the cron store: {e}"),
+    }
+
     crate::health::mark_component_ok(SCHEDULER_COMPONENT);
 
     loop {
@@ -127,6 +140,12 @@ async fn execute_and_persist_job(
     crate::health::mark_component_ok(component);
     warn_if_high_frequency_agent_job(job);
 
+    crate::notifications::notify(
+        &config.ui.notifications,
+        crate::notifications::NotifyEvent::ReminderFired,
+        job.name.as_deref().unwrap_or(&job.id),
+    );
+
     let started_at = Utc::now();
     let (success, output) = execute_job_with_retry(config, security, job).await;
     let finished_at = Utc::now();
@@ -167,7 +186,7 @@ async fn run_agent_job(
 
     let run_result = match job.session_target {
         SessionTarget::Main | SessionTarget::Isolated => {
-            crate::agent::run(
+            Box::pin(crate::agent::run(
                 config.clone(),
                 Some(prefixed_prompt),
                 None,
@@ -175,7 +194,7 @@ async fn run_agent_job(
                 config.default_temperature,
                 vec![],
                 false,
-            )
+            ))
             .await
         }
     };
@@ -305,68 +324,91 @@ pub(crate) async fn deliver_announcement(
     target: &str,
     output: &str,
 ) -> Result<()> {
-    match channel.to_ascii_lowercase().as_str() {
+    if crate::connectivity::Connectivity::new(config).try_queue(channel, target, output) {
+        tracing::info!(
+            channel,
+            "Offline; holding announcement until connectivity returns"
+        );
+        return Ok(());
+    }
+    delivery_channel(config, channel)?
+        .send(&SendMessage::new(output, target))
+        .await?;
+
+    Ok(())
+}
+
+/// Build a sender for an announcement channel compiled into this build.
+#[cfg_attr(
+    not(any(
+        feature = "channel-telegram",
+        feature = "channel-discord",
+        feature = "channel-slack",
+        feature = "channel-mattermost"
+    )),
+    allow(unused_variables)
+)]
+fn delivery_channel(config: &Config, name: &str) -> Result<Box<dyn Channel>> {
+    match name.to_ascii_lowercase().as_str() {
+        #[cfg(feature = "channel-telegram")]
         "telegram" => {
             let tg = config
                 .channels_config
                 .telegram
                 .as_ref()
                 .ok_or_else(|| anyhow::anyhow!("telegram channel not configured"))?;
-            let channel = TelegramChannel::new(
+            Ok(Box::new(TelegramChannel::new(
                 tg.bot_token.clone(),
                 tg.allowed_users.clone(),
                 tg.mention_only,
-            );
-            channel.send(&SendMessage::new(output, target)).await?;
+            )))
         }
+        #[cfg(feature = "channel-discord")]
         "discord" => {
             let dc = config
                 .channels_config
                 .discord
                 .as_ref()
                 .ok_or_else(|| anyhow::anyhow!("discord channel not configured"))?;
-            let channel = DiscordChannel::new(
+            Ok(Box::new(DiscordChannel::new(
                 dc.bot_token.clone(),
                 dc.guild_id.clone(),
                 dc.allowed_users.clone(),
                 dc.listen_to_bots,
                 dc.mention_only,
-            );
-            channel.send(&SendMessage::new(output, target)).await?;
+            )))
         }
+        #[cfg(feature = "channel-slack")]
         "slack" => {
             let sl = config
                 .channels_config
                 .slack
                 .as_ref()
                 .ok_or_else(|| anyhow::anyhow!("slack channel not configured"))?;
-            let channel = SlackChannel::new(
+            Ok(Box::new(SlackChannel::new(
                 sl.bot_token.clone(),
                 sl.channel_id.clone(),
                 sl.allowed_users.clone(),
-            );
-            channel.send(&SendMessage::new(output, target)).await?;
+            )))
         }
+        #[cfg(feature = "channel-mattermost")]
         "mattermost" => {
             let mm = config
                 .channels_config
                 .mattermost
                 .as_ref()
                 .ok_or_else(|| anyhow::anyhow!("mattermost channel not configured"))?;
-            let channel = MattermostChannel::new(
+            Ok(Box::new(MattermostChannel::new(
                 mm.url.clone(),
                 mm.bot_token.clone(),
                 mm.channel_id.clone(),
                 mm.allowed_users.clone(),
                 mm.thread_replies.unwrap_or(true),
                 mm.mention_only.unwrap_or(false),
-            );
-            channel.send(&SendMessage::new(output, target)).await?;
+            )))
         }
         other => anyhow::bail!("unsupported delivery channel: {other}"),
     }
-
-    Ok(())
 }
 
 async fn run_job_command(
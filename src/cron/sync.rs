@@ -0,0 +1,148 @@
+use crate::config::{Config, ScheduledJobConfig};
+use crate::cron::{
+    add_agent_job, list_jobs, remove_job, update_job, CronJob, CronJobPatch, DeliveryConfig,
+    Schedule, SessionTarget,
+};
+use anyhow::Result;
+use std::collections::HashMap;
+
+/// Name prefix marking cron jobs owned by `[schedules]`.
+pub const CONFIG_JOB_PREFIX: &str = "schedules:";
+
+fn schedule_for(entry: &ScheduledJobConfig) -> Schedule {
+    Schedule::Cron {
+        expr: entry.cron.trim().to_string(),
+        tz: entry.tz.clone(),
+    }
+}
+
+fn delivery_for(entry: &ScheduledJobConfig) -> DeliveryConfig {
+    match (&entry.channel, &entry.to) {
+        (Some(channel), Some(to)) => DeliveryConfig {
+            mode: "announce".into(),
+            channel: Some(channel.clone()),
+            to: Some(to.clone()),
+            best_effort: true,
+        },
+        _ => DeliveryConfig::default(),
+    }
+}
+
+fn sync_entry(
+    config: &Config,
+    entry: &ScheduledJobConfig,
+    existing: Option<CronJob>,
+) -> Result<()> {
+    let schedule = schedule_for(entry);
+    let delivery = delivery_for(entry);
+    match existing {
+        Some(job)
+            if job.schedule == schedule
+                && job.prompt.as_deref() == Some(entry.prompt.as_str())
+                && job.delivery == delivery => {}
+        Some(job) => {
+            update_job(
+                config,
+                &job.id,
+                CronJobPatch {
+                    schedule: (job.schedule != schedule).then_some(schedule),
+                    prompt: Some(entry.prompt.clone()),
+                    delivery: Some(delivery),
+                    ..CronJobPatch::default()
+                },
+            )?;
+        }
+        None => {
+            add_agent_job(
+                config,
+                Some(format!("{CONFIG_JOB_PREFIX}{}", entry.name.trim())),
+                schedule,
+                &entry.prompt,
+                SessionTarget::Isolated,
+                None,
+                Some(delivery),
+                false,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Make the cron store match `[schedules]`: add new entries, update changed
+/// ones, and remove jobs whose entry was deleted. Jobs added through
+/// `zeroclaw cron` are left alone. An invalid entry is logged and skipped so
+/// it cannot block the rest. Returns the number of entries registered.
+pub fn sync_config_schedules(config: &Config) -> Result<usize> {
+    let mut owned: HashMap<String, CronJob> = list_jobs(config)?
+        .into_iter()
+        .filter_map(|job| {
+            let name = job.name.as_deref()?.strip_prefix(CONFIG_JOB_PREFIX)?;
+            Some((name.to_string(), job))
+        })
+        .collect();
+
+    let mut registered = 0;
+    for entry in &config.schedules.jobs {
+        let existing = owned.remove(entry.name.trim());
+        match sync_entry(config, entry, existing) {
+            Ok(()) => registered += 1,
+            Err(err) => tracing::warn!("Skipping [schedules] job `{}`: {err:#}", entry.name),
+        }
+    }
+    for job in owned.into_values() {
+        remove_job(config, &job.id)?;
+    }
+    Ok(registered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn entry(name: &str, cron: &str) -> ScheduledJobConfig {
+        ScheduledJobConfig {
+            name: name.into(),
+            cron: cron.into(),
+            tz: None,
+            prompt: "Summarize yesterday".into(),
+            channel: Some("telegram".into()),
+            to: Some("12345".into()),
+        }
+    }
+
+    #[test]
+    fn sync_adds_updates_and_removes_only_config_jobs() {
+        let tmp = TempDir::new().unwrap();
+        let mut config = Config {
+            workspace_dir: tmp.path().join("workspace"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+        std::fs::create_dir_all(&config.workspace_dir).unwrap();
+        crate::cron::add_job(&config, "*/5 * * * *", "echo manual").unwrap();
+
+        config.schedules.jobs = vec![entry("daily", "0 9 * * *"), entry("bad", "nope")];
+        assert_eq!(sync_config_schedules(&config).unwrap(), 1);
+        let daily = list_jobs(&config)
+            .unwrap()
+            .into_iter()
+            .find(|job| job.name.as_deref() == Some("schedules:daily"))
+            .unwrap();
+        assert_eq!(daily.delivery.mode, "announce");
+        assert_eq!(daily.delivery.to.as_deref(), Some("12345"));
+
+        config.schedules.jobs = vec![entry("daily", "30 7 * * *")];
+        sync_config_schedules(&config).unwrap();
+        let jobs = list_jobs(&config).unwrap();
+        assert_eq!(jobs.len(), 2);
+        let updated = jobs.iter().find(|job| job.id == daily.id).unwrap();
+        assert_eq!(updated.expression, "30 7 * * *");
+
+        config.schedules.jobs.clear();
+        sync_config_schedules(&config).unwrap();
+        let jobs = list_jobs(&config).unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].command, "echo manual");
+    }
+}
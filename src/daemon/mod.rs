@@ -1,4 +1,6 @@
-use crate::config::Config;
+pub mod shutdown;
+
+use crate::config::{Config, UiNotificationsConfig};
 use anyhow::Result;
 use chrono::Utc;
 use std::future::Future;
@@ -23,33 +25,68 @@ pub async fn run(config: Config, host: String, port: u16) -> Result<()> {
                 .await;
     }
 
+    let drain = shutdown::Drain::default();
+    let metrics = crate::health::metrics::RuntimeMetrics::default();
+    let toasts = crate::notifications::Toasts::default();
+    let recorder = crate::providers::recorder::Recorder::new(&config);
+
     let mut handles: Vec<JoinHandle<()>> = vec![spawn_state_writer(config.clone())];
 
     {
         let gateway_cfg = config.clone();
         let gateway_host = host.clone();
+        let gateway_drain = drain.clone();
+        let gateway_metrics = metrics.clone();
+        let gateway_toasts = toasts.clone();
+        let gateway_recorder = recorder.clone();
         handles.push(spawn_component_supervisor(
             "gateway",
             initial_backoff,
             max_backoff,
+            &config.ui.notifications,
             move || {
                 let cfg = gateway_cfg.clone();
                 let host = gateway_host.clone();
-                async move { crate::gateway::run_gateway(&host, port, cfg).await }
+                let drain = gateway_drain.clone();
+                let metrics = gateway_metrics.clone();
+                let toasts = gateway_toasts.clone();
+                let recorder = gateway_recorder.clone();
+                async move {
+                    crate::gateway::run_gateway(&host, port, cfg, drain, metrics, toasts, recorder)
+                        .await
+                }
             },
         ));
     }
 
+    let mut channels_handle = None;
     {
-        if has_supervised_channels(&config) {
+        if crate::connectivity::manual_offline(&config.workspace_dir) {
+            crate::health::mark_component_ok("channels");
+            tracing::info!("Offline mode on; channel listeners not started");
+        } else if has_supervised_channels(&config) {
             let channels_cfg = config.clone();
-            handles.push(spawn_component_supervisor(
+            let channels_drain = drain.clone();
+            let channels_metrics = metrics.clone();
+            let channels_toasts = toasts.clone();
+            let channels_recorder = recorder.clone();
+            channels_handle = Some(spawn_component_supervisor(
                 "channels",
                 initial_backoff,
                 max_backoff,
+                &config.ui.notifications,
                 move || {
                     let cfg = channels_cfg.clone();
-                    async move { crate::channels::start_channels(cfg).await }
+                    let drain = channels_drain.clone();
+                    let metrics = channels_metrics.clone();
+                    let toasts = channels_toasts.clone();
+                    let recorder = channels_recorder.clone();
+                    async move {
+                        Box::pin(crate::channels::start_channels(
+                            cfg, drain, metrics, toasts, recorder,
+                        ))
+                        .await
+                    }
                 },
             ));
         } else {
@@ -64,6 +101,7 @@ pub async fn run(config: Config, host: String, port: u16) -> Result<()> {
             "heartbeat",
             initial_backoff,
             max_backoff,
+            &config.ui.notifications,
             move || {
                 let cfg = heartbeat_cfg.clone();
                 async move { Box::pin(run_heartbeat_worker(cfg)).await }
@@ -71,12 +109,35 @@ pub async fn run(config: Config, host: String, port: u16) -> Result<()> {
         ));
     }
 
+    let (stop_channels_tx, mut stop_channels_rx) = tokio::sync::mpsc::channel::<()>(1);
+    if config.heartbeat.deadman.enabled {
+        let deadman_cfg = config.clone();
+        let deadman_stop_tx = stop_channels_tx.clone();
+        let deadman_toasts = toasts.clone();
+        handles.push(spawn_component_supervisor(
+            "deadman",
+            initial_backoff,
+            max_backoff,
+            &config.ui.notifications,
+            move || {
+                let cfg = deadman_cfg.clone();
+                let stop_channels = deadman_stop_tx.clone();
+                let toasts = deadman_toasts.clone();
+                async move {
+                    crate::heartbeat::deadman::run_worker(cfg, stop_channels, toasts).await
+                }
+            },
+        ));
+    }
+    drop(stop_channels_tx);
+
     if config.cron.enabled {
         let scheduler_cfg = config.clone();
         handles.push(spawn_component_supervisor(
             "scheduler",
             initial_backoff,
             max_backoff,
+            &config.ui.notifications,
             move || {
                 let cfg = scheduler_cfg.clone();
                 async move { crate::cron::scheduler::run(cfg).await }
@@ -87,14 +148,136 @@ pub async fn run(config: Config, host: String, port: u16) -> Result<()> {
         tracing::info!("Cron disabled; scheduler supervisor not started");
     }
 
+    if config.workspace_sync.enabled {
+        let sync_cfg = config.clone();
+        handles.push(spawn_component_supervisor(
+            "workspace-sync",
+            initial_backoff,
+            max_backoff,
+            &config.ui.notifications,
+            move || {
+                let cfg = sync_cfg.clone();
+                async move { crate::workspace_sync::run_worker(cfg).await }
+            },
+        ));
+    }
+
+    if config.backup.enabled {
+        let backup_cfg = config.clone();
+        handles.push(spawn_component_supervisor(
+            "backup",
+            initial_backoff,
+            max_backoff,
+            &config.ui.notifications,
+            move || {
+                let cfg = backup_cfg.clone();
+                async move { crate::backup::run_worker(cfg).await }
+            },
+        ));
+    }
+
+    {
+        let connectivity_cfg = config.clone();
+        handles.push(spawn_component_supervisor(
+            "connectivity",
+            initial_backoff,
+            max_backoff,
+            &config.ui.notifications,
+            move || {
+                let cfg = connectivity_cfg.clone();
+                async move { crate::connectivity::run_worker(cfg).await }
+            },
+        ));
+    }
+
+    if config.power.enabled {
+        let power_cfg = config.clone();
+        let power_toasts = toasts.clone();
+        handles.push(spawn_component_supervisor(
+            "power",
+            initial_backoff,
+            max_backoff,
+            &config.ui.notifications,
+            move || {
+                let cfg = power_cfg.clone();
+                let toasts = power_toasts.clone();
+                async move { crate::power::run_worker(cfg, toasts).await }
+            },
+        ));
+    }
+
+    if config.security.estop.enabled && config.security.estop.kill_switch_pin.is_some() {
+        let estop_cfg = config.clone();
+        let estop_toasts = toasts.clone();
+        handles.push(spawn_component_supervisor(
+            "estop-kill-switch",
+            initial_backoff,
+            max_backoff,
+            &config.ui.notifications,
+            move || {
+                let cfg = estop_cfg.clone();
+                let toasts = estop_toasts.clone();
+                async move { crate::peripherals::run_estop_kill_switch(cfg, toasts).await }
+            },
+        ));
+    }
+
+    if config.peripherals.enabled && config.peripherals.recorder.enabled {
+        let recorder_cfg = config.clone();
+        handles.push(spawn_component_supervisor(
+            "sensor-recorder",
+            initial_backoff,
+            max_backoff,
+            &config.ui.notifications,
+            move || {
+                let cfg = recorder_cfg.clone();
+                async move { Box::pin(crate::peripherals::recorder::run_worker(cfg)).await }
+            },
+        ));
+    }
+
+    if config.peripherals.enabled && config.peripherals.node.mqtt_broker.is_some() {
+        let node_cfg = config.clone();
+        handles.push(spawn_component_supervisor(
+            "peripheral-node",
+            initial_backoff,
+            max_backoff,
+            &config.ui.notifications,
+            move || {
+                let cfg = node_cfg.clone();
+                async move { crate::peripherals::remote::run_mqtt_node(cfg).await }
+            },
+        ));
+    }
+
     println!("🧠 ZeroClaw daemon started");
     println!("   Gateway:  http://{host}:{port}");
     println!("   Components: gateway, channels, heartbeat, scheduler");
     println!("   Ctrl+C to stop");
 
-    tokio::signal::ctrl_c().await?;
+    loop {
+        tokio::select! {
+            result = shutdown::signal() => {
+                result?;
+                break;
+            }
+            Some(()) = stop_channels_rx.recv() => {
+                if let Some(handle) = channels_handle.take() {
+                    handle.abort();
+                    crate::health::mark_component_error(
+                        "channels",
+                        "stopped by dead-man's switch",
+                    );
+                    tracing::warn!("Channel listeners stopped by dead-man's switch");
+                }
+            }
+        }
+    }
+    tracing::info!("Shutdown requested; draining in-flight work");
+    shutdown::drain(&config, &drain).await;
     crate::health::mark_component_error("daemon", "shutdown requested");
 
+    handles.extend(channels_handle);
     for handle in &handles {
         handle.abort();
     }
@@ -140,12 +323,14 @@ fn spawn_component_supervisor<F, Fut>(
     name: &'static str,
     initial_backoff_secs: u64,
     max_backoff_secs: u64,
+    notifications: &UiNotificationsConfig,
     mut run_component: F,
 ) -> JoinHandle<()>
 where
     F: FnMut() -> Fut + Send + 'static,
     Fut: Future<Output = Result<()>> + Send + 'static,
 {
+    let notifications = notifications.clone();
     tokio::spawn(async move {
         let mut backoff = initial_backoff_secs.max(1);
         let max_backoff = max_backoff_secs.max(backoff);
@@ -156,12 +341,22 @@ where
                 Ok(()) => {
                     crate::health::mark_component_error(name, "component exited unexpectedly");
                     tracing::warn!("Daemon component '{name}' exited unexpectedly");
+                    crate::notifications::notify(
+                        &notifications,
+                        crate::notifications::NotifyEvent::ChannelDown,
+                        &format!("Component '{name}' exited unexpectedly"),
+                    );
                     // Clean exit — reset backoff since the component ran successfully
                     backoff = initial_backoff_secs.max(1);
                 }
                 Err(e) => {
                     crate::health::mark_component_error(name, e.to_string());
                     tracing::error!("Daemon component '{name}' failed: {e}");
+                    crate::notifications::notify(
+                        &notifications,
+                        crate::notifications::NotifyEvent::ChannelDown,
+                        &format!("Component '{name}' failed: {e}"),
+                    );
                 }
             }
 
@@ -198,7 +393,7 @@ async fn run_heartbeat_worker(config: Config) -> Result<()> {
         for task in tasks {
             let prompt = format!("[Heartbeat Task] {task}");
             let temp = config.default_temperature;
-            match crate::agent::run(
+            match Box::pin(crate::agent::run(
                 config.clone(),
                 Some(prompt),
                 None,
@@ -206,7 +401,7 @@ async fn run_heartbeat_worker(config: Config) -> Result<()> {
                 temp,
                 vec![],
                 false,
-            )
+            ))
             .await
             {
                 Ok(output) => {
@@ -352,9 +547,13 @@ mod tests {
 
     #[tokio::test]
     async fn supervisor_marks_error_and_restart_on_failure() {
-        let handle = spawn_component_supervisor("daemon-test-fail", 1, 1, || async {
-            anyhow::bail!("boom")
-        });
+        let handle = spawn_component_supervisor(
+            "daemon-test-fail",
+            1,
+            1,
+            &UiNotificationsConfig::default(),
+            || async { anyhow::bail!("boom") },
+        );
 
         tokio::time::sleep(Duration::from_millis(50)).await;
         handle.abort();
@@ -372,7 +571,13 @@ mod tests {
 
     #[tokio::test]
     async fn supervisor_marks_unexpected_exit_as_error() {
-        let handle = spawn_component_supervisor("daemon-test-exit", 1, 1, || async { Ok(()) });
+        let handle = spawn_component_supervisor(
+            "daemon-test-exit",
+            1,
+            1,
+            &UiNotificationsConfig::default(),
+            || async { Ok(()) },
+        );
 
         tokio::time::sleep(Duration::from_millis(50)).await;
         handle.abort();
@@ -404,6 +609,8 @@ mod tests {
             draft_update_interval_ms: 1000,
             interrupt_on_new_message: false,
             mention_only: false,
+            quote_replies: false,
+            lightweight_acks: false,
         });
         assert!(has_supervised_channels(&config));
     }
@@ -536,6 +743,8 @@ mod tests {
             draft_update_interval_ms: 1000,
             interrupt_on_new_message: false,
             mention_only: false,
+            quote_replies: false,
+            lightweight_acks: false,
         });
 
         let target = heartbeat_delivery_target(&config).unwrap();
@@ -0,0 +1,165 @@
+//! Graceful daemon shutdown with in-flight draining.
+//!
+//! The daemon hands one [`Drain`] to the gateway and the channel runtime.
+//! Channel workers and webhook requests hold an [`InFlight`] guard from it
+//! while they generate a reply. Once [`Drain::begin`] is called, new messages
+//! are turned away and [`Drain::wait_idle`] waits (up to
+//! `[shutdown].drain_timeout_secs`) for the guards to drop before the daemon
+//! aborts its components.
+
+use crate::config::Config;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
+
+#[derive(Default)]
+struct DrainState {
+    draining: AtomicBool,
+    in_flight: AtomicUsize,
+    idle: Notify,
+}
+
+/// Shared in-flight counter and drain flag. Clones share the same state.
+#[derive(Clone, Default)]
+pub struct Drain(Arc<DrainState>);
+
+/// Held while one message is being answered.
+#[must_use = "the message counts as in flight only while the guard is held"]
+pub struct InFlight(Arc<DrainState>);
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        if self.0.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.0.idle.notify_waiters();
+        }
+    }
+}
+
+impl Drain {
+    /// Count a message as in flight until the guard drops.
+    pub fn track(&self) -> InFlight {
+        self.0.in_flight.fetch_add(1, Ordering::SeqCst);
+        InFlight(Arc::clone(&self.0))
+    }
+
+    pub fn in_flight(&self) -> usize {
+        self.0.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Stop taking new messages.
+    pub fn begin(&self) {
+        self.0.draining.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether shutdown has started and new messages should be turned away.
+    pub fn is_draining(&self) -> bool {
+        self.0.draining.load(Ordering::SeqCst)
+    }
+
+    /// Wait until nothing is in flight or `timeout` passes. True when idle.
+    pub async fn wait_idle(&self, timeout: Duration) -> bool {
+        let idle = async {
+            loop {
+                let notified = self.0.idle.notified();
+                if self.in_flight() == 0 {
+                    return;
+                }
+                notified.await;
+            }
+        };
+        tokio::time::timeout(timeout, idle).await.is_ok()
+    }
+}
+
+/// Resolve on SIGINT (Ctrl+C) or, on Unix, SIGTERM.
+pub async fn signal() -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        let mut term = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())?;
+        tokio::select! {
+            result = tokio::signal::ctrl_c() => result,
+            _ = term.recv() => Ok(()),
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        tokio::signal::ctrl_c().await
+    }
+}
+
+/// Announce downtime, drain in-flight replies, then send held announcements
+/// and flush memory. Components are still running while this runs.
+pub async fn drain(config: &Config, drain: &Drain) {
+    let shutdown = &config.shutdown;
+    drain.begin();
+    crate::health::mark_component_error("daemon", "draining for shutdown");
+
+    if let Some(notice) = shutdown
+        .notice
+        .as_deref()
+        .map(str::trim)
+        .filter(|notice| !notice.is_empty())
+    {
+        crate::security::estop::notify_target_or_owners(
+            config,
+            shutdown.notify_channel.as_deref(),
+            shutdown.notify_to.as_deref(),
+            notice,
+        )
+        .await;
+    }
+
+    let pending = drain.in_flight();
+    if pending > 0 {
+        tracing::info!(
+            "Shutdown: waiting up to {}s for {pending} in-flight replies",
+            shutdown.drain_timeout_secs
+        );
+        if !drain
+            .wait_idle(Duration::from_secs(shutdown.drain_timeout_secs))
+            .await
+        {
+            tracing::warn!(
+                "Shutdown: drain timed out with {} replies still in flight",
+                drain.in_flight()
+            );
+        }
+    }
+
+    crate::connectivity::flush_queue(config).await;
+
+    if shutdown.flush_memory {
+        let workspace_dir = config.workspace_dir.clone();
+        match tokio::task::spawn_blocking(move || {
+            crate::memory::snapshot::export_snapshot(&workspace_dir)
+        })
+        .await
+        {
+            Ok(Ok(count)) => tracing::info!("Shutdown: exported {count} memories to snapshot"),
+            Ok(Err(error)) => tracing::warn!("Shutdown: memory snapshot failed: {error}"),
+            Err(error) => tracing::warn!("Shutdown: memory snapshot task failed: {error}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn wait_idle_returns_once_guards_drop_or_times_out() {
+        let drain = Drain::default();
+        let guard = drain.track();
+        assert!(!drain.wait_idle(Duration::from_millis(20)).await);
+
+        let waiter = tokio::spawn({
+            let drain = drain.clone();
+            async move { drain.wait_idle(Duration::from_secs(5)).await }
+        });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        drop(guard);
+        assert!(waiter.await.unwrap());
+        assert!(!Drain::default().is_draining());
+    }
+}
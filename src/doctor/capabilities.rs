@@ -0,0 +1,473 @@
+//! Live capability probing for `zeroclaw doctor models --probe-live`.
+//!
+//! Sends tiny requests to the selected model to check streaming, native tool
+//! calling, JSON output and image input. Results are cached per provider and
+//! model in `state/model_capabilities.json` and compared with what the
+//! provider adapter declares and what the config relies on.
+
+use crate::config::{Config, StreamMode};
+use crate::providers::traits::StreamOptions;
+use crate::providers::{ChatMessage, ChatRequest, Provider};
+use crate::tools::ToolSpec;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const REGISTRY_FILE: &str = "model_capabilities.json";
+const PROBE_TIMEOUT_SECS: u64 = 45;
+const PROBE_TEMPERATURE: f64 = 0.0;
+const PROBE_TOOL: &str = "doctor_echo";
+/// 8×8 solid red PNG.
+const PROBE_IMAGE: &str = "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAgAAAAICAIAAABLbSncAAAAEUlEQVR42mP4z8CAFTEMLQkAKP8/wc53yE8AAAAASUVORK5CYII=";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Capability {
+    Streaming,
+    ToolCalling,
+    JsonMode,
+    Vision,
+}
+
+impl Capability {
+    pub const ALL: [Self; 4] = [
+        Self::Streaming,
+        Self::ToolCalling,
+        Self::JsonMode,
+        Self::Vision,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Streaming => "streaming",
+            Self::ToolCalling => "tool calling",
+            Self::JsonMode => "JSON mode",
+            Self::Vision => "vision",
+        }
+    }
+}
+
+/// Probe outcome for one model. A `None` capability means the probe itself
+/// failed (network, auth, timeout) and says nothing either way.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CapabilityRecord {
+    pub probed_at: DateTime<Utc>,
+    pub streaming: Option<bool>,
+    pub tool_calling: Option<bool>,
+    pub json_mode: Option<bool>,
+    pub vision: Option<bool>,
+}
+
+impl CapabilityRecord {
+    pub fn get(&self, capability: Capability) -> Option<bool> {
+        match capability {
+            Capability::Streaming => self.streaming,
+            Capability::ToolCalling => self.tool_calling,
+            Capability::JsonMode => self.json_mode,
+            Capability::Vision => self.vision,
+        }
+    }
+
+    fn set(&mut self, capability: Capability, value: Option<bool>) {
+        let slot = match capability {
+            Capability::Streaming => &mut self.streaming,
+            Capability::ToolCalling => &mut self.tool_calling,
+            Capability::JsonMode => &mut self.json_mode,
+            Capability::Vision => &mut self.vision,
+        };
+        *slot = value;
+    }
+}
+
+/// What the provider adapter claims. The trait has no JSON-mode flag.
+#[derive(Debug, Clone, Copy)]
+pub struct Declared {
+    pub streaming: bool,
+    pub tool_calling: bool,
+    pub vision: bool,
+}
+
+impl Declared {
+    pub fn of(provider: &dyn Provider) -> Self {
+        Self {
+            streaming: provider.supports_streaming(),
+            tool_calling: provider.supports_native_tools(),
+            vision: provider.supports_vision(),
+        }
+    }
+
+    fn get(self, capability: Capability) -> Option<bool> {
+        match capability {
+            Capability::Streaming => Some(self.streaming),
+            Capability::ToolCalling => Some(self.tool_calling),
+            Capability::JsonMode => None,
+            Capability::Vision => Some(self.vision),
+        }
+    }
+}
+
+/// Cached probe results, keyed by provider then model.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CapabilityRegistry {
+    providers: BTreeMap<String, BTreeMap<String, CapabilityRecord>>,
+}
+
+fn registry_path(workspace_dir: &Path) -> PathBuf {
+    workspace_dir.join("state").join(REGISTRY_FILE)
+}
+
+impl CapabilityRegistry {
+    /// Load the registry; a missing file is an empty registry.
+    pub fn load(workspace_dir: &Path) -> Result<Self> {
+        let path = registry_path(workspace_dir);
+        match std::fs::read_to_string(&path) {
+            Ok(raw) => serde_json::from_str(&raw)
+                .with_context(|| format!("Failed to parse {}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("Failed to read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, workspace_dir: &Path) -> Result<()> {
+        let path = registry_path(workspace_dir);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub fn get(&self, provider: &str, model: &str) -> Option<&CapabilityRecord> {
+        self.providers.get(provider)?.get(model)
+    }
+
+    pub fn insert(&mut self, provider: &str, model: &str, record: CapabilityRecord) {
+        self.providers
+            .entry(provider.to_string())
+            .or_default()
+            .insert(model.to_string(), record);
+    }
+}
+
+async fn with_timeout<T>(probe: impl Future<Output = Result<T>>) -> Result<T> {
+    tokio::time::timeout(Duration::from_secs(PROBE_TIMEOUT_SECS), probe)
+        .await
+        .map_err(|_| anyhow::anyhow!("timed out after {PROBE_TIMEOUT_SECS}s"))?
+}
+
+async fn probe_streaming(provider: &dyn Provider, model: &str) -> Result<bool> {
+    let mut stream = provider.stream_chat_with_system(
+        None,
+        "Reply with the single word: pong",
+        model,
+        PROBE_TEMPERATURE,
+        StreamOptions::new(true),
+    );
+    let mut text = String::new();
+    while let Some(chunk) = stream.next().await {
+        text.push_str(&chunk?.delta);
+    }
+    Ok(!text.trim().is_empty())
+}
+
+async fn probe_tool_calling(provider: &dyn Provider, model: &str) -> Result<bool> {
+    let tools = [ToolSpec {
+        name: PROBE_TOOL.into(),
+        description: "Echo a word back. Always call this tool when asked to.".into(),
+        parameters: serde_json::json!({
+            "type": "object",
+            "properties": { "word": { "type": "string" } },
+            "required": ["word"]
+        }),
+    }];
+    let messages = [ChatMessage::user(format!(
+        "Call the `{PROBE_TOOL}` tool with word \"pong\". Do not answer in text."
+    ))];
+    let response = provider
+        .chat(
+            ChatRequest {
+                messages: &messages,
+                tools: Some(&tools),
+            },
+            model,
+            PROBE_TEMPERATURE,
+        )
+        .await?;
+    Ok(response
+        .tool_calls
+        .iter()
+        .any(|call| call.name == PROBE_TOOL))
+}
+
+/// Accepts a bare object or one wrapped in a ```json fence.
+fn is_json_object(reply: &str) -> bool {
+    let trimmed = reply.trim();
+    let body = trimmed
+        .strip_prefix("```json")
+        .or_else(|| trimmed.strip_prefix("```"))
+        .and_then(|rest| rest.strip_suffix("```"))
+        .unwrap_or(trimmed);
+    serde_json::from_str::<serde_json::Value>(body.trim()).is_ok_and(|value| value.is_object())
+}
+
+async fn probe_json_mode(provider: &dyn Provider, model: &str) -> Result<bool> {
+    let reply = provider
+        .chat_with_system(
+            Some("You are a JSON API. Respond with a single JSON object and nothing else."),
+            "Return {\"status\": \"ok\"}.",
+            model,
+            PROBE_TEMPERATURE,
+        )
+        .await?;
+    Ok(is_json_object(&reply))
+}
+
+async fn probe_vision(provider: &dyn Provider, model: &str) -> Result<bool> {
+    let messages = [ChatMessage::user(format!(
+        "What colour is this image? Answer with one word.\n[IMAGE:{PROBE_IMAGE}]"
+    ))];
+    let reply = provider
+        .chat_with_history(&messages, model, PROBE_TEMPERATURE)
+        .await?;
+    Ok(reply.to_lowercase().contains("red"))
+}
+
+/// Run every probe against `model`, printing one line per capability.
+pub async fn probe(provider: &dyn Provider, model: &str) -> CapabilityRecord {
+    let mut record = CapabilityRecord {
+        probed_at: Utc::now(),
+        ..CapabilityRecord::default()
+    };
+    for capability in Capability::ALL {
+        let outcome = match capability {
+            Capability::Streaming => with_timeout(probe_streaming(provider, model)).await,
+            Capability::ToolCalling => with_timeout(probe_tool_calling(provider, model)).await,
+            Capability::JsonMode => with_timeout(probe_json_mode(provider, model)).await,
+            Capability::Vision => with_timeout(probe_vision(provider, model)).await,
+        };
+        let value = match outcome {
+            Ok(supported) => {
+                println!(
+                    "    {} {}",
+                    if supported { "✅" } else { "⚪" },
+                    capability.label()
+                );
+                Some(supported)
+            }
+            Err(err) => {
+                println!(
+                    "    ❌ {}: probe failed: {}",
+                    capability.label(),
+                    super::truncate_for_display(&super::format_error_chain(&err), 160)
+                );
+                None
+            }
+        };
+        record.set(capability, value);
+    }
+    record
+}
+
+/// Disagreements between the probe and the adapter's declarations or the
+/// features the config turns on.
+pub fn mismatches(record: &CapabilityRecord, declared: Declared, config: &Config) -> Vec<String> {
+    let mut found = Vec::new();
+    for capability in Capability::ALL {
+        let (Some(probed), Some(claimed)) = (record.get(capability), declared.get(capability))
+        else {
+            continue;
+        };
+        if claimed && !probed {
+            found.push(format!(
+                "{}: the provider adapter declares support but the probe failed",
+                capability.label()
+            ));
+        } else if probed && !claimed {
+            found.push(format!(
+                "{}: the model handled the probe but the provider adapter does not declare it, \
+                 so ZeroClaw will not use it",
+                capability.label()
+            ));
+        }
+    }
+
+    if config.agent.tool_dispatcher == "native" && record.tool_calling == Some(false) {
+        found.push(
+            "`agent.tool_dispatcher = \"native\"` but the model returned no native tool call; \
+             use \"auto\" or \"xml\""
+                .into(),
+        );
+    }
+    if record.streaming == Some(false)
+        && config
+            .channels_config
+            .telegram
+            .as_ref()
+            .is_some_and(|telegram| telegram.stream_mode != StreamMode::Off)
+    {
+        found.push(
+            "`[channels_config.telegram].stream_mode` is on but the model did not stream; \
+             replies will arrive in one piece"
+                .into(),
+        );
+    }
+    if record.vision == Some(false) && config.multimodal.max_images > 0 {
+        found.push(format!(
+            "`[multimodal].max_images = {}` accepts image attachments but the model could not \
+             read the probe image",
+            config.multimodal.max_images
+        ));
+    }
+    found
+}
+
+/// Print a cached record as a one-line summary.
+pub fn describe(record: &CapabilityRecord) -> String {
+    let parts: Vec<String> = Capability::ALL
+        .iter()
+        .map(|capability| {
+            let state = match record.get(*capability) {
+                Some(true) => "yes",
+                Some(false) => "no",
+                None => "?",
+            };
+            format!("{} {state}", capability.label())
+        })
+        .collect();
+    format!(
+        "{} (probed {})",
+        parts.join(", "),
+        record.probed_at.format("%Y-%m-%d %H:%M UTC")
+    )
+}
+
+/// The capability section of `doctor models`: probe the selected model when
+/// `live`, otherwise show the cached result.
+pub async fn report(config: &Config, provider_override: Option<&str>, live: bool) -> Result<()> {
+    let provider_name = provider_override
+        .or(config.default_provider.as_deref())
+        .unwrap_or("openrouter");
+    let Some(model) = config.default_model.as_deref() else {
+        println!("  Capabilities: no `default_model` configured, nothing to probe.");
+        return Ok(());
+    };
+
+    let mut registry = CapabilityRegistry::load(&config.workspace_dir)?;
+    println!("  Capabilities of {provider_name} / {model}:");
+    if !live {
+        match registry.get(provider_name, model) {
+            Some(record) => println!("    {}", describe(record)),
+            None => println!("    not probed yet; run with --probe-live"),
+        }
+        return Ok(());
+    }
+
+    let options = crate::providers::ProviderRuntimeOptions {
+        auth_profile_override: None,
+        provider_api_url: config.api_url.clone(),
+        zeroclaw_dir: config.config_path.parent().map(PathBuf::from),
+        secrets_encrypt: config.secrets.encrypt,
+        reasoning_enabled: config.runtime.reasoning_enabled,
+        connectivity: None,
+        recorder: None,
+    };
+    let provider = crate::providers::create_provider_with_options(
+        provider_name,
+        config.api_key.as_deref(),
+        &options,
+    )?;
+    let record = probe(provider.as_ref(), model).await;
+    let found = mismatches(&record, Declared::of(provider.as_ref()), config);
+    registry.insert(provider_name, model, record);
+    registry.save(&config.workspace_dir)?;
+
+    if found.is_empty() {
+        println!("    No mismatches with the declared or configured feature set.");
+    } else {
+        println!("  Mismatches:");
+        for mismatch in found {
+            println!("    ⚠️  {mismatch}");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TelegramConfig;
+    use tempfile::TempDir;
+
+    fn record() -> CapabilityRecord {
+        CapabilityRecord {
+            probed_at: Utc::now(),
+            streaming: Some(false),
+            tool_calling: Some(false),
+            json_mode: Some(true),
+            vision: None,
+        }
+    }
+
+    #[test]
+    fn mismatches_flag_declarations_and_config() {
+        let mut config = Config::default();
+        config.agent.tool_dispatcher = "native".into();
+        config.channels_config.telegram = Some(TelegramConfig {
+            bot_token: "t".into(),
+            allowed_users: vec![],
+            stream_mode: StreamMode::Partial,
+            draft_update_interval_ms: 1000,
+            interrupt_on_new_message: false,
+            mention_only: false,
+            quote_replies: false,
+            lightweight_acks: false,
+        });
+        let declared = Declared {
+            streaming: true,
+            tool_calling: true,
+            vision: false,
+        };
+
+        let found = mismatches(&record(), declared, &config);
+        assert_eq!(found.len(), 4, "{found:#?}");
+        assert!(found[0].starts_with("streaming: the provider adapter declares"));
+        assert!(found[1].starts_with("tool calling: the provider adapter declares"));
+        assert!(found[2].contains("tool_dispatcher"));
+        assert!(found[3].contains("stream_mode"));
+
+        // Inconclusive probes and matching declarations are not mismatches.
+        let quiet = Declared {
+            streaming: false,
+            tool_calling: false,
+            vision: true,
+        };
+        assert!(mismatches(&record(), quiet, &Config::default()).is_empty());
+    }
+
+    #[test]
+    fn registry_round_trips_and_json_replies_are_recognised() {
+        let tmp = TempDir::new().unwrap();
+        assert!(CapabilityRegistry::load(tmp.path())
+            .unwrap()
+            .get("openrouter", "a/b")
+            .is_none());
+
+        let saved = record();
+        let mut registry = CapabilityRegistry::default();
+        registry.insert("openrouter", "a/b", saved.clone());
+        registry.save(tmp.path()).unwrap();
+        let loaded = CapabilityRegistry::load(tmp.path()).unwrap();
+        assert_eq!(loaded.get("openrouter", "a/b"), Some(&saved));
+        assert!(loaded.get("openrouter", "other").is_none());
+
+        assert!(is_json_object("{\"status\": \"ok\"}"));
+        assert!(is_json_object("```json\n{\"status\": \"ok\"}\n```"));
+        assert!(!is_json_object("Sure! {\"status\": \"ok\"}"));
+        assert!(!is_json_object("[1, 2]"));
+    }
+}
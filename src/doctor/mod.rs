@@ -1,13 +1,26 @@
 use crate::config::Config;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use std::io::Write;
 use std::path::Path;
 
+mod capabilities;
+
 const DAEMON_STALE_SECONDS: i64 = 30;
 const SCHEDULER_STALE_SECONDS: i64 = 120;
 const CHANNEL_STALE_SECONDS: i64 = 300;
 const COMMAND_VERSION_PREVIEW_CHARS: usize = 60;
+/// Workspace path length past which Windows' 260-char `MAX_PATH` becomes a
+/// risk for nested files when long-path support is off.
+const WINDOWS_WORKSPACE_PATH_WARN_CHARS: usize = 160;
+const NTP_SERVER: &str = "pool.ntp.org:123";
+const NTP_TIMEOUT_SECS: u64 = 2;
+/// Seconds between the NTP era start (1900-01-01) and the Unix epoch.
+const NTP_UNIX_EPOCH_OFFSET_SECS: f64 = 2_208_988_800.0;
+const CLOCK_DRIFT_WARN_SECONDS: f64 = 5.0;
+/// Cron expressions have minute granularity; a minute of drift fires jobs
+/// in the wrong slot.
+const CLOCK_DRIFT_SCHEDULER_SECONDS: f64 = 60.0;
 
 // ── Diagnostic item ──────────────────────────────────────────────
 
@@ -24,36 +37,74 @@ pub enum Severity {
 pub struct DiagResult {
     pub severity: Severity,
     pub category: String,
+    /// Short name of the check (e.g. `default model`, `disk space`).
+    pub name: String,
     pub message: String,
+    /// Suggested fix, for items that are not ok.
+    pub fix: Option<String>,
+    /// Set when ZeroClaw can apply the fix itself.
+    pub action: Option<FixAction>,
+}
+
+/// Fixes `zeroclaw doctor` and the dashboard can apply automatically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FixAction {
+    /// Create the missing workspace directory.
+    CreateWorkspace,
+    /// Set `default_model` to the default provider's recommended model.
+    SetDefaultModel,
+    /// Reset `default_temperature` to 0.7.
+    ResetTemperature,
+    /// Reset `gateway.port` to its default.
+    ResetGatewayPort,
 }
 
 struct DiagItem {
     severity: Severity,
     category: &'static str,
+    name: String,
     message: String,
+    fix: Option<String>,
+    action: Option<FixAction>,
 }
 
 impl DiagItem {
-    fn ok(category: &'static str, msg: impl Into<String>) -> Self {
+    fn new(
+        severity: Severity,
+        category: &'static str,
+        name: impl Into<String>,
+        msg: impl Into<String>,
+    ) -> Self {
         Self {
-            severity: Severity::Ok,
+            severity,
             category,
+            name: name.into(),
             message: msg.into(),
+            fix: None,
+            action: None,
         }
     }
-    fn warn(category: &'static str, msg: impl Into<String>) -> Self {
-        Self {
-            severity: Severity::Warn,
-            category,
-            message: msg.into(),
-        }
+    fn ok(category: &'static str, name: impl Into<String>, msg: impl Into<String>) -> Self {
+        Self::new(Severity::Ok, category, name, msg)
     }
-    fn error(category: &'static str, msg: impl Into<String>) -> Self {
-        Self {
-            severity: Severity::Error,
-            category,
-            message: msg.into(),
-        }
+    fn warn(category: &'static str, name: impl Into<String>, msg: impl Into<String>) -> Self {
+        Self::new(Severity::Warn, category, name, msg)
+    }
+    fn error(category: &'static str, name: impl Into<String>, msg: impl Into<String>) -> Self {
+        Self::new(Severity::Error, category, name, msg)
+    }
+
+    /// Suggested fix shown under the item.
+    fn fix(mut self, fix: impl Into<String>) -> Self {
+        self.fix = Some(fix.into());
+        self
+    }
+
+    /// A fix ZeroClaw can apply itself (see [`apply_fix`]).
+    fn action(mut self, action: FixAction) -> Self {
+        self.action = Some(action);
+        self
     }
 
     fn icon(&self) -> &'static str {
@@ -68,7 +119,10 @@ impl DiagItem {
         DiagResult {
             severity: self.severity,
             category: self.category.to_string(),
+            name: self.name,
             message: self.message,
+            fix: self.fix,
+            action: self.action,
         }
     }
 }
@@ -83,31 +137,52 @@ pub fn diagnose(config: &Config) -> Vec<DiagResult> {
     check_workspace(config, &mut items);
     check_daemon_state(config, &mut items);
     check_environment(&mut items);
+    if cfg!(windows) {
+        check_windows_platform(config, &mut items);
+    }
+    // Clock drift needs an NTP round trip, so it stays in `doctor time`.
+    check_timezones(config, &mut items);
+    check_connectivity(config, &mut items);
     check_cli_tools(&mut items);
 
     items.into_iter().map(DiagItem::into_result).collect()
 }
 
-/// Run diagnostics and print human-readable report to stdout.
+/// Run diagnostics and print human-readable report to stdout, paged when
+/// it is a terminal.
 pub fn run(config: &Config) -> Result<()> {
+    let mut report = Vec::new();
+    write_report(config, &mut report)?;
+    crate::onboard::preview::page(String::from_utf8_lossy(&report).trim_end())
+}
+
+/// Run diagnostics and write the human-readable report to `out`.
+pub fn write_report(config: &Config, out: &mut dyn Write) -> Result<()> {
     let results = diagnose(config);
 
-    // Print report
-    println!("🩺 ZeroClaw Doctor (enhanced)");
-    println!();
+    writeln!(out, "🩺 ZeroClaw Doctor (enhanced)")?;
+    writeln!(out)?;
 
     let mut current_cat = "";
     for item in &results {
         if item.category != current_cat {
             current_cat = &item.category;
-            println!("  [{current_cat}]");
+            writeln!(out, "  [{current_cat}]")?;
         }
         let icon = match item.severity {
             Severity::Ok => "✅",
             Severity::Warn => "⚠️ ",
             Severity::Error => "❌",
         };
-        println!("    {} {}", icon, item.message);
+        writeln!(out, "    {} {}", icon, item.message)?;
+        if let Some(fix) = &item.fix {
+            let auto = if item.action.is_some() {
+                " (one click on the dashboard's Doctor page)"
+            } else {
+                ""
+            };
+            writeln!(out, "       ↳ {fix}{auto}")?;
+        }
     }
 
     let errors = results
@@ -123,18 +198,57 @@ pub fn run(config: &Config) -> Result<()> {
         .filter(|i| i.severity == Severity::Ok)
         .count();
 
-    println!();
-    println!("  Summary: {oks} ok, {warns} warnings, {errors} errors");
+    writeln!(out)?;
+    writeln!(
+        out,
+        "  Summary: {oks} ok, {warns} warnings, {errors} errors"
+    )?;
 
     if errors > 0 {
-        println!("  💡 Fix the errors above, then run `zeroclaw doctor` again.");
+        writeln!(
+            out,
+            "  💡 Fix the errors above, then run `zeroclaw doctor` again."
+        )?;
     }
 
     Ok(())
 }
 
+/// Apply an automatable fix, saving the config when it changes. Returns a
+/// one-line description of what was done.
+pub async fn apply_fix(config: &mut Config, action: FixAction) -> Result<String> {
+    let done = match action {
+        FixAction::CreateWorkspace => {
+            tokio::fs::create_dir_all(&config.workspace_dir)
+                .await
+                .with_context(|| format!("Failed to create {}", config.workspace_dir.display()))?;
+            return Ok(format!("Created {}", config.workspace_dir.display()));
+        }
+        FixAction::SetDefaultModel => {
+            let provider = config.default_provider.as_deref().unwrap_or("openrouter");
+            let model = crate::onboard::wizard::default_model_for_provider(provider);
+            let done = format!("default_model set to {model}");
+            config.default_model = Some(model);
+            done
+        }
+        FixAction::ResetTemperature => {
+            config.default_temperature = Config::default().default_temperature;
+            format!(
+                "default_temperature reset to {}",
+                config.default_temperature
+            )
+        }
+        FixAction::ResetGatewayPort => {
+            config.gateway.port = crate::config::GatewayConfig::default().port;
+            format!("gateway.port reset to {}", config.gateway.port)
+        }
+    };
+    config.save().await?;
+    Ok(done)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum ModelProbeOutcome {
+pub(crate) enum ModelProbeOutcome {
     Ok,
     Skipped,
     AuthOrAccess,
@@ -150,7 +264,7 @@ fn model_probe_status_label(outcome: ModelProbeOutcome) -> &'static str {
     }
 }
 
-fn classify_model_probe_error(err_message: &str) -> ModelProbeOutcome {
+pub(crate) fn classify_model_probe_error(err_message: &str) -> ModelProbeOutcome {
     let lower = err_message.to_lowercase();
 
     if lower.contains("does not support live model discovery") {
@@ -194,6 +308,7 @@ pub async fn run_models(
     config: &Config,
     provider_override: Option<&str>,
     use_cache: bool,
+    probe_live: bool,
 ) -> Result<()> {
     let targets = doctor_model_targets(provider_override);
 
@@ -325,6 +440,9 @@ pub async fn run_models(
         );
     }
 
+    println!();
+    capabilities::report(config, provider_override, probe_live).await?;
+
     if provider_override.is_some() && ok_count == 0 {
         anyhow::bail!("Model probe failed for target provider")
     }
@@ -414,6 +532,78 @@ pub fn run_traces(
     Ok(())
 }
 
+/// Run clock drift and timezone checks. With `fix`, rewrite an invalid
+/// USER.md timezone and print suggestions for everything else.
+pub fn run_time(config: &Config, fix: bool) -> Result<()> {
+    let mut items = Vec::new();
+    check_clock_drift(config, &mut items);
+    check_timezones(config, &mut items);
+
+    println!("🕒 ZeroClaw Doctor — time");
+    println!();
+    for item in &items {
+        println!("  {} {}", item.icon(), item.message);
+    }
+
+    let has_problems = items.iter().any(|item| item.severity != Severity::Ok);
+    if !fix {
+        if has_problems {
+            println!();
+            println!("  💡 Run `zeroclaw doctor time --fix` for suggested fixes.");
+        }
+        return Ok(());
+    }
+
+    println!();
+    if !has_problems {
+        println!("  Nothing to fix.");
+        return Ok(());
+    }
+
+    if items
+        .iter()
+        .any(|item| item.severity != Severity::Ok && item.message.starts_with("clock"))
+    {
+        println!("  Clock: {}", clock_sync_hint());
+    }
+
+    let fallback = detect_system_timezone();
+    for source in collect_timezone_sources(config) {
+        if is_valid_timezone(&source.value) {
+            continue;
+        }
+        let Some(suggestion) = suggest_timezone(&source.value).or_else(|| fallback.clone()) else {
+            println!(
+                "  {}: no suggestion for '{}'; use an IANA name such as Europe/Berlin",
+                source.label(),
+                source.value
+            );
+            continue;
+        };
+
+        match &source.origin {
+            TimezoneOrigin::UserProfile(path) => {
+                let contents = std::fs::read_to_string(path)?;
+                let updated = contents.replacen(
+                    &format!("**Timezone:** {}", source.value),
+                    &format!("**Timezone:** {suggestion}"),
+                    1,
+                );
+                std::fs::write(path, updated)?;
+                println!("  ✅ {}: '{}' → {suggestion}", path.display(), source.value);
+            }
+            TimezoneOrigin::CronJob(id) => {
+                println!("  Cron job {id}: run `zeroclaw cron update {id} --tz {suggestion}`");
+            }
+            TimezoneOrigin::Environment => {
+                println!("  $TZ: export TZ={suggestion}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
 // ── Config semantic validation ───────────────────────────────────
 
 fn check_config_semantics(config: &Config, items: &mut Vec<DiagItem>) {
@@ -423,13 +613,18 @@ fn check_config_semantics(config: &Config, items: &mut Vec<DiagItem>) {
     if config.config_path.exists() {
         items.push(DiagItem::ok(
             cat,
+            "config file",
             format!("config file: {}", config.config_path.display()),
         ));
     } else {
-        items.push(DiagItem::error(
-            cat,
-            format!("config file not found: {}", config.config_path.display()),
-        ));
+        items.push(
+            DiagItem::error(
+                cat,
+                "config file",
+                format!("config file not found: {}", config.config_path.display()),
+            )
+            .fix("Run `zeroclaw onboard` to create it"),
+        );
     }
 
     // Provider validity
@@ -437,27 +632,35 @@ fn check_config_semantics(config: &Config, items: &mut Vec<DiagItem>) {
         if let Some(reason) = provider_validation_error(provider) {
             items.push(DiagItem::error(
                 cat,
+                "default provider",
                 format!("default provider \"{provider}\" is invalid: {reason}"),
-            ));
+            )
+            .fix("Pick a provider from `zeroclaw providers` and set default_provider in config.toml"));
         } else {
             items.push(DiagItem::ok(
                 cat,
+                "default provider",
                 format!("provider \"{provider}\" is valid"),
             ));
         }
     } else {
-        items.push(DiagItem::error(cat, "no default_provider configured"));
+        items.push(
+            DiagItem::error(cat, "default provider", "no default_provider configured")
+                .fix("Run `zeroclaw onboard` or set default_provider in config.toml"),
+        );
     }
 
     // API key presence
     if config.default_provider.as_deref() != Some("ollama") {
         if config.api_key.is_some() {
-            items.push(DiagItem::ok(cat, "API key configured"));
+            items.push(DiagItem::ok(cat, "API key", "API key configured"));
         } else {
             items.push(DiagItem::warn(
                 cat,
+                "API key",
                 "no api_key set (may rely on env vars or provider defaults)",
-            ));
+            )
+            .fix("Set api_key in config.toml (an `env:NAME` reference works) or export the provider's API key variable"));
         }
     }
 
@@ -465,40 +668,61 @@ fn check_config_semantics(config: &Config, items: &mut Vec<DiagItem>) {
     if config.default_model.is_some() {
         items.push(DiagItem::ok(
             cat,
+            "default model",
             format!(
                 "default model: {}",
                 config.default_model.as_deref().unwrap_or("?")
             ),
         ));
     } else {
-        items.push(DiagItem::warn(cat, "no default_model configured"));
+        items.push(DiagItem::warn(
+            cat,
+            "default model",
+            "no default_model configured",
+        )
+        .fix("Use the default provider's recommended model, or pick one with `zeroclaw models set <model>`")
+        .action(FixAction::SetDefaultModel));
     }
 
     // Temperature range
     if config.default_temperature >= 0.0 && config.default_temperature <= 2.0 {
         items.push(DiagItem::ok(
             cat,
+            "temperature",
             format!(
                 "temperature {:.1} (valid range 0.0–2.0)",
                 config.default_temperature
             ),
         ));
     } else {
-        items.push(DiagItem::error(
-            cat,
-            format!(
-                "temperature {:.1} is out of range (expected 0.0–2.0)",
-                config.default_temperature
-            ),
-        ));
+        items.push(
+            DiagItem::error(
+                cat,
+                "temperature",
+                format!(
+                    "temperature {:.1} is out of range (expected 0.0–2.0)",
+                    config.default_temperature
+                ),
+            )
+            .fix("Reset default_temperature to 0.7")
+            .action(FixAction::ResetTemperature),
+        );
     }
 
     // Gateway port range
     let port = config.gateway.port;
     if port > 0 {
-        items.push(DiagItem::ok(cat, format!("gateway port: {port}")));
+        items.push(DiagItem::ok(
+            cat,
+            "gateway port",
+            format!("gateway port: {port}"),
+        ));
     } else {
-        items.push(DiagItem::error(cat, "gateway port is 0 (invalid)"));
+        items.push(
+            DiagItem::error(cat, "gateway port", "gateway port is 0 (invalid)")
+                .fix("Reset gateway.port to its default")
+                .action(FixAction::ResetGatewayPort),
+        );
     }
 
     // Reliability: fallback providers
@@ -506,19 +730,26 @@ fn check_config_semantics(config: &Config, items: &mut Vec<DiagItem>) {
         if let Some(reason) = provider_validation_error(fb) {
             items.push(DiagItem::warn(
                 cat,
+                "fallback providers",
                 format!("fallback provider \"{fb}\" is invalid: {reason}"),
-            ));
+            )
+            .fix("Remove it from reliability.fallback_providers or fix the name (see `zeroclaw providers`)"));
         }
     }
 
     // Model routes validation
     for route in &config.model_routes {
         if route.hint.is_empty() {
-            items.push(DiagItem::warn(cat, "model route with empty hint"));
+            items.push(DiagItem::warn(
+                cat,
+                "model routes",
+                "model route with empty hint",
+            ));
         }
         if let Some(reason) = provider_validation_error(&route.provider) {
             items.push(DiagItem::warn(
                 cat,
+                "model routes",
                 format!(
                     "model route \"{}\" uses invalid provider \"{}\": {}",
                     route.hint, route.provider, reason
@@ -528,6 +759,7 @@ fn check_config_semantics(config: &Config, items: &mut Vec<DiagItem>) {
         if route.model.is_empty() {
             items.push(DiagItem::warn(
                 cat,
+                "model routes",
                 format!("model route \"{}\" has empty model", route.hint),
             ));
         }
@@ -536,11 +768,16 @@ fn check_config_semantics(config: &Config, items: &mut Vec<DiagItem>) {
     // Embedding routes validation
     for route in &config.embedding_routes {
         if route.hint.trim().is_empty() {
-            items.push(DiagItem::warn(cat, "embedding route with empty hint"));
+            items.push(DiagItem::warn(
+                cat,
+                "embedding routes",
+                "embedding route with empty hint",
+            ));
         }
         if let Some(reason) = embedding_provider_validation_error(&route.provider) {
             items.push(DiagItem::warn(
                 cat,
+                "embedding routes",
                 format!(
                     "embedding route \"{}\" uses invalid provider \"{}\": {}",
                     route.hint, route.provider, reason
@@ -550,12 +787,14 @@ fn check_config_semantics(config: &Config, items: &mut Vec<DiagItem>) {
         if route.model.trim().is_empty() {
             items.push(DiagItem::warn(
                 cat,
+                "embedding routes",
                 format!("embedding route \"{}\" has empty model", route.hint),
             ));
         }
         if route.dimensions.is_some_and(|value| value == 0) {
             items.push(DiagItem::warn(
                 cat,
+                "embedding routes",
                 format!(
                     "embedding route \"{}\" has invalid dimensions=0",
                     route.hint
@@ -578,10 +817,12 @@ fn check_config_semantics(config: &Config, items: &mut Vec<DiagItem>) {
         {
             items.push(DiagItem::warn(
                 cat,
+                "embedding model",
                 format!(
                     "memory.embedding_model uses hint \"{hint}\" but no matching [[embedding_routes]] entry exists"
                 ),
-            ));
+            )
+            .fix(format!("Add an [[embedding_routes]] entry with hint = \"{hint}\"")));
         }
     }
 
@@ -590,12 +831,20 @@ fn check_config_semantics(config: &Config, items: &mut Vec<DiagItem>) {
     let has_channel = cc.channels().iter().any(|(_, ok)| *ok);
 
     if has_channel {
-        items.push(DiagItem::ok(cat, "at least one channel configured"));
-    } else {
-        items.push(DiagItem::warn(
+        items.push(DiagItem::ok(
             cat,
-            "no channels configured — run `zeroclaw onboard` to set one up",
+            "channels",
+            "at least one channel configured",
         ));
+    } else {
+        items.push(
+            DiagItem::warn(
+                cat,
+                "channels",
+                "no channels configured — run `zeroclaw onboard` to set one up",
+            )
+            .fix("Run `zeroclaw onboard --channels-only`"),
+        );
     }
 
     // Delegate agents: provider validity
@@ -606,6 +855,7 @@ fn check_config_semantics(config: &Config, items: &mut Vec<DiagItem>) {
         if let Some(reason) = provider_validation_error(&agent.provider) {
             items.push(DiagItem::warn(
                 cat,
+                "delegate agents",
                 format!(
                     "agent \"{name}\" uses invalid provider \"{}\": {}",
                     agent.provider, reason
@@ -662,13 +912,19 @@ fn check_workspace(config: &Config, items: &mut Vec<DiagItem>) {
     if ws.exists() {
         items.push(DiagItem::ok(
             cat,
+            "workspace directory",
             format!("directory exists: {}", ws.display()),
         ));
     } else {
-        items.push(DiagItem::error(
-            cat,
-            format!("directory missing: {}", ws.display()),
-        ));
+        items.push(
+            DiagItem::error(
+                cat,
+                "workspace directory",
+                format!("directory missing: {}", ws.display()),
+            )
+            .fix("Create the workspace directory")
+            .action(FixAction::CreateWorkspace),
+        );
         return;
     }
 
@@ -684,18 +940,27 @@ fn check_workspace(config: &Config, items: &mut Vec<DiagItem>) {
             drop(probe_file);
             let _ = std::fs::remove_file(&probe);
             match write_result {
-                Ok(()) => items.push(DiagItem::ok(cat, "directory is writable")),
+                Ok(()) => items.push(DiagItem::ok(
+                    cat,
+                    "workspace writable",
+                    "directory is writable",
+                )),
                 Err(e) => items.push(DiagItem::error(
                     cat,
+                    "workspace writable",
                     format!("directory write probe failed: {e}"),
                 )),
             }
         }
         Err(e) => {
-            items.push(DiagItem::error(
-                cat,
-                format!("directory is not writable: {e}"),
-            ));
+            items.push(
+                DiagItem::error(
+                    cat,
+                    "workspace writable",
+                    format!("directory is not writable: {e}"),
+                )
+                .fix("Check ownership and permissions of the workspace directory"),
+            );
         }
     }
 
@@ -704,13 +969,18 @@ fn check_workspace(config: &Config, items: &mut Vec<DiagItem>) {
         if avail_mb >= 100 {
             items.push(DiagItem::ok(
                 cat,
+                "disk space",
                 format!("disk space: {avail_mb} MB available"),
             ));
         } else {
-            items.push(DiagItem::warn(
-                cat,
-                format!("low disk space: only {avail_mb} MB available"),
-            ));
+            items.push(
+                DiagItem::warn(
+                    cat,
+                    "disk space",
+                    format!("low disk space: only {avail_mb} MB available"),
+                )
+                .fix("Free disk space, or prune old memory and LLM records in the workspace"),
+            );
         }
     }
 
@@ -728,11 +998,14 @@ fn check_file_exists(
 ) {
     let path = base.join(name);
     if path.is_file() {
-        items.push(DiagItem::ok(cat, format!("{name} present")));
+        items.push(DiagItem::ok(cat, name, format!("{name} present")));
     } else if required {
-        items.push(DiagItem::error(cat, format!("{name} missing")));
+        items.push(DiagItem::error(cat, name, format!("{name} missing")));
     } else {
-        items.push(DiagItem::warn(cat, format!("{name} not found (optional)")));
+        items.push(
+            DiagItem::warn(cat, name, format!("{name} not found (optional)"))
+                .fix("Run `zeroclaw onboard` to scaffold the workspace files"),
+        );
     }
 }
 
@@ -773,20 +1046,28 @@ fn check_daemon_state(config: &Config, items: &mut Vec<DiagItem>) {
     let state_file = crate::daemon::state_file_path(config);
 
     if !state_file.exists() {
-        items.push(DiagItem::error(
-            cat,
-            format!(
-                "state file not found: {} — is the daemon running?",
-                state_file.display()
-            ),
-        ));
+        items.push(
+            DiagItem::error(
+                cat,
+                "daemon state file",
+                format!(
+                    "state file not found: {} — is the daemon running?",
+                    state_file.display()
+                ),
+            )
+            .fix("Start it with `zeroclaw service start` or `zeroclaw daemon`"),
+        );
         return;
     }
 
     let raw = match std::fs::read_to_string(&state_file) {
         Ok(r) => r,
         Err(e) => {
-            items.push(DiagItem::error(cat, format!("cannot read state file: {e}")));
+            items.push(DiagItem::error(
+                cat,
+                "daemon state file",
+                format!("cannot read state file: {e}"),
+            ));
             return;
         }
     };
@@ -794,7 +1075,11 @@ fn check_daemon_state(config: &Config, items: &mut Vec<DiagItem>) {
     let snapshot: serde_json::Value = match serde_json::from_str(&raw) {
         Ok(v) => v,
         Err(e) => {
-            items.push(DiagItem::error(cat, format!("invalid state JSON: {e}")));
+            items.push(DiagItem::error(
+                cat,
+                "daemon state file",
+                format!("invalid state JSON: {e}"),
+            ));
             return;
         }
     };
@@ -810,16 +1095,25 @@ fn check_daemon_state(config: &Config, items: &mut Vec<DiagItem>) {
             .signed_duration_since(ts.with_timezone(&Utc))
             .num_seconds();
         if age <= DAEMON_STALE_SECONDS {
-            items.push(DiagItem::ok(cat, format!("heartbeat fresh ({age}s ago)")));
-        } else {
-            items.push(DiagItem::error(
+            items.push(DiagItem::ok(
                 cat,
-                format!("heartbeat stale ({age}s ago)"),
+                "daemon heartbeat",
+                format!("heartbeat fresh ({age}s ago)"),
             ));
+        } else {
+            items.push(
+                DiagItem::error(
+                    cat,
+                    "daemon heartbeat",
+                    format!("heartbeat stale ({age}s ago)"),
+                )
+                .fix("Restart the daemon with `zeroclaw service restart` or `zeroclaw daemon`"),
+            );
         }
     } else {
         items.push(DiagItem::error(
             cat,
+            "daemon heartbeat",
             format!("invalid daemon timestamp: {updated_at}"),
         ));
     }
@@ -846,16 +1140,25 @@ fn check_daemon_state(config: &Config, items: &mut Vec<DiagItem>) {
             if scheduler_ok && scheduler_age <= SCHEDULER_STALE_SECONDS {
                 items.push(DiagItem::ok(
                     cat,
+                    "scheduler",
                     format!("scheduler healthy (last ok {scheduler_age}s ago)"),
                 ));
             } else {
-                items.push(DiagItem::error(
-                    cat,
-                    format!("scheduler unhealthy (ok={scheduler_ok}, age={scheduler_age}s)"),
-                ));
+                items.push(
+                    DiagItem::error(
+                        cat,
+                        "scheduler",
+                        format!("scheduler unhealthy (ok={scheduler_ok}, age={scheduler_age}s)"),
+                    )
+                    .fix("Check `zeroclaw cron list` and the daemon logs, then restart the daemon"),
+                );
             }
         } else {
-            items.push(DiagItem::warn(cat, "scheduler component not tracked yet"));
+            items.push(DiagItem::warn(
+                cat,
+                "scheduler",
+                "scheduler component not tracked yet",
+            ));
         }
 
         // Channels
@@ -879,21 +1182,34 @@ fn check_daemon_state(config: &Config, items: &mut Vec<DiagItem>) {
                 });
 
             if status_ok && age <= CHANNEL_STALE_SECONDS {
-                items.push(DiagItem::ok(cat, format!("{name} fresh ({age}s ago)")));
-            } else {
-                stale += 1;
-                items.push(DiagItem::error(
+                items.push(DiagItem::ok(
                     cat,
-                    format!("{name} stale (ok={status_ok}, age={age}s)"),
+                    name.as_str(),
+                    format!("{name} fresh ({age}s ago)"),
                 ));
+            } else {
+                stale += 1;
+                items.push(
+                    DiagItem::error(
+                        cat,
+                        name.as_str(),
+                        format!("{name} stale (ok={status_ok}, age={age}s)"),
+                    )
+                    .fix("Run `zeroclaw channel doctor` to probe it, then restart the daemon"),
+                );
             }
         }
 
         if channel_count == 0 {
-            items.push(DiagItem::warn(cat, "no channel components tracked yet"));
+            items.push(DiagItem::warn(
+                cat,
+                "channel freshness",
+                "no channel components tracked yet",
+            ));
         } else if stale > 0 {
             items.push(DiagItem::warn(
                 cat,
+                "channel freshness",
                 format!("{channel_count} channels, {stale} stale"),
             ));
         }
@@ -908,35 +1224,450 @@ fn check_environment(items: &mut Vec<DiagItem>) {
     // git
     check_command_available("git", &["--version"], cat, items);
 
-    // Shell
-    let shell = std::env::var("SHELL").unwrap_or_default();
+    // Shell (Windows has no $SHELL; cmd.exe is advertised via %ComSpec%)
+    let shell_var = if cfg!(windows) { "ComSpec" } else { "SHELL" };
+    let shell = std::env::var(shell_var).unwrap_or_default();
     if shell.is_empty() {
-        items.push(DiagItem::warn(cat, "$SHELL not set"));
+        items.push(DiagItem::warn(
+            cat,
+            "shell",
+            format!("${shell_var} not set"),
+        ));
     } else {
-        items.push(DiagItem::ok(cat, format!("shell: {shell}")));
+        items.push(DiagItem::ok(cat, "shell", format!("shell: {shell}")));
     }
 
     // HOME
     if std::env::var("HOME").is_ok() || std::env::var("USERPROFILE").is_ok() {
-        items.push(DiagItem::ok(cat, "home directory env set"));
-    } else {
-        items.push(DiagItem::error(
+        items.push(DiagItem::ok(
             cat,
-            "neither $HOME nor $USERPROFILE is set",
+            "home directory",
+            "home directory env set",
         ));
+    } else {
+        items.push(
+            DiagItem::error(
+                cat,
+                "home directory",
+                "neither $HOME nor $USERPROFILE is set",
+            )
+            .fix("Set $HOME (or %USERPROFILE% on Windows) in the service environment"),
+        );
     }
 
     // Optional tools
     check_command_available("curl", &["--version"], cat, items);
 }
 
+// ── Windows platform checks ──────────────────────────────────────
+
+fn check_windows_platform(config: &Config, items: &mut Vec<DiagItem>) {
+    let cat = "windows";
+
+    if console::Term::stdout().features().colors_supported() {
+        items.push(DiagItem::ok(
+            cat,
+            "ANSI console",
+            "console supports ANSI escape sequences",
+        ));
+    } else {
+        items.push(DiagItem::warn(
+            cat, "ANSI console",
+            "console does not report ANSI support — use Windows Terminal, or set NO_COLOR=1 to avoid escape-code noise",
+        ));
+    }
+
+    let long_paths = std::process::Command::new("reg")
+        .args([
+            "query",
+            r"HKLM\SYSTEM\CurrentControlSet\Control\FileSystem",
+            "/v",
+            "LongPathsEnabled",
+        ])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| parse_long_paths_enabled(&String::from_utf8_lossy(&output.stdout)));
+    let workspace_chars = config.workspace_dir.to_string_lossy().chars().count();
+
+    match long_paths {
+        Some(true) => items.push(DiagItem::ok(cat, "long paths", "long path support enabled")),
+        _ if workspace_chars > WINDOWS_WORKSPACE_PATH_WARN_CHARS => {
+            items.push(DiagItem::warn(
+                cat, "long paths",
+                format!(
+                    "long paths are disabled and the workspace path is {workspace_chars} chars — nested files may hit the 260-char limit; enable LongPathsEnabled or move the workspace"
+                ),
+            ));
+        }
+        Some(false) => items.push(DiagItem::warn(
+            cat, "long paths",
+            "long path support disabled (LongPathsEnabled=0); deep workspace files may fail to open",
+        )),
+        None => items.push(DiagItem::warn(
+            cat, "long paths",
+            "could not read LongPathsEnabled from the registry",
+        )),
+    }
+}
+
+/// Parse `reg query ... /v LongPathsEnabled` output
+/// (`    LongPathsEnabled    REG_DWORD    0x1`).
+fn parse_long_paths_enabled(reg_output: &str) -> Option<bool> {
+    let line = reg_output
+        .lines()
+        .find(|line| line.trim_start().starts_with("LongPathsEnabled"))?;
+    let value = line.split_whitespace().last()?;
+    let value = value.strip_prefix("0x").unwrap_or(value);
+    u32::from_str_radix(value, 16).ok().map(|v| v != 0)
+}
+
+// ── Time checks ──────────────────────────────────────────────────
+
+/// Where a configured timezone name came from.
+enum TimezoneOrigin {
+    /// `**Timezone:**` line in the workspace USER.md written by onboarding.
+    UserProfile(std::path::PathBuf),
+    /// `--tz` on a cron schedule, keyed by job id.
+    CronJob(String),
+    Environment,
+}
+
+struct TimezoneSource {
+    origin: TimezoneOrigin,
+    value: String,
+}
+
+impl TimezoneSource {
+    fn label(&self) -> String {
+        match &self.origin {
+            TimezoneOrigin::UserProfile(_) => "USER.md timezone".to_string(),
+            TimezoneOrigin::CronJob(id) => format!("cron job {id} timezone"),
+            TimezoneOrigin::Environment => "$TZ".to_string(),
+        }
+    }
+}
+
+/// Measure the local clock against [`NTP_SERVER`]. Blocks for up to
+/// [`NTP_TIMEOUT_SECS`] on the network round trip.
+fn check_clock_drift(config: &Config, items: &mut Vec<DiagItem>) {
+    let otp_step = config
+        .security
+        .otp
+        .enabled
+        .then_some(config.security.otp.token_ttl_secs.max(1));
+    match query_ntp_offset(NTP_SERVER) {
+        Ok(offset) => {
+            let item = classify_clock_drift(offset, otp_step);
+            items.push(if item.severity == Severity::Ok {
+                item
+            } else {
+                item.fix("Enable NTP time synchronization (for example `timedatectl set-ntp true`)")
+            });
+        }
+        Err(e) => items.push(DiagItem::warn(
+            "time",
+            "clock drift",
+            format!("could not measure clock drift against {NTP_SERVER}: {e}"),
+        )),
+    }
+}
+
+fn check_timezones(config: &Config, items: &mut Vec<DiagItem>) {
+    let cat = "time";
+
+    for source in collect_timezone_sources(config) {
+        if is_valid_timezone(&source.value) {
+            items.push(DiagItem::ok(
+                cat,
+                source.label(),
+                format!("{}: {}", source.label(), source.value),
+            ));
+            continue;
+        }
+        let hint = suggest_timezone(&source.value)
+            .map(|tz| format!(" (did you mean {tz}?)"))
+            .unwrap_or_default();
+        items.push(
+            DiagItem::warn(
+                cat,
+                source.label(),
+                format!(
+                    "{}: '{}' is not in the tz database{hint}",
+                    source.label(),
+                    source.value
+                ),
+            )
+            .fix("Use an IANA timezone name such as Europe/Berlin or America/New_York"),
+        );
+    }
+}
+
+/// Turn a measured offset (positive = local clock behind NTP) into a diagnostic.
+fn classify_clock_drift(offset_secs: f64, otp_step_secs: Option<u64>) -> DiagItem {
+    let cat = "time";
+    let drift = offset_secs.abs();
+    let direction = if offset_secs > 0.0 {
+        "behind"
+    } else {
+        "ahead of"
+    };
+    let summary = format!("clock is {drift:.1}s {direction} NTP");
+
+    if let Some(step) = otp_step_secs {
+        // TOTP accepts the previous and next step, so a full step of drift
+        // rejects every code and half a step already rejects some.
+        #[allow(clippy::cast_precision_loss)]
+        let step = step as f64;
+        if drift >= step {
+            return DiagItem::error(
+                cat,
+                "clock drift",
+                format!("{summary} — OTP codes ({step:.0}s step) will be rejected"),
+            );
+        }
+        if drift >= step / 2.0 {
+            return DiagItem::warn(
+                cat,
+                "clock drift",
+                format!(
+                    "{summary} — OTP codes ({step:.0}s step) may be rejected near step boundaries"
+                ),
+            );
+        }
+    }
+
+    if drift >= CLOCK_DRIFT_SCHEDULER_SECONDS {
+        DiagItem::error(
+            cat,
+            "clock drift",
+            format!("{summary} — scheduled jobs will fire in the wrong minute"),
+        )
+    } else if drift >= CLOCK_DRIFT_WARN_SECONDS {
+        DiagItem::warn(
+            cat,
+            "clock drift",
+            format!("{summary} — enable time synchronization"),
+        )
+    } else {
+        DiagItem::ok(cat, "clock drift", summary)
+    }
+}
+
+/// Query an SNTP server and return the local clock offset in seconds.
+fn query_ntp_offset(server: &str) -> Result<f64> {
+    use std::net::UdpSocket;
+    use std::time::{Duration, SystemTime};
+
+    let socket = UdpSocket::bind("0.0.0.0:0")?;
+    socket.set_read_timeout(Some(Duration::from_secs(NTP_TIMEOUT_SECS)))?;
+    socket.set_write_timeout(Some(Duration::from_secs(NTP_TIMEOUT_SECS)))?;
+
+    // LI = 0, VN = 3, Mode = 3 (client)
+    let mut request = [0u8; 48];
+    request[0] = 0x1B;
+
+    let sent = unix_secs_f64(SystemTime::now());
+    socket.send_to(&request, server)?;
+    let mut response = [0u8; 48];
+    let (len, _) = socket.recv_from(&mut response)?;
+    let received = unix_secs_f64(SystemTime::now());
+
+    parse_ntp_offset(&response[..len], sent, received)
+}
+
+fn unix_secs_f64(time: std::time::SystemTime) -> f64 {
+    time.duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs_f64())
+        .unwrap_or_default()
+}
+
+fn ntp_timestamp_secs(bytes: &[u8]) -> f64 {
+    let secs = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let frac = u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+    f64::from(secs) - NTP_UNIX_EPOCH_OFFSET_SECS + f64::from(frac) / 4_294_967_296.0
+}
+
+/// Compute the clock offset from an SNTP reply given local send/receive
+/// times (Unix seconds), per RFC 4330 `((T2 - T1) + (T3 - T4)) / 2`.
+fn parse_ntp_offset(response: &[u8], sent: f64, received: f64) -> Result<f64> {
+    if response.len() < 48 {
+        anyhow::bail!("short NTP reply ({} bytes)", response.len());
+    }
+    if response[0] & 0x07 != 4 {
+        anyhow::bail!("unexpected NTP reply mode");
+    }
+    if response[40..44].iter().all(|b| *b == 0) {
+        anyhow::bail!("NTP server returned no transmit timestamp");
+    }
+
+    let server_received = ntp_timestamp_secs(&response[32..40]);
+    let server_sent = ntp_timestamp_secs(&response[40..48]);
+    Ok(f64::midpoint(
+        server_received - sent,
+        server_sent - received,
+    ))
+}
+
+fn collect_timezone_sources(config: &Config) -> Vec<TimezoneSource> {
+    let mut sources = Vec::new();
+
+    let user_md = config.workspace_dir.join("USER.md");
+    if let Ok(contents) = std::fs::read_to_string(&user_md) {
+        if let Some(value) = parse_user_md_timezone(&contents) {
+            sources.push(TimezoneSource {
+                origin: TimezoneOrigin::UserProfile(user_md),
+                value,
+            });
+        }
+    }
+
+    // Only read the cron store when it exists; opening it would create it.
+    if config.workspace_dir.join("cron").join("jobs.db").exists() {
+        if let Ok(jobs) = crate::cron::list_jobs(config) {
+            for job in jobs {
+                if let crate::cron::Schedule::Cron { tz: Some(tz), .. } = job.schedule {
+                    sources.push(TimezoneSource {
+                        origin: TimezoneOrigin::CronJob(job.id),
+                        value: tz,
+                    });
+                }
+            }
+        }
+    }
+
+    if let Ok(tz) = std::env::var("TZ") {
+        let tz = tz.trim().trim_start_matches(':');
+        if !tz.is_empty() {
+            sources.push(TimezoneSource {
+                origin: TimezoneOrigin::Environment,
+                value: tz.to_string(),
+            });
+        }
+    }
+
+    sources
+}
+
+/// Extract the value of the `- **Timezone:** ...` line in USER.md.
+fn parse_user_md_timezone(contents: &str) -> Option<String> {
+    contents.lines().find_map(|line| {
+        let value = line
+            .trim()
+            .trim_start_matches('-')
+            .trim()
+            .strip_prefix("**Timezone:**")?
+            .trim();
+        (!value.is_empty()).then(|| value.to_string())
+    })
+}
+
+fn is_valid_timezone(name: &str) -> bool {
+    name.parse::<chrono_tz::Tz>().is_ok()
+}
+
+/// Suggest a tz database name for a mistyped one: case-insensitive match
+/// first, then a unique city match (`berlin` → `Europe/Berlin`).
+fn suggest_timezone(raw: &str) -> Option<String> {
+    let wanted = raw.trim().replace(' ', "_").to_ascii_lowercase();
+    if wanted.is_empty() {
+        return None;
+    }
+
+    if let Some(tz) = chrono_tz::TZ_VARIANTS
+        .iter()
+        .find(|tz| tz.name().eq_ignore_ascii_case(&wanted))
+    {
+        return Some(tz.name().to_string());
+    }
+
+    let mut city_matches = chrono_tz::TZ_VARIANTS.iter().filter(|tz| {
+        tz.name()
+            .rsplit('/')
+            .next()
+            .is_some_and(|city| city.eq_ignore_ascii_case(&wanted))
+    });
+    match (city_matches.next(), city_matches.next()) {
+        (Some(tz), None) => Some(tz.name().to_string()),
+        _ => None,
+    }
+}
+
+/// Best-effort detection of the host's configured tz database name.
+fn detect_system_timezone() -> Option<String> {
+    let from_file = std::fs::read_to_string("/etc/timezone")
+        .ok()
+        .map(|s| s.trim().to_string());
+    let from_link = std::fs::read_link("/etc/localtime")
+        .ok()
+        .and_then(|target| {
+            let target = target.to_string_lossy().into_owned();
+            target
+                .split_once("zoneinfo/")
+                .map(|(_, name)| name.to_string())
+        });
+
+    from_file
+        .into_iter()
+        .chain(from_link)
+        .find(|name| is_valid_timezone(name))
+}
+
+fn clock_sync_hint() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "run `sudo sntp -sS time.apple.com` or enable \"Set time automatically\""
+    } else if cfg!(windows) {
+        "run `w32tm /resync` from an elevated prompt"
+    } else {
+        "run `sudo timedatectl set-ntp true` (or start chronyd/ntpd)"
+    }
+}
+
+fn check_connectivity(config: &Config, items: &mut Vec<DiagItem>) {
+    let cat = "connectivity";
+    if !config.connectivity.enabled {
+        items.push(DiagItem::ok(
+            cat,
+            "connectivity monitor",
+            "monitor disabled ([connectivity].enabled = false)",
+        ));
+        return;
+    }
+    let outages = crate::connectivity::recent_outages(&config.workspace_dir, 5);
+    let week_ago = Utc::now() - chrono::Duration::days(7);
+    let recent: Vec<_> = outages
+        .iter()
+        .filter(|outage| outage.ended.is_none_or(|ended| ended > week_ago))
+        .collect();
+    if recent.is_empty() {
+        items.push(DiagItem::ok(
+            cat,
+            "connectivity monitor",
+            "no outages in the last 7 days",
+        ));
+        return;
+    }
+    for outage in recent {
+        let message = format!("outage {}", outage.describe());
+        if outage.ended.is_none() {
+            items.push(DiagItem::warn(cat, "outage", message));
+        } else {
+            items.push(DiagItem::ok(cat, "outage", message));
+        }
+    }
+}
+
 fn check_cli_tools(items: &mut Vec<DiagItem>) {
     let cat = "cli-tools";
 
     let discovered = crate::tools::cli_discovery::discover_cli_tools(&[], &[]);
 
     if discovered.is_empty() {
-        items.push(DiagItem::warn(cat, "No CLI tools found in PATH"));
+        items.push(DiagItem::warn(
+            cat,
+            "CLI tools",
+            "No CLI tools found in PATH",
+        ));
     } else {
         for cli in &discovered {
             let version_info = cli
@@ -946,11 +1677,13 @@ fn check_cli_tools(items: &mut Vec<DiagItem>) {
                 .unwrap_or_else(|| "unknown version".to_string());
             items.push(DiagItem::ok(
                 cat,
+                cli.name.as_str(),
                 format!("{} ({}) — {}", cli.name, cli.category, version_info),
             ));
         }
         items.push(DiagItem::ok(
             cat,
+            "CLI tools",
             format!("{} CLI tools discovered", discovered.len()),
         ));
     }
@@ -967,16 +1700,20 @@ fn check_command_available(cmd: &str, args: &[&str], cat: &'static str, items: &
             let ver = String::from_utf8_lossy(&output.stdout);
             let first_line = ver.lines().next().unwrap_or("").trim();
             let display = truncate_for_display(first_line, COMMAND_VERSION_PREVIEW_CHARS);
-            items.push(DiagItem::ok(cat, format!("{cmd}: {display}")));
+            items.push(DiagItem::ok(cat, cmd, format!("{cmd}: {display}")));
         }
         Ok(_) => {
             items.push(DiagItem::warn(
                 cat,
+                cmd,
                 format!("{cmd} found but returned non-zero"),
             ));
         }
         Err(_) => {
-            items.push(DiagItem::warn(cat, format!("{cmd} not found in PATH")));
+            items.push(
+                DiagItem::warn(cat, cmd, format!("{cmd} not found in PATH"))
+                    .fix(format!("Install {cmd} with your system package manager")),
+            );
         }
     }
 }
@@ -1033,11 +1770,76 @@ mod tests {
         assert!(invalid_unknown.contains("Unknown provider"));
     }
 
+    #[test]
+    fn clock_drift_classification_respects_otp_step() {
+        assert_eq!(classify_clock_drift(1.2, None).severity, Severity::Ok);
+        assert_eq!(classify_clock_drift(-8.0, None).severity, Severity::Warn);
+        assert_eq!(classify_clock_drift(75.0, None).severity, Severity::Error);
+        assert_eq!(
+            classify_clock_drift(16.0, Some(30)).severity,
+            Severity::Warn
+        );
+
+        let rejected = classify_clock_drift(-31.0, Some(30));
+        assert_eq!(rejected.severity, Severity::Error);
+        assert!(rejected.message.contains("ahead of"));
+        assert!(rejected.message.contains("OTP"));
+    }
+
+    #[test]
+    fn parse_ntp_offset_computes_symmetric_offset() {
+        let unix = 1_700_000_000.0_f64;
+        let mut reply = [0u8; 48];
+        reply[0] = 0x1C; // VN = 3, Mode = 4 (server)
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let ntp_secs = ((unix + 10.0) + NTP_UNIX_EPOCH_OFFSET_SECS) as u32;
+        reply[32..36].copy_from_slice(&ntp_secs.to_be_bytes());
+        reply[40..44].copy_from_slice(&ntp_secs.to_be_bytes());
+
+        let offset = parse_ntp_offset(&reply, unix, unix).unwrap();
+        assert!((offset - 10.0).abs() < 1e-6);
+
+        reply[0] = 0x1B;
+        assert!(parse_ntp_offset(&reply, unix, unix).is_err());
+        assert!(parse_ntp_offset(&reply[..20], unix, unix).is_err());
+    }
+
+    #[test]
+    fn diagnose_skips_the_ntp_probe() {
+        let tmp = TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.config_path = tmp.path().join("config.toml");
+        config.workspace_dir = tmp.path().join("workspace");
+
+        let results = diagnose(&config);
+        assert!(results.iter().all(|item| item.name != "clock drift"));
+    }
+
+    #[test]
+    fn timezone_helpers_validate_and_suggest() {
+        assert_eq!(
+            parse_user_md_timezone("# USER.md\n- **Name:** Ana\n- **Timezone:** US/Pacific\n")
+                .as_deref(),
+            Some("US/Pacific")
+        );
+        assert!(is_valid_timezone("Europe/Berlin"));
+        assert!(!is_valid_timezone("Mars/Olympus"));
+        assert_eq!(
+            suggest_timezone("europe/berlin").as_deref(),
+            Some("Europe/Berlin")
+        );
+        assert_eq!(
+            suggest_timezone("new york").as_deref(),
+            Some("America/New_York")
+        );
+        assert_eq!(suggest_timezone("Mars/Olympus"), None);
+    }
+
     #[test]
     fn diag_item_icons() {
-        assert_eq!(DiagItem::ok("t", "m").icon(), "✅");
-        assert_eq!(DiagItem::warn("t", "m").icon(), "⚠️ ");
-        assert_eq!(DiagItem::error("t", "m").icon(), "❌");
+        assert_eq!(DiagItem::ok("t", "n", "m").icon(), "✅");
+        assert_eq!(DiagItem::warn("t", "n", "m").icon(), "⚠️ ");
+        assert_eq!(DiagItem::error("t", "n", "m").icon(), "❌");
     }
 
     #[test]
@@ -1070,6 +1872,40 @@ mod tests {
         assert_eq!(temp_item.unwrap().severity, Severity::Error);
     }
 
+    #[tokio::test]
+    async fn automatable_fixes_are_offered_and_applied() {
+        let tmp = TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.config_path = tmp.path().join("config.toml");
+        config.workspace_dir = tmp.path().join("workspace");
+        config.default_temperature = 5.0;
+        config.default_model = None;
+
+        let mut items = Vec::new();
+        check_config_semantics(&config, &mut items);
+        check_workspace(&config, &mut items);
+        let actions: Vec<_> = items.iter().filter_map(|item| item.action).collect();
+        assert!(actions.contains(&FixAction::ResetTemperature));
+        assert!(actions.contains(&FixAction::SetDefaultModel));
+        assert!(actions.contains(&FixAction::CreateWorkspace));
+        assert!(items
+            .iter()
+            .filter(|item| item.severity != Severity::Ok)
+            .all(|item| !item.name.is_empty()));
+
+        for action in actions {
+            apply_fix(&mut config, action).await.unwrap();
+        }
+        assert!((config.default_temperature - 0.7).abs() < f64::EPSILON);
+        assert!(config.default_model.is_some());
+        assert!(config.workspace_dir.is_dir());
+
+        let mut items = Vec::new();
+        check_config_semantics(&config, &mut items);
+        check_workspace(&config, &mut items);
+        assert!(items.iter().all(|item| item.action.is_none()));
+    }
+
     #[test]
     fn config_validation_accepts_valid_temperature() {
         let mut config = Config::default();
@@ -1240,6 +2076,20 @@ mod tests {
         assert_eq!(git_item.unwrap().severity, Severity::Ok);
     }
 
+    #[test]
+    fn parse_long_paths_enabled_reads_reg_dword() {
+        let enabled = "\r\nHKEY_LOCAL_MACHINE\\SYSTEM\\CurrentControlSet\\Control\\FileSystem\r\n    LongPathsEnabled    REG_DWORD    0x1\r\n";
+        assert_eq!(parse_long_paths_enabled(enabled), Some(true));
+        assert_eq!(
+            parse_long_paths_enabled("    LongPathsEnabled    REG_DWORD    0x0"),
+            Some(false)
+        );
+        assert_eq!(
+            parse_long_paths_enabled("ERROR: The system was unable to find"),
+            None
+        );
+    }
+
     #[test]
     fn parse_df_available_mb_uses_last_data_line() {
         let stdout =
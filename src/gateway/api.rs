@@ -3,6 +3,7 @@
 //! All `/api/*` routes require bearer token authentication (PairingGuard).
 
 use super::AppState;
+use crate::memory::Memory;
 use axum::{
     extract::{Path, Query, State},
     http::{header, HeaderMap, StatusCode},
@@ -50,6 +51,14 @@ fn require_auth(
 pub struct MemoryQuery {
     pub query: Option<String>,
     pub category: Option<String>,
+    pub session: Option<String>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+#[derive(Deserialize)]
+pub struct ChannelDoctorQuery {
+    pub channel: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -66,6 +75,40 @@ pub struct CronAddBody {
     pub command: String,
 }
 
+#[derive(Deserialize)]
+pub struct EstopEngageBody {
+    pub level: Option<String>,
+    pub reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct UsageQuery {
+    pub granularity: Option<crate::cost::usage::Granularity>,
+    pub last: Option<u32>,
+}
+
+#[derive(Deserialize)]
+pub struct DebugModeBody {
+    pub enabled: bool,
+}
+
+#[derive(Deserialize)]
+pub struct DoctorFixBody {
+    pub action: crate::doctor::FixAction,
+}
+
+#[derive(Deserialize)]
+pub struct DefaultProviderBody {
+    pub provider: String,
+    /// Model to make the default; the provider's recommended model when unset.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Key for a newly selected provider. The previous provider's key is never
+    /// carried over; without one the provider's env var applies.
+    #[serde(default)]
+    pub api_key: Option<String>,
+}
+
 // ── Handlers ────────────────────────────────────────────────────
 
 /// GET /api/status — system status overview
@@ -79,6 +122,8 @@ pub async fn handle_api_status(
 
     let config = state.config.lock().clone();
     let health = crate::health::snapshot();
+    let metrics = state.metrics.snapshot(&health);
+    let memory_entries = state.mem.count().await.ok();
 
     let mut channels = serde_json::Map::new();
 
@@ -97,6 +142,13 @@ pub async fn handle_api_status(
         "paired": state.pairing.is_paired(),
         "channels": channels,
         "health": health,
+        "metrics": {
+            "channel_messages": metrics.channel_messages,
+            "tokens_today": metrics.tokens_today,
+            "memory_entries": memory_entries,
+            "last_error": metrics.last_error,
+        },
+        "debug": crate::observability::debug_mode::status(&state.recorder),
     });
 
     Json(body).into_response()
@@ -166,6 +218,18 @@ pub async fn handle_api_config_put(
             .into_response();
     }
 
+    // Keep the previous file; `Config::save` drops its own rollback copy.
+    let backup = match crate::onboard::config_editor::back_up(&new_config.config_path) {
+        Ok(backup) => backup,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": format!("{e:#}")})),
+            )
+                .into_response();
+        }
+    };
+
     // Save to disk
     if let Err(e) = new_config.save().await {
         return (
@@ -178,7 +242,46 @@ pub async fn handle_api_config_put(
     // Update in-memory config
     *state.config.lock() = new_config;
 
-    Json(serde_json::json!({"status": "ok"})).into_response()
+    Json(serde_json::json!({
+        "status": "ok",
+        "backup": backup.map(|path| path.display().to_string()),
+    }))
+    .into_response()
+}
+
+/// POST /api/config/validate — check a TOML body without saving it
+///
+/// Runs the same checks as PUT: the text must parse, match the config
+/// schema and pass `Config::validate`. Unknown keys are reported, since load
+/// ignores them.
+pub async fn handle_api_config_validate(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    body: String,
+) -> impl IntoResponse {
+    if let Err(e) = require_auth(&state, &headers) {
+        return e.into_response();
+    }
+
+    let result = crate::onboard::config_editor::parse(&body).and_then(|(incoming, unknown)| {
+        let current_config = state.config.lock().clone();
+        hydrate_config_for_save(incoming, &current_config).validate()?;
+        Ok(unknown)
+    });
+
+    match result {
+        Ok(unknown) => Json(serde_json::json!({
+            "valid": true,
+            "error": null,
+            "unknown_keys": unknown,
+        })),
+        Err(e) => Json(serde_json::json!({
+            "valid": false,
+            "error": format!("{e:#}"),
+            "unknown_keys": [],
+        })),
+    }
+    .into_response()
 }
 
 /// GET /api/tools — list registered tool specs
@@ -325,6 +428,195 @@ pub async fn handle_api_integrations(
     Json(serde_json::json!({"integrations": integrations})).into_response()
 }
 
+/// Canonical name of a known provider, matched by name or alias.
+fn resolve_provider_name(input: &str) -> Option<&'static str> {
+    let input = input.trim();
+    crate::providers::list_providers()
+        .into_iter()
+        .find(|info| {
+            info.name.eq_ignore_ascii_case(input)
+                || info
+                    .aliases
+                    .iter()
+                    .any(|alias| alias.eq_ignore_ascii_case(input))
+        })
+        .map(|info| info.name)
+}
+
+/// GET /api/providers — list known providers with their details
+pub async fn handle_api_providers(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    if let Err(e) = require_auth(&state, &headers) {
+        return e.into_response();
+    }
+
+    let config = state.config.lock().clone();
+    let default = config
+        .default_provider
+        .as_deref()
+        .map(|name| resolve_provider_name(name).unwrap_or(name))
+        .unwrap_or("openrouter");
+
+    let providers: Vec<serde_json::Value> = crate::providers::list_providers()
+        .iter()
+        .map(|info| {
+            let is_default = info.name == default;
+            // A configured `api_url` applies to the default provider only.
+            let base_url = config
+                .api_url
+                .clone()
+                .filter(|_| is_default)
+                .or_else(|| crate::onboard::wizard::provider_base_url(info.name));
+            serde_json::json!({
+                "name": info.name,
+                "display_name": info.display_name,
+                "aliases": info.aliases,
+                "local": info.local,
+                "base_url": base_url,
+                "curated_models": crate::onboard::wizard::curated_model_count(info.name),
+                "is_default": is_default,
+                "active_model": config.default_model.clone().filter(|_| is_default),
+            })
+        })
+        .collect();
+
+    Json(serde_json::json!({"default": default, "providers": providers})).into_response()
+}
+
+/// POST /api/providers/default — make a provider the default
+pub async fn handle_api_providers_default(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(body): Json<DefaultProviderBody>,
+) -> impl IntoResponse {
+    if let Err(e) = require_auth(&state, &headers) {
+        return e.into_response();
+    }
+
+    let Some(provider) = resolve_provider_name(&body.provider) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": format!("Unknown provider '{}'", body.provider)})),
+        )
+            .into_response();
+    };
+
+    let mut config = state.config.lock().clone();
+    apply_default_provider(&mut config, provider, &body);
+
+    if let Err(e) = config.validate() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": format!("Invalid config: {e}")})),
+        )
+            .into_response();
+    }
+
+    // Same rollback copy as PUT /api/config.
+    let backup = match crate::onboard::config_editor::back_up(&config.config_path) {
+        Ok(backup) => backup,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": format!("{e:#}")})),
+            )
+                .into_response();
+        }
+    };
+
+    if let Err(e) = config.save().await {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Failed to save config: {e}")})),
+        )
+            .into_response();
+    }
+
+    let response = serde_json::json!({
+        "provider": config.default_provider,
+        "model": config.default_model,
+        "backup": backup.map(|path| path.display().to_string()),
+    });
+    *state.config.lock() = config;
+
+    Json(response).into_response()
+}
+
+/// Point `config` at `provider`. Switching provider resets the model, endpoint
+/// and key, since those belong to the previous provider; the config key is
+/// tried before env vars, so a kept key would be sent to the new vendor.
+fn apply_default_provider(
+    config: &mut crate::config::Config,
+    provider: &str,
+    body: &DefaultProviderBody,
+) {
+    if config.default_provider.as_deref() != Some(provider) {
+        config.default_provider = Some(provider.to_string());
+        config.default_model = Some(crate::onboard::wizard::default_model_for_provider(provider));
+        config.api_url = None;
+        config.api_key = None;
+    }
+    if let Some(model) = body
+        .model
+        .as_deref()
+        .map(str::trim)
+        .filter(|model| !model.is_empty())
+    {
+        config.default_model = Some(model.to_string());
+    }
+    if let Some(key) = body
+        .api_key
+        .as_deref()
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+    {
+        config.api_key = Some(key.to_string());
+    }
+}
+
+/// GET /api/providers/{name}/models — models offered by the model switcher
+pub async fn handle_api_provider_models(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(name): Path<String>,
+) -> impl IntoResponse {
+    if let Err(e) = require_auth(&state, &headers) {
+        return e.into_response();
+    }
+
+    let Some(provider) = resolve_provider_name(&name) else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": format!("Unknown provider '{name}'")})),
+        )
+            .into_response();
+    };
+
+    let workspace_dir = state.config.lock().workspace_dir.clone();
+    match crate::onboard::wizard::model_choices_for_provider(&workspace_dir, provider).await {
+        Ok((options, cache_age)) => {
+            let models: Vec<serde_json::Value> = options
+                .into_iter()
+                .map(|(id, label)| serde_json::json!({"id": id, "label": label}))
+                .collect();
+            Json(serde_json::json!({
+                "provider": provider,
+                "source": if cache_age.is_some() { "cached" } else { "curated" },
+                "cache_age_secs": cache_age,
+                "models": models,
+            }))
+            .into_response()
+        }
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Failed to load models: {e}")})),
+        )
+            .into_response(),
+    }
+}
+
 /// POST /api/doctor — run diagnostics
 pub async fn handle_api_doctor(
     State(state): State<AppState>,
@@ -361,6 +653,64 @@ pub async fn handle_api_doctor(
     .into_response()
 }
 
+/// POST /api/doctor/fix — apply an automatable doctor fix
+pub async fn handle_api_doctor_fix(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(body): Json<DoctorFixBody>,
+) -> impl IntoResponse {
+    if let Err(e) = require_auth(&state, &headers) {
+        return e.into_response();
+    }
+
+    let mut config = state.config.lock().clone();
+    match crate::doctor::apply_fix(&mut config, body.action).await {
+        Ok(message) => {
+            let results = crate::doctor::diagnose(&config);
+            *state.config.lock() = config;
+            Json(serde_json::json!({"message": message, "results": results})).into_response()
+        }
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Fix failed: {e}")})),
+        )
+            .into_response(),
+    }
+}
+
+/// GET /api/channels/doctor — health-check configured channels (`?channel=` for one)
+pub async fn handle_api_channels_doctor(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(params): Query<ChannelDoctorQuery>,
+) -> impl IntoResponse {
+    if let Err(e) = require_auth(&state, &headers) {
+        return e.into_response();
+    }
+
+    let config = state.config.lock().clone();
+    match crate::channels::check_channels(&config, params.channel.as_deref()).await {
+        Ok(channels) => {
+            let channels: Vec<serde_json::Value> = channels
+                .iter()
+                .map(|health| {
+                    let mut entry = serde_json::json!(health);
+                    if health.state != crate::channels::ChannelHealthState::Healthy {
+                        entry["suggestion"] = crate::channels::fix_suggestion(health).into();
+                    }
+                    entry
+                })
+                .collect();
+            Json(serde_json::json!({ "channels": channels })).into_response()
+        }
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Channel health check failed: {e}")})),
+        )
+            .into_response(),
+    }
+}
+
 /// GET /api/memory — list or search memory entries
 pub async fn handle_api_memory_list(
     State(state): State<AppState>,
@@ -371,36 +721,59 @@ pub async fn handle_api_memory_list(
         return e.into_response();
     }
 
-    if let Some(ref query) = params.query {
-        // Search mode
-        match state.mem.recall(query, 50, None).await {
-            Ok(entries) => Json(serde_json::json!({"entries": entries})).into_response(),
-            Err(e) => (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(serde_json::json!({"error": format!("Memory recall failed: {e}")})),
-            )
-                .into_response(),
+    let category = params.category.as_deref().map(parse_memory_category);
+    match memory_page(state.mem.as_ref(), &params, category.as_ref()).await {
+        Ok((entries, total)) => {
+            Json(serde_json::json!({"entries": entries, "total": total})).into_response()
         }
-    } else {
-        // List mode
-        let category = params.category.as_deref().map(|cat| match cat {
-            "core" => crate::memory::MemoryCategory::Core,
-            "daily" => crate::memory::MemoryCategory::Daily,
-            "conversation" => crate::memory::MemoryCategory::Conversation,
-            other => crate::memory::MemoryCategory::Custom(other.to_string()),
-        });
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Memory query failed: {e}")})),
+        )
+            .into_response(),
+    }
+}
 
-        match state.mem.list(category.as_ref(), None).await {
-            Ok(entries) => Json(serde_json::json!({"entries": entries})).into_response(),
-            Err(e) => (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(serde_json::json!({"error": format!("Memory list failed: {e}")})),
-            )
-                .into_response(),
-        }
+/// Most matches a full-text search returns before paging.
+const MEMORY_SEARCH_LIMIT: usize = 500;
+
+fn parse_memory_category(cat: &str) -> crate::memory::MemoryCategory {
+    match cat {
+        "core" => crate::memory::MemoryCategory::Core,
+        "daily" => crate::memory::MemoryCategory::Daily,
+        "conversation" => crate::memory::MemoryCategory::Conversation,
+        other => crate::memory::MemoryCategory::Custom(other.to_string()),
     }
 }
 
+/// One page of entries plus the total count. A `query` runs a full-text
+/// recall; category and session narrow either mode. Without `limit` every
+/// matching entry is returned.
+async fn memory_page(
+    mem: &dyn Memory,
+    params: &MemoryQuery,
+    category: Option<&crate::memory::MemoryCategory>,
+) -> anyhow::Result<(Vec<crate::memory::MemoryEntry>, usize)> {
+    let session = params.session.as_deref().filter(|s| !s.is_empty());
+    let entries = match params.query.as_deref().filter(|q| !q.trim().is_empty()) {
+        Some(query) => mem
+            .recall(query, MEMORY_SEARCH_LIMIT, session)
+            .await?
+            .into_iter()
+            .filter(|entry| category.is_none_or(|category| &entry.category == category))
+            .collect(),
+        None => mem.list(category, session).await?,
+    };
+    let total = entries.len();
+    let offset = params.offset.unwrap_or(0);
+    let page = entries
+        .into_iter()
+        .skip(offset)
+        .take(params.limit.unwrap_or(usize::MAX))
+        .collect();
+    Ok((page, total))
+}
+
 /// POST /api/memory — store a memory entry
 pub async fn handle_api_memory_store(
     State(state): State<AppState>,
@@ -414,12 +787,7 @@ pub async fn handle_api_memory_store(
     let category = body
         .category
         .as_deref()
-        .map(|cat| match cat {
-            "core" => crate::memory::MemoryCategory::Core,
-            "daily" => crate::memory::MemoryCategory::Daily,
-            "conversation" => crate::memory::MemoryCategory::Conversation,
-            other => crate::memory::MemoryCategory::Custom(other.to_string()),
-        })
+        .map(parse_memory_category)
         .unwrap_or(crate::memory::MemoryCategory::Core);
 
     match state
@@ -436,6 +804,193 @@ pub async fn handle_api_memory_store(
     }
 }
 
+/// POST /api/checkin — owner check-in for the dead-man's switch
+pub async fn handle_api_checkin(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    if let Err(e) = require_auth(&state, &headers) {
+        return e.into_response();
+    }
+
+    let workspace_dir = state.config.lock().workspace_dir.clone();
+    match crate::heartbeat::deadman::record_checkin(&workspace_dir) {
+        Ok(at) => Json(serde_json::json!({
+            "status": "ok",
+            "checked_in_at": at.to_rfc3339(),
+        }))
+        .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Check-in failed: {e}")})),
+        )
+            .into_response(),
+    }
+}
+
+/// GET /api/debug — runtime debug mode state
+pub async fn handle_api_debug_get(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    if let Err(e) = require_auth(&state, &headers) {
+        return e.into_response();
+    }
+
+    Json(crate::observability::debug_mode::status(&state.recorder)).into_response()
+}
+
+/// POST /api/debug — switch debug logging and LLM recording on or off
+pub async fn handle_api_debug_set(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(body): Json<DebugModeBody>,
+) -> impl IntoResponse {
+    // Recording writes full prompts to disk, so only a paired owner may toggle it.
+    if !state.pairing.require_pairing() {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(serde_json::json!({
+                "error": "Debug mode requires gateway pairing (gateway.require_pairing = true)"
+            })),
+        )
+            .into_response();
+    }
+    if let Err(e) = require_auth(&state, &headers) {
+        return e.into_response();
+    }
+
+    let workspace_dir = state.config.lock().workspace_dir.clone();
+    match crate::observability::debug_mode::set_enabled(
+        body.enabled,
+        &workspace_dir,
+        &state.recorder,
+    ) {
+        Ok(status) => Json(status).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Failed to switch debug mode: {e}")})),
+        )
+            .into_response(),
+    }
+}
+
+/// POST /api/estop — engage emergency stop from an external monitor
+pub async fn handle_api_estop_engage(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(body): Json<EstopEngageBody>,
+) -> impl IntoResponse {
+    // Unlike other routes, remote estop never runs unauthenticated.
+    if !state.pairing.require_pairing() {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(serde_json::json!({
+                "error": "Remote estop requires gateway pairing (gateway.require_pairing = true)"
+            })),
+        )
+            .into_response();
+    }
+    if let Err(e) = require_auth(&state, &headers) {
+        return e.into_response();
+    }
+
+    let config = state.config.lock().clone();
+    if !config.security.estop.enabled || !config.security.estop.remote_trigger {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "Remote estop is disabled. Set [security.estop] enabled = true and remote_trigger = true"
+            })),
+        )
+            .into_response();
+    }
+
+    let level = match crate::security::EstopLevel::parse_remote(
+        body.level.as_deref().unwrap_or("kill-all"),
+    ) {
+        Ok(level) => level,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": e.to_string()})),
+            )
+                .into_response();
+        }
+    };
+
+    let engaged = config
+        .config_path
+        .parent()
+        .ok_or_else(|| anyhow::anyhow!("Config path must have a parent directory"))
+        .and_then(|config_dir| {
+            let mut manager =
+                crate::security::EstopManager::load(&config.security.estop, config_dir)?
+                    .with_toasts(state.toasts.clone());
+            manager.engage(level.clone())?;
+            Ok(manager.status())
+        });
+
+    match engaged {
+        Ok(estop_state) => {
+            tracing::warn!("Estop engaged remotely: {}", level.describe());
+            let _ = state.event_tx.send(serde_json::json!({
+                "type": "estop",
+                "level": level.describe(),
+                "source": "remote",
+            }));
+            let notice = crate::security::estop::engagement_notice(
+                &level,
+                "remote gateway",
+                body.reason.as_deref(),
+            );
+            tokio::spawn(async move {
+                crate::security::estop::notify_owners(&config, &notice).await;
+            });
+            Json(serde_json::json!({"status": "engaged", "state": estop_state})).into_response()
+        }
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Estop engage failed: {e}")})),
+        )
+            .into_response(),
+    }
+}
+
+/// POST /api/peripherals/rpc — serve this node's boards to another instance
+pub async fn handle_api_peripherals_rpc(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(body): Json<serde_json::Value>,
+) -> impl IntoResponse {
+    // Hardware control is never exposed without pairing.
+    if !state.pairing.require_pairing() {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(serde_json::json!({
+                "error": "Peripheral node requires gateway pairing (gateway.require_pairing = true)"
+            })),
+        )
+            .into_response();
+    }
+    if let Err(e) = require_auth(&state, &headers) {
+        return e.into_response();
+    }
+
+    let config = state.config.lock().clone();
+    if !config.peripherals.enabled || !config.peripherals.node.serve_http {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "Peripheral node is disabled. Set [peripherals] enabled = true and [peripherals.node] serve_http = true"
+            })),
+        )
+            .into_response();
+    }
+
+    Json(crate::peripherals::remote::serve_request(&config, &body).await).into_response()
+}
+
 /// DELETE /api/memory/:key — delete a memory entry
 pub async fn handle_api_memory_delete(
     State(state): State<AppState>,
@@ -458,6 +1013,46 @@ pub async fn handle_api_memory_delete(
     }
 }
 
+/// POST /api/memory/{key}/pin — move an entry to `core`, which retention never prunes
+pub async fn handle_api_memory_pin(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(key): Path<String>,
+) -> impl IntoResponse {
+    if let Err(e) = require_auth(&state, &headers) {
+        return e.into_response();
+    }
+
+    match pin_memory(state.mem.as_ref(), &key).await {
+        Ok(true) => Json(serde_json::json!({"status": "ok"})).into_response(),
+        Ok(false) => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": format!("No memory entry with key '{key}'")})),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Memory pin failed: {e}")})),
+        )
+            .into_response(),
+    }
+}
+
+/// Re-store an entry under `core`, keeping its content and session.
+async fn pin_memory(mem: &dyn Memory, key: &str) -> anyhow::Result<bool> {
+    let Some(entry) = mem.get(key).await? else {
+        return Ok(false);
+    };
+    mem.store(
+        &entry.key,
+        &entry.content,
+        crate::memory::MemoryCategory::Core,
+        entry.session_id.as_deref(),
+    )
+    .await?;
+    Ok(true)
+}
+
 /// GET /api/cost — cost summary
 pub async fn handle_api_cost(
     State(state): State<AppState>,
@@ -491,6 +1086,40 @@ pub async fn handle_api_cost(
     }
 }
 
+/// GET /api/usage — token usage and cost by day or week, with budget status
+pub async fn handle_api_usage(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(params): Query<UsageQuery>,
+) -> impl IntoResponse {
+    if let Err(e) = require_auth(&state, &headers) {
+        return e.into_response();
+    }
+
+    let config = state.config.lock().clone();
+    let granularity = params
+        .granularity
+        .unwrap_or(crate::cost::usage::Granularity::Daily);
+    let last = params.last.unwrap_or(7).clamp(1, 366);
+    match tokio::task::spawn_blocking(move || {
+        crate::cost::usage::report(&config, granularity, last)
+    })
+    .await
+    {
+        Ok(Ok(report)) => Json(serde_json::json!({"usage": report})).into_response(),
+        Ok(Err(e)) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Usage report failed: {e}")})),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Usage report task failed: {e}")})),
+        )
+            .into_response(),
+    }
+}
+
 /// GET /api/cli-tools — discovered CLI tools
 pub async fn handle_api_cli_tools(
     State(state): State<AppState>,
@@ -989,6 +1618,58 @@ fn hydrate_config_for_save(
 mod tests {
     use super::*;
 
+    #[test]
+    fn provider_names_resolve_by_name_or_alias() {
+        assert_eq!(resolve_provider_name("openrouter"), Some("openrouter"));
+        assert_eq!(resolve_provider_name(" Anthropic "), Some("anthropic"));
+        let aliased = crate::providers::list_providers()
+            .into_iter()
+            .find(|info| !info.aliases.is_empty())
+            .expect("some provider has an alias");
+        assert_eq!(
+            resolve_provider_name(aliased.aliases[0]),
+            Some(aliased.name)
+        );
+        assert_eq!(resolve_provider_name("not-a-provider"), None);
+    }
+
+    #[test]
+    fn switching_default_provider_drops_the_previous_key() {
+        let mut config = crate::config::Config {
+            default_provider: Some("openrouter".into()),
+            api_key: Some("sk-or-old".into()),
+            api_url: Some("https://proxy.example.com".into()),
+            ..crate::config::Config::default()
+        };
+        let body = DefaultProviderBody {
+            provider: "anthropic".into(),
+            model: None,
+            api_key: None,
+        };
+        apply_default_provider(&mut config, "anthropic", &body);
+        assert_eq!(config.default_provider.as_deref(), Some("anthropic"));
+        assert_eq!(config.api_key, None);
+        assert_eq!(config.api_url, None);
+
+        let body = DefaultProviderBody {
+            provider: "openai".into(),
+            model: Some("gpt-5".into()),
+            api_key: Some(" sk-new ".into()),
+        };
+        apply_default_provider(&mut config, "openai", &body);
+        assert_eq!(config.api_key.as_deref(), Some("sk-new"));
+        assert_eq!(config.default_model.as_deref(), Some("gpt-5"));
+
+        // Re-selecting the same provider keeps its key.
+        let body = DefaultProviderBody {
+            provider: "openai".into(),
+            model: None,
+            api_key: None,
+        };
+        apply_default_provider(&mut config, "openai", &body);
+        assert_eq!(config.api_key.as_deref(), Some("sk-new"));
+    }
+
     #[test]
     fn masking_keeps_toml_valid_and_preserves_api_keys_type() {
         let mut cfg = crate::config::Config::default();
@@ -1014,7 +1695,7 @@ mod tests {
             receive_mode: crate::config::schema::LarkReceiveMode::Websocket,
             port: None,
         });
-        cfg.channels_config.email = Some(crate::channels::email_channel::EmailConfig {
+        cfg.channels_config.email = Some(crate::channels::email_config::EmailConfig {
             imap_host: "imap.example.com".to_string(),
             imap_port: 993,
             imap_folder: "INBOX".to_string(),
@@ -1147,7 +1828,7 @@ mod tests {
             receive_mode: crate::config::schema::LarkReceiveMode::Websocket,
             port: None,
         });
-        current.channels_config.email = Some(crate::channels::email_channel::EmailConfig {
+        current.channels_config.email = Some(crate::channels::email_config::EmailConfig {
             imap_host: "imap.example.com".to_string(),
             imap_port: 993,
             imap_folder: "INBOX".to_string(),
@@ -1393,4 +2074,53 @@ mod tests {
             .iter()
             .all(|route| route.api_key.as_deref() != Some(MASKED_SECRET)));
     }
+
+    #[tokio::test]
+    async fn memory_pages_filter_search_and_pin() {
+        use crate::memory::MemoryCategory;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let mem = crate::memory::SqliteMemory::new(tmp.path()).unwrap();
+        for i in 0..5 {
+            mem.store(
+                &format!("note_{i}"),
+                &format!("coffee note {i}"),
+                MemoryCategory::Daily,
+                Some("s1"),
+            )
+            .await
+            .unwrap();
+        }
+        mem.store("tea", "dislikes tea", MemoryCategory::Conversation, None)
+            .await
+            .unwrap();
+
+        let params = |query: Option<&str>, session: Option<&str>| MemoryQuery {
+            query: query.map(str::to_string),
+            category: None,
+            session: session.map(str::to_string),
+            limit: Some(2),
+            offset: Some(4),
+        };
+        let (page, total) = memory_page(&mem, &params(None, Some("s1")), None)
+            .await
+            .unwrap();
+        assert_eq!(total, 5);
+        assert_eq!(page.len(), 1);
+
+        let (page, total) = memory_page(
+            &mem,
+            &params(Some("coffee"), None),
+            Some(&MemoryCategory::Conversation),
+        )
+        .await
+        .unwrap();
+        assert_eq!((page.len(), total), (0, 0));
+
+        assert!(pin_memory(&mem, "tea").await.unwrap());
+        assert!(!pin_memory(&mem, "missing").await.unwrap());
+        let pinned = mem.get("tea").await.unwrap().unwrap();
+        assert_eq!(pinned.category, MemoryCategory::Core);
+        assert_eq!(pinned.content, "dislikes tea");
+    }
 }
@@ -8,6 +8,7 @@
 //! - Header sanitization (handled by axum/hyper)
 
 pub mod api;
+pub mod selftest;
 pub mod sse;
 pub mod static_files;
 pub mod ws;
@@ -17,7 +18,8 @@ use crate::channels::{
 };
 use crate::config::Config;
 use crate::cost::CostTracker;
-use crate::memory::{self, Memory, MemoryCategory};
+use crate::memory::redaction::WEBHOOK_CHANNEL;
+use crate::memory::{self, Memory};
 use crate::providers::{self, ChatMessage, Provider};
 use crate::runtime;
 use crate::security::pairing::{constant_time_eq, is_public_bind, PairingGuard};
@@ -310,11 +312,27 @@ pub struct AppState {
     pub cost_tracker: Option<Arc<CostTracker>>,
     /// SSE broadcast channel for real-time events
     pub event_tx: tokio::sync::broadcast::Sender<serde_json::Value>,
+    /// Shared with the daemon so shutdown can wait for webhook replies
+    pub drain: crate::daemon::shutdown::Drain,
+    /// Dashboard counters, shared with the channel runtime under the daemon
+    pub metrics: crate::health::metrics::RuntimeMetrics,
+    /// Toast bus forwarded to `/api/events`, shared with the daemon's workers
+    pub toasts: crate::notifications::Toasts,
+    /// LLM recorder switched by debug mode, shared with the channel runtime
+    pub recorder: crate::providers::recorder::Recorder,
 }
 
 /// Run the HTTP gateway using axum with proper HTTP/1.1 compliance.
 #[allow(clippy::too_many_lines)]
-pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
+pub async fn run_gateway(
+    host: &str,
+    port: u16,
+    config: Config,
+    drain: crate::daemon::shutdown::Drain,
+    metrics: crate::health::metrics::RuntimeMetrics,
+    toasts: crate::notifications::Toasts,
+    recorder: crate::providers::recorder::Recorder,
+) -> Result<()> {
     // ── Security: refuse public bind without tunnel or explicit opt-in ──
     if is_public_bind(host) && config.tunnel.provider == "none" && !config.gateway.allow_public_bind
     {
@@ -349,6 +367,8 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
             zeroclaw_dir: config.config_path.parent().map(std::path::PathBuf::from),
             secrets_encrypt: config.secrets.encrypt,
             reasoning_enabled: config.runtime.reasoning_enabled,
+            connectivity: Some(crate::connectivity::Connectivity::new(&config)),
+            recorder: Some(recorder.clone()),
         },
     )?);
     let model = config
@@ -356,12 +376,15 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
         .clone()
         .unwrap_or_else(|| "anthropic/claude-sonnet-4".into());
     let temperature = config.default_temperature;
-    let mem: Arc<dyn Memory> = Arc::from(memory::create_memory_with_storage(
-        &config.memory,
-        Some(&config.storage.provider.config),
-        &config.workspace_dir,
-        config.api_key.as_deref(),
-    )?);
+    let mem: Arc<dyn Memory> = Arc::from(memory::with_auto_save_policy(
+        memory::create_memory_with_storage(
+            &config.memory,
+            Some(&config.storage.provider.config),
+            &config.workspace_dir,
+            config.api_key.as_deref(),
+        )?,
+        &config,
+    ));
     let runtime: Arc<dyn runtime::RuntimeAdapter> =
         Arc::from(runtime::create_runtime(&config.runtime)?);
     let security = Arc::new(SecurityPolicy::from_config(
@@ -411,6 +434,7 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
 
     // SSE broadcast channel for real-time events
     let (event_tx, _event_rx) = tokio::sync::broadcast::channel::<serde_json::Value>(256);
+    sse::spawn_toast_forwarder(&toasts, event_tx.clone());
     // Extract webhook secret for authentication
     let webhook_secret_hash: Option<Arc<str>> =
         config.channels_config.webhook.as_ref().and_then(|webhook| {
@@ -618,7 +642,7 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
     // Wrap observer with broadcast capability for SSE
     let broadcast_observer: Arc<dyn crate::observability::Observer> =
         Arc::new(sse::BroadcastObserver::new(
-            crate::observability::create_observer(&config.observability),
+            metrics.observe(crate::observability::create_observer(&config.observability)),
             event_tx.clone(),
         ));
 
@@ -645,11 +669,19 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
         tools_registry,
         cost_tracker,
         event_tx,
+        drain,
+        metrics,
+        toasts,
+        recorder,
     };
 
-    // Config PUT needs larger body limit (1MB)
+    // Config PUT and validate take the whole file, so a larger body limit (1MB)
     let config_put_router = Router::new()
         .route("/api/config", put(api::handle_api_config_put))
+        .route(
+            "/api/config/validate",
+            post(api::handle_api_config_validate),
+        )
         .layer(RequestBodyLimitLayer::new(1_048_576));
 
     // Build router with middleware
@@ -673,16 +705,39 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
         .route("/api/cron", post(api::handle_api_cron_add))
         .route("/api/cron/{id}", delete(api::handle_api_cron_delete))
         .route("/api/integrations", get(api::handle_api_integrations))
+        .route("/api/providers", get(api::handle_api_providers))
+        .route(
+            "/api/providers/{name}/models",
+            get(api::handle_api_provider_models),
+        )
+        .route(
+            "/api/providers/default",
+            post(api::handle_api_providers_default),
+        )
         .route(
             "/api/doctor",
             get(api::handle_api_doctor).post(api::handle_api_doctor),
         )
+        .route("/api/doctor/fix", post(api::handle_api_doctor_fix))
+        .route("/api/channels/doctor", get(api::handle_api_channels_doctor))
         .route("/api/memory", get(api::handle_api_memory_list))
         .route("/api/memory", post(api::handle_api_memory_store))
         .route("/api/memory/{key}", delete(api::handle_api_memory_delete))
+        .route("/api/memory/{key}/pin", post(api::handle_api_memory_pin))
+        .route("/api/checkin", post(api::handle_api_checkin))
+        .route("/api/estop", post(api::handle_api_estop_engage))
+        .route(
+            crate::peripherals::remote::RPC_PATH,
+            post(api::handle_api_peripherals_rpc),
+        )
         .route("/api/cost", get(api::handle_api_cost))
+        .route("/api/usage", get(api::handle_api_usage))
         .route("/api/cli-tools", get(api::handle_api_cli_tools))
         .route("/api/health", get(api::handle_api_health))
+        .route(
+            "/api/debug",
+            get(api::handle_api_debug_get).post(api::handle_api_debug_set),
+        )
         // ── SSE event stream ──
         .route("/api/events", get(sse::handle_sse_events))
         // ── WebSocket agent chat ──
@@ -862,7 +917,12 @@ async fn run_gateway_chat_simple(state: &AppState, message: &str) -> anyhow::Res
 /// Full-featured chat with tools for channel handlers (WhatsApp, Linq, Nextcloud Talk).
 async fn run_gateway_chat_with_tools(state: &AppState, message: &str) -> anyhow::Result<String> {
     let config = state.config.lock().clone();
-    crate::agent::process_message(config, message).await
+    Box::pin(crate::agent::process_message(
+        config,
+        message,
+        &state.metrics,
+    ))
+    .await
 }
 
 /// Webhook request body
@@ -878,6 +938,14 @@ async fn handle_webhook(
     headers: HeaderMap,
     body: Result<Json<WebhookBody>, axum::extract::rejection::JsonRejection>,
 ) -> impl IntoResponse {
+    if state.drain.is_draining() {
+        let err = serde_json::json!({
+            "error": "Shutting down. Please retry after the restart.",
+        });
+        return (StatusCode::SERVICE_UNAVAILABLE, Json(err));
+    }
+    let _in_flight = state.drain.track();
+
     let rate_key =
         client_key_from_request(Some(peer_addr), &headers, state.trust_forwarded_headers);
     if !state.rate_limiter.allow_webhook(&rate_key) {
@@ -956,11 +1024,13 @@ async fn handle_webhook(
     let message = &webhook_body.message;
 
     if state.auto_save {
-        let key = webhook_memory_key();
-        let _ = state
-            .mem
-            .store(&key, message, MemoryCategory::Conversation, None)
-            .await;
+        if let Some(content) = state.mem.redact_for_storage(WEBHOOK_CHANNEL, message) {
+            let key = webhook_memory_key();
+            let _ = state
+                .mem
+                .store(&key, &content, state.mem.auto_save_category(&content), None)
+                .await;
+        }
     }
 
     let provider_label = state
@@ -1183,11 +1253,13 @@ async fn handle_whatsapp_message(
 
         // Auto-save to memory
         if state.auto_save {
-            let key = whatsapp_memory_key(msg);
-            let _ = state
-                .mem
-                .store(&key, &msg.content, MemoryCategory::Conversation, None)
-                .await;
+            if let Some(content) = state.mem.redact_for_storage(&msg.channel, &msg.content) {
+                let key = whatsapp_memory_key(msg);
+                let _ = state
+                    .mem
+                    .store(&key, &content, state.mem.auto_save_category(&content), None)
+                    .await;
+            }
         }
 
         match run_gateway_chat_with_tools(&state, &msg.content).await {
@@ -1290,11 +1362,13 @@ async fn handle_linq_webhook(
 
         // Auto-save to memory
         if state.auto_save {
-            let key = linq_memory_key(msg);
-            let _ = state
-                .mem
-                .store(&key, &msg.content, MemoryCategory::Conversation, None)
-                .await;
+            if let Some(content) = state.mem.redact_for_storage(&msg.channel, &msg.content) {
+                let key = linq_memory_key(msg);
+                let _ = state
+                    .mem
+                    .store(&key, &content, state.mem.auto_save_category(&content), None)
+                    .await;
+            }
         }
 
         // Call the LLM
@@ -1382,11 +1456,13 @@ async fn handle_wati_webhook(State(state): State<AppState>, body: Bytes) -> impl
 
         // Auto-save to memory
         if state.auto_save {
-            let key = wati_memory_key(msg);
-            let _ = state
-                .mem
-                .store(&key, &msg.content, MemoryCategory::Conversation, None)
-                .await;
+            if let Some(content) = state.mem.redact_for_storage(&msg.channel, &msg.content) {
+                let key = wati_memory_key(msg);
+                let _ = state
+                    .mem
+                    .store(&key, &content, state.mem.auto_save_category(&content), None)
+                    .await;
+            }
         }
 
         // Call the LLM
@@ -1487,11 +1563,13 @@ async fn handle_nextcloud_talk_webhook(
         );
 
         if state.auto_save {
-            let key = nextcloud_talk_memory_key(msg);
-            let _ = state
-                .mem
-                .store(&key, &msg.content, MemoryCategory::Conversation, None)
-                .await;
+            if let Some(content) = state.mem.redact_for_storage(&msg.channel, &msg.content) {
+                let key = nextcloud_talk_memory_key(msg);
+                let _ = state
+                    .mem
+                    .store(&key, &content, state.mem.auto_save_category(&content), None)
+                    .await;
+            }
         }
 
         match run_gateway_chat_with_tools(&state, &msg.content).await {
@@ -1600,6 +1678,10 @@ mod tests {
             tools_registry: Arc::new(Vec::new()),
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            drain: crate::daemon::shutdown::Drain::default(),
+            metrics: crate::health::metrics::RuntimeMetrics::default(),
+            toasts: crate::notifications::Toasts::default(),
+            recorder: crate::providers::recorder::Recorder::default(),
         };
 
         let response = handle_metrics(State(state)).await.into_response();
@@ -1649,6 +1731,10 @@ mod tests {
             tools_registry: Arc::new(Vec::new()),
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            drain: crate::daemon::shutdown::Drain::default(),
+            metrics: crate::health::metrics::RuntimeMetrics::default(),
+            toasts: crate::notifications::Toasts::default(),
+            recorder: crate::providers::recorder::Recorder::default(),
         };
 
         let response = handle_metrics(State(state)).await.into_response();
@@ -2015,6 +2101,10 @@ mod tests {
             tools_registry: Arc::new(Vec::new()),
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            drain: crate::daemon::shutdown::Drain::default(),
+            metrics: crate::health::metrics::RuntimeMetrics::default(),
+            toasts: crate::notifications::Toasts::default(),
+            recorder: crate::providers::recorder::Recorder::default(),
         };
 
         let mut headers = HeaderMap::new();
@@ -2079,6 +2169,10 @@ mod tests {
             tools_registry: Arc::new(Vec::new()),
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            drain: crate::daemon::shutdown::Drain::default(),
+            metrics: crate::health::metrics::RuntimeMetrics::default(),
+            toasts: crate::notifications::Toasts::default(),
+            recorder: crate::providers::recorder::Recorder::default(),
         };
 
         let headers = HeaderMap::new();
@@ -2155,6 +2249,10 @@ mod tests {
             tools_registry: Arc::new(Vec::new()),
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            drain: crate::daemon::shutdown::Drain::default(),
+            metrics: crate::health::metrics::RuntimeMetrics::default(),
+            toasts: crate::notifications::Toasts::default(),
+            recorder: crate::providers::recorder::Recorder::default(),
         };
 
         let response = handle_webhook(
@@ -2203,6 +2301,10 @@ mod tests {
             tools_registry: Arc::new(Vec::new()),
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            drain: crate::daemon::shutdown::Drain::default(),
+            metrics: crate::health::metrics::RuntimeMetrics::default(),
+            toasts: crate::notifications::Toasts::default(),
+            recorder: crate::providers::recorder::Recorder::default(),
         };
 
         let mut headers = HeaderMap::new();
@@ -2256,6 +2358,10 @@ mod tests {
             tools_registry: Arc::new(Vec::new()),
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            drain: crate::daemon::shutdown::Drain::default(),
+            metrics: crate::health::metrics::RuntimeMetrics::default(),
+            toasts: crate::notifications::Toasts::default(),
+            recorder: crate::providers::recorder::Recorder::default(),
         };
 
         let mut headers = HeaderMap::new();
@@ -2314,6 +2420,10 @@ mod tests {
             tools_registry: Arc::new(Vec::new()),
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            drain: crate::daemon::shutdown::Drain::default(),
+            metrics: crate::health::metrics::RuntimeMetrics::default(),
+            toasts: crate::notifications::Toasts::default(),
+            recorder: crate::providers::recorder::Recorder::default(),
         };
 
         let response = handle_nextcloud_talk_webhook(
@@ -2368,6 +2478,10 @@ mod tests {
             tools_registry: Arc::new(Vec::new()),
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            drain: crate::daemon::shutdown::Drain::default(),
+            metrics: crate::health::metrics::RuntimeMetrics::default(),
+            toasts: crate::notifications::Toasts::default(),
+            recorder: crate::providers::recorder::Recorder::default(),
         };
 
         let mut headers = HeaderMap::new();
@@ -0,0 +1,310 @@
+//! Startup self-test for `zeroclaw gateway` and `zeroclaw daemon`.
+//!
+//! Runs once before the gateway binds: config validation, memory backend
+//! health, provider auth (via the live model listing), and a health check of
+//! every configured channel. The summary is logged and posted to the owner
+//! channel; `[gateway.selftest] on_failure` decides whether a failure aborts
+//! the start or is reported as degraded.
+
+use crate::config::{Config, SelftestFailurePolicy};
+use crate::doctor::{classify_model_probe_error, ModelProbeOutcome};
+use anyhow::Result;
+use std::time::Duration;
+
+/// Per-check limit, matching `channel doctor`.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_DETAIL_CHARS: usize = 160;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckOutcome {
+    Passed(String),
+    Skipped(String),
+    Failed(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelftestCheck {
+    pub name: String,
+    pub outcome: CheckOutcome,
+}
+
+impl SelftestCheck {
+    fn new(name: impl Into<String>, outcome: CheckOutcome) -> Self {
+        Self {
+            name: name.into(),
+            outcome,
+        }
+    }
+
+    fn failed(&self) -> bool {
+        matches!(self.outcome, CheckOutcome::Failed(_))
+    }
+
+    fn line(&self) -> String {
+        match &self.outcome {
+            CheckOutcome::Passed(detail) => format!("✅ {}: {detail}", self.name),
+            CheckOutcome::Skipped(detail) => format!("⚪ {}: skipped ({detail})", self.name),
+            CheckOutcome::Failed(detail) => format!("❌ {}: {detail}", self.name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SelftestReport {
+    pub checks: Vec<SelftestCheck>,
+}
+
+impl SelftestReport {
+    pub fn failures(&self) -> Vec<&SelftestCheck> {
+        self.checks.iter().filter(|check| check.failed()).collect()
+    }
+
+    /// Owner-channel summary: a headline, then one line per check.
+    pub fn summary(&self, policy: SelftestFailurePolicy) -> String {
+        let failed = self.failures().len();
+        let passed = self
+            .checks
+            .iter()
+            .filter(|check| matches!(check.outcome, CheckOutcome::Passed(_)))
+            .count();
+        let headline = match (failed, policy) {
+            (0, _) => format!(
+                "✅ ZeroClaw started — self-test passed ({passed}/{} checks)",
+                self.checks.len()
+            ),
+            (_, SelftestFailurePolicy::Degrade) => {
+                format!("⚠️ ZeroClaw started degraded — {failed} self-test check(s) failed")
+            }
+            (_, SelftestFailurePolicy::Abort) => {
+                format!("🛑 ZeroClaw did not start — {failed} self-test check(s) failed")
+            }
+        };
+        let mut summary = headline;
+        for check in &self.checks {
+            summary.push('\n');
+            summary.push_str(&check.line());
+        }
+        summary
+    }
+}
+
+fn truncate(text: &str) -> String {
+    if text.chars().count() <= MAX_DETAIL_CHARS {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(MAX_DETAIL_CHARS).collect();
+    format!("{cut}…")
+}
+
+fn check_config(config: &Config) -> SelftestCheck {
+    let outcome = match config.validate() {
+        Ok(()) => CheckOutcome::Passed("valid".into()),
+        Err(error) => CheckOutcome::Failed(truncate(&format!("{error:#}"))),
+    };
+    SelftestCheck::new("Config", outcome)
+}
+
+async fn check_memory(config: &Config) -> SelftestCheck {
+    let name = format!("Memory ({})", config.memory.backend);
+    let memory = match crate::memory::create_memory_with_storage(
+        &config.memory,
+        Some(&config.storage.provider.config),
+        &config.workspace_dir,
+        config.api_key.as_deref(),
+    ) {
+        Ok(memory) => memory,
+        Err(error) => {
+            return SelftestCheck::new(name, CheckOutcome::Failed(truncate(&format!("{error:#}"))))
+        }
+    };
+    let outcome = match tokio::time::timeout(CHECK_TIMEOUT, memory.health_check()).await {
+        Ok(true) => CheckOutcome::Passed("reachable".into()),
+        Ok(false) => CheckOutcome::Failed("health check failed".into()),
+        Err(_) => CheckOutcome::Failed(format!("no answer within {}s", CHECK_TIMEOUT.as_secs())),
+    };
+    SelftestCheck::new(name, outcome)
+}
+
+async fn check_provider(config: &Config) -> SelftestCheck {
+    let provider = config
+        .default_provider
+        .clone()
+        .unwrap_or_else(|| "openrouter".into());
+    let name = format!("Provider ({provider})");
+    if !crate::onboard::wizard::supports_live_model_fetch(&provider) {
+        return SelftestCheck::new(
+            name,
+            CheckOutcome::Skipped("no model listing to verify auth with".into()),
+        );
+    }
+
+    let key = config.api_key.clone().unwrap_or_default();
+    let url = config.api_url.clone();
+    let probe_provider = provider.clone();
+    let probe = tokio::task::spawn_blocking(move || {
+        crate::onboard::wizard::fetch_live_models_for_provider(
+            &probe_provider,
+            &key,
+            url.as_deref(),
+        )
+    });
+    let outcome = match tokio::time::timeout(CHECK_TIMEOUT, probe).await {
+        Ok(Ok(Ok(_))) => CheckOutcome::Passed("auth OK".into()),
+        Ok(Ok(Err(error))) => {
+            let text = format!("{error:#}");
+            match classify_model_probe_error(&text) {
+                ModelProbeOutcome::Skipped => CheckOutcome::Skipped(truncate(&text)),
+                ModelProbeOutcome::AuthOrAccess => {
+                    CheckOutcome::Failed(format!("auth rejected: {}", truncate(&text)))
+                }
+                _ => CheckOutcome::Failed(truncate(&text)),
+            }
+        }
+        Ok(Err(error)) => CheckOutcome::Failed(format!("probe panicked: {error}")),
+        Err(_) => CheckOutcome::Failed(format!("no answer within {}s", CHECK_TIMEOUT.as_secs())),
+    };
+    SelftestCheck::new(name, outcome)
+}
+
+async fn check_channels(config: &Config) -> Vec<SelftestCheck> {
+    use crate::channels::ChannelHealthState;
+
+    match crate::channels::check_all_channels(config).await {
+        Ok(report) => report
+            .into_iter()
+            .map(|health| {
+                let outcome = match health.state {
+                    ChannelHealthState::Healthy => CheckOutcome::Passed("token accepted".into()),
+                    ChannelHealthState::Unhealthy => {
+                        CheckOutcome::Failed("unhealthy (auth/config/network)".into())
+                    }
+                    ChannelHealthState::Timeout => CheckOutcome::Failed(format!(
+                        "no answer within {}s",
+                        CHECK_TIMEOUT.as_secs()
+                    )),
+                };
+                SelftestCheck::new(health.channel, outcome)
+            })
+            .collect(),
+        Err(error) => vec![SelftestCheck::new(
+            "Channels",
+            CheckOutcome::Failed(truncate(&format!("{error:#}"))),
+        )],
+    }
+}
+
+/// Run every check. Network checks are skipped under `zeroclaw offline on`.
+pub async fn run(config: &Config) -> SelftestReport {
+    let mut checks = vec![check_config(config), check_memory(config).await];
+    if crate::connectivity::manual_offline(&config.workspace_dir) {
+        checks.push(SelftestCheck::new(
+            "Provider and channels",
+            CheckOutcome::Skipped("offline mode".into()),
+        ));
+    } else {
+        checks.push(check_provider(config).await);
+        checks.extend(check_channels(config).await);
+    }
+    SelftestReport { checks }
+}
+
+/// Whether the gateway may start after `report` under `policy`.
+pub fn verdict(report: &SelftestReport, policy: SelftestFailurePolicy) -> Result<()> {
+    let failures = report.failures();
+    if failures.is_empty() || policy == SelftestFailurePolicy::Degrade {
+        return Ok(());
+    }
+    let names: Vec<&str> = failures.iter().map(|check| check.name.as_str()).collect();
+    anyhow::bail!(
+        "Startup self-test failed ({}); [gateway.selftest] on_failure = \"abort\". \
+         Fix the checks above, set on_failure = \"degrade\", or pass --skip-selftest.",
+        names.join(", ")
+    )
+}
+
+/// Run the self-test before the gateway starts, report it, and apply the
+/// failure policy. Does nothing when `[gateway.selftest]` is disabled.
+pub async fn run_on_start(config: &Config) -> Result<()> {
+    let selftest = &config.gateway.selftest;
+    if !selftest.enabled {
+        return Ok(());
+    }
+
+    tracing::info!("Running startup self-test");
+    let report = run(config).await;
+    for check in &report.checks {
+        if check.failed() {
+            tracing::warn!("Self-test: {}", check.line());
+        } else {
+            tracing::info!("Self-test: {}", check.line());
+        }
+    }
+
+    let failures = report.failures();
+    if failures.is_empty() {
+        crate::health::mark_component_ok("selftest");
+    } else {
+        let names: Vec<&str> = failures.iter().map(|check| check.name.as_str()).collect();
+        crate::health::mark_component_error("selftest", format!("failed: {}", names.join(", ")));
+    }
+
+    crate::security::estop::notify_target_or_owners(
+        config,
+        selftest.notify_channel.as_deref(),
+        selftest.notify_to.as_deref(),
+        &report.summary(selftest.on_failure),
+    )
+    .await;
+    verdict(&report, selftest.on_failure)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> SelftestReport {
+        SelftestReport {
+            checks: vec![
+                SelftestCheck::new("Config", CheckOutcome::Passed("valid".into())),
+                SelftestCheck::new(
+                    "Provider (ollama)",
+                    CheckOutcome::Skipped("no model listing to verify auth with".into()),
+                ),
+                SelftestCheck::new(
+                    "Discord",
+                    CheckOutcome::Failed("unhealthy (auth/config/network)".into()),
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn summary_lists_every_check_under_a_policy_headline() {
+        let summary = report().summary(SelftestFailurePolicy::Degrade);
+        let lines: Vec<&str> = summary.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "⚠️ ZeroClaw started degraded — 1 self-test check(s) failed",
+                "✅ Config: valid",
+                "⚪ Provider (ollama): skipped (no model listing to verify auth with)",
+                "❌ Discord: unhealthy (auth/config/network)",
+            ]
+        );
+        assert!(report()
+            .summary(SelftestFailurePolicy::Abort)
+            .starts_with("🛑 ZeroClaw did not start"));
+    }
+
+    #[test]
+    fn only_abort_policy_blocks_start_on_failure() {
+        let failing = report();
+        assert!(verdict(&failing, SelftestFailurePolicy::Degrade).is_ok());
+        let error = verdict(&failing, SelftestFailurePolicy::Abort).unwrap_err();
+        assert!(error.to_string().contains("Discord"));
+
+        let mut passing = report();
+        passing.checks.pop();
+        assert!(verdict(&passing, SelftestFailurePolicy::Abort).is_ok());
+    }
+}
@@ -57,6 +57,31 @@ pub async fn handle_sse_events(
         .into_response()
 }
 
+/// Forward toasts from `toasts` to SSE subscribers as
+/// `{"type": "toast", "level", "message"}` events.
+pub fn spawn_toast_forwarder(
+    toasts: &crate::notifications::Toasts,
+    tx: tokio::sync::broadcast::Sender<serde_json::Value>,
+) {
+    let mut toasts = toasts.subscribe();
+    tokio::spawn(async move {
+        loop {
+            match toasts.recv().await {
+                Ok(toast) => {
+                    let _ = tx.send(serde_json::json!({
+                        "type": "toast",
+                        "level": toast.level,
+                        "message": toast.message,
+                        "timestamp": chrono::Utc::now().to_rfc3339(),
+                    }));
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {}
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
 /// Broadcast observer that forwards events to the SSE broadcast channel.
 pub struct BroadcastObserver {
     inner: Box<dyn crate::observability::Observer>,
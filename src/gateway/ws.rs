@@ -93,7 +93,8 @@ async fn handle_socket(socket: WebSocket, state: AppState) {
             "model": state.model,
         }));
 
-        // Simple single-turn chat (no streaming for now — use provider.chat_with_system)
+        // Single-turn chat; deltas are forwarded as `chunk` frames when the
+        // provider streams.
         let system_prompt = {
             let config_guard = state.config.lock();
             crate::channels::build_system_prompt(
@@ -127,11 +128,16 @@ async fn handle_socket(socket: WebSocket, state: AppState) {
                 }
             };
 
-        match state
-            .provider
-            .chat_with_history(&prepared.messages, &state.model, state.temperature)
+        let watchdog = crate::agent::watchdog::Watchdog::new(&state.config.lock());
+        let reply = watchdog
+            .guard_generation(
+                &provider_label,
+                &state.model,
+                stream_reply(&state, &prepared.messages, &mut sender),
+            )
             .await
-        {
+            .unwrap_or_else(|timeout| Err(timeout.into()));
+        match reply {
             Ok(response) => {
                 // Send the full response as a done message
                 let done = serde_json::json!({
@@ -165,3 +171,46 @@ async fn handle_socket(socket: WebSocket, state: AppState) {
         }
     }
 }
+
+/// Send the reply as `chunk` frames while the provider streams it, and return
+/// the full text. Providers without streaming answer in one piece.
+async fn stream_reply<S>(
+    state: &AppState,
+    messages: &[crate::providers::ChatMessage],
+    sender: &mut S,
+) -> anyhow::Result<String>
+where
+    S: futures_util::Sink<Message> + Unpin,
+{
+    if !state.provider.supports_streaming() {
+        return state
+            .provider
+            .chat_with_history(messages, &state.model, state.temperature)
+            .await;
+    }
+
+    let mut stream = state.provider.stream_chat_with_history(
+        messages,
+        &state.model,
+        state.temperature,
+        crate::providers::traits::StreamOptions::new(true),
+    );
+    let mut full_response = String::new();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        if chunk.is_final {
+            // A final chunk only carries text when it reports an error.
+            if !chunk.delta.is_empty() {
+                anyhow::bail!(chunk.delta);
+            }
+            break;
+        }
+        if chunk.delta.is_empty() {
+            continue;
+        }
+        let frame = serde_json::json!({"type": "chunk", "content": chunk.delta});
+        let _ = sender.send(Message::Text(frame.to_string().into())).await;
+        full_response.push_str(&chunk.delta);
+    }
+    Ok(full_response)
+}
@@ -3,6 +3,8 @@
 //! USB enumeration via `nusb` is only supported on Linux, macOS, and Windows.
 //! On Android (Termux) and other unsupported platforms this module is excluded
 //! from compilation; callers in `hardware/mod.rs` fall back to an empty result.
+//! Serial port names come from the OS port list, so Windows COM ports are
+//! reported alongside `/dev/tty*` paths.
 
 #![cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
 
@@ -20,12 +22,15 @@ pub struct UsbDeviceInfo {
     pub product_string: Option<String>,
     pub board_name: Option<String>,
     pub architecture: Option<String>,
+    /// Serial port the device exposes, if any (`/dev/ttyACM0`, `COM3`, ...).
+    pub serial_port: Option<String>,
 }
 
 /// Enumerate all connected USB devices and enrich with board registry lookup.
 #[cfg(feature = "hardware")]
 pub fn list_usb_devices() -> Result<Vec<UsbDeviceInfo>> {
     let mut devices = Vec::new();
+    let mut serial_ports = usb_serial_ports();
 
     let iter = nusb::list_devices()
         .wait()
@@ -35,6 +40,10 @@ pub fn list_usb_devices() -> Result<Vec<UsbDeviceInfo>> {
         let vid = dev.vendor_id();
         let pid = dev.product_id();
         let board = registry::lookup_board(vid, pid);
+        let serial_port = serial_ports
+            .iter()
+            .position(|port| port.vid == vid && port.pid == pid)
+            .map(|idx| serial_ports.swap_remove(idx).name);
 
         devices.push(UsbDeviceInfo {
             bus_id: dev.bus_id().to_string(),
@@ -44,8 +53,35 @@ pub fn list_usb_devices() -> Result<Vec<UsbDeviceInfo>> {
             product_string: dev.product_string().map(String::from),
             board_name: board.map(|b| b.name.to_string()),
             architecture: board.and_then(|b| b.architecture.map(String::from)),
+            serial_port,
         });
     }
 
     Ok(devices)
 }
+
+/// A USB-backed serial port as reported by the OS.
+#[cfg(feature = "hardware")]
+struct UsbSerialPort {
+    name: String,
+    vid: u16,
+    pid: u16,
+}
+
+/// Serial ports backed by USB devices. This is the only way to learn COM port
+/// names on Windows; elsewhere it saves guessing `/dev/tty*` paths.
+#[cfg(feature = "hardware")]
+fn usb_serial_ports() -> Vec<UsbSerialPort> {
+    tokio_serial::available_ports()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|port| match port.port_type {
+            tokio_serial::SerialPortType::UsbPort(usb) => Some(UsbSerialPort {
+                name: port.port_name,
+                vid: usb.vid,
+                pid: usb.pid,
+            }),
+            _ => None,
+        })
+        .collect()
+}
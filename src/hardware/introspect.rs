@@ -2,6 +2,7 @@
 
 use super::discover;
 use super::registry;
+use crate::peripherals::serial::normalize_serial_path;
 use anyhow::Result;
 
 /// Result of introspecting a device by path.
@@ -15,16 +16,24 @@ pub struct IntrospectResult {
     pub memory_map_note: String,
 }
 
-/// Introspect a device by its serial path (e.g. /dev/ttyACM0, /dev/tty.usbmodem*).
+/// Introspect a device by its serial path (e.g. /dev/ttyACM0, /dev/tty.usbmodem*, COM3).
 /// Attempts to correlate with USB devices from discovery.
 #[cfg(feature = "hardware")]
 pub fn introspect_device(path: &str) -> Result<IntrospectResult> {
     let devices = discover::list_usb_devices()?;
 
-    // Try to correlate path with a discovered device.
-    // On Linux, /dev/ttyACM0 corresponds to a CDC-ACM device; we may have multiple.
-    // Best-effort: if we have exactly one CDC-like device, use it. Otherwise unknown.
-    let matched = if devices.len() == 1 {
+    // Prefer an exact serial-port match (the only reliable option for COM ports
+    // on Windows). Otherwise fall back to best-effort: if we have exactly one
+    // device, use it.
+    let wanted = normalize_serial_path(path);
+    let by_port = devices.iter().find(|d| {
+        d.serial_port
+            .as_deref()
+            .is_some_and(|port| normalize_serial_path(port) == wanted)
+    });
+    let matched = if let Some(device) = by_port {
+        Some(device.clone())
+    } else if devices.len() == 1 {
         devices.first().cloned()
     } else if devices.is_empty() {
         None
@@ -49,7 +49,7 @@ pub fn discover_hardware() -> Vec<DiscoveredDevice> {
                         .board_name
                         .unwrap_or_else(|| format!("{:04x}:{:04x}", d.vid, d.pid)),
                     detail: d.product_string,
-                    device_path: None,
+                    device_path: d.serial_port,
                     transport: if d.architecture.as_deref() == Some("native") {
                         HardwareTransport::Native
                     } else {
@@ -101,6 +101,98 @@ pub fn config_from_wizard_choice(choice: usize, devices: &[DiscoveredDevice]) ->
     }
 }
 
+/// Board presets offered by the onboarding hardware step. Each one fills in
+/// the transport, GPIO chip, baud rate or probe target for a common board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardPreset {
+    RaspberryPi4,
+    RaspberryPi5,
+    Jetson,
+    Esp32Serial,
+    NucleoProbe,
+    GenericX86,
+}
+
+impl BoardPreset {
+    pub const ALL: [Self; 6] = [
+        Self::RaspberryPi4,
+        Self::RaspberryPi5,
+        Self::Jetson,
+        Self::Esp32Serial,
+        Self::NucleoProbe,
+        Self::GenericX86,
+    ];
+
+    /// Stable name stored in `[hardware].board`.
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::RaspberryPi4 => "rpi4",
+            Self::RaspberryPi5 => "rpi5",
+            Self::Jetson => "jetson",
+            Self::Esp32Serial => "esp32-serial",
+            Self::NucleoProbe => "nucleo-f401re-probe",
+            Self::GenericX86 => "generic-x86",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::RaspberryPi4 => "Raspberry Pi 4 — native GPIO (gpiochip0)",
+            Self::RaspberryPi5 => "Raspberry Pi 5 — native GPIO via RP1 (gpiochip0)",
+            Self::Jetson => "NVIDIA Jetson (Orin/Nano) — native GPIO (gpiochip0)",
+            Self::Esp32Serial => "ESP32 over USB serial — 115200 baud",
+            Self::NucleoProbe => "STM32 Nucleo-F401RE — debug probe (STM32F401RETx)",
+            Self::GenericX86 => "Generic x86 PC — tethered USB board, no onboard GPIO",
+        }
+    }
+
+    /// The preset's `HardwareConfig`. Serial presets use the first discovered
+    /// serial device, falling back to the usual port for that board.
+    pub fn config(self, devices: &[DiscoveredDevice]) -> HardwareConfig {
+        let native = |chip: &str| HardwareConfig {
+            enabled: true,
+            transport: HardwareTransport::Native,
+            board: Some(self.key().to_string()),
+            gpio_chip: Some(chip.to_string()),
+            ..HardwareConfig::default()
+        };
+        let serial = |fallback: &str| HardwareConfig {
+            enabled: true,
+            transport: HardwareTransport::Serial,
+            board: Some(self.key().to_string()),
+            serial_port: devices
+                .iter()
+                .find(|d| d.transport == HardwareTransport::Serial)
+                .and_then(|d| d.device_path.clone())
+                .or_else(|| Some(fallback.to_string())),
+            baud_rate: 115_200,
+            ..HardwareConfig::default()
+        };
+        match self {
+            // The Pi 5 header moved to the RP1 chip; kernels before 6.6.45
+            // exposed it as gpiochip4, current Raspberry Pi OS as gpiochip0.
+            Self::RaspberryPi4 | Self::RaspberryPi5 | Self::Jetson => native("gpiochip0"),
+            Self::Esp32Serial => serial(if cfg!(windows) {
+                "COM3"
+            } else {
+                "/dev/ttyUSB0"
+            }),
+            Self::GenericX86 => serial(if cfg!(windows) {
+                "COM3"
+            } else {
+                "/dev/ttyACM0"
+            }),
+            Self::NucleoProbe => HardwareConfig {
+                enabled: true,
+                transport: HardwareTransport::Probe,
+                board: Some(self.key().to_string()),
+                probe_target: Some("STM32F401RETx".to_string()),
+                ..HardwareConfig::default()
+            },
+        }
+    }
+}
+
 /// Handle `zeroclaw hardware` subcommands.
 #[allow(clippy::module_name_repetitions)]
 pub fn handle_command(cmd: crate::HardwareCommands, _config: &Config) -> Result<()> {
@@ -128,7 +220,7 @@ pub fn handle_command(cmd: crate::HardwareCommands, _config: &Config) -> Result<
         any(target_os = "linux", target_os = "macos", target_os = "windows")
     ))]
     match cmd {
-        crate::HardwareCommands::Discover => run_discover(),
+        crate::HardwareCommands::Discover => write_discover(&mut std::io::stdout()),
         crate::HardwareCommands::Introspect { path } => run_introspect(&path),
         crate::HardwareCommands::Info { chip } => run_info(&chip),
     }
@@ -138,29 +230,38 @@ pub fn handle_command(cmd: crate::HardwareCommands, _config: &Config) -> Result<
     feature = "hardware",
     any(target_os = "linux", target_os = "macos", target_os = "windows")
 ))]
-fn run_discover() -> Result<()> {
+/// Write the `hardware discover` device table to `out`.
+pub(crate) fn write_discover(out: &mut dyn std::io::Write) -> Result<()> {
     let devices = discover::list_usb_devices()?;
 
     if devices.is_empty() {
-        println!("No USB devices found.");
-        println!();
-        println!("Connect a board (e.g. Nucleo-F401RE) via USB and try again.");
+        writeln!(out, "No USB devices found.")?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "Connect a board (e.g. Nucleo-F401RE) via USB and try again."
+        )?;
         return Ok(());
     }
 
-    println!("USB devices:");
-    println!();
+    writeln!(out, "USB devices:")?;
+    writeln!(out)?;
     for d in &devices {
         let board = d.board_name.as_deref().unwrap_or("(unknown)");
         let arch = d.architecture.as_deref().unwrap_or("—");
         let product = d.product_string.as_deref().unwrap_or("—");
-        println!(
-            "  {:04x}:{:04x}  {}  {}  {}",
-            d.vid, d.pid, board, arch, product
-        );
+        let port = d.serial_port.as_deref().unwrap_or("—");
+        writeln!(
+            out,
+            "  {:04x}:{:04x}  {}  {}  {}  {}",
+            d.vid, d.pid, board, arch, port, product
+        )?;
     }
-    println!();
-    println!("Known boards: nucleo-f401re, nucleo-f411re, arduino-uno, arduino-mega, cp2102");
+    writeln!(out)?;
+    writeln!(
+        out,
+        "Known boards: nucleo-f401re, nucleo-f411re, arduino-uno, arduino-mega, cp2102"
+    )?;
 
     Ok(())
 }
@@ -265,3 +366,34 @@ fn info_via_probe(chip: &str) -> anyhow::Result<()> {
     println!("Info read via USB (SWD) — no firmware on target needed.");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn board_presets_fill_transport_specific_fields() {
+        let devices = vec![DiscoveredDevice {
+            name: "esp32".into(),
+            detail: None,
+            device_path: Some("/dev/ttyUSB3".into()),
+            transport: HardwareTransport::Serial,
+        }];
+
+        let pi = BoardPreset::RaspberryPi5.config(&[]);
+        assert_eq!(pi.transport, HardwareTransport::Native);
+        assert_eq!(pi.gpio_chip.as_deref(), Some("gpiochip0"));
+        assert_eq!(pi.board.as_deref(), Some("rpi5"));
+
+        let esp = BoardPreset::Esp32Serial.config(&devices);
+        assert_eq!(esp.serial_port.as_deref(), Some("/dev/ttyUSB3"));
+        assert_eq!(esp.baud_rate, 115_200);
+        assert!(BoardPreset::Esp32Serial.config(&[]).serial_port.is_some());
+
+        let probe = BoardPreset::NucleoProbe.config(&[]);
+        assert_eq!(probe.probe_target.as_deref(), Some("STM32F401RETx"));
+        assert!(BoardPreset::ALL
+            .iter()
+            .all(|preset| preset.config(&[]).enabled));
+    }
+}
@@ -0,0 +1,264 @@
+//! Runtime counters for the dashboard home screen.
+//!
+//! A [`RuntimeMetrics`] handle is created by whoever owns the runtime (the
+//! daemon, or `zeroclaw gateway` / `zeroclaw channel start` on their own) and
+//! shared with the gateway's `AppState` and the channel runtime. It observes
+//! channel and LLM events through the [`Observer`] fan-out, and `/api/status`
+//! reads a snapshot. Token totals are kept per provider for the current UTC day.
+
+use super::HealthSnapshot;
+use crate::observability::traits::{Observer, ObserverEvent, ObserverMetric};
+use crate::observability::MultiObserver;
+use chrono::{DateTime, NaiveDate, Utc};
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LastError {
+    pub source: String,
+    pub message: String,
+    pub at: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MetricsSnapshot {
+    /// Inbound messages per channel since start.
+    pub channel_messages: BTreeMap<String, u64>,
+    /// Tokens (input + output) per provider today (UTC).
+    pub tokens_today: BTreeMap<String, u64>,
+    pub last_error: Option<LastError>,
+}
+
+#[derive(Default)]
+struct Metrics {
+    channel_messages: BTreeMap<String, u64>,
+    tokens_day: Option<NaiveDate>,
+    tokens_today: BTreeMap<String, u64>,
+    last_error: Option<LastError>,
+}
+
+impl Metrics {
+    fn roll_day(&mut self, today: NaiveDate) {
+        if self.tokens_day != Some(today) {
+            self.tokens_day = Some(today);
+            self.tokens_today.clear();
+        }
+    }
+}
+
+/// Shared handle to the dashboard counters. Clones count into the same totals.
+#[derive(Clone, Default)]
+pub struct RuntimeMetrics {
+    inner: Arc<Mutex<Metrics>>,
+}
+
+impl RuntimeMetrics {
+    /// `observer` plus these counters, for runtimes whose events should show
+    /// up on the dashboard.
+    pub fn observe(&self, observer: Box<dyn Observer>) -> Box<dyn Observer> {
+        Box::new(MultiObserver::new(vec![observer, Box::new(self.clone())]))
+    }
+
+    /// Count one inbound message on `channel`.
+    pub fn record_channel_message(&self, channel: &str) {
+        let mut metrics = self.inner.lock();
+        *metrics
+            .channel_messages
+            .entry(channel.to_string())
+            .or_default() += 1;
+    }
+
+    /// Add a response's token usage to today's total for `provider`.
+    pub fn record_tokens(&self, provider: &str, tokens: u64) {
+        if tokens == 0 {
+            return;
+        }
+        let mut metrics = self.inner.lock();
+        metrics.roll_day(Utc::now().date_naive());
+        *metrics
+            .tokens_today
+            .entry(provider.to_string())
+            .or_default() += tokens;
+    }
+
+    /// Remember the most recent error and where it came from.
+    pub fn record_error(&self, source: &str, message: &str) {
+        self.inner.lock().last_error = Some(LastError {
+            source: source.to_string(),
+            message: message.to_string(),
+            at: Utc::now().to_rfc3339(),
+        });
+    }
+
+    /// Current counters. `last_error` also considers components the health
+    /// registry has marked as failed, whichever happened last.
+    pub fn snapshot(&self, health: &HealthSnapshot) -> MetricsSnapshot {
+        let mut metrics = self.inner.lock();
+        metrics.roll_day(Utc::now().date_naive());
+        let component_errors = health
+            .components
+            .iter()
+            .filter(|(_, component)| component.status == "error")
+            .map(|(name, component)| LastError {
+                source: name.clone(),
+                message: component.last_error.clone().unwrap_or_default(),
+                at: component.updated_at.clone(),
+            });
+        let last_error = metrics
+            .last_error
+            .clone()
+            .into_iter()
+            .chain(component_errors)
+            .max_by_key(|error| DateTime::parse_from_rfc3339(&error.at).ok());
+        MetricsSnapshot {
+            channel_messages: metrics.channel_messages.clone(),
+            tokens_today: metrics.tokens_today.clone(),
+            last_error,
+        }
+    }
+}
+
+impl Observer for RuntimeMetrics {
+    fn record_event(&self, event: &ObserverEvent) {
+        match event {
+            ObserverEvent::ChannelMessage { channel, direction } if direction == "inbound" => {
+                self.record_channel_message(channel);
+            }
+            ObserverEvent::LlmResponse {
+                provider,
+                success: true,
+                input_tokens,
+                output_tokens,
+                ..
+            } => {
+                self.record_tokens(
+                    provider,
+                    input_tokens.unwrap_or(0) + output_tokens.unwrap_or(0),
+                );
+            }
+            ObserverEvent::LlmResponse {
+                provider,
+                success: false,
+                error_message: Some(message),
+                ..
+            } => self.record_error(provider, message),
+            _ => {}
+        }
+    }
+
+    fn record_metric(&self, _metric: &ObserverMetric) {}
+
+    fn name(&self) -> &str {
+        "runtime-metrics"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::health::ComponentHealth;
+    use std::time::Duration;
+
+    fn health(components: BTreeMap<String, ComponentHealth>) -> HealthSnapshot {
+        HealthSnapshot {
+            pid: 1,
+            updated_at: Utc::now().to_rfc3339(),
+            uptime_seconds: 0,
+            components,
+        }
+    }
+
+    fn response(provider: &str, error: Option<&str>, tokens: Option<u64>) -> ObserverEvent {
+        ObserverEvent::LlmResponse {
+            provider: provider.into(),
+            model: "model".into(),
+            duration: Duration::from_millis(10),
+            success: error.is_none(),
+            error_message: error.map(str::to_string),
+            input_tokens: tokens,
+            output_tokens: tokens,
+        }
+    }
+
+    #[test]
+    fn counts_messages_tokens_and_last_error() {
+        let metrics = RuntimeMetrics::default();
+        let observer = metrics.observe(Box::new(crate::observability::NoopObserver));
+        for direction in ["inbound", "inbound", "outbound"] {
+            observer.record_event(&ObserverEvent::ChannelMessage {
+                channel: "telegram".into(),
+                direction: direction.into(),
+            });
+        }
+        observer.record_event(&response("openrouter", None, Some(60)));
+        observer.record_event(&response("openrouter", None, None));
+        observer.record_event(&response("openrouter", None, Some(15)));
+        observer.record_event(&response("openrouter", Some("rate limited"), None));
+
+        let snapshot = metrics.snapshot(&health(BTreeMap::new()));
+        assert_eq!(snapshot.channel_messages.get("telegram"), Some(&2));
+        assert_eq!(snapshot.tokens_today.get("openrouter"), Some(&150));
+        let last = snapshot.last_error.expect("last error recorded");
+        assert_eq!(last.source, "openrouter");
+        assert_eq!(last.message, "rate limited");
+    }
+
+    #[test]
+    fn separate_handles_do_not_share_counts() {
+        let first = RuntimeMetrics::default();
+        let second = RuntimeMetrics::default();
+        first.clone().record_channel_message("discord");
+        assert_eq!(
+            first
+                .snapshot(&health(BTreeMap::new()))
+                .channel_messages
+                .get("discord"),
+            Some(&1)
+        );
+        assert!(second
+            .snapshot(&health(BTreeMap::new()))
+            .channel_messages
+            .is_empty());
+    }
+
+    #[test]
+    fn newer_component_failure_is_the_last_error() {
+        let metrics = RuntimeMetrics::default();
+        metrics.record_error("openrouter", "rate limited");
+        let later = (Utc::now() + chrono::Duration::seconds(5)).to_rfc3339();
+        let components = BTreeMap::from([(
+            "channels".to_string(),
+            ComponentHealth {
+                status: "error".into(),
+                updated_at: later,
+                last_ok: None,
+                last_error: Some("listener exited".into()),
+                restart_count: 1,
+            },
+        )]);
+
+        let last = metrics.snapshot(&health(components)).last_error.unwrap();
+        assert_eq!(last.source, "channels");
+        assert_eq!(last.message, "listener exited");
+    }
+
+    #[test]
+    fn token_totals_reset_on_a_new_day() {
+        let mut metrics = Metrics::default();
+        let monday = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        metrics.roll_day(monday);
+        metrics.tokens_today.insert("openai".into(), 500);
+
+        metrics.roll_day(monday);
+        assert_eq!(metrics.tokens_today.get("openai"), Some(&500));
+        metrics.roll_day(monday.succ_opt().unwrap());
+        assert!(metrics.tokens_today.is_empty());
+    }
+}
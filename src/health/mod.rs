@@ -1,3 +1,5 @@
+pub mod metrics;
+
 use chrono::Utc;
 use parking_lot::Mutex;
 use serde::Serialize;
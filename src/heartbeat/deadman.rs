@@ -0,0 +1,255 @@
+//! Dead-man's switch: external pings plus owner check-ins.
+//!
+//! Each beat pings `[heartbeat.deadman].ping_url` and checks when the owner
+//! last checked in via `POST /api/checkin`. A run of failed pings or an
+//! overdue check-in trips the switch once; it re-arms after beats recover.
+
+use crate::config::{Config, DeadmanAction};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use tokio::sync::mpsc;
+use tokio::time::Duration;
+
+const CHECKIN_STATE_FILE: &str = "deadman.json";
+const PING_TIMEOUT_SECS: u64 = 10;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CheckinState {
+    last_checkin: Option<DateTime<Utc>>,
+}
+
+/// Why the switch tripped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Trip {
+    PingsFailed(u32),
+    CheckinOverdue { since: DateTime<Utc> },
+}
+
+impl Trip {
+    pub fn describe(&self) -> String {
+        match self {
+            Self::PingsFailed(count) => format!("{count} consecutive heartbeat pings failed"),
+            Self::CheckinOverdue { since } => format!(
+                "no owner check-in since {}",
+                since.format("%Y-%m-%d %H:%M UTC")
+            ),
+        }
+    }
+}
+
+fn checkin_state_path(workspace_dir: &Path) -> PathBuf {
+    workspace_dir.join("state").join(CHECKIN_STATE_FILE)
+}
+
+/// Record an owner check-in and return its timestamp.
+pub fn record_checkin(workspace_dir: &Path) -> Result<DateTime<Utc>> {
+    let path = checkin_state_path(workspace_dir);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let now = Utc::now();
+    let state = CheckinState {
+        last_checkin: Some(now),
+    };
+    std::fs::write(&path, serde_json::to_vec_pretty(&state)?)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(now)
+}
+
+fn last_checkin(workspace_dir: &Path) -> Option<DateTime<Utc>> {
+    let raw = std::fs::read_to_string(checkin_state_path(workspace_dir)).ok()?;
+    serde_json::from_str::<CheckinState>(&raw)
+        .ok()?
+        .last_checkin
+}
+
+/// Whether the owner is overdue. Without any recorded check-in the window
+/// starts when the worker started, so a fresh install is not tripped at once.
+fn checkin_overdue(
+    last: Option<DateTime<Utc>>,
+    started_at: DateTime<Utc>,
+    timeout_hours: u32,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let since = last.map_or(started_at, |last| last.max(started_at));
+    (now - since > ChronoDuration::hours(i64::from(timeout_hours))).then_some(since)
+}
+
+async fn ping(client: &reqwest::Client, url: &str) -> Result<()> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .with_context(|| format!("Failed to reach {url}"))?;
+    if !response.status().is_success() {
+        anyhow::bail!("{url} returned {}", response.status());
+    }
+    Ok(())
+}
+
+/// Run the dead-man's switch until cancelled. `stop_channels` asks the
+/// daemon to stop its channel listeners.
+pub async fn run_worker(
+    config: Config,
+    stop_channels: mpsc::Sender<()>,
+    toasts: crate::notifications::Toasts,
+) -> Result<()> {
+    let deadman = config.heartbeat.deadman.clone();
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(PING_TIMEOUT_SECS))
+        .build()
+        .context("Failed to build dead-man's switch HTTP client")?;
+    let started_at = Utc::now();
+    let mut failed_pings = 0_u32;
+    let mut tripped = false;
+    let mut interval = tokio::time::interval(Duration::from_secs(
+        u64::from(deadman.interval_minutes.max(1)) * 60,
+    ));
+
+    loop {
+        interval.tick().await;
+
+        if let Some(url) = deadman.ping_url.as_deref() {
+            match ping(&client, url).await {
+                Ok(()) => failed_pings = 0,
+                Err(error) => {
+                    failed_pings += 1;
+                    tracing::warn!("Dead-man's switch ping failed ({failed_pings}): {error}");
+                }
+            }
+        }
+
+        let trip = if failed_pings >= deadman.max_failed_pings {
+            Some(Trip::PingsFailed(failed_pings))
+        } else {
+            deadman.checkin_timeout_hours.and_then(|hours| {
+                checkin_overdue(
+                    last_checkin(&config.workspace_dir),
+                    started_at,
+                    hours,
+                    Utc::now(),
+                )
+                .map(|since| Trip::CheckinOverdue { since })
+            })
+        };
+
+        match trip {
+            Some(trip) if !tripped => {
+                tripped = true;
+                crate::health::mark_component_error("deadman", trip.describe());
+                run_action(&config, deadman.action, &trip, &stop_channels, &toasts).await;
+            }
+            Some(_) => {}
+            None => {
+                if tripped {
+                    tracing::info!("Dead-man's switch re-armed; beats recovered");
+                }
+                tripped = false;
+                crate::health::mark_component_ok("deadman");
+            }
+        }
+    }
+}
+
+async fn run_action(
+    config: &Config,
+    action: DeadmanAction,
+    trip: &Trip,
+    stop_channels: &mpsc::Sender<()>,
+    toasts: &crate::notifications::Toasts,
+) {
+    let mut notice = format!("⚠️ ZeroClaw dead-man's switch tripped: {}", trip.describe());
+    match action {
+        DeadmanAction::Notify => {}
+        DeadmanAction::Estop => match engage_estop(config, toasts) {
+            Ok(()) => notice.push_str("\nEstop kill-all engaged."),
+            Err(error) => {
+                tracing::error!("Dead-man's switch could not engage estop: {error}");
+                let _ = write!(notice, "\nEstop engage failed: {error}");
+            }
+        },
+        DeadmanAction::StopChannels => {
+            if stop_channels.send(()).await.is_ok() {
+                notice.push_str("\nChannel listeners stopped.");
+            }
+        }
+    }
+
+    tracing::error!("{notice}");
+    crate::notifications::notify(
+        &config.ui.notifications,
+        crate::notifications::NotifyEvent::ChannelDown,
+        &notice,
+    );
+    if let (Some(channel), Some(target)) = (
+        config.heartbeat.target.as_deref(),
+        config.heartbeat.to.as_deref(),
+    ) {
+        if let Err(error) =
+            crate::cron::scheduler::deliver_announcement(config, channel, target, &notice).await
+        {
+            tracing::warn!("Dead-man's switch notice delivery failed: {error}");
+        }
+    }
+    if action == DeadmanAction::Estop {
+        crate::security::estop::notify_owners(config, &notice).await;
+    }
+}
+
+fn engage_estop(config: &Config, toasts: &crate::notifications::Toasts) -> Result<()> {
+    if !config.security.estop.enabled {
+        anyhow::bail!("[security.estop].enabled is false");
+    }
+    let config_dir = config
+        .config_path
+        .parent()
+        .context("Config path must have a parent directory")?;
+    let mut manager = crate::security::EstopManager::load(&config.security.estop, config_dir)?
+        .with_toasts(toasts.clone());
+    manager.engage(crate::security::EstopLevel::KillAll)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checkin_round_trips_through_state_file() {
+        let temp = tempfile::tempdir().unwrap();
+        assert!(last_checkin(temp.path()).is_none());
+
+        let recorded = record_checkin(temp.path()).unwrap();
+        assert_eq!(last_checkin(temp.path()), Some(recorded));
+    }
+
+    #[test]
+    fn checkin_window_starts_at_worker_start_without_history() {
+        let started = Utc::now() - ChronoDuration::hours(30);
+        let now = Utc::now();
+
+        assert_eq!(checkin_overdue(None, started, 24, now), Some(started));
+        assert_eq!(checkin_overdue(None, started, 48, now), None);
+
+        let recent = now - ChronoDuration::hours(2);
+        assert_eq!(checkin_overdue(Some(recent), started, 24, now), None);
+    }
+
+    #[test]
+    fn trip_descriptions_are_human_readable() {
+        assert_eq!(
+            Trip::PingsFailed(3).describe(),
+            "3 consecutive heartbeat pings failed"
+        );
+        let since = DateTime::parse_from_rfc3339("2026-01-02T03:04:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            Trip::CheckinOverdue { since }.describe(),
+            "no owner check-in since 2026-01-02 03:04 UTC"
+        );
+    }
+}
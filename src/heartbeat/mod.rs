@@ -1,3 +1,4 @@
+pub mod deadman;
 pub mod engine;
 
 #[cfg(test)]
@@ -41,8 +41,11 @@ use serde::{Deserialize, Serialize};
 pub mod agent;
 pub(crate) mod approval;
 pub(crate) mod auth;
+pub(crate) mod backup;
 pub mod channels;
 pub mod config;
+pub(crate) mod connectivity;
+pub(crate) mod conversation_import;
 pub(crate) mod cost;
 pub(crate) mod cron;
 pub(crate) mod daemon;
@@ -57,10 +60,14 @@ pub(crate) mod integrations;
 pub mod memory;
 pub(crate) mod migration;
 pub(crate) mod multimodal;
+pub(crate) mod notifications;
 pub mod observability;
 pub(crate) mod onboard;
 pub mod peripherals;
+pub(crate) mod power;
+pub(crate) mod privacy;
 pub mod providers;
+pub(crate) mod provision;
 pub mod rag;
 pub mod runtime;
 pub(crate) mod security;
@@ -69,6 +76,7 @@ pub(crate) mod skills;
 pub mod tools;
 pub(crate) mod tunnel;
 pub(crate) mod util;
+pub(crate) mod workspace_sync;
 
 pub use config::Config;
 
@@ -87,6 +95,36 @@ pub enum ServiceCommands {
     Status,
     /// Uninstall daemon service unit
     Uninstall,
+    /// Show or follow the daemon log
+    #[command(long_about = "\
+Show or follow the daemon log.
+
+Reads the log file written by the installed service \
+(<config-dir>/logs/daemon.stderr.log on macOS/Windows, \
+/var/log/zeroclaw/error.log with OpenRC) or the systemd user journal.
+
+Examples:
+  zeroclaw service logs
+  zeroclaw service logs --follow --level warn
+  zeroclaw service logs --grep telegram --lines 200
+  zeroclaw service logs --file ./daemon.log")]
+    Logs {
+        /// Keep printing new lines as they are written
+        #[arg(short, long)]
+        follow: bool,
+        /// Show this level and more severe ones
+        #[arg(long, value_parser = ["error", "warn", "info", "debug", "trace"])]
+        level: Option<String>,
+        /// Only show lines containing this text (case-insensitive)
+        #[arg(long)]
+        grep: Option<String>,
+        /// Number of matching lines to show from the end of the log
+        #[arg(short = 'n', long, default_value_t = 100)]
+        lines: usize,
+        /// Read this log file instead of the service log
+        #[arg(long)]
+        file: Option<std::path::PathBuf>,
+    },
 }
 
 /// Channel management subcommands
@@ -97,7 +135,11 @@ pub enum ChannelCommands {
     /// Start all configured channels (handled in main.rs for async)
     Start,
     /// Run health checks for configured channels (handled in main.rs for async)
-    Doctor,
+    Doctor {
+        /// Probe only this channel (e.g. `telegram`)
+        #[arg(long)]
+        channel: Option<String>,
+    },
     /// Add a new channel configuration
     #[command(long_about = "\
 Add a new channel configuration.
@@ -175,6 +217,132 @@ pub enum MigrateCommands {
     },
 }
 
+/// Workspace subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum WorkspaceCommands {
+    /// Commit workspace Markdown, pull remote changes, and push (`[workspace_sync]`)
+    Sync {
+        /// Commit and pull only; do not push to the remote
+        #[arg(long)]
+        no_push: bool,
+    },
+}
+
+/// Backup subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BackupCommands {
+    /// Create an encrypted backup now and apply the retention policy
+    Run,
+    /// List archives on the configured target (newest first)
+    List,
+    /// Restore an archive into the workspace
+    Restore {
+        /// Archive name from `backup list`, or "latest"
+        name: String,
+        /// Overwrite existing workspace files
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// Offline mode subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum OfflineCommands {
+    /// Force offline mode: local provider only, hold announcements
+    On,
+    /// Leave manual offline mode
+    Off,
+    /// Show whether offline mode is on and the last recorded outage
+    Status,
+}
+
+/// Power monitoring subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PowerCommands {
+    /// Read the configured UPS / battery source once and print its state
+    Status,
+}
+
+/// Privacy subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PrivacyCommands {
+    /// Delete memories and session files for an identity and redact it in audit entries
+    Forget {
+        /// Identities to purge, as `<channel>:<sender>` or a bare sender ID.
+        /// Pass several to purge linked identities of the same person.
+        #[arg(required = true)]
+        identities: Vec<String>,
+        /// Report what would be deleted without deleting anything
+        #[arg(long)]
+        dry_run: bool,
+        /// Skip confirmation prompt
+        #[arg(long)]
+        yes: bool,
+        /// Print the deletion report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// Options shared by the provisioning subcommands
+#[derive(clap::Args, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProvisionOptions {
+    /// Bootstrap preset TOML (same format as `zeroclaw bootstrap --preset`)
+    #[arg(long)]
+    pub preset: std::path::PathBuf,
+    /// Device hostname
+    #[arg(long, default_value = "zeroclaw")]
+    pub hostname: String,
+    /// Login user created on the device; the service runs as this user
+    /// (no sudo)
+    #[arg(long, default_value = "zeroclaw")]
+    pub user: String,
+    /// Separate administrator created with passwordless sudo; receives the
+    /// SSH key instead of the service user
+    #[arg(long, value_name = "NAME")]
+    pub admin_user: Option<String>,
+    /// Public key file to authorize for SSH (password login is disabled)
+    #[arg(long)]
+    pub ssh_key: Option<std::path::PathBuf>,
+    /// Wi-Fi network name
+    #[arg(long)]
+    pub wifi_ssid: Option<String>,
+    /// Wi-Fi passphrase (omit for open networks)
+    #[arg(long, requires = "wifi_ssid")]
+    pub wifi_password: Option<String>,
+    /// Wi-Fi regulatory country code
+    #[arg(long, default_value = "US")]
+    pub wifi_country: String,
+    /// Local directory whose files pre-seed the device workspace
+    /// (`--workspace` itself selects the named profile)
+    #[arg(long = "workspace-files", value_name = "DIR")]
+    pub workspace: Option<std::path::PathBuf>,
+}
+
+/// Provisioning subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ProvisionCommands {
+    /// Write cloud-init seed files into the boot partition of a freshly flashed SD card
+    Sd {
+        #[command(flatten)]
+        options: ProvisionOptions,
+        /// Block device (/dev/sdX, /dev/mmcblk0) with a mounted boot partition, or the mount point
+        #[arg(long)]
+        device: std::path::PathBuf,
+        /// Write even if the target does not look like a Raspberry Pi boot partition
+        #[arg(long)]
+        force: bool,
+    },
+    /// Generate cloud-init user-data, meta-data, and network-config into a directory
+    CloudInit {
+        #[command(flatten)]
+        options: ProvisionOptions,
+        /// Output directory
+        #[arg(long, default_value = ".")]
+        output: std::path::PathBuf,
+    },
+}
+
 /// Cron subcommands
 #[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum CronCommands {
@@ -314,7 +482,23 @@ pub enum MemoryCommands {
         key: String,
     },
     /// Show memory backend statistics and health
-    Stats,
+    Stats {
+        /// Include index sizes, embedding coverage, namespaces, and fragmentation
+        #[arg(long)]
+        deep: bool,
+    },
+    /// Vacuum/optimize the memory store to reclaim space
+    Compact {
+        /// Skip confirmation prompt
+        #[arg(long)]
+        yes: bool,
+    },
+    /// Re-embed every entry with the current embedding settings (batched on Qdrant)
+    Reembed {
+        /// Skip confirmation prompt
+        #[arg(long)]
+        yes: bool,
+    },
     /// Clear memories by category, by key, or clear all
     Clear {
         /// Delete a single entry by key (supports prefix match)
@@ -433,3 +617,50 @@ Examples:
     /// Flash ZeroClaw firmware to Nucleo-F401RE (builds + probe-rs run)
     FlashNucleo,
 }
+
+/// Sensor time-series subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SensorCommands {
+    /// Summarize recorded sensor readings with sparklines
+    #[command(long_about = "\
+Summarize readings recorded by [peripherals.recorder].
+
+Shows each sensor's sample count, min/avg/max, latest value, and a \
+sparkline of the window.
+
+Examples:
+  zeroclaw sensors query --last 24h
+  zeroclaw sensors query --last 7d --board esp32 --pin 0")]
+    Query {
+        /// Look-back window (e.g. 90m, 24h, 7d, 2w)
+        #[arg(long, default_value = "24h")]
+        last: String,
+        /// Only this board (as named in [[peripherals.boards]])
+        #[arg(long)]
+        board: Option<String>,
+        /// Only this sensor pin
+        #[arg(long)]
+        pin: Option<u32>,
+    },
+    /// Show sensor alert rules and which are firing
+    #[command(long_about = "\
+Show every [[peripherals.recorder.alerts]] rule with its state: ok, or \
+firing with the reason and whether it was acknowledged.
+
+Examples:
+  zeroclaw sensors alerts")]
+    Alerts,
+    /// Acknowledge a firing sensor alert so it stops repeating
+    #[command(long_about = "\
+Acknowledge a firing sensor alert.
+
+An acknowledged alarm is not repeated every renotify_mins; it still \
+sends a resolved notice when it clears, and fires again normally after that.
+
+Examples:
+  zeroclaw sensors ack tank-hot")]
+    Ack {
+        /// Alert rule name
+        name: String,
+    },
+}
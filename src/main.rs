@@ -36,7 +36,7 @@ use anyhow::{bail, Context, Result};
 use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use dialoguer::{Input, Password};
 use serde::{Deserialize, Serialize};
-use std::io::Write;
+use std::io::{IsTerminal, Write};
 use tracing::{info, warn};
 use tracing_subscriber::{fmt, EnvFilter};
 
@@ -51,11 +51,14 @@ fn parse_temperature(s: &str) -> std::result::Result<f64, String> {
 mod agent;
 mod approval;
 mod auth;
+mod backup;
 mod channels;
 mod rag {
     pub use zeroclaw::rag::*;
 }
 mod config;
+mod connectivity;
+mod conversation_import;
 mod cost;
 mod cron;
 mod daemon;
@@ -70,10 +73,14 @@ mod integrations;
 mod memory;
 mod migration;
 mod multimodal;
+mod notifications;
 mod observability;
 mod onboard;
 mod peripherals;
+mod power;
+mod privacy;
 mod providers;
+mod provision;
 mod runtime;
 mod security;
 mod service;
@@ -82,13 +89,16 @@ mod skills;
 mod tools;
 mod tunnel;
 mod util;
+mod workspace_sync;
 
-use config::Config;
+use config::{Config, UiLocale, UiTheme};
 
 // Re-export so binary modules can use crate::<CommandEnum> while keeping a single source of truth.
 pub use zeroclaw::{
-    ChannelCommands, CronCommands, HardwareCommands, IntegrationCommands, MigrateCommands,
-    PeripheralCommands, ServiceCommands, SkillCommands,
+    BackupCommands, ChannelCommands, CronCommands, HardwareCommands, IntegrationCommands,
+    MigrateCommands, OfflineCommands, PeripheralCommands, PowerCommands, PrivacyCommands,
+    ProvisionCommands, ProvisionOptions, SensorCommands, ServiceCommands, SkillCommands,
+    WorkspaceCommands,
 };
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
@@ -127,6 +137,14 @@ struct Cli {
     #[arg(long, global = true)]
     config_dir: Option<String>,
 
+    /// Named profile/workspace to use (config and memory under <config-dir>/profiles/NAME)
+    #[arg(long, global = true, value_name = "NAME", visible_alias = "workspace")]
+    profile: Option<String>,
+
+    /// Save every provider request/response as numbered JSON files in DIR
+    #[arg(long, global = true, value_name = "DIR")]
+    record_llm: Option<std::path::PathBuf>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -160,6 +178,70 @@ enum Commands {
         /// Memory backend (sqlite, lucid, markdown, none) - used in quick mode, default: sqlite
         #[arg(long)]
         memory: Option<String>,
+        /// Wizard color theme (overrides [ui].theme)
+        #[arg(long, value_parser = UiTheme::NAMES)]
+        theme: Option<String>,
+        /// Wizard language (overrides [ui].locale and LANG)
+        #[arg(long, value_parser = UiLocale::NAMES)]
+        locale: Option<String>,
+        /// Plain line-based prompts for screen readers and dumb terminals (implies --interactive unless --channels-only)
+        #[arg(long)]
+        no_tui: bool,
+    },
+
+    /// Non-interactive first-run setup from env vars / preset, then start the gateway
+    #[command(long_about = "\
+Non-interactive first-run setup for containers and headless hosts.
+
+Reads onboarding answers from ZEROCLAW_* environment variables and an \
+optional preset TOML file (--preset or ZEROCLAW_PRESET; environment \
+wins), writes config.toml and the workspace, then starts the gateway \
+in the same process. If a config already exists it is reused, so the \
+same command is safe across container restarts.
+
+Examples:
+  zeroclaw bootstrap
+  zeroclaw bootstrap --preset /zeroclaw-data/preset.toml
+  zeroclaw bootstrap --daemon
+  ZEROCLAW_PROVIDER=anthropic ZEROCLAW_API_KEY=sk-... zeroclaw bootstrap --no-start")]
+    Bootstrap {
+        /// Preset TOML file with onboarding answers
+        #[arg(long)]
+        preset: Option<std::path::PathBuf>,
+
+        /// Regenerate config even if one already exists
+        #[arg(long)]
+        force: bool,
+
+        /// Start the full daemon (gateway, channels, heartbeat, scheduler) instead of only the gateway
+        #[arg(long, conflicts_with = "no_start")]
+        daemon: bool,
+
+        /// Write config and exit without starting anything
+        #[arg(long)]
+        no_start: bool,
+    },
+
+    /// Prepare a headless device (Raspberry Pi SD card or cloud-init seed)
+    #[command(long_about = "\
+Prepare a headless device for a one-command deployment.
+
+Generates cloud-init seed files that create the login user, install \
+the ZeroClaw release binary for the device architecture, drop the \
+bootstrap preset and optional workspace files in place, and enable a \
+systemd unit running `zeroclaw bootstrap --daemon` on first boot.
+
+`sd` writes the seed into the mounted boot partition of a freshly \
+flashed Raspberry Pi OS card (it never writes to the raw device); \
+`cloud-init` writes it to any directory for VMs or other images.
+
+Examples:
+  zeroclaw provision sd --preset preset.toml --device /dev/sdX --ssh-key ~/.ssh/id_ed25519.pub
+  zeroclaw provision sd --preset preset.toml --device /media/$USER/bootfs --wifi-ssid Home --wifi-password secret
+  zeroclaw provision cloud-init --preset preset.toml --output ./seed --workspace-files ./my-workspace")]
+    Provision {
+        #[command(subcommand)]
+        provision_command: ProvisionCommands,
     },
 
     /// Start the AI agent loop
@@ -173,7 +255,8 @@ Examples:
   zeroclaw agent                              # interactive session
   zeroclaw agent -m \"Summarize today's logs\"  # single message
   zeroclaw agent -p anthropic --model claude-sonnet-4-20250514
-  zeroclaw agent --peripheral nucleo-f401re:/dev/ttyACM0")]
+  zeroclaw agent --peripheral nucleo-f401re:/dev/ttyACM0
+  zeroclaw agent --dry-run                    # tools describe, never execute")]
     Agent {
         /// Single message mode (don't enter interactive mode)
         #[arg(short, long)]
@@ -194,6 +277,10 @@ Examples:
         /// Attach a peripheral (board:path, e.g. nucleo-f401re:/dev/ttyACM0)
         #[arg(long)]
         peripheral: Vec<String>,
+
+        /// Describe tool calls instead of executing them (toggle with /dry-run)
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Start the gateway server (webhooks, websockets)
@@ -217,6 +304,10 @@ Examples:
         /// Host to bind to; defaults to config gateway.host
         #[arg(long)]
         host: Option<String>,
+
+        /// Start without the [gateway.selftest] startup checks
+        #[arg(long)]
+        skip_selftest: bool,
     },
 
     /// Start long-running autonomous runtime (gateway + channels + heartbeat + scheduler)
@@ -243,6 +334,10 @@ Examples:
         /// Host to bind to; defaults to config gateway.host
         #[arg(long)]
         host: Option<String>,
+
+        /// Start without the [gateway.selftest] startup checks
+        #[arg(long)]
+        skip_selftest: bool,
     },
 
     /// Manage OS service lifecycle (launchd/systemd user service)
@@ -272,9 +367,11 @@ Examples:
     /// - `zeroclaw estop --level domain-block --domain "*.chase.com"`
     /// - `zeroclaw estop --level tool-freeze --tool shell --tool browser`
     /// - `zeroclaw estop status`
+    /// - `zeroclaw estop status --manage`
     /// - `zeroclaw estop resume --network`
     /// - `zeroclaw estop resume --domain "*.chase.com"`
     /// - `zeroclaw estop resume --tool shell`
+    /// - `zeroclaw estop engage --remote https://gateway.example.com --token <TOKEN>`
     Estop {
         #[command(subcommand)]
         estop_command: Option<EstopSubcommands>,
@@ -357,6 +454,31 @@ Examples:
         skill_command: SkillCommands,
     },
 
+    /// Import conversation history from a ChatGPT or Claude data export
+    #[command(long_about = "\
+Import conversation history from another assistant.
+
+Reads the official ChatGPT or Claude data export (the .zip, its extracted \
+folder, or conversations.json) and stores each conversation as a \
+conversation memory tagged with its source. Re-running skips conversations \
+that have not changed.
+
+Examples:
+  zeroclaw import ~/Downloads/chatgpt-export.zip
+  zeroclaw import ~/Downloads/claude-export --dry-run")]
+    Import {
+        /// Export .zip, extracted export folder, or conversations.json
+        path: std::path::PathBuf,
+
+        /// Export format (detected from the file when omitted)
+        #[arg(long, value_enum)]
+        format: Option<conversation_import::ExportFormat>,
+
+        /// Preview what would be imported without writing memory
+        #[arg(long)]
+        dry_run: bool,
+    },
+
     /// Migrate data from other agent runtimes
     Migrate {
         #[command(subcommand)]
@@ -405,7 +527,22 @@ Examples:
         peripheral_command: zeroclaw::PeripheralCommands,
     },
 
-    /// Manage agent memory (list, get, stats, clear)
+    /// Query recorded sensor time-series
+    #[command(long_about = "\
+Query sensor readings recorded by the daemon.
+
+Enable [peripherals.recorder] to sample calibrated sensors on serial \
+boards into workspace/sensors/readings.db.
+
+Examples:
+  zeroclaw sensors query --last 24h
+  zeroclaw sensors query --last 7d --pin 0")]
+    Sensors {
+        #[command(subcommand)]
+        sensors_command: SensorCommands,
+    },
+
+    /// Manage agent memory (list, get, stats, compact, clear)
     #[command(long_about = "\
 Manage agent memory entries.
 
@@ -415,6 +552,8 @@ batch clearing with confirmation.
 
 Examples:
   zeroclaw memory stats
+  zeroclaw memory stats --deep
+  zeroclaw memory compact --yes
   zeroclaw memory list
   zeroclaw memory list --category core --limit 10
   zeroclaw memory get <key>
@@ -424,22 +563,165 @@ Examples:
         memory_command: MemoryCommands,
     },
 
+    /// Sync the workspace with a git remote
+    #[command(long_about = "\
+Sync the workspace with a git remote.
+
+Commits workspace Markdown (identity files, daily notes, memory \
+snapshots), rebases onto [workspace_sync].remote, and pushes. On a \
+rebase conflict the local commit is kept and nothing is overwritten.
+
+Examples:
+  zeroclaw workspace sync
+  zeroclaw workspace sync --no-push")]
+    Workspace {
+        #[command(subcommand)]
+        workspace_command: WorkspaceCommands,
+    },
+
+    /// Create, list, and restore encrypted backups
+    #[command(long_about = "\
+Create, list, and restore encrypted backups.
+
+Archives contain the redacted config, workspace files, and a snapshot \
+of the memory database, encrypted with [backup].passphrase (or \
+ZEROCLAW_BACKUP_PASSPHRASE) and stored on the [backup] target: a local \
+directory, S3-compatible bucket, or WebDAV server.
+
+Examples:
+  zeroclaw backup run
+  zeroclaw backup list
+  zeroclaw backup restore latest
+  zeroclaw backup restore zeroclaw-backup-20260101T030000Z.zcbak --force")]
+    Backup {
+        #[command(subcommand)]
+        backup_command: BackupCommands,
+    },
+
+    /// Switch offline mode on or off
+    #[command(long_about = "\
+Switch offline mode on or off.
+
+In offline mode cloud providers refuse requests unless \
+[connectivity].offline_provider names a local provider to use instead, \
+outbound announcements are held, and the daemon starts without channel \
+listeners. The interactive agent keeps working with a local provider. \
+Turning it off lets a running daemon send what it held.
+
+Examples:
+  zeroclaw offline on
+  zeroclaw offline status
+  zeroclaw offline off")]
+    Offline {
+        #[command(subcommand)]
+        offline_command: OfflineCommands,
+    },
+
+    /// Show UPS / battery state
+    #[command(long_about = "\
+Show UPS / battery state.
+
+Reads the [power] source once: a NUT server (upsd) or \
+/sys/class/power_supply. With [power].enabled the daemon polls the same \
+source and reacts to power loss, low battery and restored power.
+
+Examples:
+  zeroclaw power status")]
+    Power {
+        #[command(subcommand)]
+        power_command: PowerCommands,
+    },
+
+    /// Purge stored data about a person
+    #[command(long_about = "\
+Purge stored data about a person.
+
+`forget` deletes conversation memories keyed by the sender ID and session \
+files named after them, redacts their IDs in audit log entries, then \
+prints a deletion report. Identities are given as \
+<channel>:<sender> (e.g. telegram:123456789) or a bare sender ID that \
+matches on every channel.
+
+Examples:
+  zeroclaw privacy forget telegram:123456789 --dry-run
+  zeroclaw privacy forget telegram:123456789 discord:987654321 --yes
+  zeroclaw privacy forget alice --json")]
+    Privacy {
+        #[command(subcommand)]
+        privacy_command: PrivacyCommands,
+    },
+
     /// Manage configuration
     #[command(long_about = "\
 Manage ZeroClaw configuration.
 
 Inspect and export configuration settings. Use 'schema' to dump \
 the full JSON Schema for the config file, which documents every \
-available key, type, and default value.
+available key, type, and default value, or 'browse' to explore it \
+section by section.
 
 Examples:
   zeroclaw config schema              # print JSON Schema to stdout
-  zeroclaw config schema > schema.json")]
+  zeroclaw config schema > schema.json
+  zeroclaw config browse              # interactive field browser
+  zeroclaw config browse --search port")]
     Config {
         #[command(subcommand)]
         config_command: ConfigCommands,
     },
 
+    /// Show token usage and estimated cost per provider and model
+    #[command(long_about = "\
+Show token usage and estimated cost per provider and model.
+
+Reads the usage ledger (state/costs.jsonl), which records every provider \
+response while [cost] enabled = true. Usage is grouped by UTC day, or by \
+week with --weekly, and followed by today's and this month's spend against \
+the [cost] limits.
+
+Examples:
+  zeroclaw usage
+  zeroclaw usage --last 30
+  zeroclaw usage --weekly --last 4
+  zeroclaw usage --json")]
+    Usage {
+        /// Group by week (starting Monday) instead of by day
+        #[arg(long)]
+        weekly: bool,
+
+        /// Number of days (or weeks with --weekly) to include
+        #[arg(long, default_value_t = 7)]
+        last: u32,
+
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Re-issue a provider request captured with --record-llm
+    #[command(long_about = "\
+Re-issue a provider request captured with --record-llm.
+
+The recorded provider and model are used unless overridden. The response \
+is printed along with timing and whether its text matches the recording.
+
+Examples:
+  zeroclaw --record-llm ./llm-trace agent -m \"hello\"
+  zeroclaw replay ./llm-trace/0001-openrouter-chat.json
+  zeroclaw replay ./llm-trace/0001-openrouter-chat.json --provider anthropic")]
+    Replay {
+        /// Recording file written by --record-llm
+        file: std::path::PathBuf,
+
+        /// Send to a different provider than the one recorded
+        #[arg(long)]
+        provider: Option<String>,
+
+        /// Use a different model than the one recorded
+        #[arg(long)]
+        model: Option<String>,
+    },
+
     /// Generate shell completion script to stdout
     #[command(long_about = "\
 Generate shell completion scripts for `zeroclaw`.
@@ -461,12 +743,48 @@ Examples:
 enum ConfigCommands {
     /// Dump the full configuration JSON Schema to stdout
     Schema,
+    /// Browse config fields with their type, default and description
+    Browse {
+        /// Start from fields whose path or description contains this text
+        #[arg(long)]
+        search: Option<String>,
+    },
+    /// Edit config.toml: view it highlighted, change values inline or in
+    /// $EDITOR, validate against the schema, and save with a backup
+    Edit,
 }
 
 #[derive(Subcommand, Debug)]
 enum EstopSubcommands {
     /// Print current estop status.
-    Status,
+    Status {
+        /// After printing, offer to engage kill-all or network-kill, freeze a
+        /// tool, or release an engaged level (each with a confirmation).
+        #[arg(long)]
+        manage: bool,
+    },
+    /// Engage an estop level locally (same as bare `zeroclaw estop`) or on a remote gateway.
+    Engage {
+        /// Level to engage.
+        #[arg(long, value_enum)]
+        level: Option<EstopLevelArg>,
+        /// Domain pattern(s) for `domain-block` (repeatable).
+        #[arg(long = "domain")]
+        domains: Vec<String>,
+        /// Tool name(s) for `tool-freeze` (repeatable).
+        #[arg(long = "tool")]
+        tools: Vec<String>,
+        /// Gateway base URL to trip instead of the local state file.
+        /// Only `kill-all` and `network-kill` can be engaged remotely.
+        #[arg(long, value_name = "GATEWAY_URL")]
+        remote: Option<String>,
+        /// Gateway bearer token for `--remote` (default: `ZEROCLAW_GATEWAY_TOKEN`).
+        #[arg(long)]
+        token: Option<String>,
+        /// Reason included in owner-channel notices.
+        #[arg(long)]
+        reason: Option<String>,
+    },
     /// Resume from an engaged estop level.
     Resume {
         /// Resume only network kill.
@@ -561,10 +879,24 @@ enum AuthCommands {
         #[arg(long)]
         profile: String,
     },
+    /// Replace the token of an existing token profile
+    Rotate {
+        /// Provider
+        #[arg(long)]
+        provider: String,
+        /// Profile name (default: default)
+        #[arg(long, default_value = "default")]
+        profile: String,
+        /// New token value (if omitted, read interactively)
+        #[arg(long)]
+        token: Option<String>,
+    },
     /// List auth profiles
     List,
     /// Show auth status with active profile and token expiry info
     Status,
+    /// Add, activate, rotate and delete profiles interactively
+    Manage,
 }
 
 #[derive(Subcommand, Debug)]
@@ -596,6 +928,8 @@ enum ModelCommands {
     },
     /// Show current model configuration and cache status
     Status,
+    /// Interactively pick a provider and model and save them as the default
+    Switch,
 }
 
 #[derive(Subcommand, Debug)]
@@ -609,6 +943,11 @@ enum DoctorCommands {
         /// Prefer cached catalogs when available (skip forced live refresh)
         #[arg(long)]
         use_cache: bool,
+
+        /// Send tiny test requests to the configured model to check streaming,
+        /// tool calling, JSON mode, and vision
+        #[arg(long)]
+        probe_live: bool,
     },
     /// Query runtime trace events (tool diagnostics and model replies)
     Traces {
@@ -625,6 +964,12 @@ enum DoctorCommands {
         #[arg(long, default_value = "20")]
         limit: usize,
     },
+    /// Check clock drift against NTP and validate configured timezones
+    Time {
+        /// Rewrite an invalid USER.md timezone and print suggested fixes
+        #[arg(long)]
+        fix: bool,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -643,7 +988,23 @@ enum MemoryCommands {
     /// Get a specific memory entry by key
     Get { key: String },
     /// Show memory backend statistics and health
-    Stats,
+    Stats {
+        /// Include index sizes, embedding coverage, namespaces, and fragmentation
+        #[arg(long)]
+        deep: bool,
+    },
+    /// Vacuum/optimize the memory store to reclaim space
+    Compact {
+        /// Skip confirmation prompt
+        #[arg(long)]
+        yes: bool,
+    },
+    /// Re-embed every entry with the current embedding settings (batched on Qdrant)
+    Reembed {
+        /// Skip confirmation prompt
+        #[arg(long)]
+        yes: bool,
+    },
     /// Clear memories by category, by key, or clear all
     Clear {
         /// Delete a single entry by key (supports prefix match)
@@ -676,20 +1037,45 @@ async fn main() -> Result<()> {
         std::env::set_var("ZEROCLAW_CONFIG_DIR", config_dir);
     }
 
-    // Completions must remain stdout-only and should not load config or initialize logging.
+    if let Some(profile) = &cli.profile {
+        config::schema::validate_profile_name(profile.trim())?;
+        std::env::set_var("ZEROCLAW_PROFILE", profile.trim());
+    }
+
+    // Each runtime starts its own recorder on this directory; fail before any
+    // command runs if it cannot be created.
+    if let Some(dir) = &cli.record_llm {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create LLM record directory {}", dir.display()))?;
+    }
+
+    // Completions must remain stdout-only and should not initialize logging.
     // This avoids warnings/log lines corrupting sourced completion scripts.
+    // Config is read best-effort, only to offer provider/model/channel names.
     if let Commands::Completions { shell } = &cli.command {
+        let config = Config::load_or_init().await.ok();
+        let values = CompletionValues::from_config(config.as_ref());
         let mut stdout = std::io::stdout().lock();
-        write_shell_completion(*shell, &mut stdout)?;
+        write_shell_completion(*shell, &values, &mut stdout)?;
         return Ok(());
     }
 
-    // Initialize logging - respects RUST_LOG env var, defaults to INFO
-    let subscriber = fmt::Subscriber::builder()
-        .with_env_filter(
-            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
-        )
-        .finish();
+    // Initialize logging - respects RUST_LOG env var, defaults to INFO.
+    // The filter stays reloadable so `!debug on|off` can change it at runtime.
+    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let base_filter = env_filter.to_string();
+    let builder = fmt::Subscriber::builder()
+        .with_env_filter(env_filter)
+        .with_filter_reloading();
+    let reload_handle = builder.reload_handle();
+    observability::debug_mode::install_log_reload(
+        base_filter,
+        Box::new(move |directives| {
+            reload_handle.reload(EnvFilter::try_new(directives)?)?;
+            Ok(())
+        }),
+    );
+    let subscriber = builder.finish();
 
     tracing::subscriber::set_global_default(subscriber).expect("setting default subscriber failed");
 
@@ -705,19 +1091,34 @@ async fn main() -> Result<()> {
         provider,
         model,
         memory,
+        theme,
+        locale,
+        no_tui,
     } = &cli.command
     {
-        let interactive = *interactive;
         let force = *force;
         let channels_only = *channels_only;
+        let interactive = *interactive || (*no_tui && !channels_only);
         let api_key = api_key.clone();
         let provider = provider.clone();
         let model = model.clone();
         let memory = memory.clone();
+        onboard::prompt::set_plain(*no_tui);
+        let theme = theme
+            .as_deref()
+            .and_then(UiTheme::parse)
+            .or(no_tui.then_some(UiTheme::NoColor));
+        onboard::theme::init_for_onboarding(theme).await;
+        onboard::i18n::init_for_onboarding(locale.as_deref().and_then(UiLocale::parse)).await;
 
         if interactive && channels_only {
             bail!("Use either --interactive or --channels-only, not both");
         }
+        if (interactive || channels_only) && !std::io::stdin().is_terminal() {
+            bail!(
+                "The interactive wizard needs a TTY. For containers and headless installs, use `zeroclaw bootstrap` with ZEROCLAW_* env vars or --preset."
+            );
+        }
         if channels_only
             && (api_key.is_some() || provider.is_some() || model.is_some() || memory.is_some())
         {
@@ -726,10 +1127,10 @@ async fn main() -> Result<()> {
         if channels_only && force {
             bail!("--channels-only does not accept --force");
         }
-        let config = if channels_only {
-            onboard::run_channels_repair_wizard().await
+        let mut config = if channels_only {
+            Box::pin(onboard::run_channels_repair_wizard()).await
         } else if interactive {
-            onboard::run_wizard(force).await
+            Box::pin(onboard::run_wizard(force)).await
         } else {
             onboard::run_quick_setup(
                 api_key.as_deref(),
@@ -740,16 +1141,65 @@ async fn main() -> Result<()> {
             )
             .await
         }?;
+        config.record_llm_dir.clone_from(&cli.record_llm);
         // Auto-start channels if user said yes during wizard
         if std::env::var("ZEROCLAW_AUTOSTART_CHANNELS").as_deref() == Ok("1") {
-            channels::start_channels(config).await?;
+            let recorder = providers::recorder::Recorder::new(&config);
+            Box::pin(channels::start_channels(
+                config,
+                daemon::shutdown::Drain::default(),
+                health::metrics::RuntimeMetrics::default(),
+                notifications::Toasts::default(),
+                recorder,
+            ))
+            .await?;
         }
         return Ok(());
     }
 
+    if let Commands::Bootstrap {
+        preset,
+        force,
+        daemon,
+        no_start,
+    } = &cli.command
+    {
+        let mut config = onboard::run_bootstrap(preset.as_deref(), *force).await?;
+        if *no_start {
+            return Ok(());
+        }
+        config.record_llm_dir.clone_from(&cli.record_llm);
+        let host = config.gateway.host.clone();
+        let port = config.gateway.port;
+        gateway::selftest::run_on_start(&config).await?;
+        return if *daemon {
+            info!("🧠 Starting ZeroClaw Daemon on {host}:{port}");
+            daemon::run(config, host, port).await
+        } else {
+            info!("🚀 Starting ZeroClaw Gateway on {host}:{port}");
+            let recorder = providers::recorder::Recorder::new(&config);
+            gateway::run_gateway(
+                &host,
+                port,
+                config,
+                daemon::shutdown::Drain::default(),
+                health::metrics::RuntimeMetrics::default(),
+                notifications::Toasts::default(),
+                recorder,
+            )
+            .await
+        };
+    }
+
+    if let Commands::Provision { provision_command } = &cli.command {
+        return provision::handle_command(provision_command.clone());
+    }
+
     // All other commands need config loaded first
     let mut config = Config::load_or_init().await?;
     config.apply_env_overrides();
+    config.record_llm_dir.clone_from(&cli.record_llm);
+    onboard::theme::set_theme(config.ui.theme);
     observability::runtime_trace::init_from_config(&config.observability, &config.workspace_dir);
     if config.security.otp.enabled {
         let config_dir = config
@@ -766,41 +1216,89 @@ async fn main() -> Result<()> {
     }
 
     match cli.command {
-        Commands::Onboard { .. } => unreachable!(),
+        Commands::Onboard { .. } | Commands::Bootstrap { .. } | Commands::Provision { .. } => {
+            unreachable!()
+        }
         Commands::Completions { .. } => unreachable!(),
 
-        Commands::Agent {
-            message,
+        Commands::Usage { weekly, last, json } => {
+            let granularity = if weekly {
+                cost::usage::Granularity::Weekly
+            } else {
+                cost::usage::Granularity::Daily
+            };
+            cost::usage::run_cli(&config, granularity, last, json)
+        }
+
+        Commands::Replay {
+            file,
             provider,
             model,
-            temperature,
-            peripheral,
-        } => agent::run(
-            config,
+        } => {
+            providers::recorder::run_replay(&config, &file, provider.as_deref(), model.as_deref())
+                .await
+        }
+
+        Commands::Agent {
             message,
             provider,
             model,
             temperature,
             peripheral,
-            true,
-        )
-        .await
-        .map(|_| ()),
+            dry_run,
+        } => {
+            config.agent.dry_run |= dry_run;
+            Box::pin(agent::run(
+                config,
+                message,
+                provider,
+                model,
+                temperature,
+                peripheral,
+                true,
+            ))
+            .await
+            .map(|_| ())
+        }
 
-        Commands::Gateway { port, host } => {
+        Commands::Gateway {
+            port,
+            host,
+            skip_selftest,
+        } => {
             let port = port.unwrap_or(config.gateway.port);
             let host = host.unwrap_or_else(|| config.gateway.host.clone());
+            if !skip_selftest {
+                gateway::selftest::run_on_start(&config).await?;
+            }
             if port == 0 {
                 info!("🚀 Starting ZeroClaw Gateway on {host} (random port)");
             } else {
                 info!("🚀 Starting ZeroClaw Gateway on {host}:{port}");
             }
-            gateway::run_gateway(&host, port, config).await
+            let recorder = providers::recorder::Recorder::new(&config);
+            gateway::run_gateway(
+                &host,
+                port,
+                config,
+                daemon::shutdown::Drain::default(),
+                health::metrics::RuntimeMetrics::default(),
+                notifications::Toasts::default(),
+                recorder,
+            )
+            .await
         }
 
-        Commands::Daemon { port, host } => {
+        Commands::Daemon {
+            port,
+            host,
+            skip_selftest,
+        } => {
             let port = port.unwrap_or(config.gateway.port);
             let host = host.unwrap_or_else(|| config.gateway.host.clone());
+            if !skip_selftest {
+                gateway::selftest::run_on_start(&config).await?;
+            }
             if port == 0 {
                 info!("🧠 Starting ZeroClaw Daemon on {host} (random port)");
             } else {
@@ -848,6 +1346,34 @@ async fn main() -> Result<()> {
                 effective_memory_backend,
                 if config.memory.auto_save { "on" } else { "off" }
             );
+            if connectivity::manual_offline(&config.workspace_dir) {
+                println!("🌐 Connectivity:   offline mode on (zeroclaw offline off to leave)");
+            } else if config.connectivity.enabled {
+                let outages = connectivity::recent_outages(&config.workspace_dir, 3);
+                if outages.is_empty() {
+                    println!("🌐 Connectivity:   monitored, no outages recorded");
+                } else {
+                    println!("🌐 Connectivity:   recent outages");
+                    for outage in &outages {
+                        println!("     {}", outage.describe());
+                    }
+                }
+            } else {
+                println!("🌐 Connectivity:   not monitored");
+            }
+            if config.agent.watchdog.enabled {
+                let incidents = agent::watchdog::recent_incidents(&config.workspace_dir, 3);
+                if incidents.is_empty() {
+                    println!("⏱️  Watchdog:       on, no incidents recorded");
+                } else {
+                    println!("⏱️  Watchdog:       recent incidents");
+                    for incident in &incidents {
+                        println!("     {}", incident.describe());
+                    }
+                }
+            } else {
+                println!("⏱️  Watchdog:       off");
+            }
 
             println!();
             println!("Security:");
@@ -908,7 +1434,7 @@ async fn main() -> Result<()> {
             level,
             domains,
             tools,
-        } => handle_estop_command(&config, estop_command, level, domains, tools),
+        } => handle_estop_command(&config, estop_command, level, domains, tools).await,
 
         Commands::Cron { cron_command } => cron::handle_command(cron_command, &config),
 
@@ -932,6 +1458,7 @@ async fn main() -> Result<()> {
             }
             ModelCommands::Set { model } => onboard::run_models_set(&config, &model).await,
             ModelCommands::Status => onboard::run_models_status(&config).await,
+            ModelCommands::Switch => onboard::run_models_switch(&config).await,
         },
 
         Commands::Providers => {
@@ -979,7 +1506,8 @@ async fn main() -> Result<()> {
             Some(DoctorCommands::Models {
                 provider,
                 use_cache,
-            }) => doctor::run_models(&config, provider.as_deref(), use_cache).await,
+                probe_live,
+            }) => doctor::run_models(&config, provider.as_deref(), use_cache, probe_live).await,
             Some(DoctorCommands::Traces {
                 id,
                 event,
@@ -992,12 +1520,25 @@ async fn main() -> Result<()> {
                 contains.as_deref(),
                 limit,
             ),
+            Some(DoctorCommands::Time { fix }) => doctor::run_time(&config, fix),
             None => doctor::run(&config),
         },
 
         Commands::Channel { channel_command } => match channel_command {
-            ChannelCommands::Start => channels::start_channels(config).await,
-            ChannelCommands::Doctor => channels::doctor_channels(config).await,
+            ChannelCommands::Start => {
+                let recorder = providers::recorder::Recorder::new(&config);
+                Box::pin(channels::start_channels(
+                    config,
+                    daemon::shutdown::Drain::default(),
+                    health::metrics::RuntimeMetrics::default(),
+                    notifications::Toasts::default(),
+                    recorder,
+                ))
+                .await
+            }
+            ChannelCommands::Doctor { channel } => {
+                Box::pin(channels::doctor_channels(config, channel)).await
+            }
             other => channels::handle_command(other, &config).await,
         },
 
@@ -1007,10 +1548,34 @@ async fn main() -> Result<()> {
 
         Commands::Skills { skill_command } => skills::handle_command(skill_command, &config),
 
+        Commands::Import {
+            path,
+            format,
+            dry_run,
+        } => conversation_import::run(&config, &path, format, dry_run).await,
+
         Commands::Migrate { migrate_command } => {
             migration::handle_command(migrate_command, &config).await
         }
 
+        Commands::Workspace { workspace_command } => {
+            workspace_sync::handle_command(workspace_command, &config).await
+        }
+
+        Commands::Backup { backup_command } => {
+            backup::handle_command(backup_command, &config).await
+        }
+
+        Commands::Offline { offline_command } => {
+            connectivity::handle_command(offline_command, &config)
+        }
+
+        Commands::Power { power_command } => power::handle_command(power_command, &config).await,
+
+        Commands::Privacy { privacy_command } => {
+            privacy::handle_command(privacy_command, &config).await
+        }
+
         Commands::Memory { memory_command } => {
             memory::cli::handle_command(memory_command, &config).await
         }
@@ -1025,26 +1590,68 @@ async fn main() -> Result<()> {
             peripherals::handle_command(peripheral_command.clone(), &config).await
         }
 
+        Commands::Sensors { sensors_command } => match sensors_command {
+            SensorCommands::Query { last, board, pin } => {
+                peripherals::recorder::print_query(&config, &last, board.as_deref(), pin)
+            }
+            SensorCommands::Alerts => peripherals::alerts::print_alerts(&config),
+            SensorCommands::Ack { name } => peripherals::alerts::acknowledge(&config, &name),
+        },
+
         Commands::Config { config_command } => match config_command {
             ConfigCommands::Schema => {
-                let schema = schemars::schema_for!(config::Config);
-                println!(
-                    "{}",
-                    serde_json::to_string_pretty(&schema).expect("failed to serialize JSON Schema")
-                );
-                Ok(())
+                let schema = config::config_json_schema();
+                // Paged on a terminal; piped output is printed as is.
+                onboard::preview::page(
+                    &serde_json::to_string_pretty(&schema)
+                        .expect("failed to serialize JSON Schema"),
+                )
             }
+            ConfigCommands::Browse { search } => {
+                onboard::schema_browser::browse(&config::config_json_schema(), search.as_deref())
+            }
+            ConfigCommands::Edit => onboard::config_editor::run(&config.config_path),
         },
     }
 }
 
-fn handle_estop_command(
+async fn handle_estop_command(
     config: &Config,
     estop_command: Option<EstopSubcommands>,
     level: Option<EstopLevelArg>,
     domains: Vec<String>,
     tools: Vec<String>,
 ) -> Result<()> {
+    if let Some(EstopSubcommands::Engage {
+        level,
+        domains,
+        tools,
+        remote: Some(gateway_url),
+        token,
+        reason,
+    }) = &estop_command
+    {
+        // The remote gateway enforces its own estop config; local settings do not apply.
+        let engage_level = build_engage_level(*level, domains.clone(), tools.clone())?;
+        if !matches!(
+            engage_level,
+            security::EstopLevel::KillAll | security::EstopLevel::NetworkKill
+        ) {
+            bail!("--remote only supports --level kill-all or --level network-kill");
+        }
+        let token = token
+            .clone()
+            .or_else(|| std::env::var("ZEROCLAW_GATEWAY_TOKEN").ok())
+            .filter(|token| !token.trim().is_empty())
+            .context("--remote requires --token or ZEROCLAW_GATEWAY_TOKEN")?;
+        let state =
+            security::estop::engage_remote(gateway_url, &token, &engage_level, reason.as_deref())
+                .await?;
+        println!("Remote estop engaged on {gateway_url}.");
+        print_estop_status(&state);
+        return Ok(());
+    }
+
     if !config.security.estop.enabled {
         bail!("Emergency stop is disabled. Enable [security.estop].enabled = true in config.toml");
     }
@@ -1056,10 +1663,13 @@ fn handle_estop_command(
     let mut manager = security::EstopManager::load(&config.security.estop, config_dir)?;
 
     match estop_command {
-        Some(EstopSubcommands::Status) => {
+        Some(EstopSubcommands::Status { manage: false }) => {
             print_estop_status(&manager.status());
             Ok(())
         }
+        Some(EstopSubcommands::Status { manage: true }) => {
+            manage_estop(config, config_dir, &mut manager).await
+        }
         Some(EstopSubcommands::Resume {
             network,
             domains,
@@ -1067,48 +1677,196 @@ fn handle_estop_command(
             otp,
         }) => {
             let selector = build_resume_selector(network, domains, tools)?;
-            let mut otp_code = otp;
-            let otp_validator = if config.security.estop.require_otp_to_resume {
-                if !config.security.otp.enabled {
-                    bail!(
-                        "security.estop.require_otp_to_resume=true but security.otp.enabled=false"
-                    );
-                }
-                if otp_code.is_none() {
-                    let entered = Password::new()
-                        .with_prompt("Enter OTP code")
-                        .allow_empty_password(false)
-                        .interact()?;
-                    otp_code = Some(entered);
-                }
-
-                let store = security::SecretStore::new(config_dir, config.secrets.encrypt);
-                let (validator, enrollment_uri) =
-                    security::OtpValidator::from_config(&config.security.otp, config_dir, &store)?;
-                if let Some(uri) = enrollment_uri {
-                    println!("Initialized OTP secret for ZeroClaw.");
-                    println!("Enrollment URI: {uri}");
-                }
-                Some(validator)
-            } else {
-                None
-            };
-
+            let (otp_code, otp_validator) = estop_resume_otp(config, config_dir, otp)?;
             manager.resume(selector, otp_code.as_deref(), otp_validator.as_ref())?;
             println!("Estop resume completed.");
             print_estop_status(&manager.status());
             Ok(())
         }
-        None => {
-            let engage_level = build_engage_level(level, domains, tools)?;
-            manager.engage(engage_level)?;
-            println!("Estop engaged.");
-            print_estop_status(&manager.status());
-            Ok(())
+        Some(EstopSubcommands::Engage {
+            level,
+            domains,
+            tools,
+            reason,
+            ..
+        }) => engage_local_estop(config, &mut manager, level, domains, tools, reason).await,
+        None => engage_local_estop(config, &mut manager, level, domains, tools, None).await,
+    }
+}
+
+/// OTP code and validator for an estop resume, prompting for the code when
+/// `require_otp_to_resume` is set and none was given.
+fn estop_resume_otp(
+    config: &Config,
+    config_dir: &std::path::Path,
+    otp: Option<String>,
+) -> Result<(Option<String>, Option<security::OtpValidator>)> {
+    if !config.security.estop.require_otp_to_resume {
+        return Ok((otp, None));
+    }
+    if !config.security.otp.enabled {
+        bail!("security.estop.require_otp_to_resume=true but security.otp.enabled=false");
+    }
+    let otp_code = match otp {
+        Some(code) => code,
+        None => Password::new()
+            .with_prompt("Enter OTP code")
+            .allow_empty_password(false)
+            .interact()?,
+    };
+
+    let store = security::SecretStore::new(config_dir, config.secrets.encrypt);
+    let (validator, enrollment_uri) =
+        security::OtpValidator::from_config(&config.security.otp, config_dir, &store)?;
+    if let Some(uri) = enrollment_uri {
+        println!("Initialized OTP secret for ZeroClaw.");
+        println!("Enrollment URI: {uri}");
+    }
+    Ok((Some(otp_code), Some(validator)))
+}
+
+enum EstopAction {
+    Engage(security::EstopLevel),
+    FreezeTool,
+    Release(security::ResumeSelector),
+    Done,
+}
+
+/// Actions offered by `estop status --manage` for the current state.
+fn estop_actions(state: &security::EstopState) -> Vec<(String, EstopAction)> {
+    let mut actions = Vec::new();
+    if !state.kill_all {
+        actions.push((
+            "Engage kill-all".to_string(),
+            EstopAction::Engage(security::EstopLevel::KillAll),
+        ));
+    }
+    if !state.network_kill {
+        actions.push((
+            "Engage network-kill".to_string(),
+            EstopAction::Engage(security::EstopLevel::NetworkKill),
+        ));
+    }
+    actions.push(("Freeze a tool...".to_string(), EstopAction::FreezeTool));
+    if state.kill_all {
+        actions.push((
+            "Release kill-all".to_string(),
+            EstopAction::Release(security::ResumeSelector::KillAll),
+        ));
+    }
+    if state.network_kill {
+        actions.push((
+            "Release network-kill".to_string(),
+            EstopAction::Release(security::ResumeSelector::Network),
+        ));
+    }
+    for tool in &state.frozen_tools {
+        actions.push((
+            format!("Unfreeze tool {tool}"),
+            EstopAction::Release(security::ResumeSelector::Tools(vec![tool.clone()])),
+        ));
+    }
+    for domain in &state.blocked_domains {
+        actions.push((
+            format!("Unblock domain {domain}"),
+            EstopAction::Release(security::ResumeSelector::Domains(vec![domain.clone()])),
+        ));
+    }
+    actions.push(("Done".to_string(), EstopAction::Done));
+    actions
+}
+
+/// Status screen with engage/release actions for an operator at the terminal.
+async fn manage_estop(
+    config: &Config,
+    config_dir: &std::path::Path,
+    manager: &mut security::EstopManager,
+) -> Result<()> {
+    if !std::io::stdin().is_terminal() {
+        bail!("`zeroclaw estop status --manage` needs an interactive terminal");
+    }
+    loop {
+        let state = manager.status();
+        print_estop_status(&state);
+        println!();
+        let mut actions = estop_actions(&state);
+        let labels: Vec<&str> = actions.iter().map(|(label, _)| label.as_str()).collect();
+        let choice = dialoguer::Select::new()
+            .with_prompt("Estop action")
+            .items(&labels)
+            .default(0)
+            .interact()?;
+        let (label, action) = actions.swap_remove(choice);
+
+        let level = match action {
+            EstopAction::Done => return Ok(()),
+            EstopAction::Engage(level) => level,
+            EstopAction::FreezeTool => {
+                let tool: String = Input::new()
+                    .with_prompt("Tool to freeze")
+                    .allow_empty(true)
+                    .interact_text()?;
+                if tool.trim().is_empty() {
+                    continue;
+                }
+                security::EstopLevel::ToolFreeze(vec![tool.trim().to_string()])
+            }
+            EstopAction::Release(selector) => {
+                if !dialoguer::Confirm::new()
+                    .with_prompt(format!("{label}?"))
+                    .default(false)
+                    .interact()?
+                {
+                    continue;
+                }
+                let released = estop_resume_otp(config, config_dir, None).and_then(
+                    |(otp_code, otp_validator)| {
+                        manager.resume(selector, otp_code.as_deref(), otp_validator.as_ref())
+                    },
+                );
+                match released {
+                    Ok(()) => println!("Estop resume completed.\n"),
+                    Err(e) => println!("Release failed: {e}\n"),
+                }
+                continue;
+            }
+        };
+
+        if !dialoguer::Confirm::new()
+            .with_prompt(format!("Engage {}?", level.describe()))
+            .default(false)
+            .interact()?
+        {
+            continue;
+        }
+        match manager.engage(level.clone()) {
+            Ok(()) => {
+                println!("Estop engaged.\n");
+                let notice = security::estop::engagement_notice(&level, "cli", None);
+                security::estop::notify_owners(config, &notice).await;
+            }
+            Err(e) => println!("Engage failed: {e}\n"),
         }
     }
 }
 
+async fn engage_local_estop(
+    config: &Config,
+    manager: &mut security::EstopManager,
+    level: Option<EstopLevelArg>,
+    domains: Vec<String>,
+    tools: Vec<String>,
+    reason: Option<String>,
+) -> Result<()> {
+    let engage_level = build_engage_level(level, domains, tools)?;
+    manager.engage(engage_level.clone())?;
+    println!("Estop engaged.");
+    print_estop_status(&manager.status());
+    let notice = security::estop::engagement_notice(&engage_level, "cli", reason.as_deref());
+    security::estop::notify_owners(config, &notice).await;
+    Ok(())
+}
+
 fn build_engage_level(
     level: Option<EstopLevelArg>,
     domains: Vec<String>,
@@ -1204,11 +1962,103 @@ fn print_estop_status(state: &security::EstopState) {
     }
 }
 
-fn write_shell_completion<W: Write>(shell: CompletionShell, writer: &mut W) -> Result<()> {
+/// Names offered for provider, model and channel arguments in generated
+/// completion scripts. They are a snapshot of the config at generation time.
+#[derive(Debug, Default)]
+struct CompletionValues {
+    providers: Vec<String>,
+    models: Vec<String>,
+    channels: Vec<String>,
+}
+
+impl CompletionValues {
+    fn from_config(config: Option<&Config>) -> Self {
+        let mut providers: Vec<String> = providers::list_providers()
+            .iter()
+            .map(|info| info.name.to_string())
+            .collect();
+        let mut models = Vec::new();
+        let mut channel_names = Vec::new();
+
+        if let Some(config) = config {
+            if let Some(provider) = &config.default_provider {
+                providers.push(provider.clone());
+                models.extend(channels::load_cached_models(
+                    &config.workspace_dir,
+                    provider,
+                ));
+            }
+            models.extend(config.default_model.clone());
+            for route in &config.model_routes {
+                providers.push(route.provider.clone());
+                models.push(route.model.clone());
+                models.push(format!("hint:{}", route.hint));
+            }
+            channel_names.extend(
+                config
+                    .channels_config
+                    .channels_except_webhook()
+                    .iter()
+                    .filter(|(_, configured)| *configured)
+                    .map(|(channel, _)| channel.name().to_ascii_lowercase()),
+            );
+        }
+
+        for values in [&mut providers, &mut models, &mut channel_names] {
+            values.sort();
+            values.dedup();
+        }
+        Self {
+            providers,
+            models,
+            channels: channel_names,
+        }
+    }
+}
+
+/// Attach [`CompletionValues`] as possible values to every `provider`,
+/// `model` and `channel` argument (and `channel remove <name>`). Only used for
+/// script generation; parsing keeps accepting any value.
+fn with_completion_values(
+    mut cmd: clap::Command,
+    values: &CompletionValues,
+    in_channel: bool,
+) -> clap::Command {
+    let subcommands: Vec<String> = cmd
+        .get_subcommands()
+        .map(|sub| sub.get_name().to_string())
+        .collect();
+    for name in subcommands {
+        let nested = in_channel || name == "channel";
+        cmd = cmd.mut_subcommand(&name, |sub| with_completion_values(sub, values, nested));
+    }
+
+    cmd.mut_args(|arg| {
+        let candidates = match arg.get_id().as_str() {
+            "provider" => &values.providers,
+            "model" => &values.models,
+            "channel" => &values.channels,
+            "name" if in_channel => &values.channels,
+            _ => return arg,
+        };
+        if candidates.is_empty() {
+            return arg;
+        }
+        arg.value_parser(clap::builder::PossibleValuesParser::new(
+            candidates.iter().cloned(),
+        ))
+    })
+}
+
+fn write_shell_completion<W: Write>(
+    shell: CompletionShell,
+    values: &CompletionValues,
+    writer: &mut W,
+) -> Result<()> {
     use clap_complete::generate;
     use clap_complete::shells;
 
-    let mut cmd = Cli::command();
+    let mut cmd = with_completion_values(Cli::command(), values, false);
     let bin_name = cmd.get_name().to_string();
 
     match shell {
@@ -1381,6 +2231,130 @@ fn format_expiry(profile: &auth::profiles::AuthProfile) -> String {
     }
 }
 
+/// One line per profile for `auth manage`: active marker, id and kind.
+fn auth_profile_label(data: &auth::profiles::AuthProfilesData, id: &str) -> String {
+    let Some(profile) = data.profiles.get(id) else {
+        return id.to_string();
+    };
+    let active = data
+        .active_profiles
+        .get(&profile.provider)
+        .is_some_and(|active_id| active_id == id);
+    let kind = match profile.kind {
+        auth::profiles::AuthProfileKind::OAuth => format!("oauth, {}", format_expiry(profile)),
+        auth::profiles::AuthProfileKind::Token => "token".to_string(),
+    };
+    format!("{} {id} ({kind})", if active { "*" } else { " " })
+}
+
+/// Interactive profile manager over [`auth::AuthService`]; changes are
+/// written to the same store the other `auth` commands use.
+async fn manage_auth_profiles(auth_service: &auth::AuthService) -> Result<()> {
+    if !std::io::stdin().is_terminal() {
+        bail!("`zeroclaw auth manage` needs an interactive terminal");
+    }
+    loop {
+        let data = auth_service.load_profiles().await?;
+        let ids: Vec<String> = data.profiles.keys().cloned().collect();
+        let mut items: Vec<String> = ids.iter().map(|id| auth_profile_label(&data, id)).collect();
+        items.push("Add a profile...".to_string());
+        items.push("Done".to_string());
+
+        let choice = dialoguer::Select::new()
+            .with_prompt("Auth profiles (* = active for its provider)")
+            .items(&items)
+            .default(0)
+            .interact()?;
+        if choice == ids.len() + 1 {
+            return Ok(());
+        }
+        if choice == ids.len() {
+            let provider = auth::normalize_provider(&read_plain_input("Provider")?)?;
+            let profile: String = Input::new()
+                .with_prompt("Profile name")
+                .default("default".to_string())
+                .interact_text()?;
+            let token = read_auth_input("Paste token")?;
+            if token.is_empty() {
+                println!("Token cannot be empty; nothing saved.\n");
+                continue;
+            }
+            let kind = auth::anthropic_token::detect_auth_kind(&token, None);
+            let metadata = std::collections::HashMap::from([(
+                "auth_kind".to_string(),
+                kind.as_metadata_value().to_string(),
+            )]);
+            let set_active = !data.active_profiles.contains_key(&provider)
+                || dialoguer::Confirm::new()
+                    .with_prompt(format!("Make it the active profile for {provider}?"))
+                    .default(true)
+                    .interact()?;
+            let saved = auth_service
+                .store_provider_token(&provider, profile.trim(), &token, metadata, set_active)
+                .await?;
+            println!("Saved profile {}\n", saved.id);
+            continue;
+        }
+
+        let id = &ids[choice];
+        let profile = &data.profiles[id];
+        let is_active = data
+            .active_profiles
+            .get(&profile.provider)
+            .is_some_and(|active_id| active_id == id);
+        let is_token = profile.kind == auth::profiles::AuthProfileKind::Token;
+        let mut actions = Vec::new();
+        if !is_active {
+            actions.push("Set active");
+        }
+        if is_token {
+            actions.push("Rotate key");
+        }
+        actions.push("Delete");
+        actions.push("Back");
+        let action = dialoguer::Select::new()
+            .with_prompt(id.as_str())
+            .items(&actions)
+            .default(0)
+            .interact()?;
+
+        let result = match actions[action] {
+            "Set active" => auth_service
+                .set_active_profile(&profile.provider, id)
+                .await
+                .map(|_| format!("Active profile for {}: {id}", profile.provider)),
+            "Rotate key" => {
+                let token = read_auth_input("Paste new token")?;
+                if token.is_empty() {
+                    continue;
+                }
+                auth_service
+                    .rotate_token(&profile.provider, id, &token)
+                    .await
+                    .map(|_| format!("Rotated token for {id}"))
+            }
+            "Delete" => {
+                if !dialoguer::Confirm::new()
+                    .with_prompt(format!("Delete {id}?"))
+                    .default(false)
+                    .interact()?
+                {
+                    continue;
+                }
+                auth_service
+                    .remove_profile(&profile.provider, id)
+                    .await
+                    .map(|_| format!("Removed auth profile {id}"))
+            }
+            _ => continue,
+        };
+        match result {
+            Ok(message) => println!("{message}\n"),
+            Err(e) => println!("Failed: {e}\n"),
+        }
+    }
+}
+
 #[allow(clippy::too_many_lines)]
 async fn handle_auth_command(auth_command: AuthCommands, config: &Config) -> Result<()> {
     let auth_service = auth::AuthService::from_config(config);
@@ -1790,6 +2764,27 @@ async fn handle_auth_command(auth_command: AuthCommands, config: &Config) -> Res
             Ok(())
         }
 
+        AuthCommands::Rotate {
+            provider,
+            profile,
+            token,
+        } => {
+            let token = match token {
+                Some(token) => token.trim().to_string(),
+                None => read_auth_input("Paste new token")?,
+            };
+            if token.is_empty() {
+                bail!("Token cannot be empty");
+            }
+            let rotated = auth_service
+                .rotate_token(&provider, &profile, &token)
+                .await?;
+            println!("Rotated token for {}", rotated.id);
+            Ok(())
+        }
+
+        AuthCommands::Manage => manage_auth_profiles(&auth_service).await,
+
         AuthCommands::List => {
             let data = auth_service.load_profiles().await?;
             if data.profiles.is_empty() {
@@ -1906,6 +2901,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn onboard_cli_accepts_known_themes_only() {
+        let cli = Cli::try_parse_from(["zeroclaw", "onboard", "--theme", "high-contrast"])
+            .expect("known theme should parse");
+        match cli.command {
+            Commands::Onboard { theme, .. } => {
+                assert_eq!(
+                    theme.as_deref().and_then(UiTheme::parse),
+                    Some(UiTheme::HighContrast)
+                );
+            }
+            other => panic!("expected onboard command, got {other:?}"),
+        }
+
+        assert!(Cli::try_parse_from(["zeroclaw", "onboard", "--theme", "neon"]).is_err());
+    }
+
     #[test]
     fn completions_cli_parses_supported_shells() {
         for shell in ["bash", "fish", "zsh", "powershell", "elvish"] {
@@ -1918,11 +2930,63 @@ mod tests {
         }
     }
 
+    #[test]
+    fn replay_cli_parses_global_record_flag() {
+        let cli = Cli::try_parse_from([
+            "zeroclaw",
+            "replay",
+            "trace/0001-openrouter-chat.json",
+            "--model",
+            "gpt-4o",
+            "--record-llm",
+            "trace-2",
+        ])
+        .expect("replay invocation should parse");
+
+        assert_eq!(
+            cli.record_llm.as_deref(),
+            Some(std::path::Path::new("trace-2"))
+        );
+        match cli.command {
+            Commands::Replay {
+                file,
+                provider,
+                model,
+            } => {
+                assert_eq!(
+                    file,
+                    std::path::Path::new("trace/0001-openrouter-chat.json")
+                );
+                assert_eq!(provider, None);
+                assert_eq!(model.as_deref(), Some("gpt-4o"));
+            }
+            other => panic!("expected replay command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn usage_command_parses_weekly_window() {
+        let cli = Cli::try_parse_from(["zeroclaw", "usage", "--weekly", "--last", "4"])
+            .expect("usage invocation should parse");
+        match cli.command {
+            Commands::Usage { weekly, last, json } => {
+                assert!(weekly);
+                assert_eq!(last, 4);
+                assert!(!json);
+            }
+            other => panic!("expected usage command, got {other:?}"),
+        }
+    }
+
     #[test]
     fn completion_generation_mentions_binary_name() {
         let mut output = Vec::new();
-        write_shell_completion(CompletionShell::Bash, &mut output)
-            .expect("completion generation should succeed");
+        write_shell_completion(
+            CompletionShell::Bash,
+            &CompletionValues::default(),
+            &mut output,
+        )
+        .expect("completion generation should succeed");
         let script = String::from_utf8(output).expect("completion output should be valid utf-8");
         assert!(
             script.contains("zeroclaw"),
@@ -1930,6 +2994,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn completion_scripts_offer_names_from_config() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.workspace_dir = tmp.path().to_path_buf();
+        config.default_provider = Some("ollama".into());
+        config.default_model = Some("qwen3-coder:30b".into());
+        config.channels_config.telegram = Some(crate::config::schema::TelegramConfig {
+            bot_token: "token".into(),
+            allowed_users: vec![],
+            stream_mode: crate::config::StreamMode::default(),
+            draft_update_interval_ms: 1000,
+            interrupt_on_new_message: false,
+            mention_only: false,
+            quote_replies: false,
+            lightweight_acks: false,
+        });
+        let values = CompletionValues::from_config(Some(&config));
+        assert!(values.providers.iter().any(|p| p == "openrouter"));
+        assert_eq!(values.models, vec!["qwen3-coder:30b".to_string()]);
+        assert_eq!(values.channels, vec!["telegram".to_string()]);
+
+        let mut output = Vec::new();
+        write_shell_completion(CompletionShell::Fish, &values, &mut output)
+            .expect("completion generation should succeed");
+        let script = String::from_utf8(output).expect("completion output should be valid utf-8");
+        assert!(script.contains("qwen3-coder:30b"));
+        assert!(script.contains("telegram"));
+
+        // Parsing still accepts names that were not offered.
+        Cli::try_parse_from(["zeroclaw", "agent", "--model", "other-model"])
+            .expect("unlisted model should still parse");
+    }
+
     #[test]
     fn onboard_cli_accepts_force_flag() {
         let cli = Cli::try_parse_from(["zeroclaw", "onboard", "--force"])
@@ -1961,6 +3059,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cli_parses_estop_remote_engage() {
+        let cli = Cli::try_parse_from([
+            "zeroclaw",
+            "estop",
+            "engage",
+            "--level",
+            "network-kill",
+            "--remote",
+            "https://gw.example.com",
+        ])
+        .expect("estop engage --remote should parse");
+
+        match cli.command {
+            Commands::Estop {
+                estop_command:
+                    Some(EstopSubcommands::Engage {
+                        level,
+                        remote,
+                        token,
+                        ..
+                    }),
+                ..
+            } => {
+                assert_eq!(level, Some(EstopLevelArg::NetworkKill));
+                assert_eq!(remote.as_deref(), Some("https://gw.example.com"));
+                assert!(token.is_none());
+            }
+            other => panic!("expected estop engage command, got {other:?}"),
+        }
+    }
+
     #[test]
     fn cli_parses_estop_resume_domain() {
         let cli = Cli::try_parse_from(["zeroclaw", "estop", "resume", "--domain", "*.chase.com"])
@@ -1974,4 +3104,37 @@ mod tests {
             other => panic!("expected estop resume command, got {other:?}"),
         }
     }
+
+    #[test]
+    fn estop_manage_offers_release_for_engaged_levels() {
+        let cli = Cli::try_parse_from(["zeroclaw", "estop", "status", "--manage"])
+            .expect("estop status --manage should parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Estop {
+                estop_command: Some(EstopSubcommands::Status { manage: true }),
+                ..
+            }
+        ));
+
+        let state = security::EstopState {
+            kill_all: true,
+            frozen_tools: vec!["shell".into()],
+            ..security::EstopState::default()
+        };
+        let labels: Vec<String> = estop_actions(&state)
+            .into_iter()
+            .map(|(label, _)| label)
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                "Engage network-kill",
+                "Freeze a tool...",
+                "Release kill-all",
+                "Unfreeze tool shell",
+                "Done",
+            ]
+        );
+    }
 }
@@ -0,0 +1,186 @@
+//! Auto-save write policy carried by the memory backend.
+//!
+//! The factory wraps backends in [`AutoSaveMemory`] when `[redaction]` or
+//! `[memory.classification]` is enabled, so every writer holding the backend
+//! (agent loop, channels, gateway, `memory_store`) redacts and files entries
+//! the same way without process-wide state.
+
+use super::redaction::PiiRedactor;
+use super::traits::{Memory, MemoryCompactReport, MemoryEntry, MemoryInput, MemoryStorageStats};
+use super::{classify, MemoryCategory};
+use crate::config::{MemoryClassificationConfig, RedactionConfig};
+use async_trait::async_trait;
+use std::borrow::Cow;
+
+/// Memory backend that applies the auto-save policy before writes.
+///
+/// Reads and deletes go straight to the wrapped backend; writers call
+/// [`Memory::redact_for_storage`] and [`Memory::auto_save_category`] before
+/// storing.
+pub struct AutoSaveMemory {
+    inner: Box<dyn Memory>,
+    redactor: Option<PiiRedactor>,
+    classification: Option<MemoryClassificationConfig>,
+}
+
+impl AutoSaveMemory {
+    pub fn new(
+        inner: Box<dyn Memory>,
+        redaction: &RedactionConfig,
+        classification: &MemoryClassificationConfig,
+    ) -> Self {
+        Self {
+            inner,
+            redactor: redaction.enabled.then(|| PiiRedactor::new(redaction)),
+            classification: classification.enabled.then(|| classification.clone()),
+        }
+    }
+}
+
+#[async_trait]
+impl Memory for AutoSaveMemory {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn redact_for_storage(&self, channel: &str, content: &str) -> Option<String> {
+        let Some(redactor) = &self.redactor else {
+            return Some(content.to_string());
+        };
+        let redacted = redactor.redact(channel, content).map(Cow::into_owned);
+        if redacted.is_none() {
+            tracing::debug!("Dropped memory entry from channel '{channel}' per redaction policy");
+        }
+        redacted
+    }
+
+    fn auto_save_category(&self, content: &str) -> MemoryCategory {
+        self.classification
+            .as_ref()
+            .and_then(|classification| classify::classify(&classification.taxonomy, content))
+            .map_or(MemoryCategory::Conversation, MemoryCategory::Custom)
+    }
+
+    async fn store(
+        &self,
+        key: &str,
+        content: &str,
+        category: MemoryCategory,
+        session_id: Option<&str>,
+    ) -> anyhow::Result<()> {
+        self.inner.store(key, content, category, session_id).await
+    }
+
+    async fn store_batch(&self, entries: &[MemoryInput]) -> anyhow::Result<usize> {
+        self.inner.store_batch(entries).await
+    }
+
+    async fn recall(
+        &self,
+        query: &str,
+        limit: usize,
+        session_id: Option<&str>,
+    ) -> anyhow::Result<Vec<MemoryEntry>> {
+        self.inner.recall(query, limit, session_id).await
+    }
+
+    async fn get(&self, key: &str) -> anyhow::Result<Option<MemoryEntry>> {
+        self.inner.get(key).await
+    }
+
+    async fn list(
+        &self,
+        category: Option<&MemoryCategory>,
+        session_id: Option<&str>,
+    ) -> anyhow::Result<Vec<MemoryEntry>> {
+        self.inner.list(category, session_id).await
+    }
+
+    async fn forget(&self, key: &str) -> anyhow::Result<bool> {
+        self.inner.forget(key).await
+    }
+
+    async fn count(&self) -> anyhow::Result<usize> {
+        self.inner.count().await
+    }
+
+    async fn health_check(&self) -> bool {
+        self.inner.health_check().await
+    }
+
+    async fn storage_stats(&self) -> anyhow::Result<Option<MemoryStorageStats>> {
+        self.inner.storage_stats().await
+    }
+
+    async fn compact(&self) -> anyhow::Result<Option<MemoryCompactReport>> {
+        self.inner.compact().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RedactionPolicy;
+    use crate::memory::NoneMemory;
+
+    fn wrapped(redaction_enabled: bool, classification_enabled: bool) -> AutoSaveMemory {
+        AutoSaveMemory::new(
+            Box::new(NoneMemory::new()),
+            &RedactionConfig {
+                enabled: redaction_enabled,
+                policy: RedactionPolicy::Mask,
+                ..RedactionConfig::default()
+            },
+            &MemoryClassificationConfig {
+                enabled: classification_enabled,
+                ..MemoryClassificationConfig::default()
+            },
+        )
+    }
+
+    #[test]
+    fn plain_backends_store_content_unchanged() {
+        let plain = NoneMemory::new();
+        assert_eq!(
+            plain
+                .redact_for_storage("cli", "jane@example.com")
+                .as_deref(),
+            Some("jane@example.com")
+        );
+        assert_eq!(
+            plain.auto_save_category("I prefer tabs"),
+            MemoryCategory::Conversation
+        );
+    }
+
+    #[test]
+    fn wrapper_applies_its_own_policy() {
+        let memory = wrapped(true, true);
+        assert_eq!(memory.name(), "none");
+        assert_eq!(
+            memory
+                .redact_for_storage("cli", "mail jane@example.com")
+                .as_deref(),
+            Some("mail [REDACTED:EMAIL]")
+        );
+        assert_eq!(
+            memory.auto_save_category("I prefer tabs"),
+            MemoryCategory::Custom("preference".into())
+        );
+    }
+
+    #[test]
+    fn disabled_parts_fall_back_to_defaults() {
+        let memory = wrapped(false, false);
+        assert_eq!(
+            memory
+                .redact_for_storage("cli", "jane@example.com")
+                .as_deref(),
+            Some("jane@example.com")
+        );
+        assert_eq!(
+            memory.auto_save_category("I prefer tabs"),
+            MemoryCategory::Conversation
+        );
+    }
+}
@@ -0,0 +1,88 @@
+//! Keyword classification of auto-saved memories (`[memory.classification]`).
+//!
+//! Auto-saved user messages are filed under the taxonomy category whose
+//! keywords they match most often (`preference`, `fact`, `task`, ...), so
+//! retrieval, `[memory.categories.<name>]` retention and `zeroclaw memory
+//! browse --category` can tell them apart. Unmatched messages, and every
+//! message while classification is off, stay in `conversation`. The taxonomy
+//! travels with the memory backend (see [`super::autosave`]).
+
+use std::collections::BTreeMap;
+
+/// The taxonomy category with the most keyword matches in `content`, if any.
+/// Ties go to the category that sorts first.
+pub fn classify(taxonomy: &BTreeMap<String, Vec<String>>, content: &str) -> Option<String> {
+    let text = content.to_lowercase();
+    taxonomy
+        .iter()
+        .map(|(category, keywords)| {
+            let hits = keywords
+                .iter()
+                .filter(|keyword| contains_phrase(&text, &keyword.trim().to_lowercase()))
+                .count();
+            (category, hits)
+        })
+        .filter(|(_, hits)| *hits > 0)
+        .fold(
+            None,
+            |best: Option<(&String, usize)>, (category, hits)| match best {
+                Some((_, best_hits)) if best_hits >= hits => best,
+                _ => Some((category, hits)),
+            },
+        )
+        .map(|(category, _)| category.trim().to_ascii_lowercase())
+}
+
+/// Whether `phrase` occurs in `text` on word boundaries (so `led` does not
+/// match `called`).
+fn contains_phrase(text: &str, phrase: &str) -> bool {
+    if phrase.is_empty() {
+        return false;
+    }
+    text.match_indices(phrase).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + phrase.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MemoryClassificationConfig;
+
+    fn taxonomy() -> BTreeMap<String, Vec<String>> {
+        MemoryClassificationConfig::default().taxonomy
+    }
+
+    #[test]
+    fn classifies_with_the_default_taxonomy() {
+        let taxonomy = taxonomy();
+        assert_eq!(
+            classify(&taxonomy, "I prefer short answers in English").as_deref(),
+            Some("preference")
+        );
+        assert_eq!(
+            classify(
+                &taxonomy,
+                "Remind me to renew the domain, deadline is Friday"
+            )
+            .as_deref(),
+            Some("task")
+        );
+        assert_eq!(
+            classify(&taxonomy, "The LED is wired to GPIO 17 on my Raspberry Pi").as_deref(),
+            Some("hardware")
+        );
+        assert_eq!(classify(&taxonomy, "what's the weather like?"), None);
+    }
+
+    #[test]
+    fn keywords_match_on_word_boundaries_only() {
+        assert!(contains_phrase("the led is on", "led"));
+        assert!(contains_phrase("led.", "led"));
+        assert!(!contains_phrase("who called earlier", "led"));
+        assert!(!contains_phrase("spinning up", "pin"));
+        assert!(contains_phrase("todo: ship it", "todo"));
+    }
+}
@@ -1,7 +1,7 @@
 use super::traits::{Memory, MemoryCategory};
 use super::{
-    classify_memory_backend, create_memory_for_migration, effective_memory_backend_name,
-    MemoryBackendKind,
+    classify_memory_backend, create_memory_for_migration, create_memory_with_storage_and_routes,
+    effective_memory_backend_name, MemoryBackendKind,
 };
 use crate::config::Config;
 #[cfg(feature = "memory-postgres")]
@@ -17,9 +17,21 @@ pub async fn handle_command(command: crate::MemoryCommands, config: &Config) ->
             session,
             limit,
             offset,
-        } => handle_list(config, category, session, limit, offset).await,
+        } => {
+            write_list(
+                config,
+                category,
+                session,
+                limit,
+                offset,
+                &mut std::io::stdout(),
+            )
+            .await
+        }
         crate::MemoryCommands::Get { key } => handle_get(config, &key).await,
-        crate::MemoryCommands::Stats => handle_stats(config).await,
+        crate::MemoryCommands::Stats { deep } => handle_stats(config, deep).await,
+        crate::MemoryCommands::Compact { yes } => handle_compact(config, yes).await,
+        crate::MemoryCommands::Reembed { yes } => handle_reembed(config, yes).await,
         crate::MemoryCommands::Clear { key, category, yes } => {
             handle_clear(config, key, category, yes).await
         }
@@ -31,7 +43,7 @@ pub async fn handle_command(command: crate::MemoryCommands, config: &Config) ->
 /// CLI commands (list/get/stats/clear) never use vector search, so we skip
 /// embedding provider initialisation for local backends by using the
 /// migration factory.  Postgres still needs its full connection config.
-fn create_cli_memory(config: &Config) -> Result<Box<dyn Memory>> {
+pub(crate) fn create_cli_memory(config: &Config) -> Result<Box<dyn Memory>> {
     let backend = effective_memory_backend_name(
         &config.memory.backend,
         Some(&config.storage.provider.config),
@@ -75,19 +87,59 @@ fn create_cli_memory(config: &Config) -> Result<Box<dyn Memory>> {
     }
 }
 
-async fn handle_list(
+/// Create a memory backend for storage maintenance (deep stats, compaction).
+///
+/// Unlike [`create_cli_memory`], networked vector stores are built through the
+/// full factory so stats and optimization reach the real collection instead of
+/// the markdown fallback used for migration.
+fn create_maintenance_memory(config: &Config) -> Result<Box<dyn Memory>> {
+    let backend = effective_memory_backend_name(
+        &config.memory.backend,
+        Some(&config.storage.provider.config),
+    );
+    if matches!(classify_memory_backend(&backend), MemoryBackendKind::Qdrant) {
+        return create_memory_with_storage_and_routes(
+            &config.memory,
+            &config.embedding_routes,
+            Some(&config.storage.provider.config),
+            &config.workspace_dir,
+            config.api_key.as_deref(),
+        );
+    }
+    create_cli_memory(config)
+}
+
+/// Create the target backend for bulk imports (OpenClaw migration, history
+/// import, re-embedding).
+///
+/// Qdrant is built through the full factory so imports reach the real
+/// collection and its batched upsert pipeline; every other backend matches
+/// [`create_memory_for_migration`].
+pub(crate) fn create_import_memory(config: &Config) -> Result<Box<dyn Memory>> {
+    if matches!(
+        classify_memory_backend(&config.memory.backend),
+        MemoryBackendKind::Qdrant
+    ) {
+        return create_maintenance_memory(config);
+    }
+    create_memory_for_migration(&config.memory.backend, &config.workspace_dir)
+}
+
+/// Write one page of `memory list` to `out`.
+pub(crate) async fn write_list(
     config: &Config,
     category: Option<String>,
     session: Option<String>,
     limit: usize,
     offset: usize,
+    out: &mut dyn std::io::Write,
 ) -> Result<()> {
     let mem = create_cli_memory(config)?;
     let cat = category.as_deref().map(parse_category);
     let entries = mem.list(cat.as_ref(), session.as_deref()).await?;
 
     if entries.is_empty() {
-        println!("No memory entries found.");
+        writeln!(out, "No memory entries found.")?;
         return Ok(());
     }
 
@@ -95,27 +147,33 @@ async fn handle_list(
     let page: Vec<_> = entries.into_iter().skip(offset).take(limit).collect();
 
     if page.is_empty() {
-        println!("No entries at offset {offset} (total: {total}).");
+        writeln!(out, "No entries at offset {offset} (total: {total}).")?;
         return Ok(());
     }
 
-    println!(
+    writeln!(
+        out,
         "Memory entries ({total} total, showing {}-{}):\n",
         offset + 1,
         offset + page.len(),
-    );
+    )?;
 
     for entry in &page {
-        println!(
+        writeln!(
+            out,
             "- {} [{}]",
             style(&entry.key).white().bold(),
             entry.category,
-        );
-        println!("    {}", truncate_content(&entry.content, 80));
+        )?;
+        writeln!(out, "    {}", truncate_content(&entry.content, 80))?;
     }
 
     if offset + page.len() < total {
-        println!("\n  Use --offset {} to see the next page.", offset + limit);
+        writeln!(
+            out,
+            "\n  Use --offset {} to see the next page.",
+            offset + limit
+        )?;
     }
 
     Ok(())
@@ -163,7 +221,11 @@ fn print_entry(entry: &super::traits::MemoryEntry) {
     println!("\n{}", entry.content);
 }
 
-async fn handle_stats(config: &Config) -> Result<()> {
+async fn handle_stats(config: &Config, deep: bool) -> Result<()> {
+    if deep {
+        return handle_stats_deep(config).await;
+    }
+
     let mem = create_cli_memory(config)?;
     let healthy = mem.health_check().await;
     let total = mem.count().await.unwrap_or(0);
@@ -198,6 +260,139 @@ async fn handle_stats(config: &Config) -> Result<()> {
     Ok(())
 }
 
+async fn handle_stats_deep(config: &Config) -> Result<()> {
+    let mem = create_maintenance_memory(config)?;
+    let Some(stats) = mem.storage_stats().await? else {
+        println!(
+            "Deep statistics are not available for the '{}' backend.",
+            mem.name()
+        );
+        return Ok(());
+    };
+
+    println!("Memory Statistics (deep):\n");
+    println!("  Backend:  {}", style(mem.name()).white().bold());
+    println!("  Total:    {}", stats.total_entries);
+
+    if !stats.by_category.is_empty() {
+        println!("\n  By category:");
+        for (cat, count) in &stats.by_category {
+            println!("    {cat:<20} {count}");
+        }
+    }
+
+    if !stats.by_namespace.is_empty() {
+        println!("\n  By namespace:");
+        for (session, count) in &stats.by_namespace {
+            let label = session.as_deref().unwrap_or("(global)");
+            println!("    {label:<20} {count}");
+        }
+    }
+
+    if let Some(missing) = stats.entries_without_vectors {
+        let embedded = stats.total_entries.saturating_sub(missing);
+        let coverage = if stats.total_entries == 0 {
+            100.0
+        } else {
+            embedded as f64 * 100.0 / stats.total_entries as f64
+        };
+        println!("\n  Embedding coverage: {coverage:.1}% ({missing} entries without vectors)");
+    }
+
+    if !stats.index_bytes.is_empty() {
+        println!("\n  Index sizes:");
+        for (name, bytes) in &stats.index_bytes {
+            println!("    {name:<20} {}", format_bytes(*bytes));
+        }
+    }
+
+    if let Some(total) = stats.storage_bytes {
+        println!("\n  Storage:  {}", format_bytes(total));
+    }
+    if let (Some(free), Some(ratio)) = (stats.reclaimable_bytes, stats.fragmentation()) {
+        println!(
+            "  Fragmentation: {:.1}% ({} reclaimable via `zeroclaw memory compact`)",
+            ratio * 100.0,
+            format_bytes(free)
+        );
+    }
+
+    for (name, value) in &stats.extra {
+        println!("  {name}: {value}");
+    }
+
+    Ok(())
+}
+
+async fn handle_compact(config: &Config, yes: bool) -> Result<()> {
+    let mem = create_maintenance_memory(config)?;
+
+    if !yes {
+        let confirmed = dialoguer::Confirm::new()
+            .with_prompt(format!("  Compact the '{}' memory store now?", mem.name()))
+            .default(true)
+            .interact()?;
+        if !confirmed {
+            println!("Aborted.");
+            return Ok(());
+        }
+    }
+
+    let Some(report) = mem.compact().await? else {
+        println!(
+            "Compaction is not supported for the '{}' backend.",
+            mem.name()
+        );
+        return Ok(());
+    };
+
+    println!(
+        "{} Compacted: {}",
+        style("✓").green().bold(),
+        report.summary
+    );
+    if let (Some(before), Some(after)) = (report.bytes_before, report.bytes_after) {
+        println!(
+            "  {} → {} (reclaimed {})",
+            format_bytes(before),
+            format_bytes(after),
+            format_bytes(before.saturating_sub(after))
+        );
+    }
+
+    Ok(())
+}
+
+async fn handle_reembed(config: &Config, yes: bool) -> Result<()> {
+    let mem = create_import_memory(config)?;
+    let count = mem.count().await?;
+    if count == 0 {
+        println!("No memory entries to re-embed.");
+        return Ok(());
+    }
+
+    if !yes {
+        let confirmed = dialoguer::Confirm::new()
+            .with_prompt(format!(
+                "  Re-embed {count} entries in the '{}' memory store?",
+                mem.name()
+            ))
+            .default(true)
+            .interact()?;
+        if !confirmed {
+            println!("Aborted.");
+            return Ok(());
+        }
+    }
+
+    let rewritten = super::hygiene::reembed_all(mem.as_ref()).await?;
+    println!(
+        "{} Re-embedded {rewritten} entries",
+        style("✓").green().bold()
+    );
+    Ok(())
+}
+
 async fn handle_clear(
     config: &Config,
     key: Option<String>,
@@ -306,6 +501,21 @@ fn parse_category(s: &str) -> MemoryCategory {
     }
 }
 
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
 fn truncate_content(s: &str, max_len: usize) -> String {
     let line = s.lines().next().unwrap_or(s);
     if line.len() <= max_len {
@@ -357,4 +567,11 @@ mod tests {
     fn truncate_content_empty_string() {
         assert_eq!(truncate_content("", 10), "");
     }
+
+    #[test]
+    fn format_bytes_scales_units() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(2048), "2.0 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MiB");
+    }
 }
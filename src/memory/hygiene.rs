@@ -1,8 +1,10 @@
-use crate::config::MemoryConfig;
+use super::traits::{Memory, MemoryInput};
+use crate::config::{MemoryCategoryConfig, MemoryConfig};
 use anyhow::Result;
 use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
 use rusqlite::{params, Connection};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::{Duration as StdDuration, SystemTime};
@@ -17,6 +19,8 @@ struct HygieneReport {
     purged_memory_archives: u64,
     purged_session_archives: u64,
     pruned_conversation_rows: u64,
+    #[serde(default)]
+    pruned_category_rows: u64,
 }
 
 impl HygieneReport {
@@ -26,6 +30,7 @@ impl HygieneReport {
             + self.purged_memory_archives
             + self.purged_session_archives
             + self.pruned_conversation_rows
+            + self.pruned_category_rows
     }
 }
 
@@ -57,26 +62,46 @@ pub fn run_if_due(config: &MemoryConfig, workspace_dir: &Path) -> Result<()> {
         purged_session_archives: purge_session_archives(workspace_dir, config.purge_after_days)?,
         pruned_conversation_rows: prune_conversation_rows(
             workspace_dir,
-            config.conversation_retention_days,
+            conversation_retention_hours(config),
         )?,
+        pruned_category_rows: prune_expired_category_rows(workspace_dir, &config.categories)?,
     };
 
     write_state(workspace_dir, &report)?;
 
     if report.total_actions() > 0 {
         tracing::info!(
-            "memory hygiene complete: archived_memory={} archived_sessions={} purged_memory={} purged_sessions={} pruned_conversation_rows={}",
+            "memory hygiene complete: archived_memory={} archived_sessions={} purged_memory={} purged_sessions={} pruned_conversation_rows={} pruned_category_rows={}",
             report.archived_memory_files,
             report.archived_session_files,
             report.purged_memory_archives,
             report.purged_session_archives,
             report.pruned_conversation_rows,
+            report.pruned_category_rows,
         );
     }
 
     Ok(())
 }
 
+/// Re-embed every stored entry with the backend's current embedder, e.g.
+/// after changing the embedding model. Writes go through
+/// [`Memory::store_batch`] so Qdrant uses its batched upsert pipeline.
+pub async fn reembed_all(memory: &dyn Memory) -> Result<usize> {
+    let entries: Vec<MemoryInput> = memory
+        .list(None, None)
+        .await?
+        .into_iter()
+        .map(|entry| MemoryInput {
+            key: entry.key,
+            content: entry.content,
+            category: entry.category,
+            session_id: entry.session_id,
+        })
+        .collect();
+    memory.store_batch(&entries).await
+}
+
 fn should_run_now(workspace_dir: &Path) -> Result<bool> {
     let path = state_path(workspace_dir);
     if !path.exists() {
@@ -295,24 +320,74 @@ fn purge_session_archives(workspace_dir: &Path, purge_after_days: u32) -> Result
     Ok(removed)
 }
 
-fn prune_conversation_rows(workspace_dir: &Path, retention_days: u32) -> Result<u64> {
-    if retention_days == 0 {
+/// Retention for `conversation` rows (which auto-save writes): a
+/// `[memory.categories.conversation]` override wins over `conversation_retention_days`.
+fn conversation_retention_hours(config: &MemoryConfig) -> u64 {
+    config.categories.get("conversation").map_or_else(
+        || u64::from(config.conversation_retention_days) * 24,
+        |policy| policy.ttl_hours,
+    )
+}
+
+fn prune_conversation_rows(workspace_dir: &Path, retention_hours: u64) -> Result<u64> {
+    if retention_hours == 0 {
         return Ok(0);
     }
 
+    let Some(conn) = open_brain_db(workspace_dir)? else {
+        return Ok(0);
+    };
+    prune_category_rows(&conn, "conversation", retention_hours)
+}
+
+fn prune_expired_category_rows(
+    workspace_dir: &Path,
+    categories: &HashMap<String, MemoryCategoryConfig>,
+) -> Result<u64> {
+    let expiring: Vec<(&str, u64)> = categories
+        .iter()
+        .filter(|(name, policy)| name.as_str() != "conversation" && policy.ttl_hours > 0)
+        .map(|(name, policy)| (name.as_str(), policy.ttl_hours))
+        .collect();
+    if expiring.is_empty() {
+        return Ok(0);
+    }
+
+    let Some(conn) = open_brain_db(workspace_dir)? else {
+        return Ok(0);
+    };
+
+    let mut pruned = 0_u64;
+    for (category, ttl_hours) in expiring {
+        pruned += prune_category_rows(&conn, category, ttl_hours)?;
+    }
+    Ok(pruned)
+}
+
+fn open_brain_db(workspace_dir: &Path) -> Result<Option<Connection>> {
     let db_path = workspace_dir.join("memory").join("brain.db");
     if !db_path.exists() {
-        return Ok(0);
+        return Ok(None);
     }
 
     let conn = Connection::open(db_path)?;
     // Use WAL so hygiene pruning doesn't block agent reads
     conn.execute_batch("PRAGMA journal_mode = WAL; PRAGMA synchronous = NORMAL;")?;
-    let cutoff = (Local::now() - Duration::days(i64::from(retention_days))).to_rfc3339();
+    Ok(Some(conn))
+}
+
+fn prune_category_rows(conn: &Connection, category: &str, ttl_hours: u64) -> Result<u64> {
+    let Some(cutoff) = i64::try_from(ttl_hours)
+        .ok()
+        .and_then(Duration::try_hours)
+        .and_then(|ttl| Local::now().checked_sub_signed(ttl))
+    else {
+        return Ok(0);
+    };
 
     let affected = conn.execute(
-        "DELETE FROM memories WHERE category = 'conversation' AND updated_at < ?1",
-        params![cutoff],
+        "DELETE FROM memories WHERE category = ?1 AND updated_at < ?2",
+        params![category, cutoff.to_rfc3339()],
     )?;
 
     Ok(u64::try_from(affected).unwrap_or(0))
@@ -379,7 +454,7 @@ fn split_name(filename: &str) -> (&str, &str) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::memory::{Memory, MemoryCategory, SqliteMemory};
+    use crate::memory::{MemoryCategory, SqliteMemory};
     use tempfile::TempDir;
 
     fn default_cfg() -> MemoryConfig {
@@ -537,4 +612,60 @@ mod tests {
             "core memory should remain"
         );
     }
+
+    #[tokio::test]
+    async fn category_ttl_overrides_prune_and_preserve() {
+        let tmp = TempDir::new().unwrap();
+        let workspace = tmp.path();
+
+        let mem = SqliteMemory::new(workspace).unwrap();
+        for (key, category) in [
+            ("scratch_old", "scratch"),
+            ("scratch_new", "scratch"),
+            ("pref_old", "preferences"),
+            ("conv_old", "conversation"),
+        ] {
+            mem.store(key, "value", MemoryCategory::Custom(category.into()), None)
+                .await
+                .unwrap();
+        }
+        drop(mem);
+
+        let conn = Connection::open(workspace.join("memory").join("brain.db")).unwrap();
+        let two_days_ago = (Local::now() - Duration::hours(48)).to_rfc3339();
+        let year_ago = (Local::now() - Duration::days(365)).to_rfc3339();
+        conn.execute(
+            "UPDATE memories SET updated_at = ?1 WHERE key = 'scratch_old'",
+            params![two_days_ago],
+        )
+        .unwrap();
+        conn.execute(
+            "UPDATE memories SET updated_at = ?1 WHERE key IN ('pref_old', 'conv_old')",
+            params![year_ago],
+        )
+        .unwrap();
+        drop(conn);
+
+        let mut cfg = default_cfg();
+        cfg.categories
+            .insert("scratch".into(), MemoryCategoryConfig { ttl_hours: 24 });
+        cfg.categories
+            .insert("preferences".into(), MemoryCategoryConfig { ttl_hours: 0 });
+        cfg.categories
+            .insert("conversation".into(), MemoryCategoryConfig { ttl_hours: 0 });
+
+        run_if_due(&cfg, workspace).unwrap();
+
+        let mem2 = SqliteMemory::new(workspace).unwrap();
+        assert!(mem2.get("scratch_old").await.unwrap().is_none());
+        assert!(mem2.get("scratch_new").await.unwrap().is_some());
+        assert!(
+            mem2.get("pref_old").await.unwrap().is_some(),
+            "ttl_hours = 0 never expires"
+        );
+        assert!(
+            mem2.get("conv_old").await.unwrap().is_some(),
+            "conversation override wins over conversation_retention_days"
+        );
+    }
 }
@@ -1,5 +1,5 @@
 use super::sqlite::SqliteMemory;
-use super::traits::{Memory, MemoryCategory, MemoryEntry};
+use super::traits::{Memory, MemoryCategory, MemoryCompactReport, MemoryEntry, MemoryStorageStats};
 use async_trait::async_trait;
 use chrono::Local;
 use parking_lot::Mutex;
@@ -382,6 +382,14 @@ impl Memory for LucidMemory {
     async fn health_check(&self) -> bool {
         self.local.health_check().await
     }
+
+    async fn storage_stats(&self) -> anyhow::Result<Option<MemoryStorageStats>> {
+        self.local.storage_stats().await
+    }
+
+    async fn compact(&self) -> anyhow::Result<Option<MemoryCompactReport>> {
+        self.local.compact().await
+    }
 }
 
 #[cfg(all(test, unix))]
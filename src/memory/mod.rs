@@ -1,14 +1,20 @@
+pub mod autosave;
 pub mod backend;
 pub mod chunker;
+pub mod classify;
 pub mod cli;
 pub mod embeddings;
 pub mod hygiene;
 pub mod lucid;
 pub mod markdown;
 pub mod none;
+pub mod packing;
 #[cfg(feature = "memory-postgres")]
 pub mod postgres;
 pub mod qdrant;
+#[cfg(feature = "memory-qdrant-grpc")]
+pub mod qdrant_grpc;
+pub mod redaction;
 pub mod response_cache;
 pub mod snapshot;
 pub mod sqlite;
@@ -30,9 +36,12 @@ pub use response_cache::ResponseCache;
 pub use sqlite::SqliteMemory;
 pub use traits::Memory;
 #[allow(unused_imports)]
-pub use traits::{MemoryCategory, MemoryEntry};
+pub use traits::{MemoryCategory, MemoryEntry, MemoryInput};
 
-use crate::config::{EmbeddingRouteConfig, MemoryConfig, StorageProviderConfig};
+use crate::config::{
+    Config, EmbeddingRouteConfig, MemoryConfig, QdrantConfig, QdrantTransport,
+    StorageProviderConfig,
+};
 use anyhow::Context;
 use std::path::Path;
 use std::sync::Arc;
@@ -169,6 +178,21 @@ fn resolve_embedding_config(
     }
 }
 
+/// Wrap `memory` with the auto-save policy from `[redaction]` and
+/// `[memory.classification]`. Returns the backend unchanged when both are off.
+pub fn with_auto_save_policy(memory: Box<dyn Memory>, config: &Config) -> Box<dyn Memory> {
+    let classification = &config.memory.classification;
+    if config.redaction.enabled || classification.enabled {
+        Box::new(autosave::AutoSaveMemory::new(
+            memory,
+            &config.redaction,
+            classification,
+        ))
+    } else {
+        memory
+    }
+}
+
 /// Factory: create the right memory backend from config
 pub fn create_memory(
     config: &MemoryConfig,
@@ -254,13 +278,14 @@ pub fn create_memory_with_storage_and_routes(
             ));
 
         #[allow(clippy::cast_possible_truncation)]
-        let mem = SqliteMemory::with_embedder(
+        let mem = SqliteMemory::with_tuning(
             workspace_dir,
             embedder,
             config.vector_weight as f32,
             config.keyword_weight as f32,
             config.embedding_cache_size,
             config.sqlite_open_timeout_secs,
+            &config.sqlite,
         )?;
         Ok(mem)
     }
@@ -298,6 +323,31 @@ pub fn create_memory_with_storage_and_routes(
         );
     }
 
+    #[cfg(feature = "memory-qdrant-grpc")]
+    fn with_qdrant_grpc(
+        memory: QdrantMemory,
+        qdrant: &QdrantConfig,
+        rest_url: &str,
+    ) -> anyhow::Result<QdrantMemory> {
+        let grpc_url = match qdrant.grpc_url.as_deref().map(str::trim) {
+            Some(url) if !url.is_empty() => url.to_string(),
+            _ => qdrant::default_grpc_url(rest_url)?,
+        };
+        tracing::info!("📦 Qdrant upserts routed over gRPC ({grpc_url})");
+        memory.with_grpc(&grpc_url)
+    }
+
+    #[cfg(not(feature = "memory-qdrant-grpc"))]
+    fn with_qdrant_grpc(
+        _memory: QdrantMemory,
+        _qdrant: &QdrantConfig,
+        _rest_url: &str,
+    ) -> anyhow::Result<QdrantMemory> {
+        anyhow::bail!(
+            "[memory.qdrant] transport = \"grpc\" requires a build with `memory-qdrant-grpc`; rebuild with `--features memory-qdrant-grpc`"
+        );
+    }
+
     if matches!(backend_kind, MemoryBackendKind::Qdrant) {
         let url = config
             .qdrant
@@ -331,12 +381,16 @@ pub fn create_memory_with_storage_and_routes(
             url,
             collection
         );
-        return Ok(Box::new(QdrantMemory::new_lazy(
-            &url,
-            &collection,
-            qdrant_api_key,
-            embedder,
-        )));
+        let memory = QdrantMemory::new_lazy(&url, &collection, qdrant_api_key, embedder)
+            .with_upsert_pipeline(
+                config.qdrant.upsert_batch_size,
+                config.qdrant.upsert_parallelism,
+            );
+        let memory = match config.qdrant.transport {
+            QdrantTransport::Rest => memory,
+            QdrantTransport::Grpc => with_qdrant_grpc(memory, &config.qdrant, &url)?,
+        };
+        return Ok(Box::new(memory));
     }
 
     create_memory_with_builders(
@@ -525,6 +579,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn factory_qdrant_grpc_requires_feature() {
+        let tmp = TempDir::new().unwrap();
+        let cfg = MemoryConfig {
+            backend: "qdrant".into(),
+            qdrant: QdrantConfig {
+                url: Some("http://localhost:6333".into()),
+                transport: QdrantTransport::Grpc,
+                ..QdrantConfig::default()
+            },
+            ..MemoryConfig::default()
+        };
+
+        let result = create_memory(&cfg, tmp.path(), None);
+        if cfg!(feature = "memory-qdrant-grpc") {
+            assert_eq!(result.unwrap().name(), "qdrant");
+        } else {
+            let error = result
+                .err()
+                .expect("grpc without feature should be rejected");
+            assert!(error.to_string().contains("memory-qdrant-grpc"));
+        }
+    }
+
     #[test]
     fn resolve_embedding_config_uses_base_config_when_model_is_not_hint() {
         let cfg = MemoryConfig {
@@ -0,0 +1,128 @@
+//! Token-budgeted packing of recalled memories into the `[Memory context]`
+//! block.
+//!
+//! Callers recall a wider candidate pool, filter it, and hand the formatted
+//! lines here. The most relevant lines that fit `[memory] context_token_budget`
+//! are kept; the rest are summarised in a single omission marker.
+
+use std::fmt::Write as _;
+
+/// How many memories to recall before packing.
+pub const CONTEXT_CANDIDATES: usize = 20;
+
+const CONTEXT_HEADER: &str = "[Memory context]\n";
+
+/// Local token estimate: ~4 characters per token for ASCII text (as in
+/// [`chunk_markdown`](super::chunker::chunk_markdown)), one token per
+/// character for everything else (CJK, emoji), which tokenizes far denser.
+pub fn estimate_tokens(text: &str) -> usize {
+    let (ascii, other) = text.chars().fold((0usize, 0usize), |(ascii, other), c| {
+        if c.is_ascii() {
+            (ascii + 1, other)
+        } else {
+            (ascii, other + 1)
+        }
+    });
+    ascii.div_ceil(4) + other
+}
+
+/// One formatted memory line (`- key: content`) and its relevance score.
+#[derive(Debug, Clone)]
+pub struct ContextCandidate {
+    pub line: String,
+    pub score: Option<f64>,
+}
+
+impl ContextCandidate {
+    pub fn new(key: &str, content: &str, score: Option<f64>) -> Self {
+        Self {
+            line: format!("- {key}: {content}\n"),
+            score,
+        }
+    }
+}
+
+/// Pack `candidates` greedily by relevance under `token_budget` and return the
+/// context block, or an empty string when nothing fits. A budget of `0`
+/// disables the limit. Unscored candidates keep their recall order after the
+/// scored ones.
+pub fn pack_context(mut candidates: Vec<ContextCandidate>, token_budget: usize) -> String {
+    candidates.sort_by(|a, b| match (a.score, b.score) {
+        (Some(a), Some(b)) => b.total_cmp(&a),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+
+    let mut context = String::from(CONTEXT_HEADER);
+    let mut used = 0usize;
+    let mut omitted = 0usize;
+    for candidate in &candidates {
+        let tokens = estimate_tokens(&candidate.line);
+        if token_budget > 0 && used + tokens > token_budget {
+            omitted += 1;
+            continue;
+        }
+        context.push_str(&candidate.line);
+        used += tokens;
+    }
+
+    if context == CONTEXT_HEADER {
+        return String::new();
+    }
+    match omitted {
+        0 => {}
+        1 => context.push_str("[1 more related memory omitted]\n"),
+        n => {
+            let _ = writeln!(context, "[{n} more related memories omitted]");
+        }
+    }
+    context.push('\n');
+    context
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_counts_non_ascii_densely() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("abcd"), 1);
+        assert_eq!(estimate_tokens("abcde"), 2);
+        assert_eq!(estimate_tokens("你好"), 2);
+    }
+
+    #[test]
+    fn packs_most_relevant_first_and_marks_omissions() {
+        let long = "x".repeat(400);
+        let candidates = vec![
+            ContextCandidate::new("low", "short", Some(0.5)),
+            ContextCandidate::new("big", &long, Some(0.8)),
+            ContextCandidate::new("top", "short", Some(0.9)),
+            ContextCandidate::new("tail", "short", Some(0.4)),
+        ];
+
+        let context = pack_context(candidates, 20);
+        let lines: Vec<&str> = context.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "[Memory context]",
+                "- top: short",
+                "- low: short",
+                "- tail: short",
+                "[1 more related memory omitted]",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_when_nothing_fits_and_unlimited_at_zero() {
+        let candidates = vec![ContextCandidate::new("k", &"y".repeat(100), None)];
+        assert!(pack_context(candidates.clone(), 5).is_empty());
+        assert!(pack_context(candidates, 0).contains("- k: "));
+        assert!(pack_context(Vec::new(), 100).is_empty());
+    }
+}
@@ -1,16 +1,21 @@
 use super::embeddings::EmbeddingProvider;
-use super::traits::{Memory, MemoryCategory, MemoryEntry};
+use super::traits::{
+    Memory, MemoryCategory, MemoryCompactReport, MemoryEntry, MemoryInput, MemoryStorageStats,
+};
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use chrono::Utc;
+use futures_util::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use tokio::sync::OnceCell;
 use uuid::Uuid;
 
 /// Qdrant vector database memory backend.
 ///
-/// Uses Qdrant's REST API for vector storage and semantic search.
+/// Uses Qdrant's REST API for vector storage and semantic search, with an
+/// optional gRPC channel for point upserts (`memory-qdrant-grpc` feature).
 /// Requires an embedding provider for converting text to vectors.
 pub struct QdrantMemory {
     client: reqwest::Client,
@@ -20,6 +25,38 @@ pub struct QdrantMemory {
     embedder: Arc<dyn EmbeddingProvider>,
     /// Tracks whether collection has been initialized (lazy init for sync factory).
     initialized: OnceCell<()>,
+    /// Points per upsert request in `store_batch`.
+    upsert_batch_size: usize,
+    /// Max upsert batches in flight in `store_batch`.
+    upsert_parallelism: usize,
+    #[cfg(feature = "memory-qdrant-grpc")]
+    grpc: Option<super::qdrant_grpc::QdrantGrpcClient>,
+}
+
+/// Default Qdrant gRPC port.
+const QDRANT_GRPC_PORT: u16 = 6334;
+
+/// Derive the gRPC endpoint from a REST url by switching to the gRPC port.
+pub fn default_grpc_url(rest_url: &str) -> Result<String> {
+    let mut url =
+        reqwest::Url::parse(rest_url).with_context(|| format!("invalid Qdrant url: {rest_url}"))?;
+    url.set_port(Some(QDRANT_GRPC_PORT))
+        .map_err(|()| anyhow::anyhow!("Qdrant url cannot carry a port: {rest_url}"))?;
+    Ok(url.as_str().trim_end_matches('/').to_string())
+}
+
+/// Keep only the last write for each key, ordered by where that write appears.
+fn dedup_by_key(entries: &[MemoryInput]) -> Vec<&MemoryInput> {
+    let mut last: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+    for (idx, entry) in entries.iter().enumerate() {
+        last.insert(entry.key.as_str(), idx);
+    }
+    entries
+        .iter()
+        .enumerate()
+        .filter(|(idx, entry)| last.get(entry.key.as_str()) == Some(idx))
+        .map(|(_, entry)| entry)
+        .collect()
 }
 
 impl QdrantMemory {
@@ -65,9 +102,30 @@ impl QdrantMemory {
             api_key,
             embedder,
             initialized: OnceCell::new(),
+            upsert_batch_size: 64,
+            upsert_parallelism: 4,
+            #[cfg(feature = "memory-qdrant-grpc")]
+            grpc: None,
         }
     }
 
+    /// Tune the batch write pipeline used by `store_batch`.
+    pub fn with_upsert_pipeline(mut self, batch_size: usize, parallelism: usize) -> Self {
+        self.upsert_batch_size = batch_size.max(1);
+        self.upsert_parallelism = parallelism.max(1);
+        self
+    }
+
+    /// Route point upserts over gRPC instead of REST.
+    #[cfg(feature = "memory-qdrant-grpc")]
+    pub fn with_grpc(mut self, grpc_url: &str) -> Result<Self> {
+        self.grpc = Some(super::qdrant_grpc::QdrantGrpcClient::new_lazy(
+            grpc_url,
+            self.api_key.clone(),
+        )?);
+        Ok(self)
+    }
+
     /// Ensure the collection is initialized (called lazily on first operation).
     async fn ensure_initialized(&self) -> Result<()> {
         self.initialized
@@ -177,6 +235,195 @@ impl QdrantMemory {
             other => MemoryCategory::Custom(other.to_string()),
         }
     }
+
+    /// Write points (`id`, `vector`, `payload`) via gRPC when configured, else REST.
+    async fn upsert_points(&self, points: Vec<(String, Vec<f32>, MemoryPayload)>) -> Result<()> {
+        #[cfg(feature = "memory-qdrant-grpc")]
+        if let Some(ref grpc) = self.grpc {
+            let points = points
+                .into_iter()
+                .map(|(id, vector, payload)| {
+                    Ok(super::qdrant_grpc::point(
+                        id,
+                        vector,
+                        serde_json::to_value(payload)?,
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            return grpc.upsert(&self.collection, points).await;
+        }
+
+        let points: Vec<serde_json::Value> = points
+            .into_iter()
+            .map(|(id, vector, payload)| {
+                serde_json::json!({
+                    "id": id,
+                    "vector": vector,
+                    "payload": payload
+                })
+            })
+            .collect();
+
+        let resp = self
+            .request(
+                reqwest::Method::PUT,
+                &format!("/collections/{}/points", self.collection),
+            )
+            .query(&[("wait", "true")])
+            .json(&serde_json::json!({ "points": points }))
+            .send()
+            .await
+            .context("failed to upsert point to Qdrant")?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let text = resp.text().await.unwrap_or_default();
+            anyhow::bail!("Qdrant upsert failed ({status}): {text}");
+        }
+
+        Ok(())
+    }
+
+    /// Delete all points whose payload `key` matches any of `keys`.
+    async fn delete_keys(&self, keys: &[&str]) -> Result<()> {
+        let delete_body = serde_json::json!({
+            "filter": {
+                "must": [{
+                    "key": "key",
+                    "match": { "any": keys }
+                }]
+            }
+        });
+
+        let resp = self
+            .request(
+                reqwest::Method::POST,
+                &format!("/collections/{}/points/delete", self.collection),
+            )
+            .query(&[("wait", "true")])
+            .json(&delete_body)
+            .send()
+            .await
+            .context("failed to delete from Qdrant")?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let text = resp.text().await.unwrap_or_default();
+            anyhow::bail!("Qdrant delete failed ({status}): {text}");
+        }
+
+        Ok(())
+    }
+
+    /// Embed, replace, and upsert one chunk of a batch write.
+    async fn store_chunk(&self, chunk: Vec<MemoryInput>) -> Result<usize> {
+        let texts: Vec<String> = chunk
+            .iter()
+            .map(|entry| format!("{}\n{}", entry.key, entry.content))
+            .collect();
+        let refs: Vec<&str> = texts.iter().map(String::as_str).collect();
+        let embeddings = self.embedder.embed(&refs).await?;
+
+        if embeddings.len() != chunk.len() {
+            anyhow::bail!(
+                "embedding provider returned {} vectors for {} inputs",
+                embeddings.len(),
+                chunk.len()
+            );
+        }
+        if embeddings.iter().any(Vec::is_empty) {
+            anyhow::bail!("Qdrant requires non-zero dimensional embeddings");
+        }
+
+        let keys: Vec<&str> = chunk.iter().map(|entry| entry.key.as_str()).collect();
+        self.delete_keys(&keys).await?;
+
+        let timestamp = Utc::now().to_rfc3339();
+        let points = chunk
+            .iter()
+            .zip(embeddings)
+            .map(|(entry, vector)| {
+                let payload = MemoryPayload {
+                    key: entry.key.clone(),
+                    content: entry.content.clone(),
+                    category: Self::category_to_str(&entry.category),
+                    timestamp: timestamp.clone(),
+                    session_id: entry.session_id.clone(),
+                };
+                (Uuid::new_v4().to_string(), vector, payload)
+            })
+            .collect();
+        self.upsert_points(points).await?;
+
+        Ok(chunk.len())
+    }
+
+    /// Fetch raw collection info (`GET /collections/{name}`).
+    async fn collection_info(&self) -> Result<serde_json::Value> {
+        let resp = self
+            .request(
+                reqwest::Method::GET,
+                &format!("/collections/{}", self.collection),
+            )
+            .send()
+            .await
+            .context("failed to get Qdrant collection info")?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let text = resp.text().await.unwrap_or_default();
+            anyhow::bail!("Qdrant collection info failed ({status}): {text}");
+        }
+
+        Ok(resp.json().await?)
+    }
+
+    /// Build storage statistics from collection info plus listed entries.
+    fn stats_from_collection_info(
+        info: &serde_json::Value,
+        entries: &[MemoryEntry],
+    ) -> MemoryStorageStats {
+        let result = info.get("result");
+        let field = |name: &str| result.and_then(|r| r.get(name)).and_then(|v| v.as_u64());
+
+        let mut by_category: BTreeMap<String, usize> = BTreeMap::new();
+        let mut by_namespace: BTreeMap<Option<String>, usize> = BTreeMap::new();
+        for entry in entries {
+            *by_category.entry(entry.category.to_string()).or_default() += 1;
+            *by_namespace.entry(entry.session_id.clone()).or_default() += 1;
+        }
+        let mut by_category: Vec<_> = by_category.into_iter().collect();
+        by_category.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        let mut by_namespace: Vec<_> = by_namespace.into_iter().collect();
+        by_namespace.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+        let points = field("points_count").unwrap_or(entries.len() as u64);
+        let mut extra = Vec::new();
+        if let Some(status) = result
+            .and_then(|r| r.get("status"))
+            .and_then(|v| v.as_str())
+        {
+            extra.push(("status".to_string(), status.to_string()));
+        }
+        if let Some(segments) = field("segments_count") {
+            extra.push(("segments".to_string(), segments.to_string()));
+        }
+        if let Some(indexed) = field("indexed_vectors_count") {
+            extra.push(("indexed_vectors".to_string(), indexed.to_string()));
+        }
+
+        MemoryStorageStats {
+            total_entries: usize::try_from(points).unwrap_or(usize::MAX),
+            by_category,
+            by_namespace,
+            // Every Qdrant point is stored with a vector (store rejects empty embeddings).
+            entries_without_vectors: Some(0),
+            index_bytes: Vec::new(),
+            storage_bytes: None,
+            reclaimable_bytes: None,
+            extra,
+        }
+    }
 }
 
 /// Qdrant point payload structure
@@ -257,33 +504,29 @@ impl Memory for QdrantMemory {
         // Delete any existing point with the same key first
         let _ = self.forget(key).await;
 
-        // Upsert point
-        let upsert_body = serde_json::json!({
-            "points": [{
-                "id": id,
-                "vector": embedding,
-                "payload": payload
-            }]
-        });
-
-        let resp = self
-            .request(
-                reqwest::Method::PUT,
-                &format!("/collections/{}/points", self.collection),
-            )
-            .query(&[("wait", "true")])
-            .json(&upsert_body)
-            .send()
-            .await
-            .context("failed to upsert point to Qdrant")?;
+        self.upsert_points(vec![(id, embedding, payload)]).await
+    }
 
-        if !resp.status().is_success() {
-            let status = resp.status();
-            let text = resp.text().await.unwrap_or_default();
-            anyhow::bail!("Qdrant upsert failed ({status}): {text}");
+    async fn store_batch(&self, entries: &[MemoryInput]) -> Result<usize> {
+        if entries.is_empty() {
+            return Ok(0);
         }
+        self.ensure_initialized().await?;
 
-        Ok(())
+        // Dedup first so parallel chunks never race on the same key.
+        let entries = dedup_by_key(entries);
+        let chunks: Vec<Vec<MemoryInput>> = entries
+            .chunks(self.upsert_batch_size)
+            .map(|chunk| chunk.iter().map(|entry| (*entry).clone()).collect())
+            .collect();
+
+        let results: Vec<Result<usize>> = stream::iter(chunks)
+            .map(|chunk| self.store_chunk(chunk))
+            .buffer_unordered(self.upsert_parallelism)
+            .collect()
+            .await;
+
+        results.into_iter().sum()
     }
 
     async fn recall(
@@ -506,32 +749,7 @@ impl Memory for QdrantMemory {
     async fn forget(&self, key: &str) -> Result<bool> {
         self.ensure_initialized().await?;
 
-        // Delete points matching the key
-        let delete_body = serde_json::json!({
-            "filter": {
-                "must": [{
-                    "key": "key",
-                    "match": { "value": key }
-                }]
-            }
-        });
-
-        let resp = self
-            .request(
-                reqwest::Method::POST,
-                &format!("/collections/{}/points/delete", self.collection),
-            )
-            .query(&[("wait", "true")])
-            .json(&delete_body)
-            .send()
-            .await
-            .context("failed to delete from Qdrant")?;
-
-        if !resp.status().is_success() {
-            let status = resp.status();
-            let text = resp.text().await.unwrap_or_default();
-            anyhow::bail!("Qdrant delete failed ({status}): {text}");
-        }
+        self.delete_keys(&[key]).await?;
 
         // Qdrant doesn't return deleted count easily, assume success
         Ok(true)
@@ -540,22 +758,7 @@ impl Memory for QdrantMemory {
     async fn count(&self) -> Result<usize> {
         self.ensure_initialized().await?;
 
-        let resp = self
-            .request(
-                reqwest::Method::GET,
-                &format!("/collections/{}", self.collection),
-            )
-            .send()
-            .await
-            .context("failed to get Qdrant collection info")?;
-
-        if !resp.status().is_success() {
-            let status = resp.status();
-            let text = resp.text().await.unwrap_or_default();
-            anyhow::bail!("Qdrant collection info failed ({status}): {text}");
-        }
-
-        let json: serde_json::Value = resp.json().await?;
+        let json = self.collection_info().await?;
 
         let count = json
             .get("result")
@@ -573,6 +776,45 @@ impl Memory for QdrantMemory {
 
         matches!(resp, Ok(r) if r.status().is_success())
     }
+
+    async fn storage_stats(&self) -> Result<Option<MemoryStorageStats>> {
+        self.ensure_initialized().await?;
+
+        let info = self.collection_info().await?;
+        let entries = self.list(None, None).await?;
+        Ok(Some(Self::stats_from_collection_info(&info, &entries)))
+    }
+
+    async fn compact(&self) -> Result<Option<MemoryCompactReport>> {
+        self.ensure_initialized().await?;
+
+        // An empty optimizers_config PATCH asks Qdrant to re-run its
+        // optimizers (segment merge + vacuum of deleted points).
+        let resp = self
+            .request(
+                reqwest::Method::PATCH,
+                &format!("/collections/{}", self.collection),
+            )
+            .json(&serde_json::json!({ "optimizers_config": {} }))
+            .send()
+            .await
+            .context("failed to trigger Qdrant optimization")?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let text = resp.text().await.unwrap_or_default();
+            anyhow::bail!("Qdrant optimize request failed ({status}): {text}");
+        }
+
+        Ok(Some(MemoryCompactReport {
+            bytes_before: None,
+            bytes_after: None,
+            summary: format!(
+                "triggered optimizer run for collection '{}'",
+                self.collection
+            ),
+        }))
+    }
 }
 
 #[cfg(test)]
@@ -639,4 +881,70 @@ mod tests {
         let json = serde_json::to_string(&payload).unwrap();
         assert!(!json.contains("session_id"));
     }
+
+    #[test]
+    fn default_grpc_url_switches_port() {
+        assert_eq!(
+            default_grpc_url("http://localhost:6333").unwrap(),
+            "http://localhost:6334"
+        );
+        assert_eq!(
+            default_grpc_url("https://xyz.cloud.qdrant.io:6333/").unwrap(),
+            "https://xyz.cloud.qdrant.io:6334"
+        );
+        assert!(default_grpc_url("not a url").is_err());
+    }
+
+    #[test]
+    fn dedup_by_key_keeps_last_write() {
+        let input = |key: &str, content: &str| MemoryInput {
+            key: key.into(),
+            content: content.into(),
+            category: MemoryCategory::Core,
+            session_id: None,
+        };
+        let entries = vec![input("a", "1"), input("b", "2"), input("a", "3")];
+        let deduped = dedup_by_key(&entries);
+        let pairs: Vec<(&str, &str)> = deduped
+            .iter()
+            .map(|e| (e.key.as_str(), e.content.as_str()))
+            .collect();
+        assert_eq!(pairs, vec![("b", "2"), ("a", "3")]);
+    }
+
+    #[test]
+    fn stats_from_collection_info_reads_counts_and_segments() {
+        let info = serde_json::json!({
+            "result": {
+                "status": "green",
+                "points_count": 2,
+                "indexed_vectors_count": 0,
+                "segments_count": 4
+            }
+        });
+        let entry = |key: &str, category: MemoryCategory| MemoryEntry {
+            id: key.into(),
+            key: key.into(),
+            content: "c".into(),
+            category,
+            timestamp: "2026-02-20T00:00:00Z".into(),
+            session_id: None,
+            score: None,
+        };
+        let entries = vec![
+            entry("a", MemoryCategory::Core),
+            entry("b", MemoryCategory::Core),
+        ];
+
+        let stats = QdrantMemory::stats_from_collection_info(&info, &entries);
+        assert_eq!(stats.total_entries, 2);
+        assert_eq!(stats.by_category, vec![("core".to_string(), 2)]);
+        assert_eq!(stats.by_namespace, vec![(None, 2)]);
+        assert!(stats
+            .extra
+            .contains(&("segments".to_string(), "4".to_string())));
+        assert!(stats
+            .extra
+            .contains(&("status".to_string(), "green".to_string())));
+    }
 }
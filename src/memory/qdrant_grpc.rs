@@ -0,0 +1,252 @@
+//! Minimal Qdrant gRPC client for point upserts (`qdrant.Points/Upsert`).
+//!
+//! Only the subset of Qdrant's `points.proto` / `json_with_int.proto` needed to
+//! write points is mirrored here; field tags must stay in sync with upstream.
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
+
+const UPSERT_PATH: &str = "/qdrant.Points/Upsert";
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PointId {
+    #[prost(oneof = "point_id::PointIdOptions", tags = "1, 2")]
+    pub point_id_options: Option<point_id::PointIdOptions>,
+}
+
+pub mod point_id {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum PointIdOptions {
+        #[prost(uint64, tag = "1")]
+        Num(u64),
+        #[prost(string, tag = "2")]
+        Uuid(String),
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Vector {
+    #[prost(float, repeated, tag = "1")]
+    pub data: Vec<f32>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Vectors {
+    #[prost(oneof = "vectors::VectorsOptions", tags = "1")]
+    pub vectors_options: Option<vectors::VectorsOptions>,
+}
+
+pub mod vectors {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum VectorsOptions {
+        #[prost(message, tag = "1")]
+        Vector(super::Vector),
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Value {
+    #[prost(oneof = "value::Kind", tags = "1, 2, 3, 4, 5, 6, 7")]
+    pub kind: Option<value::Kind>,
+}
+
+pub mod value {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum Kind {
+        #[prost(int32, tag = "1")]
+        NullValue(i32),
+        #[prost(double, tag = "2")]
+        DoubleValue(f64),
+        #[prost(int64, tag = "3")]
+        IntegerValue(i64),
+        #[prost(string, tag = "4")]
+        StringValue(String),
+        #[prost(bool, tag = "5")]
+        BoolValue(bool),
+        #[prost(message, tag = "6")]
+        StructValue(super::Struct),
+        #[prost(message, tag = "7")]
+        ListValue(super::ListValue),
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Struct {
+    #[prost(btree_map = "string, message", tag = "1")]
+    pub fields: BTreeMap<String, Value>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ListValue {
+    #[prost(message, repeated, tag = "1")]
+    pub values: Vec<Value>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PointStruct {
+    #[prost(message, optional, tag = "1")]
+    pub id: Option<PointId>,
+    #[prost(btree_map = "string, message", tag = "3")]
+    pub payload: BTreeMap<String, Value>,
+    #[prost(message, optional, tag = "4")]
+    pub vectors: Option<Vectors>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct UpsertPoints {
+    #[prost(string, tag = "1")]
+    pub collection_name: String,
+    #[prost(bool, optional, tag = "2")]
+    pub wait: Option<bool>,
+    #[prost(message, repeated, tag = "3")]
+    pub points: Vec<PointStruct>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct UpdateResult {
+    #[prost(uint64, optional, tag = "1")]
+    pub operation_id: Option<u64>,
+    #[prost(int32, tag = "2")]
+    pub status: i32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PointsOperationResponse {
+    #[prost(message, optional, tag = "1")]
+    pub result: Option<UpdateResult>,
+    #[prost(double, tag = "2")]
+    pub time: f64,
+}
+
+/// Convert a JSON value into Qdrant's protobuf `Value`.
+pub fn json_to_value(json: serde_json::Value) -> Value {
+    use value::Kind;
+
+    let kind = match json {
+        serde_json::Value::Null => Kind::NullValue(0),
+        serde_json::Value::Bool(b) => Kind::BoolValue(b),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => Kind::IntegerValue(i),
+            None => Kind::DoubleValue(n.as_f64().unwrap_or_default()),
+        },
+        serde_json::Value::String(s) => Kind::StringValue(s),
+        serde_json::Value::Array(items) => Kind::ListValue(ListValue {
+            values: items.into_iter().map(json_to_value).collect(),
+        }),
+        serde_json::Value::Object(map) => Kind::StructValue(Struct {
+            fields: map
+                .into_iter()
+                .map(|(k, v)| (k, json_to_value(v)))
+                .collect(),
+        }),
+    };
+    Value { kind: Some(kind) }
+}
+
+/// Build a point with a UUID id, a single dense vector, and a JSON object payload.
+pub fn point(id: String, vector: Vec<f32>, payload: serde_json::Value) -> PointStruct {
+    let payload = match json_to_value(payload).kind {
+        Some(value::Kind::StructValue(s)) => s.fields,
+        _ => BTreeMap::new(),
+    };
+    PointStruct {
+        id: Some(PointId {
+            point_id_options: Some(point_id::PointIdOptions::Uuid(id)),
+        }),
+        payload,
+        vectors: Some(Vectors {
+            vectors_options: Some(vectors::VectorsOptions::Vector(Vector { data: vector })),
+        }),
+    }
+}
+
+/// gRPC client for Qdrant point writes; all requests share one HTTP/2 channel.
+pub struct QdrantGrpcClient {
+    endpoint: Endpoint,
+    /// Created on first use so construction works outside a Tokio runtime.
+    channel: OnceLock<Channel>,
+    api_key: Option<String>,
+}
+
+impl QdrantGrpcClient {
+    /// Create a client that connects lazily on first request.
+    pub fn new_lazy(url: &str, api_key: Option<String>) -> Result<Self> {
+        let mut endpoint = Endpoint::from_shared(url.trim_end_matches('/').to_string())
+            .with_context(|| format!("invalid Qdrant gRPC url: {url}"))?;
+        if url.starts_with("https://") {
+            endpoint = endpoint
+                .tls_config(ClientTlsConfig::new().with_native_roots())
+                .context("failed to configure TLS for Qdrant gRPC")?;
+        }
+        Ok(Self {
+            endpoint,
+            channel: OnceLock::new(),
+            api_key,
+        })
+    }
+
+    /// Upsert points and wait for the write to be applied.
+    pub async fn upsert(&self, collection: &str, points: Vec<PointStruct>) -> Result<()> {
+        let channel = self
+            .channel
+            .get_or_init(|| self.endpoint.connect_lazy())
+            .clone();
+        let mut grpc = tonic::client::Grpc::new(channel);
+        grpc.ready()
+            .await
+            .context("Qdrant gRPC channel not ready")?;
+
+        let mut request = tonic::Request::new(UpsertPoints {
+            collection_name: collection.to_string(),
+            wait: Some(true),
+            points,
+        });
+        if let Some(ref key) = self.api_key {
+            request.metadata_mut().insert(
+                "api-key",
+                key.parse().context("invalid Qdrant API key header")?,
+            );
+        }
+
+        let codec = tonic_prost::ProstCodec::<UpsertPoints, PointsOperationResponse>::default();
+        grpc.unary(request, PathAndQuery::from_static(UPSERT_PATH), codec)
+            .await
+            .map_err(|status| {
+                anyhow::anyhow!(
+                    "Qdrant gRPC upsert failed ({:?}): {}",
+                    status.code(),
+                    status.message()
+                )
+            })?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+
+    #[test]
+    fn point_payload_roundtrips_through_protobuf() {
+        let p = point(
+            "6f1c5b1e-0000-4000-8000-000000000001".into(),
+            vec![0.5, -0.25],
+            serde_json::json!({"key": "k", "content": "c", "n": 3}),
+        );
+        let bytes = p.encode_to_vec();
+        let decoded = PointStruct::decode(bytes.as_slice()).unwrap();
+        assert_eq!(decoded, p);
+        assert_eq!(
+            decoded.payload.get("key").and_then(|v| v.kind.clone()),
+            Some(value::Kind::StringValue("k".into()))
+        );
+        assert_eq!(
+            decoded.payload.get("n").and_then(|v| v.kind.clone()),
+            Some(value::Kind::IntegerValue(3))
+        );
+    }
+}
@@ -0,0 +1,247 @@
+//! PII redaction applied to conversation memory before it is persisted.
+//!
+//! Configured under `[redaction]`. Detectors find emails, phone numbers,
+//! payment card numbers and user-defined patterns; the per-channel policy then
+//! masks them, replaces them with a short SHA-256 token, or drops the entry.
+//! The redactor travels with the memory backend (see [`super::autosave`]).
+
+use crate::config::{PiiDetector, RedactionConfig, RedactionPolicy};
+use regex::{Captures, Regex};
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+
+/// Channel key used for turns entered through the CLI agent loop.
+pub const CLI_CHANNEL: &str = "cli";
+/// Channel key used for gateway `/webhook` messages.
+pub const WEBHOOK_CHANNEL: &str = "webhook";
+/// Channel key used for agent-initiated `memory_store` calls.
+pub const AGENT_CHANNEL: &str = "agent";
+
+const HASH_PREFIX_LEN: usize = 12;
+
+struct Rule {
+    label: String,
+    regex: Regex,
+    luhn_checked: bool,
+}
+
+/// Compiled redaction rules plus the channel policy table.
+pub struct PiiRedactor {
+    config: RedactionConfig,
+    rules: Vec<Rule>,
+}
+
+impl PiiRedactor {
+    /// Compile the configured detectors. Invalid custom patterns are skipped
+    /// with a warning; `Config::validate` rejects them on load.
+    pub fn new(config: &RedactionConfig) -> Self {
+        let mut rules = Vec::new();
+        // Card numbers run first so the phone detector cannot claim their digits.
+        for detector in [
+            PiiDetector::CreditCard,
+            PiiDetector::Email,
+            PiiDetector::Phone,
+        ] {
+            if config.detectors.contains(&detector) {
+                rules.push(builtin_rule(detector));
+            }
+        }
+        for custom in &config.custom {
+            match Regex::new(&custom.pattern) {
+                Ok(regex) => rules.push(Rule {
+                    label: custom.name.trim().to_ascii_uppercase(),
+                    regex,
+                    luhn_checked: false,
+                }),
+                Err(error) => {
+                    tracing::warn!("Skipping redaction pattern '{}': {error}", custom.name);
+                }
+            }
+        }
+
+        Self {
+            config: config.clone(),
+            rules,
+        }
+    }
+
+    /// Redact `content` for storage from `channel`.
+    ///
+    /// Returns `None` when the channel policy is `drop` and PII was found,
+    /// meaning the entry must not be persisted at all.
+    pub fn redact<'a>(&self, channel: &str, content: &'a str) -> Option<Cow<'a, str>> {
+        if !self.config.enabled {
+            return Some(Cow::Borrowed(content));
+        }
+
+        let policy = self.config.policy_for(channel);
+        let mut output = Cow::Borrowed(content);
+        let mut found = false;
+
+        for rule in &self.rules {
+            let replaced = rule.regex.replace_all(&output, |caps: &Captures<'_>| {
+                let value = &caps[0];
+                if rule.luhn_checked && !passes_luhn(value) {
+                    return value.to_string();
+                }
+                found = true;
+                replacement(policy, &rule.label, value)
+            });
+            if let Cow::Owned(replaced) = replaced {
+                output = Cow::Owned(replaced);
+            }
+        }
+
+        if found && policy == RedactionPolicy::Drop {
+            None
+        } else {
+            Some(output)
+        }
+    }
+}
+
+fn builtin_rule(detector: PiiDetector) -> Rule {
+    let (label, pattern, luhn_checked) = match detector {
+        PiiDetector::Email => (
+            "EMAIL",
+            r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}\b",
+            false,
+        ),
+        PiiDetector::Phone => (
+            "PHONE",
+            r"(?:\+\d{1,3}[\s.-]?)?(?:\(\d{1,4}\)[\s.-]?|\b\d{2,4}[\s.-])\d{3,4}[\s.-]?\d{3,4}\b|\+\d{8,15}\b",
+            false,
+        ),
+        PiiDetector::CreditCard => ("CARD", r"\b\d(?:[ -]?\d){12,18}\b", true),
+    };
+    Rule {
+        label: label.to_string(),
+        regex: Regex::new(pattern).expect("built-in redaction pattern must compile"),
+        luhn_checked,
+    }
+}
+
+fn replacement(policy: RedactionPolicy, label: &str, value: &str) -> String {
+    match policy {
+        RedactionPolicy::Mask | RedactionPolicy::Drop => format!("[REDACTED:{label}]"),
+        RedactionPolicy::Hash => {
+            let digest = hex::encode(Sha256::digest(value.as_bytes()));
+            format!("[{label}:{}]", &digest[..HASH_PREFIX_LEN])
+        }
+    }
+}
+
+fn passes_luhn(value: &str) -> bool {
+    let digits: Vec<u32> = value.chars().filter_map(|c| c.to_digit(10)).collect();
+    if !(13..=19).contains(&digits.len()) {
+        return false;
+    }
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| {
+            if i % 2 == 1 {
+                let doubled = d * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                d
+            }
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CustomRedactionPattern;
+
+    fn enabled(policy: RedactionPolicy) -> RedactionConfig {
+        RedactionConfig {
+            enabled: true,
+            policy,
+            ..RedactionConfig::default()
+        }
+    }
+
+    #[test]
+    fn disabled_redactor_passes_content_through() {
+        let redactor = PiiRedactor::new(&RedactionConfig::default());
+        let text = "mail me at jane@example.com";
+        assert_eq!(redactor.redact("telegram", text).as_deref(), Some(text));
+    }
+
+    #[test]
+    fn mask_policy_replaces_builtin_detectors() {
+        let redactor = PiiRedactor::new(&enabled(RedactionPolicy::Mask));
+        let out = redactor
+            .redact(
+                "telegram",
+                "jane@example.com, +1 (415) 555-2671, card 4111 1111 1111 1111 on 2024-01-15",
+            )
+            .unwrap();
+        assert_eq!(
+            out,
+            "[REDACTED:EMAIL], [REDACTED:PHONE], card [REDACTED:CARD] on 2024-01-15"
+        );
+    }
+
+    #[test]
+    fn card_detector_ignores_numbers_failing_luhn() {
+        let redactor = PiiRedactor::new(&RedactionConfig {
+            detectors: vec![PiiDetector::CreditCard],
+            ..enabled(RedactionPolicy::Mask)
+        });
+        let text = "order 1234567890123456 shipped";
+        assert_eq!(redactor.redact("cli", text).as_deref(), Some(text));
+    }
+
+    #[test]
+    fn hash_policy_is_stable_and_hides_the_value() {
+        let redactor = PiiRedactor::new(&enabled(RedactionPolicy::Hash));
+        let first = redactor.redact("cli", "jane@example.com").unwrap();
+        let second = redactor.redact("cli", "ping jane@example.com").unwrap();
+        assert!(first.starts_with("[EMAIL:"));
+        assert!(!first.contains("jane"));
+        assert!(second.ends_with(first.as_ref()));
+    }
+
+    #[test]
+    fn channel_override_can_drop_entries_with_pii() {
+        let mut config = enabled(RedactionPolicy::Mask);
+        config
+            .channels
+            .insert("telegram".into(), RedactionPolicy::Drop);
+        let redactor = PiiRedactor::new(&config);
+
+        assert!(redactor.redact("telegram", "jane@example.com").is_none());
+        assert_eq!(
+            redactor.redact("telegram", "no pii here").as_deref(),
+            Some("no pii here")
+        );
+        assert_eq!(
+            redactor.redact("discord", "jane@example.com").as_deref(),
+            Some("[REDACTED:EMAIL]")
+        );
+    }
+
+    #[test]
+    fn custom_patterns_use_their_name_as_label() {
+        let redactor = PiiRedactor::new(&RedactionConfig {
+            custom: vec![CustomRedactionPattern {
+                name: "employee_id".into(),
+                pattern: r"\bEMP-\d{5}\b".into(),
+            }],
+            ..enabled(RedactionPolicy::Mask)
+        });
+        assert_eq!(
+            redactor.redact("slack", "badge EMP-12345").as_deref(),
+            Some("badge [REDACTED:EMPLOYEE_ID]")
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use super::embeddings::EmbeddingProvider;
-use super::traits::{Memory, MemoryCategory, MemoryEntry};
+use super::traits::{Memory, MemoryCategory, MemoryCompactReport, MemoryEntry, MemoryStorageStats};
 use super::vector;
+use crate::config::SqliteConfig;
 use anyhow::Context;
 use async_trait::async_trait;
 use chrono::Local;
@@ -17,6 +18,28 @@ use uuid::Uuid;
 /// Maximum allowed open timeout (seconds) to avoid unreasonable waits.
 const SQLITE_OPEN_TIMEOUT_CAP_SECS: u64 = 300;
 
+/// Render the connection PRAGMA batch for the given tuning.
+///
+/// Defaults reproduce the production profile:
+/// WAL mode: concurrent reads during writes, crash-safe
+/// normal sync: 2× write speed, still durable on WAL
+/// mmap 8 MB: let the OS page-cache serve hot reads
+/// cache 2 MB: keep ~500 hot pages in-process
+/// temp_store memory: temp tables never hit disk
+fn tuning_pragmas(tuning: &SqliteConfig) -> String {
+    format!(
+        "PRAGMA journal_mode = {};
+         PRAGMA synchronous  = {};
+         PRAGMA mmap_size    = {};
+         PRAGMA cache_size   = -{};
+         PRAGMA temp_store   = MEMORY;",
+        tuning.journal_mode.as_pragma(),
+        tuning.synchronous.as_pragma(),
+        tuning.mmap_size_bytes,
+        tuning.cache_size_kib,
+    )
+}
+
 /// SQLite-backed persistent memory — the brain
 ///
 /// Full-stack search engine:
@@ -58,6 +81,30 @@ impl SqliteMemory {
         keyword_weight: f32,
         cache_max: usize,
         open_timeout_secs: Option<u64>,
+    ) -> anyhow::Result<Self> {
+        Self::with_tuning(
+            workspace_dir,
+            embedder,
+            vector_weight,
+            keyword_weight,
+            cache_max,
+            open_timeout_secs,
+            &SqliteConfig::default(),
+        )
+    }
+
+    /// Build SQLite memory with explicit pragma tuning (`[memory.sqlite]`).
+    ///
+    /// When `open_timeout_secs` is set, a busy handler also retries locked
+    /// reads/writes for up to that long instead of failing with `SQLITE_BUSY`.
+    pub fn with_tuning(
+        workspace_dir: &Path,
+        embedder: Arc<dyn EmbeddingProvider>,
+        vector_weight: f32,
+        keyword_weight: f32,
+        cache_max: usize,
+        open_timeout_secs: Option<u64>,
+        tuning: &SqliteConfig,
     ) -> anyhow::Result<Self> {
         let db_path = workspace_dir.join("memory").join("brain.db");
 
@@ -67,19 +114,13 @@ impl SqliteMemory {
 
         let conn = Self::open_connection(&db_path, open_timeout_secs)?;
 
-        // ── Production-grade PRAGMA tuning ──────────────────────
-        // WAL mode: concurrent reads during writes, crash-safe
-        // normal sync: 2× write speed, still durable on WAL
-        // mmap 8 MB: let the OS page-cache serve hot reads
-        // cache 2 MB: keep ~500 hot pages in-process
-        // temp_store memory: temp tables never hit disk
-        conn.execute_batch(
-            "PRAGMA journal_mode = WAL;
-             PRAGMA synchronous  = NORMAL;
-             PRAGMA mmap_size    = 8388608;
-             PRAGMA cache_size   = -2000;
-             PRAGMA temp_store   = MEMORY;",
-        )?;
+        if let Some(secs) = open_timeout_secs {
+            conn.busy_timeout(Duration::from_secs(secs.min(SQLITE_OPEN_TIMEOUT_CAP_SECS)))
+                .context("SQLite failed to install busy handler")?;
+        }
+
+        conn.execute_batch(&tuning_pragmas(tuning))
+            .context("SQLite failed to apply pragma tuning")?;
 
         Self::init_schema(&conn)?;
 
@@ -376,6 +417,86 @@ impl SqliteMemory {
         Ok(scored)
     }
 
+    /// Collect deep storage statistics from an open connection.
+    fn collect_storage_stats(conn: &Connection) -> anyhow::Result<MemoryStorageStats> {
+        let page_size: i64 = conn.query_row("PRAGMA page_size", [], |row| row.get(0))?;
+        let page_count: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+        let freelist: i64 = conn.query_row("PRAGMA freelist_count", [], |row| row.get(0))?;
+        let total: i64 = conn.query_row("SELECT COUNT(*) FROM memories", [], |row| row.get(0))?;
+        let without_vectors: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM memories WHERE embedding IS NULL",
+            [],
+            |row| row.get(0),
+        )?;
+
+        let mut by_category = Vec::new();
+        let mut stmt = conn.prepare(
+            "SELECT category, COUNT(*) AS n FROM memories GROUP BY category ORDER BY n DESC, category",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })?;
+        for row in rows {
+            let (category, count) = row?;
+            #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+            by_category.push((category, count as usize));
+        }
+
+        let mut by_namespace = Vec::new();
+        let mut stmt = conn.prepare(
+            "SELECT session_id, COUNT(*) AS n FROM memories GROUP BY session_id ORDER BY n DESC",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, Option<String>>(0)?, row.get::<_, i64>(1)?))
+        })?;
+        for row in rows {
+            let (session, count) = row?;
+            #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+            by_namespace.push((session, count as usize));
+        }
+
+        let index_sizes = [
+            (
+                "fts5",
+                "SELECT COALESCE(SUM(LENGTH(block)), 0) FROM memories_fts_data",
+            ),
+            (
+                "vectors",
+                "SELECT COALESCE(SUM(LENGTH(embedding)), 0) FROM memories",
+            ),
+            (
+                "embedding_cache",
+                "SELECT COALESCE(SUM(LENGTH(embedding)), 0) FROM embedding_cache",
+            ),
+        ];
+        let mut index_bytes = Vec::new();
+        for (name, sql) in index_sizes {
+            let bytes: i64 = conn.query_row(sql, [], |row| row.get(0)).unwrap_or(0);
+            #[allow(clippy::cast_sign_loss)]
+            index_bytes.push((name.to_string(), bytes.max(0) as u64));
+        }
+
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        Ok(MemoryStorageStats {
+            total_entries: total as usize,
+            by_category,
+            by_namespace,
+            entries_without_vectors: Some(without_vectors as usize),
+            index_bytes,
+            storage_bytes: Some((page_size * page_count).max(0) as u64),
+            reclaimable_bytes: Some((page_size * freelist).max(0) as u64),
+            extra: Vec::new(),
+        })
+    }
+
+    /// Current on-disk size of the database (pages × page size).
+    fn database_bytes(conn: &Connection) -> anyhow::Result<u64> {
+        let page_size: i64 = conn.query_row("PRAGMA page_size", [], |row| row.get(0))?;
+        let page_count: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+        #[allow(clippy::cast_sign_loss)]
+        Ok((page_size * page_count).max(0) as u64)
+    }
+
     /// Safe reindex: rebuild FTS5 + embeddings with rollback on failure
     #[allow(dead_code)]
     pub async fn reindex(&self) -> anyhow::Result<usize> {
@@ -779,6 +900,40 @@ impl Memory for SqliteMemory {
             .await
             .unwrap_or(false)
     }
+
+    async fn storage_stats(&self) -> anyhow::Result<Option<MemoryStorageStats>> {
+        let conn = self.conn.clone();
+        let stats = tokio::task::spawn_blocking(move || {
+            let conn = conn.lock();
+            Self::collect_storage_stats(&conn)
+        })
+        .await??;
+        Ok(Some(stats))
+    }
+
+    async fn compact(&self) -> anyhow::Result<Option<MemoryCompactReport>> {
+        let conn = self.conn.clone();
+        let report = tokio::task::spawn_blocking(move || -> anyhow::Result<MemoryCompactReport> {
+            let conn = conn.lock();
+            let before = Self::database_bytes(&conn)?;
+            // Merge FTS5 b-tree segments, fold the WAL back into the main
+            // file, then rebuild the database to drop free pages.
+            conn.execute_batch(
+                "INSERT INTO memories_fts(memories_fts) VALUES('optimize');
+                 PRAGMA wal_checkpoint(TRUNCATE);
+                 VACUUM;
+                 PRAGMA optimize;",
+            )?;
+            let after = Self::database_bytes(&conn)?;
+            Ok(MemoryCompactReport {
+                bytes_before: Some(before),
+                bytes_after: Some(after),
+                summary: "optimized FTS5 index, checkpointed WAL, vacuumed database".into(),
+            })
+        })
+        .await??;
+        Ok(Some(report))
+    }
 }
 
 #[cfg(test)]
@@ -1255,6 +1410,79 @@ mod tests {
         assert_eq!(entry.content, "value with timeout");
     }
 
+    #[test]
+    fn open_with_timeout_installs_busy_handler() {
+        let tmp = TempDir::new().unwrap();
+        let embedder = Arc::new(super::super::embeddings::NoopEmbedding);
+        let mem =
+            SqliteMemory::with_embedder(tmp.path(), embedder, 0.7, 0.3, 1000, Some(3)).unwrap();
+        let conn = mem.conn.lock();
+        let busy_ms: i64 = conn
+            .query_row("PRAGMA busy_timeout", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(busy_ms, 3000);
+    }
+
+    // ── Pragma tuning tests ──────────────────────────────────────
+
+    #[test]
+    fn default_tuning_matches_production_profile() {
+        let (_tmp, mem) = temp_sqlite();
+        let conn = mem.conn.lock();
+        let journal: String = conn
+            .query_row("PRAGMA journal_mode", [], |row| row.get(0))
+            .unwrap();
+        let sync: i64 = conn
+            .query_row("PRAGMA synchronous", [], |row| row.get(0))
+            .unwrap();
+        let cache: i64 = conn
+            .query_row("PRAGMA cache_size", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(journal, "wal");
+        assert_eq!(sync, 1, "NORMAL");
+        assert_eq!(cache, -2000);
+    }
+
+    #[test]
+    fn custom_tuning_is_applied() {
+        use crate::config::{SqliteJournalMode, SqliteSynchronous};
+
+        let tmp = TempDir::new().unwrap();
+        let tuning = SqliteConfig {
+            journal_mode: SqliteJournalMode::Delete,
+            synchronous: SqliteSynchronous::Full,
+            cache_size_kib: 4000,
+            mmap_size_bytes: 0,
+        };
+        let mem = SqliteMemory::with_tuning(
+            tmp.path(),
+            Arc::new(super::super::embeddings::NoopEmbedding),
+            0.7,
+            0.3,
+            1000,
+            None,
+            &tuning,
+        )
+        .unwrap();
+        let conn = mem.conn.lock();
+        let journal: String = conn
+            .query_row("PRAGMA journal_mode", [], |row| row.get(0))
+            .unwrap();
+        let sync: i64 = conn
+            .query_row("PRAGMA synchronous", [], |row| row.get(0))
+            .unwrap();
+        let cache: i64 = conn
+            .query_row("PRAGMA cache_size", [], |row| row.get(0))
+            .unwrap();
+        let mmap: i64 = conn
+            .query_row("PRAGMA mmap_size", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(journal, "delete");
+        assert_eq!(sync, 2, "FULL");
+        assert_eq!(cache, -4000);
+        assert_eq!(mmap, 0);
+    }
+
     // ── With-embedder constructor test ───────────────────────────
 
     #[test]
@@ -1897,4 +2125,50 @@ mod tests {
 
         assert_eq!(mem.count().await.unwrap(), 1);
     }
+
+    #[tokio::test]
+    async fn sqlite_storage_stats_reports_categories_and_coverage() {
+        let (_tmp, mem) = temp_sqlite();
+        mem.store("a", "Rust is fast", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+        mem.store("b", "standup notes", MemoryCategory::Daily, Some("s1"))
+            .await
+            .unwrap();
+        mem.store("c", "more notes", MemoryCategory::Daily, Some("s1"))
+            .await
+            .unwrap();
+
+        let stats = mem.storage_stats().await.unwrap().unwrap();
+        assert_eq!(stats.total_entries, 3);
+        assert_eq!(stats.by_category[0], ("daily".to_string(), 2));
+        assert!(stats.by_namespace.contains(&(Some("s1".to_string()), 2)));
+        // Noop embedder never stores vectors
+        assert_eq!(stats.entries_without_vectors, Some(3));
+        assert!(stats.storage_bytes.unwrap() > 0);
+        assert!(stats.index_bytes.iter().any(|(name, _)| name == "fts5"));
+    }
+
+    #[tokio::test]
+    async fn sqlite_compact_preserves_entries() {
+        let (_tmp, mem) = temp_sqlite();
+        for i in 0..20 {
+            mem.store(
+                &format!("k{i}"),
+                &"x".repeat(512),
+                MemoryCategory::Core,
+                None,
+            )
+            .await
+            .unwrap();
+        }
+        for i in 0..15 {
+            mem.forget(&format!("k{i}")).await.unwrap();
+        }
+
+        let report = mem.compact().await.unwrap().unwrap();
+        assert!(report.bytes_after.unwrap() <= report.bytes_before.unwrap());
+        assert_eq!(mem.count().await.unwrap(), 5);
+        assert_eq!(mem.recall("x", 10, None).await.unwrap().len(), 5);
+    }
 }
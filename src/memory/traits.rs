@@ -51,12 +51,76 @@ impl std::fmt::Display for MemoryCategory {
     }
 }
 
+/// Backend storage statistics reported by `zeroclaw memory stats --deep`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MemoryStorageStats {
+    /// Total entries stored in the backend
+    pub total_entries: usize,
+    /// Entry counts per category, sorted by count (descending)
+    pub by_category: Vec<(String, usize)>,
+    /// Entry counts per session namespace (`None` session = global)
+    pub by_namespace: Vec<(Option<String>, usize)>,
+    /// Entries stored without an embedding vector (`None` = not tracked)
+    pub entries_without_vectors: Option<usize>,
+    /// Named index/structure sizes in bytes (FTS, vectors, caches)
+    pub index_bytes: Vec<(String, u64)>,
+    /// Total on-disk size of the store in bytes
+    pub storage_bytes: Option<u64>,
+    /// Bytes held by free pages that compaction can reclaim
+    pub reclaimable_bytes: Option<u64>,
+    /// Backend-specific extra facts (segment counts, optimizer status)
+    pub extra: Vec<(String, String)>,
+}
+
+impl MemoryStorageStats {
+    /// Fraction of storage held by free pages (0.0–1.0), when known.
+    pub fn fragmentation(&self) -> Option<f64> {
+        let total = self.storage_bytes.filter(|total| *total > 0)?;
+        let free = self.reclaimable_bytes?;
+        Some(free as f64 / total as f64)
+    }
+}
+
+/// Result of a `Memory::compact` pass.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MemoryCompactReport {
+    /// On-disk size before compaction, when measurable
+    pub bytes_before: Option<u64>,
+    /// On-disk size after compaction, when measurable
+    pub bytes_after: Option<u64>,
+    /// Human-readable summary of what was done
+    pub summary: String,
+}
+
+/// A pending write for `Memory::store_batch`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryInput {
+    pub key: String,
+    pub content: String,
+    pub category: MemoryCategory,
+    pub session_id: Option<String>,
+}
+
 /// Core memory trait — implement for any persistence backend
 #[async_trait]
 pub trait Memory: Send + Sync {
     /// Backend name
     fn name(&self) -> &str;
 
+    /// Prepare `content` written from `channel` for storage. Backends built
+    /// with a `[redaction]` policy apply it here; `None` means the entry must
+    /// not be stored.
+    fn redact_for_storage(&self, _channel: &str, content: &str) -> Option<String> {
+        Some(content.to_string())
+    }
+
+    /// Category for an auto-saved message. Backends built with
+    /// `[memory.classification]` file it under the matching taxonomy entry;
+    /// otherwise it stays in `conversation`.
+    fn auto_save_category(&self, _content: &str) -> MemoryCategory {
+        MemoryCategory::Conversation
+    }
+
     /// Store a memory entry, optionally scoped to a session
     async fn store(
         &self,
@@ -66,6 +130,21 @@ pub trait Memory: Send + Sync {
         session_id: Option<&str>,
     ) -> anyhow::Result<()>;
 
+    /// Store many entries at once. Backends with a bulk write path (e.g. Qdrant)
+    /// override this; the default stores entries one by one.
+    async fn store_batch(&self, entries: &[MemoryInput]) -> anyhow::Result<usize> {
+        for entry in entries {
+            self.store(
+                &entry.key,
+                &entry.content,
+                entry.category.clone(),
+                entry.session_id.as_deref(),
+            )
+            .await?;
+        }
+        Ok(entries.len())
+    }
+
     /// Recall memories matching a query (keyword search), optionally scoped to a session
     async fn recall(
         &self,
@@ -92,6 +171,17 @@ pub trait Memory: Send + Sync {
 
     /// Health check
     async fn health_check(&self) -> bool;
+
+    /// Deep storage statistics (index sizes, embedding coverage, fragmentation).
+    /// Backends that cannot introspect their storage return `None`.
+    async fn storage_stats(&self) -> anyhow::Result<Option<MemoryStorageStats>> {
+        Ok(None)
+    }
+
+    /// Reclaim space and optimize indexes. Returns `None` when unsupported.
+    async fn compact(&self) -> anyhow::Result<Option<MemoryCompactReport>> {
+        Ok(None)
+    }
 }
 
 #[cfg(test)]
@@ -142,4 +232,17 @@ mod tests {
         assert_eq!(parsed.session_id.as_deref(), Some("session-abc"));
         assert_eq!(parsed.score, Some(0.98));
     }
+
+    #[test]
+    fn storage_stats_fragmentation_ratio() {
+        let stats = MemoryStorageStats {
+            storage_bytes: Some(4096),
+            reclaimable_bytes: Some(1024),
+            ..MemoryStorageStats::default()
+        };
+        assert_eq!(stats.fragmentation(), Some(0.25));
+
+        let unknown = MemoryStorageStats::default();
+        assert_eq!(unknown.fragmentation(), None);
+    }
 }
@@ -1,5 +1,5 @@
 use crate::config::Config;
-use crate::memory::{self, Memory, MemoryCategory};
+use crate::memory::{self, Memory, MemoryCategory, MemoryInput};
 use anyhow::{bail, Context, Result};
 use directories::UserDirs;
 use rusqlite::{Connection, OpenFlags, OptionalExtension};
@@ -78,28 +78,36 @@ async fn migrate_openclaw_memory(
 
     let memory = target_memory_backend(config)?;
 
+    let mut pending: Vec<MemoryInput> = Vec::new();
+    let mut pending_keys: HashSet<String> = HashSet::new();
     for (idx, entry) in entries.into_iter().enumerate() {
         let mut key = entry.key.trim().to_string();
         if key.is_empty() {
             key = format!("openclaw_{idx}");
         }
 
-        if let Some(existing) = memory.get(&key).await? {
-            if existing.content.trim() == entry.content.trim() {
-                stats.skipped_unchanged += 1;
-                continue;
-            }
-
-            let renamed = next_available_key(memory.as_ref(), &key).await?;
-            key = renamed;
+        let existing = memory.get(&key).await?;
+        if existing
+            .as_ref()
+            .is_some_and(|existing| existing.content.trim() == entry.content.trim())
+        {
+            stats.skipped_unchanged += 1;
+            continue;
+        }
+        if existing.is_some() || pending_keys.contains(&key) {
+            key = next_available_key(memory.as_ref(), &key, &pending_keys).await?;
             stats.renamed_conflicts += 1;
         }
 
-        memory
-            .store(&key, &entry.content, entry.category, None)
-            .await?;
-        stats.imported += 1;
+        pending_keys.insert(key.clone());
+        pending.push(MemoryInput {
+            key,
+            content: entry.content,
+            category: entry.category,
+            session_id: None,
+        });
     }
+    stats.imported = memory.store_batch(&pending).await?;
 
     println!("✅ OpenClaw memory migration complete");
     println!("  Source: {}", source_workspace.display());
@@ -114,7 +122,7 @@ async fn migrate_openclaw_memory(
 }
 
 fn target_memory_backend(config: &Config) -> Result<Box<dyn Memory>> {
-    memory::create_memory_for_migration(&config.memory.backend, &config.workspace_dir)
+    memory::cli::create_import_memory(config)
 }
 
 fn collect_source_entries(
@@ -315,10 +323,14 @@ fn normalize_key(key: &str, fallback_idx: usize) -> String {
     trimmed.to_string()
 }
 
-async fn next_available_key(memory: &dyn Memory, base: &str) -> Result<String> {
+async fn next_available_key(
+    memory: &dyn Memory,
+    base: &str,
+    pending: &HashSet<String>,
+) -> Result<String> {
     for i in 1..=10_000 {
         let candidate = format!("{base}__openclaw_{i}");
-        if memory.get(&candidate).await?.is_none() {
+        if !pending.contains(&candidate) && memory.get(&candidate).await?.is_none() {
             return Ok(candidate);
         }
     }
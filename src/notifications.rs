@@ -0,0 +1,213 @@
+//! Terminal bell and desktop notifications for important runtime events,
+//! plus the toast bus behind the dashboard's corner notices.
+//!
+//! Bell and desktop delivery are configured under `[ui.notifications]` and are
+//! best-effort: a missing `notify-send`/`osascript`/`powershell` binary or a
+//! non-terminal stderr is silently ignored so notifications never interfere
+//! with the event itself. Toasts go through a [`Toasts`] handle owned by the
+//! runtime (the daemon, or `zeroclaw gateway` on its own) and shared with the
+//! channel runtime; the gateway forwards them to dashboard clients over
+//! `/api/events`.
+
+pub use crate::config::NotifyEvent;
+use crate::config::UiNotificationsConfig;
+use serde::Serialize;
+use std::io::{IsTerminal, Write};
+use std::process::{Command, Stdio};
+use tokio::sync::broadcast;
+
+/// Toasts buffered per subscriber before the oldest are dropped.
+const TOAST_BUS_CAPACITY: usize = 64;
+
+impl NotifyEvent {
+    fn title(self) -> &'static str {
+        match self {
+            Self::ChannelDown => "ZeroClaw: component down",
+            Self::ApprovalRequested => "ZeroClaw: approval requested",
+            Self::ReminderFired => "ZeroClaw: reminder",
+        }
+    }
+}
+
+fn event_enabled(config: &UiNotificationsConfig, event: NotifyEvent) -> bool {
+    (config.bell || config.desktop) && config.events.contains(&event)
+}
+
+/// Raise an attention signal for `event` as configured by `config`.
+pub fn notify(config: &UiNotificationsConfig, event: NotifyEvent, body: &str) {
+    if !event_enabled(config, event) {
+        return;
+    }
+
+    if config.bell {
+        ring_bell();
+    }
+    if config.desktop {
+        if let Err(e) = show_desktop_notification(event.title(), body) {
+            tracing::debug!("Desktop notification failed: {e}");
+        }
+    }
+}
+
+/// Severity of a dashboard toast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ToastLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+/// A short background notice shown briefly in a corner of the dashboard.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Toast {
+    pub level: ToastLevel,
+    pub message: String,
+}
+
+/// Shared handle to the toast bus. Clones publish to the same subscribers.
+#[derive(Debug, Clone)]
+pub struct Toasts {
+    tx: broadcast::Sender<Toast>,
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Self {
+            tx: broadcast::channel(TOAST_BUS_CAPACITY).0,
+        }
+    }
+}
+
+impl Toasts {
+    /// Publish a toast. Dropped when nothing is subscribed (no gateway running).
+    pub fn publish(&self, level: ToastLevel, message: impl Into<String>) {
+        let _ = self.tx.send(Toast {
+            level,
+            message: message.into(),
+        });
+    }
+
+    /// Receive toasts published from now on.
+    pub fn subscribe(&self) -> broadcast::Receiver<Toast> {
+        self.tx.subscribe()
+    }
+}
+
+fn ring_bell() {
+    let mut stderr = std::io::stderr();
+    if stderr.is_terminal() {
+        let _ = stderr.write_all(b"\x07");
+        let _ = stderr.flush();
+    }
+}
+
+/// Escape a value for embedding inside a double-quoted AppleScript string.
+fn applescript_quote(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Escape a value for embedding inside a single-quoted PowerShell string.
+fn powershell_quote(value: &str) -> String {
+    value.replace('\'', "''")
+}
+
+fn show_desktop_notification(title: &str, body: &str) -> std::io::Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        let mut cmd = Command::new("osascript");
+        cmd.arg("-e").arg(format!(
+            "display notification \"{}\" with title \"{}\"",
+            applescript_quote(body),
+            applescript_quote(title)
+        ));
+        cmd
+    } else if cfg!(windows) {
+        let script = format!(
+            "Add-Type -AssemblyName System.Windows.Forms; \
+             $n = New-Object System.Windows.Forms.NotifyIcon; \
+             $n.Icon = [System.Drawing.SystemIcons]::Information; \
+             $n.Visible = $true; \
+             $n.ShowBalloonTip(5000, '{}', '{}', 'Info'); \
+             Start-Sleep -Seconds 6; $n.Dispose()",
+            powershell_quote(title),
+            powershell_quote(body)
+        );
+        let mut cmd = Command::new("powershell");
+        cmd.args(["-NoProfile", "-NonInteractive", "-Command", &script]);
+        cmd
+    } else {
+        let mut cmd = Command::new("notify-send");
+        // `--` so a title or body starting with `-` is not read as an option.
+        cmd.args(["--app-name=ZeroClaw", "--", title, body]);
+        cmd
+    };
+
+    // The notifier must never block the caller; a detached thread reaps the
+    // child so the long-running daemon does not collect zombies.
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_are_disabled_without_a_delivery_method() {
+        let config = UiNotificationsConfig::default();
+        assert!(!event_enabled(&config, NotifyEvent::ChannelDown));
+        assert!(!event_enabled(&config, NotifyEvent::ApprovalRequested));
+        assert!(!event_enabled(&config, NotifyEvent::ReminderFired));
+    }
+
+    #[test]
+    fn per_event_toggles_apply_once_a_method_is_enabled() {
+        let config = UiNotificationsConfig {
+            bell: true,
+            events: vec![NotifyEvent::ChannelDown, NotifyEvent::ApprovalRequested],
+            ..UiNotificationsConfig::default()
+        };
+        assert!(event_enabled(&config, NotifyEvent::ChannelDown));
+        assert!(event_enabled(&config, NotifyEvent::ApprovalRequested));
+        assert!(!event_enabled(&config, NotifyEvent::ReminderFired));
+    }
+
+    #[test]
+    fn toasts_reach_subscribers() {
+        let toasts = Toasts::default();
+        let mut rx = toasts.subscribe();
+        toasts
+            .clone()
+            .publish(ToastLevel::Warning, "Emergency stop engaged");
+        let toast = rx.try_recv().unwrap();
+        assert_eq!(toast.level, ToastLevel::Warning);
+        assert_eq!(toast.message, "Emergency stop engaged");
+        assert_eq!(
+            serde_json::to_value(&toast).unwrap()["level"],
+            serde_json::json!("warning")
+        );
+    }
+
+    #[test]
+    fn separate_buses_do_not_share_toasts() {
+        let first = Toasts::default();
+        let second = Toasts::default();
+        let mut rx = second.subscribe();
+        first.publish(ToastLevel::Info, "not for you");
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn quoting_escapes_script_delimiters() {
+        assert_eq!(applescript_quote(r#"say "hi" \o/"#), r#"say \"hi\" \\o/"#);
+        assert_eq!(powershell_quote("it's"), "it''s");
+    }
+}
@@ -0,0 +1,238 @@
+//! Runtime debug mode (`!debug on|off` in chat, or the dashboard toggle).
+//!
+//! Turning it on raises ZeroClaw's own log level to `debug` through the
+//! reload handle installed at startup and starts the runtime's LLM transcript
+//! [`Recorder`] under `<workspace>/state/llm-records`. Turning it off restores
+//! the startup filter and stops a recording it started. Nothing is restarted,
+//! so an intermittent channel issue can be captured while it is happening.
+//!
+//! The log filter is process-wide, so the on/off state is too. Who may send
+//! `!debug` is a [`DebugCommand`] owned by the channel runtime.
+
+use crate::config::Config;
+use crate::providers::recorder::Recorder;
+use anyhow::Result;
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+pub const DEBUG_COMMAND: &str = "!debug";
+const RECORD_SUBDIR: &str = "state/llm-records";
+
+type ReloadFn = Box<dyn Fn(&str) -> Result<()> + Send + Sync>;
+
+struct LogControl {
+    base: String,
+    reload: ReloadFn,
+}
+
+#[derive(Default)]
+struct DebugState {
+    enabled: bool,
+    /// Whether the recorder was started by debug mode (and so may be stopped by it).
+    started_recording: bool,
+}
+
+/// `!debug` settings from `[observability].debug_command_users`.
+#[derive(Debug, Clone, Default)]
+pub struct DebugCommand {
+    users: Vec<String>,
+    workspace_dir: PathBuf,
+    recorder: Recorder,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DebugStatus {
+    pub enabled: bool,
+    pub log_filter: Option<String>,
+    pub recording_dir: Option<String>,
+}
+
+static LOG_CONTROL: OnceLock<LogControl> = OnceLock::new();
+static STATE: OnceLock<Mutex<DebugState>> = OnceLock::new();
+
+fn state() -> &'static Mutex<DebugState> {
+    STATE.get_or_init(Mutex::default)
+}
+
+/// Register the log filter reload hook. `base` is the startup filter that
+/// debug mode returns to. Called once from `main` after logging is set up.
+pub fn install_log_reload(base: String, reload: ReloadFn) {
+    let _ = LOG_CONTROL.set(LogControl { base, reload });
+}
+
+fn debug_filter(base: &str) -> String {
+    if base.trim().is_empty() {
+        "zeroclaw=debug".into()
+    } else {
+        format!("{base},zeroclaw=debug")
+    }
+}
+
+fn current_filter(enabled: bool) -> Option<String> {
+    let control = LOG_CONTROL.get()?;
+    Some(if enabled {
+        debug_filter(&control.base)
+    } else {
+        control.base.clone()
+    })
+}
+
+pub fn status(recorder: &Recorder) -> DebugStatus {
+    let enabled = state().lock().enabled;
+    DebugStatus {
+        enabled,
+        log_filter: current_filter(enabled),
+        recording_dir: recorder.dir().map(|dir| dir.display().to_string()),
+    }
+}
+
+/// Switch debug mode on or off. A recording it starts on `recorder` goes
+/// under `workspace_dir`.
+pub fn set_enabled(
+    enabled: bool,
+    workspace_dir: &Path,
+    recorder: &Recorder,
+) -> Result<DebugStatus> {
+    let mut state = state().lock();
+    if enabled && recorder.dir().is_none() {
+        recorder.enable(&workspace_dir.join(RECORD_SUBDIR))?;
+        state.started_recording = true;
+    } else if !enabled && state.started_recording {
+        recorder.disable();
+        state.started_recording = false;
+    }
+
+    if let (Some(control), Some(filter)) = (LOG_CONTROL.get(), current_filter(enabled)) {
+        (control.reload)(&filter)?;
+    }
+
+    state.enabled = enabled;
+    tracing::info!(
+        "Debug mode {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    drop(state);
+    Ok(status(recorder))
+}
+
+/// Entries are `<channel>:<sender>`; the channel matches case-insensitively
+/// and the sender exactly, so an ID granted on one channel does not carry over
+/// to another where someone else may hold it.
+fn may_toggle(users: &[String], channel: &str, sender: &str) -> bool {
+    users.iter().any(|user| {
+        user.split_once(':')
+            .is_some_and(|(allowed_channel, allowed_sender)| {
+                allowed_channel.eq_ignore_ascii_case(channel)
+                    && allowed_sender != "*"
+                    && allowed_sender == sender
+            })
+    })
+}
+
+fn describe(status: &DebugStatus) -> String {
+    let mut reply = format!(
+        "Debug mode is {}.",
+        if status.enabled { "on" } else { "off" }
+    );
+    if let Some(filter) = status.log_filter.as_deref() {
+        let _ = write!(reply, "\nLog filter: `{filter}`");
+    }
+    match status.recording_dir.as_deref() {
+        Some(dir) => {
+            let _ = write!(reply, "\nLLM transcripts: `{dir}`");
+        }
+        None => reply.push_str("\nLLM transcripts: not recording"),
+    }
+    reply
+}
+
+impl DebugCommand {
+    pub fn new(config: &Config, recorder: Recorder) -> Self {
+        Self {
+            users: config.observability.debug_command_users.clone(),
+            workspace_dir: config.workspace_dir.clone(),
+            recorder,
+        }
+    }
+
+    /// Handle `!debug on|off|status`. Returns the reply, or `None` when
+    /// `content` is not the command.
+    pub fn handle(&self, channel: &str, sender: &str, content: &str) -> Option<String> {
+        let mut parts = content.split_whitespace();
+        if !parts.next()?.eq_ignore_ascii_case(DEBUG_COMMAND) {
+            return None;
+        }
+        if !may_toggle(&self.users, channel, sender) {
+            return Some(format!(
+                "`{DEBUG_COMMAND}` is not permitted for `{channel}:{sender}`. \
+                 Ask the owner to add it to `[observability].debug_command_users`."
+            ));
+        }
+
+        let result = match parts.next().map(str::to_ascii_lowercase).as_deref() {
+            Some("on") => set_enabled(true, &self.workspace_dir, &self.recorder),
+            Some("off") => set_enabled(false, &self.workspace_dir, &self.recorder),
+            None | Some("status") => Ok(status(&self.recorder)),
+            Some(other) => {
+                return Some(format!(
+                    "Unknown argument `{other}`. Usage: `{DEBUG_COMMAND} on|off|status`"
+                ))
+            }
+        };
+        Some(match result {
+            Ok(status) => describe(&status),
+            Err(error) => format!("Failed to switch debug mode: {error:#}"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_filter_keeps_the_startup_directives() {
+        assert_eq!(debug_filter("info"), "info,zeroclaw=debug");
+        assert_eq!(
+            debug_filter("warn,hyper=off"),
+            "warn,hyper=off,zeroclaw=debug"
+        );
+        assert_eq!(debug_filter(""), "zeroclaw=debug");
+    }
+
+    #[test]
+    fn only_listed_senders_may_toggle() {
+        let users = vec![
+            "telegram:alice".to_string(),
+            "matrix:@bob:example.org".to_string(),
+        ];
+        assert!(may_toggle(&users, "telegram", "alice"));
+        assert!(may_toggle(&users, "Telegram", "alice"));
+        assert!(!may_toggle(&users, "discord", "alice"));
+        assert!(!may_toggle(&users, "telegram", "Alice"));
+        assert!(!may_toggle(&users, "telegram", "mallory"));
+        assert!(may_toggle(&users, "matrix", "@bob:example.org"));
+        assert!(!may_toggle(&["alice".to_string()], "telegram", "alice"));
+        assert!(!may_toggle(
+            &["telegram:*".to_string()],
+            "telegram",
+            "anyone"
+        ));
+        assert!(!may_toggle(&[], "telegram", "alice"));
+    }
+
+    #[test]
+    fn other_messages_are_not_the_command() {
+        let command = DebugCommand::default();
+        assert!(command
+            .handle("telegram", "alice", "debug this for me")
+            .is_none());
+        assert!(command.handle("telegram", "alice", "").is_none());
+        assert!(command
+            .handle("telegram", "alice", "!debug on")
+            .is_some_and(|reply| reply.contains("not permitted")));
+    }
+}
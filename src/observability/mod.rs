@@ -1,3 +1,4 @@
+pub mod debug_mode;
 pub mod log;
 pub mod multi;
 pub mod noop;
@@ -0,0 +1,346 @@
+//! Non-interactive first-run bootstrap for containers and headless hosts.
+//!
+//! Onboarding answers come from `ZEROCLAW_*` environment variables and an
+//! optional mounted preset file (environment wins). On first start the config
+//! and workspace are written; on later starts the existing config is reused, so
+//! the same container command is safe across restarts.
+
+use super::wizard::{
+    default_model_for_provider, memory_config_defaults_for_backend, persist_workspace_selection,
+    resolve_quick_setup_dirs_with_home, scaffold_workspace, ProjectContext,
+};
+use crate::config::{ChannelsConfig, Config};
+use crate::memory::{default_memory_backend_key, selectable_memory_backends};
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::path::Path;
+
+/// Env var naming a preset file, used when `--preset` is not given.
+pub const PRESET_ENV: &str = "ZEROCLAW_PRESET";
+
+/// Onboarding answers. Scalar fields can also be set via the matching
+/// `ZEROCLAW_<FIELD>` environment variable (upper-cased).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BootstrapPreset {
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub api_key: Option<String>,
+    pub api_url: Option<String>,
+    /// Memory backend key (sqlite, lucid, markdown, none)
+    pub memory: Option<String>,
+    pub user_name: Option<String>,
+    pub timezone: Option<String>,
+    pub agent_name: Option<String>,
+    pub communication_style: Option<String>,
+    pub gateway_host: Option<String>,
+    pub gateway_port: Option<u16>,
+    pub allow_public_bind: Option<bool>,
+    /// Channel sections, same shape as `[channels_config]` in config.toml
+    pub channels_config: toml::Table,
+}
+
+impl BootstrapPreset {
+    pub fn load(path: &Path) -> Result<Self> {
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read preset {}", path.display()))?;
+        toml::from_str(&raw).with_context(|| format!("invalid preset {}", path.display()))
+    }
+
+    /// Overlay environment answers on top of the preset.
+    pub fn apply_env(&mut self, env: impl Fn(&str) -> Option<String>) -> Result<()> {
+        let var = |names: &[&str]| {
+            names
+                .iter()
+                .find_map(|n| env(n))
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        };
+
+        let strings: [(&mut Option<String>, &[&str]); 9] = [
+            (&mut self.provider, &["ZEROCLAW_PROVIDER", "PROVIDER"]),
+            (&mut self.model, &["ZEROCLAW_MODEL", "MODEL"]),
+            (&mut self.api_key, &["ZEROCLAW_API_KEY", "API_KEY"]),
+            (&mut self.api_url, &["ZEROCLAW_API_URL"]),
+            (&mut self.memory, &["ZEROCLAW_MEMORY"]),
+            (&mut self.user_name, &["ZEROCLAW_USER_NAME"]),
+            (&mut self.timezone, &["ZEROCLAW_TIMEZONE", "TZ"]),
+            (&mut self.agent_name, &["ZEROCLAW_AGENT_NAME"]),
+            (
+                &mut self.communication_style,
+                &["ZEROCLAW_COMMUNICATION_STYLE"],
+            ),
+        ];
+        for (field, names) in strings {
+            if let Some(value) = var(names) {
+                *field = Some(value);
+            }
+        }
+
+        if let Some(host) = var(&["ZEROCLAW_GATEWAY_HOST", "HOST"]) {
+            self.gateway_host = Some(host);
+        }
+        if let Some(port) = var(&["ZEROCLAW_GATEWAY_PORT", "PORT"]) {
+            self.gateway_port = Some(
+                port.parse()
+                    .with_context(|| format!("invalid gateway port: {port}"))?,
+            );
+        }
+        if let Some(flag) = var(&["ZEROCLAW_ALLOW_PUBLIC_BIND"]) {
+            self.allow_public_bind = Some(flag == "1" || flag.eq_ignore_ascii_case("true"));
+        }
+
+        if let Some(token) = var(&["ZEROCLAW_TELEGRAM_BOT_TOKEN"]) {
+            let mut telegram = toml::Table::new();
+            telegram.insert("bot_token".into(), token.into());
+            telegram.insert(
+                "allowed_users".into(),
+                split_list(var(&["ZEROCLAW_TELEGRAM_ALLOWED_USERS"])).into(),
+            );
+            self.channels_config
+                .insert("telegram".into(), telegram.into());
+        }
+        if let Some(token) = var(&["ZEROCLAW_DISCORD_BOT_TOKEN"]) {
+            let mut discord = toml::Table::new();
+            discord.insert("bot_token".into(), token.into());
+            if let Some(guild) = var(&["ZEROCLAW_DISCORD_GUILD_ID"]) {
+                discord.insert("guild_id".into(), guild.into());
+            }
+            discord.insert(
+                "allowed_users".into(),
+                split_list(var(&["ZEROCLAW_DISCORD_ALLOWED_USERS"])).into(),
+            );
+            self.channels_config
+                .insert("discord".into(), discord.into());
+        }
+
+        Ok(())
+    }
+
+    /// Build a complete config rooted at the given directories.
+    pub fn into_config(self, zeroclaw_dir: &Path, workspace_dir: &Path) -> Result<Config> {
+        let provider = self.provider.unwrap_or_else(|| "openrouter".into());
+        let memory = self
+            .memory
+            .unwrap_or_else(|| default_memory_backend_key().into());
+        if !selectable_memory_backends()
+            .iter()
+            .any(|backend| backend.key == memory)
+        {
+            bail!("unknown memory backend in bootstrap answers: {memory}");
+        }
+
+        let mut config = Config {
+            workspace_dir: workspace_dir.to_path_buf(),
+            config_path: zeroclaw_dir.join("config.toml"),
+            api_key: self.api_key,
+            api_url: self.api_url,
+            default_model: Some(
+                self.model
+                    .unwrap_or_else(|| default_model_for_provider(&provider)),
+            ),
+            default_provider: Some(provider),
+            memory: memory_config_defaults_for_backend(&memory),
+            channels_config: merge_channels(self.channels_config)?,
+            ..Config::default()
+        };
+        if let Some(host) = self.gateway_host {
+            config.gateway.host = host;
+        }
+        if let Some(port) = self.gateway_port {
+            config.gateway.port = port;
+        }
+        if let Some(allow) = self.allow_public_bind {
+            config.gateway.allow_public_bind = allow;
+        }
+        Ok(config)
+    }
+
+    fn project_context(&self) -> ProjectContext {
+        ProjectContext {
+            user_name: self.user_name.clone().unwrap_or_else(|| "User".into()),
+            timezone: self.timezone.clone().unwrap_or_else(|| "UTC".into()),
+            agent_name: self.agent_name.clone().unwrap_or_else(|| "ZeroClaw".into()),
+            communication_style: self.communication_style.clone().unwrap_or_else(|| {
+                "Be warm, natural, and clear. Use occasional relevant emojis (1-2 max) and avoid robotic phrasing."
+                    .into()
+            }),
+        }
+    }
+}
+
+/// Overlay preset channel sections on the default `[channels_config]`.
+fn merge_channels(sections: toml::Table) -> Result<ChannelsConfig> {
+    let mut merged = toml::Table::try_from(ChannelsConfig::default())?;
+    merged.extend(sections);
+    merged
+        .try_into()
+        .context("invalid [channels_config] in bootstrap answers")
+}
+
+fn split_list(raw: Option<String>) -> Vec<String> {
+    raw.map(|s| {
+        s.split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Write config and workspace from env/preset answers unless a config already
+/// exists (or `force` is set), then return the effective config.
+pub async fn run_bootstrap(preset: Option<&Path>, force: bool) -> Result<Config> {
+    let home = directories::UserDirs::new()
+        .map(|u| u.home_dir().to_path_buf())
+        .context("Could not find home directory")?;
+    let (zeroclaw_dir, workspace_dir) = resolve_quick_setup_dirs_with_home(&home);
+
+    let preset_path = preset
+        .map(Path::to_path_buf)
+        .or_else(|| std::env::var_os(PRESET_ENV).map(Into::into));
+    let config_path = zeroclaw_dir.join("config.toml");
+    if config_path.exists() && !force {
+        tracing::info!(
+            "bootstrap: existing config at {} kept",
+            config_path.display()
+        );
+        return Config::load_or_init().await;
+    }
+
+    let config = bootstrap_into(
+        preset_path.as_deref(),
+        &zeroclaw_dir,
+        &workspace_dir,
+        |name| std::env::var(name).ok(),
+    )
+    .await?;
+    persist_workspace_selection(&config.config_path).await?;
+    Ok(config)
+}
+
+async fn bootstrap_into(
+    preset: Option<&Path>,
+    zeroclaw_dir: &Path,
+    workspace_dir: &Path,
+    env: impl Fn(&str) -> Option<String>,
+) -> Result<Config> {
+    let mut answers = match preset {
+        Some(path) => BootstrapPreset::load(path)?,
+        None => BootstrapPreset::default(),
+    };
+    answers.apply_env(env)?;
+    let ctx = answers.project_context();
+    let mut config = answers.into_config(zeroclaw_dir, workspace_dir)?;
+
+    tokio::fs::create_dir_all(workspace_dir)
+        .await
+        .context("Failed to create workspace directory")?;
+    config.save().await?;
+    scaffold_workspace(workspace_dir, &ctx).await?;
+
+    println!(
+        "✅ Bootstrapped {} (provider: {}, model: {})",
+        config.config_path.display(),
+        config.default_provider.as_deref().unwrap_or_default(),
+        config.default_model.as_deref().unwrap_or_default()
+    );
+
+    config.apply_env_overrides();
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn env_from(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let map: HashMap<String, String> = pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect();
+        move |name| map.get(name).cloned()
+    }
+
+    #[test]
+    fn env_overrides_preset_answers() {
+        let mut preset: BootstrapPreset = toml::from_str(
+            r#"
+provider = "anthropic"
+model = "claude-sonnet-4-20250514"
+agent_name = "Pi"
+
+[channels_config.webhook]
+port = 8081
+"#,
+        )
+        .unwrap();
+        preset
+            .apply_env(env_from(&[
+                ("ZEROCLAW_PROVIDER", "openai"),
+                ("ZEROCLAW_GATEWAY_PORT", "9000"),
+                ("ZEROCLAW_TELEGRAM_BOT_TOKEN", "123:abc"),
+                ("ZEROCLAW_TELEGRAM_ALLOWED_USERS", "alice, bob"),
+            ]))
+            .unwrap();
+
+        assert_eq!(preset.provider.as_deref(), Some("openai"));
+        assert_eq!(preset.agent_name.as_deref(), Some("Pi"));
+        assert_eq!(preset.gateway_port, Some(9000));
+
+        let tmp = TempDir::new().unwrap();
+        let config = preset
+            .into_config(tmp.path(), &tmp.path().join("workspace"))
+            .unwrap();
+        assert_eq!(config.default_provider.as_deref(), Some("openai"));
+        assert_eq!(config.gateway.port, 9000);
+        let telegram = config.channels_config.telegram.unwrap();
+        assert_eq!(telegram.bot_token, "123:abc");
+        assert_eq!(telegram.allowed_users, vec!["alice", "bob"]);
+        assert!(config.channels_config.webhook.is_some());
+    }
+
+    #[test]
+    fn invalid_answers_are_rejected() {
+        let mut preset = BootstrapPreset::default();
+        assert!(preset
+            .apply_env(env_from(&[("ZEROCLAW_GATEWAY_PORT", "not-a-port")]))
+            .is_err());
+
+        let preset = BootstrapPreset {
+            memory: Some("floppy".into()),
+            ..BootstrapPreset::default()
+        };
+        let tmp = TempDir::new().unwrap();
+        assert!(preset.into_config(tmp.path(), tmp.path()).is_err());
+
+        assert!(toml::from_str::<BootstrapPreset>("provder = \"typo\"").is_err());
+    }
+
+    #[tokio::test]
+    async fn bootstrap_writes_config_and_workspace() {
+        let tmp = TempDir::new().unwrap();
+        let zeroclaw_dir = tmp.path().join(".zeroclaw");
+        let workspace_dir = zeroclaw_dir.join("workspace");
+        let preset = tmp.path().join("preset.toml");
+        std::fs::write(&preset, "provider = \"ollama\"\nuser_name = \"Sam\"\n").unwrap();
+
+        let config = bootstrap_into(
+            Some(&preset),
+            &zeroclaw_dir,
+            &workspace_dir,
+            env_from(&[("ZEROCLAW_MEMORY", "markdown")]),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(config.memory.backend, "markdown");
+        let saved = std::fs::read_to_string(zeroclaw_dir.join("config.toml")).unwrap();
+        assert!(saved.contains("default_provider = \"ollama\""));
+        let user_md = std::fs::read_to_string(workspace_dir.join("USER.md")).unwrap();
+        assert!(user_md.contains("Sam"));
+    }
+}
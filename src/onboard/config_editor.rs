@@ -0,0 +1,486 @@
+//! Interactive editor for the live `config.toml` (`zeroclaw config edit`).
+//!
+//! The file is shown highlighted with secrets masked. Scalar values (strings,
+//! numbers, booleans) can be changed inline, keeping their type, or the
+//! whole file can be opened in `$EDITOR`. Every save is checked by
+//! deserializing into [`Config`] — the types the JSON Schema is generated
+//! from — plus [`Config::validate`], and the previous file is kept as a
+//! timestamped backup.
+
+use crate::config::Config;
+use crate::onboard::preview::{is_secret_key, MASK};
+use crate::onboard::{preview, prompt, theme};
+use anyhow::{bail, Context, Result};
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
+const MAX_VALUE_CHARS: usize = 50;
+
+/// One editable `key = value`, addressed by its table path.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScalarField {
+    pub path: Vec<String>,
+    pub value: toml::Value,
+}
+
+impl ScalarField {
+    pub fn dotted(&self) -> String {
+        self.path.join(".")
+    }
+
+    fn secret(&self) -> bool {
+        self.path.last().is_some_and(|key| is_secret_key(key))
+    }
+
+    fn shown_value(&self) -> String {
+        if self.secret() && matches!(&self.value, toml::Value::String(text) if !text.is_empty()) {
+            return MASK.to_string();
+        }
+        let text = self.value.to_string();
+        if text.chars().count() > MAX_VALUE_CHARS {
+            let cut: String = text.chars().take(MAX_VALUE_CHARS).collect();
+            format!("{cut}…")
+        } else {
+            text
+        }
+    }
+}
+
+/// `line` with TOML syntax colors; values of secret keys are masked.
+fn highlight_line(line: &str) -> String {
+    let trimmed = line.trim_start();
+    if trimmed.is_empty() {
+        return String::new();
+    }
+    if trimmed.starts_with('#') {
+        return theme::muted(line).to_string();
+    }
+    if trimmed.starts_with('[') {
+        return theme::accent(line).bold().to_string();
+    }
+    let Some((key, value)) = line.split_once('=') else {
+        return line.to_string();
+    };
+    let bare_key = key.trim().trim_matches('"');
+    let value = value.trim();
+    let shown = if is_secret_key(bare_key) && value.starts_with('"') && value != "\"\"" {
+        theme::muted(format!("\"{MASK}\"")).to_string()
+    } else if value.starts_with('"') || value.starts_with('\'') {
+        theme::success(value).to_string()
+    } else {
+        theme::warning(value).to_string()
+    };
+    format!("{} = {shown}", theme::strong(key.trim_end()))
+}
+
+/// The whole file highlighted for the pager.
+pub fn highlight(text: &str) -> String {
+    text.lines()
+        .map(highlight_line)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn collect_scalars(table: &toml::Table, prefix: &mut Vec<String>, out: &mut Vec<ScalarField>) {
+    for (key, value) in table {
+        prefix.push(key.clone());
+        match value {
+            toml::Value::Table(child) => collect_scalars(child, prefix, out),
+            // Arrays and arrays of tables are edited in `$EDITOR`.
+            toml::Value::Array(_) => {}
+            _ => out.push(ScalarField {
+                path: prefix.clone(),
+                value: value.clone(),
+            }),
+        }
+        prefix.pop();
+    }
+}
+
+/// Every scalar value in `table`, in file order.
+pub fn scalar_fields(table: &toml::Table) -> Vec<ScalarField> {
+    let mut out = Vec::new();
+    collect_scalars(table, &mut Vec::new(), &mut out);
+    out
+}
+
+/// Parse `input` as the same TOML type as `current`.
+pub fn parse_like(current: &toml::Value, input: &str) -> Result<toml::Value> {
+    let input = input.trim();
+    Ok(match current {
+        toml::Value::Boolean(_) => match input.to_ascii_lowercase().as_str() {
+            "true" | "yes" | "on" => toml::Value::Boolean(true),
+            "false" | "no" | "off" => toml::Value::Boolean(false),
+            _ => bail!("expected true or false"),
+        },
+        toml::Value::Integer(_) => toml::Value::Integer(
+            input
+                .parse()
+                .with_context(|| format!("expected an integer, got {input:?}"))?,
+        ),
+        toml::Value::Float(_) => toml::Value::Float(
+            input
+                .parse()
+                .with_context(|| format!("expected a number, got {input:?}"))?,
+        ),
+        toml::Value::Datetime(_) => toml::Value::Datetime(
+            input
+                .parse()
+                .with_context(|| format!("expected a TOML datetime, got {input:?}"))?,
+        ),
+        _ => toml::Value::String(input.to_string()),
+    })
+}
+
+/// Replace the value at `path`; the path must already exist.
+pub fn set_value(table: &mut toml::Table, path: &[String], value: toml::Value) -> Result<()> {
+    let Some((last, parents)) = path.split_last() else {
+        bail!("empty config path");
+    };
+    let mut current = table;
+    for key in parents {
+        current = current
+            .get_mut(key)
+            .and_then(toml::Value::as_table_mut)
+            .with_context(|| format!("no table [{key}] in config"))?;
+    }
+    let slot = current
+        .get_mut(last)
+        .with_context(|| format!("no key {} in config", path.join(".")))?;
+    *slot = value;
+    Ok(())
+}
+
+/// Deserialize `text` the way `Config::load_or_init` reads it, without
+/// [`Config::validate`]. Also returns the keys the schema does not know,
+/// which load ignores with a warning.
+pub fn parse(text: &str) -> Result<(Config, Vec<String>)> {
+    let mut unknown = Vec::new();
+    let config: Config = serde_ignored::deserialize(
+        toml::de::Deserializer::parse(text).context("not valid TOML")?,
+        |path| unknown.push(path.to_string()),
+    )
+    .context("does not match the config schema")?;
+    Ok((config, unknown))
+}
+
+/// [`parse`] plus [`Config::validate`]. Returns the unknown keys.
+pub fn check(text: &str) -> Result<Vec<String>> {
+    let (config, unknown) = parse(text)?;
+    config.validate()?;
+    Ok(unknown)
+}
+
+/// Print the result of [`check`]; true when the text may be saved.
+fn report_check(text: &str) -> bool {
+    match check(text) {
+        Ok(unknown) => {
+            for key in &unknown {
+                println!(
+                    "  {} Unknown key {} will be ignored on load",
+                    theme::warning("⚠").bold(),
+                    theme::accent(key)
+                );
+            }
+            println!("  {} Config is valid", theme::success("✓").bold());
+            true
+        }
+        Err(error) => {
+            println!("  {} {error:#}", theme::error("✗").bold());
+            false
+        }
+    }
+}
+
+/// Copy `path` aside as `<name>.bak-<YYYYMMDD-HHMMSS>`. `None` when there
+/// is no file yet.
+pub fn back_up(path: &Path) -> Result<Option<PathBuf>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("config.toml");
+    let backup = path.with_file_name(format!(
+        "{file_name}.bak-{}",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    std::fs::copy(path, &backup)
+        .with_context(|| format!("Failed to back up {}", path.display()))?;
+    Ok(Some(backup))
+}
+
+/// [`back_up`] `path`, then write `text`.
+pub fn save_with_backup(path: &Path, text: &str) -> Result<Option<PathBuf>> {
+    let backup = back_up(path)?;
+    std::fs::write(path, text).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(backup)
+}
+
+/// Hand `text` to `$VISUAL`/`$EDITOR` (falling back to `vi`) and return
+/// what was saved. The prompts are line-based, so the editor simply takes
+/// over the terminal until it exits.
+fn edit_externally(text: &str) -> Result<String> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .ok()
+        .filter(|editor| !editor.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+    let scratch = std::env::temp_dir().join(format!("zeroclaw-config-{}.toml", std::process::id()));
+    std::fs::write(&scratch, text)
+        .with_context(|| format!("Failed to write {}", scratch.display()))?;
+
+    // `$EDITOR` may carry arguments, e.g. `code --wait`.
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+    let status = std::process::Command::new(program)
+        .args(parts)
+        .arg(&scratch)
+        .status()
+        .with_context(|| format!("Failed to start editor {editor:?}"));
+    let edited = std::fs::read_to_string(&scratch);
+    let _ = std::fs::remove_file(&scratch);
+    if !status?.success() {
+        bail!("editor {editor:?} exited with an error; changes discarded");
+    }
+    edited.context("Failed to read the edited config")
+}
+
+fn edit_scalar(text: &str) -> Result<Option<String>> {
+    let mut table: toml::Table = toml::from_str(text).context("config.toml is not valid TOML")?;
+    let fields = scalar_fields(&table);
+    if fields.is_empty() {
+        println!("  {}", theme::warning("No scalar values to edit."));
+        return Ok(None);
+    }
+
+    let mut items = vec!["⬅ Back".to_string()];
+    items.extend(
+        fields
+            .iter()
+            .map(|field| format!("{} = {}", field.dotted(), field.shown_value())),
+    );
+    let choice = prompt::Select::new()
+        .with_prompt("  Value to edit")
+        .items(&items)
+        .default(0)
+        .interact()?;
+    if choice == 0 {
+        return Ok(None);
+    }
+
+    let field = &fields[choice - 1];
+    let current = match &field.value {
+        // Never prefill a secret; empty input keeps it.
+        toml::Value::String(text) if field.secret() => {
+            if !text.is_empty() {
+                println!(
+                    "  {}",
+                    theme::muted("Leave empty to keep the current secret.")
+                );
+            }
+            String::new()
+        }
+        toml::Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+    let kind = field.value.type_str();
+    let sample = field.value.clone();
+    let input: String = prompt::Input::new()
+        .with_prompt(format!("  {} ({kind})", field.dotted()))
+        .with_initial_text(current)
+        .allow_empty(true)
+        .validate_with(move |input: &String| {
+            if input.is_empty() {
+                return Ok(());
+            }
+            parse_like(&sample, input)
+                .map(|_| ())
+                .map_err(|error| error.to_string())
+        })
+        .interact_text()?;
+    if input.is_empty() && field.secret() {
+        return Ok(None);
+    }
+
+    set_value(&mut table, &field.path, parse_like(&field.value, &input)?)?;
+    Ok(Some(
+        toml::to_string_pretty(&table).context("Failed to render config")?,
+    ))
+}
+
+/// Edit the config at `path` until the user saves or quits.
+pub fn run(path: &Path) -> Result<()> {
+    let original = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+
+    if !std::io::stdin().is_terminal() || !std::io::stdout().is_terminal() {
+        println!("{original}");
+        return Ok(());
+    }
+
+    let mut text = original.clone();
+    println!(
+        "  {} {}",
+        theme::strong("Editing"),
+        theme::accent(path.display())
+    );
+    loop {
+        let dirty = text != original;
+        let items = [
+            "View config".to_string(),
+            "Edit a value".to_string(),
+            format!(
+                "Open in {}",
+                std::env::var("EDITOR").unwrap_or_else(|_| "$EDITOR".to_string())
+            ),
+            "Validate".to_string(),
+            if dirty {
+                "Save (keeps a backup)".to_string()
+            } else {
+                "Save (no changes)".to_string()
+            },
+            if dirty {
+                "Quit without saving".to_string()
+            } else {
+                "Quit".to_string()
+            },
+        ];
+        let choice = prompt::Select::new()
+            .with_prompt(if dirty {
+                "  config.toml (unsaved changes)"
+            } else {
+                "  config.toml"
+            })
+            .items(&items)
+            .default(0)
+            .interact()?;
+        match choice {
+            0 => preview::page(&highlight(&text))?,
+            1 => match edit_scalar(&text) {
+                Ok(Some(edited)) => {
+                    text = edited;
+                    report_check(&text);
+                }
+                Ok(None) => {}
+                Err(error) => println!("  {} {error:#}", theme::error("✗").bold()),
+            },
+            2 => match edit_externally(&text) {
+                Ok(edited) => {
+                    text = edited;
+                    report_check(&text);
+                }
+                Err(error) => println!("  {} {error:#}", theme::error("✗").bold()),
+            },
+            3 => {
+                report_check(&text);
+            }
+            4 => {
+                if !dirty {
+                    println!("  {}", theme::muted("Nothing to save."));
+                    continue;
+                }
+                if !report_check(&text) {
+                    println!(
+                        "  {}",
+                        theme::warning("Fix the error before saving, or quit to discard.")
+                    );
+                    continue;
+                }
+                if let Some(backup) = save_with_backup(path, &text)? {
+                    println!(
+                        "  {} Previous config backed up to {}",
+                        theme::success("✓").bold(),
+                        theme::success(backup.display())
+                    );
+                }
+                println!(
+                    "  {} Saved {} — restart the daemon to apply it",
+                    theme::success("✓").bold(),
+                    path.display()
+                );
+                return Ok(());
+            }
+            _ => {
+                if dirty
+                    && !prompt::Confirm::new()
+                        .with_prompt("  Discard unsaved changes?")
+                        .default(false)
+                        .interact()?
+                {
+                    continue;
+                }
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+default_temperature = 0.7
+api_key = "sk-live"
+
+[gateway]
+port = 42617
+require_pairing = true
+"#;
+
+    #[test]
+    fn scalar_edits_keep_their_type_and_path() {
+        let mut table: toml::Table = toml::from_str(SAMPLE).unwrap();
+        let fields = scalar_fields(&table);
+        let port = fields
+            .iter()
+            .find(|field| field.dotted() == "gateway.port")
+            .unwrap();
+
+        assert!(parse_like(&port.value, "not a number").is_err());
+        let value = parse_like(&port.value, "8080").unwrap();
+        set_value(&mut table, &port.path, value).unwrap();
+        assert_eq!(table["gateway"]["port"].as_integer(), Some(8080));
+        assert_eq!(
+            parse_like(&toml::Value::Boolean(true), "off").unwrap(),
+            toml::Value::Boolean(false)
+        );
+
+        let key = fields
+            .iter()
+            .find(|field| field.dotted() == "api_key")
+            .unwrap();
+        assert_eq!(key.shown_value(), MASK);
+        assert!(!highlight(SAMPLE).contains("sk-live"));
+    }
+
+    #[test]
+    fn check_rejects_wrong_types_and_reports_unknown_keys() {
+        assert!(check("[gateway]\nport = \"eighty\"\n").is_err());
+        assert!(check("default_temperature = [").is_err());
+
+        let unknown = check("default_temperature = 0.5\nmystery_key = 1\n").unwrap();
+        assert_eq!(unknown, vec!["mystery_key".to_string()]);
+    }
+
+    #[test]
+    fn save_keeps_a_backup_of_the_previous_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "default_temperature = 0.7\n").unwrap();
+
+        let backup = save_with_backup(&path, "default_temperature = 0.2\n")
+            .unwrap()
+            .expect("backup written");
+        assert_eq!(
+            std::fs::read_to_string(&backup).unwrap(),
+            "default_temperature = 0.7\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "default_temperature = 0.2\n"
+        );
+    }
+}
@@ -0,0 +1,277 @@
+//! String catalogs for the onboarding wizard.
+//!
+//! Wizard chrome (step titles, the step header, the welcome text and the
+//! review screen) is looked up through [`text`] so one [`UiLocale`] switch
+//! translates all of it. The active locale comes from `zeroclaw onboard
+//! --locale`, else `[ui].locale`, else `LANG`, and can be changed in the
+//! wizard's Language step.
+
+use crate::config::UiLocale;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static ACTIVE: AtomicU8 = AtomicU8::new(0);
+
+/// Catalog keys. Every key has an entry for every locale, so a missing
+/// translation is a compile error rather than a blank line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Msg {
+    Welcome,
+    Intro,
+    /// `{current}` and `{total}` are substituted by [`step_header`].
+    StepHeader,
+    LanguagePrompt,
+    StepLanguage,
+    StepWorkspace,
+    StepImport,
+    StepProvider,
+    StepChannels,
+    StepSchedules,
+    StepTunnel,
+    StepToolMode,
+    StepSecurity,
+    StepHardware,
+    StepEstop,
+    StepMemory,
+    StepEmbeddings,
+    StepProjectContext,
+    StepConfirmation,
+    StepWorkspaceFiles,
+    StepPreview,
+    ReviewHint,
+    ReviewPrompt,
+    ReviewSave,
+    ReviewAbort,
+}
+
+/// `[en, zh, ja, es]` for `msg`, in [`UiLocale::ALL`] order.
+fn entry(msg: Msg) -> [&'static str; 4] {
+    match msg {
+        Msg::Welcome => [
+            "Welcome to ZeroClaw — the fastest, smallest AI assistant.",
+            "欢迎使用 ZeroClaw —— 最快、最小的 AI 助手。",
+            "ZeroClaw へようこそ — 最速・最小の AI アシスタント。",
+            "Bienvenido a ZeroClaw: el asistente de IA más rápido y ligero.",
+        ],
+        Msg::Intro => [
+            "This wizard will configure your agent in under 60 seconds.",
+            "本向导将在 60 秒内完成代理配置。",
+            "このウィザードは 60 秒以内にエージェントを設定します。",
+            "Este asistente configurará tu agente en menos de 60 segundos.",
+        ],
+        Msg::StepHeader => [
+            "Step {current} of {total}:",
+            "第 {current}/{total} 步：",
+            "ステップ {current}/{total}:",
+            "Paso {current} de {total}:",
+        ],
+        Msg::LanguagePrompt => [
+            "Choose the wizard language",
+            "选择向导语言",
+            "ウィザードの言語を選択",
+            "Elige el idioma del asistente",
+        ],
+        Msg::StepLanguage => ["Language", "语言", "言語", "Idioma"],
+        Msg::StepWorkspace => [
+            "Workspace Setup",
+            "工作区设置",
+            "ワークスペースの設定",
+            "Configuración del espacio de trabajo",
+        ],
+        Msg::StepImport => [
+            "Import Existing Settings",
+            "导入现有设置",
+            "既存の設定をインポート",
+            "Importar configuración existente",
+        ],
+        Msg::StepProvider => [
+            "AI Provider & API Key",
+            "AI 提供商与 API 密钥",
+            "AI プロバイダーと API キー",
+            "Proveedor de IA y clave de API",
+        ],
+        Msg::StepChannels => [
+            "Channels (How You Talk to ZeroClaw)",
+            "渠道（与 ZeroClaw 对话的方式）",
+            "チャネル（ZeroClaw との会話方法）",
+            "Canales (cómo hablas con ZeroClaw)",
+        ],
+        Msg::StepSchedules => [
+            "Scheduled Jobs",
+            "定时任务",
+            "定期ジョブ",
+            "Tareas programadas",
+        ],
+        Msg::StepTunnel => [
+            "Tunnel (Expose to Internet)",
+            "隧道（暴露到互联网）",
+            "トンネル（インターネットに公開）",
+            "Túnel (exponer a Internet)",
+        ],
+        Msg::StepToolMode => [
+            "Tool Mode & Security",
+            "工具模式与安全",
+            "ツールモードとセキュリティ",
+            "Modo de herramientas y seguridad",
+        ],
+        Msg::StepSecurity => [
+            "Security Defaults",
+            "默认安全设置",
+            "セキュリティの既定値",
+            "Seguridad predeterminada",
+        ],
+        Msg::StepHardware => [
+            "Hardware (Physical World)",
+            "硬件（物理世界）",
+            "ハードウェア（物理世界）",
+            "Hardware (mundo físico)",
+        ],
+        Msg::StepEstop => [
+            "Emergency Stop",
+            "紧急停止",
+            "緊急停止",
+            "Parada de emergencia",
+        ],
+        Msg::StepMemory => [
+            "Memory Configuration",
+            "记忆配置",
+            "メモリの設定",
+            "Configuración de memoria",
+        ],
+        Msg::StepEmbeddings => [
+            "Embeddings (Semantic Memory)",
+            "嵌入（语义记忆）",
+            "埋め込み（セマンティックメモリ）",
+            "Embeddings (memoria semántica)",
+        ],
+        Msg::StepProjectContext => [
+            "Project Context (Personalize Your Agent)",
+            "项目上下文（个性化你的代理）",
+            "プロジェクトコンテキスト（エージェントのカスタマイズ）",
+            "Contexto del proyecto (personaliza tu agente)",
+        ],
+        Msg::StepConfirmation => [
+            "Review & Confirm",
+            "检查并确认",
+            "確認",
+            "Revisar y confirmar",
+        ],
+        Msg::StepWorkspaceFiles => [
+            "Workspace Files",
+            "工作区文件",
+            "ワークスペースファイル",
+            "Archivos del espacio de trabajo",
+        ],
+        Msg::StepPreview => [
+            "Preview config.toml",
+            "预览 config.toml",
+            "config.toml のプレビュー",
+            "Vista previa de config.toml",
+        ],
+        Msg::ReviewHint => [
+            "Select a line and press Enter to change it, or save to continue.",
+            "选择一行并按 Enter 修改，或选择保存以继续。",
+            "行を選んで Enter で変更するか、保存して続行します。",
+            "Selecciona una línea y pulsa Enter para cambiarla, o guarda para continuar.",
+        ],
+        Msg::ReviewPrompt => [
+            "Review your configuration",
+            "检查你的配置",
+            "設定を確認してください",
+            "Revisa tu configuración",
+        ],
+        Msg::ReviewSave => [
+            "✅ Looks good — save configuration",
+            "✅ 没问题 —— 保存配置",
+            "✅ 問題なし — 設定を保存",
+            "✅ Todo bien: guardar la configuración",
+        ],
+        Msg::ReviewAbort => [
+            "✖  Abort without saving",
+            "✖  放弃，不保存",
+            "✖  保存せずに中止",
+            "✖  Cancelar sin guardar",
+        ],
+    }
+}
+
+fn encode(locale: UiLocale) -> u8 {
+    match locale {
+        UiLocale::En => 0,
+        UiLocale::Zh => 1,
+        UiLocale::Ja => 2,
+        UiLocale::Es => 3,
+    }
+}
+
+/// The locale currently in effect.
+pub fn current() -> UiLocale {
+    UiLocale::ALL[usize::from(ACTIVE.load(Ordering::Relaxed)).min(3)]
+}
+
+/// Switch the process-wide wizard locale.
+pub fn set_locale(locale: UiLocale) {
+    ACTIVE.store(encode(locale), Ordering::Relaxed);
+}
+
+/// `msg` in the active locale.
+pub fn text(msg: Msg) -> &'static str {
+    entry(msg)[usize::from(encode(current()))]
+}
+
+/// The "Step N of M:" header in the active locale.
+pub fn step_header(current: usize, total: usize) -> String {
+    text(Msg::StepHeader)
+        .replace("{current}", &current.to_string())
+        .replace("{total}", &total.to_string())
+}
+
+/// Pick the onboarding locale: the `--locale` flag wins, then `[ui].locale`
+/// from an existing config file, then `LANG`, then English.
+pub async fn init_for_onboarding(flag: Option<UiLocale>) {
+    let locale = match flag {
+        Some(locale) => Some(locale),
+        None => configured_locale().await,
+    };
+    set_locale(
+        locale
+            .or_else(|| {
+                std::env::var("LANG")
+                    .ok()
+                    .as_deref()
+                    .and_then(UiLocale::parse)
+            })
+            .unwrap_or_default(),
+    );
+}
+
+async fn configured_locale() -> Option<UiLocale> {
+    let (config_dir, _) = crate::config::schema::resolve_runtime_dirs_for_onboarding()
+        .await
+        .ok()?;
+    let raw = tokio::fs::read_to_string(config_dir.join("config.toml"))
+        .await
+        .ok()?;
+    let value: toml::Value = toml::from_str(&raw).ok()?;
+    value.get("ui")?.get("locale")?.clone().try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catalogs_translate_step_header_and_parse_posix_locales() {
+        assert_eq!(UiLocale::parse("zh_CN.UTF-8"), Some(UiLocale::Zh));
+        assert_eq!(UiLocale::parse("es-MX"), Some(UiLocale::Es));
+        assert_eq!(UiLocale::parse("C"), None);
+
+        set_locale(UiLocale::Ja);
+        assert_eq!(step_header(3, 16), "ステップ 3/16:");
+        assert_eq!(text(Msg::StepLanguage), "言語");
+        set_locale(UiLocale::En);
+        assert_eq!(step_header(3, 16), "Step 3 of 16:");
+        for locale in UiLocale::ALL {
+            assert!(entry(Msg::StepHeader)[usize::from(encode(locale))].contains("{total}"));
+        }
+    }
+}
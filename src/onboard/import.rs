@@ -0,0 +1,250 @@
+//! Pre-fill onboarding answers from an existing configuration file.
+//!
+//! Supports dotenv files as used by OpenAI-compatible assistants and
+//! `config.toml` files written by another ZeroClaw/OpenClaw install. Only the
+//! provider, model, API key/URL and channel settings are picked up.
+
+use crate::config::{ChannelsConfig, DiscordConfig, SlackConfig, StreamMode, TelegramConfig};
+use anyhow::{bail, Context, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Env vars holding a provider key, checked in order.
+const PROVIDER_KEY_VARS: &[(&str, &str)] = &[
+    ("ZEROCLAW_API_KEY", ""),
+    ("OPENROUTER_API_KEY", "openrouter"),
+    ("ANTHROPIC_API_KEY", "anthropic"),
+    ("OPENAI_API_KEY", "openai"),
+    ("GEMINI_API_KEY", "gemini"),
+    ("GOOGLE_API_KEY", "gemini"),
+    ("GROQ_API_KEY", "groq"),
+    ("DEEPSEEK_API_KEY", "deepseek"),
+    ("MISTRAL_API_KEY", "mistral"),
+    ("XAI_API_KEY", "xai"),
+];
+
+const MODEL_VARS: &[&str] = &["ZEROCLAW_MODEL", "OPENAI_MODEL", "LLM_MODEL", "MODEL"];
+const BASE_URL_VARS: &[&str] = &["OPENAI_BASE_URL", "OPENAI_API_BASE"];
+
+/// Settings recovered from an existing file. Every field is optional; the
+/// wizard asks for whatever is missing.
+#[derive(Debug, Clone, Default)]
+pub struct ImportedSettings {
+    pub source: PathBuf,
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub api_key: Option<String>,
+    pub api_url: Option<String>,
+    pub channels: ChannelsConfig,
+}
+
+impl ImportedSettings {
+    /// Names of channels that were imported, for the summary.
+    pub fn channel_names(&self) -> Vec<&'static str> {
+        self.channels
+            .channels()
+            .into_iter()
+            .filter(|(_, configured)| *configured)
+            .map(|(channel, _)| channel.name())
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.provider.is_none()
+            && self.model.is_none()
+            && self.api_key.is_none()
+            && self.channel_names().is_empty()
+    }
+}
+
+/// Read `path` and extract what onboarding can reuse. `.toml` files are read
+/// as ZeroClaw configs; anything else is treated as a dotenv file.
+pub fn import_from_path(path: &Path) -> Result<ImportedSettings> {
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let mut settings = if path.extension().is_some_and(|ext| ext == "toml") {
+        parse_config_toml(&raw)?
+    } else {
+        parse_dotenv(&raw)
+    };
+    if settings.is_empty() {
+        bail!(
+            "No provider, model, key, or channel settings found in {}",
+            path.display()
+        );
+    }
+    settings.source = path.to_path_buf();
+    Ok(settings)
+}
+
+fn parse_dotenv(raw: &str) -> ImportedSettings {
+    let vars: BTreeMap<&str, String> = raw
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let (key, value) = line.split_once('=')?;
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+                .unwrap_or(value);
+            (!value.is_empty()).then(|| (key.trim(), value.to_string()))
+        })
+        .collect();
+    let first = |keys: &[&str]| keys.iter().find_map(|key| vars.get(key).cloned());
+
+    let mut settings = ImportedSettings::default();
+    if let Some((var, provider)) = PROVIDER_KEY_VARS
+        .iter()
+        .find(|(var, _)| vars.contains_key(var))
+    {
+        settings.api_key = vars.get(var).cloned();
+        if !provider.is_empty() {
+            settings.provider = Some((*provider).to_string());
+        }
+    }
+    settings.provider = first(&["ZEROCLAW_PROVIDER"]).or(settings.provider);
+    settings.model = first(MODEL_VARS);
+
+    // An OpenAI key pointed at another base URL is a custom endpoint.
+    if let Some(base_url) = first(BASE_URL_VARS) {
+        if settings.provider.as_deref().map_or(true, |p| p == "openai") {
+            settings.provider = Some(format!("custom:{}", base_url.trim_end_matches('/')));
+        }
+    }
+
+    let users = |key: &str| -> Vec<String> {
+        vars.get(key)
+            .map(|list| {
+                list.split(',')
+                    .map(|user| user.trim().to_string())
+                    .filter(|user| !user.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    if let Some(bot_token) = first(&["TELEGRAM_BOT_TOKEN"]) {
+        settings.channels.telegram = Some(TelegramConfig {
+            bot_token,
+            allowed_users: users("TELEGRAM_ALLOWED_USERS"),
+            stream_mode: StreamMode::default(),
+            draft_update_interval_ms: 1000,
+            interrupt_on_new_message: false,
+            mention_only: false,
+            quote_replies: false,
+            lightweight_acks: false,
+        });
+    }
+    if let Some(bot_token) = first(&["DISCORD_BOT_TOKEN"]) {
+        settings.channels.discord = Some(DiscordConfig {
+            bot_token,
+            guild_id: first(&["DISCORD_GUILD_ID"]),
+            allowed_users: users("DISCORD_ALLOWED_USERS"),
+            listen_to_bots: false,
+            mention_only: false,
+            quote_replies: false,
+            lightweight_acks: false,
+        });
+    }
+    if let Some(bot_token) = first(&["SLACK_BOT_TOKEN"]) {
+        settings.channels.slack = Some(SlackConfig {
+            bot_token,
+            app_token: first(&["SLACK_APP_TOKEN"]),
+            channel_id: first(&["SLACK_CHANNEL_ID"]),
+            allowed_users: users("SLACK_ALLOWED_USERS"),
+            quote_replies: true,
+        });
+    }
+    settings
+}
+
+fn parse_config_toml(raw: &str) -> Result<ImportedSettings> {
+    let value: toml::Value = toml::from_str(raw).context("File is not valid TOML")?;
+    let text = |key: &str| {
+        value
+            .get(key)
+            .and_then(toml::Value::as_str)
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+    };
+    let channels = match value.get("channels_config").and_then(toml::Value::as_table) {
+        Some(table) => {
+            let mut table = table.clone();
+            table.entry("cli").or_insert(toml::Value::Boolean(true));
+            toml::Value::Table(table)
+                .try_into()
+                .context("Invalid [channels_config] section")?
+        }
+        None => ChannelsConfig::default(),
+    };
+    Ok(ImportedSettings {
+        source: PathBuf::new(),
+        provider: text("default_provider"),
+        model: text("default_model"),
+        api_key: text("api_key"),
+        api_url: text("api_url"),
+        channels,
+    })
+}
+
+/// Files worth offering without the user typing a path.
+pub fn detected_sources() -> Vec<PathBuf> {
+    let mut candidates = vec![PathBuf::from(".env")];
+    if let Some(home) = directories::UserDirs::new().map(|dirs| dirs.home_dir().to_path_buf()) {
+        candidates.push(home.join(".openclaw").join("config.toml"));
+        candidates.push(home.join(".openclaw").join(".env"));
+    }
+    candidates.retain(|path| path.is_file());
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dotenv_maps_keys_model_and_channels() {
+        let settings = parse_dotenv(
+            "# assistant settings\n\
+             export OPENAI_API_KEY=\"sk-test\"\n\
+             OPENAI_BASE_URL=https://llm.internal/v1/\n\
+             MODEL='qwen2.5-72b'\n\
+             TELEGRAM_BOT_TOKEN=123:abc\n\
+             TELEGRAM_ALLOWED_USERS=alice, bob\n\
+             EMPTY=\n",
+        );
+        assert_eq!(
+            settings.provider.as_deref(),
+            Some("custom:https://llm.internal/v1")
+        );
+        assert_eq!(settings.api_key.as_deref(), Some("sk-test"));
+        assert_eq!(settings.model.as_deref(), Some("qwen2.5-72b"));
+        let telegram = settings.channels.telegram.as_ref().unwrap();
+        assert_eq!(telegram.bot_token, "123:abc");
+        assert_eq!(telegram.allowed_users, vec!["alice", "bob"]);
+        assert_eq!(settings.channel_names(), vec!["Telegram"]);
+    }
+
+    #[test]
+    fn config_toml_keeps_provider_and_channels() {
+        let settings = parse_config_toml(
+            r#"
+default_provider = "anthropic"
+default_model = "claude-sonnet-4-6"
+api_key = "env:ANTHROPIC_API_KEY"
+
+[channels_config.discord]
+bot_token = "discord-token"
+"#,
+        )
+        .unwrap();
+        assert_eq!(settings.provider.as_deref(), Some("anthropic"));
+        assert_eq!(settings.api_key.as_deref(), Some("env:ANTHROPIC_API_KEY"));
+        assert!(settings.channels.discord.is_some());
+        assert!(!settings.is_empty());
+    }
+}
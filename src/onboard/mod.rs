@@ -1,10 +1,22 @@
+pub mod bootstrap;
+pub mod config_editor;
+pub mod i18n;
+pub mod import;
+pub mod preview;
+pub mod prompt;
+pub mod schema_browser;
+pub mod task;
+pub mod theme;
+pub mod validate;
 pub mod wizard;
 
 // Re-exported for CLI and external use
 #[allow(unused_imports)]
+pub use bootstrap::run_bootstrap;
+#[allow(unused_imports)]
 pub use wizard::{
     run_channels_repair_wizard, run_models_list, run_models_refresh, run_models_refresh_all,
-    run_models_set, run_models_status, run_quick_setup, run_wizard,
+    run_models_set, run_models_status, run_models_switch, run_quick_setup, run_wizard,
 };
 
 #[cfg(test)]
@@ -16,12 +28,14 @@ mod tests {
     #[test]
     fn wizard_functions_are_reexported() {
         assert_reexport_exists(run_wizard);
+        assert_reexport_exists(run_bootstrap);
         assert_reexport_exists(run_channels_repair_wizard);
         assert_reexport_exists(run_quick_setup);
         assert_reexport_exists(run_models_refresh);
         assert_reexport_exists(run_models_list);
         assert_reexport_exists(run_models_set);
         assert_reexport_exists(run_models_status);
+        assert_reexport_exists(run_models_switch);
         assert_reexport_exists(run_models_refresh_all);
     }
 }
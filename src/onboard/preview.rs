@@ -0,0 +1,481 @@
+//! Preview of the `config.toml` the wizard is about to write.
+//!
+//! Secrets are masked by key name (`*_key`, `*_token`, `*secret*`,
+//! `password`, …) so the preview is safe to show on a shared screen;
+//! `env:NAME` references are shown as-is since they hold no secret.
+//! The same rules redact the `SETUP.md` operator notes the wizard can
+//! write into the workspace.
+
+use crate::config::schema::ENV_SECRET_PREFIX;
+use crate::config::Config;
+use crate::onboard::{prompt, theme};
+use anyhow::{Context, Result};
+use console::{Key, Term};
+use std::fmt::Write as _;
+use std::io::IsTerminal;
+
+pub(crate) const MASK: &str = "••••••••";
+const SECRET_SEGMENTS: &[&str] = &[
+    "key",
+    "keys",
+    "token",
+    "tokens",
+    "secret",
+    "password",
+    "passphrase",
+];
+
+pub(crate) fn is_secret_key(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    key == "db_url"
+        || key
+            .split('_')
+            .any(|segment| SECRET_SEGMENTS.contains(&segment))
+}
+
+fn mask_string(value: &mut toml::Value) {
+    if let toml::Value::String(text) = value {
+        if !text.is_empty() && !text.starts_with(ENV_SECRET_PREFIX) {
+            *text = MASK.to_string();
+        }
+    }
+}
+
+fn mask_value(key: Option<&str>, value: &mut toml::Value) {
+    let secret = key.is_some_and(is_secret_key);
+    match value {
+        toml::Value::Table(table) => mask_table(table),
+        toml::Value::Array(items) => {
+            for item in items {
+                if secret {
+                    mask_string(item);
+                } else {
+                    mask_value(None, item);
+                }
+            }
+        }
+        _ if secret => mask_string(value),
+        _ => {}
+    }
+}
+
+fn mask_table(table: &mut toml::Table) {
+    for (key, value) in table.iter_mut() {
+        mask_value(Some(key), value);
+    }
+}
+
+/// The TOML `config.save()` would write, with secrets masked.
+pub fn render_masked(config: &Config) -> Result<String> {
+    let mut table = config.to_toml_table()?;
+    mask_table(&mut table);
+    toml::to_string_pretty(&table).context("Failed to render config preview")
+}
+
+/// One difference between an existing config and the one about to be
+/// written. `before`/`after` are `None` when the key is added/removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigChange {
+    pub path: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+const MAX_DIFF_VALUE_CHARS: usize = 60;
+
+fn describe(value: &toml::Value) -> String {
+    let text = match value {
+        toml::Value::Table(_) => "[section]".to_string(),
+        other => other.to_string(),
+    };
+    if text.chars().count() > MAX_DIFF_VALUE_CHARS {
+        let cut: String = text.chars().take(MAX_DIFF_VALUE_CHARS).collect();
+        format!("{cut}…")
+    } else {
+        text
+    }
+}
+
+fn diff_into(prefix: &str, old: &toml::Table, new: &toml::Table, out: &mut Vec<ConfigChange>) {
+    let mut keys: Vec<&String> = old.keys().chain(new.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    for key in keys {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match (old.get(key), new.get(key)) {
+            (Some(toml::Value::Table(before)), Some(toml::Value::Table(after))) => {
+                diff_into(&path, before, after, out);
+            }
+            (before, after) if before != after => out.push(ConfigChange {
+                path,
+                before: before.map(describe),
+                after: after.map(describe),
+            }),
+            _ => {}
+        }
+    }
+}
+
+/// Key-by-key differences between two config tables, with secrets masked.
+/// A section present on one side only is reported once, not per key.
+pub fn diff_masked(old: &toml::Table, new: &toml::Table) -> Vec<ConfigChange> {
+    let (mut old, mut new) = (old.clone(), new.clone());
+    mask_table(&mut old);
+    mask_table(&mut new);
+    let mut changes = Vec::new();
+    diff_into("", &old, &new, &mut changes);
+    changes
+}
+
+/// Dotted path and storage location of every non-empty secret in `table`.
+fn secret_locations(
+    prefix: &str,
+    table: &toml::Table,
+    encrypted: bool,
+    out: &mut Vec<(String, String)>,
+) {
+    for (key, value) in table {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match value {
+            toml::Value::Table(child) => secret_locations(&path, child, encrypted, out),
+            _ if !is_secret_key(key) => {}
+            toml::Value::String(text) if text.is_empty() => {}
+            toml::Value::Array(items) if items.is_empty() => {}
+            toml::Value::String(text) if text.starts_with(ENV_SECRET_PREFIX) => {
+                let name = &text[ENV_SECRET_PREFIX.len()..];
+                out.push((path, format!("environment variable `{name}`")));
+            }
+            _ => out.push((
+                path,
+                if encrypted {
+                    "config.toml (encrypted)".to_string()
+                } else {
+                    "config.toml (plaintext)".to_string()
+                },
+            )),
+        }
+    }
+}
+
+/// Operator notes for a deployment: provider, channels, tunnel, security
+/// posture and where each secret is kept. Secret values never appear.
+pub fn setup_markdown(config: &Config) -> Result<String> {
+    let table = config.to_toml_table()?;
+    let mut secrets = Vec::new();
+    secret_locations("", &table, config.secrets.encrypt, &mut secrets);
+
+    let channels = config.channels_config.channels();
+    let channels: Vec<_> = std::iter::once("Cli")
+        .chain(
+            channels
+                .iter()
+                .filter_map(|(channel, ok)| ok.then_some(channel.name())),
+        )
+        .collect();
+    let tunnel = if config.tunnel.provider.is_empty() || config.tunnel.provider == "none" {
+        "none (local only)"
+    } else {
+        config.tunnel.provider.as_str()
+    };
+    let on_off = |on: bool| if on { "on" } else { "off" };
+
+    let mut md = String::from("# ZeroClaw setup\n\n");
+    let _ = writeln!(
+        md,
+        "Generated by `zeroclaw onboard` on {}. Secret values are never written here.\n",
+        chrono::Local::now().format("%Y-%m-%d %H:%M")
+    );
+    md.push_str("## Provider\n\n");
+    let _ = writeln!(
+        md,
+        "- Provider: `{}`\n- Model: `{}`",
+        config.default_provider.as_deref().unwrap_or("openrouter"),
+        config.default_model.as_deref().unwrap_or("(default)")
+    );
+    if let Some(url) = &config.api_url {
+        let _ = writeln!(md, "- Endpoint: `{url}`");
+    }
+    md.push_str("\n## Channels\n\n");
+    for channel in &channels {
+        let _ = writeln!(md, "- {channel}");
+    }
+    let _ = writeln!(md, "\n## Tunnel\n\n- Provider: {tunnel}");
+    let _ = writeln!(
+        md,
+        "- Gateway: {}:{} (pairing {})",
+        config.gateway.host,
+        config.gateway.port,
+        if config.gateway.require_pairing {
+            "required"
+        } else {
+            "disabled"
+        }
+    );
+    md.push_str("\n## Security\n\n");
+    let _ = writeln!(
+        md,
+        "- Autonomy: {:?}, workspace only: {}",
+        config.autonomy.level, config.autonomy.workspace_only
+    );
+    let _ = writeln!(
+        md,
+        "- OTP: {} | Emergency stop: {}",
+        on_off(config.security.otp.enabled),
+        on_off(config.security.estop.enabled)
+    );
+    let _ = writeln!(
+        md,
+        "- Secret encryption: {}",
+        on_off(config.secrets.encrypt)
+    );
+    md.push_str("\n## Secrets\n\n");
+    if secrets.is_empty() {
+        md.push_str("No secrets are configured.\n");
+    } else {
+        md.push_str("| Setting | Stored in |\n|---|---|\n");
+        for (path, location) in &secrets {
+            let _ = writeln!(md, "| `{path}` | {location} |");
+        }
+    }
+    let _ = writeln!(md, "\nConfig file: `{}`", config.config_path.display());
+    Ok(md)
+}
+
+/// Index one past the last line from `start` that fits in `rows` terminal
+/// rows at `cols` columns, counting wrapped lines; always at least one line.
+fn page_end(lines: &[&str], start: usize, rows: usize, cols: u16) -> usize {
+    let mut used = 0;
+    let mut end = start;
+    while end < lines.len() {
+        // Each line is drawn with a 4-column gutter.
+        let height = prompt::display_rows(&format!("    {}", lines[end]), cols);
+        if end > start && used + height > rows {
+            break;
+        }
+        used += height;
+        end += 1;
+    }
+    end
+}
+
+/// A `width`-cell track with the visible `start..end` of `total` lines
+/// filled in.
+fn scrollbar(start: usize, end: usize, total: usize, width: usize) -> String {
+    if total == 0 || width == 0 {
+        return String::new();
+    }
+    let first = start * width / total;
+    let last = (end * width).div_ceil(total).clamp(first + 1, width);
+    (0..width)
+        .map(|cell| {
+            if (first..last).contains(&cell) {
+                '█'
+            } else {
+                '░'
+            }
+        })
+        .collect()
+}
+
+/// Show `text` a screen at a time. Space/Enter/PgDn pages forward, b/PgUp
+/// back, ↑/↓ scroll a line, Home/End jump to either end, q/Esc closes.
+/// Without a terminal, or with plain prompts, the whole text is printed.
+pub fn page(text: &str) -> Result<()> {
+    let lines: Vec<&str> = text.lines().collect();
+    let term = Term::stdout();
+    if !std::io::stdout().is_terminal() || prompt::is_plain() {
+        println!("{text}");
+        return Ok(());
+    }
+
+    let mut start = 0;
+    loop {
+        // Re-read the size for every screen so a resized window gets pages
+        // that fit it; wrapped lines count as the rows they occupy.
+        prompt::wait_for_room()?;
+        let (rows, cols) = term.size();
+        let budget = usize::from(rows).saturating_sub(3).max(5);
+        let end = page_end(&lines, start, budget, cols);
+        let mut drawn = 0;
+        for line in &lines[start..end] {
+            let row = format!("  {} {line}", theme::muted("│"));
+            drawn += prompt::display_rows(&row, cols);
+            println!("{row}");
+        }
+        if end >= lines.len() && start == 0 {
+            return Ok(());
+        }
+        let footer = format!(
+            "  {} {}",
+            theme::muted(scrollbar(start, end, lines.len(), 12)),
+            theme::accent(format!(
+                "lines {}-{} of {} · Space/PgDn next · b/PgUp back · ↑↓ Home End · q done",
+                start + 1,
+                end,
+                lines.len()
+            ))
+        );
+        drawn += prompt::display_rows(&footer, cols);
+        println!("{footer}");
+        let last_start = lines.len().saturating_sub(1);
+        let next = loop {
+            match term.read_key()? {
+                Key::Enter | Key::Char(' ') | Key::PageDown => {
+                    if end >= lines.len() {
+                        return Ok(());
+                    }
+                    break end;
+                }
+                Key::Char('b') | Key::PageUp => break start.saturating_sub(end - start),
+                Key::ArrowDown | Key::Char('j') if end < lines.len() => break start + 1,
+                Key::ArrowUp | Key::Char('k') if start > 0 => break start - 1,
+                Key::Home | Key::Char('g') => break 0,
+                Key::End | Key::Char('G') => {
+                    // Back up from the last line until a full screen fits.
+                    let mut first = last_start;
+                    while first > 0 && page_end(&lines, first - 1, budget, cols) == lines.len() {
+                        first -= 1;
+                    }
+                    break first;
+                }
+                Key::Char('q') | Key::Escape => return Ok(()),
+                _ => {}
+            }
+        };
+        term.clear_last_lines(drawn)?;
+        start = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pages_fit_wrapped_lines_and_scrollbar_tracks_position() {
+        let long = "x".repeat(70);
+        let lines = ["a", long.as_str(), "b", "c"];
+        // The long line wraps to two rows at 40 columns.
+        assert_eq!(page_end(&lines, 0, 3, 40), 2);
+        assert_eq!(page_end(&lines, 2, 3, 40), 4);
+        // A line taller than the screen is still shown on its own.
+        assert_eq!(page_end(&lines, 1, 1, 40), 2);
+
+        assert_eq!(scrollbar(0, 5, 10, 4), "██░░");
+        assert_eq!(scrollbar(5, 10, 10, 4), "░░██");
+        assert_eq!(scrollbar(99, 100, 100, 4), "░░░█");
+    }
+
+    #[test]
+    fn secrets_are_masked_but_env_refs_and_settings_are_not() {
+        let mut table: toml::Table = toml::from_str(
+            r#"
+api_key = "sk-live-123"
+default_model = "claude"
+
+[channels_config.telegram]
+bot_token = "123:abc"
+allowed_users = ["alice"]
+
+[gateway]
+paired_tokens = ["t1", "t2"]
+
+[reliability]
+api_keys = ["env:BACKUP_KEY"]
+
+[behavior.escalation]
+frustration_keywords = ["wtf"]
+
+[agent]
+max_tokens = 4096
+"#,
+        )
+        .unwrap();
+        mask_table(&mut table);
+        let rendered = toml::to_string(&table).unwrap();
+
+        for secret in ["sk-live-123", "123:abc", "t1", "t2"] {
+            assert!(!rendered.contains(secret), "{secret} leaked:\n{rendered}");
+        }
+        for kept in ["claude", "alice", "env:BACKUP_KEY", "wtf", "4096"] {
+            assert!(rendered.contains(kept), "{kept} missing:\n{rendered}");
+        }
+    }
+
+    #[test]
+    fn diff_reports_removed_sections_and_changed_keys_without_secrets() {
+        let old: toml::Table = toml::from_str(
+            r#"
+api_key = "sk-old"
+[memory]
+backend = "sqlite"
+auto_save = true
+[channels_config.telegram]
+bot_token = "123:abc"
+"#,
+        )
+        .unwrap();
+        let new: toml::Table = toml::from_str(
+            r#"
+api_key = "sk-new"
+[memory]
+backend = "qdrant"
+auto_save = true
+[channels_config]
+cli = true
+"#,
+        )
+        .unwrap();
+
+        let changes = diff_masked(&old, &new);
+        assert_eq!(
+            changes,
+            vec![
+                ConfigChange {
+                    path: "channels_config.cli".into(),
+                    before: None,
+                    after: Some("true".into()),
+                },
+                ConfigChange {
+                    path: "channels_config.telegram".into(),
+                    before: Some("[section]".into()),
+                    after: None,
+                },
+                ConfigChange {
+                    path: "memory.backend".into(),
+                    before: Some("\"sqlite\"".into()),
+                    after: Some("\"qdrant\"".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn setup_markdown_lists_secret_locations_without_values() {
+        let mut config = Config::default();
+        config.api_key = Some("sk-live-123".into());
+        config.secrets.encrypt = true;
+        config.channels_config.telegram =
+            Some(toml::from_str("bot_token = \"123:abc\"\nallowed_users = []").unwrap());
+
+        let md = setup_markdown(&config).unwrap();
+        assert!(
+            !md.contains("sk-live-123") && !md.contains("123:abc"),
+            "{md}"
+        );
+        assert!(
+            md.contains("| `api_key` | config.toml (encrypted) |"),
+            "{md}"
+        );
+        assert!(md.contains("`channels_config.telegram.bot_token`"), "{md}");
+        assert!(md.contains("- Telegram"), "{md}");
+    }
+}
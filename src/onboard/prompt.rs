@@ -0,0 +1,464 @@
+//! Prompt builders for the onboarding wizard.
+//!
+//! [`Select`], [`Confirm`], [`Input`] and [`MultiSelect`] mirror the parts of
+//! the `dialoguer` builders the wizard uses. Normally they hand off to
+//! `dialoguer`; after `zeroclaw onboard --no-tui` ([`set_plain`]) they print
+//! numbered options and read whole lines from stdin instead, with no cursor
+//! movement or raw mode, so screen readers and dumb terminals can finish
+//! setup through the same steps.
+//!
+//! Interactive prompts check the terminal size first: below
+//! [`MIN_COLS`]×[`MIN_ROWS`] a "terminal too small" notice is shown until
+//! the window is enlarged, and long lists scroll within the rows available.
+
+use super::validate::Rule;
+use anyhow::{bail, Result};
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+static PLAIN: AtomicBool = AtomicBool::new(false);
+
+/// Smallest terminal the interactive prompts are drawn in.
+pub const MIN_COLS: u16 = 40;
+pub const MIN_ROWS: u16 = 10;
+/// Rows kept free around a list for the prompt line and the step header.
+const LIST_CHROME_ROWS: u16 = 4;
+const RESIZE_POLL: Duration = Duration::from_millis(200);
+
+/// Switch every wizard prompt to plain line-based input.
+pub fn set_plain(plain: bool) {
+    PLAIN.store(plain, Ordering::Relaxed);
+}
+
+/// Whether prompts are in plain line-based mode.
+pub fn is_plain() -> bool {
+    PLAIN.load(Ordering::Relaxed)
+}
+
+/// Print `prompt` and read one line from stdin, without the trailing newline.
+pub fn read_plain_line(prompt: &str) -> Result<String> {
+    let mut stdout = std::io::stdout();
+    write!(stdout, "{prompt}")?;
+    stdout.flush()?;
+    let mut line = String::new();
+    if std::io::stdin().lock().read_line(&mut line)? == 0 {
+        bail!("Input closed before setup finished");
+    }
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+/// Current terminal size as (rows, cols), when stdout is a terminal.
+pub fn terminal_size() -> Option<(u16, u16)> {
+    let term = console::Term::stdout();
+    term.is_term().then(|| term.size())
+}
+
+fn too_small((rows, cols): (u16, u16)) -> bool {
+    rows < MIN_ROWS || cols < MIN_COLS
+}
+
+/// How many list items fit under a prompt in a terminal `rows` tall.
+fn visible_items(rows: u16) -> usize {
+    usize::from(rows.saturating_sub(LIST_CHROME_ROWS)).max(3)
+}
+
+/// Terminal rows `text` takes up at `cols` columns, counting wrapping.
+pub fn display_rows(text: &str, cols: u16) -> usize {
+    let cols = usize::from(cols.max(1));
+    console::measure_text_width(text).max(1).div_ceil(cols)
+}
+
+/// While the terminal is below the minimum size, show a notice and wait
+/// for it to be resized; returns at once when it fits or is not a terminal.
+pub fn wait_for_room() -> Result<()> {
+    let Some(mut size) = terminal_size() else {
+        return Ok(());
+    };
+    if !too_small(size) {
+        return Ok(());
+    }
+    let term = console::Term::stdout();
+    let draw = |(rows, cols): (u16, u16)| -> Result<()> {
+        term.clear_screen()?;
+        term.write_line(&format!(
+            "Terminal too small ({cols}×{rows}).\nEnlarge it to at least {MIN_COLS}×{MIN_ROWS}, or rerun with --no-tui."
+        ))?;
+        Ok(())
+    };
+    draw(size)?;
+    while too_small(size) {
+        std::thread::sleep(RESIZE_POLL);
+        let now = terminal_size().unwrap_or((MIN_ROWS, MIN_COLS));
+        if now != size {
+            size = now;
+            draw(size)?;
+        }
+    }
+    term.clear_screen()?;
+    Ok(())
+}
+
+/// A 1-based option number in `1..=len`; empty input picks `default`.
+fn parse_selection(raw: &str, len: usize, default: usize) -> Option<usize> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return (default < len).then_some(default);
+    }
+    raw.parse::<usize>()
+        .ok()
+        .filter(|n| (1..=len).contains(n))
+        .map(|n| n - 1)
+}
+
+/// Comma- or space-separated option numbers; empty input picks nothing.
+fn parse_multi_selection(raw: &str, len: usize) -> Option<Vec<usize>> {
+    let mut picked = Vec::new();
+    for part in raw.split([',', ' ']).filter(|part| !part.trim().is_empty()) {
+        let index = parse_selection(part, len, len)?;
+        if !picked.contains(&index) {
+            picked.push(index);
+        }
+    }
+    picked.sort_unstable();
+    Some(picked)
+}
+
+fn parse_confirm(raw: &str, default: Option<bool>) -> Option<bool> {
+    match raw.trim().to_ascii_lowercase().as_str() {
+        "" => default,
+        "y" | "yes" => Some(true),
+        "n" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+fn print_options(prompt: &str, items: &[String]) {
+    println!("{prompt}");
+    for (index, item) in items.iter().enumerate() {
+        println!("  {}) {item}", index + 1);
+    }
+}
+
+#[derive(Default)]
+pub struct Select {
+    prompt: String,
+    items: Vec<String>,
+    default: usize,
+}
+
+impl Select {
+    pub fn new() -> Self {
+        <Self as Default>::default()
+    }
+
+    #[must_use]
+    pub fn with_prompt<S: Into<String>>(mut self, prompt: S) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    #[must_use]
+    pub fn items<T: ToString, I: IntoIterator<Item = T>>(mut self, items: I) -> Self {
+        self.items = items.into_iter().map(|item| item.to_string()).collect();
+        self
+    }
+
+    #[must_use]
+    pub fn default(mut self, index: usize) -> Self {
+        self.default = index;
+        self
+    }
+
+    /// The index of the chosen item.
+    pub fn interact(self) -> Result<usize> {
+        if !is_plain() {
+            wait_for_room()?;
+            let rows = terminal_size().map_or(MIN_ROWS, |(rows, _)| rows);
+            return Ok(dialoguer::Select::new()
+                .with_prompt(self.prompt)
+                .items(&self.items)
+                .default(self.default)
+                .max_length(visible_items(rows))
+                .interact()?);
+        }
+        if self.items.is_empty() {
+            bail!("Nothing to choose from for: {}", self.prompt.trim());
+        }
+        print_options(self.prompt.trim(), &self.items);
+        let hint = format!(
+            "Enter a number 1-{} [{}]: ",
+            self.items.len(),
+            self.default.min(self.items.len() - 1) + 1
+        );
+        loop {
+            let raw = read_plain_line(&hint)?;
+            match parse_selection(&raw, self.items.len(), self.default) {
+                Some(index) => return Ok(index),
+                None => println!("Please enter a number from the list."),
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct MultiSelect {
+    prompt: String,
+    items: Vec<String>,
+}
+
+impl MultiSelect {
+    pub fn new() -> Self {
+        <Self as Default>::default()
+    }
+
+    #[must_use]
+    pub fn with_prompt<S: Into<String>>(mut self, prompt: S) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    #[must_use]
+    pub fn items<T: ToString, I: IntoIterator<Item = T>>(mut self, items: I) -> Self {
+        self.items = items.into_iter().map(|item| item.to_string()).collect();
+        self
+    }
+
+    /// The indices of the chosen items, in list order.
+    pub fn interact(self) -> Result<Vec<usize>> {
+        if !is_plain() {
+            wait_for_room()?;
+            let rows = terminal_size().map_or(MIN_ROWS, |(rows, _)| rows);
+            return Ok(dialoguer::MultiSelect::new()
+                .with_prompt(self.prompt)
+                .items(&self.items)
+                .max_length(visible_items(rows))
+                .interact()?);
+        }
+        print_options(self.prompt.trim(), &self.items);
+        loop {
+            let raw = read_plain_line("Enter numbers separated by commas (Enter for none): ")?;
+            match parse_multi_selection(&raw, self.items.len()) {
+                Some(picked) => return Ok(picked),
+                None => println!("Please enter numbers from the list."),
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Confirm {
+    prompt: String,
+    default: Option<bool>,
+}
+
+impl Confirm {
+    pub fn new() -> Self {
+        <Self as Default>::default()
+    }
+
+    #[must_use]
+    pub fn with_prompt<S: Into<String>>(mut self, prompt: S) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    #[must_use]
+    pub fn default(mut self, value: bool) -> Self {
+        self.default = Some(value);
+        self
+    }
+
+    pub fn interact(self) -> Result<bool> {
+        if !is_plain() {
+            wait_for_room()?;
+            let mut confirm = dialoguer::Confirm::new().with_prompt(self.prompt);
+            if let Some(default) = self.default {
+                confirm = confirm.default(default);
+            }
+            return Ok(confirm.interact()?);
+        }
+        let hint = match self.default {
+            Some(true) => "[Y/n]",
+            Some(false) => "[y/N]",
+            None => "[y/n]",
+        };
+        let prompt = format!("{} {hint}: ", self.prompt.trim());
+        loop {
+            match parse_confirm(&read_plain_line(&prompt)?, self.default) {
+                Some(value) => return Ok(value),
+                None => println!("Please answer y or n."),
+            }
+        }
+    }
+}
+
+type Validator<'a, T> = Box<dyn FnMut(&T) -> Result<(), String> + 'a>;
+
+pub struct Input<'a, T> {
+    prompt: String,
+    default: Option<T>,
+    initial_text: Option<String>,
+    allow_empty: bool,
+    validator: Option<Validator<'a, T>>,
+}
+
+impl<T> Default for Input<'_, T> {
+    fn default() -> Self {
+        Self {
+            prompt: String::new(),
+            default: None,
+            initial_text: None,
+            allow_empty: false,
+            validator: None,
+        }
+    }
+}
+
+impl<'a, T> Input<'a, T>
+where
+    T: Clone + ToString + FromStr,
+    T::Err: ToString,
+{
+    pub fn new() -> Self {
+        <Self as Default>::default()
+    }
+
+    #[must_use]
+    pub fn with_prompt<S: Into<String>>(mut self, prompt: S) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    #[must_use]
+    pub fn default(mut self, value: T) -> Self {
+        self.default = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn with_initial_text<S: Into<String>>(mut self, text: S) -> Self {
+        self.initial_text = Some(text.into());
+        self
+    }
+
+    #[must_use]
+    pub fn allow_empty(mut self, allow: bool) -> Self {
+        self.allow_empty = allow;
+        self
+    }
+
+    #[must_use]
+    pub fn validate_with<V, E>(mut self, mut validator: V) -> Self
+    where
+        V: FnMut(&T) -> Result<(), E> + 'a,
+        E: ToString,
+    {
+        self.validator = Some(Box::new(move |value: &T| {
+            validator(value).map_err(|err| err.to_string())
+        }));
+        self
+    }
+
+    pub fn interact_text(mut self) -> Result<T> {
+        if !is_plain() {
+            wait_for_room()?;
+            let mut input = dialoguer::Input::<T>::new()
+                .with_prompt(self.prompt)
+                .allow_empty(self.allow_empty);
+            if let Some(default) = self.default {
+                input = input.default(default);
+            }
+            if let Some(text) = self.initial_text {
+                input = input.with_initial_text(text);
+            }
+            if let Some(validator) = self.validator {
+                input = input.validate_with(validator);
+            }
+            return Ok(input.interact_text()?);
+        }
+
+        // Initial text cannot be pre-typed on a plain line, so it acts as
+        // the default instead.
+        let shown_default = self
+            .default
+            .as_ref()
+            .map(ToString::to_string)
+            .or_else(|| self.initial_text.clone().filter(|text| !text.is_empty()));
+        let prompt = match &shown_default {
+            Some(default) => format!("{} [{default}]: ", self.prompt.trim()),
+            None => format!("{}: ", self.prompt.trim()),
+        };
+        loop {
+            let raw = read_plain_line(&prompt)?;
+            let value = if raw.trim().is_empty() {
+                if let Some(default) = self.default.clone() {
+                    Ok(default)
+                } else if let Some(text) = shown_default.as_deref() {
+                    text.parse::<T>().map_err(|err| err.to_string())
+                } else if self.allow_empty {
+                    raw.trim().parse::<T>().map_err(|err| err.to_string())
+                } else {
+                    Err("A value is required.".to_string())
+                }
+            } else {
+                raw.trim().parse::<T>().map_err(|err| err.to_string())
+            };
+            let checked = value.and_then(|value| match self.validator.as_mut() {
+                Some(validator) => validator(&value).map(|()| value),
+                None => Ok(value),
+            });
+            match checked {
+                Ok(value) => return Ok(value),
+                Err(err) => println!("{err}"),
+            }
+        }
+    }
+}
+
+impl<'a> Input<'a, String> {
+    /// Check the answer against `rule` on Enter and re-prompt until it passes.
+    #[must_use]
+    pub fn rule(self, rule: Rule<'a>) -> Self {
+        self.validate_with(move |value: &String| rule.check(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_answers_parse_numbers_defaults_and_yes_no() {
+        assert_eq!(parse_selection("", 3, 1), Some(1));
+        assert_eq!(parse_selection(" 3 ", 3, 0), Some(2));
+        assert_eq!(parse_selection("0", 3, 0), None);
+        assert_eq!(parse_selection("4", 3, 0), None);
+        assert_eq!(parse_selection("two", 3, 0), None);
+
+        assert_eq!(parse_multi_selection("3, 1 3", 3), Some(vec![0, 2]));
+        assert_eq!(parse_multi_selection("", 3), Some(Vec::new()));
+        assert_eq!(parse_multi_selection("1,9", 3), None);
+
+        assert_eq!(parse_confirm("", Some(true)), Some(true));
+        assert_eq!(parse_confirm("No", Some(true)), Some(false));
+        assert_eq!(parse_confirm("", None), None);
+        assert_eq!(parse_confirm("maybe", Some(false)), None);
+    }
+
+    #[test]
+    fn small_terminals_are_detected_and_lists_fit_the_height() {
+        assert!(too_small((MIN_ROWS - 1, 120)));
+        assert!(too_small((40, MIN_COLS - 1)));
+        assert!(!too_small((MIN_ROWS, MIN_COLS)));
+
+        assert_eq!(visible_items(24), 20);
+        assert_eq!(visible_items(2), 3);
+
+        assert_eq!(display_rows("", 80), 1);
+        assert_eq!(display_rows(&"x".repeat(81), 80), 2);
+        assert_eq!(display_rows("\u{1b}[2mdim\u{1b}[0m", 3), 1);
+        assert_eq!(display_rows("abc", 0), 3);
+    }
+}
@@ -0,0 +1,440 @@
+//! Interactive browser for the config JSON Schema (`zeroclaw config browse`).
+//!
+//! The schemars output is flattened into dotted field paths (`$ref`s and
+//! nullable `anyOf` wrappers resolved) and walked section by section, with
+//! a search over paths and doc strings. Each field shows its type, default
+//! and doc string.
+
+use crate::onboard::{prompt, theme};
+use anyhow::Result;
+use serde_json::Value;
+use std::io::IsTerminal;
+
+/// Nesting limit so recursive definitions cannot loop forever.
+const MAX_DEPTH: usize = 8;
+const MAX_DEFAULT_CHARS: usize = 60;
+
+/// One key of `config.toml`, as described by the schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaField {
+    /// Dotted path, e.g. `gateway.port`.
+    pub path: String,
+    pub kind: String,
+    pub default: Option<String>,
+    pub doc: String,
+    /// A table with fields of its own.
+    pub section: bool,
+}
+
+impl SchemaField {
+    fn name(&self) -> &str {
+        self.path.rsplit('.').next().unwrap_or(&self.path)
+    }
+
+    fn matches(&self, needle: &str) -> bool {
+        self.path.to_lowercase().contains(needle) || self.doc.to_lowercase().contains(needle)
+    }
+
+    fn summary(&self) -> String {
+        let default = self
+            .default
+            .as_deref()
+            .map(|value| format!(" = {value}"))
+            .unwrap_or_default();
+        let doc = self.doc.lines().next().unwrap_or("");
+        if doc.is_empty() {
+            format!("{}  {}{default}", self.path, self.kind)
+        } else {
+            format!("{}  {}{default}  — {doc}", self.path, self.kind)
+        }
+    }
+}
+
+/// Follow a `#/$defs/...` reference.
+fn deref<'a>(root: &'a Value, node: &'a Value) -> &'a Value {
+    node.get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|reference| reference.strip_prefix('#'))
+        .and_then(|pointer| root.pointer(pointer))
+        .unwrap_or(node)
+}
+
+/// The non-null variant of a nullable `anyOf`/`oneOf`, resolved.
+fn unwrap_nullable<'a>(root: &'a Value, node: &'a Value) -> &'a Value {
+    let node = deref(root, node);
+    let variants = node
+        .get("anyOf")
+        .or_else(|| node.get("oneOf"))
+        .and_then(Value::as_array);
+    match variants.map(|variants| {
+        variants
+            .iter()
+            .filter(|variant| variant.get("type").and_then(Value::as_str) != Some("null"))
+            .collect::<Vec<_>>()
+    }) {
+        Some(non_null) if non_null.len() == 1 => deref(root, non_null[0]),
+        _ => node,
+    }
+}
+
+fn type_name(root: &Value, node: &Value) -> String {
+    let resolved = deref(root, node);
+
+    if let Some(variants) = resolved
+        .get("anyOf")
+        .or_else(|| resolved.get("oneOf"))
+        .and_then(Value::as_array)
+    {
+        let is_null =
+            |variant: &&Value| variant.get("type").and_then(Value::as_str) == Some("null");
+        let names: Vec<String> = variants
+            .iter()
+            .filter(|variant| !is_null(variant))
+            .map(|variant| type_name(root, variant))
+            .collect();
+        let name = names.join(" | ");
+        return if variants.iter().any(|variant| is_null(&variant)) {
+            format!("{name}?")
+        } else {
+            name
+        };
+    }
+    if let Some(value) = resolved.get("const") {
+        return value
+            .as_str()
+            .map_or_else(|| value.to_string(), str::to_string);
+    }
+    if let Some(values) = resolved.get("enum").and_then(Value::as_array) {
+        let names: Vec<String> = values
+            .iter()
+            .filter(|value| !value.is_null())
+            .map(|value| {
+                value
+                    .as_str()
+                    .map_or_else(|| value.to_string(), str::to_string)
+            })
+            .collect();
+        let nullable = values.iter().any(Value::is_null);
+        return format!(
+            "enum: {}{}",
+            names.join("|"),
+            if nullable { "?" } else { "" }
+        );
+    }
+
+    let (base, nullable) = match resolved.get("type") {
+        Some(Value::String(name)) => (name.clone(), false),
+        Some(Value::Array(names)) => {
+            let names: Vec<&str> = names.iter().filter_map(Value::as_str).collect();
+            let nullable = names.contains(&"null");
+            let names: Vec<&str> = names.into_iter().filter(|name| *name != "null").collect();
+            (names.join(" | "), nullable)
+        }
+        _ => ("any".to_string(), false),
+    };
+    let base = match base.as_str() {
+        "array" => {
+            let items = resolved
+                .get("items")
+                .map_or_else(|| "any".to_string(), |items| type_name(root, items));
+            format!("array<{items}>")
+        }
+        "object" if resolved.get("properties").is_some() => node
+            .get("$ref")
+            .and_then(Value::as_str)
+            .and_then(|reference| reference.rsplit('/').next())
+            .unwrap_or("object")
+            .to_string(),
+        "object" => {
+            let values = resolved
+                .get("additionalProperties")
+                .filter(|values| values.is_object())
+                .map_or_else(|| "any".to_string(), |values| type_name(root, values));
+            format!("map<string, {values}>")
+        }
+        _ => base,
+    };
+    if nullable {
+        format!("{base}?")
+    } else {
+        base
+    }
+}
+
+fn describe_default(value: &Value) -> String {
+    let text = value.to_string();
+    if text.chars().count() > MAX_DEFAULT_CHARS {
+        let cut: String = text.chars().take(MAX_DEFAULT_CHARS).collect();
+        format!("{cut}…")
+    } else {
+        text
+    }
+}
+
+fn flatten_into(
+    root: &Value,
+    node: &Value,
+    prefix: &str,
+    depth: usize,
+    out: &mut Vec<SchemaField>,
+) {
+    let node = unwrap_nullable(root, node);
+    let Some(properties) = node.get("properties").and_then(Value::as_object) else {
+        return;
+    };
+    for (name, property) in properties {
+        let path = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{prefix}.{name}")
+        };
+        let resolved = unwrap_nullable(root, property);
+        let section = resolved.get("properties").is_some_and(Value::is_object);
+        let doc = property
+            .get("description")
+            .or_else(|| resolved.get("description"))
+            .and_then(Value::as_str)
+            .unwrap_or("")
+            .to_string();
+        out.push(SchemaField {
+            path: path.clone(),
+            kind: if section {
+                "section".to_string()
+            } else {
+                type_name(root, property)
+            },
+            default: if section {
+                None
+            } else {
+                property.get("default").map(describe_default)
+            },
+            doc,
+            section,
+        });
+        if section && depth < MAX_DEPTH {
+            flatten_into(root, resolved, &path, depth + 1, out);
+        }
+    }
+}
+
+/// Every field of the schema, depth first with keys sorted in each section.
+pub fn flatten(schema: &Value) -> Vec<SchemaField> {
+    let mut fields = Vec::new();
+    flatten_into(schema, schema, "", 0, &mut fields);
+    fields
+}
+
+/// Fields whose path or doc string contains `needle` (case-insensitive).
+pub fn search<'a>(fields: &'a [SchemaField], needle: &str) -> Vec<&'a SchemaField> {
+    let needle = needle.trim().to_lowercase();
+    fields
+        .iter()
+        .filter(|field| field.matches(&needle))
+        .collect()
+}
+
+/// Direct children of the section at `prefix` (`""` is the top level).
+fn children<'a>(fields: &'a [SchemaField], prefix: &str) -> Vec<&'a SchemaField> {
+    fields
+        .iter()
+        .filter(|field| match prefix {
+            "" => !field.path.contains('.'),
+            _ => field
+                .path
+                .strip_prefix(prefix)
+                .and_then(|rest| rest.strip_prefix('.'))
+                .is_some_and(|rest| !rest.contains('.')),
+        })
+        .collect()
+}
+
+fn print_detail(field: &SchemaField) {
+    println!();
+    println!("  {}", theme::strong(&field.path));
+    println!("    {} {}", theme::muted("type:   "), field.kind);
+    println!(
+        "    {} {}",
+        theme::muted("default:"),
+        field.default.as_deref().unwrap_or("(none)")
+    );
+    if field.doc.is_empty() {
+        println!("    {}", theme::muted("(no description)"));
+    } else {
+        for line in field.doc.lines() {
+            println!("    {line}");
+        }
+    }
+    println!();
+}
+
+/// Pick from search results until the user goes back.
+fn browse_matches(matches: &[&SchemaField]) -> Result<()> {
+    if matches.is_empty() {
+        println!("  {}", theme::warning("No fields match."));
+        return Ok(());
+    }
+    loop {
+        let mut items = vec!["⬅ Back".to_string()];
+        items.extend(matches.iter().map(|field| field.summary()));
+        let choice = prompt::Select::new()
+            .with_prompt(format!("  {} matching fields", matches.len()))
+            .items(&items)
+            .default(0)
+            .interact()?;
+        if choice == 0 {
+            return Ok(());
+        }
+        print_detail(matches[choice - 1]);
+    }
+}
+
+/// Browse `schema` section by section. With `needle`, start from the search
+/// results. Without a terminal, print matching fields one per line.
+pub fn browse(schema: &Value, needle: Option<&str>) -> Result<()> {
+    let fields = flatten(schema);
+
+    if !std::io::stdin().is_terminal() || !std::io::stdout().is_terminal() {
+        let shown = match needle {
+            Some(needle) => search(&fields, needle),
+            None => fields.iter().collect(),
+        };
+        for field in shown {
+            println!("{}", field.summary());
+        }
+        return Ok(());
+    }
+
+    if let Some(needle) = needle {
+        browse_matches(&search(&fields, needle))?;
+    }
+
+    let mut path: Vec<String> = Vec::new();
+    loop {
+        let prefix = path.join(".");
+        let entries = children(&fields, &prefix);
+        let mut items = vec!["🔍 Search fields…".to_string()];
+        items.push(if path.is_empty() {
+            "✖ Quit".to_string()
+        } else {
+            "⬆ Up".to_string()
+        });
+        items.extend(entries.iter().map(|field| {
+            if field.section {
+                let count = children(&fields, &field.path).len();
+                format!("▸ {}  ({count} fields)", field.name())
+            } else {
+                format!("  {}  {}", field.name(), theme::muted(&field.kind))
+            }
+        }));
+
+        let title = if prefix.is_empty() {
+            "config.toml".to_string()
+        } else {
+            format!("[{prefix}]")
+        };
+        let choice = prompt::Select::new()
+            .with_prompt(format!("  {title}"))
+            .items(&items)
+            .default(0)
+            .interact()?;
+        match choice {
+            0 => {
+                let needle: String = prompt::Input::new()
+                    .with_prompt("  Search")
+                    .interact_text()?;
+                browse_matches(&search(&fields, &needle))?;
+            }
+            1 => {
+                if path.pop().is_none() {
+                    return Ok(());
+                }
+            }
+            _ => {
+                let field = entries[choice - 2];
+                if field.section {
+                    path.push(field.name().to_string());
+                } else {
+                    print_detail(field);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn flattens_refs_and_nullable_sections_with_types_and_defaults() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "default_model": {
+                    "description": "Model used when none is given.",
+                    "type": ["string", "null"],
+                    "default": null
+                },
+                "gateway": { "$ref": "#/$defs/GatewayConfig" },
+                "tunnel": { "anyOf": [{ "$ref": "#/$defs/TunnelConfig" }, { "type": "null" }] }
+            },
+            "$defs": {
+                "GatewayConfig": {
+                    "type": "object",
+                    "properties": {
+                        "port": { "type": "integer", "default": 42617, "description": "Port." },
+                        "paired_tokens": { "type": "array", "items": { "type": "string" } }
+                    }
+                },
+                "TunnelConfig": {
+                    "type": "object",
+                    "properties": {
+                        "provider": { "enum": ["none", "cloudflare"], "default": "none" }
+                    }
+                }
+            }
+        });
+
+        let fields = flatten(&schema);
+        let paths: Vec<&str> = fields.iter().map(|field| field.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "default_model",
+                "gateway",
+                "gateway.paired_tokens",
+                "gateway.port",
+                "tunnel",
+                "tunnel.provider",
+            ]
+        );
+        assert_eq!(fields[0].kind, "string?");
+        assert!(fields[1].section);
+        assert_eq!(fields[2].kind, "array<string>");
+        assert_eq!(fields[3].default.as_deref(), Some("42617"));
+        assert_eq!(fields[5].kind, "enum: none|cloudflare");
+
+        let top: Vec<&str> = children(&fields, "").iter().map(|f| f.name()).collect();
+        assert_eq!(top, ["default_model", "gateway", "tunnel"]);
+        let hits: Vec<&str> = search(&fields, "PORT")
+            .iter()
+            .map(|field| field.path.as_str())
+            .collect();
+        assert_eq!(hits, ["gateway.port"]);
+    }
+
+    #[test]
+    fn real_config_schema_flattens_known_fields() {
+        let fields = flatten(&crate::config::config_json_schema());
+        let port = fields
+            .iter()
+            .find(|field| field.path == "gateway.port")
+            .expect("gateway.port");
+        assert_eq!(port.kind, "integer");
+        assert!(port.default.is_some());
+        assert!(fields
+            .iter()
+            .any(|field| field.path == "memory" && field.section));
+    }
+}
@@ -0,0 +1,127 @@
+//! Long-running CLI actions on a background tokio task.
+//!
+//! The action streams progress lines back over a channel; they are printed
+//! above a spinner (whose text the action can also update) while it runs,
+//! and Esc aborts the task. Without a terminal the lines are printed as
+//! they arrive and nothing is drawn.
+
+use super::wizard::{EscKeyWatcher, SPINNER_FRAMES, SPINNER_TICK};
+use super::{prompt, theme};
+use anyhow::Result;
+use std::future::Future;
+use tokio::sync::mpsc;
+
+enum Update {
+    Line(String),
+    Status(String),
+}
+
+/// Handle a background action uses to report progress.
+#[derive(Clone)]
+pub struct Progress(mpsc::UnboundedSender<Update>);
+
+impl Progress {
+    /// Print `line` above the spinner. Lines sent after cancellation are dropped.
+    pub fn line(&self, line: impl Into<String>) {
+        let _ = self.0.send(Update::Line(line.into()));
+    }
+
+    /// Replace the text next to the spinner.
+    pub fn status(&self, status: impl Into<String>) {
+        let _ = self.0.send(Update::Status(status.into()));
+    }
+}
+
+/// Run `job` on a tokio task, printing its progress lines above a spinner
+/// next to `message`. Returns `Ok(None)` when the user pressed Esc; the task
+/// is aborted at its next await point.
+pub async fn run_with_progress<T, F, Fut>(message: &str, job: F) -> Result<Option<T>>
+where
+    T: Send + 'static,
+    F: FnOnce(Progress) -> Fut,
+    Fut: Future<Output = Result<T>> + Send + 'static,
+{
+    let (tx, mut updates) = mpsc::unbounded_channel();
+    let mut message = message.to_string();
+    let mut task = tokio::spawn(job(Progress(tx)));
+
+    let term = console::Term::stderr();
+    let draw = term.is_term() && !prompt::is_plain();
+    let mut esc = if prompt::is_plain() {
+        None
+    } else {
+        EscKeyWatcher::new()
+    };
+    let mut ticker = tokio::time::interval(SPINNER_TICK);
+    let mut frame = 0usize;
+
+    let outcome = loop {
+        tokio::select! {
+            result = &mut task => {
+                break result
+                    .unwrap_or_else(|error| Err(anyhow::anyhow!("background task failed: {error}")))
+                    .map(Some);
+            }
+            Some(update) = updates.recv() => match update {
+                Update::Line(line) => {
+                    if draw {
+                        let _ = term.clear_line();
+                    }
+                    println!("{line}");
+                }
+                Update::Status(status) => message = status,
+            },
+            _ = ticker.tick() => {
+                if esc.as_mut().is_some_and(EscKeyWatcher::esc_pressed) {
+                    task.abort();
+                    break Ok(None);
+                }
+                if draw {
+                    let _ = term.clear_line();
+                    let _ = term.write_str(&format!(
+                        "  {} {message} {}",
+                        theme::accent(SPINNER_FRAMES[frame % SPINNER_FRAMES.len()]),
+                        theme::muted(if esc.is_some() { "(Esc to cancel)" } else { "" })
+                    ));
+                    frame += 1;
+                }
+            }
+        }
+    };
+
+    drop(esc);
+    if draw {
+        let _ = term.clear_line();
+    }
+    // Lines sent just before the task finished.
+    while let Ok(update) = updates.try_recv() {
+        if let Update::Line(line) = update {
+            println!("{line}");
+        }
+    }
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_task_result_after_streaming_progress() {
+        let result = run_with_progress("Working...", |progress| async move {
+            progress.line("step 1");
+            progress.status("Finishing...");
+            progress.line("step 2");
+            Ok(42)
+        })
+        .await
+        .unwrap();
+        assert_eq!(result, Some(42));
+
+        let error = run_with_progress("Working...", |_| async { anyhow::bail!("boom") })
+            .await
+            .map(|_: Option<()>| ())
+            .unwrap_err();
+        assert!(error.to_string().contains("boom"));
+    }
+}
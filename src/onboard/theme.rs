@@ -0,0 +1,177 @@
+//! Color themes for the onboarding wizard and model commands.
+//!
+//! Screens style text through semantic helpers ([`accent`], [`success`],
+//! [`warning`], [`error`], [`strong`], [`muted`]) instead of fixed colors, so
+//! one [`UiTheme`] switch restyles everything. The active theme comes from
+//! `zeroclaw onboard --theme`, else `[ui].theme`.
+
+use crate::config::UiTheme;
+use console::{style, Color, StyledObject};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static ACTIVE: AtomicU8 = AtomicU8::new(0);
+
+struct Palette {
+    accent: Color,
+    success: Color,
+    warning: Color,
+    error: Color,
+    strong: Color,
+    bright: bool,
+    dim_muted: bool,
+}
+
+const DARK: Palette = Palette {
+    accent: Color::Cyan,
+    success: Color::Green,
+    warning: Color::Yellow,
+    error: Color::Red,
+    strong: Color::White,
+    bright: false,
+    dim_muted: true,
+};
+
+const LIGHT: Palette = Palette {
+    accent: Color::Blue,
+    success: Color::Green,
+    warning: Color::Color256(130),
+    error: Color::Red,
+    strong: Color::Black,
+    bright: false,
+    dim_muted: true,
+};
+
+const HIGH_CONTRAST: Palette = Palette {
+    accent: Color::Cyan,
+    success: Color::Green,
+    warning: Color::Yellow,
+    error: Color::Red,
+    strong: Color::White,
+    bright: true,
+    dim_muted: false,
+};
+
+fn encode(theme: UiTheme) -> u8 {
+    match theme {
+        UiTheme::Dark => 0,
+        UiTheme::Light => 1,
+        UiTheme::HighContrast => 2,
+        UiTheme::NoColor => 3,
+    }
+}
+
+/// The theme currently in effect.
+pub fn current() -> UiTheme {
+    match ACTIVE.load(Ordering::Relaxed) {
+        1 => UiTheme::Light,
+        2 => UiTheme::HighContrast,
+        3 => UiTheme::NoColor,
+        _ => UiTheme::Dark,
+    }
+}
+
+/// Switch the process-wide theme. `no-color` also turns off ANSI styling
+/// for everything printed through `console`.
+pub fn set_theme(theme: UiTheme) {
+    ACTIVE.store(encode(theme), Ordering::Relaxed);
+    if theme == UiTheme::NoColor {
+        console::set_colors_enabled(false);
+        console::set_colors_enabled_stderr(false);
+    }
+}
+
+/// Pick the onboarding theme: the `--theme` flag wins, then `[ui].theme`
+/// from an existing config file, then the default.
+pub async fn init_for_onboarding(flag: Option<UiTheme>) {
+    let theme = match flag {
+        Some(theme) => theme,
+        None => configured_theme().await.unwrap_or_default(),
+    };
+    set_theme(theme);
+}
+
+async fn configured_theme() -> Option<UiTheme> {
+    let (config_dir, _) = crate::config::schema::resolve_runtime_dirs_for_onboarding()
+        .await
+        .ok()?;
+    let raw = tokio::fs::read_to_string(config_dir.join("config.toml"))
+        .await
+        .ok()?;
+    theme_from_toml(&raw)
+}
+
+fn theme_from_toml(raw: &str) -> Option<UiTheme> {
+    let value: toml::Value = toml::from_str(raw).ok()?;
+    value.get("ui")?.get("theme")?.clone().try_into().ok()
+}
+
+fn palette() -> &'static Palette {
+    match current() {
+        UiTheme::Light => &LIGHT,
+        UiTheme::HighContrast => &HIGH_CONTRAST,
+        UiTheme::Dark | UiTheme::NoColor => &DARK,
+    }
+}
+
+fn paint<D>(val: D, color: Color) -> StyledObject<D> {
+    let palette = palette();
+    let styled = style(val).fg(color);
+    if palette.bright {
+        styled.bright().bold()
+    } else {
+        styled
+    }
+}
+
+/// Headings, links, and values the user picked.
+pub fn accent<D>(val: D) -> StyledObject<D> {
+    paint(val, palette().accent)
+}
+
+/// Completed steps and confirmations.
+pub fn success<D>(val: D) -> StyledObject<D> {
+    paint(val, palette().success)
+}
+
+/// Cautions and optional follow-ups.
+pub fn warning<D>(val: D) -> StyledObject<D> {
+    paint(val, palette().warning)
+}
+
+/// Failures.
+pub fn error<D>(val: D) -> StyledObject<D> {
+    paint(val, palette().error)
+}
+
+/// Emphasized body text.
+pub fn strong<D>(val: D) -> StyledObject<D> {
+    paint(val, palette().strong)
+}
+
+/// Secondary hints. High-contrast keeps these at full brightness.
+pub fn muted<D>(val: D) -> StyledObject<D> {
+    if palette().dim_muted {
+        style(val).dim()
+    } else {
+        style(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn theme_names_parse_and_round_trip_through_toml() {
+        for name in UiTheme::NAMES {
+            let theme = UiTheme::parse(name).unwrap();
+            assert_eq!(
+                theme_from_toml(&format!("[ui]\ntheme = \"{name}\"\n")),
+                Some(theme)
+            );
+        }
+        assert_eq!(UiTheme::parse("HIGH_CONTRAST"), Some(UiTheme::HighContrast));
+        assert_eq!(UiTheme::parse("solarized"), None);
+        assert_eq!(theme_from_toml("default_model = \"x\"\n"), None);
+    }
+}
@@ -0,0 +1,172 @@
+//! Input rules for wizard text prompts.
+//!
+//! A [`Rule`] runs when the user presses Enter; a failing rule shows its
+//! message in red under the prompt and keeps the prompt open until the
+//! value is fixed. Text prompts take one through
+//! [`Input::rule`](super::prompt::Input::rule); pasted secrets are checked
+//! the same way by the wizard's paste-aware prompt.
+
+use crate::config::schema::ENV_SECRET_PREFIX;
+
+/// Shortest value accepted as an API key or bot token.
+const MIN_TOKEN_CHARS: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule<'a> {
+    /// An `http://` or `https://` URL with a host.
+    Url,
+    /// Like [`Rule::Url`], but empty input is accepted (the step skips).
+    OptionalUrl,
+    /// A TCP port in `1..=65535`.
+    Port,
+    /// An E.164 phone number: `+`, then 8 to 15 digits.
+    E164,
+    /// Like [`Rule::E164`], but empty input is accepted (the step skips).
+    OptionalE164,
+    /// API key or bot token for a provider or channel (by name); empty
+    /// input (skip) and `env:` references are accepted.
+    Token(&'a str),
+    /// Telegram bot token (`<bot id>:<secret>`); empty input is accepted.
+    TelegramToken,
+}
+
+impl Rule<'_> {
+    /// `Ok` when `input` satisfies the rule, else the message to show.
+    pub fn check(self, input: &str) -> Result<(), String> {
+        let value = input.trim();
+        match self {
+            Self::OptionalUrl | Self::OptionalE164 if value.is_empty() => Ok(()),
+            Self::Url | Self::OptionalUrl => check_url(value),
+            Self::Port => check_port(value),
+            Self::E164 | Self::OptionalE164 => check_e164(value),
+            Self::Token(_) | Self::TelegramToken
+                if value.is_empty() || value.starts_with(ENV_SECRET_PREFIX) =>
+            {
+                Ok(())
+            }
+            Self::Token(service) => check_token(service, value),
+            Self::TelegramToken => check_telegram_token(value),
+        }
+    }
+}
+
+fn check_url(value: &str) -> Result<(), String> {
+    let parsed = reqwest::Url::parse(value).map_err(|error| format!("Not a valid URL: {error}"))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err("URL must start with http:// or https://".into());
+    }
+    if parsed.host_str().is_none_or(str::is_empty) {
+        return Err("URL needs a host name".into());
+    }
+    Ok(())
+}
+
+fn check_port(value: &str) -> Result<(), String> {
+    match value.parse::<u32>() {
+        Ok(port) if (1..=65_535).contains(&port) => Ok(()),
+        Ok(_) => Err("Port must be between 1 and 65535".into()),
+        Err(_) => Err("Port must be a number".into()),
+    }
+}
+
+fn check_e164(value: &str) -> Result<(), String> {
+    let Some(digits) = value.strip_prefix('+') else {
+        return Err("Phone number must start with + and the country code".into());
+    };
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err("Phone number may only contain digits after +".into());
+    }
+    if !(8..=15).contains(&digits.len()) || digits.starts_with('0') {
+        return Err("Phone number must have 8-15 digits and a country code".into());
+    }
+    Ok(())
+}
+
+/// Prefixes for services whose keys have a stable, documented shape.
+fn expected_prefixes(service: &str) -> &'static [&'static str] {
+    match service {
+        "openrouter" => &["sk-or-"],
+        "anthropic" => &["sk-ant-"],
+        "openai" => &["sk-"],
+        "groq" => &["gsk_"],
+        "gemini" | "google" => &["AIza"],
+        "xai" | "grok" => &["xai-"],
+        "perplexity" => &["pplx-"],
+        "huggingface" => &["hf_"],
+        "slack" => &["xoxb-"],
+        _ => &[],
+    }
+}
+
+fn check_token(service: &str, value: &str) -> Result<(), String> {
+    if value.chars().any(char::is_whitespace) {
+        return Err("Keys and tokens do not contain spaces; paste the key only".into());
+    }
+    if value.chars().count() < MIN_TOKEN_CHARS {
+        return Err("That looks too short for a key or token".into());
+    }
+    let prefixes = expected_prefixes(service);
+    if !prefixes.is_empty() && !prefixes.iter().any(|prefix| value.starts_with(prefix)) {
+        return Err(format!(
+            "{service} keys start with {}; check you copied the right key",
+            prefixes.join(" or ")
+        ));
+    }
+    Ok(())
+}
+
+fn check_telegram_token(value: &str) -> Result<(), String> {
+    let valid = value.split_once(':').is_some_and(|(id, secret)| {
+        !id.is_empty()
+            && id.bytes().all(|b| b.is_ascii_digit())
+            && secret.len() >= 30
+            && secret
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+    });
+    if valid {
+        Ok(())
+    } else {
+        Err("Telegram bot tokens look like 123456789:AA… (from @BotFather)".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_accept_good_values_and_explain_bad_ones() {
+        assert!(Rule::Url.check("https://matrix.org").is_ok());
+        assert!(Rule::Url.check("matrix.org").is_err());
+        assert!(Rule::Url.check("ftp://host").is_err());
+        assert!(Rule::OptionalUrl.check("  ").is_ok());
+        assert!(Rule::Url.check("").is_err());
+
+        assert!(Rule::Port.check("8080").is_ok());
+        assert!(Rule::Port.check("0").is_err());
+        assert!(Rule::Port.check("70000").is_err());
+
+        assert!(Rule::E164.check("+12223334444").is_ok());
+        assert!(Rule::E164.check("12223334444").is_err());
+        assert!(Rule::E164.check("+1 222 333").is_err());
+        assert!(Rule::OptionalE164.check("").is_ok());
+
+        assert!(Rule::Token("openrouter")
+            .check("sk-or-v1-0123456789abcdef")
+            .is_ok());
+        let err = Rule::Token("openrouter")
+            .check("sk-proj-0123456789abcdef")
+            .unwrap_err();
+        assert!(err.contains("sk-or-"));
+        assert!(Rule::Token("mystery").check("anything-long-enough").is_ok());
+        assert!(Rule::Token("openai").check("sk-short").is_err());
+        assert!(Rule::Token("openai").check("env:OPENAI_API_KEY").is_ok());
+        assert!(Rule::Token("openai").check("").is_ok());
+
+        assert!(Rule::TelegramToken
+            .check("123456:ABCdefGhIJKlmNoPQRsTUVwxyZ012345678")
+            .is_ok());
+        assert!(Rule::TelegramToken.check("not-a-token").is_err());
+    }
+}
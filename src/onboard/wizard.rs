@@ -1,16 +1,23 @@
+use crate::channels::registry as channel_registry;
 use crate::config::schema::{
     default_nostr_relays, DingTalkConfig, IrcConfig, LarkReceiveMode, LinqConfig,
     NextcloudTalkConfig, NostrConfig, QQConfig, SignalConfig, StreamMode, WhatsAppConfig,
 };
 use crate::config::{
     AutonomyConfig, BrowserConfig, ChannelsConfig, ComposioConfig, Config, DiscordConfig,
-    HeartbeatConfig, IMessageConfig, LarkConfig, MatrixConfig, MemoryConfig, ObservabilityConfig,
-    RuntimeConfig, SecretsConfig, SlackConfig, StorageConfig, TelegramConfig, WebhookConfig,
+    EstopConfig, HeartbeatConfig, IMessageConfig, LarkConfig, MatrixConfig, MemoryConfig,
+    ObservabilityConfig, OtpConfig, QdrantConfig, RuntimeConfig, SecretsConfig, SlackConfig,
+    StorageConfig, TelegramConfig, WebhookConfig,
 };
 use crate::hardware::{self, HardwareConfig};
 use crate::memory::{
-    default_memory_backend_key, memory_backend_profile, selectable_memory_backends,
+    classify_memory_backend, default_memory_backend_key, memory_backend_profile,
+    selectable_memory_backends, MemoryBackendKind,
 };
+use crate::onboard::i18n::{self, Msg};
+use crate::onboard::prompt::{self, Confirm, Input, MultiSelect, Select};
+use crate::onboard::validate::Rule;
+use crate::onboard::{import, preview, theme};
 use crate::providers::{
     canonical_china_provider_name, is_glm_alias, is_glm_cn_alias, is_minimax_alias,
     is_moonshot_alias, is_qianfan_alias, is_qwen_alias, is_qwen_oauth_alias, is_zai_alias,
@@ -18,10 +25,9 @@ use crate::providers::{
 };
 use anyhow::{bail, Context, Result};
 use console::style;
-use dialoguer::{Confirm, Input, Select};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
@@ -60,9 +66,11 @@ const MODEL_PREVIEW_LIMIT: usize = 20;
 const MODEL_CACHE_FILE: &str = "models_cache.json";
 const MODEL_CACHE_TTL_SECS: u64 = 12 * 60 * 60;
 const CUSTOM_MODEL_SENTINEL: &str = "__custom_model__";
+/// Model lists longer than this get a filter prompt before the selection list.
+const MODEL_FILTER_THRESHOLD: usize = 20;
 
 fn has_launchable_channels(channels: &ChannelsConfig) -> bool {
-    channels.channels_except_webhook().iter().any(|(_, ok)| *ok)
+    channels.channels_except_webhook().iter().any(|(_, ok)| *ok) || !channels.plugins.is_empty()
 }
 
 // ── Main wizard entry point ──────────────────────────────────────
@@ -74,56 +82,169 @@ enum InteractiveOnboardingMode {
 }
 
 pub async fn run_wizard(force: bool) -> Result<Config> {
-    println!("{}", style(BANNER).cyan().bold());
+    println!("{}", theme::accent(BANNER).bold());
 
-    println!(
-        "  {}",
-        style("Welcome to ZeroClaw — the fastest, smallest AI assistant.")
-            .white()
-            .bold()
-    );
-    println!(
-        "  {}",
-        style("This wizard will configure your agent in under 60 seconds.").dim()
-    );
+    println!("  {}", theme::strong(i18n::text(Msg::Welcome)).bold());
+    println!("  {}", theme::muted(i18n::text(Msg::Intro)));
     println!();
 
-    print_step(1, 9, "Workspace Setup");
+    let mut progress = WizardProgress::new(FULL_ONBOARDING_STEPS);
+
+    progress.begin(WizardStep::Language);
+    setup_language()?;
+
+    progress.begin(WizardStep::Workspace);
     let (workspace_dir, config_path) = setup_workspace().await?;
     match resolve_interactive_onboarding_mode(&config_path, force)? {
         InteractiveOnboardingMode::FullOnboarding => {}
         InteractiveOnboardingMode::UpdateProviderOnly => {
-            return run_provider_update_wizard(&workspace_dir, &config_path).await;
+            progress.keep_only(&[
+                WizardStep::Language,
+                WizardStep::Workspace,
+                WizardStep::Provider,
+            ]);
+            return run_provider_update_wizard(&workspace_dir, &config_path, &progress).await;
         }
     }
 
-    print_step(2, 9, "AI Provider & API Key");
-    let (provider, api_key, model, provider_api_url) = setup_provider(&workspace_dir).await?;
+    progress.begin(WizardStep::Import);
+    let imported = if offer_skip(WizardStep::Import)? {
+        None
+    } else {
+        setup_import()?
+    };
+    let imported_provider = match &imported {
+        Some(settings) => reuse_imported_provider(settings)?,
+        None => None,
+    };
+    let imported_channels = match &imported {
+        Some(settings) => reuse_imported_channels(settings)?,
+        None => None,
+    };
+
+    progress.begin(WizardStep::Provider);
+    let (provider, api_key, model, provider_api_url, auth_profile) = match imported_provider {
+        Some((provider, api_key, model, provider_api_url)) => {
+            (provider, api_key, model, provider_api_url, None)
+        }
+        None => setup_provider(&workspace_dir, &config_path).await?,
+    };
+
+    progress.begin(WizardStep::Channels);
+    let channels_config = match imported_channels {
+        Some(channels) => channels,
+        None => setup_channels()?,
+    };
+
+    progress.begin(WizardStep::Schedules);
+    let schedules_config = if offer_skip(WizardStep::Schedules)? {
+        crate::config::SchedulesConfig::default()
+    } else {
+        setup_schedules(&channels_config)?
+    };
+
+    progress.begin(WizardStep::Tunnel);
+    let (tunnel_config, tunnel_public_url) = if offer_skip(WizardStep::Tunnel)? {
+        (crate::config::TunnelConfig::default(), None)
+    } else {
+        setup_verified_tunnel().await?
+    };
+
+    progress.begin(WizardStep::ToolMode);
+    let (composio_config, secrets_config) = if offer_skip(WizardStep::ToolMode)? {
+        (ComposioConfig::default(), SecretsConfig::default())
+    } else {
+        setup_tool_mode()?
+    };
 
-    print_step(3, 9, "Channels (How You Talk to ZeroClaw)");
-    let channels_config = setup_channels()?;
+    progress.begin(WizardStep::Security);
+    let security_preset = setup_security()?;
+    let otp_config = setup_otp(&config_path, secrets_config.encrypt)?;
 
-    print_step(4, 9, "Tunnel (Expose to Internet)");
-    let tunnel_config = setup_tunnel()?;
+    progress.begin(WizardStep::Hardware);
+    let hardware_config = if offer_skip(WizardStep::Hardware)? {
+        HardwareConfig::default()
+    } else {
+        setup_hardware()?
+    };
 
-    print_step(5, 9, "Tool Mode & Security");
-    let (composio_config, secrets_config) = setup_tool_mode()?;
+    progress.begin(WizardStep::Estop);
+    let estop_config = if offer_skip(WizardStep::Estop)? {
+        EstopConfig::default()
+    } else {
+        setup_estop(&hardware_config)?
+    };
 
-    print_step(6, 9, "Hardware (Physical World)");
-    let hardware_config = setup_hardware()?;
+    progress.begin(WizardStep::Memory);
+    let memory_config = setup_memory().await?;
 
-    print_step(7, 9, "Memory Configuration");
-    let memory_config = setup_memory()?;
+    progress.begin(WizardStep::Embeddings);
+    let embedding = if memory_backend_uses_embeddings(&memory_config.backend)
+        && offer_skip(WizardStep::Embeddings)?
+    {
+        EmbeddingChoice::default()
+    } else {
+        setup_embeddings(&memory_config.backend, &provider, &api_key)?
+    };
 
-    print_step(8, 9, "Project Context (Personalize Your Agent)");
-    let project_ctx = setup_project_context()?;
+    progress.begin(WizardStep::ProjectContext);
+    let project_ctx = if offer_skip(WizardStep::ProjectContext)? {
+        ProjectContext::default()
+    } else {
+        setup_project_context()?
+    };
 
-    print_step(9, 9, "Workspace Files");
+    let mut answers = WizardAnswers {
+        provider,
+        api_key,
+        model,
+        provider_api_url,
+        auth_profile,
+        channels_config,
+        schedules_config,
+        tunnel_config,
+        tunnel_public_url,
+        composio_config,
+        secrets_config,
+        security_preset,
+        otp_config,
+        hardware_config,
+        estop_config,
+        memory_config,
+        embedding,
+        project_ctx,
+    };
+    review_wizard_answers(&mut answers, &workspace_dir, &config_path, &progress).await?;
+    let WizardAnswers {
+        provider,
+        api_key,
+        model,
+        provider_api_url,
+        auth_profile,
+        channels_config,
+        schedules_config,
+        tunnel_config,
+        composio_config,
+        secrets_config,
+        security_preset,
+        otp_config,
+        hardware_config,
+        mut estop_config,
+        mut memory_config,
+        embedding,
+        project_ctx,
+        ..
+    } = answers;
+    let embedding_routes = embedding.apply(&mut memory_config);
+    // Resuming from estop can only demand a code when OTP is enrolled.
+    estop_config.require_otp_to_resume = otp_config.enabled;
+
+    progress.begin(WizardStep::WorkspaceFiles);
     scaffold_workspace(&workspace_dir, &project_ctx).await?;
 
     // ── Build config ──
     // Defaults: SQLite memory, supervised autonomy, workspace-scoped, native runtime
-    let config = Config {
+    let mut config = Config {
         workspace_dir: workspace_dir.clone(),
         config_path: config_path.clone(),
         api_key: if api_key.is_empty() {
@@ -135,19 +256,26 @@ pub async fn run_wizard(force: bool) -> Result<Config> {
         default_provider: Some(provider),
         default_model: Some(model),
         model_providers: std::collections::HashMap::new(),
+        provider_requests: std::collections::HashMap::new(),
         default_temperature: 0.7,
         observability: ObservabilityConfig::default(),
-        autonomy: AutonomyConfig::default(),
-        security: crate::config::SecurityConfig::default(),
+        autonomy: security_preset.autonomy(),
+        security: crate::config::SecurityConfig {
+            otp: otp_config,
+            estop: estop_config,
+            ..crate::config::SecurityConfig::default()
+        },
         runtime: RuntimeConfig::default(),
         reliability: crate::config::ReliabilityConfig::default(),
         scheduler: crate::config::schema::SchedulerConfig::default(),
         agent: crate::config::schema::AgentConfig::default(),
         skills: crate::config::SkillsConfig::default(),
         model_routes: Vec::new(),
-        embedding_routes: Vec::new(),
+        embedding_routes,
         heartbeat: HeartbeatConfig::default(),
         cron: crate::config::CronConfig::default(),
+        schedules: schedules_config,
+        workspaces: crate::config::WorkspacesConfig::default(),
         channels_config,
         memory: memory_config, // User-selected memory backend
         storage: StorageConfig::default(),
@@ -169,22 +297,55 @@ pub async fn run_wizard(force: bool) -> Result<Config> {
         hardware: hardware_config,
         query_classification: crate::config::QueryClassificationConfig::default(),
         transcription: crate::config::TranscriptionConfig::default(),
+        workspace_sync: crate::config::WorkspaceSyncConfig::default(),
+        shutdown: crate::config::ShutdownConfig::default(),
+        backup: crate::config::BackupConfig::default(),
+        power: crate::config::PowerConfig::default(),
+        connectivity: crate::config::ConnectivityConfig::default(),
+        ui: crate::config::UiConfig {
+            theme: theme::current(),
+            locale: i18n::current(),
+            ..crate::config::UiConfig::default()
+        },
+        redaction: crate::config::RedactionConfig::default(),
+        behavior: crate::config::BehaviorConfig::default(),
+        env_secret_refs: BTreeMap::new(),
+        record_llm_dir: None,
     };
 
     println!(
         "  {} Security: {} | workspace-scoped",
-        style("✓").green().bold(),
-        style("Supervised").green()
+        theme::success("✓").bold(),
+        theme::success(security_preset.name())
     );
     println!(
         "  {} Memory: {} (auto-save: {})",
-        style("✓").green().bold(),
-        style(&config.memory.backend).green(),
+        theme::success("✓").bold(),
+        theme::success(&config.memory.backend),
         if config.memory.auto_save { "on" } else { "off" }
     );
 
+    config.resolve_env_secret_refs()?;
+
+    progress.begin(WizardStep::Preview);
+    if let Some(alternate_path) = preview_config(&config)? {
+        let mut alternate = config.clone();
+        alternate.config_path = alternate_path;
+        alternate.save().await?;
+        println!(
+            "  {} Config written to {}; {} was left unchanged.",
+            theme::success("✓").bold(),
+            theme::success(alternate.config_path.display()),
+            theme::accent(config.config_path.display())
+        );
+        print_bullet("Move it into place (or point --config-dir at its folder) when ready.");
+        return Ok(config);
+    }
+    confirm_config_overwrite(&config, force).await?;
     config.save().await?;
     persist_workspace_selection(&config.config_path).await?;
+    activate_auth_profile(&config, auth_profile.as_deref()).await?;
+    offer_setup_notes(&config).await?;
 
     // ── Final summary ────────────────────────────────────────────
     print_summary(&config);
@@ -196,7 +357,7 @@ pub async fn run_wizard(force: bool) -> Result<Config> {
         let launch: bool = Confirm::new()
             .with_prompt(format!(
                 "  {} Launch channels now? (connected channels → AI → reply)",
-                style("🚀").cyan()
+                theme::accent("🚀")
             ))
             .default(true)
             .interact()?;
@@ -205,8 +366,8 @@ pub async fn run_wizard(force: bool) -> Result<Config> {
             println!();
             println!(
                 "  {} {}",
-                style("⚡").cyan(),
-                style("Starting channel server...").white().bold()
+                theme::accent("⚡"),
+                theme::strong("Starting channel server...").bold()
             );
             println!();
             // Signal to main.rs to call start_channels after wizard returns
@@ -219,27 +380,26 @@ pub async fn run_wizard(force: bool) -> Result<Config> {
 
 /// Interactive repair flow: rerun channel setup only without redoing full onboarding.
 pub async fn run_channels_repair_wizard() -> Result<Config> {
-    println!("{}", style(BANNER).cyan().bold());
+    println!("{}", theme::accent(BANNER).bold());
     println!(
         "  {}",
-        style("Channels Repair — update channel tokens and allowlists only")
-            .white()
-            .bold()
+        theme::strong("Channels Repair — update channel tokens and allowlists only").bold()
     );
     println!();
 
     let mut config = Config::load_or_init().await?;
 
-    print_step(1, 1, "Channels (How You Talk to ZeroClaw)");
+    WizardProgress::new(&[WizardStep::Channels]).begin(WizardStep::Channels);
     config.channels_config = setup_channels()?;
+    config.resolve_env_secret_refs()?;
     config.save().await?;
     persist_workspace_selection(&config.config_path).await?;
 
     println!();
     println!(
         "  {} Channel config saved: {}",
-        style("✓").green().bold(),
-        style(config.config_path.display()).green()
+        theme::success("✓").bold(),
+        theme::success(config.config_path.display())
     );
 
     let has_channels = has_launchable_channels(&config.channels_config);
@@ -248,7 +408,7 @@ pub async fn run_channels_repair_wizard() -> Result<Config> {
         let launch: bool = Confirm::new()
             .with_prompt(format!(
                 "  {} Launch channels now? (connected channels → AI → reply)",
-                style("🚀").cyan()
+                theme::accent("🚀")
             ))
             .default(true)
             .interact()?;
@@ -257,8 +417,8 @@ pub async fn run_channels_repair_wizard() -> Result<Config> {
             println!();
             println!(
                 "  {} {}",
-                style("⚡").cyan(),
-                style("Starting channel server...").white().bold()
+                theme::accent("⚡"),
+                theme::strong("Starting channel server...").bold()
             );
             println!();
             // Signal to main.rs to call start_channels after wizard returns
@@ -270,11 +430,15 @@ pub async fn run_channels_repair_wizard() -> Result<Config> {
 }
 
 /// Interactive flow: update only provider/model/api key while preserving existing config.
-async fn run_provider_update_wizard(workspace_dir: &Path, config_path: &Path) -> Result<Config> {
+async fn run_provider_update_wizard(
+    workspace_dir: &Path,
+    config_path: &Path,
+    progress: &WizardProgress,
+) -> Result<Config> {
     println!();
     println!(
         "  {} Existing config detected. Running provider-only update mode (preserving channels, memory, tunnel, hooks, and other settings).",
-        style("↻").cyan().bold()
+        theme::accent("↻").bold()
     );
 
     let raw = fs::read_to_string(config_path).await.with_context(|| {
@@ -292,17 +456,20 @@ async fn run_provider_update_wizard(workspace_dir: &Path, config_path: &Path) ->
     config.workspace_dir = workspace_dir.to_path_buf();
     config.config_path = config_path.to_path_buf();
 
-    print_step(1, 1, "AI Provider & API Key");
-    let (provider, api_key, model, provider_api_url) = setup_provider(workspace_dir).await?;
+    progress.begin(WizardStep::Provider);
+    let (provider, api_key, model, provider_api_url, auth_profile) =
+        setup_provider(workspace_dir, config_path).await?;
     apply_provider_update(&mut config, provider, api_key, model, provider_api_url);
+    config.resolve_env_secret_refs()?;
 
     config.save().await?;
     persist_workspace_selection(&config.config_path).await?;
+    activate_auth_profile(&config, auth_profile.as_deref()).await?;
 
     println!(
         "  {} Provider settings updated at {}",
-        style("✓").green().bold(),
-        style(config.config_path.display()).green()
+        theme::success("✓").bold(),
+        theme::success(config.config_path.display())
     );
     print_summary(&config);
 
@@ -311,7 +478,7 @@ async fn run_provider_update_wizard(workspace_dir: &Path, config_path: &Path) ->
         let launch: bool = Confirm::new()
             .with_prompt(format!(
                 "  {} Launch channels now? (connected channels → AI → reply)",
-                style("🚀").cyan()
+                theme::accent("🚀")
             ))
             .default(true)
             .interact()?;
@@ -320,8 +487,8 @@ async fn run_provider_update_wizard(workspace_dir: &Path, config_path: &Path) ->
             println!();
             println!(
                 "  {} {}",
-                style("⚡").cyan(),
-                style("Starting channel server...").white().bold()
+                theme::accent("⚡"),
+                theme::strong("Starting channel server...").bold()
             );
             println!();
             std::env::set_var("ZEROCLAW_AUTOSTART_CHANNELS", "1");
@@ -359,7 +526,7 @@ fn backend_key_from_choice(choice: usize) -> &'static str {
         .map_or(default_memory_backend_key(), |backend| backend.key)
 }
 
-fn memory_config_defaults_for_backend(backend: &str) -> MemoryConfig {
+pub(super) fn memory_config_defaults_for_backend(backend: &str) -> MemoryConfig {
     let profile = memory_backend_profile(backend);
 
     MemoryConfig {
@@ -369,12 +536,15 @@ fn memory_config_defaults_for_backend(backend: &str) -> MemoryConfig {
         archive_after_days: if profile.uses_sqlite_hygiene { 7 } else { 0 },
         purge_after_days: if profile.uses_sqlite_hygiene { 30 } else { 0 },
         conversation_retention_days: 30,
+        categories: std::collections::HashMap::new(),
+        classification: crate::config::MemoryClassificationConfig::default(),
         embedding_provider: "none".to_string(),
         embedding_model: "text-embedding-3-small".to_string(),
         embedding_dimensions: 1536,
         vector_weight: 0.7,
         keyword_weight: 0.3,
         min_relevance_score: 0.4,
+        context_token_budget: 1_000,
         embedding_cache_size: if profile.uses_sqlite_hygiene {
             10000
         } else {
@@ -388,6 +558,7 @@ fn memory_config_defaults_for_backend(backend: &str) -> MemoryConfig {
         snapshot_on_hygiene: false,
         auto_hydrate: true,
         sqlite_open_timeout_secs: None,
+        sqlite: crate::config::SqliteConfig::default(),
         qdrant: crate::config::QdrantConfig::default(),
     }
 }
@@ -415,7 +586,7 @@ pub async fn run_quick_setup(
     .await
 }
 
-fn resolve_quick_setup_dirs_with_home(home: &Path) -> (PathBuf, PathBuf) {
+pub(super) fn resolve_quick_setup_dirs_with_home(home: &Path) -> (PathBuf, PathBuf) {
     if let Ok(custom_config_dir) = std::env::var("ZEROCLAW_CONFIG_DIR") {
         let trimmed = custom_config_dir.trim();
         if !trimmed.is_empty() {
@@ -446,12 +617,10 @@ async fn run_quick_setup_with_home(
     force: bool,
     home: &Path,
 ) -> Result<Config> {
-    println!("{}", style(BANNER).cyan().bold());
+    println!("{}", theme::accent(BANNER).bold());
     println!(
         "  {}",
-        style("Quick Setup — generating config with sensible defaults...")
-            .white()
-            .bold()
+        theme::strong("Quick Setup — generating config with sensible defaults...").bold()
     );
     println!();
 
@@ -486,6 +655,7 @@ async fn run_quick_setup_with_home(
         default_provider: Some(provider_name.clone()),
         default_model: Some(model.clone()),
         model_providers: std::collections::HashMap::new(),
+        provider_requests: std::collections::HashMap::new(),
         default_temperature: 0.7,
         observability: ObservabilityConfig::default(),
         autonomy: AutonomyConfig::default(),
@@ -499,6 +669,8 @@ async fn run_quick_setup_with_home(
         embedding_routes: Vec::new(),
         heartbeat: HeartbeatConfig::default(),
         cron: crate::config::CronConfig::default(),
+        schedules: crate::config::SchedulesConfig::default(),
+        workspaces: crate::config::WorkspacesConfig::default(),
         channels_config: ChannelsConfig::default(),
         memory: memory_config,
         storage: StorageConfig::default(),
@@ -520,6 +692,20 @@ async fn run_quick_setup_with_home(
         hardware: crate::config::HardwareConfig::default(),
         query_classification: crate::config::QueryClassificationConfig::default(),
         transcription: crate::config::TranscriptionConfig::default(),
+        workspace_sync: crate::config::WorkspaceSyncConfig::default(),
+        shutdown: crate::config::ShutdownConfig::default(),
+        backup: crate::config::BackupConfig::default(),
+        power: crate::config::PowerConfig::default(),
+        connectivity: crate::config::ConnectivityConfig::default(),
+        ui: crate::config::UiConfig {
+            theme: theme::current(),
+            locale: i18n::current(),
+            ..crate::config::UiConfig::default()
+        },
+        redaction: crate::config::RedactionConfig::default(),
+        behavior: crate::config::BehaviorConfig::default(),
+        env_secret_refs: BTreeMap::new(),
+        record_llm_dir: None,
     };
 
     config.save().await?;
@@ -538,37 +724,37 @@ async fn run_quick_setup_with_home(
 
     println!(
         "  {} Workspace:  {}",
-        style("✓").green().bold(),
-        style(workspace_dir.display()).green()
+        theme::success("✓").bold(),
+        theme::success(workspace_dir.display())
     );
     println!(
         "  {} Provider:   {}",
-        style("✓").green().bold(),
-        style(&provider_name).green()
+        theme::success("✓").bold(),
+        theme::success(&provider_name)
     );
     println!(
         "  {} Model:      {}",
-        style("✓").green().bold(),
-        style(&model).green()
+        theme::success("✓").bold(),
+        theme::success(&model)
     );
     println!(
         "  {} API Key:    {}",
-        style("✓").green().bold(),
+        theme::success("✓").bold(),
         if credential_override.is_some() {
-            style("set").green()
+            theme::success("set")
         } else {
-            style("not set (use --api-key or edit config.toml)").yellow()
+            theme::warning("not set (use --api-key or edit config.toml)")
         }
     );
     println!(
         "  {} Security:   {}",
-        style("✓").green().bold(),
-        style("Supervised (workspace-scoped)").green()
+        theme::success("✓").bold(),
+        theme::success("Supervised (workspace-scoped)")
     );
     println!(
         "  {} Memory:     {} (auto-save: {})",
-        style("✓").green().bold(),
-        style(&memory_backend_name).green(),
+        theme::success("✓").bold(),
+        theme::success(&memory_backend_name),
         if memory_backend_name == "none" {
             "off"
         } else {
@@ -577,32 +763,32 @@ async fn run_quick_setup_with_home(
     );
     println!(
         "  {} Secrets:    {}",
-        style("✓").green().bold(),
-        style("encrypted").green()
+        theme::success("✓").bold(),
+        theme::success("encrypted")
     );
     println!(
         "  {} Gateway:    {}",
-        style("✓").green().bold(),
-        style("pairing required (127.0.0.1:8080)").green()
+        theme::success("✓").bold(),
+        theme::success("pairing required (127.0.0.1:8080)")
     );
     println!(
         "  {} Tunnel:     {}",
-        style("✓").green().bold(),
-        style("none (local only)").dim()
+        theme::success("✓").bold(),
+        theme::muted("none (local only)")
     );
     println!(
         "  {} Composio:   {}",
-        style("✓").green().bold(),
-        style("disabled (sovereign mode)").dim()
+        theme::success("✓").bold(),
+        theme::muted("disabled (sovereign mode)")
     );
     println!();
     println!(
         "  {} {}",
-        style("Config saved:").white().bold(),
-        style(config_path.display()).green()
+        theme::strong("Config saved:").bold(),
+        theme::success(config_path.display())
     );
     println!();
-    println!("  {}", style("Next steps:").white().bold());
+    println!("  {}", theme::strong("Next steps:").bold());
     if credential_override.is_none() {
         if provider_supports_keyless_local_usage(&provider_name) {
             println!("    1. Chat:     zeroclaw agent -m \"Hello!\"");
@@ -687,7 +873,7 @@ const MINIMAX_ONBOARD_MODELS: [(&str, &str); 5] = [
     ("MiniMax-M2", "MiniMax M2 (legacy)"),
 ];
 
-fn default_model_for_provider(provider: &str) -> String {
+pub(crate) fn default_model_for_provider(provider: &str) -> String {
     match canonical_provider_name(provider) {
         "anthropic" => "claude-sonnet-4-5-20250929".into(),
         "openai" => "gpt-5.2".into(),
@@ -1129,7 +1315,7 @@ fn curated_models_for_provider(provider_name: &str) -> Vec<(String, String)> {
     }
 }
 
-fn supports_live_model_fetch(provider_name: &str) -> bool {
+pub(crate) fn supports_live_model_fetch(provider_name: &str) -> bool {
     if provider_name.trim().starts_with("custom:") {
         return true;
     }
@@ -1197,6 +1383,29 @@ fn models_endpoint_for_provider(provider_name: &str) -> Option<&'static str> {
     }
 }
 
+/// Built-in API base URL for a provider, when it has a fixed one.
+pub(crate) fn provider_base_url(provider_name: &str) -> Option<String> {
+    match canonical_provider_name(provider_name) {
+        "openrouter" => Some("https://openrouter.ai/api/v1".to_string()),
+        "anthropic" => Some("https://api.anthropic.com".to_string()),
+        "gemini" => Some("https://generativelanguage.googleapis.com/v1beta".to_string()),
+        "ollama" => Some("http://localhost:11434".to_string()),
+        _ => models_endpoint_for_provider(provider_name)
+            .map(|endpoint| endpoint.trim_end_matches("/models").to_string()),
+    }
+}
+
+/// Number of curated models onboarding offers for a provider (0 when it
+/// only has the generic `default` placeholder).
+pub(crate) fn curated_model_count(provider_name: &str) -> usize {
+    let models = curated_models_for_provider(provider_name);
+    if matches!(models.as_slice(), [(id, _)] if id == "default") {
+        0
+    } else {
+        models.len()
+    }
+}
+
 fn build_model_fetch_client() -> Result<reqwest::blocking::Client> {
     reqwest::blocking::Client::builder()
         .timeout(Duration::from_secs(8))
@@ -1467,7 +1676,7 @@ fn resolve_live_models_endpoint(
     models_endpoint_for_provider(provider_name).map(str::to_string)
 }
 
-fn fetch_live_models_for_provider(
+pub(crate) fn fetch_live_models_for_provider(
     provider_name: &str,
     api_key: &str,
     provider_api_url: Option<&str>,
@@ -1546,6 +1755,75 @@ fn fetch_live_models_for_provider(
     Ok(models)
 }
 
+/// Verdict of the live auth probe run right after ApiKeyEntry.
+#[derive(Debug)]
+enum ApiKeyCheck {
+    /// Provider accepted the key; carries the model list when the probe fetched one.
+    Accepted(Option<Vec<String>>),
+    /// Provider answered but refused the key (401/403, quota, plan access).
+    Rejected(String),
+    /// No verdict: network failure, timeout or an unexpected response.
+    Inconclusive(String),
+}
+
+/// Live auth probe for a freshly entered key. Most providers are checked via
+/// their `/models` listing; OpenRouter serves that list without auth, so its
+/// key-info endpoint is used instead.
+fn probe_provider_api_key(
+    provider_name: &str,
+    api_key: &str,
+    provider_api_url: Option<&str>,
+) -> Result<Option<Vec<String>>> {
+    if canonical_provider_name(provider_name) == "openrouter" {
+        let client = build_model_fetch_client()?;
+        client
+            .get("https://openrouter.ai/api/v1/key")
+            .bearer_auth(api_key.trim())
+            .send()
+            .and_then(reqwest::blocking::Response::error_for_status)
+            .context("key check failed: GET https://openrouter.ai/api/v1/key")?;
+        return Ok(None);
+    }
+
+    fetch_live_models_for_provider(provider_name, api_key, provider_api_url).map(Some)
+}
+
+fn classify_api_key_probe(result: Result<Option<Vec<String>>>) -> ApiKeyCheck {
+    match result {
+        Ok(models) => ApiKeyCheck::Accepted(models),
+        Err(error) => {
+            let detail = format!("{error:#}");
+            match crate::doctor::classify_model_probe_error(&detail) {
+                crate::doctor::ModelProbeOutcome::AuthOrAccess => ApiKeyCheck::Rejected(detail),
+                _ => ApiKeyCheck::Inconclusive(detail),
+            }
+        }
+    }
+}
+
+/// Run the auth probe behind a spinner. `Ok(None)` means the user pressed Esc.
+async fn validate_api_key_with_spinner(
+    provider_name: &str,
+    api_key: &str,
+    provider_api_url: Option<&str>,
+) -> Result<Option<ApiKeyCheck>> {
+    let provider = provider_name.to_string();
+    let key = api_key.to_string();
+    let url = provider_api_url.map(str::to_string);
+    let outcome = run_blocking_with_spinner(
+        format!("Checking API key with {provider_name}..."),
+        move || {
+            Ok(classify_api_key_probe(probe_provider_api_key(
+                &provider,
+                &key,
+                url.as_deref(),
+            )))
+        },
+    )
+    .await?;
+    Ok(outcome)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct ModelCacheEntry {
     provider: String,
@@ -1696,6 +1974,302 @@ fn humanize_age(age_secs: u64) -> String {
     }
 }
 
+pub(super) const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+pub(super) const SPINNER_TICK: Duration = Duration::from_millis(100);
+
+/// Fetch live models on a blocking worker while the prompt thread draws a
+/// spinner. Returns `Ok(None)` when the user presses Esc; the abandoned fetch
+/// finishes (or times out) in the background and its result is dropped.
+async fn fetch_live_models_with_spinner(
+    provider_name: &str,
+    api_key: &str,
+    provider_api_url: Option<&str>,
+) -> Result<Option<Vec<String>>> {
+    let provider = provider_name.to_string();
+    let key = api_key.to_string();
+    let url = provider_api_url.map(str::to_string);
+    run_blocking_with_spinner(
+        format!("Fetching models from {provider_name}..."),
+        move || fetch_live_models_for_provider(&provider, &key, url.as_deref()),
+    )
+    .await
+}
+
+/// Run `job` on a blocking worker while the prompt thread draws a spinner
+/// next to `message`. Returns `Ok(None)` when the user presses Esc.
+async fn run_blocking_with_spinner<T, F>(message: String, job: F) -> Result<Option<T>>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T> + Send + 'static,
+{
+    let (tx, mut rx) = tokio::sync::oneshot::channel();
+    tokio::task::spawn_blocking(move || {
+        let _ = tx.send(job());
+    });
+
+    let term = console::Term::stderr();
+    let draw = term.is_term() && !prompt::is_plain();
+    let mut esc = if prompt::is_plain() {
+        None
+    } else {
+        EscKeyWatcher::new()
+    };
+    let mut ticker = tokio::time::interval(SPINNER_TICK);
+    let mut frame = 0usize;
+
+    let outcome = loop {
+        tokio::select! {
+            result = &mut rx => {
+                break result
+                    .unwrap_or_else(|_| Err(anyhow::anyhow!("background worker stopped unexpectedly")))
+                    .map(Some);
+            }
+            _ = ticker.tick() => {
+                if esc.as_mut().is_some_and(EscKeyWatcher::esc_pressed) {
+                    break Ok(None);
+                }
+                if draw {
+                    let _ = term.clear_line();
+                    let _ = term.write_str(&format!(
+                        "  {} {message} {}",
+                        theme::accent(SPINNER_FRAMES[frame % SPINNER_FRAMES.len()]),
+                        theme::muted(if esc.is_some() { "(Esc to cancel)" } else { "" })
+                    ));
+                    frame += 1;
+                }
+            }
+        }
+    };
+
+    drop(esc);
+    if draw {
+        let _ = term.clear_line();
+    }
+    outcome
+}
+
+/// True when `input` contains a bare Esc press rather than the start of an
+/// escape sequence (arrow keys and friends send `ESC [` / `ESC O`).
+fn contains_bare_escape(input: &[u8]) -> bool {
+    input
+        .iter()
+        .enumerate()
+        .any(|(i, &b)| b == 0x1b && !matches!(input.get(i + 1), Some(b'[' | b'O' | 0x1b)))
+}
+
+/// Puts the terminal in non-canonical, no-echo mode so single key presses can
+/// be polled without blocking; the original mode is restored on drop.
+#[cfg(unix)]
+pub(super) struct EscKeyWatcher {
+    original: libc::termios,
+}
+
+#[cfg(unix)]
+impl EscKeyWatcher {
+    pub(super) fn new() -> Option<Self> {
+        let original = enter_noncanonical_mode(0, false)?;
+        Some(Self { original })
+    }
+
+    /// Drain pending input and report whether Esc was pressed.
+    pub(super) fn esc_pressed(&mut self) -> bool {
+        let mut buf = [0u8; 64];
+        // SAFETY: VMIN=0/VTIME=0 makes this read non-blocking; buf outlives the call.
+        let n = unsafe { libc::read(libc::STDIN_FILENO, buf.as_mut_ptr().cast(), buf.len()) };
+        usize::try_from(n).is_ok_and(|n| n > 0 && contains_bare_escape(&buf[..n]))
+    }
+}
+
+#[cfg(unix)]
+impl Drop for EscKeyWatcher {
+    fn drop(&mut self) {
+        restore_terminal_mode(&self.original);
+    }
+}
+
+/// Switch stdin to non-canonical, no-echo mode and return the previous
+/// attributes. `vmin = 0` makes reads non-blocking; `raw_signals` also stops
+/// Ctrl-C from raising SIGINT so the caller sees it as a byte.
+#[cfg(unix)]
+fn enter_noncanonical_mode(vmin: u8, raw_signals: bool) -> Option<libc::termios> {
+    if !std::io::stdin().is_terminal() {
+        return None;
+    }
+    // SAFETY: termios is plain data; tcgetattr/tcsetattr only touch the
+    // struct we pass and the stdin file descriptor.
+    unsafe {
+        let mut original: libc::termios = std::mem::zeroed();
+        if libc::tcgetattr(libc::STDIN_FILENO, &raw mut original) != 0 {
+            return None;
+        }
+        let mut changed = original;
+        changed.c_lflag &= !(libc::ICANON | libc::ECHO);
+        if raw_signals {
+            changed.c_lflag &= !libc::ISIG;
+        }
+        changed.c_cc[libc::VMIN] = vmin;
+        changed.c_cc[libc::VTIME] = 0;
+        if libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &raw const changed) != 0 {
+            return None;
+        }
+        Some(original)
+    }
+}
+
+#[cfg(unix)]
+fn restore_terminal_mode(original: &libc::termios) {
+    // SAFETY: restores attributes previously captured by tcgetattr.
+    unsafe {
+        libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, original);
+    }
+}
+
+/// Non-unix terminals: no key polling, so the spinner runs until the fetch
+/// completes or times out.
+#[cfg(not(unix))]
+pub(super) struct EscKeyWatcher;
+
+#[cfg(not(unix))]
+impl EscKeyWatcher {
+    pub(super) fn new() -> Option<Self> {
+        None
+    }
+
+    pub(super) fn esc_pressed(&mut self) -> bool {
+        false
+    }
+}
+
+const PASTE_START: &[u8] = b"\x1b[200~";
+const PASTE_END: &[u8] = b"\x1b[201~";
+
+#[derive(Debug, PartialEq, Eq)]
+enum LineEnd {
+    Submit,
+    Cancel,
+}
+
+/// Line state for a secret prompt with bracketed paste enabled. A paste
+/// arrives as one unit between `ESC[200~` and `ESC[201~`, so a stray newline
+/// in the clipboard cannot submit half a key.
+#[derive(Debug, Default)]
+struct PasteAwareLine {
+    text: String,
+    escape: Vec<u8>,
+    paste: Option<Vec<u8>>,
+    utf8: Vec<u8>,
+}
+
+impl PasteAwareLine {
+    fn push(&mut self, byte: u8) -> Option<LineEnd> {
+        if let Some(paste) = self.paste.as_mut() {
+            paste.push(byte);
+            if paste.ends_with(PASTE_END) {
+                paste.truncate(paste.len() - PASTE_END.len());
+                // Keys and tokens never contain whitespace; wrapped copies do.
+                let pasted: String = String::from_utf8_lossy(paste)
+                    .chars()
+                    .filter(|c| !c.is_whitespace())
+                    .collect();
+                self.text.push_str(&pasted);
+                self.paste = None;
+            }
+            return None;
+        }
+
+        if byte == 0x1b || !self.escape.is_empty() {
+            self.escape.push(byte);
+            if self.escape == PASTE_START {
+                self.escape.clear();
+                self.paste = Some(Vec::new());
+            } else if !PASTE_START.starts_with(&self.escape) {
+                // Arrow keys and other sequences are not editable here.
+                self.escape.clear();
+            }
+            return None;
+        }
+
+        match byte {
+            b'\r' | b'\n' => return Some(LineEnd::Submit),
+            0x03 | 0x04 => return Some(LineEnd::Cancel),
+            0x7f | 0x08 => {
+                self.text.pop();
+            }
+            byte if byte < 0x20 => {}
+            byte => {
+                self.utf8.push(byte);
+                if let Ok(chunk) = std::str::from_utf8(&self.utf8) {
+                    self.text.push_str(chunk);
+                    self.utf8.clear();
+                } else if self.utf8.len() >= 4 {
+                    self.utf8.clear();
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Prompt for an API key or token, accepting bracketed paste so long pasted
+/// values land in one piece. Empty input is allowed. Falls back to a plain
+/// prompt when stdin is not a terminal or prompts are in plain mode.
+fn prompt_pasteable(prompt: &str) -> Result<String> {
+    #[cfg(unix)]
+    if let Some(original) = (!prompt::is_plain())
+        .then(|| enter_noncanonical_mode(1, true))
+        .flatten()
+    {
+        let term = console::Term::stderr();
+        let _ = term.write_str("\x1b[?2004h");
+        let result = read_pasteable_line(&term, prompt);
+        let _ = term.write_str("\x1b[?2004l");
+        restore_terminal_mode(&original);
+        let _ = term.write_line("");
+        return result;
+    }
+
+    Input::new()
+        .with_prompt(prompt)
+        .allow_empty(true)
+        .interact_text()
+}
+
+/// [`prompt_pasteable`] that re-prompts until the value passes `rule`.
+fn prompt_pasteable_checked(prompt: &str, rule: Rule<'_>) -> Result<String> {
+    loop {
+        let value = prompt_pasteable(prompt)?;
+        match rule.check(&value) {
+            Ok(()) => return Ok(value),
+            Err(message) => println!("  {} {}", theme::error("✗").bold(), theme::error(message)),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn read_pasteable_line(term: &console::Term, prompt: &str) -> Result<String> {
+    use std::io::Read;
+
+    let mut line = PasteAwareLine::default();
+    let mut stdin = std::io::stdin().lock();
+    let mut byte = [0u8; 1];
+    term.write_str(&format!("{prompt}: "))?;
+    loop {
+        if stdin.read(&mut byte)? == 0 {
+            return Ok(line.text.trim().to_string());
+        }
+        let before = line.text.len();
+        match line.push(byte[0]) {
+            Some(LineEnd::Submit) => return Ok(line.text.trim().to_string()),
+            Some(LineEnd::Cancel) => bail!("Input cancelled"),
+            None if line.text.len() != before => {
+                term.clear_line()?;
+                term.write_str(&format!("{prompt}: {}", line.text))?;
+            }
+            None => {}
+        }
+    }
+}
+
 fn build_model_options(model_ids: Vec<String>, source: &str) -> Vec<(String, String)> {
     model_ids
         .into_iter()
@@ -1706,6 +2280,23 @@ fn build_model_options(model_ids: Vec<String>, source: &str) -> Vec<(String, Str
         .collect()
 }
 
+/// Keep options whose model ID or label contains every whitespace-separated
+/// term of `query` (case-insensitive). An empty query keeps everything.
+fn filter_model_options(options: &[(String, String)], query: &str) -> Vec<(String, String)> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .map(str::to_ascii_lowercase)
+        .collect();
+    options
+        .iter()
+        .filter(|(model_id, label)| {
+            let haystack = format!("{model_id} {label}").to_ascii_lowercase();
+            terms.iter().all(|term| haystack.contains(term.as_str()))
+        })
+        .cloned()
+        .collect()
+}
+
 fn print_model_preview(models: &[String]) {
     for model in models.iter().take(MODEL_PREVIEW_LIMIT) {
         println!("  {} {model}", style("-"));
@@ -1764,7 +2355,18 @@ pub async fn run_models_refresh(
 
     let api_key = config.api_key.clone().unwrap_or_default();
 
-    match fetch_live_models_for_provider(&provider_name, &api_key, config.api_url.as_deref()) {
+    let fetched =
+        fetch_live_models_with_spinner(&provider_name, &api_key, config.api_url.as_deref()).await;
+    let fetched = match fetched {
+        Ok(None) => {
+            println!("Refresh cancelled; the '{provider_name}' model cache is unchanged.");
+            return Ok(());
+        }
+        Ok(Some(models)) => Ok(models),
+        Err(error) => Err(error),
+    };
+
+    match fetched {
         Ok(models) if !models.is_empty() => {
             cache_live_models_for_provider(&config.workspace_dir, &provider_name, &models).await?;
             println!(
@@ -1855,7 +2457,7 @@ pub async fn run_models_set(config: &Config, model: &str) -> Result<()> {
     updated.save().await?;
 
     println!();
-    println!("  Default model set to '{}'.", style(model).green().bold());
+    println!("  Default model set to '{}'.", theme::success(model).bold());
     println!();
     Ok(())
 }
@@ -1865,11 +2467,11 @@ pub async fn run_models_status(config: &Config) -> Result<()> {
     let model = config.default_model.as_deref().unwrap_or("(not set)");
 
     println!();
-    println!("  Provider:  {}", style(provider).cyan());
-    println!("  Model:     {}", style(model).cyan());
+    println!("  Provider:  {}", theme::accent(provider));
+    println!("  Model:     {}", theme::accent(model));
     println!(
         "  Temp:      {}",
-        style(format!("{:.1}", config.default_temperature)).cyan()
+        theme::accent(format!("{:.1}", config.default_temperature))
     );
 
     match load_any_cached_models_for_provider(&config.workspace_dir, provider).await? {
@@ -1881,13 +2483,13 @@ pub async fn run_models_status(config: &Config) -> Result<()> {
             );
             let fresh = cached.age_secs < MODEL_CACHE_TTL_SECS;
             if fresh {
-                println!("  Freshness: {}", style("fresh").green());
+                println!("  Freshness: {}", theme::success("fresh"));
             } else {
-                println!("  Freshness: {}", style("stale").yellow());
+                println!("  Freshness: {}", theme::warning("stale"));
             }
         }
         None => {
-            println!("  Cache:     {}", style("none").yellow());
+            println!("  Cache:     {}", theme::warning("none"));
         }
     }
 
@@ -1895,6 +2497,99 @@ pub async fn run_models_status(config: &Config) -> Result<()> {
     Ok(())
 }
 
+/// Pick a provider, then one of its models (cached catalog when present,
+/// else the curated list), save the choice and show the new status.
+pub async fn run_models_switch(config: &Config) -> Result<()> {
+    let current = config.default_provider.as_deref().unwrap_or("openrouter");
+    let providers = crate::providers::list_providers();
+    let labels: Vec<String> = providers
+        .iter()
+        .map(|provider| {
+            let marker = if provider.name == current {
+                " [active]"
+            } else {
+                ""
+            };
+            format!(
+                "{}{marker} — {}",
+                provider.display_name,
+                theme::muted(provider.name)
+            )
+        })
+        .collect();
+    let provider_idx = Select::new()
+        .with_prompt("  Provider")
+        .items(&labels)
+        .default(
+            providers
+                .iter()
+                .position(|provider| provider.name == current)
+                .unwrap_or(0),
+        )
+        .interact()?;
+    let provider_name = providers[provider_idx].name;
+
+    let (model_options, cache_age) =
+        model_choices_for_provider(&config.workspace_dir, provider_name).await?;
+    if let Some(age) = cache_age {
+        print_bullet(&format!(
+            "Using cached models for '{provider_name}' (updated {} ago).",
+            humanize_age(age)
+        ));
+    }
+    let model = select_model(provider_name, model_options)?;
+
+    let mut updated = config.clone();
+    if provider_name != current {
+        // The key and any custom endpoint belong to the old provider; the
+        // config key is tried before env vars, so keeping it would send it
+        // to the new vendor.
+        updated.api_url = None;
+        let api_key = prompt_pasteable(&format!(
+            "  API key for {provider_name} (or Enter to use {})",
+            provider_env_var(provider_name)
+        ))?;
+        updated.api_key = Some(api_key.trim().to_string()).filter(|key| !key.is_empty());
+    }
+    updated.default_provider = Some(provider_name.to_string());
+    updated.default_model = Some(model);
+    updated.save().await?;
+
+    if provider_name != current && updated.api_key.is_none() {
+        print_bullet(&format!(
+            "Switched provider; the previous key was removed. {provider_name} reads its key from {} unless it needs none.",
+            provider_env_var(provider_name)
+        ));
+    }
+    run_models_status(&updated).await
+}
+
+/// Models offered when switching to `provider_name`: its cached live catalog
+/// when one exists, else the curated list. The cache age is `None` for the
+/// curated list. Shared by `zeroclaw models switch` and the dashboard.
+pub(crate) async fn model_choices_for_provider(
+    workspace_dir: &Path,
+    provider_name: &str,
+) -> Result<(Vec<(String, String)>, Option<u64>)> {
+    match load_any_cached_models_for_provider(workspace_dir, provider_name).await? {
+        Some(cached) if !cached.models.is_empty() => Ok((
+            build_model_options(
+                cached
+                    .models
+                    .into_iter()
+                    .take(LIVE_MODEL_MAX_OPTIONS)
+                    .collect(),
+                "cached",
+            ),
+            Some(cached.age_secs),
+        )),
+        _ => Ok((
+            curated_models_for_provider(canonical_provider_name(provider_name)),
+            None,
+        )),
+    }
+}
+
 pub async fn cached_model_catalog_stats(
     config: &Config,
     provider_name: &str,
@@ -1955,51 +2650,619 @@ pub async fn run_models_refresh_all(config: &Config, force: bool) -> Result<()>
 
 // ── Step helpers ─────────────────────────────────────────────────
 
-fn print_step(current: u8, total: u8, title: &str) {
-    println!();
-    println!(
-        "  {} {}",
-        style(format!("[{current}/{total}]")).cyan().bold(),
-        style(title).white().bold()
-    );
-    println!("  {}", style("─".repeat(50)).dim());
-}
+const STEP_GAUGE_WIDTH: usize = 50;
 
-fn print_bullet(text: &str) {
-    println!("  {} {}", style("›").cyan(), text);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WizardStep {
+    Language,
+    Workspace,
+    Import,
+    Provider,
+    Channels,
+    Schedules,
+    Tunnel,
+    ToolMode,
+    Security,
+    Hardware,
+    Estop,
+    Memory,
+    Embeddings,
+    ProjectContext,
+    Confirmation,
+    WorkspaceFiles,
+    Preview,
 }
 
-fn resolve_interactive_onboarding_mode(
-    config_path: &Path,
-    force: bool,
-) -> Result<InteractiveOnboardingMode> {
-    if !config_path.exists() {
-        return Ok(InteractiveOnboardingMode::FullOnboarding);
+impl WizardStep {
+    fn title(self) -> &'static str {
+        i18n::text(match self {
+            Self::Language => Msg::StepLanguage,
+            Self::Workspace => Msg::StepWorkspace,
+            Self::Import => Msg::StepImport,
+            Self::Provider => Msg::StepProvider,
+            Self::Channels => Msg::StepChannels,
+            Self::Schedules => Msg::StepSchedules,
+            Self::Tunnel => Msg::StepTunnel,
+            Self::ToolMode => Msg::StepToolMode,
+            Self::Security => Msg::StepSecurity,
+            Self::Hardware => Msg::StepHardware,
+            Self::Estop => Msg::StepEstop,
+            Self::Memory => Msg::StepMemory,
+            Self::Embeddings => Msg::StepEmbeddings,
+            Self::ProjectContext => Msg::StepProjectContext,
+            Self::Confirmation => Msg::StepConfirmation,
+            Self::WorkspaceFiles => Msg::StepWorkspaceFiles,
+            Self::Preview => Msg::StepPreview,
+        })
     }
 
-    if force {
-        println!(
-            "  {} Existing config detected at {}. Proceeding with full onboarding because --force was provided.",
-            style("!").yellow().bold(),
-            style(config_path.display()).yellow()
-        );
-        return Ok(InteractiveOnboardingMode::FullOnboarding);
+    /// The default an optional step falls back to when skipped, as shown to
+    /// the user. `None` for steps that must be answered.
+    fn skip_default(self) -> Option<&'static str> {
+        match self {
+            Self::Import => Some("start fresh"),
+            Self::Schedules => Some("no scheduled jobs"),
+            Self::Tunnel => Some("no tunnel, local only"),
+            Self::ToolMode => Some("sovereign tools, encrypted secrets"),
+            Self::Hardware => Some("no hardware"),
+            Self::Estop => Some("emergency stop off"),
+            Self::Embeddings => Some("keyword search only"),
+            Self::ProjectContext => Some("default name, timezone and style"),
+            _ => None,
+        }
     }
+}
 
-    if !std::io::stdin().is_terminal() || !std::io::stdout().is_terminal() {
-        bail!(
-            "Refusing to overwrite existing config at {} in non-interactive mode. Re-run with --force if overwrite is intentional.",
-            config_path.display()
-        );
+const FULL_ONBOARDING_STEPS: &[WizardStep] = &[
+    WizardStep::Language,
+    WizardStep::Workspace,
+    WizardStep::Import,
+    WizardStep::Provider,
+    WizardStep::Channels,
+    WizardStep::Schedules,
+    WizardStep::Tunnel,
+    WizardStep::ToolMode,
+    WizardStep::Security,
+    WizardStep::Hardware,
+    WizardStep::Estop,
+    WizardStep::Memory,
+    WizardStep::Embeddings,
+    WizardStep::ProjectContext,
+    WizardStep::Confirmation,
+    WizardStep::WorkspaceFiles,
+    WizardStep::Preview,
+];
+
+/// Numbering for the step header. Skipped steps are dropped from the plan,
+/// so "Step N of M" only counts steps the user will actually see.
+struct WizardProgress {
+    planned: Vec<WizardStep>,
+}
+
+impl WizardProgress {
+    fn new(steps: &[WizardStep]) -> Self {
+        Self {
+            planned: steps.to_vec(),
+        }
     }
 
-    let options = [
-        "Full onboarding (overwrite config.toml)",
-        "Update AI provider/model/API key only (preserve existing configuration)",
-        "Cancel",
-    ];
+    fn keep_only(&mut self, steps: &[WizardStep]) {
+        self.planned.retain(|planned| steps.contains(planned));
+    }
 
-    let mode = Select::new()
+    /// 1-based position of `step` and the total number of planned steps.
+    fn position(&self, step: WizardStep) -> Option<(usize, usize)> {
+        let index = self.planned.iter().position(|planned| *planned == step)?;
+        Some((index + 1, self.planned.len()))
+    }
+
+    fn begin(&self, step: WizardStep) {
+        let (current, total) = self.position(step).unwrap_or((1, 1));
+        print_step(current, total, step.title());
+    }
+}
+
+fn print_step(current: usize, total: usize, title: &str) {
+    println!();
+    println!(
+        "  {} {}",
+        theme::accent(i18n::step_header(current, total)).bold(),
+        theme::strong(title).bold()
+    );
+    let width = prompt::terminal_size().map_or(STEP_GAUGE_WIDTH, |(_, cols)| {
+        STEP_GAUGE_WIDTH.min(usize::from(cols).saturating_sub(4).max(1))
+    });
+    let (filled, empty) = step_gauge_split(current, total, width);
+    println!(
+        "  {}{}",
+        theme::accent("━".repeat(filled)),
+        theme::muted("─".repeat(empty))
+    );
+}
+
+/// Ctrl-S, F10 (`ESC [21~`) or a plain `s` skip an optional step.
+fn is_skip_key(key: &console::Key) -> bool {
+    match key {
+        console::Key::Char(c) => matches!(c, '\u{13}' | 's' | 'S'),
+        console::Key::UnknownEscSeq(seq) => seq.starts_with(&['[', '2', '1']),
+        _ => false,
+    }
+}
+
+/// Before an optional step, wait for one key: a skip key applies the step's
+/// documented default and moves on, anything else runs the step.
+fn offer_skip(step: WizardStep) -> Result<bool> {
+    let Some(default) = step.skip_default() else {
+        return Ok(false);
+    };
+    let skip = if prompt::is_plain() {
+        prompt::read_plain_line(&format!(
+            "  Optional step. Press Enter to set it up, or type s to skip ({default}): "
+        ))?
+        .trim()
+        .eq_ignore_ascii_case("s")
+    } else {
+        let term = console::Term::stdout();
+        if !term.is_term() {
+            return Ok(false);
+        }
+        prompt::wait_for_room()?;
+        println!(
+            "  {}",
+            theme::muted(format!(
+                "Optional — Enter to set up · Ctrl-S / F10 to skip ({default})"
+            ))
+        );
+        is_skip_key(&term.read_key()?)
+    };
+    if skip {
+        println!(
+            "  {} Skipped: {}",
+            theme::success("✓").bold(),
+            theme::success(default)
+        );
+    }
+    Ok(skip)
+}
+
+/// Split the header rule into (filled, empty) cells for the progress gauge.
+fn step_gauge_split(current: usize, total: usize, width: usize) -> (usize, usize) {
+    let total = total.max(1);
+    let filled = (current.min(total) * width) / total;
+    (filled, width - filled)
+}
+
+// ── Confirmation ─────────────────────────────────────────────────
+
+/// Answers collected by the full onboarding flow, held together so the
+/// Confirmation step can re-run a single step without losing the others.
+struct WizardAnswers {
+    provider: String,
+    api_key: String,
+    model: String,
+    provider_api_url: Option<String>,
+    /// Stored `zeroclaw auth` profile picked instead of entering a key.
+    auth_profile: Option<String>,
+    channels_config: ChannelsConfig,
+    schedules_config: crate::config::SchedulesConfig,
+    tunnel_config: crate::config::TunnelConfig,
+    /// Public URL observed when the tunnel was verified during onboarding.
+    tunnel_public_url: Option<String>,
+    composio_config: ComposioConfig,
+    secrets_config: SecretsConfig,
+    security_preset: SecurityPreset,
+    otp_config: OtpConfig,
+    hardware_config: HardwareConfig,
+    estop_config: EstopConfig,
+    memory_config: MemoryConfig,
+    embedding: EmbeddingChoice,
+    project_ctx: ProjectContext,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfirmationChoice {
+    Edit(WizardStep),
+    Save,
+    Abort,
+}
+
+fn or_unset(value: &str) -> &str {
+    if value.trim().is_empty() {
+        "(not set)"
+    } else {
+        value
+    }
+}
+
+/// One selectable line per editable step, followed by Save and Abort.
+fn confirmation_entries(answers: &WizardAnswers) -> Vec<(ConfirmationChoice, String)> {
+    let channels = answers.channels_config.channels();
+    let channels: Vec<_> = std::iter::once("Cli")
+        .chain(
+            channels
+                .iter()
+                .filter_map(|(channel, ok)| ok.then_some(channel.name())),
+        )
+        .collect();
+
+    let tunnel =
+        if answers.tunnel_config.provider.is_empty() || answers.tunnel_config.provider == "none" {
+            "none (local only)".to_string()
+        } else if let Some(url) = &answers.tunnel_public_url {
+            format!("{} → {url} (verified)", answers.tunnel_config.provider)
+        } else {
+            format!("{} (not verified)", answers.tunnel_config.provider)
+        };
+
+    let schedules = if answers.schedules_config.jobs.is_empty() {
+        "none".to_string()
+    } else {
+        answers
+            .schedules_config
+            .jobs
+            .iter()
+            .map(|job| format!("{} ({})", job.name, job.cron))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let hardware = if answers.hardware_config.enabled {
+        format!("{:?}", answers.hardware_config.transport_mode())
+    } else {
+        "disabled".to_string()
+    };
+
+    let estop = if answers.estop_config.enabled {
+        let mut triggers = vec!["CLI".to_string()];
+        if let Some(phrase) = &answers.estop_config.trigger_phrase {
+            triggers.push(format!("chat \"{phrase}\""));
+        }
+        if let Some(pin) = answers.estop_config.kill_switch_pin {
+            triggers.push(format!("GPIO {pin}"));
+        }
+        format!("on ({})", triggers.join(", "))
+    } else {
+        "off".to_string()
+    };
+
+    vec![
+        (
+            ConfirmationChoice::Edit(WizardStep::Provider),
+            format!(
+                "🤖 Provider:  {} / {} (API key: {})",
+                answers.provider,
+                answers.model,
+                if let Some(profile) = &answers.auth_profile {
+                    format!("auth profile {profile}")
+                } else if answers.api_key.is_empty() {
+                    "not set".to_string()
+                } else if let Some(name) =
+                    crate::config::schema::parse_env_secret_ref(&answers.api_key)
+                {
+                    format!("from ${name}")
+                } else {
+                    "configured".to_string()
+                }
+            ),
+        ),
+        (
+            ConfirmationChoice::Edit(WizardStep::Channels),
+            format!("📡 Channels:  {}", channels.join(", ")),
+        ),
+        (
+            ConfirmationChoice::Edit(WizardStep::Schedules),
+            format!("⏰ Schedules: {schedules}"),
+        ),
+        (
+            ConfirmationChoice::Edit(WizardStep::Tunnel),
+            format!("🌐 Tunnel:    {tunnel}"),
+        ),
+        (
+            ConfirmationChoice::Edit(WizardStep::ToolMode),
+            format!(
+                "🔗 Tools:     Composio {} | secrets encryption {}",
+                if answers.composio_config.enabled {
+                    "enabled"
+                } else {
+                    "disabled"
+                },
+                if answers.secrets_config.encrypt {
+                    "on"
+                } else {
+                    "off"
+                }
+            ),
+        ),
+        (
+            ConfirmationChoice::Edit(WizardStep::Security),
+            format!(
+                "🛡️ Security:  {} | OTP {}",
+                answers.security_preset.name(),
+                if answers.otp_config.enabled {
+                    "on"
+                } else {
+                    "off"
+                }
+            ),
+        ),
+        (
+            ConfirmationChoice::Edit(WizardStep::Hardware),
+            format!("🔌 Hardware:  {hardware}"),
+        ),
+        (
+            ConfirmationChoice::Edit(WizardStep::Estop),
+            format!("🛑 Estop:     {estop}"),
+        ),
+        (
+            ConfirmationChoice::Edit(WizardStep::Memory),
+            format!(
+                "🧠 Memory:    {} (auto-save: {})",
+                answers.memory_config.backend,
+                if answers.memory_config.auto_save {
+                    "on"
+                } else {
+                    "off"
+                }
+            ),
+        ),
+        (
+            ConfirmationChoice::Edit(WizardStep::Embeddings),
+            format!("🧭 Embeddings: {}", answers.embedding.summary()),
+        ),
+        (
+            ConfirmationChoice::Edit(WizardStep::ProjectContext),
+            format!(
+                "👤 Context:   {} · {} · agent {}",
+                or_unset(&answers.project_ctx.user_name),
+                or_unset(&answers.project_ctx.timezone),
+                or_unset(&answers.project_ctx.agent_name)
+            ),
+        ),
+        (
+            ConfirmationChoice::Save,
+            i18n::text(Msg::ReviewSave).to_string(),
+        ),
+        (
+            ConfirmationChoice::Abort,
+            i18n::text(Msg::ReviewAbort).to_string(),
+        ),
+    ]
+}
+
+/// Show the collected answers as a selectable list. Picking a line re-runs
+/// that step in place and returns here; only Save leaves the loop.
+async fn review_wizard_answers(
+    answers: &mut WizardAnswers,
+    workspace_dir: &Path,
+    config_path: &Path,
+    progress: &WizardProgress,
+) -> Result<()> {
+    loop {
+        progress.begin(WizardStep::Confirmation);
+        print_bullet(i18n::text(Msg::ReviewHint));
+        println!();
+
+        let entries = confirmation_entries(answers);
+        let labels: Vec<&str> = entries.iter().map(|(_, label)| label.as_str()).collect();
+        let save_index = entries
+            .iter()
+            .position(|(choice, _)| *choice == ConfirmationChoice::Save)
+            .unwrap_or(0);
+
+        let selected = Select::new()
+            .with_prompt(format!("  {}", i18n::text(Msg::ReviewPrompt)))
+            .items(&labels)
+            .default(save_index)
+            .interact()?;
+
+        match entries[selected].0 {
+            ConfirmationChoice::Save => return Ok(()),
+            ConfirmationChoice::Abort => {
+                bail!("Onboarding aborted at confirmation; nothing was saved.")
+            }
+            ConfirmationChoice::Edit(step) => {
+                progress.begin(step);
+                match step {
+                    WizardStep::Provider => {
+                        let (provider, api_key, model, provider_api_url, auth_profile) =
+                            setup_provider(workspace_dir, config_path).await?;
+                        answers.provider = provider;
+                        answers.api_key = api_key;
+                        answers.model = model;
+                        answers.provider_api_url = provider_api_url;
+                        answers.auth_profile = auth_profile;
+                    }
+                    WizardStep::Channels => answers.channels_config = setup_channels()?,
+                    WizardStep::Schedules => {
+                        answers.schedules_config = setup_schedules(&answers.channels_config)?;
+                    }
+                    WizardStep::Tunnel => {
+                        (answers.tunnel_config, answers.tunnel_public_url) =
+                            setup_verified_tunnel().await?;
+                    }
+                    WizardStep::ToolMode => {
+                        let (composio_config, secrets_config) = setup_tool_mode()?;
+                        answers.composio_config = composio_config;
+                        answers.secrets_config = secrets_config;
+                    }
+                    WizardStep::Security => {
+                        answers.security_preset = setup_security()?;
+                        answers.otp_config =
+                            setup_otp(config_path, answers.secrets_config.encrypt)?;
+                    }
+                    WizardStep::Hardware => answers.hardware_config = setup_hardware()?,
+                    WizardStep::Estop => {
+                        answers.estop_config = setup_estop(&answers.hardware_config)?;
+                    }
+                    WizardStep::Memory => answers.memory_config = setup_memory().await?,
+                    WizardStep::Embeddings => {
+                        answers.embedding = setup_embeddings(
+                            &answers.memory_config.backend,
+                            &answers.provider,
+                            &answers.api_key,
+                        )?;
+                    }
+                    WizardStep::ProjectContext => answers.project_ctx = setup_project_context()?,
+                    WizardStep::Language
+                    | WizardStep::Workspace
+                    | WizardStep::Import
+                    | WizardStep::Confirmation
+                    | WizardStep::WorkspaceFiles
+                    | WizardStep::Preview => {}
+                }
+            }
+        }
+    }
+}
+
+/// Offer to write `SETUP.md` into the workspace: operator notes for the
+/// deployment generated from the saved config, with secrets redacted.
+async fn offer_setup_notes(config: &Config) -> Result<()> {
+    let path = config.workspace_dir.join("SETUP.md");
+    let write = Confirm::new()
+        .with_prompt(format!(
+            "  Write {} describing this setup? (secrets redacted)",
+            theme::accent("SETUP.md")
+        ))
+        .default(false)
+        .interact()?;
+    if !write {
+        return Ok(());
+    }
+    let notes = preview::setup_markdown(config)?;
+    fs::write(&path, notes)
+        .await
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    println!(
+        "  {} Setup notes written to {}",
+        theme::success("✓").bold(),
+        theme::success(path.display())
+    );
+    Ok(())
+}
+
+// ── Secrets from the environment ─────────────────────────────────
+
+/// A secret typed during onboarding or taken from the environment.
+struct SecretInput {
+    /// Resolved value, used for connection checks while the wizard runs.
+    value: String,
+    /// Value written to config.toml: the secret itself, or an `env:NAME` reference.
+    stored: String,
+}
+
+/// First of `env_vars` that is set to a non-empty value, with that value.
+fn detect_env_secret<'a>(
+    env_vars: &[&'a str],
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Option<(&'a str, String)> {
+    env_vars.iter().find_map(|name| {
+        lookup(name)
+            .filter(|value| !value.trim().is_empty())
+            .map(|value| (*name, value))
+    })
+}
+
+/// Short preview of a secret that never shows more than its edges.
+fn mask_secret_preview(value: &str) -> String {
+    let chars: Vec<char> = value.trim().chars().collect();
+    if chars.len() <= 12 {
+        return "••••".to_string();
+    }
+    let head: String = chars[..4].iter().collect();
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("{head}…{tail}")
+}
+
+/// Value of an `env:NAME` reference, or `value` itself when it is not one.
+fn resolve_secret_input(value: &str) -> String {
+    match crate::config::schema::parse_env_secret_ref(value) {
+        Some(name) => std::env::var(name).unwrap_or_default(),
+        None => value.to_string(),
+    }
+}
+
+/// If one of `env_vars` is set, offer to read the secret from it at runtime.
+/// Returns the `env:NAME` reference to store when the user accepts.
+fn offer_env_secret(env_vars: &[&str]) -> Result<Option<String>> {
+    let Some((name, value)) = detect_env_secret(env_vars, |name| std::env::var(name).ok()) else {
+        return Ok(None);
+    };
+
+    let options = [
+        format!(
+            "Use ${name} from the environment ({}) — not written to config.toml",
+            mask_secret_preview(&value)
+        ),
+        "Enter a value manually".to_string(),
+    ];
+    let choice = Select::new()
+        .with_prompt(format!(
+            "  Found {} in your environment",
+            theme::success(name)
+        ))
+        .items(&options)
+        .default(0)
+        .interact()?;
+
+    Ok((choice == 0).then(|| format!("{}{name}", crate::config::schema::ENV_SECRET_PREFIX)))
+}
+
+/// Prompt for a channel secret, offering a detected environment variable first.
+fn prompt_secret(prompt: &str, env_vars: &[&str], rule: Rule<'_>) -> Result<SecretInput> {
+    if let Some(reference) = offer_env_secret(env_vars)? {
+        return Ok(SecretInput {
+            value: resolve_secret_input(&reference),
+            stored: reference,
+        });
+    }
+    let value = loop {
+        let value = prompt_pasteable_checked(prompt, rule)?;
+        if !value.is_empty() {
+            break value;
+        }
+    };
+    Ok(SecretInput {
+        stored: value.clone(),
+        value,
+    })
+}
+
+fn print_bullet(text: &str) {
+    println!("  {} {}", theme::accent("›"), text);
+}
+
+fn resolve_interactive_onboarding_mode(
+    config_path: &Path,
+    force: bool,
+) -> Result<InteractiveOnboardingMode> {
+    if !config_path.exists() {
+        return Ok(InteractiveOnboardingMode::FullOnboarding);
+    }
+
+    if force {
+        println!(
+            "  {} Existing config detected at {}. Proceeding with full onboarding because --force was provided.",
+            theme::warning("!").bold(),
+            theme::warning(config_path.display())
+        );
+        return Ok(InteractiveOnboardingMode::FullOnboarding);
+    }
+
+    if !std::io::stdin().is_terminal() || !std::io::stdout().is_terminal() {
+        bail!(
+            "Refusing to overwrite existing config at {} in non-interactive mode. Re-run with --force if overwrite is intentional.",
+            config_path.display()
+        );
+    }
+
+    let options = [
+        "Full onboarding (overwrite config.toml)",
+        "Update AI provider/model/API key only (preserve existing configuration)",
+        "Cancel",
+    ];
+
+    let mode = Select::new()
         .with_prompt(format!(
             "  Existing config found at {}. Select setup mode",
             config_path.display()
@@ -2023,8 +3286,8 @@ fn ensure_onboard_overwrite_allowed(config_path: &Path, force: bool) -> Result<(
     if force {
         println!(
             "  {} Existing config detected at {}. Proceeding because --force was provided.",
-            style("!").yellow().bold(),
-            style(config_path.display()).yellow()
+            theme::warning("!").bold(),
+            theme::warning(config_path.display())
         );
         return Ok(());
     }
@@ -2051,18 +3314,195 @@ fn ensure_onboard_overwrite_allowed(config_path: &Path, force: bool) -> Result<(
     Ok(())
 }
 
-async fn persist_workspace_selection(config_path: &Path) -> Result<()> {
-    let config_dir = config_path
-        .parent()
-        .context("Config path must have a parent directory")?;
-    crate::config::schema::persist_active_workspace_config_dir(config_dir)
-        .await
-        .with_context(|| {
-            format!(
-                "Failed to persist active workspace selection for {}",
-                config_dir.display()
-            )
-        })
+pub(super) async fn persist_workspace_selection(config_path: &Path) -> Result<()> {
+    let config_dir = config_path
+        .parent()
+        .context("Config path must have a parent directory")?;
+    // Profiles are picked per command with --profile; keep the default marker.
+    if crate::config::schema::is_profile_config_dir(config_dir) {
+        return Ok(());
+    }
+    crate::config::schema::persist_active_workspace_config_dir(config_dir)
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to persist active workspace selection for {}",
+                config_dir.display()
+            )
+        })
+}
+
+/// Show the masked config.toml and ask where to save it. Returns an
+/// alternate path when the live config should be left untouched.
+fn preview_config(config: &Config) -> Result<Option<PathBuf>> {
+    let rendered = preview::render_masked(config)?;
+    print_bullet("This is the config.toml that will be written (secrets masked).");
+    println!();
+    preview::page(&rendered)?;
+    println!();
+
+    let options = [
+        format!("Save to {}", config.config_path.display()),
+        "Save to a different path (leave the live config untouched)".to_string(),
+        "View the preview again".to_string(),
+        "Abort without saving".to_string(),
+    ];
+    loop {
+        match Select::new()
+            .with_prompt("  Save this configuration?")
+            .items(&options)
+            .default(0)
+            .interact()?
+        {
+            0 => return Ok(None),
+            1 => {
+                let raw: String = Input::new()
+                    .with_prompt("  Save config to")
+                    .default(
+                        config
+                            .config_path
+                            .with_file_name("config.preview.toml")
+                            .display()
+                            .to_string(),
+                    )
+                    .interact_text()?;
+                let path = PathBuf::from(shellexpand::tilde(raw.trim()).as_ref());
+                if path == config.config_path {
+                    println!(
+                        "  {} That is the live config path; pick another one.",
+                        theme::warning("!").bold()
+                    );
+                    continue;
+                }
+                return Ok(Some(path));
+            }
+            2 => preview::page(&rendered)?,
+            _ => bail!("Onboarding aborted at preview; nothing was saved."),
+        }
+    }
+}
+
+/// Most changed keys listed before the rest are summarised as a count.
+const OVERWRITE_DIFF_LIMIT: usize = 40;
+
+/// Before full onboarding replaces an existing config.toml, list what would
+/// be removed or changed, ask for confirmation (skipped with `--force`) and
+/// copy the old file to a timestamped backup next to it.
+async fn confirm_config_overwrite(config: &Config, force: bool) -> Result<()> {
+    let path = &config.config_path;
+    let Ok(raw) = fs::read_to_string(path).await else {
+        return Ok(());
+    };
+    // Round-trip through Config so keys the old file left at their defaults
+    // do not show up as additions.
+    let old = match toml::from_str::<Config>(&raw) {
+        Ok(parsed) => parsed.to_toml_table()?,
+        Err(_) => toml::from_str::<toml::Table>(&raw).unwrap_or_default(),
+    };
+    let changes = preview::diff_masked(&old, &config.to_toml_table()?);
+
+    println!();
+    println!(
+        "  {} {} already exists.",
+        theme::warning("!").bold(),
+        theme::warning(path.display())
+    );
+    if changes.is_empty() {
+        print_bullet("The new configuration matches it (secrets are not compared).");
+    } else {
+        print_bullet("Saving will make these changes (secrets masked):");
+        let (removed, rest): (Vec<_>, Vec<_>) =
+            changes.iter().partition(|change| change.after.is_none());
+        let (changed, added): (Vec<_>, Vec<_>) =
+            rest.into_iter().partition(|change| change.before.is_some());
+        for change in removed
+            .iter()
+            .chain(&changed)
+            .chain(&added)
+            .take(OVERWRITE_DIFF_LIMIT)
+        {
+            match (&change.before, &change.after) {
+                (Some(before), None) => println!(
+                    "    {} {} {}",
+                    theme::error("-").bold(),
+                    theme::error(&change.path),
+                    theme::muted(format!("(was {before})"))
+                ),
+                (Some(before), Some(after)) => println!(
+                    "    {} {}: {} → {}",
+                    theme::warning("~").bold(),
+                    change.path,
+                    theme::muted(before),
+                    after
+                ),
+                (None, after) => println!(
+                    "    {} {} {}",
+                    theme::success("+").bold(),
+                    change.path,
+                    theme::muted(after.as_deref().unwrap_or_default())
+                ),
+            }
+        }
+        if changes.len() > OVERWRITE_DIFF_LIMIT {
+            print_bullet(&format!(
+                "… and {} more",
+                changes.len() - OVERWRITE_DIFF_LIMIT
+            ));
+        }
+        println!(
+            "    {} removed · {} changed · {} added",
+            removed.len(),
+            changed.len(),
+            added.len()
+        );
+    }
+
+    if !force
+        && !Confirm::new()
+            .with_prompt("  Overwrite the existing config? (a timestamped backup is kept)")
+            .default(false)
+            .interact()?
+    {
+        bail!("Onboarding canceled: existing configuration was left unchanged.");
+    }
+
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("config.toml");
+    let backup = path.with_file_name(format!(
+        "{file_name}.bak-{}",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    fs::copy(path, &backup)
+        .await
+        .with_context(|| format!("Failed to back up {} before overwriting", path.display()))?;
+    println!(
+        "  {} Previous config backed up to {}",
+        theme::success("✓").bold(),
+        theme::success(backup.display())
+    );
+    Ok(())
+}
+
+// ── Step 0: Language ─────────────────────────────────────────────
+
+fn setup_language() -> Result<()> {
+    let names: Vec<&str> = crate::config::UiLocale::ALL
+        .iter()
+        .map(|locale| locale.native_name())
+        .collect();
+    let current = crate::config::UiLocale::ALL
+        .iter()
+        .position(|locale| *locale == i18n::current())
+        .unwrap_or(0);
+    let choice = Select::new()
+        .with_prompt(format!("  {}", i18n::text(Msg::LanguagePrompt)))
+        .items(&names)
+        .default(current)
+        .interact()?;
+    i18n::set_locale(crate::config::UiLocale::ALL[choice]);
+    Ok(())
 }
 
 // ── Step 1: Workspace ────────────────────────────────────────────
@@ -2073,7 +3513,7 @@ async fn setup_workspace() -> Result<(PathBuf, PathBuf)> {
 
     print_bullet(&format!(
         "Default location: {}",
-        style(default_workspace_dir.display()).green()
+        theme::success(default_workspace_dir.display())
     ));
 
     let use_default = Confirm::new()
@@ -2091,6 +3531,24 @@ async fn setup_workspace() -> Result<(PathBuf, PathBuf)> {
         crate::config::schema::resolve_config_dir_for_workspace(&PathBuf::from(expanded))
     };
 
+    let (config_dir, workspace_dir) = match crate::config::schema::active_profile() {
+        Some(profile) => {
+            print_bullet(&format!(
+                "Profile: {} (from --profile)",
+                theme::accent(profile)
+            ));
+            (config_dir, workspace_dir)
+        }
+        None => match prompt_profile_name()? {
+            Some(profile) => {
+                let config_dir = crate::config::schema::profile_config_dir(&config_dir, &profile);
+                let workspace_dir = config_dir.join("workspace");
+                (config_dir, workspace_dir)
+            }
+            None => (config_dir, workspace_dir),
+        },
+    };
+
     let config_path = config_dir.join("config.toml");
 
     fs::create_dir_all(&workspace_dir)
@@ -2099,17 +3557,256 @@ async fn setup_workspace() -> Result<(PathBuf, PathBuf)> {
 
     println!(
         "  {} Workspace: {}",
-        style("✓").green().bold(),
-        style(workspace_dir.display()).green()
+        theme::success("✓").bold(),
+        theme::success(workspace_dir.display())
     );
+    if crate::config::schema::is_profile_config_dir(&config_dir) {
+        let profile = config_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        print_bullet(&format!(
+            "Use it with {}",
+            theme::accent(format!("zeroclaw --profile {profile} <command>"))
+        ));
+    }
 
     Ok((workspace_dir, config_path))
 }
 
+/// Ask for an optional profile name; empty keeps the default profile.
+fn prompt_profile_name() -> Result<Option<String>> {
+    loop {
+        let name: String = Input::new()
+            .with_prompt("  Profile name (e.g. work, personal; Enter for the default profile)")
+            .allow_empty(true)
+            .interact_text()?;
+        let name = name.trim();
+        if name.is_empty() {
+            return Ok(None);
+        }
+        match crate::config::schema::validate_profile_name(name) {
+            Ok(()) => return Ok(Some(name.to_string())),
+            Err(e) => println!("  {} {e}", theme::error("✗").bold()),
+        }
+    }
+}
+
+// ── Step 1b: Import existing settings ────────────────────────────
+
+/// Offer to pre-fill provider, key, model and channels from an existing
+/// dotenv or config.toml. `None` means start fresh.
+fn setup_import() -> Result<Option<import::ImportedSettings>> {
+    print_bullet("Migrating from another machine or assistant? Reuse its settings.");
+    print_bullet("Supports .env files (OPENAI_API_KEY, TELEGRAM_BOT_TOKEN, …) and config.toml.");
+    println!();
+
+    let detected = import::detected_sources();
+    let mut options = vec!["Start fresh".to_string()];
+    options.extend(
+        detected
+            .iter()
+            .map(|path| format!("Import from {}", path.display())),
+    );
+    options.push("Import from another file…".to_string());
+
+    loop {
+        let choice = Select::new()
+            .with_prompt("  Import existing settings?")
+            .items(&options)
+            .default(0)
+            .interact()?;
+        let path = match choice {
+            0 => return Ok(None),
+            i if i <= detected.len() => detected[i - 1].clone(),
+            _ => {
+                let raw: String = Input::new()
+                    .with_prompt("  Path to .env or config.toml")
+                    .interact_text()?;
+                PathBuf::from(shellexpand::tilde(raw.trim()).as_ref())
+            }
+        };
+
+        match import::import_from_path(&path) {
+            Ok(settings) => {
+                print_import_summary(&settings);
+                if Confirm::new()
+                    .with_prompt("  Use these settings?")
+                    .default(true)
+                    .interact()?
+                {
+                    return Ok(Some(settings));
+                }
+            }
+            Err(e) => println!("  {} {e:#}", theme::error("✗").bold()),
+        }
+    }
+}
+
+fn print_import_summary(settings: &import::ImportedSettings) {
+    println!(
+        "  {} Found in {}:",
+        theme::success("✓").bold(),
+        theme::accent(settings.source.display())
+    );
+    if let Some(provider) = &settings.provider {
+        print_bullet(&format!("Provider: {}", theme::accent(provider)));
+    }
+    if let Some(model) = &settings.model {
+        print_bullet(&format!("Model: {}", theme::accent(model)));
+    }
+    if let Some(key) = &settings.api_key {
+        let preview = if crate::config::schema::parse_env_secret_ref(key).is_some() {
+            key.clone()
+        } else {
+            mask_secret_preview(key)
+        };
+        print_bullet(&format!("API key: {preview}"));
+    }
+    let channels = settings.channel_names();
+    if !channels.is_empty() {
+        print_bullet(&format!("Channels: {}", channels.join(", ")));
+    }
+    println!();
+}
+
+/// `(provider, api key, model, api url)` carried over from an import.
+type ImportedProvider = (String, String, String, Option<String>);
+
+/// Provider answers taken from `settings`, if the user keeps them.
+fn reuse_imported_provider(
+    settings: &import::ImportedSettings,
+) -> Result<Option<ImportedProvider>> {
+    let Some(provider) = settings.provider.clone() else {
+        return Ok(None);
+    };
+    let model = settings
+        .model
+        .clone()
+        .unwrap_or_else(|| default_model_for_provider(&provider));
+    let keep = Confirm::new()
+        .with_prompt(format!(
+            "  Keep imported provider {} with model {}? (No to pick again)",
+            theme::accent(&provider),
+            theme::accent(&model)
+        ))
+        .default(true)
+        .interact()?;
+    Ok(keep.then(|| {
+        (
+            provider,
+            settings.api_key.clone().unwrap_or_default(),
+            model,
+            settings.api_url.clone(),
+        )
+    }))
+}
+
+/// Imported channels, if the user keeps them instead of re-running the
+/// channel step.
+fn reuse_imported_channels(settings: &import::ImportedSettings) -> Result<Option<ChannelsConfig>> {
+    let names = settings.channel_names();
+    if names.is_empty() {
+        return Ok(None);
+    }
+    let keep = Confirm::new()
+        .with_prompt(format!(
+            "  Keep imported channels ({})? (No to configure again)",
+            names.join(", ")
+        ))
+        .default(true)
+        .interact()?;
+    Ok(keep.then(|| settings.channels.clone()))
+}
+
 // ── Step 2: Provider & API Key ───────────────────────────────────
 
+/// `(profile id, label)` for each stored auth profile of `provider`, the
+/// active one first and marked.
+fn auth_profile_choices(
+    data: &crate::auth::profiles::AuthProfilesData,
+    provider: &str,
+) -> Vec<(String, String)> {
+    let active = data.active_profiles.get(provider);
+    let mut choices: Vec<(String, String)> = data
+        .profiles
+        .values()
+        .filter(|profile| profile.provider == provider)
+        .map(|profile| {
+            let marker = if active == Some(&profile.id) {
+                " [active]"
+            } else {
+                ""
+            };
+            (
+                profile.id.clone(),
+                format!("Use profile {}{marker}", profile.profile_name),
+            )
+        })
+        .collect();
+    choices.sort_by_key(|(id, _)| active != Some(id));
+    choices
+}
+
+/// Offer profiles saved with `zeroclaw auth` for `provider` in place of
+/// typing a key. `None` when there are none or manual entry is chosen.
+async fn choose_auth_profile(
+    config_path: &Path,
+    provider: &str,
+) -> Result<Option<crate::auth::profiles::AuthProfile>> {
+    let Ok(normalized) = crate::auth::normalize_provider(provider) else {
+        return Ok(None);
+    };
+    let state_dir = config_path.parent().unwrap_or_else(|| Path::new("."));
+    let service = crate::auth::AuthService::new(state_dir, SecretsConfig::default().encrypt);
+    let data = match service.load_profiles().await {
+        Ok(data) => data,
+        Err(error) => {
+            tracing::debug!("Could not read auth profiles: {error}");
+            return Ok(None);
+        }
+    };
+    let choices = auth_profile_choices(&data, &normalized);
+    if choices.is_empty() {
+        return Ok(None);
+    }
+
+    let mut labels: Vec<String> = choices.iter().map(|(_, label)| label.clone()).collect();
+    labels.push("Enter an API key manually".to_string());
+    let picked = Select::new()
+        .with_prompt(format!("  Saved {normalized} credentials found"))
+        .items(&labels)
+        .default(0)
+        .interact()?;
+    let Some((id, _)) = choices.get(picked) else {
+        return Ok(None);
+    };
+    println!(
+        "  {} Using auth profile {}",
+        theme::success("✓").bold(),
+        theme::accent(id)
+    );
+    Ok(data.profiles.get(id).cloned())
+}
+
+/// Make the auth profile picked during onboarding the active one for the
+/// configured provider, once the config has been saved.
+async fn activate_auth_profile(config: &Config, profile_id: Option<&str>) -> Result<()> {
+    let (Some(profile_id), Some(provider)) = (profile_id, config.default_provider.as_deref())
+    else {
+        return Ok(());
+    };
+    crate::auth::AuthService::from_config(config)
+        .set_active_profile(provider, profile_id)
+        .await?;
+    Ok(())
+}
+
 #[allow(clippy::too_many_lines)]
-async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String, Option<String>)> {
+async fn setup_provider(
+    workspace_dir: &Path,
+    config_path: &Path,
+) -> Result<(String, String, String, Option<String>, Option<String>)> {
     // ── Tier selection ──
     let tiers = vec![
         "⭐ Recommended (OpenRouter, Venice, Anthropic, OpenAI, Gemini)",
@@ -2204,8 +3901,8 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
         println!();
         println!(
             "  {} {}",
-            style("Custom Provider Setup").white().bold(),
-            style("— any OpenAI-compatible API").dim()
+            theme::strong("Custom Provider Setup").bold(),
+            theme::muted("— any OpenAI-compatible API")
         );
         print_bullet("ZeroClaw works with ANY API that speaks the OpenAI chat completions format.");
         print_bullet("Examples: LiteLLM, LocalAI, vLLM, text-generation-webui, LM Studio, etc.");
@@ -2213,6 +3910,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
 
         let base_url: String = Input::new()
             .with_prompt("  API base URL (e.g. http://localhost:1234 or https://my-api.com)")
+            .rule(Rule::Url)
             .interact_text()?;
 
         let base_url = base_url.trim().trim_end_matches('/').to_string();
@@ -2234,15 +3932,19 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
 
         println!(
             "  {} Provider: {} | Model: {}",
-            style("✓").green().bold(),
-            style(&provider_name).green(),
-            style(&model).green()
+            theme::success("✓").bold(),
+            theme::success(&provider_name),
+            theme::success(&model)
         );
 
-        return Ok((provider_name, api_key, model, None));
+        return Ok((provider_name, api_key, model, None, None));
     }
 
-    let provider_labels: Vec<&str> = providers.iter().map(|(_, label)| *label).collect();
+    let provider_labels = provider_choice_labels(&providers);
+    print_bullet(
+        &theme::muted("Hints: typical latency · price ($ budget → $$$ premium) · context window")
+            .to_string(),
+    );
 
     let provider_idx = Select::new()
         .with_prompt("  Select your AI provider")
@@ -2254,7 +3956,12 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
 
     // ── API key / endpoint ──
     let mut provider_api_url: Option<String> = None;
-    let api_key = if provider_name == "ollama" {
+    let auth_profile = choose_auth_profile(config_path, provider_name).await?;
+    let api_key = if let Some(profile) = &auth_profile {
+        // OAuth profiles are read from the auth store at runtime; token
+        // profiles carry a plain key the provider needs in config.
+        profile.token.clone().unwrap_or_default()
+    } else if provider_name == "ollama" {
         let use_remote_ollama = Confirm::new()
             .with_prompt("  Use a remote Ollama endpoint (for example Ollama Cloud)?")
             .default(false)
@@ -2263,6 +3970,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
         if use_remote_ollama {
             let raw_url: String = Input::new()
                 .with_prompt("  Remote Ollama endpoint URL")
+                .rule(Rule::Url)
                 .default("https://ollama.com".into())
                 .interact_text()?;
 
@@ -2280,14 +3988,14 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
 
             print_bullet(&format!(
                 "Remote endpoint configured: {}",
-                style(&normalized_url).cyan()
+                theme::accent(&normalized_url)
             ));
             if raw_url.trim().trim_end_matches('/') != normalized_url {
                 print_bullet("Normalized endpoint to base URL (removed trailing /api).");
             }
             print_bullet(&format!(
                 "If you use cloud-only models, append {} to the model ID.",
-                style(":cloud").yellow()
+                theme::warning(":cloud")
             ));
 
             let key: String = Input::new()
@@ -2298,7 +4006,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
             if key.trim().is_empty() {
                 print_bullet(&format!(
                     "No API key provided. Set {} later if required by your endpoint.",
-                    style("OLLAMA_API_KEY").yellow()
+                    theme::warning("OLLAMA_API_KEY")
                 ));
             }
 
@@ -2310,6 +4018,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
     } else if matches!(provider_name, "llamacpp" | "llama.cpp") {
         let raw_url: String = Input::new()
             .with_prompt("  llama.cpp server endpoint URL")
+            .rule(Rule::Url)
             .default("http://localhost:8080/v1".into())
             .interact_text()?;
 
@@ -2321,7 +4030,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
 
         print_bullet(&format!(
             "Using llama.cpp server endpoint: {}",
-            style(&normalized_url).cyan()
+            theme::accent(&normalized_url)
         ));
         print_bullet("No API key needed unless your llama.cpp server is started with --api-key.");
 
@@ -2333,7 +4042,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
         if key.trim().is_empty() {
             print_bullet(&format!(
                 "No API key provided. Set {} later only if your server requires authentication.",
-                style("LLAMACPP_API_KEY").yellow()
+                theme::warning("LLAMACPP_API_KEY")
             ));
         }
 
@@ -2341,6 +4050,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
     } else if provider_name == "sglang" {
         let raw_url: String = Input::new()
             .with_prompt("  SGLang server endpoint URL")
+            .rule(Rule::Url)
             .default("http://localhost:30000/v1".into())
             .interact_text()?;
 
@@ -2352,7 +4062,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
 
         print_bullet(&format!(
             "Using SGLang server endpoint: {}",
-            style(&normalized_url).cyan()
+            theme::accent(&normalized_url)
         ));
         print_bullet("No API key needed unless your SGLang server requires authentication.");
 
@@ -2364,7 +4074,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
         if key.trim().is_empty() {
             print_bullet(&format!(
                 "No API key provided. Set {} later only if your server requires authentication.",
-                style("SGLANG_API_KEY").yellow()
+                theme::warning("SGLANG_API_KEY")
             ));
         }
 
@@ -2372,6 +4082,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
     } else if provider_name == "vllm" {
         let raw_url: String = Input::new()
             .with_prompt("  vLLM server endpoint URL")
+            .rule(Rule::Url)
             .default("http://localhost:8000/v1".into())
             .interact_text()?;
 
@@ -2383,7 +4094,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
 
         print_bullet(&format!(
             "Using vLLM server endpoint: {}",
-            style(&normalized_url).cyan()
+            theme::accent(&normalized_url)
         ));
         print_bullet("No API key needed unless your vLLM server requires authentication.");
 
@@ -2395,7 +4106,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
         if key.trim().is_empty() {
             print_bullet(&format!(
                 "No API key provided. Set {} later only if your server requires authentication.",
-                style("VLLM_API_KEY").yellow()
+                theme::warning("VLLM_API_KEY")
             ));
         }
 
@@ -2403,6 +4114,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
     } else if provider_name == "osaurus" {
         let raw_url: String = Input::new()
             .with_prompt("  Osaurus server endpoint URL")
+            .rule(Rule::Url)
             .default("http://localhost:1337/v1".into())
             .interact_text()?;
 
@@ -2414,7 +4126,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
 
         print_bullet(&format!(
             "Using Osaurus server endpoint: {}",
-            style(&normalized_url).cyan()
+            theme::accent(&normalized_url)
         ));
         print_bullet("No API key needed unless your Osaurus server requires authentication.");
 
@@ -2426,7 +4138,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
         if key.trim().is_empty() {
             print_bullet(&format!(
                 "No API key provided. Set {} later only if your server requires authentication.",
-                style("OSAURUS_API_KEY").yellow()
+                theme::warning("OSAURUS_API_KEY")
             ));
         }
 
@@ -2436,12 +4148,12 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
         if crate::providers::gemini::GeminiProvider::has_cli_credentials() {
             print_bullet(&format!(
                 "{} Gemini CLI credentials detected! You can skip the API key.",
-                style("✓").green().bold()
+                theme::success("✓").bold()
             ));
             print_bullet("ZeroClaw will reuse your existing Gemini CLI authentication.");
             println!();
 
-            let use_cli: bool = dialoguer::Confirm::new()
+            let use_cli: bool = Confirm::new()
                 .with_prompt("  Use existing Gemini CLI authentication?")
                 .default(true)
                 .interact()?;
@@ -2449,20 +4161,17 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
             if use_cli {
                 println!(
                     "  {} Using Gemini CLI OAuth tokens",
-                    style("✓").green().bold()
+                    theme::success("✓").bold()
                 );
                 String::new() // Empty key = will use CLI tokens
             } else {
                 print_bullet("Get your API key at: https://aistudio.google.com/app/apikey");
-                Input::new()
-                    .with_prompt("  Paste your Gemini API key")
-                    .allow_empty(true)
-                    .interact_text()?
+                prompt_pasteable_checked("  Paste your Gemini API key", Rule::Token("gemini"))?
             }
         } else if std::env::var("GEMINI_API_KEY").is_ok() {
             print_bullet(&format!(
                 "{} GEMINI_API_KEY environment variable detected!",
-                style("✓").green().bold()
+                theme::success("✓").bold()
             ));
             String::new()
         } else {
@@ -2470,44 +4179,42 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
             print_bullet("Or run `gemini` CLI to authenticate (tokens will be reused).");
             println!();
 
-            Input::new()
-                .with_prompt("  Paste your Gemini API key (or press Enter to skip)")
-                .allow_empty(true)
-                .interact_text()?
+            prompt_pasteable_checked(
+                "  Paste your Gemini API key (or press Enter to skip)",
+                Rule::Token("gemini"),
+            )?
         }
     } else if canonical_provider_name(provider_name) == "anthropic" {
         if std::env::var("ANTHROPIC_OAUTH_TOKEN").is_ok() {
             print_bullet(&format!(
                 "{} ANTHROPIC_OAUTH_TOKEN environment variable detected!",
-                style("✓").green().bold()
+                theme::success("✓").bold()
             ));
             String::new()
         } else if std::env::var("ANTHROPIC_API_KEY").is_ok() {
             print_bullet(&format!(
                 "{} ANTHROPIC_API_KEY environment variable detected!",
-                style("✓").green().bold()
+                theme::success("✓").bold()
             ));
             String::new()
         } else {
             print_bullet(&format!(
                 "Get your API key at: {}",
-                style("https://console.anthropic.com/settings/keys")
-                    .cyan()
-                    .underlined()
+                theme::accent("https://console.anthropic.com/settings/keys").underlined()
             ));
             print_bullet("Or run `claude setup-token` to get an OAuth setup-token.");
             println!();
 
-            let key: String = Input::new()
-                .with_prompt("  Paste your API key or setup-token (or press Enter to skip)")
-                .allow_empty(true)
-                .interact_text()?;
+            let key: String = prompt_pasteable_checked(
+                "  Paste your API key or setup-token (or press Enter to skip)",
+                Rule::Token("anthropic"),
+            )?;
 
             if key.is_empty() {
                 print_bullet(&format!(
                     "Skipped. Set {} or {} or edit config.toml later.",
-                    style("ANTHROPIC_API_KEY").yellow(),
-                    style("ANTHROPIC_OAUTH_TOKEN").yellow()
+                    theme::warning("ANTHROPIC_API_KEY"),
+                    theme::warning("ANTHROPIC_OAUTH_TOKEN")
                 ));
             }
 
@@ -2517,7 +4224,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
         if std::env::var("QWEN_OAUTH_TOKEN").is_ok() {
             print_bullet(&format!(
                 "{} QWEN_OAUTH_TOKEN environment variable detected!",
-                style("✓").green().bold()
+                theme::success("✓").bold()
             ));
             "qwen-oauth".to_string()
         } else {
@@ -2530,18 +4237,15 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
             print_bullet("You can also set QWEN_OAUTH_TOKEN directly.");
             println!();
 
-            let key: String = Input::new()
-                .with_prompt(
-                    "  Paste your Qwen OAuth token (or press Enter to auto-detect cached OAuth)",
-                )
-                .allow_empty(true)
-                .interact_text()?;
+            let key: String = prompt_pasteable(
+                "  Paste your Qwen OAuth token (or press Enter to auto-detect cached OAuth)",
+            )?;
 
             if key.trim().is_empty() {
                 print_bullet(&format!(
                     "Using OAuth auto-detection. Set {} and optional {} if needed.",
-                    style("QWEN_OAUTH_TOKEN").yellow(),
-                    style("QWEN_OAUTH_RESOURCE_URL").yellow()
+                    theme::warning("QWEN_OAUTH_TOKEN"),
+                    theme::warning("QWEN_OAUTH_RESOURCE_URL")
                 ));
                 "qwen-oauth".to_string()
             } else {
@@ -2595,17 +4299,17 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
             print_bullet("Bedrock uses AWS credentials (not a single API key).");
             print_bullet(&format!(
                 "Set {} and {} environment variables.",
-                style("AWS_ACCESS_KEY_ID").yellow(),
-                style("AWS_SECRET_ACCESS_KEY").yellow(),
+                theme::warning("AWS_ACCESS_KEY_ID"),
+                theme::warning("AWS_SECRET_ACCESS_KEY"),
             ));
             print_bullet(&format!(
                 "Optionally set {} for the region (default: us-east-1).",
-                style("AWS_REGION").yellow(),
+                theme::warning("AWS_REGION"),
             ));
             if !key_url.is_empty() {
                 print_bullet(&format!(
                     "Manage IAM credentials at: {}",
-                    style(key_url).cyan().underlined()
+                    theme::accent(key_url).underlined()
                 ));
             }
             println!();
@@ -2614,26 +4318,84 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
             if !key_url.is_empty() {
                 print_bullet(&format!(
                     "Get your API key at: {}",
-                    style(key_url).cyan().underlined()
+                    theme::accent(key_url).underlined()
                 ));
             }
             print_bullet("You can also set it later via env var or config file.");
             println!();
 
-            let key: String = Input::new()
-                .with_prompt("  Paste your API key (or press Enter to skip)")
-                .allow_empty(true)
-                .interact_text()?;
+            if let Some(reference) = offer_env_secret(&[provider_env_var(provider_name)])? {
+                reference
+            } else {
+                loop {
+                    let key: String = prompt_pasteable_checked(
+                        "  Paste your API key (or press Enter to skip)",
+                        Rule::Token(canonical_provider_name(provider_name)),
+                    )?;
+
+                    if key.is_empty() {
+                        let env_var = provider_env_var(provider_name);
+                        print_bullet(&format!(
+                            "Skipped. Set {} or edit config.toml later.",
+                            theme::warning(env_var)
+                        ));
+                        break key;
+                    }
 
-            if key.is_empty() {
-                let env_var = provider_env_var(provider_name);
-                print_bullet(&format!(
-                    "Skipped. Set {} or edit config.toml later.",
-                    style(env_var).yellow()
-                ));
-            }
+                    if !supports_live_model_fetch(provider_name) {
+                        break key;
+                    }
 
-            key
+                    let check = validate_api_key_with_spinner(provider_name, &key, None).await?;
+                    match check {
+                        None => {
+                            print_bullet("Key check cancelled; keeping the key unverified.");
+                            break key;
+                        }
+                        Some(ApiKeyCheck::Accepted(models)) => {
+                            println!(
+                                "  {} API key accepted by {}",
+                                theme::success("✓").bold(),
+                                theme::success(provider_name)
+                            );
+                            if let Some(models) = models.filter(|models| !models.is_empty()) {
+                                cache_live_models_for_provider(
+                                    workspace_dir,
+                                    provider_name,
+                                    &models,
+                                )
+                                .await?;
+                            }
+                            break key;
+                        }
+                        Some(ApiKeyCheck::Rejected(detail)) => {
+                            println!(
+                                "  {} {} rejected this key: {}",
+                                theme::error("✗").bold(),
+                                provider_name,
+                                theme::warning(detail)
+                            );
+                        }
+                        Some(ApiKeyCheck::Inconclusive(detail)) => {
+                            println!(
+                                "  {} Could not verify the key: {}",
+                                theme::warning("!").bold(),
+                                theme::warning(detail)
+                            );
+                        }
+                    }
+
+                    let options = ["Re-enter API key", "Keep this key anyway"];
+                    let retry = Select::new()
+                        .with_prompt("  How do you want to continue?")
+                        .items(options)
+                        .default(0)
+                        .interact()?;
+                    if retry == 1 {
+                        break key;
+                    }
+                }
+            }
         }
     };
 
@@ -2648,7 +4410,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
             && ollama_uses_remote_endpoint(provider_api_url.as_deref());
         let can_fetch_without_key =
             allows_unauthenticated_model_fetch(provider_name) && !ollama_remote;
-        let has_api_key = !api_key.trim().is_empty()
+        let has_api_key = !resolve_secret_input(&api_key).trim().is_empty()
             || ((canonical_provider != "ollama" || ollama_remote)
                 && std::env::var(provider_env_var(provider_name))
                     .ok()
@@ -2661,7 +4423,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
         if canonical_provider == "ollama" && ollama_remote && !has_api_key {
             print_bullet(&format!(
                 "Remote Ollama live-model refresh needs an API key ({}); using curated models.",
-                style("OLLAMA_API_KEY").yellow()
+                theme::warning("OLLAMA_API_KEY")
             ));
         }
 
@@ -2696,12 +4458,17 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
                 .interact()?;
 
             if should_fetch_now {
-                match fetch_live_models_for_provider(
+                match fetch_live_models_with_spinner(
                     provider_name,
-                    &api_key,
+                    &resolve_secret_input(&api_key),
                     provider_api_url.as_deref(),
-                ) {
-                    Ok(live_model_ids) if !live_model_ids.is_empty() => {
+                )
+                .await
+                {
+                    Ok(None) => {
+                        print_bullet("Model fetch cancelled; using cached/curated list.");
+                    }
+                    Ok(Some(live_model_ids)) if !live_model_ids.is_empty() => {
                         cache_live_models_for_provider(
                             workspace_dir,
                             provider_name,
@@ -2726,13 +4493,13 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
 
                         live_options = Some(build_model_options(shown_models, "live"));
                     }
-                    Ok(_) => {
+                    Ok(Some(_)) => {
                         print_bullet("Provider returned no models; using curated list.");
                     }
                     Err(error) => {
                         print_bullet(&format!(
                             "Live fetch failed ({}); using cached/curated list.",
-                            style(error.to_string()).yellow()
+                            theme::warning(error.to_string())
                         ));
 
                         if live_options.is_none() {
@@ -2781,6 +4548,27 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
         }
     }
 
+    let model = select_model(provider_name, model_options)?;
+
+    println!(
+        "  {} Provider: {} | Model: {}",
+        theme::success("✓").bold(),
+        theme::success(provider_name),
+        theme::success(&model)
+    );
+
+    Ok((
+        provider_name.to_string(),
+        api_key,
+        model,
+        provider_api_url,
+        auth_profile.map(|profile| profile.id),
+    ))
+}
+
+/// Filter (for long lists) and pick one of `model_options`, or type a
+/// custom model ID; the provider default stands in for an empty list.
+fn select_model(provider_name: &str, mut model_options: Vec<(String, String)>) -> Result<String> {
     if model_options.is_empty() {
         model_options.push((
             default_model_for_provider(provider_name),
@@ -2788,6 +4576,29 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
         ));
     }
 
+    if model_options.len() > MODEL_FILTER_THRESHOLD {
+        loop {
+            let query: String = Input::new()
+                .with_prompt(format!(
+                    "  Filter {} models (e.g. \"sonnet\" or \"llama 70b\"; Enter to list all)",
+                    model_options.len()
+                ))
+                .allow_empty(true)
+                .interact_text()?;
+            let filtered = filter_model_options(&model_options, &query);
+            if filtered.is_empty() {
+                print_bullet(&format!(
+                    "No models match '{}'. Try a shorter filter.",
+                    query.trim()
+                ));
+                continue;
+            }
+            model_options = filtered;
+            break;
+        }
+    }
+
+    // Always last, whatever the filter kept.
     model_options.push((
         CUSTOM_MODEL_SENTINEL.to_string(),
         "Custom model ID (type manually)".to_string(),
@@ -2795,7 +4606,7 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
 
     let model_labels: Vec<String> = model_options
         .iter()
-        .map(|(model_id, label)| format!("{label} — {}", style(model_id).dim()))
+        .map(|(model_id, label)| format!("{label} — {}", theme::muted(model_id)))
         .collect();
 
     let model_idx = Select::new()
@@ -2805,23 +4616,264 @@ async fn setup_provider(workspace_dir: &Path) -> Result<(String, String, String,
         .interact()?;
 
     let selected_model = model_options[model_idx].0.clone();
-    let model = if selected_model == CUSTOM_MODEL_SENTINEL {
+    if selected_model == CUSTOM_MODEL_SENTINEL {
         Input::new()
             .with_prompt("  Enter custom model ID")
             .default(default_model_for_provider(provider_name))
-            .interact_text()?
+            .interact_text()
     } else {
-        selected_model
-    };
+        Ok(selected_model)
+    }
+}
 
-    println!(
-        "  {} Provider: {} | Model: {}",
-        style("✓").green().bold(),
-        style(provider_name).green(),
-        style(&model).green()
-    );
+// ── Provider hints ───────────────────────────────────────────────
+
+/// Typical response latency for a provider's default models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LatencyClass {
+    Fast,
+    Moderate,
+    /// Depends on the routed model or upstream provider.
+    Varies,
+    /// Bound by the user's own hardware.
+    Local,
+}
+
+/// Rough price tier for a provider's default models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PriceTier {
+    Free,
+    Budget,
+    Standard,
+    Premium,
+    /// Covered by a flat subscription or coding plan.
+    Subscription,
+    /// Billed per routed model.
+    Varies,
+}
+
+/// Curated metadata shown next to each provider in the selection list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ProviderHint {
+    latency: LatencyClass,
+    price: PriceTier,
+    /// Typical context window in thousands of tokens, when it does not vary by model.
+    context_k: Option<u32>,
+}
+
+const fn hint(latency: LatencyClass, price: PriceTier, context_k: Option<u32>) -> ProviderHint {
+    ProviderHint {
+        latency,
+        price,
+        context_k,
+    }
+}
+
+/// Static catalog behind the provider selection hints. Values describe each
+/// provider's default/recommended models and are intentionally coarse.
+const PROVIDER_HINTS: &[(&str, ProviderHint)] = &[
+    (
+        "openrouter",
+        hint(LatencyClass::Varies, PriceTier::Varies, None),
+    ),
+    (
+        "venice",
+        hint(LatencyClass::Moderate, PriceTier::Standard, Some(128)),
+    ),
+    (
+        "anthropic",
+        hint(LatencyClass::Moderate, PriceTier::Premium, Some(200)),
+    ),
+    (
+        "openai",
+        hint(LatencyClass::Moderate, PriceTier::Premium, Some(128)),
+    ),
+    (
+        "openai-codex",
+        hint(LatencyClass::Moderate, PriceTier::Subscription, Some(200)),
+    ),
+    (
+        "deepseek",
+        hint(LatencyClass::Moderate, PriceTier::Budget, Some(128)),
+    ),
+    (
+        "mistral",
+        hint(LatencyClass::Fast, PriceTier::Standard, Some(128)),
+    ),
+    (
+        "xai",
+        hint(LatencyClass::Moderate, PriceTier::Premium, Some(256)),
+    ),
+    (
+        "perplexity",
+        hint(LatencyClass::Moderate, PriceTier::Standard, Some(128)),
+    ),
+    (
+        "gemini",
+        hint(LatencyClass::Fast, PriceTier::Standard, Some(1000)),
+    ),
+    (
+        "groq",
+        hint(LatencyClass::Fast, PriceTier::Budget, Some(128)),
+    ),
+    (
+        "fireworks",
+        hint(LatencyClass::Fast, PriceTier::Budget, Some(128)),
+    ),
+    (
+        "novita",
+        hint(LatencyClass::Moderate, PriceTier::Budget, Some(128)),
+    ),
+    (
+        "together-ai",
+        hint(LatencyClass::Fast, PriceTier::Budget, Some(128)),
+    ),
+    (
+        "nvidia",
+        hint(LatencyClass::Moderate, PriceTier::Free, Some(128)),
+    ),
+    (
+        "vercel",
+        hint(LatencyClass::Varies, PriceTier::Varies, None),
+    ),
+    (
+        "cloudflare",
+        hint(LatencyClass::Varies, PriceTier::Varies, None),
+    ),
+    (
+        "astrai",
+        hint(LatencyClass::Varies, PriceTier::Varies, None),
+    ),
+    (
+        "bedrock",
+        hint(LatencyClass::Moderate, PriceTier::Varies, None),
+    ),
+    (
+        "kimi-code",
+        hint(LatencyClass::Moderate, PriceTier::Subscription, Some(256)),
+    ),
+    (
+        "qwen-code",
+        hint(LatencyClass::Moderate, PriceTier::Free, Some(256)),
+    ),
+    (
+        "moonshot",
+        hint(LatencyClass::Moderate, PriceTier::Budget, Some(256)),
+    ),
+    (
+        "moonshot-intl",
+        hint(LatencyClass::Moderate, PriceTier::Budget, Some(256)),
+    ),
+    (
+        "glm",
+        hint(LatencyClass::Moderate, PriceTier::Budget, Some(128)),
+    ),
+    (
+        "glm-cn",
+        hint(LatencyClass::Moderate, PriceTier::Budget, Some(128)),
+    ),
+    (
+        "minimax",
+        hint(LatencyClass::Moderate, PriceTier::Budget, Some(1000)),
+    ),
+    (
+        "minimax-cn",
+        hint(LatencyClass::Moderate, PriceTier::Budget, Some(1000)),
+    ),
+    (
+        "qwen",
+        hint(LatencyClass::Moderate, PriceTier::Budget, Some(128)),
+    ),
+    (
+        "qwen-intl",
+        hint(LatencyClass::Moderate, PriceTier::Budget, Some(128)),
+    ),
+    (
+        "qwen-us",
+        hint(LatencyClass::Moderate, PriceTier::Budget, Some(128)),
+    ),
+    (
+        "qianfan",
+        hint(LatencyClass::Moderate, PriceTier::Budget, Some(128)),
+    ),
+    (
+        "zai",
+        hint(LatencyClass::Moderate, PriceTier::Subscription, Some(128)),
+    ),
+    (
+        "zai-cn",
+        hint(LatencyClass::Moderate, PriceTier::Subscription, Some(128)),
+    ),
+    (
+        "synthetic",
+        hint(LatencyClass::Moderate, PriceTier::Subscription, None),
+    ),
+    (
+        "opencode",
+        hint(LatencyClass::Varies, PriceTier::Varies, None),
+    ),
+    (
+        "cohere",
+        hint(LatencyClass::Moderate, PriceTier::Standard, Some(128)),
+    ),
+    ("ollama", hint(LatencyClass::Local, PriceTier::Free, None)),
+    ("llamacpp", hint(LatencyClass::Local, PriceTier::Free, None)),
+    ("sglang", hint(LatencyClass::Local, PriceTier::Free, None)),
+    ("vllm", hint(LatencyClass::Local, PriceTier::Free, None)),
+    ("osaurus", hint(LatencyClass::Local, PriceTier::Free, None)),
+];
+
+fn provider_hint(provider: &str) -> Option<ProviderHint> {
+    PROVIDER_HINTS
+        .iter()
+        .find(|(name, _)| *name == provider)
+        .map(|(_, hint)| *hint)
+}
+
+fn format_provider_hint(hint: ProviderHint) -> String {
+    let latency = match hint.latency {
+        LatencyClass::Fast => "fast",
+        LatencyClass::Moderate => "moderate",
+        LatencyClass::Varies => "latency varies",
+        LatencyClass::Local => "local hardware",
+    };
+    let price = match hint.price {
+        PriceTier::Free => "free",
+        PriceTier::Budget => "$",
+        PriceTier::Standard => "$$",
+        PriceTier::Premium => "$$$",
+        PriceTier::Subscription => "subscription",
+        PriceTier::Varies => "per model",
+    };
+    let context = match hint.context_k {
+        Some(k) if k >= 1000 && k % 1000 == 0 => format!("{}M ctx", k / 1000),
+        Some(k) => format!("{k}K ctx"),
+        None => "ctx varies".to_string(),
+    };
+    format!("{latency} · {price} · {context}")
+}
 
-    Ok((provider_name.to_string(), api_key, model, provider_api_url))
+/// Provider descriptions padded to a common width, followed by their hints.
+fn provider_choice_labels(providers: &[(&str, &str)]) -> Vec<String> {
+    let width = providers
+        .iter()
+        .map(|(_, label)| console::measure_text_width(label))
+        .max()
+        .unwrap_or(0);
+    providers
+        .iter()
+        .map(|(name, label)| match provider_hint(name) {
+            Some(hint) => {
+                let padding = width - console::measure_text_width(label);
+                format!(
+                    "{label}{}  {}",
+                    " ".repeat(padding),
+                    theme::muted(format_provider_hint(hint))
+                )
+            }
+            None => (*label).to_string(),
+        })
+        .collect()
 }
 
 fn local_provider_choices() -> Vec<(&'static str, &'static str)> {
@@ -2923,8 +4975,8 @@ fn setup_tool_mode() -> Result<(ComposioConfig, SecretsConfig)> {
         println!();
         println!(
             "  {} {}",
-            style("Composio Setup").white().bold(),
-            style("— 1000+ OAuth integrations (Gmail, Notion, GitHub, Slack, ...)").dim()
+            theme::strong("Composio Setup").bold(),
+            theme::muted("— 1000+ OAuth integrations (Gmail, Notion, GitHub, Slack, ...)")
         );
         print_bullet("Get your API key at: https://app.composio.dev/settings");
         print_bullet("ZeroClaw uses Composio as a tool — your core agent stays local.");
@@ -2938,14 +4990,14 @@ fn setup_tool_mode() -> Result<(ComposioConfig, SecretsConfig)> {
         if api_key.trim().is_empty() {
             println!(
                 "  {} Skipped — set composio.api_key in config.toml later",
-                style("→").dim()
+                theme::muted("→")
             );
             ComposioConfig::default()
         } else {
             println!(
                 "  {} Composio: {} (1000+ OAuth tools available)",
-                style("✓").green().bold(),
-                style("enabled").green()
+                theme::success("✓").bold(),
+                theme::success("enabled")
             );
             ComposioConfig {
                 enabled: true,
@@ -2956,39 +5008,224 @@ fn setup_tool_mode() -> Result<(ComposioConfig, SecretsConfig)> {
     } else {
         println!(
             "  {} Tool mode: {} — full privacy, you own every key",
-            style("✓").green().bold(),
-            style("Sovereign (local only)").green()
+            theme::success("✓").bold(),
+            theme::success("Sovereign (local only)")
         );
         ComposioConfig::default()
     };
 
-    // ── Encrypted secrets ──
+    // ── Encrypted secrets ──
+    println!();
+    print_bullet("ZeroClaw can encrypt API keys stored in config.toml.");
+    print_bullet("A local key file protects against plaintext exposure and accidental leaks.");
+
+    let encrypt = Confirm::new()
+        .with_prompt("  Enable encrypted secret storage?")
+        .default(true)
+        .interact()?;
+
+    let secrets_config = SecretsConfig { encrypt };
+
+    if encrypt {
+        println!(
+            "  {} Secrets: {} — keys encrypted with local key file",
+            theme::success("✓").bold(),
+            theme::success("encrypted")
+        );
+    } else {
+        println!(
+            "  {} Secrets: {} — keys stored as plaintext (not recommended)",
+            theme::success("✓").bold(),
+            theme::warning("plaintext")
+        );
+    }
+
+    Ok((composio_config, secrets_config))
+}
+
+// ── Step 4b: Security defaults ───────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SecurityPreset {
+    LockedDown,
+    Balanced,
+    Permissive,
+}
+
+impl SecurityPreset {
+    const ALL: [Self; 3] = [Self::LockedDown, Self::Balanced, Self::Permissive];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::LockedDown => "Locked down",
+            Self::Balanced => "Balanced",
+            Self::Permissive => "Permissive",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::LockedDown => "every tool call needs approval, shell off, 10 actions/hour",
+            Self::Balanced => {
+                "approve risky actions, curated shell allowlist, high-risk commands blocked (default)"
+            }
+            Self::Permissive => {
+                "no approval prompts, wider shell allowlist, high-risk commands still blocked"
+            }
+        }
+    }
+
+    fn autonomy(self) -> AutonomyConfig {
+        let balanced = AutonomyConfig::default();
+        match self {
+            Self::LockedDown => AutonomyConfig {
+                allowed_commands: Vec::new(),
+                max_actions_per_hour: 10,
+                auto_approve: Vec::new(),
+                always_ask: vec!["file_write".into(), "http_request".into()],
+                non_cli_excluded_tools: vec!["shell".into(), "file_write".into()],
+                ..balanced
+            },
+            Self::Balanced => balanced,
+            Self::Permissive => {
+                let mut allowed_commands = balanced.allowed_commands.clone();
+                allowed_commands.extend(
+                    [
+                        "python3", "node", "make", "sed", "awk", "sort", "diff", "curl",
+                    ]
+                    .map(String::from),
+                );
+                AutonomyConfig {
+                    level: crate::security::AutonomyLevel::Full,
+                    allowed_commands,
+                    max_actions_per_hour: 100,
+                    require_approval_for_medium_risk: false,
+                    ..balanced
+                }
+            }
+        }
+    }
+}
+
+fn setup_security() -> Result<SecurityPreset> {
+    print_bullet("Pick how much the agent may do without asking you first.");
+    print_bullet("Writes [autonomy] defaults; fine-tune them later in config.toml.");
     println!();
-    print_bullet("ZeroClaw can encrypt API keys stored in config.toml.");
-    print_bullet("A local key file protects against plaintext exposure and accidental leaks.");
 
-    let encrypt = Confirm::new()
-        .with_prompt("  Enable encrypted secret storage?")
-        .default(true)
+    let options: Vec<String> = SecurityPreset::ALL
+        .iter()
+        .map(|preset| format!("{} — {}", preset.name(), preset.description()))
+        .collect();
+    let choice = Select::new()
+        .with_prompt("  Select a security preset")
+        .items(&options)
+        .default(1)
         .interact()?;
+    let preset = SecurityPreset::ALL[choice];
 
-    let secrets_config = SecretsConfig { encrypt };
+    println!(
+        "  {} Security: {}",
+        theme::success("✓").bold(),
+        theme::success(preset.name())
+    );
+    Ok(preset)
+}
 
-    if encrypt {
-        println!(
-            "  {} Secrets: {} — keys encrypted with local key file",
-            style("✓").green().bold(),
-            style("encrypted").green()
-        );
+/// The base32 secret from an `otpauth://` URI, for typing in by hand.
+fn otpauth_secret(uri: &str) -> Option<&str> {
+    uri.split_once('?')?
+        .1
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("secret="))
+}
+
+/// Render `payload` as a QR code made of Unicode half blocks.
+fn render_qr(payload: &str) -> Result<String> {
+    let qr = qrcode::QrCode::new(payload.as_bytes())
+        .map_err(|err| anyhow::anyhow!("Failed to encode QR code: {err}"))?;
+    Ok(qr
+        .render::<qrcode::render::unicode::Dense1x2>()
+        .quiet_zone(true)
+        .build())
+}
+
+/// Offer TOTP gating for sensitive actions. The secret is created (or the
+/// existing one reused) next to `config_path`, shown as a QR code, and OTP is
+/// only enabled once the user has entered one valid code from their app.
+fn setup_otp(config_path: &Path, encrypt_secrets: bool) -> Result<OtpConfig> {
+    println!();
+    print_bullet("One-time passwords gate risky tools (shell, file writes, browser, forgetting");
+    print_bullet("memories) behind a 6-digit code from an authenticator app, so a hijacked");
+    print_bullet("chat channel cannot run them on its own.");
+
+    let enable = Confirm::new()
+        .with_prompt("  Protect sensitive actions with OTP (TOTP authenticator)?")
+        .default(false)
+        .interact()?;
+    if !enable {
+        return Ok(OtpConfig::default());
+    }
+
+    let config_dir = config_path
+        .parent()
+        .context("Config path must have a parent directory")?;
+    let otp_config = OtpConfig {
+        enabled: true,
+        ..OtpConfig::default()
+    };
+    let store = crate::security::SecretStore::new(config_dir, encrypt_secrets);
+    let (validator, generated) =
+        crate::security::OtpValidator::from_config(&otp_config, config_dir, &store)?;
+    let uri = validator.otpauth_uri();
+
+    println!();
+    if generated.is_none() {
+        print_bullet("Reusing the OTP secret already stored for this config.");
+    }
+    if prompt::is_plain() {
+        print_bullet("Add this account to your authenticator app:");
     } else {
+        print_bullet("Scan this code with your authenticator app:");
+        for line in render_qr(&uri)?.lines() {
+            println!("    {line}");
+        }
+    }
+    if let Some(secret) = otpauth_secret(&uri) {
         println!(
-            "  {} Secrets: {} — keys stored as plaintext (not recommended)",
-            style("✓").green().bold(),
-            style("plaintext").yellow()
+            "  {} {}",
+            theme::muted("Secret (manual entry):"),
+            theme::strong(secret)
         );
     }
+    println!("  {} {}", theme::muted("URI:"), theme::muted(&uri));
+    println!();
 
-    Ok((composio_config, secrets_config))
+    loop {
+        let code: String = Input::new()
+            .with_prompt("  Enter the 6-digit code your app shows")
+            .interact_text()?;
+        if validator.validate(&code)? {
+            println!(
+                "  {} OTP: {} for {}",
+                theme::success("✓").bold(),
+                theme::success("enabled"),
+                otp_config.gated_actions.join(", ")
+            );
+            return Ok(otp_config);
+        }
+        println!(
+            "  {} That code did not match. Check the app and your clock.",
+            theme::warning("!").bold()
+        );
+        let retry = Confirm::new()
+            .with_prompt("  Try another code? (No leaves OTP off)")
+            .default(true)
+            .interact()?;
+        if !retry {
+            println!("  {} OTP: {}", theme::muted("›"), theme::muted("off"));
+            return Ok(OtpConfig::default());
+        }
+    }
 }
 
 // ── Step 6: Hardware (Physical World) ───────────────────────────
@@ -3004,18 +5241,18 @@ fn setup_hardware() -> Result<HardwareConfig> {
     if devices.is_empty() {
         println!(
             "  {} {}",
-            style("ℹ").dim(),
-            style("No hardware devices detected on this system.").dim()
+            theme::muted("ℹ"),
+            theme::muted("No hardware devices detected on this system.")
         );
         println!(
             "  {} {}",
-            style("ℹ").dim(),
-            style("You can enable hardware later in config.toml under [hardware].").dim()
+            theme::muted("ℹ"),
+            theme::muted("You can enable hardware later in config.toml under [hardware].")
         );
     } else {
         println!(
             "  {} {} device(s) found:",
-            style("✓").green().bold(),
+            theme::success("✓").bold(),
             devices.len()
         );
         for device in &devices {
@@ -3031,32 +5268,48 @@ fn setup_hardware() -> Result<HardwareConfig> {
                 .unwrap_or_default();
             println!(
                 "    {} {}{}{} [{}]",
-                style("›").cyan(),
-                style(&device.name).green(),
-                style(&detail).dim(),
-                style(&path).dim(),
-                style(device.transport.to_string()).cyan()
+                theme::accent("›"),
+                theme::success(&device.name),
+                theme::muted(&detail),
+                theme::muted(&path),
+                theme::accent(device.transport.to_string())
             );
         }
     }
     println!();
 
-    let options = vec![
-        "🚀 Native — direct GPIO on this Linux board (Raspberry Pi, Orange Pi, etc.)",
-        "🔌 Tethered — control an Arduino/ESP32/Nucleo plugged into USB",
-        "🔬 Debug Probe — flash/read MCUs via SWD/JTAG (probe-rs)",
-        "☁️  Software Only — no hardware access (default)",
-    ];
+    let mut preset_labels: Vec<&str> = hardware::BoardPreset::ALL
+        .iter()
+        .map(|preset| preset.label())
+        .collect();
+    preset_labels.push("None — choose the connection manually");
+    let preset_idx = Select::new()
+        .with_prompt("  Start from a board preset?")
+        .items(&preset_labels)
+        .default(hardware::BoardPreset::ALL.len())
+        .interact()?;
+    let preset = hardware::BoardPreset::ALL.get(preset_idx).copied();
 
-    let recommended = hardware::recommended_wizard_default(&devices);
+    let mut hw_config = if let Some(preset) = preset {
+        preset.config(&devices)
+    } else {
+        let options = vec![
+            "🚀 Native — direct GPIO on this Linux board (Raspberry Pi, Orange Pi, etc.)",
+            "🔌 Tethered — control an Arduino/ESP32/Nucleo plugged into USB",
+            "🔬 Debug Probe — flash/read MCUs via SWD/JTAG (probe-rs)",
+            "☁️  Software Only — no hardware access (default)",
+        ];
 
-    let choice = Select::new()
-        .with_prompt("  How should ZeroClaw interact with the physical world?")
-        .items(&options)
-        .default(recommended)
-        .interact()?;
+        let recommended = hardware::recommended_wizard_default(&devices);
+
+        let choice = Select::new()
+            .with_prompt("  How should ZeroClaw interact with the physical world?")
+            .items(&options)
+            .default(recommended)
+            .interact()?;
 
-    let mut hw_config = hardware::config_from_wizard_choice(choice, &devices);
+        hardware::config_from_wizard_choice(choice, &devices)
+    };
 
     // ── Serial: pick a port if multiple found ──
     if hw_config.transport_mode() == hardware::HardwareTransport::Serial {
@@ -3086,14 +5339,26 @@ fn setup_hardware() -> Result<HardwareConfig> {
             hw_config.serial_port = serial_devices[port_idx].device_path.clone();
         } else if serial_devices.is_empty() {
             // User chose serial but no device discovered — ask for manual path
+            let example_port = if cfg!(windows) {
+                "COM3"
+            } else {
+                "/dev/ttyUSB0"
+            };
             let manual_port: String = Input::new()
-                .with_prompt("  Serial port path (e.g. /dev/ttyUSB0)")
-                .default("/dev/ttyUSB0".into())
+                .with_prompt(format!("  Serial port path (e.g. {example_port})"))
+                .default(
+                    hw_config
+                        .serial_port
+                        .clone()
+                        .unwrap_or_else(|| example_port.into()),
+                )
                 .interact_text()?;
             hw_config.serial_port = Some(manual_port);
         }
+    }
 
-        // Baud rate
+    // Baud rate (presets already carry the right one)
+    if hw_config.transport_mode() == hardware::HardwareTransport::Serial && preset.is_none() {
         let baud_options = vec![
             "115200 (default, recommended)",
             "9600 (legacy Arduino)",
@@ -3145,7 +5410,10 @@ fn setup_hardware() -> Result<HardwareConfig> {
     // ── Summary ──
     if hw_config.enabled {
         let transport_label = match hw_config.transport_mode() {
-            hardware::HardwareTransport::Native => "Native GPIO".to_string(),
+            hardware::HardwareTransport::Native => format!(
+                "Native GPIO ({})",
+                hw_config.gpio_chip.as_deref().unwrap_or("default chip")
+            ),
             hardware::HardwareTransport::Serial => format!(
                 "Serial → {} @ {} baud",
                 hw_config.serial_port.as_deref().unwrap_or("?"),
@@ -3160,25 +5428,95 @@ fn setup_hardware() -> Result<HardwareConfig> {
 
         println!(
             "  {} Hardware: {} | datasheets: {}",
-            style("✓").green().bold(),
-            style(&transport_label).green(),
+            theme::success("✓").bold(),
+            theme::success(&transport_label),
             if hw_config.workspace_datasheets {
-                style("on").green().to_string()
+                theme::success("on").to_string()
             } else {
-                style("off").dim().to_string()
+                theme::muted("off").to_string()
             }
         );
     } else {
         println!(
             "  {} Hardware: {}",
-            style("✓").green().bold(),
-            style("disabled (software only)").dim()
+            theme::success("✓").bold(),
+            theme::muted("disabled (software only)")
         );
     }
 
     Ok(hw_config)
 }
 
+// ── Step 6a: Emergency Stop ─────────────────────────────────────
+
+/// A trigger phrase answer; blank or `none` means no chat trigger.
+fn parse_trigger_phrase(raw: &str) -> Option<String> {
+    let phrase = raw.trim();
+    (!phrase.is_empty() && !phrase.eq_ignore_ascii_case("none")).then(|| phrase.to_string())
+}
+
+fn setup_estop(hardware_config: &HardwareConfig) -> Result<EstopConfig> {
+    print_bullet("The emergency stop (estop) freezes the agent: kill-all, network-kill,");
+    print_bullet("domain blocks or tool freezes, engaged with `zeroclaw estop`.");
+    println!();
+
+    let enabled = Confirm::new()
+        .with_prompt("  Enable the emergency stop?")
+        .default(false)
+        .interact()?;
+    if !enabled {
+        println!("  {} Estop: {}", theme::muted("›"), theme::muted("off"));
+        return Ok(EstopConfig::default());
+    }
+
+    print_bullet("A chat trigger phrase engages kill-all when any channel receives it.");
+    let phrase: String = Input::new()
+        .with_prompt("  Chat trigger phrase (\"none\" to disable)")
+        .default("/estop".into())
+        .interact_text()?;
+
+    let mut kill_switch_pin = None;
+    if hardware_config.enabled
+        && hardware_config.transport_mode() == hardware::HardwareTransport::Native
+    {
+        let bind = Confirm::new()
+            .with_prompt("  Bind a GPIO pin as a physical kill switch?")
+            .default(false)
+            .interact()?;
+        if bind {
+            print_bullet("Wire a normally-open switch between the pin and ground.");
+            let pin: u8 = Input::new()
+                .with_prompt("  BCM pin number")
+                .validate_with(|pin: &u8| -> Result<(), &str> {
+                    if *pin <= 27 {
+                        Ok(())
+                    } else {
+                        Err("BCM pins run from 0 to 27")
+                    }
+                })
+                .interact_text()?;
+            kill_switch_pin = Some(pin);
+        }
+    }
+
+    let config = EstopConfig {
+        enabled: true,
+        trigger_phrase: parse_trigger_phrase(&phrase),
+        kill_switch_pin,
+        ..EstopConfig::default()
+    };
+    println!(
+        "  {} Estop: {} | chat trigger: {} | kill switch: {}",
+        theme::success("✓").bold(),
+        theme::success("on"),
+        config.trigger_phrase.as_deref().unwrap_or("none"),
+        config
+            .kill_switch_pin
+            .map_or_else(|| "none".to_string(), |pin| format!("GPIO {pin}"))
+    );
+    Ok(config)
+}
+
 // ── Step 6: Project Context ─────────────────────────────────────
 
 fn setup_project_context() -> Result<ProjectContext> {
@@ -3262,11 +5600,11 @@ fn setup_project_context() -> Result<ProjectContext> {
 
     println!(
         "  {} Context: {} | {} | {} | {}",
-        style("✓").green().bold(),
-        style(&user_name).green(),
-        style(&timezone).green(),
-        style(&agent_name).green(),
-        style(&communication_style).green().dim()
+        theme::success("✓").bold(),
+        theme::success(&user_name),
+        theme::success(&timezone),
+        theme::success(&agent_name),
+        theme::success(&communication_style).dim()
     );
 
     Ok(ProjectContext {
@@ -3279,7 +5617,7 @@ fn setup_project_context() -> Result<ProjectContext> {
 
 // ── Step 6: Memory Configuration ───────────────────────────────
 
-fn setup_memory() -> Result<MemoryConfig> {
+async fn setup_memory() -> Result<MemoryConfig> {
     print_bullet("Choose how ZeroClaw stores and searches memories.");
     print_bullet("You can always change this later in config.toml.");
     println!();
@@ -3298,6 +5636,11 @@ fn setup_memory() -> Result<MemoryConfig> {
     let backend = backend_key_from_choice(choice);
     let profile = memory_backend_profile(backend);
 
+    let mut config = memory_config_defaults_for_backend(backend);
+    if classify_memory_backend(backend) == MemoryBackendKind::Qdrant {
+        config.qdrant = setup_qdrant_connection().await?;
+    }
+
     let auto_save = profile.auto_save_default
         && Confirm::new()
             .with_prompt("  Auto-save conversations to memory?")
@@ -3306,16 +5649,339 @@ fn setup_memory() -> Result<MemoryConfig> {
 
     println!(
         "  {} Memory: {} (auto-save: {})",
-        style("✓").green().bold(),
-        style(backend).green(),
+        theme::success("✓").bold(),
+        theme::success(backend),
         if auto_save { "on" } else { "off" }
     );
 
-    let mut config = memory_config_defaults_for_backend(backend);
     config.auto_save = auto_save;
     Ok(config)
 }
 
+const MEMORY_PROBE_TIMEOUT_SECS: u64 = 8;
+
+/// Ask for the Qdrant endpoint and API key, then check the server answers
+/// before moving on. Failed checks offer to re-enter the details or keep them.
+async fn setup_qdrant_connection() -> Result<QdrantConfig> {
+    let mut url = std::env::var("QDRANT_URL")
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| "http://localhost:6333".to_string());
+    let mut api_key = String::new();
+
+    loop {
+        url = Input::new()
+            .with_prompt("  Qdrant URL")
+            .with_initial_text(url.as_str())
+            .validate_with(|input: &String| -> Result<(), String> {
+                validate_qdrant_url(input).map(|_| ())
+            })
+            .interact_text()?;
+        url = validate_qdrant_url(&url).map_err(anyhow::Error::msg)?;
+        api_key = Input::new()
+            .with_prompt("  Qdrant API key (Enter for none)")
+            .with_initial_text(api_key.as_str())
+            .allow_empty(true)
+            .interact_text()?;
+        let api_key = api_key.trim();
+
+        println!("  {} Checking Qdrant at {url}...", theme::muted("⏳"));
+        match probe_qdrant(&url, (!api_key.is_empty()).then_some(api_key)).await {
+            Ok(summary) => {
+                println!(
+                    "  {} Qdrant reachable: {}",
+                    theme::success("✅").bold(),
+                    summary
+                );
+                break;
+            }
+            Err(error) => {
+                println!(
+                    "  {} Qdrant check failed: {error:#}",
+                    theme::error("✗").bold()
+                );
+                let choice = Select::new()
+                    .with_prompt("  How do you want to continue?")
+                    .items(["Re-enter URL and API key", "Keep these settings anyway"])
+                    .default(0)
+                    .interact()?;
+                if choice == 1 {
+                    break;
+                }
+            }
+        }
+    }
+
+    let api_key = api_key.trim();
+    Ok(QdrantConfig {
+        url: Some(url),
+        api_key: (!api_key.is_empty()).then(|| api_key.to_string()),
+        ..QdrantConfig::default()
+    })
+}
+
+fn validate_qdrant_url(input: &str) -> std::result::Result<String, String> {
+    let url = input.trim().trim_end_matches('/');
+    let parsed = reqwest::Url::parse(url).map_err(|error| format!("Invalid URL: {error}"))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err("Qdrant URL must start with http:// or https://".to_string());
+    }
+    Ok(url.to_string())
+}
+
+/// `GET /collections` exercises both reachability and the API key, which the
+/// root endpoint does not check on secured instances.
+async fn probe_qdrant(url: &str, api_key: Option<&str>) -> Result<String> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(MEMORY_PROBE_TIMEOUT_SECS))
+        .connect_timeout(Duration::from_secs(4))
+        .build()
+        .context("failed to build Qdrant probe client")?;
+    let mut request = client.get(format!("{url}/collections"));
+    if let Some(key) = api_key {
+        request = request.header("api-key", key);
+    }
+    let response = request
+        .send()
+        .await
+        .with_context(|| format!("could not reach {url}"))?;
+    let status = response.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+        bail!("server rejected the API key ({status})");
+    }
+    if !status.is_success() {
+        bail!("unexpected response ({status})");
+    }
+    let body: Value = response
+        .json()
+        .await
+        .context("response is not Qdrant JSON")?;
+    Ok(describe_qdrant_collections(&body))
+}
+
+fn describe_qdrant_collections(body: &Value) -> String {
+    let count = body
+        .pointer("/result/collections")
+        .and_then(Value::as_array)
+        .map_or(0, Vec::len);
+    match count {
+        1 => "1 existing collection".to_string(),
+        n => format!("{n} existing collections"),
+    }
+}
+
+// ── Step 6b: Embeddings ─────────────────────────────────────────
+
+/// Known embedding models per provider, with their output dimensions.
+const OPENAI_EMBEDDING_MODELS: &[(&str, usize)] = &[
+    ("text-embedding-3-small", 1536),
+    ("text-embedding-3-large", 3072),
+    ("text-embedding-ada-002", 1536),
+];
+const OPENROUTER_EMBEDDING_MODELS: &[(&str, usize)] = &[
+    ("openai/text-embedding-3-small", 1536),
+    ("openai/text-embedding-3-large", 3072),
+];
+
+/// `[[embedding_routes]]` hint used when embeddings need their own API key.
+const WIZARD_EMBEDDING_HINT: &str = "semantic";
+
+/// Embedding settings picked in the wizard, applied to `[memory]` when the
+/// config is built so re-running the Memory step does not discard them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct EmbeddingChoice {
+    /// `none`, `openai`, `openrouter`, or `custom:<url>`.
+    provider: String,
+    model: String,
+    dimensions: usize,
+    /// Key for the embedding endpoint when the main provider key does not
+    /// apply. Stored in an `[[embedding_routes]]` entry.
+    api_key: Option<String>,
+}
+
+impl Default for EmbeddingChoice {
+    fn default() -> Self {
+        let memory = MemoryConfig::default();
+        Self {
+            provider: "none".to_string(),
+            model: memory.embedding_model,
+            dimensions: memory.embedding_dimensions,
+            api_key: None,
+        }
+    }
+}
+
+impl EmbeddingChoice {
+    fn enabled(&self) -> bool {
+        self.provider != "none"
+    }
+
+    /// Write the choice into `memory` and return the routes it needs.
+    fn apply(&self, memory: &mut MemoryConfig) -> Vec<crate::config::EmbeddingRouteConfig> {
+        if !self.enabled() || !memory_backend_uses_embeddings(&memory.backend) {
+            return Vec::new();
+        }
+        memory.embedding_provider.clone_from(&self.provider);
+        memory.embedding_dimensions = self.dimensions;
+        let Some(api_key) = &self.api_key else {
+            memory.embedding_model.clone_from(&self.model);
+            return Vec::new();
+        };
+        memory.embedding_model = format!("hint:{WIZARD_EMBEDDING_HINT}");
+        vec![crate::config::EmbeddingRouteConfig {
+            hint: WIZARD_EMBEDDING_HINT.to_string(),
+            provider: self.provider.clone(),
+            model: self.model.clone(),
+            dimensions: Some(self.dimensions),
+            api_key: Some(api_key.clone()),
+        }]
+    }
+
+    fn summary(&self) -> String {
+        if self.enabled() {
+            format!(
+                "{} / {} ({} dims)",
+                self.provider, self.model, self.dimensions
+            )
+        } else {
+            "none (keyword search only)".to_string()
+        }
+    }
+}
+
+/// Backends that store vectors: the SQLite family and Qdrant.
+fn memory_backend_uses_embeddings(backend: &str) -> bool {
+    memory_backend_profile(backend).sqlite_based
+        || classify_memory_backend(backend) == MemoryBackendKind::Qdrant
+}
+
+fn setup_embeddings(
+    memory_backend: &str,
+    main_provider: &str,
+    main_api_key: &str,
+) -> Result<EmbeddingChoice> {
+    if !memory_backend_uses_embeddings(memory_backend) {
+        print_bullet(&format!(
+            "The {memory_backend} memory backend does not use embeddings — skipping."
+        ));
+        return Ok(EmbeddingChoice::default());
+    }
+
+    print_bullet("Embeddings let memory recall by meaning, not just matching keywords.");
+    print_bullet("Without them, recall falls back to keyword search.");
+    println!();
+
+    let options = [
+        "None — keyword search only",
+        "OpenAI — text-embedding-3 models",
+        "OpenRouter — OpenAI embedding models via OpenRouter",
+        "Custom OpenAI-compatible endpoint (Ollama, LM Studio, vLLM, ...)",
+    ];
+    let choice = Select::new()
+        .with_prompt("  Select embedding provider")
+        .items(options)
+        .default(match main_provider {
+            "openai" => 1,
+            "openrouter" => 2,
+            _ => 0,
+        })
+        .interact()?;
+
+    let (provider, model, dimensions) = match choice {
+        1 => {
+            let (model, dimensions) = select_embedding_model(OPENAI_EMBEDDING_MODELS)?;
+            ("openai".to_string(), model, dimensions)
+        }
+        2 => {
+            let (model, dimensions) = select_embedding_model(OPENROUTER_EMBEDDING_MODELS)?;
+            ("openrouter".to_string(), model, dimensions)
+        }
+        3 => {
+            let base_url: String = Input::new()
+                .with_prompt("  Embedding endpoint base URL")
+                .rule(Rule::Url)
+                .default("http://localhost:11434".to_string())
+                .interact_text()?;
+            let model: String = Input::new()
+                .with_prompt("  Embedding model")
+                .default("nomic-embed-text".to_string())
+                .interact_text()?;
+            let dimensions: usize = Input::new()
+                .with_prompt("  Embedding dimensions")
+                .default(768)
+                .validate_with(|value: &usize| -> Result<(), &str> {
+                    if *value == 0 {
+                        Err("Dimensions must be greater than zero")
+                    } else {
+                        Ok(())
+                    }
+                })
+                .interact_text()?;
+            (
+                format!("custom:{}", base_url.trim().trim_end_matches('/')),
+                model.trim().to_string(),
+                dimensions,
+            )
+        }
+        _ => {
+            println!(
+                "  {} Embeddings: {}",
+                theme::success("✓").bold(),
+                theme::success("none (keyword search only)")
+            );
+            return Ok(EmbeddingChoice::default());
+        }
+    };
+
+    let reuse_main_key = provider == main_provider
+        && !main_api_key.trim().is_empty()
+        && Confirm::new()
+            .with_prompt(format!(
+                "  Reuse your {main_provider} API key for embeddings?"
+            ))
+            .default(true)
+            .interact()?;
+    let api_key = if reuse_main_key {
+        None
+    } else {
+        let key_optional = provider.starts_with("custom:");
+        print_bullet(if key_optional {
+            "Paste an API key for this endpoint, or press Enter if it needs none."
+        } else {
+            "Paste an API key for the embedding provider."
+        });
+        let key = prompt_pasteable("  Embedding API key")?.trim().to_string();
+        (!key.is_empty()).then_some(key)
+    };
+
+    let embedding = EmbeddingChoice {
+        provider,
+        model,
+        dimensions,
+        api_key,
+    };
+    println!(
+        "  {} Embeddings: {}",
+        theme::success("✓").bold(),
+        theme::success(embedding.summary())
+    );
+    Ok(embedding)
+}
+
+fn select_embedding_model(models: &[(&str, usize)]) -> Result<(String, usize)> {
+    let labels: Vec<String> = models
+        .iter()
+        .map(|(model, dimensions)| format!("{model} ({dimensions} dims)"))
+        .collect();
+    let choice = Select::new()
+        .with_prompt("  Select embedding model")
+        .items(&labels)
+        .default(0)
+        .interact()?;
+    let (model, dimensions) = models[choice];
+    Ok((model.to_string(), dimensions))
+}
+
 // ── Step 3: Channels ────────────────────────────────────────────
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -3336,31 +6002,118 @@ enum ChannelMenuChoice {
     Lark,
     Feishu,
     Nostr,
+    /// An out-of-tree channel from the channel registry, by key.
+    Plugin(&'static str),
     Done,
 }
 
-const CHANNEL_MENU_CHOICES: &[ChannelMenuChoice] = &[
-    ChannelMenuChoice::Telegram,
-    ChannelMenuChoice::Discord,
-    ChannelMenuChoice::Slack,
-    ChannelMenuChoice::IMessage,
-    ChannelMenuChoice::Matrix,
-    ChannelMenuChoice::Signal,
-    ChannelMenuChoice::WhatsApp,
-    ChannelMenuChoice::Linq,
-    ChannelMenuChoice::Irc,
-    ChannelMenuChoice::Webhook,
-    ChannelMenuChoice::NextcloudTalk,
-    ChannelMenuChoice::DingTalk,
-    ChannelMenuChoice::QqOfficial,
-    ChannelMenuChoice::Lark,
-    ChannelMenuChoice::Feishu,
-    ChannelMenuChoice::Nostr,
-    ChannelMenuChoice::Done,
-];
+impl ChannelMenuChoice {
+    fn from_key(key: &'static str) -> Self {
+        match key {
+            "telegram" => Self::Telegram,
+            "discord" => Self::Discord,
+            "slack" => Self::Slack,
+            "imessage" => Self::IMessage,
+            "matrix" => Self::Matrix,
+            "signal" => Self::Signal,
+            "whatsapp" => Self::WhatsApp,
+            "linq" => Self::Linq,
+            "irc" => Self::Irc,
+            "webhook" => Self::Webhook,
+            "nextcloud_talk" => Self::NextcloudTalk,
+            "dingtalk" => Self::DingTalk,
+            "qq" => Self::QqOfficial,
+            "lark" => Self::Lark,
+            "feishu" => Self::Feishu,
+            "nostr" => Self::Nostr,
+            other => Self::Plugin(other),
+        }
+    }
+}
+
+/// Wizard menu, built from the channel registry plus a trailing `Done`.
+fn channel_menu_choices() -> Vec<ChannelMenuChoice> {
+    channel_registry::wizard_entries()
+        .iter()
+        .map(|entry| ChannelMenuChoice::from_key(entry.key))
+        .chain(std::iter::once(ChannelMenuChoice::Done))
+        .collect()
+}
+
+/// One row per entry. Entries arrive sorted by group, so the group name heads
+/// the first row of each section; `detail` fills the rest of the row.
+fn grouped_channel_rows(
+    entries: &[channel_registry::ChannelEntry],
+    detail: impl Fn(&channel_registry::ChannelEntry) -> String,
+) -> Vec<String> {
+    let mut previous = None;
+    entries
+        .iter()
+        .map(|entry| {
+            let heading = if previous == Some(entry.group) {
+                ""
+            } else {
+                entry.group.label()
+            };
+            previous = Some(entry.group);
+            format!("{heading:<16} {:<11} {}", entry.display_name, detail(entry))
+        })
+        .collect()
+}
+
+/// Description plus a badge when the channel needs a public webhook URL.
+fn channel_description(entry: &channel_registry::ChannelEntry) -> String {
+    if let Some(feature) = entry.missing_feature {
+        return format!("— not in this build (enable `{feature}`)");
+    }
+    let badge = if entry.needs_public_url {
+        "  [requires public URL]"
+    } else {
+        ""
+    };
+    format!("{}{badge}", entry.description)
+}
+
+fn channel_menu_label(entry: &channel_registry::ChannelEntry, channels: &ChannelsConfig) -> String {
+    if entry.missing_feature.is_none() && channel_registry::is_configured(entry.key, channels) {
+        entry.configured_label.to_string()
+    } else {
+        channel_description(entry)
+    }
+}
+
+/// Channels offered in the multi-select step (everything except `Done`).
+fn channel_selection_choices() -> Vec<ChannelMenuChoice> {
+    channel_menu_choices()
+        .iter()
+        .copied()
+        .filter(|choice| *choice != ChannelMenuChoice::Done)
+        .collect()
+}
+
+/// Map multi-select indices back to channels, in menu order and without duplicates.
+fn channel_selection_from_indices(indices: &[usize]) -> Vec<ChannelMenuChoice> {
+    let choices = channel_selection_choices();
+    let mut sorted = indices.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    sorted
+        .into_iter()
+        .filter_map(|idx| choices.get(idx).copied())
+        .collect()
+}
+
+/// ChannelSelection step: pick every channel to connect up front, then walk
+/// through token/aux entry for each one in turn.
+fn select_channels() -> Result<Vec<ChannelMenuChoice>> {
+    let labels = grouped_channel_rows(&channel_registry::wizard_entries(), channel_description);
+
+    let picked = MultiSelect::new()
+        .with_prompt("  Select channels to connect (Space to toggle, Enter to confirm)")
+        .items(&labels)
+        .interact()?;
 
-fn channel_menu_choices() -> &'static [ChannelMenuChoice] {
-    CHANNEL_MENU_CHOICES
+    Ok(channel_selection_from_indices(&picked))
 }
 
 #[allow(clippy::too_many_lines)]
@@ -3371,155 +6124,33 @@ fn setup_channels() -> Result<ChannelsConfig> {
 
     let mut config = ChannelsConfig::default();
     let menu_choices = channel_menu_choices();
+    let mut pending: VecDeque<ChannelMenuChoice> = select_channels()?.into();
 
     loop {
-        let options: Vec<String> = menu_choices
-            .iter()
-            .map(|choice| match choice {
-                ChannelMenuChoice::Telegram => format!(
-                    "Telegram   {}",
-                    if config.telegram.is_some() {
-                        "✅ connected"
-                    } else {
-                        "— connect your bot"
-                    }
-                ),
-                ChannelMenuChoice::Discord => format!(
-                    "Discord    {}",
-                    if config.discord.is_some() {
-                        "✅ connected"
-                    } else {
-                        "— connect your bot"
-                    }
-                ),
-                ChannelMenuChoice::Slack => format!(
-                    "Slack      {}",
-                    if config.slack.is_some() {
-                        "✅ connected"
-                    } else {
-                        "— connect your bot"
-                    }
-                ),
-                ChannelMenuChoice::IMessage => format!(
-                    "iMessage   {}",
-                    if config.imessage.is_some() {
-                        "✅ configured"
-                    } else {
-                        "— macOS only"
-                    }
-                ),
-                ChannelMenuChoice::Matrix => format!(
-                    "Matrix     {}",
-                    if config.matrix.is_some() {
-                        "✅ connected"
-                    } else {
-                        "— self-hosted chat"
-                    }
-                ),
-                ChannelMenuChoice::Signal => format!(
-                    "Signal     {}",
-                    if config.signal.is_some() {
-                        "✅ connected"
-                    } else {
-                        "— signal-cli daemon bridge"
-                    }
-                ),
-                ChannelMenuChoice::WhatsApp => format!(
-                    "WhatsApp   {}",
-                    if config.whatsapp.is_some() {
-                        "✅ connected"
-                    } else {
-                        "— Business Cloud API"
-                    }
-                ),
-                ChannelMenuChoice::Linq => format!(
-                    "Linq       {}",
-                    if config.linq.is_some() {
-                        "✅ connected"
-                    } else {
-                        "— iMessage/RCS/SMS via Linq API"
-                    }
-                ),
-                ChannelMenuChoice::Irc => format!(
-                    "IRC        {}",
-                    if config.irc.is_some() {
-                        "✅ configured"
-                    } else {
-                        "— IRC over TLS"
-                    }
-                ),
-                ChannelMenuChoice::Webhook => format!(
-                    "Webhook    {}",
-                    if config.webhook.is_some() {
-                        "✅ configured"
-                    } else {
-                        "— HTTP endpoint"
-                    }
-                ),
-                ChannelMenuChoice::NextcloudTalk => format!(
-                    "Nextcloud  {}",
-                    if config.nextcloud_talk.is_some() {
-                        "✅ connected"
-                    } else {
-                        "— Talk webhook + OCS API"
-                    }
-                ),
-                ChannelMenuChoice::DingTalk => format!(
-                    "DingTalk   {}",
-                    if config.dingtalk.is_some() {
-                        "✅ connected"
-                    } else {
-                        "— DingTalk Stream Mode"
-                    }
-                ),
-                ChannelMenuChoice::QqOfficial => format!(
-                    "QQ Official {}",
-                    if config.qq.is_some() {
-                        "✅ connected"
-                    } else {
-                        "— Tencent QQ Bot"
-                    }
-                ),
-                ChannelMenuChoice::Lark => format!(
-                    "Lark       {}",
-                    if config.lark.as_ref().is_some_and(|cfg| !cfg.use_feishu) {
-                        "✅ connected"
-                    } else {
-                        "— Lark Bot"
-                    }
-                ),
-                ChannelMenuChoice::Feishu => format!(
-                    "Feishu     {}",
-                    if config.feishu.is_some()
-                        || config.lark.as_ref().is_some_and(|cfg| cfg.use_feishu)
-                    {
-                        "✅ connected"
-                    } else {
-                        "— Feishu Bot"
-                    }
-                ),
-                ChannelMenuChoice::Nostr => format!(
-                    "Nostr {}",
-                    if config.nostr.is_some() {
-                        "✅ connected"
-                    } else {
-                        "     — Nostr DMs"
-                    }
-                ),
-                ChannelMenuChoice::Done => "Done — finish setup".to_string(),
-            })
-            .collect();
+        // Work through the multi-selected channels first; once they are all
+        // configured (or skipped), fall back to the menu to add more or finish.
+        let choice = if let Some(next) = pending.pop_front() {
+            next
+        } else {
+            let options: Vec<String> =
+                grouped_channel_rows(&channel_registry::wizard_entries(), |entry| {
+                    channel_menu_label(entry, &config)
+                })
+                .into_iter()
+                .chain(std::iter::once("Done — finish setup".to_string()))
+                .collect();
 
-        let selection = Select::new()
-            .with_prompt("  Connect a channel (or Done to continue)")
-            .items(&options)
-            .default(options.len() - 1)
-            .interact()?;
+            let selection = Select::new()
+                .with_prompt("  Connect a channel (or Done to continue)")
+                .items(&options)
+                .default(options.len() - 1)
+                .interact()?;
 
-        let choice = menu_choices
-            .get(selection)
-            .copied()
-            .unwrap_or(ChannelMenuChoice::Done);
+            menu_choices
+                .get(selection)
+                .copied()
+                .unwrap_or(ChannelMenuChoice::Done)
+        };
 
         match choice {
             ChannelMenuChoice::Telegram => {
@@ -3527,27 +6158,29 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 println!();
                 println!(
                     "  {} {}",
-                    style("Telegram Setup").white().bold(),
-                    style("— talk to ZeroClaw from Telegram").dim()
+                    theme::strong("Telegram Setup").bold(),
+                    theme::muted("— talk to ZeroClaw from Telegram")
                 );
                 print_bullet("1. Open Telegram and message @BotFather");
                 print_bullet("2. Send /newbot and follow the prompts");
                 print_bullet("3. Copy the bot token and paste it below");
                 println!();
 
-                let token: String = Input::new()
-                    .with_prompt("  Bot token (from @BotFather)")
-                    .interact_text()?;
+                let token = prompt_secret(
+                    "  Bot token (from @BotFather)",
+                    &["TELEGRAM_BOT_TOKEN", "ZEROCLAW_TELEGRAM_BOT_TOKEN"],
+                    Rule::TelegramToken,
+                )?;
 
-                if token.trim().is_empty() {
-                    println!("  {} Skipped", style("→").dim());
+                if token.value.trim().is_empty() {
+                    println!("  {} Skipped", theme::muted("→"));
                     continue;
                 }
 
                 // Test connection (run entirely in separate thread — reqwest::blocking Response
                 // must be used and dropped there to avoid "Cannot drop a runtime" panic)
-                print!("  {} Testing connection... ", style("⏳").dim());
-                let token_clone = token.clone();
+                print!("  {} Testing connection... ", theme::muted("⏳"));
+                let token_clone = token.value.clone();
                 let thread_result = std::thread::spawn(move || {
                     let client = reqwest::blocking::Client::new();
                     let url = format!("https://api.telegram.org/bot{token_clone}/getMe");
@@ -3567,13 +6200,13 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     Ok(Ok((true, bot_name))) => {
                         println!(
                             "\r  {} Connected as @{bot_name}        ",
-                            style("✅").green().bold()
+                            theme::success("✅").bold()
                         );
                     }
                     _ => {
                         println!(
                             "\r  {} Connection failed — check your token and try again",
-                            style("❌").red().bold()
+                            theme::error("❌").bold()
                         );
                         continue;
                     }
@@ -3607,17 +6240,19 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 if allowed_users.is_empty() {
                     println!(
                         "  {} No users allowlisted — Telegram inbound messages will be denied until you add your username/user ID or '*'.",
-                        style("⚠").yellow().bold()
+                        theme::warning("⚠").bold()
                     );
                 }
 
                 config.telegram = Some(TelegramConfig {
-                    bot_token: token,
+                    bot_token: token.stored,
                     allowed_users,
                     stream_mode: StreamMode::default(),
                     draft_update_interval_ms: 1000,
                     interrupt_on_new_message: false,
                     mention_only: false,
+                    quote_replies: false,
+                    lightweight_acks: false,
                 });
             }
             ChannelMenuChoice::Discord => {
@@ -3625,8 +6260,8 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 println!();
                 println!(
                     "  {} {}",
-                    style("Discord Setup").white().bold(),
-                    style("— talk to ZeroClaw from Discord").dim()
+                    theme::strong("Discord Setup").bold(),
+                    theme::muted("— talk to ZeroClaw from Discord")
                 );
                 print_bullet("1. Go to https://discord.com/developers/applications");
                 print_bullet("2. Create a New Application → Bot → Copy token");
@@ -3634,16 +6269,20 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 print_bullet("4. Invite bot to your server with messages permission");
                 println!();
 
-                let token: String = Input::new().with_prompt("  Bot token").interact_text()?;
+                let token = prompt_secret(
+                    "  Bot token",
+                    &["DISCORD_BOT_TOKEN", "ZEROCLAW_DISCORD_BOT_TOKEN"],
+                    Rule::Token("discord"),
+                )?;
 
-                if token.trim().is_empty() {
-                    println!("  {} Skipped", style("→").dim());
+                if token.value.trim().is_empty() {
+                    println!("  {} Skipped", theme::muted("→"));
                     continue;
                 }
 
                 // Test connection (run entirely in separate thread — Response must be used/dropped there)
-                print!("  {} Testing connection... ", style("⏳").dim());
-                let token_clone = token.clone();
+                print!("  {} Testing connection... ", theme::muted("⏳"));
+                let token_clone = token.value.clone();
                 let thread_result = std::thread::spawn(move || {
                     let client = reqwest::blocking::Client::new();
                     let resp = client
@@ -3664,13 +6303,13 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     Ok(Ok((true, bot_name))) => {
                         println!(
                             "\r  {} Connected as {bot_name}        ",
-                            style("✅").green().bold()
+                            theme::success("✅").bold()
                         );
                     }
                     _ => {
                         println!(
                             "\r  {} Connection failed — check your token and try again",
-                            style("❌").red().bold()
+                            theme::error("❌").bold()
                         );
                         continue;
                     }
@@ -3707,16 +6346,18 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 if allowed_users.is_empty() {
                     println!(
                         "  {} No users allowlisted — Discord inbound messages will be denied until you add IDs or '*'.",
-                        style("⚠").yellow().bold()
+                        theme::warning("⚠").bold()
                     );
                 }
 
                 config.discord = Some(DiscordConfig {
-                    bot_token: token,
+                    bot_token: token.stored,
                     guild_id: if guild.is_empty() { None } else { Some(guild) },
                     allowed_users,
                     listen_to_bots: false,
                     mention_only: false,
+                    quote_replies: false,
+                    lightweight_acks: false,
                 });
             }
             ChannelMenuChoice::Slack => {
@@ -3724,26 +6365,28 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 println!();
                 println!(
                     "  {} {}",
-                    style("Slack Setup").white().bold(),
-                    style("— talk to ZeroClaw from Slack").dim()
+                    theme::strong("Slack Setup").bold(),
+                    theme::muted("— talk to ZeroClaw from Slack")
                 );
                 print_bullet("1. Go to https://api.slack.com/apps → Create New App");
                 print_bullet("2. Add Bot Token Scopes: chat:write, channels:history");
                 print_bullet("3. Install to workspace and copy the Bot Token");
                 println!();
 
-                let token: String = Input::new()
-                    .with_prompt("  Bot token (xoxb-...)")
-                    .interact_text()?;
+                let token = prompt_secret(
+                    "  Bot token (xoxb-...)",
+                    &["SLACK_BOT_TOKEN"],
+                    Rule::Token("slack"),
+                )?;
 
-                if token.trim().is_empty() {
-                    println!("  {} Skipped", style("→").dim());
+                if token.value.trim().is_empty() {
+                    println!("  {} Skipped", theme::muted("→"));
                     continue;
                 }
 
                 // Test connection (run entirely in separate thread — Response must be used/dropped there)
-                print!("  {} Testing connection... ", style("⏳").dim());
-                let token_clone = token.clone();
+                print!("  {} Testing connection... ", theme::muted("⏳"));
+                let token_clone = token.value.clone();
                 let thread_result = std::thread::spawn(move || {
                     let client = reqwest::blocking::Client::new();
                     let resp = client
@@ -3773,17 +6416,17 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     Ok(Ok((true, true, team, _))) => {
                         println!(
                             "\r  {} Connected to workspace: {team}        ",
-                            style("✅").green().bold()
+                            theme::success("✅").bold()
                         );
                     }
                     Ok(Ok((true, false, _, err))) => {
-                        println!("\r  {} Slack error: {err}", style("❌").red().bold());
+                        println!("\r  {} Slack error: {err}", theme::error("❌").bold());
                         continue;
                     }
                     _ => {
                         println!(
                             "\r  {} Connection failed — check your token",
-                            style("❌").red().bold()
+                            theme::error("❌").bold()
                         );
                         continue;
                     }
@@ -3827,12 +6470,12 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 if allowed_users.is_empty() {
                     println!(
                         "  {} No users allowlisted — Slack inbound messages will be denied until you add IDs or '*'.",
-                        style("⚠").yellow().bold()
+                        theme::warning("⚠").bold()
                     );
                 }
 
                 config.slack = Some(SlackConfig {
-                    bot_token: token,
+                    bot_token: token.stored,
                     app_token: if app_token.is_empty() {
                         None
                     } else {
@@ -3844,6 +6487,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                         Some(channel)
                     },
                     allowed_users,
+                    quote_replies: true,
                 });
             }
             ChannelMenuChoice::IMessage => {
@@ -3851,14 +6495,14 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 println!();
                 println!(
                     "  {} {}",
-                    style("iMessage Setup").white().bold(),
-                    style("— macOS only, reads from Messages.app").dim()
+                    theme::strong("iMessage Setup").bold(),
+                    theme::muted("— macOS only, reads from Messages.app")
                 );
 
                 if !cfg!(target_os = "macos") {
                     println!(
                         "  {} iMessage is only available on macOS.",
-                        style("⚠").yellow().bold()
+                        theme::warning("⚠").bold()
                     );
                     continue;
                 }
@@ -3886,8 +6530,8 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 config.imessage = Some(IMessageConfig { allowed_contacts });
                 println!(
                     "  {} iMessage configured (contacts: {})",
-                    style("✅").green().bold(),
-                    style(&contacts_str).cyan()
+                    theme::success("✅").bold(),
+                    theme::accent(&contacts_str)
                 );
             }
             ChannelMenuChoice::Matrix => {
@@ -3895,8 +6539,8 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 println!();
                 println!(
                     "  {} {}",
-                    style("Matrix Setup").white().bold(),
-                    style("— self-hosted, federated chat").dim()
+                    theme::strong("Matrix Setup").bold(),
+                    theme::muted("— self-hosted, federated chat")
                 );
                 print_bullet("You need a Matrix account and an access token.");
                 print_bullet("Get a token via Element → Settings → Help & About → Access Token.");
@@ -3904,10 +6548,12 @@ fn setup_channels() -> Result<ChannelsConfig> {
 
                 let homeserver: String = Input::new()
                     .with_prompt("  Homeserver URL (e.g. https://matrix.org)")
+                    .allow_empty(true)
+                    .rule(Rule::OptionalUrl)
                     .interact_text()?;
 
                 if homeserver.trim().is_empty() {
-                    println!("  {} Skipped", style("→").dim());
+                    println!("  {} Skipped", theme::muted("→"));
                     continue;
                 }
 
@@ -3915,13 +6561,13 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     Input::new().with_prompt("  Access token").interact_text()?;
 
                 if access_token.trim().is_empty() {
-                    println!("  {} Skipped — token required", style("→").dim());
+                    println!("  {} Skipped — token required", theme::muted("→"));
                     continue;
                 }
 
                 // Test connection (run entirely in separate thread — Response must be used/dropped there)
                 let hs = homeserver.trim_end_matches('/');
-                print!("  {} Testing connection... ", style("⏳").dim());
+                print!("  {} Testing connection... ", theme::muted("⏳"));
                 let hs_owned = hs.to_string();
                 let access_token_clone = access_token.clone();
                 let thread_result = std::thread::spawn(move || {
@@ -3957,13 +6603,13 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     Ok(Ok((true, user_id, device_id))) => {
                         println!(
                             "\r  {} Connection verified        ",
-                            style("✅").green().bold()
+                            theme::success("✅").bold()
                         );
 
                         if device_id.is_none() {
                             println!(
                                 "  {} Homeserver did not return device_id from whoami. If E2EE decryption fails, set channels.matrix.device_id manually in config.toml.",
-                                style("⚠️").yellow().bold()
+                                theme::warning("⚠️").bold()
                             );
                         }
 
@@ -3972,7 +6618,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     _ => {
                         println!(
                             "\r  {} Connection failed — check homeserver URL and token",
-                            style("❌").red().bold()
+                            theme::error("❌").bold()
                         );
                         continue;
                     }
@@ -4007,8 +6653,8 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 println!();
                 println!(
                     "  {} {}",
-                    style("Signal Setup").white().bold(),
-                    style("— signal-cli daemon bridge").dim()
+                    theme::strong("Signal Setup").bold(),
+                    theme::muted("— signal-cli daemon bridge")
                 );
                 print_bullet("1. Run signal-cli daemon with HTTP enabled (default port 8686).");
                 print_bullet("2. Ensure your Signal account is registered in signal-cli.");
@@ -4017,20 +6663,24 @@ fn setup_channels() -> Result<ChannelsConfig> {
 
                 let http_url: String = Input::new()
                     .with_prompt("  signal-cli HTTP URL")
+                    .allow_empty(true)
+                    .rule(Rule::OptionalUrl)
                     .default("http://127.0.0.1:8686".into())
                     .interact_text()?;
 
                 if http_url.trim().is_empty() {
-                    println!("  {} Skipped — HTTP URL required", style("→").dim());
+                    println!("  {} Skipped — HTTP URL required", theme::muted("→"));
                     continue;
                 }
 
                 let account: String = Input::new()
                     .with_prompt("  Account number (E.164, e.g. +1234567890)")
+                    .allow_empty(true)
+                    .rule(Rule::OptionalE164)
                     .interact_text()?;
 
                 if account.trim().is_empty() {
-                    println!("  {} Skipped — account number required", style("→").dim());
+                    println!("  {} Skipped — account number required", theme::muted("→"));
                     continue;
                 }
 
@@ -4052,7 +6702,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                             Input::new().with_prompt("  Group ID").interact_text()?;
                         let group_input = group_input.trim().to_string();
                         if group_input.is_empty() {
-                            println!("  {} Skipped — group ID required", style("→").dim());
+                            println!("  {} Skipped — group ID required", theme::muted("→"));
                             continue;
                         }
                         Some(group_input)
@@ -4096,12 +6746,12 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     ignore_stories,
                 });
 
-                println!("  {} Signal configured", style("✅").green().bold());
+                println!("  {} Signal configured", theme::success("✅").bold());
             }
             ChannelMenuChoice::WhatsApp => {
                 // ── WhatsApp ──
                 println!();
-                println!("  {}", style("WhatsApp Setup").white().bold());
+                println!("  {}", theme::strong("WhatsApp Setup").bold());
 
                 let mode_options = vec![
                     "WhatsApp Web (QR / pair-code, no Meta Business API)",
@@ -4114,7 +6764,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     .interact()?;
 
                 if mode_idx == 0 {
-                    println!("  {}", style("Mode: WhatsApp Web").dim());
+                    println!("  {}", theme::muted("Mode: WhatsApp Web"));
                     print_bullet("1. Build with --features whatsapp-web");
                     print_bullet(
                         "2. Start channel/daemon and scan QR in WhatsApp > Linked Devices",
@@ -4128,7 +6778,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                         .interact_text()?;
 
                     if session_path.trim().is_empty() {
-                        println!("  {} Skipped — session path required", style("→").dim());
+                        println!("  {} Skipped — session path required", theme::muted("→"));
                         continue;
                     }
 
@@ -4178,15 +6828,15 @@ fn setup_channels() -> Result<ChannelsConfig> {
 
                     println!(
                         "  {} WhatsApp Web configuration saved.",
-                        style("✅").green().bold()
+                        theme::success("✅").bold()
                     );
                     continue;
                 }
 
                 println!(
                     "  {} {}",
-                    style("Mode:").dim(),
-                    style("Business Cloud API").dim()
+                    theme::muted("Mode:"),
+                    theme::muted("Business Cloud API")
                 );
                 print_bullet("1. Go to developers.facebook.com and create a WhatsApp app");
                 print_bullet("2. Add the WhatsApp product and get your phone number ID");
@@ -4199,7 +6849,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     .interact_text()?;
 
                 if access_token.trim().is_empty() {
-                    println!("  {} Skipped", style("→").dim());
+                    println!("  {} Skipped", theme::muted("→"));
                     continue;
                 }
 
@@ -4208,7 +6858,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     .interact_text()?;
 
                 if phone_number_id.trim().is_empty() {
-                    println!("  {} Skipped — phone number ID required", style("→").dim());
+                    println!("  {} Skipped — phone number ID required", theme::muted("→"));
                     continue;
                 }
 
@@ -4218,7 +6868,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     .interact_text()?;
 
                 // Test connection (run entirely in separate thread — Response must be used/dropped there)
-                print!("  {} Testing connection... ", style("⏳").dim());
+                print!("  {} Testing connection... ", theme::muted("⏳"));
                 let phone_number_id_clone = phone_number_id.clone();
                 let access_token_clone = access_token.clone();
                 let thread_result = std::thread::spawn(move || {
@@ -4241,13 +6891,13 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     Ok(Ok(true)) => {
                         println!(
                             "\r  {} Connected to WhatsApp API        ",
-                            style("✅").green().bold()
+                            theme::success("✅").bold()
                         );
                     }
                     _ => {
                         println!(
                             "\r  {} Connection failed — check access token and phone number ID",
-                            style("❌").red().bold()
+                            theme::error("❌").bold()
                         );
                         continue;
                     }
@@ -4282,8 +6932,8 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 println!();
                 println!(
                     "  {} {}",
-                    style("Linq Setup").white().bold(),
-                    style("— iMessage/RCS/SMS via Linq API").dim()
+                    theme::strong("Linq Setup").bold(),
+                    theme::muted("— iMessage/RCS/SMS via Linq API")
                 );
                 print_bullet("1. Sign up at linqapp.com and get your Partner API token");
                 print_bullet("2. Note your Linq phone number (E.164 format)");
@@ -4295,21 +6945,23 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     .interact_text()?;
 
                 if api_token.trim().is_empty() {
-                    println!("  {} Skipped", style("→").dim());
+                    println!("  {} Skipped", theme::muted("→"));
                     continue;
                 }
 
                 let from_phone: String = Input::new()
                     .with_prompt("  From phone number (E.164 format, e.g. +12223334444)")
+                    .allow_empty(true)
+                    .rule(Rule::OptionalE164)
                     .interact_text()?;
 
                 if from_phone.trim().is_empty() {
-                    println!("  {} Skipped — phone number required", style("→").dim());
+                    println!("  {} Skipped — phone number required", theme::muted("→"));
                     continue;
                 }
 
                 // Test connection
-                print!("  {} Testing connection... ", style("⏳").dim());
+                print!("  {} Testing connection... ", theme::muted("⏳"));
                 let api_token_clone = api_token.clone();
                 let thread_result = std::thread::spawn(move || {
                     let client = reqwest::blocking::Client::new();
@@ -4328,13 +6980,13 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     Ok(Ok(true)) => {
                         println!(
                             "\r  {} Connected to Linq API              ",
-                            style("✅").green().bold()
+                            theme::success("✅").bold()
                         );
                     }
                     _ => {
                         println!(
                             "\r  {} Connection failed — check API token",
-                            style("❌").red().bold()
+                            theme::error("❌").bold()
                         );
                         continue;
                     }
@@ -4374,8 +7026,8 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 println!();
                 println!(
                     "  {} {}",
-                    style("IRC Setup").white().bold(),
-                    style("— IRC over TLS").dim()
+                    theme::strong("IRC Setup").bold(),
+                    theme::muted("— IRC over TLS")
                 );
                 print_bullet("IRC connects over TLS to any IRC server");
                 print_bullet("Supports SASL PLAIN and NickServ authentication");
@@ -4386,19 +7038,20 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     .interact_text()?;
 
                 if server.trim().is_empty() {
-                    println!("  {} Skipped", style("→").dim());
+                    println!("  {} Skipped", theme::muted("→"));
                     continue;
                 }
 
                 let port_str: String = Input::new()
                     .with_prompt("  Port")
+                    .rule(Rule::Port)
                     .default("6697".into())
                     .interact_text()?;
 
                 let port: u16 = match port_str.trim().parse() {
                     Ok(p) => p,
                     Err(_) => {
-                        println!("  {} Invalid port, using 6697", style("→").dim());
+                        println!("  {} Invalid port, using 6697", theme::muted("→"));
                         6697
                     }
                 };
@@ -4407,7 +7060,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     Input::new().with_prompt("  Bot nickname").interact_text()?;
 
                 if nickname.trim().is_empty() {
-                    println!("  {} Skipped — nickname required", style("→").dim());
+                    println!("  {} Skipped — nickname required", theme::muted("→"));
                     continue;
                 }
 
@@ -4477,10 +7130,10 @@ fn setup_channels() -> Result<ChannelsConfig> {
 
                 println!(
                     "  {} IRC configured as {}@{}:{}",
-                    style("✅").green().bold(),
-                    style(&nickname).cyan(),
-                    style(&server).cyan(),
-                    style(port).cyan()
+                    theme::success("✅").bold(),
+                    theme::accent(&nickname),
+                    theme::accent(&server),
+                    theme::accent(port)
                 );
 
                 config.irc = Some(IrcConfig {
@@ -4513,14 +7166,11 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 println!();
                 println!(
                     "  {} {}",
-                    style("Webhook Setup").white().bold(),
-                    style("— HTTP endpoint for custom integrations").dim()
+                    theme::strong("Webhook Setup").bold(),
+                    theme::muted("— HTTP endpoint for custom integrations")
                 );
 
-                let port: String = Input::new()
-                    .with_prompt("  Port")
-                    .default("8080".into())
-                    .interact_text()?;
+                let port = prompt_listen_port("  Port", 8080)?;
 
                 let secret: String = Input::new()
                     .with_prompt("  Secret (optional, Enter to skip)")
@@ -4528,7 +7178,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     .interact_text()?;
 
                 config.webhook = Some(WebhookConfig {
-                    port: port.parse().unwrap_or(8080),
+                    port,
                     secret: if secret.is_empty() {
                         None
                     } else {
@@ -4537,8 +7187,8 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 });
                 println!(
                     "  {} Webhook on port {}",
-                    style("✅").green().bold(),
-                    style(&port).cyan()
+                    theme::success("✅").bold(),
+                    theme::accent(&port)
                 );
             }
             ChannelMenuChoice::NextcloudTalk => {
@@ -4546,8 +7196,8 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 println!();
                 println!(
                     "  {} {}",
-                    style("Nextcloud Talk Setup").white().bold(),
-                    style("— Talk webhook receive + OCS API send").dim()
+                    theme::strong("Nextcloud Talk Setup").bold(),
+                    theme::muted("— Talk webhook receive + OCS API send")
                 );
                 print_bullet("1. Configure your Nextcloud Talk bot app and app token.");
                 print_bullet("2. Set webhook URL to: https://<your-public-url>/nextcloud-talk");
@@ -4558,11 +7208,13 @@ fn setup_channels() -> Result<ChannelsConfig> {
 
                 let base_url: String = Input::new()
                     .with_prompt("  Nextcloud base URL (e.g. https://cloud.example.com)")
+                    .allow_empty(true)
+                    .rule(Rule::OptionalUrl)
                     .interact_text()?;
 
                 let base_url = base_url.trim().trim_end_matches('/').to_string();
                 if base_url.is_empty() {
-                    println!("  {} Skipped — base URL required", style("→").dim());
+                    println!("  {} Skipped — base URL required", theme::muted("→"));
                     continue;
                 }
 
@@ -4571,7 +7223,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     .interact_text()?;
 
                 if app_token.trim().is_empty() {
-                    println!("  {} Skipped — app token required", style("→").dim());
+                    println!("  {} Skipped — app token required", theme::muted("→"));
                     continue;
                 }
 
@@ -4606,15 +7258,18 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     allowed_users,
                 });
 
-                println!("  {} Nextcloud Talk configured", style("✅").green().bold());
+                println!(
+                    "  {} Nextcloud Talk configured",
+                    theme::success("✅").bold()
+                );
             }
             ChannelMenuChoice::DingTalk => {
                 // ── DingTalk ──
                 println!();
                 println!(
                     "  {} {}",
-                    style("DingTalk Setup").white().bold(),
-                    style("— DingTalk Stream Mode").dim()
+                    theme::strong("DingTalk Setup").bold(),
+                    theme::muted("— DingTalk Stream Mode")
                 );
                 print_bullet("1. Go to DingTalk developer console (open.dingtalk.com)");
                 print_bullet("2. Create an app and enable the Stream Mode bot");
@@ -4626,7 +7281,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     .interact_text()?;
 
                 if client_id.trim().is_empty() {
-                    println!("  {} Skipped", style("→").dim());
+                    println!("  {} Skipped", theme::muted("→"));
                     continue;
                 }
 
@@ -4635,7 +7290,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     .interact_text()?;
 
                 // Test connection
-                print!("  {} Testing connection... ", style("⏳").dim());
+                print!("  {} Testing connection... ", theme::muted("⏳"));
                 let client = reqwest::blocking::Client::new();
                 let body = serde_json::json!({
                     "clientId": client_id,
@@ -4649,13 +7304,13 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     Ok(resp) if resp.status().is_success() => {
                         println!(
                             "\r  {} DingTalk credentials verified        ",
-                            style("✅").green().bold()
+                            theme::success("✅").bold()
                         );
                     }
                     _ => {
                         println!(
                             "\r  {} Connection failed — check your credentials",
-                            style("❌").red().bold()
+                            theme::error("❌").bold()
                         );
                         continue;
                     }
@@ -4683,8 +7338,8 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 println!();
                 println!(
                     "  {} {}",
-                    style("QQ Official Setup").white().bold(),
-                    style("— Tencent QQ Bot SDK").dim()
+                    theme::strong("QQ Official Setup").bold(),
+                    theme::muted("— Tencent QQ Bot SDK")
                 );
                 print_bullet("1. Go to QQ Bot developer console (q.qq.com)");
                 print_bullet("2. Create a bot application");
@@ -4694,7 +7349,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 let app_id: String = Input::new().with_prompt("  App ID").interact_text()?;
 
                 if app_id.trim().is_empty() {
-                    println!("  {} Skipped", style("→").dim());
+                    println!("  {} Skipped", theme::muted("→"));
                     continue;
                 }
 
@@ -4702,7 +7357,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     Input::new().with_prompt("  App Secret").interact_text()?;
 
                 // Test connection
-                print!("  {} Testing connection... ", style("⏳").dim());
+                print!("  {} Testing connection... ", theme::muted("⏳"));
                 let client = reqwest::blocking::Client::new();
                 let body = serde_json::json!({
                     "appId": app_id,
@@ -4718,12 +7373,12 @@ fn setup_channels() -> Result<ChannelsConfig> {
                         if data.get("access_token").is_some() {
                             println!(
                                 "\r  {} QQ Bot credentials verified        ",
-                                style("✅").green().bold()
+                                theme::success("✅").bold()
                             );
                         } else {
                             println!(
                                 "\r  {} Auth error — check your credentials",
-                                style("❌").red().bold()
+                                theme::error("❌").bold()
                             );
                             continue;
                         }
@@ -4731,7 +7386,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     _ => {
                         println!(
                             "\r  {} Connection failed — check your credentials",
-                            style("❌").red().bold()
+                            theme::error("❌").bold()
                         );
                         continue;
                     }
@@ -4772,8 +7427,8 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 println!();
                 println!(
                     "  {} {}",
-                    style(format!("{provider_label} Setup")).white().bold(),
-                    style(format!("— talk to ZeroClaw from {provider_label}")).dim()
+                    theme::strong(format!("{provider_label} Setup")).bold(),
+                    theme::muted(format!("— talk to ZeroClaw from {provider_label}"))
                 );
                 print_bullet(&format!(
                     "1. Go to {provider_label} Open Platform ({provider_host})"
@@ -4786,7 +7441,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 let app_id = app_id.trim().to_string();
 
                 if app_id.trim().is_empty() {
-                    println!("  {} Skipped", style("→").dim());
+                    println!("  {} Skipped", theme::muted("→"));
                     continue;
                 }
 
@@ -4795,12 +7450,12 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 let app_secret = app_secret.trim().to_string();
 
                 if app_secret.is_empty() {
-                    println!("  {} App Secret is required", style("❌").red().bold());
+                    println!("  {} App Secret is required", theme::error("❌").bold());
                     continue;
                 }
 
                 // Test connection (run entirely in separate thread — Response must be used/dropped there)
-                print!("  {} Testing connection... ", style("⏳").dim());
+                print!("  {} Testing connection... ", theme::muted("⏳"));
                 let app_id_clone = app_id.clone();
                 let app_secret_clone = app_secret.clone();
                 let endpoint = format!("{base_url}/auth/v3/tenant_access_token/internal");
@@ -4847,21 +7502,21 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     Ok(Ok(())) => {
                         println!(
                             "\r  {} {provider_label} credentials verified        ",
-                            style("✅").green().bold()
+                            theme::success("✅").bold()
                         );
                     }
                     Ok(Err(reason)) => {
                         println!(
                             "\r  {} Connection failed — check your credentials",
-                            style("❌").red().bold()
+                            theme::error("❌").bold()
                         );
-                        println!("    {}", style(reason).dim());
+                        println!("    {}", theme::muted(reason));
                         continue;
                     }
                     Err(_) => {
                         println!(
                             "\r  {} Connection failed — check your credentials",
-                            style("❌").red().bold()
+                            theme::error("❌").bold()
                         );
                         continue;
                     }
@@ -4899,16 +7554,12 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 if receive_mode == LarkReceiveMode::Webhook && verification_token.is_none() {
                     println!(
                         "  {} Verification Token is empty — webhook authenticity checks are reduced.",
-                        style("⚠").yellow().bold()
+                        theme::warning("⚠").bold()
                     );
                 }
 
                 let port = if receive_mode == LarkReceiveMode::Webhook {
-                    let p: String = Input::new()
-                        .with_prompt("  Webhook Port")
-                        .default("8080".into())
-                        .interact_text()?;
-                    Some(p.parse().unwrap_or(8080))
+                    Some(prompt_listen_port("  Webhook Port", 8080)?)
                 } else {
                     None
                 };
@@ -4927,7 +7578,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 if allowed_users.is_empty() {
                     println!(
                         "  {} No users allowlisted — {provider_label} inbound messages will be denied until you add Open IDs or '*'.",
-                        style("⚠").yellow().bold()
+                        theme::warning("⚠").bold()
                     );
                 }
 
@@ -4948,8 +7599,8 @@ fn setup_channels() -> Result<ChannelsConfig> {
                 println!();
                 println!(
                     "  {} {}",
-                    style("Nostr Setup").white().bold(),
-                    style("— private messages via NIP-04 & NIP-17").dim()
+                    theme::strong("Nostr Setup").bold(),
+                    theme::muted("— private messages via NIP-04 & NIP-17")
                 );
                 print_bullet("ZeroClaw will listen for encrypted DMs on Nostr relays.");
                 print_bullet("You need a Nostr private key (hex or nsec) and at least one relay.");
@@ -4960,27 +7611,32 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     .interact_text()?;
 
                 if private_key.trim().is_empty() {
-                    println!("  {} Skipped", style("→").dim());
+                    println!("  {} Skipped", theme::muted("→"));
                     continue;
                 }
 
                 // Validate the key immediately
+                #[cfg(feature = "channel-nostr")]
                 match nostr_sdk::Keys::parse(private_key.trim()) {
                     Ok(keys) => {
                         println!(
                             "  {} Key valid — public key: {}",
-                            style("✅").green().bold(),
-                            style(keys.public_key().to_hex()).cyan()
+                            theme::success("✅").bold(),
+                            theme::accent(keys.public_key().to_hex())
                         );
                     }
                     Err(_) => {
                         println!(
                             "  {} Invalid private key — check format and try again",
-                            style("❌").red().bold()
+                            theme::error("❌").bold()
                         );
                         continue;
                     }
                 }
+                #[cfg(not(feature = "channel-nostr"))]
+                print_bullet(
+                    "This build lacks `channel-nostr`; the key is checked once a Nostr-enabled build starts.",
+                );
 
                 let default_relays = default_nostr_relays().join(",");
                 let relays_str: String = Input::new()
@@ -4994,66 +7650,570 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     .filter(|s| !s.is_empty())
                     .collect();
 
-                print_bullet("Allowlist pubkeys that can message the bot (hex or npub).");
-                print_bullet("Use '*' to allow anyone (not recommended for production).");
+                print_bullet("Allowlist pubkeys that can message the bot (hex or npub).");
+                print_bullet("Use '*' to allow anyone (not recommended for production).");
+
+                let pubkeys_str: String = Input::new()
+                    .with_prompt("  Allowed pubkeys (comma-separated, or * for all)")
+                    .allow_empty(true)
+                    .interact_text()?;
+
+                let allowed_pubkeys: Vec<String> = if pubkeys_str.trim() == "*" {
+                    vec!["*".into()]
+                } else {
+                    pubkeys_str
+                        .split(',')
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect()
+                };
+
+                if allowed_pubkeys.is_empty() {
+                    println!(
+                        "  {} No pubkeys allowlisted — inbound messages will be denied until you add pubkeys or '*'.",
+                        theme::warning("⚠").bold()
+                    );
+                }
+
+                config.nostr = Some(NostrConfig {
+                    private_key: private_key.trim().to_string(),
+                    relays: relays.clone(),
+                    allowed_pubkeys,
+                });
+
+                println!(
+                    "  {} Nostr configured with {} relay(s)",
+                    theme::success("✅").bold(),
+                    theme::accent(relays.len())
+                );
+            }
+            ChannelMenuChoice::Plugin(key) => {
+                let Some(plugin) = channel_registry::find_plugin(key) else {
+                    continue;
+                };
+                println!();
+                println!(
+                    "  {} {}",
+                    theme::strong(format!("{} Setup", plugin.display_name())).bold(),
+                    theme::muted(plugin.wizard_hint())
+                );
+
+                let Some(settings) = plugin.wizard_setup()? else {
+                    println!("  {} Skipped", theme::muted("→"));
+                    continue;
+                };
+                config.plugins.insert(key.to_string(), settings);
+                println!(
+                    "  {} {} configured",
+                    theme::success("✅").bold(),
+                    plugin.display_name()
+                );
+            }
+            ChannelMenuChoice::Done => break,
+        }
+        println!();
+    }
+
+    offer_test_message(&config)?;
+
+    // Summary line
+    let channels = config.channels();
+    let channels = channels
+        .iter()
+        .filter_map(|(channel, ok)| ok.then_some(channel.name()));
+    let channels: Vec<_> = std::iter::once("Cli")
+        .chain(channels)
+        .chain(config.plugins.keys().map(String::as_str))
+        .collect();
+    let active = channels.join(", ");
+
+    println!(
+        "  {} Channels: {}",
+        theme::success("✓").bold(),
+        theme::success(active)
+    );
+
+    Ok(config)
+}
+
+// ── Channel test message ─────────────────────────────────────────
+
+const TEST_MESSAGE_TEXT: &str =
+    "👋 Hello from ZeroClaw! This test message confirms your channel credentials work.";
+
+/// Configured channels whose adapters can post a one-off message.
+fn test_message_channels(config: &ChannelsConfig) -> Vec<&'static str> {
+    [
+        ("telegram", config.telegram.is_some()),
+        ("discord", config.discord.is_some()),
+        ("slack", config.slack.is_some()),
+        ("mattermost", config.mattermost.is_some()),
+    ]
+    .into_iter()
+    .filter_map(|(name, configured)| configured.then_some(name))
+    .collect()
+}
+
+fn test_message_recipient_prompt(channel: &str) -> &'static str {
+    match channel {
+        "telegram" => "  Telegram chat ID (your numeric user ID for a DM)",
+        "discord" => "  Discord channel ID",
+        "slack" => "  Slack channel ID (e.g. C0123456789)",
+        _ => "  Mattermost channel ID",
+    }
+}
+
+fn default_test_message_recipient(config: &ChannelsConfig, channel: &str) -> Option<String> {
+    match channel {
+        "slack" => config.slack.as_ref()?.channel_id.clone(),
+        "mattermost" => config.mattermost.as_ref()?.channel_id.clone(),
+        _ => None,
+    }
+}
+
+/// Offer to post a hello message through each freshly configured adapter.
+fn offer_test_message(config: &ChannelsConfig) -> Result<()> {
+    let channels = test_message_channels(config);
+    if channels.is_empty() {
+        return Ok(());
+    }
+
+    let options: Vec<String> = channels
+        .iter()
+        .map(|channel| format!("Send a test message via {channel}"))
+        .chain(std::iter::once("Continue".to_string()))
+        .collect();
+
+    loop {
+        let selection = Select::new()
+            .with_prompt("  Send a test message to confirm your credentials?")
+            .items(&options)
+            .default(options.len() - 1)
+            .interact()?;
+        let Some(channel) = channels.get(selection).copied() else {
+            return Ok(());
+        };
+
+        let mut input = Input::<String>::new()
+            .with_prompt(test_message_recipient_prompt(channel))
+            .allow_empty(true);
+        if let Some(default) = default_test_message_recipient(config, channel) {
+            input = input.default(default);
+        }
+        let recipient = input.interact_text()?;
+        let recipient = recipient.trim();
+        if recipient.is_empty() {
+            println!("  {} Skipped", theme::muted("→"));
+            continue;
+        }
+
+        print!("  {} Sending test message... ", theme::muted("⏳"));
+        match send_test_message(config, channel, recipient) {
+            Ok(()) => println!(
+                "\r  {} Test message delivered via {channel}        ",
+                theme::success("✅").bold()
+            ),
+            Err(error) => println!(
+                "\r  {} Test message failed: {error}",
+                theme::error("❌").bold()
+            ),
+        }
+    }
+}
+
+fn send_test_message(config: &ChannelsConfig, channel: &str, recipient: &str) -> Result<()> {
+    let mut full = Config {
+        channels_config: config.clone(),
+        ..Config::default()
+    };
+    // Tokens may be stored as `env:NAME` references at this point.
+    full.resolve_env_secret_refs()?;
+    let secret = match channel {
+        "telegram" => full
+            .channels_config
+            .telegram
+            .as_ref()
+            .map(|c| c.bot_token.clone()),
+        "discord" => full
+            .channels_config
+            .discord
+            .as_ref()
+            .map(|c| c.bot_token.clone()),
+        "slack" => full
+            .channels_config
+            .slack
+            .as_ref()
+            .map(|c| c.bot_token.clone()),
+        _ => full
+            .channels_config
+            .mattermost
+            .as_ref()
+            .map(|c| c.bot_token.clone()),
+    };
+
+    let channel = channel.to_string();
+    let recipient = recipient.to_string();
+    // Run on a separate thread with its own runtime; the wizard may already be
+    // inside one.
+    let result = std::thread::spawn(move || {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?
+            .block_on(crate::cron::scheduler::deliver_announcement(
+                &full,
+                &channel,
+                &recipient,
+                TEST_MESSAGE_TEXT,
+            ))
+    })
+    .join()
+    .map_err(|_| anyhow::anyhow!("test message thread panicked"))?;
+
+    result.map_err(|error| {
+        let mut message = error.to_string();
+        if let Some(secret) = secret.filter(|secret| !secret.is_empty()) {
+            message = message.replace(&secret, "[REDACTED]");
+        }
+        anyhow::anyhow!(crate::providers::sanitize_api_error(&message))
+    })
+}
+
+// ── Step 3b: Scheduled jobs ─────────────────────────────────────
+
+const SCHEDULE_PRESETS: &[(&str, &str)] = &[
+    ("Every day at 09:00", "0 9 * * *"),
+    ("Weekdays at 09:00", "0 9 * * 1-5"),
+    ("Every Monday at 08:00", "0 8 * * 1"),
+    ("Every hour", "0 * * * *"),
+];
+
+/// Configured channels the scheduler can deliver a job's reply to.
+fn schedule_delivery_channels(channels: &ChannelsConfig) -> Vec<&'static str> {
+    [
+        ("telegram", channels.telegram.is_some()),
+        ("discord", channels.discord.is_some()),
+        ("slack", channels.slack.is_some()),
+        ("mattermost", channels.mattermost.is_some()),
+    ]
+    .into_iter()
+    .filter_map(|(name, configured)| configured.then_some(name))
+    .collect()
+}
+
+fn prompt_schedule_expression() -> Result<(String, Option<String>)> {
+    let mut options: Vec<String> = SCHEDULE_PRESETS
+        .iter()
+        .map(|(label, expr)| format!("{label} ({expr})"))
+        .collect();
+    options.push("Custom cron expression".to_string());
+    let choice = Select::new()
+        .with_prompt("  When should it run?")
+        .items(&options)
+        .default(0)
+        .interact()?;
+
+    loop {
+        let expr = match SCHEDULE_PRESETS.get(choice) {
+            Some((_, expr)) => (*expr).to_string(),
+            None => Input::<String>::new()
+                .with_prompt("  Cron expression (minute hour day month weekday)")
+                .interact_text()?
+                .trim()
+                .to_string(),
+        };
+        let tz: String = Input::new()
+            .with_prompt("  Timezone (IANA name, e.g. Europe/Berlin; Enter for UTC)")
+            .allow_empty(true)
+            .interact_text()?;
+        let tz = Some(tz.trim().to_string()).filter(|tz| !tz.is_empty());
+
+        let schedule = crate::cron::Schedule::Cron {
+            expr: expr.clone(),
+            tz: tz.clone(),
+        };
+        match crate::cron::validate_schedule(&schedule, chrono::Utc::now()) {
+            Ok(()) => return Ok((expr, tz)),
+            Err(e) => println!("  {} {e}", theme::error("✗").bold()),
+        }
+    }
+}
+
+fn setup_schedules(channels: &ChannelsConfig) -> Result<crate::config::SchedulesConfig> {
+    print_bullet("Schedule recurring agent jobs, e.g. a morning summary sent to Telegram.");
+    print_bullet("Saved under [schedules]; the daemon registers them when it starts.");
+    println!();
+
+    let targets = schedule_delivery_channels(channels);
+    let mut jobs: Vec<crate::config::ScheduledJobConfig> = Vec::new();
+    loop {
+        let add = Confirm::new()
+            .with_prompt(if jobs.is_empty() {
+                "  Add a scheduled job?"
+            } else {
+                "  Add another scheduled job?"
+            })
+            .default(false)
+            .interact()?;
+        if !add {
+            break;
+        }
+
+        let name = loop {
+            let name: String = Input::new()
+                .with_prompt("  Job name")
+                .default(if jobs.is_empty() {
+                    "daily-summary".to_string()
+                } else {
+                    format!("job-{}", jobs.len() + 1)
+                })
+                .interact_text()?;
+            let name = name.trim().to_string();
+            if name.is_empty() {
+                println!("  {} Name must not be empty", theme::error("✗").bold());
+            } else if jobs.iter().any(|job| job.name == name) {
+                println!("  {} `{name}` is already used", theme::error("✗").bold());
+            } else {
+                break name;
+            }
+        };
+        let (cron, tz) = prompt_schedule_expression()?;
+        let prompt: String = Input::new()
+            .with_prompt("  Prompt for the agent")
+            .default("Summarize what happened yesterday and list today's priorities.".to_string())
+            .interact_text()?;
+
+        let mut options: Vec<String> = targets.iter().map(|name| (*name).to_string()).collect();
+        options.push("No delivery (only record the run)".to_string());
+        let choice = Select::new()
+            .with_prompt("  Send the reply to")
+            .items(&options)
+            .default(0)
+            .interact()?;
+        let (channel, to) = match targets.get(choice) {
+            Some(channel) => {
+                let to: String = Input::new()
+                    .with_prompt(format!(
+                        "  Recipient on {channel} (chat, user, or channel ID)"
+                    ))
+                    .interact_text()?;
+                (Some((*channel).to_string()), Some(to.trim().to_string()))
+            }
+            None => (None, None),
+        };
+
+        println!(
+            "  {} {} → {}",
+            theme::success("✓").bold(),
+            theme::success(&name),
+            theme::muted(format!(
+                "{cron}{}",
+                channel
+                    .as_deref()
+                    .map(|channel| format!(", sent to {channel}"))
+                    .unwrap_or_default()
+            ))
+        );
+        jobs.push(crate::config::ScheduledJobConfig {
+            name,
+            cron,
+            tz,
+            prompt: prompt.trim().to_string(),
+            channel,
+            to,
+        });
+    }
+
+    if jobs.is_empty() {
+        println!(
+            "  {} {}",
+            theme::muted("ℹ"),
+            theme::muted(
+                "No scheduled jobs. Add them later under [schedules] or with `zeroclaw cron`."
+            )
+        );
+    }
+    Ok(crate::config::SchedulesConfig { jobs })
+}
+
+// ── Step 4: Tunnel ──────────────────────────────────────────────
 
-                let pubkeys_str: String = Input::new()
-                    .with_prompt("  Allowed pubkeys (comma-separated, or * for all)")
-                    .allow_empty(true)
-                    .interact_text()?;
+const TUNNEL_VERIFY_TIMEOUT_SECS: u64 = 60;
 
-                let allowed_pubkeys: Vec<String> = if pubkeys_str.trim() == "*" {
-                    vec!["*".into()]
-                } else {
-                    pubkeys_str
-                        .split(',')
-                        .map(|s| s.trim().to_string())
-                        .filter(|s| !s.is_empty())
-                        .collect()
-                };
+enum TunnelCheck {
+    Skipped,
+    Verified(String),
+    Failed,
+}
 
-                if allowed_pubkeys.is_empty() {
-                    println!(
-                        "  {} No pubkeys allowlisted — inbound messages will be denied until you add pubkeys or '*'.",
-                        style("⚠").yellow().bold()
-                    );
+/// Run the tunnel step, then start the tunnel once to prove it works before
+/// the config is written. A failed check lets the user reconfigure.
+async fn setup_verified_tunnel() -> Result<(crate::config::TunnelConfig, Option<String>)> {
+    loop {
+        let config = setup_tunnel()?;
+        warn_if_gateway_port_taken(&config);
+        match verify_tunnel(&config).await? {
+            TunnelCheck::Skipped => return Ok((config, None)),
+            TunnelCheck::Verified(url) => return Ok((config, Some(url))),
+            TunnelCheck::Failed => {
+                let keep = Confirm::new()
+                    .with_prompt("  Keep this tunnel configuration anyway?")
+                    .default(false)
+                    .interact()?;
+                if keep {
+                    return Ok((config, None));
                 }
-
-                config.nostr = Some(NostrConfig {
-                    private_key: private_key.trim().to_string(),
-                    relays: relays.clone(),
-                    allowed_pubkeys,
-                });
-
-                println!(
-                    "  {} Nostr configured with {} relay(s)",
-                    style("✅").green().bold(),
-                    style(relays.len()).cyan()
-                );
+                println!();
             }
-            ChannelMenuChoice::Done => break,
         }
-        println!();
     }
+}
 
-    // Summary line
-    let channels = config.channels();
-    let channels = channels
-        .iter()
-        .filter_map(|(channel, ok)| ok.then_some(channel.name()));
-    let channels: Vec<_> = std::iter::once("Cli").chain(channels).collect();
-    let active = channels.join(", ");
+async fn verify_tunnel(config: &crate::config::TunnelConfig) -> Result<TunnelCheck> {
+    let Some(tunnel) = crate::tunnel::create_tunnel(config)? else {
+        return Ok(TunnelCheck::Skipped);
+    };
+
+    let verify = Confirm::new()
+        .with_prompt(format!(
+            "  Start {} now to verify the public URL?",
+            tunnel.name()
+        ))
+        .default(true)
+        .interact()?;
+    if !verify {
+        return Ok(TunnelCheck::Skipped);
+    }
 
     println!(
-        "  {} Channels: {}",
-        style("✓").green().bold(),
-        style(active).green()
+        "  {} Starting {} tunnel...",
+        theme::muted("⏳"),
+        tunnel.name()
     );
+    let port = crate::config::GatewayConfig::default().port;
+    let started = tokio::time::timeout(
+        Duration::from_secs(TUNNEL_VERIFY_TIMEOUT_SECS),
+        tunnel.start("127.0.0.1", port),
+    )
+    .await;
+    let result = match started {
+        Ok(Ok(url)) => match resolve_tunnel_url(&url).await {
+            Ok(()) => Ok(url),
+            Err(error) => Err(error),
+        },
+        Ok(Err(error)) => Err(error),
+        Err(_) => Err(anyhow::anyhow!(
+            "tunnel did not report a public URL within {TUNNEL_VERIFY_TIMEOUT_SECS}s"
+        )),
+    };
+    if let Err(error) = tunnel.stop().await {
+        tracing::warn!("Failed to stop verification tunnel: {error}");
+    }
 
-    Ok(config)
+    match result {
+        Ok(url) => {
+            println!(
+                "  {} Tunnel verified: {}",
+                theme::success("✅").bold(),
+                theme::success(&url)
+            );
+            Ok(TunnelCheck::Verified(url))
+        }
+        Err(error) => {
+            println!(
+                "  {} Tunnel check failed: {error}",
+                theme::error("❌").bold()
+            );
+            Ok(TunnelCheck::Failed)
+        }
+    }
 }
 
-// ── Step 4: Tunnel ──────────────────────────────────────────────
+/// `host:port` to resolve for a tunnel's public URL.
+fn tunnel_lookup_target(url: &str) -> Result<String> {
+    let parsed = reqwest::Url::parse(url).with_context(|| format!("invalid tunnel URL: {url}"))?;
+    let host = parsed
+        .host_str()
+        .filter(|host| !host.is_empty())
+        .with_context(|| format!("tunnel URL has no host: {url}"))?;
+    let port = parsed.port_or_known_default().unwrap_or(443);
+    Ok(format!("{host}:{port}"))
+}
+
+async fn resolve_tunnel_url(url: &str) -> Result<()> {
+    let target = tunnel_lookup_target(url)?;
+    let mut addrs = tokio::net::lookup_host(&target)
+        .await
+        .with_context(|| format!("{target} does not resolve"))?;
+    if addrs.next().is_none() {
+        bail!("{target} resolved to no addresses");
+    }
+    Ok(())
+}
+
+/// How many ports above a taken one to probe for a free suggestion.
+const FREE_PORT_SCAN_RANGE: u16 = 100;
+
+/// Whether something on this machine already listens on `port`.
+fn port_in_use(port: u16) -> bool {
+    std::net::TcpListener::bind(("127.0.0.1", port)).is_err()
+}
+
+/// First free port after `taken`, for the "address already in use" warning.
+fn suggest_free_port(taken: u16) -> Option<u16> {
+    (1..=FREE_PORT_SCAN_RANGE)
+        .filter_map(|offset| taken.checked_add(offset))
+        .find(|&port| !port_in_use(port))
+}
+
+/// Ask for a local listen port, warning when it is already bound and
+/// offering the next free one instead.
+fn prompt_listen_port(prompt: &str, default: u16) -> Result<u16> {
+    let raw: String = Input::new()
+        .with_prompt(prompt)
+        .rule(Rule::Port)
+        .default(default.to_string())
+        .interact_text()?;
+    let port = raw.trim().parse().unwrap_or(default);
+    if !port_in_use(port) {
+        return Ok(port);
+    }
+
+    println!(
+        "  {} Port {port} is already in use on this machine.",
+        theme::warning("⚠").bold()
+    );
+    let Some(free) = suggest_free_port(port) else {
+        return Ok(port);
+    };
+    let switch = Confirm::new()
+        .with_prompt(format!("  Use free port {free} instead?"))
+        .default(true)
+        .interact()?;
+    Ok(if switch { free } else { port })
+}
+
+/// Tunnels forward to the gateway, so a taken gateway port breaks both on
+/// first start. Warn before the config is written.
+fn warn_if_gateway_port_taken(config: &crate::config::TunnelConfig) {
+    if config.provider.is_empty() || config.provider == "none" {
+        return;
+    }
+    let port = crate::config::GatewayConfig::default().port;
+    if !port_in_use(port) {
+        return;
+    }
+    println!(
+        "  {} Gateway port {port} is already in use — the gateway and tunnel will fail to start.",
+        theme::warning("⚠").bold()
+    );
+    if let Some(free) = suggest_free_port(port) {
+        println!(
+            "    Stop the other process, or set {} in config.toml (or run {}).",
+            theme::accent(format!("[gateway] port = {free}")),
+            theme::accent(format!("zeroclaw gateway --port {free}"))
+        );
+    }
+}
 
 #[allow(clippy::too_many_lines)]
 fn setup_tunnel() -> Result<crate::config::TunnelConfig> {
@@ -5088,13 +8248,13 @@ fn setup_tunnel() -> Result<crate::config::TunnelConfig> {
                 .with_prompt("  Cloudflare tunnel token")
                 .interact_text()?;
             if tunnel_value.trim().is_empty() {
-                println!("  {} Skipped", style("→").dim());
+                println!("  {} Skipped", theme::muted("→"));
                 TunnelConfig::default()
             } else {
                 println!(
                     "  {} Tunnel: {}",
-                    style("✓").green().bold(),
-                    style("Cloudflare").green()
+                    theme::success("✓").bold(),
+                    theme::success("Cloudflare")
                 );
                 TunnelConfig {
                     provider: "cloudflare".into(),
@@ -5114,8 +8274,8 @@ fn setup_tunnel() -> Result<crate::config::TunnelConfig> {
                 .interact()?;
             println!(
                 "  {} Tunnel: {} ({})",
-                style("✓").green().bold(),
-                style("Tailscale").green(),
+                theme::success("✓").bold(),
+                theme::success("Tailscale"),
                 if funnel {
                     "Funnel — public"
                 } else {
@@ -5140,7 +8300,7 @@ fn setup_tunnel() -> Result<crate::config::TunnelConfig> {
                 .with_prompt("  ngrok auth token")
                 .interact_text()?;
             if auth_token.trim().is_empty() {
-                println!("  {} Skipped", style("→").dim());
+                println!("  {} Skipped", theme::muted("→"));
                 TunnelConfig::default()
             } else {
                 let domain: String = Input::new()
@@ -5149,8 +8309,8 @@ fn setup_tunnel() -> Result<crate::config::TunnelConfig> {
                     .interact_text()?;
                 println!(
                     "  {} Tunnel: {}",
-                    style("✓").green().bold(),
-                    style("ngrok").green()
+                    theme::success("✓").bold(),
+                    theme::success("ngrok")
                 );
                 TunnelConfig {
                     provider: "ngrok".into(),
@@ -5175,14 +8335,14 @@ fn setup_tunnel() -> Result<crate::config::TunnelConfig> {
                 .with_prompt("  Start command")
                 .interact_text()?;
             if cmd.trim().is_empty() {
-                println!("  {} Skipped", style("→").dim());
+                println!("  {} Skipped", theme::muted("→"));
                 TunnelConfig::default()
             } else {
                 println!(
                     "  {} Tunnel: {} ({})",
-                    style("✓").green().bold(),
-                    style("Custom").green(),
-                    style(&cmd).dim()
+                    theme::success("✓").bold(),
+                    theme::success("Custom"),
+                    theme::muted(&cmd)
                 );
                 TunnelConfig {
                     provider: "custom".into(),
@@ -5198,8 +8358,8 @@ fn setup_tunnel() -> Result<crate::config::TunnelConfig> {
         _ => {
             println!(
                 "  {} Tunnel: {}",
-                style("✓").green().bold(),
-                style("none (local only)").dim()
+                theme::success("✓").bold(),
+                theme::muted("none (local only)")
             );
             TunnelConfig::default()
         }
@@ -5211,7 +8371,7 @@ fn setup_tunnel() -> Result<crate::config::TunnelConfig> {
 // ── Step 6: Scaffold workspace files ─────────────────────────────
 
 #[allow(clippy::too_many_lines)]
-async fn scaffold_workspace(workspace_dir: &Path, ctx: &ProjectContext) -> Result<()> {
+pub(super) async fn scaffold_workspace(workspace_dir: &Path, ctx: &ProjectContext) -> Result<()> {
     let agent = if ctx.agent_name.is_empty() {
         "ZeroClaw"
     } else {
@@ -5458,21 +8618,21 @@ async fn scaffold_workspace(workspace_dir: &Path, ctx: &ProjectContext) -> Resul
 
     println!(
         "  {} Created {} files, skipped {} existing | {} subdirectories",
-        style("✓").green().bold(),
-        style(created).green(),
-        style(skipped).dim(),
-        style(subdirs.len()).green()
+        theme::success("✓").bold(),
+        theme::success(created),
+        theme::muted(skipped),
+        theme::success(subdirs.len())
     );
 
     // Show workspace tree
     println!();
-    println!("  {}", style("Workspace layout:").dim());
+    println!("  {}", theme::muted("Workspace layout:"));
     println!(
         "  {}",
-        style(format!("  {}/", workspace_dir.display())).dim()
+        theme::muted(format!("  {}/", workspace_dir.display()))
     );
     for dir in &subdirs {
-        println!("  {}", style(format!("  ├── {dir}/")).dim());
+        println!("  {}", theme::muted(format!("  ├── {dir}/")));
     }
     for (i, (filename, _)) in files.iter().enumerate() {
         let prefix = if i == files.len() - 1 {
@@ -5480,7 +8640,7 @@ async fn scaffold_workspace(workspace_dir: &Path, ctx: &ProjectContext) -> Resul
         } else {
             "├──"
         };
-        println!("  {}", style(format!("  {prefix} {filename}")).dim());
+        println!("  {}", theme::muted(format!("  {prefix} {filename}")));
     }
 
     Ok(())
@@ -5495,42 +8655,42 @@ fn print_summary(config: &Config) {
     println!();
     println!(
         "  {}",
-        style("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━").cyan()
+        theme::accent("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━")
     );
     println!(
         "  {}  {}",
-        style("⚡").cyan(),
-        style("ZeroClaw is ready!").white().bold()
+        theme::accent("⚡"),
+        theme::strong("ZeroClaw is ready!").bold()
     );
     println!(
         "  {}",
-        style("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━").cyan()
+        theme::accent("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━")
     );
     println!();
 
-    println!("  {}", style("Configuration saved to:").dim());
-    println!("    {}", style(config.config_path.display()).green());
+    println!("  {}", theme::muted("Configuration saved to:"));
+    println!("    {}", theme::success(config.config_path.display()));
     println!();
 
-    println!("  {}", style("Quick summary:").white().bold());
+    println!("  {}", theme::strong("Quick summary:").bold());
     println!(
         "    {} Provider:      {}",
-        style("🤖").cyan(),
+        theme::accent("🤖"),
         config.default_provider.as_deref().unwrap_or("openrouter")
     );
     println!(
         "    {} Model:         {}",
-        style("🧠").cyan(),
+        theme::accent("🧠"),
         config.default_model.as_deref().unwrap_or("(default)")
     );
     println!(
         "    {} Autonomy:      {:?}",
-        style("🛡️").cyan(),
+        theme::accent("🛡️"),
         config.autonomy.level
     );
     println!(
         "    {} Memory:        {} (auto-save: {})",
-        style("🧠").cyan(),
+        theme::accent("🧠"),
         config.memory.backend,
         if config.memory.auto_save { "on" } else { "off" }
     );
@@ -5544,26 +8704,24 @@ fn print_summary(config: &Config) {
 
     println!(
         "    {} Channels:      {}",
-        style("📡").cyan(),
+        theme::accent("📡"),
         channels.join(", ")
     );
 
     println!(
         "    {} API Key:       {}",
-        style("🔑").cyan(),
+        theme::accent("🔑"),
         if config.api_key.is_some() {
-            style("configured").green().to_string()
+            theme::success("configured").to_string()
         } else {
-            style("not set (set via env var or config)")
-                .yellow()
-                .to_string()
+            theme::warning("not set (set via env var or config)").to_string()
         }
     );
 
     // Tunnel
     println!(
         "    {} Tunnel:        {}",
-        style("🌐").cyan(),
+        theme::accent("🌐"),
         if config.tunnel.provider == "none" || config.tunnel.provider.is_empty() {
             "none (local only)".to_string()
         } else {
@@ -5574,21 +8732,21 @@ fn print_summary(config: &Config) {
     // Composio
     println!(
         "    {} Composio:      {}",
-        style("🔗").cyan(),
+        theme::accent("🔗"),
         if config.composio.enabled {
-            style("enabled (1000+ OAuth apps)").green().to_string()
+            theme::success("enabled (1000+ OAuth apps)").to_string()
         } else {
             "disabled (sovereign mode)".to_string()
         }
     );
 
     // Secrets
-    println!("    {} Secrets:       configured", style("🔒").cyan());
+    println!("    {} Secrets:       configured", theme::accent("🔒"));
 
     // Gateway
     println!(
         "    {} Gateway:       {}",
-        style("🚪").cyan(),
+        theme::accent("🚪"),
         if config.gateway.require_pairing {
             "pairing required (secure)"
         } else {
@@ -5599,29 +8757,27 @@ fn print_summary(config: &Config) {
     // Hardware
     println!(
         "    {} Hardware:      {}",
-        style("🔌").cyan(),
+        theme::accent("🔌"),
         if config.hardware.enabled {
             let mode = config.hardware.transport_mode();
             match mode {
                 hardware::HardwareTransport::Native => {
-                    style("Native GPIO (direct)").green().to_string()
+                    theme::success("Native GPIO (direct)").to_string()
                 }
                 hardware::HardwareTransport::Serial => format!(
                     "{}",
-                    style(format!(
+                    theme::success(format!(
                         "Serial → {} @ {} baud",
                         config.hardware.serial_port.as_deref().unwrap_or("?"),
                         config.hardware.baud_rate
                     ))
-                    .green()
                 ),
                 hardware::HardwareTransport::Probe => format!(
                     "{}",
-                    style(format!(
+                    theme::success(format!(
                         "Probe → {}",
                         config.hardware.probe_target.as_deref().unwrap_or("?")
                     ))
-                    .green()
                 ),
                 hardware::HardwareTransport::None => "disabled (software only)".to_string(),
             }
@@ -5631,7 +8787,7 @@ fn print_summary(config: &Config) {
     );
 
     println!();
-    println!("  {}", style("Next steps:").white().bold());
+    println!("  {}", theme::strong("Next steps:").bold());
     println!();
 
     let mut step = 1u8;
@@ -5641,37 +8797,36 @@ fn print_summary(config: &Config) {
         if provider == "openai-codex" {
             println!(
                 "    {} Authenticate OpenAI Codex:",
-                style(format!("{step}.")).cyan().bold()
+                theme::accent(format!("{step}.")).bold()
             );
             println!(
                 "       {}",
-                style("zeroclaw auth login --provider openai-codex --device-code").yellow()
+                theme::warning("zeroclaw auth login --provider openai-codex --device-code")
             );
         } else if provider == "anthropic" {
             println!(
                 "    {} Configure Anthropic auth:",
-                style(format!("{step}.")).cyan().bold()
+                theme::accent(format!("{step}.")).bold()
             );
             println!(
                 "       {}",
-                style("export ANTHROPIC_API_KEY=\"sk-ant-...\"").yellow()
+                theme::warning("export ANTHROPIC_API_KEY=\"sk-ant-...\"")
             );
             println!(
                 "       {}",
-                style(
+                theme::warning(
                     "or: zeroclaw auth paste-token --provider anthropic --auth-kind authorization"
                 )
-                .yellow()
             );
         } else {
             let env_var = provider_env_var(provider);
             println!(
                 "    {} Set your API key:",
-                style(format!("{step}.")).cyan().bold()
+                theme::accent(format!("{step}.")).bold()
             );
             println!(
                 "       {}",
-                style(format!("export {env_var}=\"sk-...\"")).yellow()
+                theme::warning(format!("export {env_var}=\"sk-...\""))
             );
         }
         println!();
@@ -5682,44 +8837,44 @@ fn print_summary(config: &Config) {
     if has_channels {
         println!(
             "    {} {} (connected channels → AI → reply):",
-            style(format!("{step}.")).cyan().bold(),
-            style("Launch your channels").white().bold()
+            theme::accent(format!("{step}.")).bold(),
+            theme::strong("Launch your channels").bold()
         );
-        println!("       {}", style("zeroclaw channel start").yellow());
+        println!("       {}", theme::warning("zeroclaw channel start"));
         println!();
         step += 1;
     }
 
     println!(
         "    {} Send a quick message:",
-        style(format!("{step}.")).cyan().bold()
+        theme::accent(format!("{step}.")).bold()
     );
     println!(
         "       {}",
-        style("zeroclaw agent -m \"Hello, ZeroClaw!\"").yellow()
+        theme::warning("zeroclaw agent -m \"Hello, ZeroClaw!\"")
     );
     println!();
     step += 1;
 
     println!(
         "    {} Start interactive CLI mode:",
-        style(format!("{step}.")).cyan().bold()
+        theme::accent(format!("{step}.")).bold()
     );
-    println!("       {}", style("zeroclaw agent").yellow());
+    println!("       {}", theme::warning("zeroclaw agent"));
     println!();
     step += 1;
 
     println!(
         "    {} Check full status:",
-        style(format!("{step}.")).cyan().bold()
+        theme::accent(format!("{step}.")).bold()
     );
-    println!("       {}", style("zeroclaw status").yellow());
+    println!("       {}", theme::warning("zeroclaw status"));
 
     println!();
     println!(
         "  {} {}",
-        style("⚡").cyan(),
-        style("Happy hacking! 🦀").white().bold()
+        theme::accent("⚡"),
+        theme::strong("Happy hacking! 🦀").bold()
     );
     println!();
 }
@@ -5768,6 +8923,172 @@ mod tests {
 
     // ── ProjectContext defaults ──────────────────────────────────
 
+    #[test]
+    fn wizard_progress_counts_only_planned_steps() {
+        let mut progress = WizardProgress::new(FULL_ONBOARDING_STEPS);
+        assert_eq!(progress.position(WizardStep::Channels), Some((5, 17)));
+
+        progress.keep_only(&[WizardStep::Workspace, WizardStep::Provider]);
+        assert_eq!(progress.position(WizardStep::Provider), Some((2, 2)));
+        assert_eq!(progress.position(WizardStep::Channels), None);
+    }
+
+    #[test]
+    fn only_optional_steps_offer_skip_keys() {
+        assert!(WizardStep::Tunnel.skip_default().is_some());
+        assert!(WizardStep::ProjectContext.skip_default().is_some());
+        assert!(WizardStep::Provider.skip_default().is_none());
+        assert!(WizardStep::Confirmation.skip_default().is_none());
+
+        assert!(is_skip_key(&console::Key::Char('\u{13}')));
+        assert!(is_skip_key(&console::Key::UnknownEscSeq(vec![
+            '[', '2', '1'
+        ])));
+        assert!(!is_skip_key(&console::Key::Enter));
+        assert!(!is_skip_key(&console::Key::Char('x')));
+    }
+
+    #[test]
+    fn otp_enrollment_shows_secret_and_qr() {
+        let uri =
+            "otpauth://totp/ZeroClaw:zeroclaw?secret=JBSWY3DPEHPK3PXP&issuer=ZeroClaw&period=30";
+        assert_eq!(otpauth_secret(uri), Some("JBSWY3DPEHPK3PXP"));
+        assert_eq!(otpauth_secret("otpauth://totp/x?issuer=y"), None);
+
+        let qr = render_qr(uri).unwrap();
+        assert!(qr.lines().count() > 10);
+        assert!(qr.contains('█') || qr.contains('▀') || qr.contains('▄'));
+    }
+
+    #[test]
+    fn security_presets_tighten_and_relax_balanced_defaults() {
+        let balanced = SecurityPreset::Balanced.autonomy();
+        assert_eq!(
+            balanced.allowed_commands,
+            AutonomyConfig::default().allowed_commands
+        );
+
+        let locked = SecurityPreset::LockedDown.autonomy();
+        assert!(locked.allowed_commands.is_empty());
+        assert!(locked.auto_approve.is_empty());
+        assert!(locked.non_cli_excluded_tools.contains(&"shell".to_string()));
+        assert!(locked.workspace_only && locked.block_high_risk_commands);
+
+        let permissive = SecurityPreset::Permissive.autonomy();
+        assert_eq!(permissive.level, crate::security::AutonomyLevel::Full);
+        assert!(permissive.allowed_commands.len() > balanced.allowed_commands.len());
+        assert!(permissive.block_high_risk_commands);
+    }
+
+    #[test]
+    fn confirmation_entries_cover_each_editable_step_then_save_and_abort() {
+        let answers = WizardAnswers {
+            provider: "openrouter".into(),
+            api_key: String::new(),
+            model: "anthropic/claude-sonnet-4".into(),
+            provider_api_url: None,
+            auth_profile: None,
+            channels_config: ChannelsConfig::default(),
+            schedules_config: crate::config::SchedulesConfig::default(),
+            tunnel_config: crate::config::TunnelConfig::default(),
+            tunnel_public_url: None,
+            composio_config: ComposioConfig::default(),
+            secrets_config: SecretsConfig::default(),
+            security_preset: SecurityPreset::Balanced,
+            otp_config: OtpConfig::default(),
+            hardware_config: HardwareConfig::default(),
+            estop_config: EstopConfig {
+                enabled: true,
+                trigger_phrase: parse_trigger_phrase(" STOP NOW "),
+                ..EstopConfig::default()
+            },
+            memory_config: MemoryConfig::default(),
+            embedding: EmbeddingChoice::default(),
+            project_ctx: ProjectContext::default(),
+        };
+
+        let entries = confirmation_entries(&answers);
+        let choices: Vec<_> = entries.iter().map(|(choice, _)| *choice).collect();
+        assert_eq!(
+            choices,
+            vec![
+                ConfirmationChoice::Edit(WizardStep::Provider),
+                ConfirmationChoice::Edit(WizardStep::Channels),
+                ConfirmationChoice::Edit(WizardStep::Schedules),
+                ConfirmationChoice::Edit(WizardStep::Tunnel),
+                ConfirmationChoice::Edit(WizardStep::ToolMode),
+                ConfirmationChoice::Edit(WizardStep::Security),
+                ConfirmationChoice::Edit(WizardStep::Hardware),
+                ConfirmationChoice::Edit(WizardStep::Estop),
+                ConfirmationChoice::Edit(WizardStep::Memory),
+                ConfirmationChoice::Edit(WizardStep::Embeddings),
+                ConfirmationChoice::Edit(WizardStep::ProjectContext),
+                ConfirmationChoice::Save,
+                ConfirmationChoice::Abort,
+            ]
+        );
+        assert!(entries[0].1.contains("openrouter"));
+        assert!(entries[0].1.contains("not set"));
+        assert!(entries[1].1.contains("Cli"));
+        assert!(entries[2].1.contains("none"));
+        assert!(entries[7].1.contains("on (CLI, chat \"STOP NOW\")"));
+        assert!(entries[9].1.contains("keyword search only"));
+        assert!(entries[10].1.contains("(not set)"));
+        assert_eq!(parse_trigger_phrase("None"), None);
+    }
+
+    #[test]
+    fn auth_profile_choices_list_provider_profiles_active_first() {
+        use crate::auth::profiles::{AuthProfile, AuthProfilesData};
+
+        let mut data = AuthProfilesData::default();
+        for (provider, name) in [("openai", "home"), ("openai", "work"), ("gemini", "x")] {
+            let profile = AuthProfile::new_token(provider, name, "sk-test".into());
+            data.profiles.insert(profile.id.clone(), profile);
+        }
+        let work = data
+            .profiles
+            .values()
+            .find(|profile| profile.profile_name == "work")
+            .unwrap()
+            .id
+            .clone();
+        data.active_profiles.insert("openai".into(), work.clone());
+
+        let choices = auth_profile_choices(&data, "openai");
+        assert_eq!(choices.len(), 2);
+        assert_eq!(choices[0], (work, "Use profile work [active]".to_string()));
+        assert_eq!(choices[1].1, "Use profile home");
+        assert!(auth_profile_choices(&data, "anthropic").is_empty());
+    }
+
+    #[test]
+    fn detect_env_secret_picks_first_non_empty_variable() {
+        let lookup = |name: &str| match name {
+            "EMPTY_TOKEN" => Some("  ".to_string()),
+            "TELEGRAM_BOT_TOKEN" => Some("123:abc".to_string()),
+            _ => None,
+        };
+        assert_eq!(
+            detect_env_secret(&["MISSING", "EMPTY_TOKEN", "TELEGRAM_BOT_TOKEN"], lookup),
+            Some(("TELEGRAM_BOT_TOKEN", "123:abc".to_string()))
+        );
+        assert_eq!(detect_env_secret(&["MISSING"], lookup), None);
+    }
+
+    #[test]
+    fn mask_secret_preview_hides_the_middle() {
+        assert_eq!(mask_secret_preview("sk-or-v1-abcdef123456"), "sk-o…3456");
+        assert_eq!(mask_secret_preview("short"), "••••");
+    }
+
+    #[test]
+    fn step_gauge_split_fills_proportionally() {
+        assert_eq!(step_gauge_split(1, 9, 45), (5, 40));
+        assert_eq!(step_gauge_split(9, 9, 50), (50, 0));
+        assert_eq!(step_gauge_split(3, 0, 10), (10, 0));
+    }
+
     #[test]
     fn project_context_default_is_empty() {
         let ctx = ProjectContext::default();
@@ -5828,6 +9149,26 @@ mod tests {
         assert!(config.api_url.is_none());
     }
 
+    #[tokio::test]
+    async fn model_choices_prefer_cached_catalog_over_curated() {
+        let tmp = TempDir::new().unwrap();
+
+        let (curated, age) = model_choices_for_provider(tmp.path(), "anthropic")
+            .await
+            .unwrap();
+        assert!(age.is_none());
+        assert_eq!(curated.len(), curated_model_count("anthropic"));
+
+        cache_live_models_for_provider(tmp.path(), "anthropic", &["claude-live-1".to_string()])
+            .await
+            .unwrap();
+        let (cached, age) = model_choices_for_provider(tmp.path(), "anthropic")
+            .await
+            .unwrap();
+        assert!(age.is_some());
+        assert_eq!(cached[0].0, "claude-live-1");
+    }
+
     #[tokio::test]
     async fn quick_setup_model_override_persists_to_config_toml() {
         let _env_guard = env_lock().lock().await;
@@ -6440,6 +9781,54 @@ mod tests {
 
     // ── model helper coverage ───────────────────────────────────
 
+    #[test]
+    fn bare_escape_is_distinguished_from_escape_sequences() {
+        assert!(contains_bare_escape(b"\x1b"));
+        assert!(contains_bare_escape(b"ab\x1b"));
+        assert!(contains_bare_escape(b"\x1b[A\x1b"));
+        assert!(!contains_bare_escape(b"\x1b[A"));
+        assert!(!contains_bare_escape(b"\x1bOP"));
+        assert!(!contains_bare_escape(b"q"));
+    }
+
+    #[tokio::test]
+    async fn fetch_with_spinner_returns_worker_result() {
+        // Unknown providers have no models endpoint, so the worker returns
+        // immediately without touching the network; that must not read as a cancel.
+        let result = fetch_live_models_with_spinner("definitely-not-a-provider", "", None)
+            .await
+            .unwrap();
+        assert_eq!(result, Some(Vec::new()));
+    }
+
+    #[test]
+    fn api_key_probe_separates_auth_failures_from_network_errors() {
+        assert!(matches!(
+            classify_api_key_probe(Ok(Some(vec!["gpt-5".to_string()]))),
+            ApiKeyCheck::Accepted(Some(models)) if models == ["gpt-5"]
+        ));
+        assert!(matches!(
+            classify_api_key_probe(Err(anyhow::anyhow!(
+                "model fetch failed: GET https://api.openai.com/v1/models: HTTP status client error (401 Unauthorized)"
+            ))),
+            ApiKeyCheck::Rejected(_)
+        ));
+        assert!(matches!(
+            classify_api_key_probe(Err(anyhow::anyhow!(
+                "model fetch failed: GET https://api.openai.com/v1/models: connection refused"
+            ))),
+            ApiKeyCheck::Inconclusive(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn validate_api_key_with_spinner_reports_probe_verdict() {
+        let check = validate_api_key_with_spinner("definitely-not-a-provider", "sk-test", None)
+            .await
+            .unwrap();
+        assert!(matches!(check, Some(ApiKeyCheck::Accepted(Some(models))) if models.is_empty()));
+    }
+
     #[test]
     fn default_model_for_provider_uses_latest_defaults() {
         assert_eq!(
@@ -6514,6 +9903,38 @@ mod tests {
         assert_eq!(canonical_provider_name("llama.cpp"), "llamacpp");
     }
 
+    #[test]
+    fn bracketed_paste_is_inserted_whole_and_newlines_do_not_submit() {
+        let mut line = PasteAwareLine::default();
+        let mut ends = Vec::new();
+        for &byte in b"sk-\x1b[200~abc\r\ndef \x1b[201~\x1b[Dx\x7f!" {
+            ends.extend(line.push(byte));
+        }
+        assert!(ends.is_empty());
+        assert_eq!(line.text, "sk-abcdef!");
+
+        assert_eq!(line.push(b'\r'), Some(LineEnd::Submit));
+        assert_eq!(PasteAwareLine::default().push(0x03), Some(LineEnd::Cancel));
+    }
+
+    #[test]
+    fn filter_model_options_matches_all_terms_case_insensitively() {
+        let options = build_model_options(
+            vec![
+                "anthropic/claude-sonnet-4.6".to_string(),
+                "meta-llama/llama-3.3-70b-instruct".to_string(),
+                "meta-llama/llama-3.1-8b-instruct".to_string(),
+            ],
+            "live",
+        );
+
+        let filtered = filter_model_options(&options, "LLAMA 70b");
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].0, "meta-llama/llama-3.3-70b-instruct");
+        assert_eq!(filter_model_options(&options, "  ").len(), 3);
+        assert!(filter_model_options(&options, "gpt").is_empty());
+    }
+
     #[test]
     fn curated_models_for_openai_include_latest_choices() {
         let ids: Vec<String> = curated_models_for_provider("openai")
@@ -6730,6 +10151,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn provider_base_url_and_curated_count_for_dashboard() {
+        assert_eq!(
+            provider_base_url("groq").as_deref(),
+            Some("https://api.groq.com/openai/v1")
+        );
+        assert_eq!(
+            provider_base_url("google").as_deref(),
+            Some("https://generativelanguage.googleapis.com/v1beta")
+        );
+        assert_eq!(provider_base_url("definitely-unknown"), None);
+        assert!(curated_model_count("openrouter") > 1);
+        assert_eq!(curated_model_count("definitely-unknown"), 0);
+    }
+
     #[test]
     fn models_endpoint_for_provider_supports_additional_openai_compatible_providers() {
         assert_eq!(
@@ -7077,6 +10513,25 @@ mod tests {
         assert!(choices.iter().any(|(provider, _)| *provider == "sglang"));
     }
 
+    #[test]
+    fn provider_hints_cover_local_choices_and_format_compactly() {
+        for (provider, _) in local_provider_choices() {
+            assert!(
+                provider_hint(provider).is_some(),
+                "missing hint: {provider}"
+            );
+        }
+        assert_eq!(
+            format_provider_hint(provider_hint("anthropic").unwrap()),
+            "moderate · $$$ · 200K ctx"
+        );
+        assert_eq!(
+            format_provider_hint(provider_hint("gemini").unwrap()),
+            "fast · $$ · 1M ctx"
+        );
+        assert_eq!(provider_hint("some-new-provider"), None);
+    }
+
     #[test]
     fn provider_env_var_unknown_falls_back() {
         assert_eq!(provider_env_var("some-new-provider"), "API_KEY");
@@ -7087,10 +10542,54 @@ mod tests {
         assert_eq!(backend_key_from_choice(0), "sqlite");
         assert_eq!(backend_key_from_choice(1), "lucid");
         assert_eq!(backend_key_from_choice(2), "markdown");
-        assert_eq!(backend_key_from_choice(3), "none");
+        assert_eq!(backend_key_from_choice(3), "qdrant");
+        assert_eq!(backend_key_from_choice(4), "none");
         assert_eq!(backend_key_from_choice(999), "sqlite");
     }
 
+    #[test]
+    fn qdrant_url_validation_normalizes_and_rejects_bad_schemes() {
+        assert_eq!(
+            validate_qdrant_url(" http://localhost:6333/ ").unwrap(),
+            "http://localhost:6333"
+        );
+        assert!(validate_qdrant_url("ftp://qdrant:6333").is_err());
+        assert!(validate_qdrant_url("not a url").is_err());
+        assert_eq!(
+            describe_qdrant_collections(&serde_json::json!({
+                "result": {"collections": [{"name": "zeroclaw_memories"}]}
+            })),
+            "1 existing collection"
+        );
+    }
+
+    #[test]
+    fn embedding_choice_uses_a_route_only_for_a_separate_key() {
+        let mut choice = EmbeddingChoice {
+            provider: "openai".into(),
+            model: "text-embedding-3-large".into(),
+            dimensions: 3072,
+            api_key: None,
+        };
+        let mut memory = memory_config_defaults_for_backend("sqlite");
+        assert!(choice.apply(&mut memory).is_empty());
+        assert_eq!(memory.embedding_provider, "openai");
+        assert_eq!(memory.embedding_model, "text-embedding-3-large");
+        assert_eq!(memory.embedding_dimensions, 3072);
+
+        choice.api_key = Some("sk-embed".into());
+        let mut memory = memory_config_defaults_for_backend("qdrant");
+        let routes = choice.apply(&mut memory);
+        assert_eq!(memory.embedding_model, "hint:semantic");
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].model, "text-embedding-3-large");
+        assert_eq!(routes[0].api_key.as_deref(), Some("sk-embed"));
+
+        let mut memory = memory_config_defaults_for_backend("markdown");
+        assert!(choice.apply(&mut memory).is_empty());
+        assert_eq!(memory.embedding_provider, "none");
+    }
+
     #[test]
     fn memory_backend_profile_marks_lucid_as_optional_sqlite_backed() {
         let lucid = memory_backend_profile("lucid");
@@ -7134,6 +10633,60 @@ mod tests {
         assert_eq!(config.embedding_cache_size, 0);
     }
 
+    #[test]
+    fn tunnel_lookup_target_uses_host_and_default_port() {
+        assert_eq!(
+            tunnel_lookup_target("https://demo.trycloudflare.com").unwrap(),
+            "demo.trycloudflare.com:443"
+        );
+        assert_eq!(
+            tunnel_lookup_target("http://bore.pub:40123/path").unwrap(),
+            "bore.pub:40123"
+        );
+        assert!(tunnel_lookup_target("not a url").is_err());
+    }
+
+    #[test]
+    fn port_conflicts_are_detected_with_a_free_suggestion() {
+        let listener = std::net::TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let taken = listener.local_addr().unwrap().port();
+
+        assert!(port_in_use(taken));
+        let free = suggest_free_port(taken).expect("a free port nearby");
+        assert!(free > taken);
+        assert!(!port_in_use(free));
+    }
+
+    #[test]
+    fn test_message_channels_only_list_deliverable_adapters() {
+        let mut config = ChannelsConfig::default();
+        assert!(test_message_channels(&config).is_empty());
+
+        config.telegram = Some(TelegramConfig {
+            bot_token: "env:TELEGRAM_BOT_TOKEN".into(),
+            allowed_users: vec!["*".into()],
+            stream_mode: StreamMode::default(),
+            draft_update_interval_ms: 1000,
+            interrupt_on_new_message: false,
+            mention_only: false,
+            quote_replies: false,
+            lightweight_acks: false,
+        });
+        config.slack = Some(SlackConfig {
+            bot_token: "xoxb-test".into(),
+            app_token: None,
+            channel_id: Some("C0123".into()),
+            allowed_users: vec![],
+            quote_replies: true,
+        });
+        assert_eq!(test_message_channels(&config), vec!["telegram", "slack"]);
+        assert_eq!(
+            default_test_message_recipient(&config, "slack").as_deref(),
+            Some("C0123")
+        );
+        assert!(default_test_message_recipient(&config, "telegram").is_none());
+    }
+
     #[test]
     fn channel_menu_choices_include_signal_nextcloud_lark_and_feishu() {
         assert!(channel_menu_choices().contains(&ChannelMenuChoice::Signal));
@@ -7142,6 +10695,54 @@ mod tests {
         assert!(channel_menu_choices().contains(&ChannelMenuChoice::Feishu));
     }
 
+    #[test]
+    fn channel_rows_are_grouped_with_descriptions_and_badges() {
+        let entries = channel_registry::wizard_entries();
+        let groups: Vec<_> = entries.iter().map(|entry| entry.group).collect();
+        assert!(groups.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        let rows = grouped_channel_rows(&entries, channel_description);
+        assert!(rows[0].starts_with("Mainstream"));
+        let headings = rows.iter().filter(|row| !row.starts_with(' ')).count();
+        let mut distinct = groups.clone();
+        distinct.dedup();
+        assert_eq!(headings, distinct.len());
+
+        let whatsapp = entries.iter().position(|e| e.key == "whatsapp").unwrap();
+        assert!(rows[whatsapp].contains("[requires public URL]"));
+        let telegram = entries.iter().position(|e| e.key == "telegram").unwrap();
+        assert!(!rows[telegram].contains("[requires public URL]"));
+    }
+
+    #[test]
+    fn channel_selection_maps_indices_in_menu_order_without_done() {
+        let choices = channel_selection_choices();
+        assert!(!choices.contains(&ChannelMenuChoice::Done));
+
+        let telegram = choices
+            .iter()
+            .position(|c| *c == ChannelMenuChoice::Telegram)
+            .unwrap();
+        let discord = choices
+            .iter()
+            .position(|c| *c == ChannelMenuChoice::Discord)
+            .unwrap();
+        let webhook = choices
+            .iter()
+            .position(|c| *c == ChannelMenuChoice::Webhook)
+            .unwrap();
+
+        assert_eq!(
+            channel_selection_from_indices(&[webhook, telegram, discord, telegram, 999]),
+            vec![
+                ChannelMenuChoice::Telegram,
+                ChannelMenuChoice::Discord,
+                ChannelMenuChoice::Webhook
+            ]
+        );
+        assert!(channel_selection_from_indices(&[]).is_empty());
+    }
+
     #[test]
     fn launchable_channels_include_signal_mattermost_qq_nextcloud_and_feishu() {
         let mut channels = ChannelsConfig::default();
@@ -0,0 +1,423 @@
+//! Alert rules on recorded sensor readings (`[[peripherals.recorder.alerts]]`).
+//!
+//! After each recorder tick every rule is checked against the latest
+//! readings for its pin. An alarm notifies once when it fires, repeats every
+//! `renotify_mins` until acknowledged with `zeroclaw sensors ack`, and sends
+//! a resolved notice when it clears. Limits carry `hysteresis` so a value
+//! hovering at the threshold does not flap.
+
+use super::recorder::{self, SensorSample};
+use crate::config::{Config, SensorAlertRule};
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+
+/// Persisted state of one alert rule.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AlertState {
+    pub name: String,
+    pub firing: bool,
+    pub reason: Option<String>,
+    pub since: Option<DateTime<Utc>>,
+    pub notified_at: Option<DateTime<Utc>>,
+    pub acknowledged: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transition {
+    Fired,
+    Repeated,
+    Resolved,
+}
+
+/// Why `rule` is in alarm given `series` (oldest first, this pin only), or
+/// `None` when it is not. While `firing`, limits are relaxed by
+/// `hysteresis` so the alarm only clears once the value is clearly back.
+pub fn evaluate(
+    rule: &SensorAlertRule,
+    series: &[SensorSample],
+    now: DateTime<Utc>,
+    firing: bool,
+) -> Option<String> {
+    let slack = if firing { rule.hysteresis.abs() } else { 0.0 };
+    let latest = series.last();
+
+    if let Some(stale_after) = rule.stale_after_secs {
+        let age = latest.map(|sample| (now - sample.recorded_at).num_seconds());
+        #[allow(clippy::cast_possible_wrap)]
+        let limit = stale_after as i64;
+        match age {
+            None => return Some(format!("no reading for over {stale_after}s")),
+            Some(age) if age > limit => return Some(format!("no reading for {age}s")),
+            Some(_) => {}
+        }
+    }
+    let latest = latest?;
+    let unit = latest.unit.as_deref().unwrap_or("");
+
+    if let Some(above) = rule.above {
+        if latest.value > above - slack {
+            return Some(format!("{:.2}{unit} is above {above}{unit}", latest.value));
+        }
+    }
+    if let Some(below) = rule.below {
+        if latest.value < below + slack {
+            return Some(format!("{:.2}{unit} is below {below}{unit}", latest.value));
+        }
+    }
+    if let (Some(max_rate), [.., previous, _]) = (rule.max_rate_per_min, series) {
+        #[allow(clippy::cast_precision_loss)]
+        let minutes = (latest.recorded_at - previous.recorded_at).num_seconds() as f64 / 60.0;
+        if minutes > 0.0 {
+            let rate = (latest.value - previous.value) / minutes;
+            if rate.abs() > (max_rate - slack).max(0.0) {
+                return Some(format!(
+                    "changing {rate:+.2}{unit}/min (limit {max_rate}{unit}/min)"
+                ));
+            }
+        }
+    }
+    None
+}
+
+/// Advance `state` with the latest evaluation. Returns the transition to
+/// announce, if any; the caller persists `state` either way.
+pub fn step(
+    state: &mut AlertState,
+    reason: Option<String>,
+    now: DateTime<Utc>,
+    renotify_mins: u64,
+) -> Option<Transition> {
+    match (state.firing, reason) {
+        (false, Some(reason)) => {
+            state.firing = true;
+            state.reason = Some(reason);
+            state.since = Some(now);
+            state.notified_at = Some(now);
+            state.acknowledged = false;
+            Some(Transition::Fired)
+        }
+        (true, Some(reason)) => {
+            state.reason = Some(reason);
+            #[allow(clippy::cast_possible_wrap)]
+            let due = renotify_mins > 0
+                && !state.acknowledged
+                && state
+                    .notified_at
+                    .is_none_or(|at| now - at >= chrono::Duration::minutes(renotify_mins as i64));
+            if due {
+                state.notified_at = Some(now);
+                Some(Transition::Repeated)
+            } else {
+                None
+            }
+        }
+        (true, None) => {
+            state.firing = false;
+            state.acknowledged = false;
+            Some(Transition::Resolved)
+        }
+        (false, None) => None,
+    }
+}
+
+fn init(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS sensor_alerts (
+            name         TEXT PRIMARY KEY,
+            firing       INTEGER NOT NULL DEFAULT 0,
+            reason       TEXT,
+            since        TEXT,
+            notified_at  TEXT,
+            acknowledged INTEGER NOT NULL DEFAULT 0
+         );",
+    )?;
+    Ok(())
+}
+
+fn parse_time(value: Option<String>) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(&value?)
+        .ok()
+        .map(|at| at.with_timezone(&Utc))
+}
+
+fn load_state(conn: &Connection, name: &str) -> Result<AlertState> {
+    let state = conn
+        .query_row(
+            "SELECT firing, reason, since, notified_at, acknowledged
+             FROM sensor_alerts WHERE name = ?1",
+            params![name],
+            |row| {
+                Ok(AlertState {
+                    name: name.to_string(),
+                    firing: row.get(0)?,
+                    reason: row.get(1)?,
+                    since: parse_time(row.get(2)?),
+                    notified_at: parse_time(row.get(3)?),
+                    acknowledged: row.get(4)?,
+                })
+            },
+        )
+        .optional()?;
+    Ok(state.unwrap_or_else(|| AlertState {
+        name: name.to_string(),
+        ..AlertState::default()
+    }))
+}
+
+fn save_state(conn: &Connection, state: &AlertState) -> Result<()> {
+    conn.execute(
+        "INSERT INTO sensor_alerts (name, firing, reason, since, notified_at, acknowledged)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+         ON CONFLICT(name) DO UPDATE SET
+            firing = excluded.firing, reason = excluded.reason, since = excluded.since,
+            notified_at = excluded.notified_at, acknowledged = excluded.acknowledged",
+        params![
+            state.name,
+            state.firing,
+            state.reason,
+            state.since.map(|at| at.to_rfc3339()),
+            state.notified_at.map(|at| at.to_rfc3339()),
+            state.acknowledged,
+        ],
+    )?;
+    Ok(())
+}
+
+/// How far back to load readings for `rule`.
+fn lookback(rule: &SensorAlertRule, interval_secs: u64) -> chrono::Duration {
+    let secs = rule
+        .stale_after_secs
+        .unwrap_or(0)
+        .max(interval_secs.saturating_mul(3))
+        .max(300);
+    #[allow(clippy::cast_possible_wrap)]
+    chrono::Duration::seconds(secs as i64)
+}
+
+async fn announce(config: &Config, rule: &SensorAlertRule, notice: &str) {
+    tracing::warn!("{notice}");
+    let channel = rule
+        .channel
+        .as_deref()
+        .or(config.heartbeat.target.as_deref());
+    let target = rule.to.as_deref().or(config.heartbeat.to.as_deref());
+    if let (Some(channel), Some(target)) = (channel, target) {
+        if let Err(error) =
+            crate::cron::scheduler::deliver_announcement(config, channel, target, notice).await
+        {
+            tracing::warn!(alert = %rule.name, "Sensor alert delivery failed: {error}");
+        }
+    }
+}
+
+async fn run_workflow(config: &Config, rule: &SensorAlertRule, job_id: &str) {
+    match crate::cron::get_job(config, job_id) {
+        Ok(job) => {
+            let (ok, output) =
+                Box::pin(crate::cron::scheduler::execute_job_now(config, &job)).await;
+            if !ok {
+                tracing::warn!(alert = %rule.name, job = job_id, "Alert job failed: {output}");
+            }
+        }
+        Err(error) => {
+            tracing::warn!(alert = %rule.name, job = job_id, "Alert job not found: {error}");
+        }
+    }
+}
+
+/// A transition to announce after the database work for a tick is done.
+pub(super) struct Announcement {
+    rule: SensorAlertRule,
+    notice: String,
+    fired: bool,
+}
+
+/// Check every rule after a recorder tick and persist the new states.
+pub(super) fn check_all(config: &Config, conn: &Connection) -> Result<Vec<Announcement>> {
+    let recorder_cfg = &config.peripherals.recorder;
+    let mut announcements = Vec::new();
+    if recorder_cfg.alerts.is_empty() {
+        return Ok(announcements);
+    }
+    init(conn)?;
+    let now = Utc::now();
+    for rule in &recorder_cfg.alerts {
+        let since = now - lookback(rule, recorder_cfg.interval_secs);
+        let series = recorder::query(conn, since, rule.board.as_deref(), Some(rule.pin))?;
+        let mut state = load_state(conn, &rule.name)?;
+        let reason = evaluate(rule, &series, now, state.firing);
+        let transition = step(&mut state, reason, now, rule.renotify_mins);
+        save_state(conn, &state)?;
+
+        let reason = state.reason.as_deref().unwrap_or("");
+        let notice = match transition {
+            None => continue,
+            Some(Transition::Fired) => format!("🚨 Sensor alert '{}': {reason}", rule.name),
+            Some(Transition::Repeated) => format!(
+                "🚨 Sensor alert '{}' still firing: {reason}\nAcknowledge with `zeroclaw sensors ack {}`.",
+                rule.name, rule.name
+            ),
+            Some(Transition::Resolved) => format!("✅ Sensor alert '{}' resolved", rule.name),
+        };
+        announcements.push(Announcement {
+            rule: rule.clone(),
+            notice,
+            fired: transition == Some(Transition::Fired),
+        });
+    }
+    Ok(announcements)
+}
+
+/// Deliver notices and start the workflows of newly fired alarms.
+pub(super) async fn dispatch(config: &Config, announcements: Vec<Announcement>) {
+    for Announcement {
+        rule,
+        notice,
+        fired,
+    } in announcements
+    {
+        announce(config, &rule, &notice).await;
+        if fired {
+            if let Some(job_id) = rule.run_job.as_deref() {
+                Box::pin(run_workflow(config, &rule, job_id)).await;
+            }
+        }
+    }
+}
+
+/// Print each configured rule with its current state.
+pub fn print_alerts(config: &Config) -> Result<()> {
+    let rules = &config.peripherals.recorder.alerts;
+    if rules.is_empty() {
+        println!("No sensor alert rules configured ([[peripherals.recorder.alerts]]).");
+        return Ok(());
+    }
+    let conn = recorder::open(config)?;
+    init(&conn)?;
+    println!("🚨 Sensor alerts");
+    for rule in rules {
+        let state = load_state(&conn, &rule.name)?;
+        let status = match (state.firing, state.acknowledged) {
+            (false, _) => "ok".to_string(),
+            (true, ack) => format!(
+                "FIRING since {}{}: {}",
+                state.since.map_or_else(
+                    || "?".into(),
+                    |at| at.format("%Y-%m-%d %H:%M UTC").to_string()
+                ),
+                if ack { " (acknowledged)" } else { "" },
+                state.reason.as_deref().unwrap_or("")
+            ),
+        };
+        let board = rule.board.as_deref().unwrap_or("any board");
+        println!("  {} ({board} pin {}) · {status}", rule.name, rule.pin);
+    }
+    Ok(())
+}
+
+/// Acknowledge a firing alarm so it stops repeating until it clears.
+pub fn acknowledge(config: &Config, name: &str) -> Result<()> {
+    if !config
+        .peripherals
+        .recorder
+        .alerts
+        .iter()
+        .any(|rule| rule.name == name)
+    {
+        bail!("No sensor alert rule named '{name}'");
+    }
+    let conn = recorder::open(config)?;
+    init(&conn)?;
+    let mut state = load_state(&conn, name)?;
+    if !state.firing {
+        println!("Alert '{name}' is not firing.");
+        return Ok(());
+    }
+    state.acknowledged = true;
+    save_state(&conn, &state)?;
+    println!("✅ Acknowledged '{name}'; it will not repeat until it clears and fires again.");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule() -> SensorAlertRule {
+        SensorAlertRule {
+            name: "tank-hot".into(),
+            board: None,
+            pin: 0,
+            above: Some(80.0),
+            below: None,
+            max_rate_per_min: None,
+            stale_after_secs: None,
+            hysteresis: 2.0,
+            renotify_mins: 30,
+            channel: None,
+            to: None,
+            run_job: None,
+        }
+    }
+
+    fn sample(now: DateTime<Utc>, secs_ago: i64, value: f64) -> SensorSample {
+        SensorSample {
+            recorded_at: now - chrono::Duration::seconds(secs_ago),
+            board: "esp32".into(),
+            pin: 0,
+            name: None,
+            raw: value,
+            value,
+            unit: Some("C".into()),
+        }
+    }
+
+    #[test]
+    fn alarms_fire_hold_with_hysteresis_repeat_and_resolve() {
+        let now = Utc::now();
+        let rule = rule();
+        let mut state = AlertState::default();
+
+        let hot = evaluate(&rule, &[sample(now, 0, 81.0)], now, state.firing);
+        assert!(hot.as_deref().unwrap().contains("above 80C"));
+        assert_eq!(step(&mut state, hot, now, 30), Some(Transition::Fired));
+
+        // 79 is under the limit but inside the hysteresis band: still firing.
+        let hovering = evaluate(&rule, &[sample(now, 0, 79.0)], now, state.firing);
+        assert!(hovering.is_some());
+        assert_eq!(step(&mut state, hovering.clone(), now, 30), None);
+        let later = now + chrono::Duration::minutes(31);
+        assert_eq!(
+            step(&mut state, hovering.clone(), later, 30),
+            Some(Transition::Repeated)
+        );
+        state.acknowledged = true;
+        let much_later = later + chrono::Duration::minutes(60);
+        assert_eq!(step(&mut state, hovering, much_later, 30), None);
+
+        let cool = evaluate(&rule, &[sample(now, 0, 77.0)], now, state.firing);
+        assert_eq!(step(&mut state, cool, now, 30), Some(Transition::Resolved));
+        assert!(!state.firing && !state.acknowledged);
+
+        let fast = SensorAlertRule {
+            above: None,
+            max_rate_per_min: Some(5.0),
+            hysteresis: 0.0,
+            ..rule.clone()
+        };
+        let climbing = [sample(now, 60, 20.0), sample(now, 0, 30.0)];
+        assert!(evaluate(&fast, &climbing, now, false)
+            .unwrap()
+            .contains("+10.00C/min"));
+
+        let silent = SensorAlertRule {
+            above: None,
+            stale_after_secs: Some(120),
+            ..rule
+        };
+        assert!(evaluate(&silent, &[sample(now, 300, 20.0)], now, false).is_some());
+        assert!(evaluate(&silent, &[], now, false).is_some());
+        assert!(evaluate(&silent, &[sample(now, 30, 20.0)], now, false).is_none());
+    }
+}
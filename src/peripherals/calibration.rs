@@ -0,0 +1,174 @@
+//! Calibration and unit conversion for analog sensor readings.
+//!
+//! Boards report raw ADC counts; `[[peripherals.boards.sensors]]` entries
+//! turn them into engineering units before tools hand them to the agent.
+
+use crate::config::SensorCalibration;
+
+impl SensorCalibration {
+    /// The calibrated value of `raw`, in `unit`.
+    pub fn apply(&self, raw: f64) -> f64 {
+        if self.polynomial.is_empty() {
+            return self.offset + self.scale * raw;
+        }
+        // Horner's method, highest order first.
+        self.polynomial
+            .iter()
+            .rev()
+            .fold(0.0, |acc, coefficient| acc * raw + coefficient)
+    }
+
+    /// The calibrated value converted to `display_unit` when possible, with
+    /// the unit it ends up in.
+    pub fn reading(&self, raw: f64) -> (f64, Option<&str>) {
+        let value = self.apply(raw);
+        match (self.unit.as_deref(), self.display_unit.as_deref()) {
+            (Some(from), Some(to)) => match convert_units(value, from, to) {
+                Some(converted) => (converted, Some(to)),
+                None => (value, Some(from)),
+            },
+            (unit, _) => (value, unit),
+        }
+    }
+}
+
+/// Linear units as (name, factor to the dimension's base unit).
+const LINEAR_UNITS: &[&[(&str, f64)]] = &[
+    &[
+        ("pa", 1.0),
+        ("hpa", 100.0),
+        ("kpa", 1_000.0),
+        ("bar", 100_000.0),
+        ("psi", 6_894.757),
+    ],
+    &[
+        ("mm", 0.001),
+        ("cm", 0.01),
+        ("m", 1.0),
+        ("in", 0.0254),
+        ("ft", 0.3048),
+    ],
+    &[("mv", 0.001), ("v", 1.0)],
+    &[("ma", 0.001), ("a", 1.0)],
+    &[("g", 0.001), ("kg", 1.0), ("lb", 0.453_592_37)],
+    &[("%", 1.0), ("percent", 1.0)],
+];
+
+fn normalize_unit(unit: &str) -> String {
+    let unit = unit.trim().trim_start_matches('°').to_ascii_lowercase();
+    match unit.as_str() {
+        "celsius" => "c".into(),
+        "fahrenheit" => "f".into(),
+        "kelvin" => "k".into(),
+        _ => unit,
+    }
+}
+
+fn to_celsius(value: f64, unit: &str) -> Option<f64> {
+    match unit {
+        "c" => Some(value),
+        "f" => Some((value - 32.0) * 5.0 / 9.0),
+        "k" => Some(value - 273.15),
+        _ => None,
+    }
+}
+
+fn from_celsius(value: f64, unit: &str) -> Option<f64> {
+    match unit {
+        "c" => Some(value),
+        "f" => Some(value * 9.0 / 5.0 + 32.0),
+        "k" => Some(value + 273.15),
+        _ => None,
+    }
+}
+
+/// Convert `value` between two units of the same dimension. `None` when a
+/// unit is unknown or the dimensions differ.
+pub fn convert_units(value: f64, from: &str, to: &str) -> Option<f64> {
+    let (from, to) = (normalize_unit(from), normalize_unit(to));
+    if from == to {
+        return Some(value);
+    }
+    if let Some(celsius) = to_celsius(value, &from) {
+        return from_celsius(celsius, &to);
+    }
+    LINEAR_UNITS.iter().find_map(|dimension| {
+        let factor = |unit: &str| {
+            dimension
+                .iter()
+                .find(|(name, _)| *name == unit)
+                .map(|(_, factor)| *factor)
+        };
+        Some(value * factor(&from)? / factor(&to)?)
+    })
+}
+
+/// One line describing a reading from `pin`: calibrated when a calibration
+/// exists for it, raw counts otherwise.
+pub fn describe_reading(sensors: &[SensorCalibration], pin: u32, raw: f64) -> String {
+    let Some(sensor) = sensors.iter().find(|sensor| sensor.pin == pin) else {
+        return format!("pin {pin} = {raw} (raw, uncalibrated)");
+    };
+    let (value, unit) = sensor.reading(raw);
+    let label = sensor.name.as_deref().map_or_else(
+        || format!("pin {pin}"),
+        |name| format!("{name} (pin {pin})"),
+    );
+    match unit {
+        Some(unit) => format!("{label} = {value:.3} {unit} (raw {raw})"),
+        None => format!("{label} = {value:.3} (raw {raw})"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sensor(pin: u32) -> SensorCalibration {
+        SensorCalibration {
+            pin,
+            name: None,
+            offset: 0.0,
+            scale: 1.0,
+            polynomial: Vec::new(),
+            unit: None,
+            display_unit: None,
+        }
+    }
+
+    #[test]
+    fn calibration_applies_linear_and_polynomial_then_converts() {
+        let thermistor = SensorCalibration {
+            name: Some("tank".into()),
+            offset: -50.0,
+            scale: 0.1,
+            unit: Some("°C".into()),
+            display_unit: Some("F".into()),
+            ..sensor(2)
+        };
+        assert!((thermistor.apply(700.0) - 20.0).abs() < 1e-9);
+        let (value, unit) = thermistor.reading(700.0);
+        assert!((value - 68.0).abs() < 1e-9);
+        assert_eq!(unit, Some("F"));
+
+        let curve = SensorCalibration {
+            polynomial: vec![1.0, 2.0, 3.0],
+            offset: 99.0,
+            ..sensor(0)
+        };
+        assert!((curve.apply(2.0) - 17.0).abs() < 1e-9);
+
+        assert!((convert_units(1.0, "bar", "kPa").unwrap() - 100.0).abs() < 1e-9);
+        assert!(convert_units(1.0, "bar", "V").is_none());
+
+        let sensors = vec![thermistor];
+        assert_eq!(
+            describe_reading(&sensors, 2, 700.0),
+            "tank (pin 2) = 68.000 F (raw 700)"
+        );
+        assert_eq!(
+            describe_reading(&sensors, 3, 12.0),
+            "pin 3 = 12 (raw, uncalibrated)"
+        );
+    }
+}
@@ -3,6 +3,10 @@
 //! Peripherals extend the agent with physical capabilities. See
 //! `docs/hardware-peripherals-design.md` for the full design.
 
+pub mod alerts;
+pub mod calibration;
+pub mod recorder;
+pub mod remote;
 pub mod traits;
 
 #[cfg(feature = "hardware")]
@@ -63,7 +67,10 @@ pub async fn handle_command(cmd: crate::PeripheralCommands, config: &Config) ->
             } else {
                 println!("Configured peripherals:");
                 for b in boards {
-                    let path = b.path.as_deref().unwrap_or("(native)");
+                    let path = match &b.remote {
+                        Some(remote) if remote::is_remote(b) => remote.url.as_str(),
+                        _ => b.path.as_deref().unwrap_or("(native)"),
+                    };
                     println!("  {}  {}  {}", b.board, b.transport, path);
                 }
             }
@@ -94,6 +101,8 @@ pub async fn handle_command(cmd: crate::PeripheralCommands, config: &Config) ->
                 transport: transport.to_string(),
                 path: path_opt,
                 baud: 115_200,
+                sensors: Vec::new(),
+                remote: None,
             });
             cfg.save().await?;
             println!("Added {} at {}. Restart daemon to apply.", board, path);
@@ -172,6 +181,12 @@ pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<B
             continue;
         }
 
+        // Remote node over HTTP or MQTT
+        if remote::is_remote(board) {
+            tools.extend(remote::connect_tools(board).await);
+            continue;
+        }
+
         // Serial transport (STM32, ESP32, Arduino, etc.)
         if board.transport != "serial" {
             continue;
@@ -227,15 +242,64 @@ pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<B
     Ok(tools)
 }
 
+/// Without the hardware feature only remote nodes can be used.
 #[cfg(not(feature = "hardware"))]
-pub async fn create_peripheral_tools(_config: &PeripheralsConfig) -> Result<Vec<Box<dyn Tool>>> {
-    Ok(Vec::new())
+pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<Box<dyn Tool>>> {
+    let mut tools: Vec<Box<dyn Tool>> = Vec::new();
+    if !config.enabled {
+        return Ok(tools);
+    }
+    for board in config
+        .boards
+        .iter()
+        .filter(|board| remote::is_remote(board))
+    {
+        tools.extend(remote::connect_tools(board).await);
+    }
+    Ok(tools)
+}
+
+/// Watch the `[security.estop] kill_switch_pin` GPIO and engage `kill-all`
+/// when the switch closes. Runs under the daemon supervisor.
+pub async fn run_estop_kill_switch(
+    config: Config,
+    toasts: crate::notifications::Toasts,
+) -> Result<()> {
+    let Some(pin) = config.security.estop.kill_switch_pin else {
+        return std::future::pending().await;
+    };
+
+    #[cfg(all(feature = "peripheral-rpi", target_os = "linux"))]
+    loop {
+        crate::health::mark_component_ok("estop-kill-switch");
+        rpi::wait_for_kill_switch(pin).await?;
+        tracing::warn!("Estop kill switch on GPIO {pin} closed");
+        crate::security::estop::engage_local(
+            &config,
+            &format!("kill switch (GPIO {pin})"),
+            &toasts,
+        )
+        .await?;
+        // Wait for the switch to be released before re-arming.
+        tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+    }
+
+    #[cfg(not(all(feature = "peripheral-rpi", target_os = "linux")))]
+    {
+        let _ = toasts;
+        tracing::warn!(
+            "Estop kill switch on GPIO {pin} needs the peripheral-rpi feature on Linux; not armed"
+        );
+        std::future::pending().await
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{PeripheralBoardConfig, PeripheralsConfig};
+    use crate::config::{
+        PeripheralBoardConfig, PeripheralNodeConfig, PeripheralsConfig, SensorRecorderConfig,
+    };
 
     #[test]
     fn list_configured_boards_when_disabled_returns_empty() {
@@ -246,8 +310,12 @@ mod tests {
                 transport: "serial".into(),
                 path: Some("/dev/ttyACM0".into()),
                 baud: 115_200,
+                sensors: Vec::new(),
+                remote: None,
             }],
             datasheet_dir: None,
+            recorder: SensorRecorderConfig::default(),
+            node: PeripheralNodeConfig::default(),
         };
         let result = list_configured_boards(&config);
         assert!(
@@ -266,15 +334,21 @@ mod tests {
                     transport: "serial".into(),
                     path: Some("/dev/ttyACM0".into()),
                     baud: 115_200,
+                    sensors: Vec::new(),
+                    remote: None,
                 },
                 PeripheralBoardConfig {
                     board: "rpi-gpio".into(),
                     transport: "native".into(),
                     path: None,
                     baud: 115_200,
+                    sensors: Vec::new(),
+                    remote: None,
                 },
             ],
             datasheet_dir: None,
+            recorder: SensorRecorderConfig::default(),
+            node: PeripheralNodeConfig::default(),
         };
         let result = list_configured_boards(&config);
         assert_eq!(result.len(), 2);
@@ -288,6 +362,8 @@ mod tests {
             enabled: true,
             boards: vec![],
             datasheet_dir: None,
+            recorder: SensorRecorderConfig::default(),
+            node: PeripheralNodeConfig::default(),
         };
         let result = list_configured_boards(&config);
         assert!(
@@ -302,6 +378,8 @@ mod tests {
             enabled: false,
             boards: vec![],
             datasheet_dir: None,
+            recorder: SensorRecorderConfig::default(),
+            node: PeripheralNodeConfig::default(),
         };
         let tools = create_peripheral_tools(&config).await.unwrap();
         assert!(
@@ -0,0 +1,390 @@
+//! Sensor time-series recorder (`[peripherals.recorder]`).
+//!
+//! The daemon samples every calibrated sensor pin on serial boards and
+//! remote nodes into a SQLite table in `workspace/sensors/readings.db`;
+//! `zeroclaw sensors query` summarizes a window of it with sparklines. Alert rules in
+//! [`super::alerts`] are checked after every sample.
+
+use crate::config::Config;
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use rusqlite::{params, Connection};
+use std::path::PathBuf;
+
+const SPARK_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const SPARK_WIDTH: usize = 48;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SensorSample {
+    pub recorded_at: DateTime<Utc>,
+    pub board: String,
+    pub pin: u32,
+    pub name: Option<String>,
+    pub raw: f64,
+    pub value: f64,
+    pub unit: Option<String>,
+}
+
+fn db_path(config: &Config) -> PathBuf {
+    config.workspace_dir.join("sensors").join("readings.db")
+}
+
+pub(super) fn open(config: &Config) -> Result<Connection> {
+    let path = db_path(config);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let conn = Connection::open(&path)
+        .with_context(|| format!("Failed to open sensor DB: {}", path.display()))?;
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS sensor_readings (
+            recorded_at TEXT NOT NULL,
+            board       TEXT NOT NULL,
+            pin         INTEGER NOT NULL,
+            name        TEXT,
+            raw         REAL NOT NULL,
+            value       REAL NOT NULL,
+            unit        TEXT
+         );
+         CREATE INDEX IF NOT EXISTS idx_sensor_readings_time
+            ON sensor_readings(recorded_at);",
+    )
+    .context("Failed to initialize sensor_readings table")?;
+    Ok(conn)
+}
+
+fn timestamp(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn record(conn: &Connection, samples: &[SensorSample]) -> Result<()> {
+    let mut stmt = conn.prepare(
+        "INSERT INTO sensor_readings (recorded_at, board, pin, name, raw, value, unit)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+    )?;
+    for sample in samples {
+        stmt.execute(params![
+            timestamp(sample.recorded_at),
+            sample.board,
+            sample.pin,
+            sample.name,
+            sample.raw,
+            sample.value,
+            sample.unit,
+        ])?;
+    }
+    Ok(())
+}
+
+fn prune(conn: &Connection, before: DateTime<Utc>) -> Result<usize> {
+    Ok(conn.execute(
+        "DELETE FROM sensor_readings WHERE recorded_at < ?1",
+        params![timestamp(before)],
+    )?)
+}
+
+pub(super) fn query(
+    conn: &Connection,
+    since: DateTime<Utc>,
+    board: Option<&str>,
+    pin: Option<u32>,
+) -> Result<Vec<SensorSample>> {
+    let mut stmt = conn.prepare(
+        "SELECT recorded_at, board, pin, name, raw, value, unit FROM sensor_readings
+         WHERE recorded_at >= ?1
+           AND (?2 IS NULL OR board = ?2)
+           AND (?3 IS NULL OR pin = ?3)
+         ORDER BY board, pin, recorded_at",
+    )?;
+    let rows = stmt.query_map(params![timestamp(since), board, pin], |row| {
+        let recorded_at: String = row.get(0)?;
+        Ok(SensorSample {
+            recorded_at: DateTime::parse_from_rfc3339(&recorded_at)
+                .map(|at| at.with_timezone(&Utc))
+                .unwrap_or_default(),
+            board: row.get(1)?,
+            pin: row.get(2)?,
+            name: row.get(3)?,
+            raw: row.get(4)?,
+            value: row.get(5)?,
+            unit: row.get(6)?,
+        })
+    })?;
+    Ok(rows.collect::<rusqlite::Result<_>>()?)
+}
+
+/// A look-back window such as `90m`, `24h`, `7d` or `2w`.
+pub fn parse_window(input: &str) -> Result<chrono::Duration> {
+    let input = input.trim();
+    let split = input
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len());
+    let (num, unit) = input.split_at(split);
+    let amount: i64 = num
+        .parse()
+        .with_context(|| format!("invalid window '{input}', expected e.g. 24h"))?;
+    Ok(match unit {
+        "m" => chrono::Duration::minutes(amount),
+        "h" | "" => chrono::Duration::hours(amount),
+        "d" => chrono::Duration::days(amount),
+        "w" => chrono::Duration::weeks(amount),
+        _ => bail!("unsupported window unit '{unit}', use m/h/d/w"),
+    })
+}
+
+/// Average `values` into at most `width` buckets and draw them as a
+/// sparkline scaled between the series minimum and maximum.
+pub fn sparkline(values: &[f64], width: usize) -> String {
+    if values.is_empty() || width == 0 {
+        return String::new();
+    }
+    let buckets = values.len().min(width);
+    let averaged: Vec<f64> = (0..buckets)
+        .map(|bucket| {
+            let start = bucket * values.len() / buckets;
+            let end = ((bucket + 1) * values.len() / buckets).max(start + 1);
+            let slice = &values[start..end];
+            #[allow(clippy::cast_precision_loss)]
+            let len = slice.len() as f64;
+            slice.iter().sum::<f64>() / len
+        })
+        .collect();
+    let min = averaged.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = averaged.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let span = max - min;
+    averaged
+        .iter()
+        .map(|value| {
+            if span <= f64::EPSILON {
+                return SPARK_LEVELS[SPARK_LEVELS.len() / 2];
+            }
+            #[allow(
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                clippy::cast_precision_loss
+            )]
+            let level = ((value - min) / span * (SPARK_LEVELS.len() - 1) as f64).round() as usize;
+            SPARK_LEVELS[level.min(SPARK_LEVELS.len() - 1)]
+        })
+        .collect()
+}
+
+/// Print a summary and sparkline per sensor for the last `window`.
+pub fn print_query(
+    config: &Config,
+    window: &str,
+    board: Option<&str>,
+    pin: Option<u32>,
+) -> Result<()> {
+    let since = Utc::now() - parse_window(window)?;
+    if !db_path(config).exists() {
+        println!("No sensor readings recorded yet.");
+        println!("Enable [peripherals.recorder] and run `zeroclaw daemon` to start sampling.");
+        return Ok(());
+    }
+    let samples = query(&open(config)?, since, board, pin)?;
+    if samples.is_empty() {
+        println!("No sensor readings in the last {window}.");
+        return Ok(());
+    }
+
+    println!("📈 Sensor readings (last {window})");
+    for series in samples.chunk_by(|a, b| a.board == b.board && a.pin == b.pin) {
+        let first = &series[0];
+        let latest = &series[series.len() - 1];
+        let values: Vec<f64> = series.iter().map(|sample| sample.value).collect();
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        #[allow(clippy::cast_precision_loss)]
+        let avg = values.iter().sum::<f64>() / values.len() as f64;
+        let unit = latest.unit.as_deref().unwrap_or("");
+        let label = first
+            .name
+            .as_deref()
+            .map_or_else(|| format!("pin {}", first.pin), str::to_string);
+
+        println!();
+        println!(
+            "  {label} ({} pin {}) · {} samples",
+            first.board,
+            first.pin,
+            series.len()
+        );
+        println!("    {}", sparkline(&values, SPARK_WIDTH));
+        println!(
+            "    min {min:.2} · avg {avg:.2} · max {max:.2} · latest {:.2} {unit} at {}",
+            latest.value,
+            latest.recorded_at.format("%Y-%m-%d %H:%M UTC")
+        );
+    }
+    Ok(())
+}
+
+fn sample(
+    board: &crate::config::PeripheralBoardConfig,
+    sensor: &crate::config::SensorCalibration,
+    raw: f64,
+) -> SensorSample {
+    let (value, unit) = sensor.reading(raw);
+    SensorSample {
+        recorded_at: Utc::now(),
+        board: board.board.clone(),
+        pin: sensor.pin,
+        name: sensor.name.clone(),
+        raw,
+        value,
+        unit: unit.map(str::to_string),
+    }
+}
+
+/// Take one reading from every calibrated sensor on remote nodes.
+async fn sample_remote(config: &Config, samples: &mut Vec<SensorSample>) {
+    for board in &config.peripherals.boards {
+        if !super::remote::is_remote(board) || board.sensors.is_empty() {
+            continue;
+        }
+        let link = match super::remote::RemoteLink::from_board(board) {
+            Ok(link) => link,
+            Err(err) => {
+                tracing::warn!(board = %board.board, "Sensor recorder could not reach node: {err}");
+                continue;
+            }
+        };
+        for sensor in &board.sensors {
+            match link.analog_read(sensor.pin).await {
+                Ok(raw) => samples.push(sample(board, sensor, raw)),
+                Err(err) => tracing::warn!(board = %board.board, pin = sensor.pin, "{err}"),
+            }
+        }
+    }
+}
+
+/// Take one reading from every calibrated sensor on serial boards and
+/// remote nodes.
+#[cfg(feature = "hardware")]
+async fn sample_all(config: &Config) -> Vec<SensorSample> {
+    let mut samples = Vec::new();
+    sample_remote(config, &mut samples).await;
+    for board in &config.peripherals.boards {
+        if board.transport != "serial" || board.sensors.is_empty() {
+            continue;
+        }
+        let peripheral = match super::serial::SerialPeripheral::connect(board).await {
+            Ok(peripheral) => peripheral,
+            Err(err) => {
+                tracing::warn!(board = %board.board, "Sensor recorder could not open board: {err}");
+                continue;
+            }
+        };
+        let transport = peripheral.transport();
+        for sensor in &board.sensors {
+            match transport.analog_read(sensor.pin).await {
+                Ok(raw) => samples.push(sample(board, sensor, raw)),
+                Err(err) => tracing::warn!(board = %board.board, pin = sensor.pin, "{err}"),
+            }
+        }
+    }
+    samples
+}
+
+#[cfg(not(feature = "hardware"))]
+async fn sample_all(config: &Config) -> Vec<SensorSample> {
+    let mut samples = Vec::new();
+    sample_remote(config, &mut samples).await;
+    samples
+}
+
+/// Daemon worker: sample on `interval_secs` and prune past `retention_days`.
+pub async fn run_worker(config: Config) -> Result<()> {
+    let has_remote = config
+        .peripherals
+        .boards
+        .iter()
+        .any(super::remote::is_remote);
+    if !cfg!(feature = "hardware") && !has_remote {
+        tracing::warn!("Sensor recorder needs the 'hardware' feature; not sampling");
+        return std::future::pending().await;
+    }
+    let recorder = &config.peripherals.recorder;
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(
+        recorder.interval_secs.max(1),
+    ));
+    loop {
+        interval.tick().await;
+        let samples = sample_all(&config).await;
+        let announcements = {
+            let conn = open(&config)?;
+            record(&conn, &samples)?;
+            prune(
+                &conn,
+                Utc::now() - chrono::Duration::days(i64::from(recorder.retention_days)),
+            )?;
+            super::alerts::check_all(&config, &conn).unwrap_or_else(|err| {
+                tracing::warn!("Sensor alert check failed: {err}");
+                Vec::new()
+            })
+        };
+        Box::pin(super::alerts::dispatch(&config, announcements)).await;
+        crate::health::mark_component_ok("sensor-recorder");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn sample(minutes_ago: i64, pin: u32, value: f64) -> SensorSample {
+        SensorSample {
+            recorded_at: Utc::now() - chrono::Duration::minutes(minutes_ago),
+            board: "esp32".into(),
+            pin,
+            name: Some("tank".into()),
+            raw: value * 10.0,
+            value,
+            unit: Some("C".into()),
+        }
+    }
+
+    #[test]
+    fn readings_round_trip_prune_and_draw() {
+        let tmp = TempDir::new().unwrap();
+        let config = Config {
+            workspace_dir: tmp.path().to_path_buf(),
+            ..Config::default()
+        };
+        let conn = open(&config).unwrap();
+        record(
+            &conn,
+            &[
+                sample(3 * 24 * 60, 0, 10.0),
+                sample(30, 0, 20.0),
+                sample(10, 0, 22.5),
+                sample(10, 1, 1.0),
+            ],
+        )
+        .unwrap();
+
+        let day = Utc::now() - parse_window("24h").unwrap();
+        let pin0 = query(&conn, day, Some("esp32"), Some(0)).unwrap();
+        assert_eq!(pin0.len(), 2);
+        assert!((pin0[1].value - 22.5).abs() < 1e-9);
+        assert_eq!(pin0[1].unit.as_deref(), Some("C"));
+
+        assert_eq!(
+            prune(&conn, Utc::now() - chrono::Duration::days(2)).unwrap(),
+            1
+        );
+        assert_eq!(
+            query(&conn, day - chrono::Duration::days(7), None, None)
+                .unwrap()
+                .len(),
+            3
+        );
+
+        assert_eq!(sparkline(&[1.0, 2.0, 3.0], 48), "▁▅█");
+        assert_eq!(sparkline(&[5.0; 100], 4).chars().count(), 4);
+        assert!(parse_window("3x").is_err());
+    }
+}
@@ -0,0 +1,620 @@
+//! Remote peripheral nodes over HTTP or MQTT.
+//!
+//! A board with `transport = "http"` or `"mqtt"` is another ZeroClaw serving
+//! `[peripherals.node]`, or networked firmware speaking the serial JSON
+//! protocol (`{"id","cmd","args"}` → `{"id","ok","result","error"}`). Its
+//! tools are fetched with a `describe` request and registered here as
+//! `<board>_<tool>`, so one agent drives boards in several rooms as if they
+//! were plugged in locally. [`serve_request`] and [`run_mqtt_node`] are the
+//! node side.
+
+use crate::config::{Config, PeripheralBoardConfig, SensorCalibration};
+use crate::tools::traits::{Tool, ToolResult, ToolSpec};
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+/// Gateway route a node answers requests on.
+pub const RPC_PATH: &str = "/api/peripherals/rpc";
+const DEFAULT_TOPIC: &str = "zeroclaw/node";
+const DEFAULT_MQTT_PORT: u16 = 1883;
+const MQTT_KEEPALIVE_SECS: u16 = 60;
+
+/// Whether `board` is reached over the network rather than locally.
+pub fn is_remote(board: &PeripheralBoardConfig) -> bool {
+    matches!(board.transport.as_str(), "http" | "mqtt")
+}
+
+fn topic_or_default(topic: Option<&str>) -> &str {
+    topic
+        .map(|topic| topic.trim().trim_end_matches('/'))
+        .filter(|topic| !topic.is_empty())
+        .unwrap_or(DEFAULT_TOPIC)
+}
+
+fn next_id() -> String {
+    static ID: AtomicU64 = AtomicU64::new(0);
+    format!(
+        "{}-{}",
+        std::process::id(),
+        ID.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// `<board>_<tool>`, restricted to characters tool names allow.
+fn remote_tool_name(board: &str, tool: &str) -> String {
+    format!("{board}_{tool}")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+enum Transport {
+    Http {
+        client: reqwest::Client,
+        endpoint: String,
+        token: Option<String>,
+    },
+    Mqtt {
+        broker: String,
+        topic: String,
+        password: Option<String>,
+    },
+}
+
+/// Request/response link to one remote node.
+pub struct RemoteLink {
+    transport: Transport,
+    timeout: Duration,
+}
+
+impl RemoteLink {
+    pub fn from_board(board: &PeripheralBoardConfig) -> Result<Self> {
+        let remote = board.remote.as_ref().with_context(|| {
+            format!(
+                "Board {} uses transport {} but has no [peripherals.boards.remote] section",
+                board.board, board.transport
+            )
+        })?;
+        let transport = match board.transport.as_str() {
+            "http" => Transport::Http {
+                client: reqwest::Client::new(),
+                endpoint: format!("{}{RPC_PATH}", remote.url.trim_end_matches('/')),
+                token: remote.token.clone(),
+            },
+            "mqtt" => Transport::Mqtt {
+                broker: broker_address(&remote.url)?,
+                topic: topic_or_default(remote.topic.as_deref()).to_string(),
+                password: remote.token.clone(),
+            },
+            other => bail!("Not a remote transport: {other}"),
+        };
+        Ok(Self {
+            transport,
+            timeout: Duration::from_secs(remote.timeout_secs.max(1)),
+        })
+    }
+
+    async fn exchange(&self, request: Value) -> Result<Value> {
+        let exchange = async {
+            match &self.transport {
+                Transport::Http {
+                    client,
+                    endpoint,
+                    token,
+                } => {
+                    let mut http = client.post(endpoint).json(&request);
+                    if let Some(token) = token {
+                        http = http.bearer_auth(token);
+                    }
+                    let response = http.send().await?.error_for_status()?;
+                    Ok(response.json::<Value>().await?)
+                }
+                Transport::Mqtt {
+                    broker,
+                    topic,
+                    password,
+                } => mqtt_exchange(broker, topic, password.as_deref(), &request).await,
+            }
+        };
+        tokio::time::timeout(self.timeout, exchange)
+            .await
+            .map_err(|_| {
+                anyhow::anyhow!("Remote node timed out after {}s", self.timeout.as_secs())
+            })?
+    }
+
+    /// Send `cmd` with `args` and wait for the node's reply.
+    pub async fn request(&self, cmd: &str, args: Value) -> Result<ToolResult> {
+        let id = next_id();
+        let response = self
+            .exchange(json!({ "id": id, "cmd": cmd, "args": args }))
+            .await?;
+        if response["id"].as_str() != Some(id.as_str()) {
+            bail!(
+                "Response id mismatch: expected {id}, got {}",
+                response["id"].as_str().unwrap_or("")
+            );
+        }
+        Ok(ToolResult {
+            success: response["ok"].as_bool().unwrap_or(false),
+            output: response["result"]
+                .as_str()
+                .map(String::from)
+                .unwrap_or_else(|| response["result"].to_string()),
+            error: response["error"].as_str().map(String::from),
+        })
+    }
+
+    /// Raw ADC count from `pin` (used by the sensor recorder).
+    pub async fn analog_read(&self, pin: u32) -> Result<f64> {
+        let result = self.request("analog_read", json!({ "pin": pin })).await?;
+        if !result.success {
+            bail!(
+                "analog_read failed: {}",
+                result.error.unwrap_or(result.output)
+            );
+        }
+        result
+            .output
+            .trim()
+            .parse()
+            .with_context(|| format!("analog_read returned non-numeric {:?}", result.output))
+    }
+
+    /// The node's tool specs; firmware without `describe` gets the basic
+    /// serial command set.
+    async fn describe(&self) -> Vec<ToolSpec> {
+        match self.request("describe", json!({})).await {
+            Ok(result) if result.success => {
+                let parsed: Result<Vec<ToolSpec>, _> =
+                    serde_json::from_str::<Value>(&result.output)
+                        .and_then(|value| serde_json::from_value(value["tools"].clone()));
+                match parsed {
+                    Ok(specs) => return specs,
+                    Err(err) => tracing::warn!("Remote node sent an unreadable describe: {err}"),
+                }
+            }
+            Ok(_) => {}
+            Err(err) => tracing::debug!("Remote node describe failed: {err}"),
+        }
+        basic_specs()
+    }
+}
+
+fn pin_schema(extra: Option<(&str, Value)>) -> Value {
+    let mut properties = json!({
+        "pin": { "type": "integer", "description": "Pin number" }
+    });
+    let mut required = vec!["pin"];
+    if let Some((name, schema)) = extra {
+        properties[name] = schema;
+        required.push(name);
+    }
+    json!({ "type": "object", "properties": properties, "required": required })
+}
+
+fn basic_specs() -> Vec<ToolSpec> {
+    vec![
+        ToolSpec {
+            name: "gpio_read".into(),
+            description: "Read a GPIO pin (0 or 1)".into(),
+            parameters: pin_schema(None),
+        },
+        ToolSpec {
+            name: "gpio_write".into(),
+            description: "Set a GPIO pin high (1) or low (0)".into(),
+            parameters: pin_schema(Some((
+                "value",
+                json!({ "type": "integer", "description": "0 or 1" }),
+            ))),
+        },
+        ToolSpec {
+            name: "analog_read".into(),
+            description: "Read an analog sensor pin".into(),
+            parameters: pin_schema(None),
+        },
+    ]
+}
+
+/// One of a remote node's tools, registered under a board-prefixed name.
+struct RemoteTool {
+    link: Arc<RemoteLink>,
+    name: String,
+    remote_name: String,
+    description: String,
+    parameters: Value,
+    sensors: Arc<Vec<SensorCalibration>>,
+}
+
+#[async_trait]
+impl Tool for RemoteTool {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn parameters_schema(&self) -> Value {
+        self.parameters.clone()
+    }
+
+    async fn execute(&self, args: Value) -> Result<ToolResult> {
+        let pin = args
+            .get("pin")
+            .and_then(Value::as_u64)
+            .and_then(|pin| u32::try_from(pin).ok());
+        let mut result = self.link.request(&self.remote_name, args).await?;
+        if let (true, "analog_read", Some(pin)) = (result.success, self.remote_name.as_str(), pin) {
+            if let Ok(raw) = result.output.trim().parse::<f64>() {
+                result.output = super::calibration::describe_reading(&self.sensors, pin, raw);
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Connect to a remote board and return its tools (empty on failure).
+pub async fn connect_tools(board: &PeripheralBoardConfig) -> Vec<Box<dyn Tool>> {
+    let link = match RemoteLink::from_board(board) {
+        Ok(link) => Arc::new(link),
+        Err(err) => {
+            tracing::warn!(board = %board.board, "Skipping remote node: {err}");
+            return Vec::new();
+        }
+    };
+    let sensors = Arc::new(board.sensors.clone());
+    let specs = link.describe().await;
+    tracing::info!(board = %board.board, tools = specs.len(), "Remote peripheral node connected");
+    specs
+        .into_iter()
+        .map(|spec| {
+            Box::new(RemoteTool {
+                link: Arc::clone(&link),
+                name: remote_tool_name(&board.board, &spec.name),
+                description: format!("[remote node {}] {}", board.board, spec.description),
+                remote_name: spec.name,
+                parameters: spec.parameters,
+                sensors: Arc::clone(&sensors),
+            }) as Box<dyn Tool>
+        })
+        .collect()
+}
+
+// ── Node side ────────────────────────────────────────────────────
+
+static LOCAL_TOOLS: tokio::sync::OnceCell<Vec<Box<dyn Tool>>> = tokio::sync::OnceCell::const_new();
+
+/// This instance's own board tools; remote boards are left out so nodes
+/// never relay to each other.
+async fn local_tools(config: &Config) -> &'static [Box<dyn Tool>] {
+    LOCAL_TOOLS
+        .get_or_init(|| async {
+            let mut local = config.peripherals.clone();
+            local.boards.retain(|board| !is_remote(board));
+            super::create_peripheral_tools(&local)
+                .await
+                .unwrap_or_else(|err| {
+                    tracing::warn!("Peripheral node could not open local boards: {err}");
+                    Vec::new()
+                })
+        })
+        .await
+}
+
+fn estop_engaged(config: &Config) -> bool {
+    if !config.security.estop.enabled {
+        return false;
+    }
+    let config_dir = config
+        .config_path
+        .parent()
+        .unwrap_or_else(|| std::path::Path::new("."));
+    crate::security::EstopManager::load(&config.security.estop, config_dir)
+        .map_or(true, |manager| manager.status().is_engaged())
+}
+
+/// Answer one protocol request from a primary instance.
+pub async fn serve_request(config: &Config, request: &Value) -> Value {
+    let id = request["id"].as_str().unwrap_or_default();
+    let cmd = request["cmd"].as_str().unwrap_or_default();
+    let args = request.get("args").cloned().unwrap_or_else(|| json!({}));
+    let tools = local_tools(config).await;
+
+    if cmd == "describe" {
+        let specs: Vec<ToolSpec> = tools.iter().map(|tool| tool.spec()).collect();
+        return json!({ "id": id, "ok": true, "result": json!({ "tools": specs }).to_string() });
+    }
+    if estop_engaged(config) {
+        return json!({ "id": id, "ok": false, "error": "Emergency stop is engaged on this node" });
+    }
+    let Some(tool) = tools.iter().find(|tool| tool.name() == cmd) else {
+        return json!({ "id": id, "ok": false, "error": format!("Unknown command: {cmd}") });
+    };
+    match tool.execute(args).await {
+        Ok(result) => json!({
+            "id": id,
+            "ok": result.success,
+            "result": result.output,
+            "error": result.error,
+        }),
+        Err(err) => json!({ "id": id, "ok": false, "error": err.to_string() }),
+    }
+}
+
+/// Daemon worker: answer requests published to `<topic>/request` on the
+/// `[peripherals.node]` MQTT broker.
+pub async fn run_mqtt_node(config: Config) -> Result<()> {
+    let node = &config.peripherals.node;
+    let Some(broker) = node.mqtt_broker.as_deref() else {
+        return std::future::pending().await;
+    };
+    let topic = topic_or_default(node.mqtt_topic.as_deref());
+    let (request_topic, response_topic) = (format!("{topic}/request"), format!("{topic}/response"));
+
+    let mut stream = mqtt_connect(
+        &broker_address(broker)?,
+        &format!("zeroclaw-node-{}", std::process::id()),
+        node.mqtt_password.as_deref(),
+    )
+    .await?;
+    mqtt_subscribe(&mut stream, &request_topic).await?;
+    tracing::info!("Peripheral node serving on mqtt {broker} topic {topic}");
+    crate::health::mark_component_ok("peripheral-node");
+
+    // Packets are read on their own task so keepalive pings never cut a
+    // read short.
+    let (mut reader, mut writer) = stream.into_split();
+    let (packets_tx, mut packets) = tokio::sync::mpsc::channel(16);
+    let reader_task = tokio::spawn(async move {
+        loop {
+            let packet = read_packet(&mut reader).await;
+            let failed = packet.is_err();
+            if packets_tx.send(packet).await.is_err() || failed {
+                break;
+            }
+        }
+    });
+
+    let mut ping = tokio::time::interval(Duration::from_secs(u64::from(MQTT_KEEPALIVE_SECS / 2)));
+    let served: Result<()> = async {
+        loop {
+            tokio::select! {
+                _ = ping.tick() => {
+                    writer.write_all(&[0xC0, 0x00]).await?;
+                    crate::health::mark_component_ok("peripheral-node");
+                }
+                packet = packets.recv() => {
+                    let Some(packet) = packet else {
+                        bail!("MQTT broker closed the connection");
+                    };
+                    let (header, body) = packet?;
+                    let Some((packet_topic, payload)) = parse_publish(header, &body) else {
+                        continue;
+                    };
+                    if packet_topic != request_topic {
+                        continue;
+                    }
+                    let Ok(request) = serde_json::from_slice::<Value>(&payload) else {
+                        tracing::warn!("Ignoring malformed peripheral request on {packet_topic}");
+                        continue;
+                    };
+                    let response = serve_request(&config, &request).await;
+                    mqtt_publish(&mut writer, &response_topic, response.to_string().as_bytes())
+                        .await?;
+                }
+            }
+        }
+    }
+    .await;
+    reader_task.abort();
+    served
+}
+
+// ── Minimal MQTT 3.1.1 (QoS 0) ───────────────────────────────────
+
+/// `host:port` from `mqtt://host[:port]` or a bare `host[:port]`.
+fn broker_address(url: &str) -> Result<String> {
+    let url = url.trim();
+    if url.starts_with("mqtts://") {
+        bail!("TLS MQTT brokers are not supported yet; use mqtt:// on a trusted network");
+    }
+    let host = url
+        .strip_prefix("mqtt://")
+        .unwrap_or(url)
+        .trim_end_matches('/');
+    if host.is_empty() {
+        bail!("MQTT broker address is empty");
+    }
+    Ok(
+        if host
+            .rsplit_once(':')
+            .is_some_and(|(_, port)| port.parse::<u16>().is_ok())
+        {
+            host.to_string()
+        } else {
+            format!("{host}:{DEFAULT_MQTT_PORT}")
+        },
+    )
+}
+
+fn put_str(out: &mut Vec<u8>, value: &[u8]) {
+    let len = u16::try_from(value.len()).unwrap_or(u16::MAX);
+    out.extend_from_slice(&len.to_be_bytes());
+    out.extend_from_slice(&value[..usize::from(len)]);
+}
+
+fn packet(header: u8, body: &[u8]) -> Vec<u8> {
+    let mut out = vec![header];
+    let mut len = body.len();
+    loop {
+        #[allow(clippy::cast_possible_truncation)]
+        let mut byte = (len % 128) as u8;
+        len /= 128;
+        if len > 0 {
+            byte |= 0x80;
+        }
+        out.push(byte);
+        if len == 0 {
+            break;
+        }
+    }
+    out.extend_from_slice(body);
+    out
+}
+
+async fn read_packet<R: AsyncRead + Unpin>(stream: &mut R) -> Result<(u8, Vec<u8>)> {
+    let header = stream.read_u8().await?;
+    let (mut len, mut shift) = (0usize, 0u32);
+    loop {
+        let byte = stream.read_u8().await?;
+        len |= usize::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+        if shift > 21 {
+            bail!("Malformed MQTT packet length");
+        }
+    }
+    let mut body = vec![0; len];
+    stream.read_exact(&mut body).await?;
+    Ok((header, body))
+}
+
+/// Topic and payload of a PUBLISH packet; `None` for other packets.
+fn parse_publish(header: u8, body: &[u8]) -> Option<(String, Vec<u8>)> {
+    if header >> 4 != 3 || body.len() < 2 {
+        return None;
+    }
+    let topic_len = usize::from(u16::from_be_bytes([body[0], body[1]]));
+    let topic = std::str::from_utf8(body.get(2..2 + topic_len)?).ok()?;
+    // QoS 1/2 publishes carry a packet id after the topic.
+    let skip = if header & 0x06 == 0 { 0 } else { 2 };
+    let payload = body.get(2 + topic_len + skip..)?;
+    Some((topic.to_string(), payload.to_vec()))
+}
+
+async fn mqtt_connect(broker: &str, client_id: &str, password: Option<&str>) -> Result<TcpStream> {
+    let mut stream = TcpStream::connect(broker)
+        .await
+        .with_context(|| format!("Failed to reach MQTT broker {broker}"))?;
+    let mut body = Vec::new();
+    put_str(&mut body, b"MQTT");
+    body.push(4); // protocol level 3.1.1
+    body.push(if password.is_some() { 0xC2 } else { 0x02 }); // clean session (+ user/pass)
+    body.extend_from_slice(&MQTT_KEEPALIVE_SECS.to_be_bytes());
+    put_str(&mut body, client_id.as_bytes());
+    if let Some(password) = password {
+        put_str(&mut body, b"zeroclaw");
+        put_str(&mut body, password.as_bytes());
+    }
+    stream.write_all(&packet(0x10, &body)).await?;
+    let (header, ack) = read_packet(&mut stream).await?;
+    if header != 0x20 || ack.get(1) != Some(&0) {
+        bail!(
+            "MQTT broker {broker} refused the connection (code {})",
+            ack.get(1).copied().unwrap_or(0xFF)
+        );
+    }
+    Ok(stream)
+}
+
+async fn mqtt_subscribe(stream: &mut TcpStream, topic: &str) -> Result<()> {
+    let mut body = vec![0x00, 0x01];
+    put_str(&mut body, topic.as_bytes());
+    body.push(0); // QoS 0
+    stream.write_all(&packet(0x82, &body)).await?;
+    let (header, _) = read_packet(stream).await?;
+    if header != 0x90 {
+        bail!("MQTT broker did not acknowledge subscription to {topic}");
+    }
+    Ok(())
+}
+
+async fn mqtt_publish<W: AsyncWrite + Unpin>(
+    stream: &mut W,
+    topic: &str,
+    payload: &[u8],
+) -> Result<()> {
+    let mut body = Vec::new();
+    put_str(&mut body, topic.as_bytes());
+    body.extend_from_slice(payload);
+    stream.write_all(&packet(0x30, &body)).await?;
+    Ok(())
+}
+
+async fn mqtt_exchange(
+    broker: &str,
+    topic: &str,
+    password: Option<&str>,
+    request: &Value,
+) -> Result<Value> {
+    let response_topic = format!("{topic}/response");
+    let mut stream = mqtt_connect(broker, &format!("zeroclaw-{}", next_id()), password).await?;
+    mqtt_subscribe(&mut stream, &response_topic).await?;
+    mqtt_publish(
+        &mut stream,
+        &format!("{topic}/request"),
+        request.to_string().as_bytes(),
+    )
+    .await?;
+    loop {
+        let (header, body) = read_packet(&mut stream).await?;
+        let Some((packet_topic, payload)) = parse_publish(header, &body) else {
+            continue;
+        };
+        let Ok(response) = serde_json::from_slice::<Value>(&payload) else {
+            continue;
+        };
+        if packet_topic == response_topic && response["id"] == request["id"] {
+            let _ = stream.write_all(&[0xE0, 0x00]).await;
+            return Ok(response);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remote_names_brokers_and_mqtt_framing() {
+        assert_eq!(
+            remote_tool_name("living room", "gpio_write"),
+            "living_room_gpio_write"
+        );
+        assert_eq!(
+            broker_address("mqtt://broker.local").unwrap(),
+            "broker.local:1883"
+        );
+        assert_eq!(broker_address("10.0.0.2:1884").unwrap(), "10.0.0.2:1884");
+        assert!(broker_address("mqtts://broker").is_err());
+        assert_eq!(topic_or_default(Some(" home/kitchen/ ")), "home/kitchen");
+        assert_eq!(topic_or_default(None), DEFAULT_TOPIC);
+
+        let mut body = Vec::new();
+        put_str(&mut body, b"zeroclaw/node/response");
+        body.extend_from_slice(br#"{"id":"1"}"#);
+        let framed = packet(0x30, &body);
+        assert_eq!(framed[1] as usize, body.len());
+        let (topic, payload) = parse_publish(framed[0], &framed[2..]).unwrap();
+        assert_eq!(topic, "zeroclaw/node/response");
+        assert_eq!(payload, br#"{"id":"1"}"#);
+        assert_eq!(packet(0x30, &[0; 200])[1..3], [0xC8, 0x01]);
+        assert!(parse_publish(0x90, &[0, 1, 0]).is_none());
+    }
+}
@@ -111,6 +111,19 @@ impl Tool for RpiGpioReadTool {
     }
 }
 
+/// Block until `pin` (BCM, pulled up) reads low, i.e. the kill switch closes.
+pub async fn wait_for_kill_switch(pin: u8) -> anyhow::Result<()> {
+    tokio::task::spawn_blocking(move || {
+        let gpio = rppal::gpio::Gpio::new()?;
+        let input = gpio.get(pin)?.into_input_pullup();
+        while input.read() == rppal::gpio::Level::High {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+        Ok::<_, anyhow::Error>(())
+    })
+    .await?
+}
+
 /// Tool: write GPIO pin value (BCM numbering).
 struct RpiGpioWriteTool;
 
@@ -141,6 +154,18 @@ impl Tool for RpiGpioWriteTool {
         })
     }
 
+    fn dry_run_description(&self, args: &Value) -> String {
+        let level = match args.get("value").and_then(|v| v.as_u64()) {
+            Some(0) => "LOW",
+            Some(_) => "HIGH",
+            None => "<missing value>",
+        };
+        format!(
+            "would set GPIO pin {} {level} on Raspberry Pi (BCM numbering)",
+            args["pin"]
+        )
+    }
+
     async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
         let pin = args
             .get("pin")
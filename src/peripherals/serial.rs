@@ -5,7 +5,7 @@
 //! Response: {"id":"1","ok":true,"result":"done"}
 
 use super::traits::Peripheral;
-use crate::config::PeripheralBoardConfig;
+use crate::config::{PeripheralBoardConfig, SensorCalibration};
 use crate::tools::traits::{Tool, ToolResult};
 use async_trait::async_trait;
 use serde_json::{json, Value};
@@ -23,11 +23,30 @@ const ALLOWED_PATH_PREFIXES: &[&str] = &[
     "/dev/cu.usbmodem",
     "/dev/tty.usbserial",
     "/dev/cu.usbserial", // Arduino Uno (FTDI), clones
-    "COM",               // Windows
 ];
 
 fn is_path_allowed(path: &str) -> bool {
     ALLOWED_PATH_PREFIXES.iter().any(|p| path.starts_with(p))
+        || is_windows_com_port(&normalize_serial_path(path))
+}
+
+/// Normalize a serial path for comparison: Windows accepts both `COM10` and
+/// the device-namespace form `\\.\COM10`, case-insensitively.
+pub fn normalize_serial_path(path: &str) -> String {
+    let trimmed = path.trim();
+    match trimmed.strip_prefix(r"\\.\") {
+        Some(port) => port.to_ascii_uppercase(),
+        None if is_windows_com_port(trimmed) => trimmed.to_ascii_uppercase(),
+        None => trimmed.to_string(),
+    }
+}
+
+/// `COM<n>` (any case), the Windows serial port naming scheme.
+pub fn is_windows_com_port(path: &str) -> bool {
+    path.get(..3)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("COM"))
+        && path.len() > 3
+        && path[3..].bytes().all(|b| b.is_ascii_digit())
 }
 
 /// JSON request/response over serial.
@@ -101,13 +120,36 @@ impl SerialTransport {
     pub async fn capabilities(&self) -> anyhow::Result<ToolResult> {
         self.request("capabilities", json!({})).await
     }
+
+    /// Raw ADC count from `pin` (used by the sensor recorder).
+    pub async fn analog_read(&self, pin: u32) -> anyhow::Result<f64> {
+        let result = self.request("analog_read", json!({ "pin": pin })).await?;
+        if !result.success {
+            anyhow::bail!(
+                "analog_read on pin {pin} failed: {}",
+                result.error.unwrap_or(result.output)
+            );
+        }
+        result
+            .output
+            .trim()
+            .parse()
+            .map_err(|_| anyhow::anyhow!("analog_read on pin {pin} returned {:?}", result.output))
+    }
 }
 
+/// Open transports by port path, so tools and the sensor recorder in one
+/// process share a port instead of fighting over exclusive access.
+static OPEN_PORTS: std::sync::OnceLock<
+    parking_lot::Mutex<std::collections::HashMap<String, std::sync::Weak<SerialTransport>>>,
+> = std::sync::OnceLock::new();
+
 /// Serial peripheral for STM32, Arduino, etc. over USB CDC.
 pub struct SerialPeripheral {
     name: String,
     board_type: String,
     transport: Arc<SerialTransport>,
+    sensors: Arc<Vec<SensorCalibration>>,
 }
 
 impl SerialPeripheral {
@@ -121,24 +163,35 @@ impl SerialPeripheral {
 
         if !is_path_allowed(path) {
             anyhow::bail!(
-                "Serial path not allowed: {}. Allowed: /dev/ttyACM*, /dev/ttyUSB*, /dev/tty.usbmodem*, /dev/cu.usbmodem*",
+                "Serial path not allowed: {}. Allowed: /dev/ttyACM*, /dev/ttyUSB*, /dev/tty.usbmodem*, /dev/cu.usbmodem*, COM<n>",
                 path
             );
         }
 
-        let port = tokio_serial::new(path, config.baud)
-            .open_native_async()
-            .map_err(|e| anyhow::anyhow!("Failed to open {}: {}", path, e))?;
-
-        let name = format!("{}-{}", config.board, path.replace('/', "_"));
-        let transport = Arc::new(SerialTransport {
-            port: Mutex::new(port),
-        });
+        let mut open_ports = OPEN_PORTS.get_or_init(Default::default).lock();
+        let key = normalize_serial_path(path);
+        let transport = match open_ports.get(&key).and_then(std::sync::Weak::upgrade) {
+            Some(transport) => transport,
+            None => {
+                let port = tokio_serial::new(path, config.baud)
+                    .open_native_async()
+                    .map_err(|e| anyhow::anyhow!("Failed to open {}: {}", path, e))?;
+                let transport = Arc::new(SerialTransport {
+                    port: Mutex::new(port),
+                });
+                open_ports.insert(key, Arc::downgrade(&transport));
+                transport
+            }
+        };
+        drop(open_ports);
+
+        let name = format!("{}-{}", config.board, path.replace(['/', '\\'], "_"));
 
         Ok(Self {
             name: name.clone(),
             board_type: config.board.clone(),
             transport,
+            sensors: Arc::new(config.sensors.clone()),
         })
     }
 }
@@ -177,6 +230,10 @@ impl Peripheral for SerialPeripheral {
             Box::new(GpioWriteTool {
                 transport: self.transport.clone(),
             }),
+            Box::new(AnalogReadTool {
+                transport: self.transport.clone(),
+                sensors: self.sensors.clone(),
+            }),
         ]
     }
 }
@@ -227,6 +284,59 @@ impl Tool for GpioReadTool {
     }
 }
 
+/// Tool: read an analog pin, calibrated per `[[peripherals.boards.sensors]]`.
+struct AnalogReadTool {
+    transport: Arc<SerialTransport>,
+    sensors: Arc<Vec<SensorCalibration>>,
+}
+
+#[async_trait]
+impl Tool for AnalogReadTool {
+    fn name(&self) -> &str {
+        "analog_read"
+    }
+
+    fn description(&self) -> &str {
+        "Read an analog sensor pin on a connected peripheral. Calibrated pins report engineering units (e.g. °C, kPa); others report raw ADC counts"
+    }
+
+    fn parameters_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "pin": {
+                    "type": "integer",
+                    "description": "ADC pin/channel number"
+                }
+            },
+            "required": ["pin"]
+        })
+    }
+
+    async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
+        let pin = args
+            .get("pin")
+            .and_then(|v| v.as_u64())
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'pin' parameter"))?;
+        let mut result = self
+            .transport
+            .request("analog_read", json!({ "pin": pin }))
+            .await?;
+        if result.success {
+            match result.output.trim().parse::<f64>() {
+                Ok(raw) => {
+                    result.output = super::calibration::describe_reading(&self.sensors, pin, raw);
+                }
+                Err(_) => {
+                    tracing::warn!(pin, output = %result.output, "analog_read returned a non-numeric value");
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
 /// Tool: write GPIO pin value.
 struct GpioWriteTool {
     transport: Arc<SerialTransport>,
@@ -259,6 +369,18 @@ impl Tool for GpioWriteTool {
         })
     }
 
+    fn dry_run_description(&self, args: &Value) -> String {
+        let level = match args.get("value").and_then(|v| v.as_u64()) {
+            Some(0) => "LOW",
+            Some(_) => "HIGH",
+            None => "<missing value>",
+        };
+        format!(
+            "would set GPIO pin {} {level} on the connected serial peripheral",
+            args["pin"]
+        )
+    }
+
     async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
         let pin = args
             .get("pin")
@@ -273,3 +395,33 @@ impl Tool for GpioWriteTool {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serial_allowlist_accepts_unix_ttys_and_windows_com_ports() {
+        assert!(is_path_allowed("/dev/ttyACM0"));
+        assert!(is_path_allowed("COM4"));
+        assert!(is_path_allowed(r"\\.\COM17"));
+        assert!(!is_path_allowed("COMMAND.COM"));
+        assert!(!is_path_allowed("/etc/passwd"));
+    }
+
+    #[test]
+    fn windows_com_ports_are_recognized() {
+        assert!(is_windows_com_port("COM3"));
+        assert!(is_windows_com_port("com12"));
+        assert!(!is_windows_com_port("COM"));
+        assert!(!is_windows_com_port("COMMAND"));
+        assert!(!is_windows_com_port("/dev/ttyACM0"));
+    }
+
+    #[test]
+    fn serial_paths_normalize_device_namespace() {
+        assert_eq!(normalize_serial_path(r"\\.\COM10"), "COM10");
+        assert_eq!(normalize_serial_path("com3"), "COM3");
+        assert_eq!(normalize_serial_path("/dev/ttyACM0"), "/dev/ttyACM0");
+    }
+}
@@ -116,6 +116,18 @@ impl Tool for UnoQGpioWriteTool {
         })
     }
 
+    fn dry_run_description(&self, args: &Value) -> String {
+        let level = match args.get("value").and_then(|v| v.as_u64()) {
+            Some(0) => "LOW",
+            Some(_) => "HIGH",
+            None => "<missing value>",
+        };
+        format!(
+            "would set GPIO pin {} {level} on Arduino Uno Q",
+            args["pin"]
+        )
+    }
+
     async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
         let pin = args
             .get("pin")
@@ -0,0 +1,401 @@
+//! UPS and battery awareness.
+//!
+//! The daemon polls mains/battery state from a NUT server (`upsd`) or from
+//! `/sys/class/power_supply` and reacts to changes: losing mains power
+//! notifies the owner channel, exports the memory snapshot and optionally
+//! engages the emergency stop; a low battery notifies and flushes again;
+//! restored power notifies. `zeroclaw power status` prints one reading.
+
+use crate::config::{Config, PowerSource};
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::TcpStream;
+
+const SYSFS_POWER_SUPPLY: &str = "/sys/class/power_supply";
+const MIN_POLL_SECS: u64 = 5;
+const NUT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// One reading of the power source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PowerStatus {
+    /// Running from battery (mains lost).
+    pub on_battery: bool,
+    /// Battery charge in percent, when reported.
+    pub charge: Option<u8>,
+    /// The source flagged the battery as low, or the charge is below the
+    /// configured threshold while on battery.
+    pub low_battery: bool,
+    /// Which source produced the reading ("nut" or "sysfs").
+    pub source: &'static str,
+}
+
+/// A change worth reacting to between two readings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerEvent {
+    OnBattery,
+    LowBattery,
+    Restored,
+}
+
+/// Events for moving from `previous` (None on the first poll) to `current`.
+pub fn transitions(previous: Option<&PowerStatus>, current: &PowerStatus) -> Vec<PowerEvent> {
+    let was_on_battery = previous.is_some_and(|p| p.on_battery);
+    let was_low = previous.is_some_and(|p| p.low_battery);
+    let mut events = Vec::new();
+    if current.on_battery && !was_on_battery {
+        events.push(PowerEvent::OnBattery);
+    }
+    if current.on_battery && current.low_battery && !was_low {
+        events.push(PowerEvent::LowBattery);
+    }
+    if !current.on_battery && was_on_battery {
+        events.push(PowerEvent::Restored);
+    }
+    events
+}
+
+pub async fn handle_command(command: crate::PowerCommands, config: &Config) -> Result<()> {
+    match command {
+        crate::PowerCommands::Status => {
+            let status = read_status(config).await?;
+            println!("Source:   {}", status.source);
+            println!(
+                "Mains:    {}",
+                if status.on_battery {
+                    "lost (on battery)"
+                } else {
+                    "online"
+                }
+            );
+            match status.charge {
+                Some(charge) => println!("Battery:  {charge}%"),
+                None => println!("Battery:  not reported"),
+            }
+            if status.low_battery {
+                println!("Warning:  battery low");
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Read the configured power source once.
+pub async fn read_status(config: &Config) -> Result<PowerStatus> {
+    let power = &config.power;
+    let mut status = match power.source {
+        PowerSource::Nut => read_nut(&power.nut_address, &power.nut_ups).await?,
+        PowerSource::Sysfs => read_sysfs(Path::new(SYSFS_POWER_SUPPLY))?
+            .context("no mains or battery supply found under /sys/class/power_supply")?,
+        PowerSource::Auto => match read_nut(&power.nut_address, &power.nut_ups).await {
+            Ok(status) => status,
+            Err(nut_error) => read_sysfs(Path::new(SYSFS_POWER_SUPPLY))?.with_context(|| {
+                format!("no power source found (NUT: {nut_error}; no sysfs power supply)")
+            })?,
+        },
+    };
+    if status.on_battery
+        && status
+            .charge
+            .is_some_and(|charge| charge <= power.low_battery_percent)
+    {
+        status.low_battery = true;
+    }
+    Ok(status)
+}
+
+/// Mains and battery state from a `power_supply` class directory. `None`
+/// when it has no mains, UPS or battery entries.
+fn read_sysfs(root: &Path) -> Result<Option<PowerStatus>> {
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return Ok(None);
+    };
+    let read = |dir: &Path, name: &str| {
+        std::fs::read_to_string(dir.join(name))
+            .ok()
+            .map(|value| value.trim().to_string())
+    };
+
+    let mut mains_online: Option<bool> = None;
+    let mut discharging = false;
+    let mut charge: Option<u8> = None;
+    let mut low = false;
+    for entry in entries {
+        let dir = entry?.path();
+        match read(&dir, "type").as_deref() {
+            Some("Mains" | "USB") => {
+                let online = read(&dir, "online").as_deref() == Some("1");
+                mains_online = Some(mains_online.unwrap_or(false) || online);
+            }
+            Some("Battery" | "UPS") => {
+                discharging |= read(&dir, "status").as_deref() == Some("Discharging");
+                if let Some(capacity) = read(&dir, "capacity").and_then(|c| c.parse::<u8>().ok()) {
+                    charge = Some(charge.map_or(capacity, |c| c.min(capacity)));
+                }
+                low |= matches!(
+                    read(&dir, "capacity_level").as_deref(),
+                    Some("Low" | "Critical")
+                );
+            }
+            _ => {}
+        }
+    }
+    if mains_online.is_none() && charge.is_none() && !discharging {
+        return Ok(None);
+    }
+    let on_battery = match mains_online {
+        Some(online) => !online,
+        None => discharging,
+    };
+    Ok(Some(PowerStatus {
+        on_battery,
+        charge,
+        low_battery: on_battery && low,
+        source: "sysfs",
+    }))
+}
+
+/// `ups.status` and `battery.charge` from a NUT server.
+async fn read_nut(address: &str, ups: &str) -> Result<PowerStatus> {
+    let exchange = async {
+        let stream = TcpStream::connect(address)
+            .await
+            .with_context(|| format!("cannot reach NUT server at {address}"))?;
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+        let status = nut_get(&mut writer, &mut lines, ups, "ups.status")
+            .await?
+            .with_context(|| format!("UPS '{ups}' reports no ups.status"))?;
+        let charge = nut_get(&mut writer, &mut lines, ups, "battery.charge").await?;
+        Ok::<_, anyhow::Error>((status, charge))
+    };
+    let (status, charge) = tokio::time::timeout(NUT_TIMEOUT, exchange)
+        .await
+        .context("NUT server did not answer in time")??;
+
+    let flags: Vec<&str> = status.split_whitespace().collect();
+    let on_battery = flags.contains(&"OB");
+    Ok(PowerStatus {
+        on_battery,
+        charge: charge.and_then(|c| c.parse::<f64>().ok()).map(percent),
+        low_battery: on_battery && flags.contains(&"LB"),
+        source: "nut",
+    })
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn percent(value: f64) -> u8 {
+    value.clamp(0.0, 100.0).round() as u8
+}
+
+async fn nut_get(
+    writer: &mut OwnedWriteHalf,
+    lines: &mut Lines<BufReader<OwnedReadHalf>>,
+    ups: &str,
+    var: &str,
+) -> Result<Option<String>> {
+    writer
+        .write_all(format!("GET VAR {ups} {var}\n").as_bytes())
+        .await?;
+    let line = lines
+        .next_line()
+        .await?
+        .context("NUT server closed the connection")?;
+    parse_nut_var(&line)
+}
+
+/// Value of a `VAR <ups> <name> "<value>"` reply; `None` for a variable the
+/// UPS does not support, an error for any other `ERR`.
+fn parse_nut_var(line: &str) -> Result<Option<String>> {
+    let line = line.trim();
+    if let Some(error) = line.strip_prefix("ERR ") {
+        if error == "VAR-NOT-SUPPORTED" {
+            return Ok(None);
+        }
+        bail!("NUT server error: {error}");
+    }
+    let value = line
+        .strip_prefix("VAR ")
+        .and_then(|rest| rest.split_once('"'))
+        .and_then(|(_, quoted)| quoted.strip_suffix('"'))
+        .with_context(|| format!("unexpected NUT reply: {line}"))?;
+    Ok(Some(value.to_string()))
+}
+
+fn describe(event: PowerEvent, status: &PowerStatus) -> String {
+    let charge = status
+        .charge
+        .map(|c| format!(" (battery {c}%)"))
+        .unwrap_or_default();
+    match event {
+        PowerEvent::OnBattery => {
+            format!("⚡ ZeroClaw host lost mains power; running on battery{charge}")
+        }
+        PowerEvent::LowBattery => {
+            format!("🪫 ZeroClaw host battery low{charge}; shutdown may follow")
+        }
+        PowerEvent::Restored => format!("🔌 ZeroClaw host mains power restored{charge}"),
+    }
+}
+
+async fn announce(config: &Config, notice: &str) {
+    tracing::warn!("{notice}");
+    let channel = config
+        .power
+        .channel
+        .as_deref()
+        .or(config.heartbeat.target.as_deref());
+    let target = config
+        .power
+        .to
+        .as_deref()
+        .or(config.heartbeat.to.as_deref());
+    if let (Some(channel), Some(target)) = (channel, target) {
+        if let Err(error) =
+            crate::cron::scheduler::deliver_announcement(config, channel, target, notice).await
+        {
+            tracing::warn!("Power notice delivery failed: {error}");
+        }
+    }
+}
+
+async fn flush_memory(config: &Config) {
+    let workspace_dir = config.workspace_dir.clone();
+    match tokio::task::spawn_blocking(move || {
+        crate::memory::snapshot::export_snapshot(&workspace_dir)
+    })
+    .await
+    {
+        Ok(Ok(count)) => tracing::info!("Power event: exported {count} memories to snapshot"),
+        Ok(Err(error)) => tracing::warn!("Power event: memory snapshot failed: {error}"),
+        Err(error) => tracing::warn!("Power event: memory snapshot task failed: {error}"),
+    }
+}
+
+async fn react(
+    config: &Config,
+    event: PowerEvent,
+    status: &PowerStatus,
+    toasts: &crate::notifications::Toasts,
+) {
+    announce(config, &describe(event, status)).await;
+    match event {
+        PowerEvent::OnBattery => {
+            if config.power.flush_memory {
+                flush_memory(config).await;
+            }
+            if config.power.estop_on_battery && config.security.estop.enabled {
+                if let Err(error) =
+                    crate::security::estop::engage_local(config, "power loss", toasts).await
+                {
+                    tracing::warn!("Power event: could not engage estop: {error}");
+                }
+            }
+        }
+        PowerEvent::LowBattery => {
+            if config.power.flush_memory {
+                flush_memory(config).await;
+            }
+        }
+        PowerEvent::Restored => {}
+    }
+}
+
+pub async fn run_worker(config: Config, toasts: crate::notifications::Toasts) -> Result<()> {
+    let poll = Duration::from_secs(config.power.poll_secs.max(MIN_POLL_SECS));
+    let mut interval = tokio::time::interval(poll);
+    let mut previous: Option<PowerStatus> = None;
+
+    loop {
+        interval.tick().await;
+        match read_status(&config).await {
+            Ok(status) => {
+                crate::health::mark_component_ok("power");
+                for event in transitions(previous.as_ref(), &status) {
+                    react(&config, event, &status, &toasts).await;
+                }
+                previous = Some(status);
+            }
+            Err(error) => {
+                crate::health::mark_component_error("power", error.to_string());
+                tracing::debug!("power poll failed: {error}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(on_battery: bool, low_battery: bool) -> PowerStatus {
+        PowerStatus {
+            on_battery,
+            charge: Some(50),
+            low_battery,
+            source: "nut",
+        }
+    }
+
+    #[test]
+    fn transitions_fire_once_per_change() {
+        assert!(transitions(None, &status(false, false)).is_empty());
+        assert_eq!(
+            transitions(None, &status(true, true)),
+            vec![PowerEvent::OnBattery, PowerEvent::LowBattery]
+        );
+        assert_eq!(
+            transitions(Some(&status(false, false)), &status(true, false)),
+            vec![PowerEvent::OnBattery]
+        );
+        assert!(transitions(Some(&status(true, false)), &status(true, false)).is_empty());
+        assert_eq!(
+            transitions(Some(&status(true, false)), &status(true, true)),
+            vec![PowerEvent::LowBattery]
+        );
+        assert_eq!(
+            transitions(Some(&status(true, true)), &status(false, false)),
+            vec![PowerEvent::Restored]
+        );
+    }
+
+    #[test]
+    fn nut_replies_are_parsed() {
+        assert_eq!(
+            parse_nut_var("VAR ups ups.status \"OB LB\"\n").unwrap(),
+            Some("OB LB".into())
+        );
+        assert_eq!(parse_nut_var("ERR VAR-NOT-SUPPORTED").unwrap(), None);
+        assert!(parse_nut_var("ERR UNKNOWN-UPS").is_err());
+        assert!(parse_nut_var("garbage").is_err());
+    }
+
+    #[test]
+    fn sysfs_reads_mains_and_battery() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let write = |dir: &str, name: &str, value: &str| {
+            let dir = tmp.path().join(dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join(name), value).unwrap();
+        };
+        assert!(read_sysfs(tmp.path()).unwrap().is_none());
+
+        write("AC", "type", "Mains\n");
+        write("AC", "online", "0\n");
+        write("BAT0", "type", "Battery\n");
+        write("BAT0", "status", "Discharging\n");
+        write("BAT0", "capacity", "42\n");
+        write("BAT0", "capacity_level", "Low\n");
+        let status = read_sysfs(tmp.path()).unwrap().unwrap();
+        assert!(status.on_battery);
+        assert!(status.low_battery);
+        assert_eq!(status.charge, Some(42));
+
+        write("AC", "online", "1\n");
+        let status = read_sysfs(tmp.path()).unwrap().unwrap();
+        assert!(!status.on_battery);
+        assert!(!status.low_battery);
+    }
+}
@@ -0,0 +1,538 @@
+//! Per-identity data purge (`zeroclaw privacy forget`).
+//!
+//! Removes everything stored about one person: conversation memories tagged
+//! with (or keyed by) their sender ID and session files named after them.
+//! Usage records are not attributed to a sender and are left alone. Audit
+//! log entries are kept for tamper evidence, with the person's actor IDs
+//! redacted in place. Several linked identities (the same person on Telegram
+//! and Discord, say) can be purged in one run.
+//!
+//! Matching is exact on the stored structure, never by substring, so
+//! forgetting `alice` leaves `alice_bob` alone.
+
+use crate::config::Config;
+use anyhow::{bail, Context, Result};
+use console::style;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Replaces a forgotten person's IDs in audit log actors.
+const REDACTED_ACTOR: &str = "[forgotten]";
+
+/// One identity to purge: `<channel>:<sender>` or a bare sender ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdentitySelector {
+    channel: Option<String>,
+    sender: String,
+}
+
+impl IdentitySelector {
+    pub fn parse(raw: &str) -> Result<Self> {
+        let raw = raw.trim();
+        let (channel, sender) = match raw.split_once(':') {
+            Some((channel, sender)) if !channel.is_empty() => {
+                (Some(channel.to_ascii_lowercase()), sender.trim())
+            }
+            _ => (None, raw),
+        };
+        if sender.is_empty() {
+            bail!("identity must not be empty (expected <channel>:<sender> or <sender>)");
+        }
+        Ok(Self {
+            channel,
+            sender: sender.to_string(),
+        })
+    }
+
+    fn channel_matches(&self, channel: &str) -> bool {
+        self.channel
+            .as_deref()
+            .is_none_or(|expected| expected.eq_ignore_ascii_case(channel))
+    }
+
+    /// Channel names a key or file name may start with: the selector's own
+    /// channel, or any built-in channel for a bare sender ID.
+    fn channel_prefix<'a>(&self, name: &'a str) -> Option<(&'a str, &'a str)> {
+        let split = |channel: &str| {
+            name.strip_prefix(channel)
+                .and_then(|rest| rest.strip_prefix('_'))
+                .map(|rest| (&name[..channel.len()], rest))
+        };
+        match self.channel.as_deref() {
+            Some(channel) => split(channel),
+            None => std::iter::once("cli")
+                .chain(
+                    crate::channels::registry::BUILTIN_CHANNELS
+                        .iter()
+                        .map(|b| b.key),
+                )
+                .filter_map(split)
+                .max_by_key(|(channel, _)| channel.len()),
+        }
+    }
+
+    /// Untagged conversation memory keys look like
+    /// `<channel>[_<thread>]_<sender>_<message id>`. Thread IDs are numeric
+    /// (Telegram topics, Slack timestamps). Message IDs either have no `_` or
+    /// start with `<channel>_`; anything else is ambiguous and left alone.
+    fn matches_memory_key(&self, key: &str) -> bool {
+        let Some((channel, rest)) = self.channel_prefix(key) else {
+            return false;
+        };
+        let sender_then_id = |rest: &str| {
+            rest.strip_prefix(self.sender.as_str())
+                .and_then(|rest| rest.strip_prefix('_'))
+                .is_some_and(|id| {
+                    !id.is_empty()
+                        && (!id.contains('_')
+                            || id
+                                .strip_prefix(channel)
+                                .is_some_and(|tail| tail.starts_with('_')))
+                })
+        };
+        let threaded = rest.split_once('_').is_some_and(|(thread, after)| {
+            !thread.is_empty()
+                && thread.chars().all(|c| c.is_ascii_digit() || c == '.')
+                && sender_then_id(after)
+        });
+        sender_then_id(rest) || threaded
+    }
+
+    /// Session IDs are the bare sender ID or `<channel>:<sender>`.
+    fn matches_session_id(&self, session_id: &str) -> bool {
+        if session_id == self.sender {
+            return true;
+        }
+        session_id
+            .split_once(':')
+            .is_some_and(|(channel, sender)| sender == self.sender && self.channel_matches(channel))
+    }
+
+    /// Session files are named `[<channel>_]<sender>[_<YYYY-MM-DD>].<ext>`.
+    fn matches_file_name(&self, name: &str) -> bool {
+        let stem = name.rsplit_once('.').map_or(name, |(stem, _)| stem);
+        let stem = stem
+            .rsplit_once('_')
+            .filter(|(_, date)| chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok())
+            .map_or(stem, |(stem, _)| stem);
+        (self.channel.is_none() && stem == self.sender)
+            || self
+                .channel_prefix(stem)
+                .is_some_and(|(_, sender)| sender == self.sender)
+    }
+
+    fn matches_actor(&self, actor: &serde_json::Value) -> bool {
+        let channel = actor.get("channel").and_then(|v| v.as_str()).unwrap_or("");
+        let mut user_ids = ["user_id", "username"]
+            .iter()
+            .filter_map(|field| actor.get(*field).and_then(|v| v.as_str()));
+        self.channel_matches(channel) && user_ids.any(|id| id == self.sender)
+    }
+}
+
+/// What `forget` removed (or would remove, for a dry run).
+#[derive(Debug, Default, Serialize)]
+pub struct DeletionReport {
+    pub identities: Vec<String>,
+    pub dry_run: bool,
+    pub memory_keys: Vec<String>,
+    pub session_files: Vec<PathBuf>,
+    /// Audit log entries kept with their actor IDs replaced by `[forgotten]`.
+    pub audit_entries_redacted: usize,
+    /// Stores that could not be searched, with the reason.
+    pub skipped: Vec<String>,
+}
+
+impl DeletionReport {
+    pub fn total(&self) -> usize {
+        self.memory_keys.len() + self.session_files.len() + self.audit_entries_redacted
+    }
+}
+
+pub async fn handle_command(command: crate::PrivacyCommands, config: &Config) -> Result<()> {
+    match command {
+        crate::PrivacyCommands::Forget {
+            identities,
+            dry_run,
+            yes,
+            json,
+        } => {
+            let selectors = identities
+                .iter()
+                .map(|raw| IdentitySelector::parse(raw))
+                .collect::<Result<Vec<_>>>()?;
+
+            let plan = forget(config, &identities, &selectors, true).await?;
+            if dry_run || plan.total() == 0 {
+                return print_report(&plan, json);
+            }
+
+            if !yes {
+                print_report(&plan, false)?;
+                let confirmed = dialoguer::Confirm::new()
+                    .with_prompt(format!("  Permanently delete {} item(s)?", plan.total()))
+                    .default(false)
+                    .interact()?;
+                if !confirmed {
+                    println!("Aborted.");
+                    return Ok(());
+                }
+            }
+
+            let report = forget(config, &identities, &selectors, false).await?;
+            print_report(&report, json)
+        }
+    }
+}
+
+/// Find (and unless `dry_run`, delete) everything stored for `selectors`.
+pub async fn forget(
+    config: &Config,
+    identities: &[String],
+    selectors: &[IdentitySelector],
+    dry_run: bool,
+) -> Result<DeletionReport> {
+    let mut report = DeletionReport {
+        identities: identities.to_vec(),
+        dry_run,
+        ..DeletionReport::default()
+    };
+
+    // Memories
+    match crate::memory::cli::create_cli_memory(config) {
+        Ok(memory) => {
+            for entry in memory.list(None, None).await? {
+                // Channel memories are tagged with `<channel>:<sender>`; only
+                // untagged (older) entries fall back to the key structure.
+                let matched = selectors.iter().any(|selector| match entry.session_id {
+                    Some(ref session) => selector.matches_session_id(session),
+                    None => selector.matches_memory_key(&entry.key),
+                });
+                if !matched {
+                    continue;
+                }
+                if !dry_run {
+                    memory.forget(&entry.key).await?;
+                }
+                report.memory_keys.push(entry.key);
+            }
+        }
+        Err(error) => report.skipped.push(format!("memory: {error}")),
+    }
+
+    // Session files (including archived ones)
+    let sessions_dir = config.workspace_dir.join("sessions");
+    for dir in [sessions_dir.clone(), sessions_dir.join("archive")] {
+        for path in matching_files(&dir, selectors)? {
+            if !dry_run {
+                std::fs::remove_file(&path)
+                    .with_context(|| format!("Failed to delete {}", path.display()))?;
+            }
+            report.session_files.push(path);
+        }
+    }
+
+    // Audit log, including rotated files: redact in place, never drop entries
+    if let Some(zeroclaw_dir) = config.config_path.parent() {
+        for path in audit_log_files(&zeroclaw_dir.join(&config.security.audit.log_path)) {
+            report.audit_entries_redacted += redact_audit_actors(&path, dry_run, selectors)?;
+        }
+    }
+
+    Ok(report)
+}
+
+fn matching_files(dir: &Path, selectors: &[IdentitySelector]) -> Result<Vec<PathBuf>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut matches = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_file() {
+            continue;
+        }
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if selectors.iter().any(|s| s.matches_file_name(name)) {
+            matches.push(path);
+        }
+    }
+    matches.sort();
+    Ok(matches)
+}
+
+/// The active audit log followed by its rotated siblings (`<log>.N.log`).
+fn audit_log_files(log_path: &Path) -> Vec<PathBuf> {
+    let mut files = vec![log_path.to_path_buf()];
+    files.extend((1..=10).map(|i| PathBuf::from(format!("{}.{i}.log", log_path.display()))));
+    files.retain(|path| path.is_file());
+    files
+}
+
+/// Replace the IDs of matching audit actors with `[forgotten]`, keeping every
+/// entry and its order. Returns the number of entries redacted.
+fn redact_audit_actors(
+    path: &Path,
+    dry_run: bool,
+    selectors: &[IdentitySelector],
+) -> Result<usize> {
+    if !path.is_file() {
+        return Ok(0);
+    }
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+
+    let mut rewritten = String::with_capacity(contents.len());
+    let mut redacted = 0;
+    for line in contents.lines() {
+        let mut event = match serde_json::from_str::<serde_json::Value>(line) {
+            Ok(event) => event,
+            Err(_) => {
+                rewritten.push_str(line);
+                rewritten.push('\n');
+                continue;
+            }
+        };
+        let actor = event
+            .get_mut("actor")
+            .filter(|actor| selectors.iter().any(|s| s.matches_actor(actor)));
+        match actor.and_then(serde_json::Value::as_object_mut) {
+            Some(actor) => {
+                for field in ["user_id", "username"] {
+                    if actor.get(field).is_some_and(|v| !v.is_null()) {
+                        actor.insert(field.into(), REDACTED_ACTOR.into());
+                    }
+                }
+                redacted += 1;
+                rewritten.push_str(&serde_json::to_string(&event)?);
+            }
+            None => rewritten.push_str(line),
+        }
+        rewritten.push('\n');
+    }
+
+    if redacted > 0 && !dry_run {
+        let temp = path.with_extension("forget.tmp");
+        std::fs::write(&temp, rewritten)
+            .with_context(|| format!("Failed to write {}", temp.display()))?;
+        std::fs::rename(&temp, path)
+            .with_context(|| format!("Failed to replace {}", path.display()))?;
+    }
+    Ok(redacted)
+}
+
+fn print_report(report: &DeletionReport, json: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(report)?);
+        return Ok(());
+    }
+
+    let verb = if report.dry_run {
+        "Would delete"
+    } else {
+        "Deleted"
+    };
+    println!(
+        "{} data for {}",
+        style("Privacy purge").white().bold(),
+        report.identities.join(", ")
+    );
+    println!("  {verb} {} memory entries", report.memory_keys.len());
+    for key in &report.memory_keys {
+        println!("    - {key}");
+    }
+    println!("  {verb} {} session files", report.session_files.len());
+    for path in &report.session_files {
+        println!("    - {}", path.display());
+    }
+    let redact_verb = if report.dry_run {
+        "Would redact"
+    } else {
+        "Redacted"
+    };
+    println!(
+        "  {redact_verb} the actor in {} audit log entries (entries are kept)",
+        report.audit_entries_redacted
+    );
+    for skipped in &report.skipped {
+        println!("  {} Skipped {skipped}", style("!").yellow().bold());
+    }
+    if !report.dry_run {
+        println!(
+            "{} Removed or redacted {} item(s). Backups and exported snapshots are not modified.",
+            style("✓").green().bold(),
+            report.total()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::{Memory, MemoryCategory, SqliteMemory};
+    use tempfile::TempDir;
+
+    #[test]
+    fn identity_selector_matches_channel_scoped_memory_keys() {
+        let scoped = IdentitySelector::parse("telegram:alice").unwrap();
+        assert!(scoped.matches_memory_key("telegram_alice_msg1"));
+        assert!(scoped.matches_memory_key("telegram_99_alice_msg1"));
+        assert!(!scoped.matches_memory_key("discord_alice_msg1"));
+        assert!(!scoped.matches_memory_key("telegram_alicex_msg1"));
+
+        let bare = IdentitySelector::parse("alice").unwrap();
+        assert!(bare.matches_memory_key("discord_alice_msg1"));
+        assert!(bare.matches_memory_key("telegram_alice_telegram_42_7"));
+        assert!(IdentitySelector::parse("telegram:").is_err());
+    }
+
+    #[test]
+    fn identity_selector_does_not_match_longer_sender_or_other_segments() {
+        let alice = IdentitySelector::parse("alice").unwrap();
+        assert!(!alice.matches_memory_key("telegram_alice_bob_m1"));
+        assert!(!alice.matches_memory_key("telegram_alice_bob_telegram_42_7"));
+        assert!(!alice.matches_memory_key("telegram_bob_alice"));
+        assert!(!alice.matches_file_name("alice_bob.md"));
+        assert!(!alice.matches_file_name("telegram_alice_bob.md"));
+        assert!(!alice.matches_session_id("telegram:alice_bob"));
+
+        let alice_bob = IdentitySelector::parse("alice_bob").unwrap();
+        assert!(alice_bob.matches_memory_key("telegram_alice_bob_m1"));
+        assert!(alice_bob.matches_file_name("telegram_alice_bob.md"));
+
+        // A non-numeric segment before the sender is not a thread.
+        let bob = IdentitySelector::parse("bob").unwrap();
+        assert!(!bob.matches_memory_key("telegram_alice_bob_m1"));
+        assert!(!bob.matches_file_name("alice_bob.md"));
+        assert!(bob.matches_memory_key("telegram_1700000000.1_bob_m1"));
+    }
+
+    #[test]
+    fn identity_selector_matches_session_file_structure() {
+        let selector = IdentitySelector::parse("12345").unwrap();
+        assert!(selector.matches_file_name("telegram_12345_2026-01-01.md"));
+        assert!(selector.matches_file_name("12345.md"));
+        assert!(!selector.matches_file_name("telegram_123456.md"));
+        assert!(!selector.matches_file_name("telegram_12345_notes.md"));
+
+        let scoped = IdentitySelector::parse("telegram:12345").unwrap();
+        assert!(scoped.matches_file_name("telegram_12345.md"));
+        assert!(!scoped.matches_file_name("discord_12345.md"));
+        assert!(scoped.matches_session_id("telegram:12345"));
+        assert!(!scoped.matches_session_id("discord:12345"));
+    }
+
+    #[test]
+    fn audit_actors_are_redacted_in_place() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("audit.log");
+        let original = concat!(
+            r#"{"event_id":"1","actor":{"channel":"telegram","user_id":"alice","username":"@al"}}"#,
+            "\n",
+            r#"{"event_id":"2","actor":{"channel":"telegram","user_id":"alice_bob","username":null}}"#,
+            "\nnot json\n"
+        );
+        std::fs::write(&path, original).unwrap();
+        let selectors = [IdentitySelector::parse("alice").unwrap()];
+
+        assert_eq!(redact_audit_actors(&path, true, &selectors).unwrap(), 1);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), original);
+
+        assert_eq!(redact_audit_actors(&path, false, &selectors).unwrap(), 1);
+        let rewritten = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = rewritten.lines().collect();
+        assert_eq!(lines.len(), 3, "no entry is dropped");
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["event_id"], "1");
+        assert_eq!(first["actor"]["user_id"], REDACTED_ACTOR);
+        assert_eq!(first["actor"]["username"], REDACTED_ACTOR);
+        assert_eq!(first["actor"]["channel"], "telegram");
+        assert_eq!(lines[1], original.lines().nth(1).unwrap());
+        assert_eq!(lines[2], "not json");
+    }
+
+    #[tokio::test]
+    async fn forget_removes_memories_and_session_files_for_identity() {
+        let tmp = TempDir::new().unwrap();
+        let config = Config {
+            workspace_dir: tmp.path().join("workspace"),
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+        std::fs::create_dir_all(config.workspace_dir.join("sessions")).unwrap();
+        std::fs::write(
+            config
+                .workspace_dir
+                .join("sessions")
+                .join("telegram_alice.md"),
+            "hi",
+        )
+        .unwrap();
+
+        {
+            let memory = SqliteMemory::new(&config.workspace_dir).unwrap();
+            memory
+                .store(
+                    "telegram_alice_m1",
+                    "my number is 555",
+                    MemoryCategory::Conversation,
+                    None,
+                )
+                .await
+                .unwrap();
+            memory
+                .store(
+                    "telegram_bob_m2",
+                    "hello",
+                    MemoryCategory::Conversation,
+                    None,
+                )
+                .await
+                .unwrap();
+            memory
+                .store(
+                    "telegram_alice_bob_m3",
+                    "tagged",
+                    MemoryCategory::Conversation,
+                    Some("telegram:alice_bob"),
+                )
+                .await
+                .unwrap();
+            memory
+                .store(
+                    "telegram_alice_m4",
+                    "tagged",
+                    MemoryCategory::Conversation,
+                    Some("telegram:alice"),
+                )
+                .await
+                .unwrap();
+        }
+
+        let identities = vec!["telegram:alice".to_string()];
+        let selectors = vec![IdentitySelector::parse(&identities[0]).unwrap()];
+
+        let plan = forget(&config, &identities, &selectors, true)
+            .await
+            .unwrap();
+        let mut planned = plan.memory_keys.clone();
+        planned.sort();
+        assert_eq!(planned, vec!["telegram_alice_m1", "telegram_alice_m4"]);
+        assert_eq!(plan.session_files.len(), 1);
+        assert!(plan.session_files[0].exists());
+
+        let report = forget(&config, &identities, &selectors, false)
+            .await
+            .unwrap();
+        assert_eq!(report.total(), 3);
+        assert!(!report.session_files[0].exists());
+
+        let memory = SqliteMemory::new(&config.workspace_dir).unwrap();
+        assert!(memory.get("telegram_alice_m1").await.unwrap().is_none());
+        assert!(memory.get("telegram_bob_m2").await.unwrap().is_some());
+        assert!(memory.get("telegram_alice_bob_m3").await.unwrap().is_some());
+    }
+}
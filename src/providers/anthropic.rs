@@ -450,6 +450,7 @@ impl Provider for AnthropicProvider {
         let mut request = self
             .http_client()
             .post(format!("{}/v1/messages", self.base_url))
+            .query(&crate::config::provider_request_query("provider.anthropic"))
             .header("anthropic-version", "2023-06-01")
             .header("content-type", "application/json")
             .json(&request);
@@ -497,6 +498,7 @@ impl Provider for AnthropicProvider {
         let req = self
             .http_client()
             .post(format!("{}/v1/messages", self.base_url))
+            .query(&crate::config::provider_request_query("provider.anthropic"))
             .header("anthropic-version", "2023-06-01")
             .header("content-type", "application/json")
             .json(&native_request);
@@ -566,6 +568,7 @@ impl Provider for AnthropicProvider {
             let mut request = self
                 .http_client()
                 .post(format!("{}/v1/messages", self.base_url))
+                .query(&crate::config::provider_request_query("provider.anthropic"))
                 .header("anthropic-version", "2023-06-01");
             request = self.apply_auth(request, credential);
             // Send a minimal request; the goal is TLS + HTTP/2 setup, not a valid response.
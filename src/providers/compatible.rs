@@ -215,11 +215,14 @@ impl OpenAiCompatibleProvider {
                 .timeout(std::time::Duration::from_secs(120))
                 .connect_timeout(std::time::Duration::from_secs(10))
                 .default_headers(headers);
-            let builder =
-                crate::config::apply_runtime_proxy_to_builder(builder, "provider.compatible");
+            let client =
+                crate::config::apply_runtime_proxy_to_builder(builder, "provider.compatible")
+                    .and_then(|builder| Ok(builder.build()?));
 
-            return builder.build().unwrap_or_else(|error| {
-                tracing::warn!("Failed to build proxied timeout client with user-agent: {error}");
+            return client.unwrap_or_else(|error| {
+                tracing::error!(
+                    "Failed to build proxied timeout client with user-agent: {error:#}"
+                );
                 Client::new()
             });
         }
@@ -848,7 +851,15 @@ impl OpenAiCompatibleProvider {
         let url = self.responses_url();
 
         let response = self
-            .apply_auth_header(self.http_client().post(&url).json(&request), credential)
+            .apply_auth_header(
+                self.http_client()
+                    .post(&url)
+                    .query(&crate::config::provider_request_query(
+                        "provider.compatible",
+                    ))
+                    .json(&request),
+                credential,
+            )
             .send()
             .await?;
 
@@ -1161,7 +1172,15 @@ impl Provider for OpenAiCompatibleProvider {
         };
 
         let response = match self
-            .apply_auth_header(self.http_client().post(&url).json(&request), credential)
+            .apply_auth_header(
+                self.http_client()
+                    .post(&url)
+                    .query(&crate::config::provider_request_query(
+                        "provider.compatible",
+                    ))
+                    .json(&request),
+                credential,
+            )
             .send()
             .await
         {
@@ -1271,7 +1290,15 @@ impl Provider for OpenAiCompatibleProvider {
 
         let url = self.chat_completions_url();
         let response = match self
-            .apply_auth_header(self.http_client().post(&url).json(&request), credential)
+            .apply_auth_header(
+                self.http_client()
+                    .post(&url)
+                    .query(&crate::config::provider_request_query(
+                        "provider.compatible",
+                    ))
+                    .json(&request),
+                credential,
+            )
             .send()
             .await
         {
@@ -1389,7 +1416,15 @@ impl Provider for OpenAiCompatibleProvider {
 
         let url = self.chat_completions_url();
         let response = match self
-            .apply_auth_header(self.http_client().post(&url).json(&request), credential)
+            .apply_auth_header(
+                self.http_client()
+                    .post(&url)
+                    .query(&crate::config::provider_request_query(
+                        "provider.compatible",
+                    ))
+                    .json(&request),
+                credential,
+            )
             .send()
             .await
         {
@@ -1486,7 +1521,12 @@ impl Provider for OpenAiCompatibleProvider {
         let url = self.chat_completions_url();
         let response = match self
             .apply_auth_header(
-                self.http_client().post(&url).json(&native_request),
+                self.http_client()
+                    .post(&url)
+                    .query(&crate::config::provider_request_query(
+                        "provider.compatible",
+                    ))
+                    .json(&native_request),
                 credential,
             )
             .send()
@@ -1634,7 +1674,12 @@ impl Provider for OpenAiCompatibleProvider {
 
         tokio::spawn(async move {
             // Build request with auth
-            let mut req_builder = client.post(&url).json(&request);
+            let mut req_builder = client
+                .post(&url)
+                .query(&crate::config::provider_request_query(
+                    "provider.compatible",
+                ))
+                .json(&request);
 
             // Apply auth header
             req_builder = match &auth_header {
@@ -1693,7 +1738,14 @@ impl Provider for OpenAiCompatibleProvider {
             // the goal is TLS handshake and HTTP/2 negotiation.
             let url = self.chat_completions_url();
             let _ = self
-                .apply_auth_header(self.http_client().get(&url), credential)
+                .apply_auth_header(
+                    self.http_client()
+                        .get(&url)
+                        .query(&crate::config::provider_request_query(
+                            "provider.compatible",
+                        )),
+                    credential,
+                )
                 .send()
                 .await?;
         }
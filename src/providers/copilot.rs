@@ -336,6 +336,7 @@ impl CopilotProvider {
         let mut req = self
             .http_client()
             .post(&url)
+            .query(&crate::config::provider_request_query("provider.copilot"))
             .header("Authorization", format!("Bearer {token}"))
             .json(&request);
 
@@ -880,7 +880,11 @@ impl GeminiProvider {
         project: Option<&str>,
         oauth_token: Option<&str>,
     ) -> reqwest::RequestBuilder {
-        let req = self.http_client().post(url).json(request);
+        let req = self
+            .http_client()
+            .post(url)
+            .query(&crate::config::provider_request_query("provider.gemini"))
+            .json(request);
         match auth {
             GeminiAuth::OAuthToken(_) | GeminiAuth::ManagedOAuth => {
                 let token = oauth_token.unwrap_or_default();
@@ -902,6 +906,7 @@ impl GeminiProvider {
                 };
                 self.http_client()
                     .post(url)
+                    .query(&crate::config::provider_request_query("provider.gemini"))
                     .json(&internal_request)
                     .bearer_auth(token)
             }
@@ -1314,6 +1319,7 @@ impl Provider for GeminiProvider {
 
                     self.http_client()
                         .get(&url)
+                        .query(&crate::config::provider_request_query("provider.gemini"))
                         .send()
                         .await?
                         .error_for_status()?;
@@ -185,6 +185,7 @@ impl Provider for GlmProvider {
         let response = self
             .http_client()
             .post(&url)
+            .query(&crate::config::provider_request_query("provider.glm"))
             .header("Authorization", format!("Bearer {token}"))
             .json(&request)
             .send()
@@ -232,6 +233,7 @@ impl Provider for GlmProvider {
         let response = self
             .client
             .post(&url)
+            .query(&crate::config::provider_request_query("provider.glm"))
             .header("Authorization", format!("Bearer {token}"))
             .json(&request)
             .send()
@@ -264,6 +266,7 @@ impl Provider for GlmProvider {
         let _ = self
             .client
             .get(&url)
+            .query(&crate::config::provider_request_query("provider.glm"))
             .header("Authorization", format!("Bearer {token}"))
             .send()
             .await?;
@@ -0,0 +1,430 @@
+//! Deterministic scripted provider for tests and demos.
+//!
+//! Select it with `default_provider = "mock"` (optionally pointing `api_url`
+//! at a script) or `default_provider = "mock:path/to/script.toml"`. Without a
+//! script the provider echoes the latest user message.
+//!
+//! ```toml
+//! repeat = false
+//! fallback = "Nothing scripted for that."
+//!
+//! [[turns]]
+//! match = "weather"             # picked whenever the user message contains this
+//! text = "Let me check."
+//! tool_calls = [{ name = "http_request", arguments = { url = "https://wttr.in/?format=3" } }]
+//!
+//! [[turns]]
+//! text = "Hello from the mock provider."
+//! chunks = ["Hello ", "from the ", "mock provider."]
+//! usage = { input_tokens = 12, output_tokens = 6 }
+//! ```
+//!
+//! Turns without `match` are played in order, one per call. Once they run out
+//! the script restarts when `repeat` is set, otherwise `fallback` is returned
+//! (or an error, if there is no fallback).
+
+use super::traits::{
+    ChatMessage, ChatRequest, ChatResponse, Provider, ProviderCapabilities, StreamChunk,
+    StreamOptions, StreamResult, TokenUsage, ToolCall, ToolsPayload,
+};
+use crate::tools::ToolSpec;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use futures_util::{stream, StreamExt};
+use parking_lot::Mutex;
+use serde::Deserialize;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Parsed mock script.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MockScript {
+    #[serde(default)]
+    pub turns: Vec<MockTurn>,
+    /// Restart the ordered turns after the last one.
+    #[serde(default)]
+    pub repeat: bool,
+    /// Reply used once ordered turns are exhausted and `repeat` is off.
+    #[serde(default)]
+    pub fallback: Option<String>,
+}
+
+/// One scripted model reply.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MockTurn {
+    /// Case-insensitive substring of the latest user message that selects this turn.
+    #[serde(default, rename = "match")]
+    pub match_text: Option<String>,
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub tool_calls: Vec<MockToolCall>,
+    /// Streaming chunks; defaults to the text split on word boundaries.
+    #[serde(default)]
+    pub chunks: Vec<String>,
+    #[serde(default)]
+    pub usage: Option<MockUsage>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MockToolCall {
+    pub name: String,
+    #[serde(default)]
+    pub arguments: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct MockUsage {
+    #[serde(default)]
+    pub input_tokens: Option<u64>,
+    #[serde(default)]
+    pub output_tokens: Option<u64>,
+}
+
+impl MockScript {
+    pub fn from_toml(raw: &str) -> Result<Self> {
+        toml::from_str(raw).context("Invalid mock provider script")
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read mock script {}", path.display()))?;
+        Self::from_toml(&raw).with_context(|| format!("in {}", path.display()))
+    }
+}
+
+pub struct MockProvider {
+    script: Option<MockScript>,
+    cursor: Mutex<usize>,
+    call_ids: AtomicU64,
+}
+
+impl MockProvider {
+    pub fn new(script: Option<MockScript>) -> Self {
+        Self {
+            script,
+            cursor: Mutex::new(0),
+            call_ids: AtomicU64::new(1),
+        }
+    }
+
+    /// Build from an optional script path (`mock:<path>` or `api_url`).
+    pub fn from_script_path(path: Option<&str>) -> Result<Self> {
+        let path = path.map(str::trim).filter(|path| !path.is_empty());
+        let script = path
+            .map(|path| MockScript::load(Path::new(shellexpand::tilde(path).as_ref())))
+            .transpose()?;
+        Ok(Self::new(script))
+    }
+
+    fn next_turn(&self, last_user: &str) -> Result<MockTurn> {
+        let Some(script) = &self.script else {
+            return Ok(MockTurn {
+                text: Some(format!("mock: {last_user}")),
+                ..MockTurn::default()
+            });
+        };
+
+        let lowered = last_user.to_lowercase();
+        if let Some(turn) = script.turns.iter().find(|turn| {
+            turn.match_text
+                .as_deref()
+                .is_some_and(|pattern| lowered.contains(&pattern.to_lowercase()))
+        }) {
+            return Ok(turn.clone());
+        }
+
+        let ordered: Vec<&MockTurn> = script
+            .turns
+            .iter()
+            .filter(|turn| turn.match_text.is_none())
+            .collect();
+        let mut cursor = self.cursor.lock();
+        if *cursor >= ordered.len() && script.repeat && !ordered.is_empty() {
+            *cursor = 0;
+        }
+        if let Some(turn) = ordered.get(*cursor) {
+            *cursor += 1;
+            return Ok((*turn).clone());
+        }
+
+        match &script.fallback {
+            Some(text) => Ok(MockTurn {
+                text: Some(text.clone()),
+                ..MockTurn::default()
+            }),
+            None => anyhow::bail!(
+                "mock provider script exhausted after {} turns",
+                ordered.len()
+            ),
+        }
+    }
+
+    fn build_response(&self, turn: MockTurn) -> ChatResponse {
+        let tool_calls = turn
+            .tool_calls
+            .into_iter()
+            .map(|call| ToolCall {
+                id: format!("mock_call_{}", self.call_ids.fetch_add(1, Ordering::SeqCst)),
+                name: call.name,
+                arguments: if call.arguments.is_null() {
+                    "{}".to_string()
+                } else {
+                    call.arguments.to_string()
+                },
+            })
+            .collect();
+        ChatResponse {
+            text: turn.text,
+            tool_calls,
+            usage: turn.usage.map(|usage| TokenUsage {
+                input_tokens: usage.input_tokens,
+                output_tokens: usage.output_tokens,
+            }),
+            reasoning_content: None,
+        }
+    }
+
+    fn stream_turn(
+        &self,
+        last_user: &str,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        let turn = match self.next_turn(last_user) {
+            Ok(turn) => turn,
+            Err(error) => {
+                return stream::once(async move { Ok(StreamChunk::error(error.to_string())) })
+                    .boxed();
+            }
+        };
+
+        let chunks = if turn.chunks.is_empty() {
+            split_into_chunks(turn.text.as_deref().unwrap_or_default())
+        } else {
+            turn.chunks
+        };
+        let chunks = chunks
+            .into_iter()
+            .map(move |text| {
+                let chunk = StreamChunk::delta(text);
+                Ok(if options.count_tokens {
+                    chunk.with_token_estimate()
+                } else {
+                    chunk
+                })
+            })
+            .chain(std::iter::once(Ok(StreamChunk::final_chunk())));
+        stream::iter(chunks).boxed()
+    }
+}
+
+/// Split text into word-sized deltas, keeping the trailing whitespace with
+/// each word so the concatenation reproduces the input exactly.
+fn split_into_chunks(text: &str) -> Vec<String> {
+    text.split_inclusive(char::is_whitespace)
+        .map(str::to_string)
+        .collect()
+}
+
+fn last_user_message(messages: &[ChatMessage]) -> &str {
+    messages
+        .iter()
+        .rfind(|message| message.role == "user")
+        .map_or("", |message| message.content.as_str())
+}
+
+#[async_trait]
+impl Provider for MockProvider {
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            native_tool_calling: true,
+            vision: false,
+        }
+    }
+
+    fn convert_tools(&self, tools: &[ToolSpec]) -> ToolsPayload {
+        ToolsPayload::OpenAI {
+            tools: tools
+                .iter()
+                .map(|tool| {
+                    serde_json::json!({
+                        "type": "function",
+                        "function": {
+                            "name": tool.name,
+                            "description": tool.description,
+                            "parameters": tool.parameters,
+                        }
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    async fn chat_with_system(
+        &self,
+        _system_prompt: Option<&str>,
+        message: &str,
+        _model: &str,
+        _temperature: f64,
+    ) -> Result<String> {
+        let turn = self.next_turn(message)?;
+        Ok(turn.text.unwrap_or_default())
+    }
+
+    async fn chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        _model: &str,
+        _temperature: f64,
+    ) -> Result<String> {
+        let turn = self.next_turn(last_user_message(messages))?;
+        Ok(turn.text.unwrap_or_default())
+    }
+
+    async fn chat(
+        &self,
+        request: ChatRequest<'_>,
+        _model: &str,
+        _temperature: f64,
+    ) -> Result<ChatResponse> {
+        let turn = self.next_turn(last_user_message(request.messages))?;
+        Ok(self.build_response(turn))
+    }
+
+    async fn chat_with_tools(
+        &self,
+        messages: &[ChatMessage],
+        _tools: &[serde_json::Value],
+        _model: &str,
+        _temperature: f64,
+    ) -> Result<ChatResponse> {
+        let turn = self.next_turn(last_user_message(messages))?;
+        Ok(self.build_response(turn))
+    }
+
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
+    fn stream_chat_with_system(
+        &self,
+        _system_prompt: Option<&str>,
+        message: &str,
+        _model: &str,
+        _temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        self.stream_turn(message, options)
+    }
+
+    fn stream_chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        _model: &str,
+        _temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        self.stream_turn(last_user_message(messages), options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCRIPT: &str = r#"
+fallback = "nothing scripted"
+
+[[turns]]
+match = "Weather"
+text = "Let me check."
+tool_calls = [{ name = "http_request", arguments = { url = "https://wttr.in" } }]
+
+[[turns]]
+text = "first"
+usage = { input_tokens = 3, output_tokens = 1 }
+
+[[turns]]
+text = "second reply"
+chunks = ["sec", "ond ", "reply"]
+"#;
+
+    fn provider() -> MockProvider {
+        MockProvider::new(Some(MockScript::from_toml(SCRIPT).unwrap()))
+    }
+
+    #[tokio::test]
+    async fn plays_ordered_turns_then_fallback() {
+        let mock = provider();
+        let history = [ChatMessage::user("hi")];
+        let first = mock
+            .chat(
+                ChatRequest {
+                    messages: &history,
+                    tools: None,
+                },
+                "m",
+                0.0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(first.text.as_deref(), Some("first"));
+        assert_eq!(first.usage.unwrap().output_tokens, Some(1));
+
+        assert_eq!(
+            mock.simple_chat("hi", "m", 0.0).await.unwrap(),
+            "second reply"
+        );
+        assert_eq!(
+            mock.simple_chat("hi", "m", 0.0).await.unwrap(),
+            "nothing scripted"
+        );
+    }
+
+    #[tokio::test]
+    async fn match_turns_return_tool_calls_without_advancing() {
+        let mock = provider();
+        let history = [ChatMessage::user("what's the weather?")];
+        let response = mock.chat_with_tools(&history, &[], "m", 0.0).await.unwrap();
+        assert_eq!(response.tool_calls.len(), 1);
+        assert_eq!(response.tool_calls[0].name, "http_request");
+        assert!(response.tool_calls[0].arguments.contains("wttr.in"));
+
+        assert_eq!(mock.simple_chat("hi", "m", 0.0).await.unwrap(), "first");
+    }
+
+    #[tokio::test]
+    async fn streams_scripted_chunks() {
+        let mock = provider();
+        let _ = mock.simple_chat("hi", "m", 0.0).await.unwrap();
+        let chunks: Vec<StreamChunk> = mock
+            .stream_chat_with_system(None, "hi", "m", 0.0, StreamOptions::new(true))
+            .map(|chunk| chunk.unwrap())
+            .collect()
+            .await;
+        let deltas: Vec<&str> = chunks.iter().map(|c| c.delta.as_str()).collect();
+        assert_eq!(deltas, ["sec", "ond ", "reply", ""]);
+        assert!(chunks.last().unwrap().is_final);
+    }
+
+    #[tokio::test]
+    async fn echoes_without_script_and_errors_when_exhausted() {
+        let echo = MockProvider::new(None);
+        assert_eq!(
+            echo.simple_chat("ping", "m", 0.0).await.unwrap(),
+            "mock: ping"
+        );
+
+        let strict = MockProvider::new(Some(
+            MockScript::from_toml("[[turns]]\ntext = \"once\"\n").unwrap(),
+        ));
+        assert_eq!(strict.simple_chat("a", "m", 0.0).await.unwrap(), "once");
+        assert!(strict.simple_chat("a", "m", 0.0).await.is_err());
+    }
+
+    #[test]
+    fn split_into_chunks_round_trips() {
+        let text = "hello  mock\nworld";
+        assert_eq!(split_into_chunks(text).concat(), text);
+    }
+}
@@ -21,10 +21,14 @@ pub mod bedrock;
 pub mod compatible;
 pub mod copilot;
 pub mod gemini;
+pub mod mock;
+pub mod offline;
 pub mod ollama;
 pub mod openai;
 pub mod openai_codex;
 pub mod openrouter;
+pub mod progress;
+pub mod recorder;
 pub mod reliable;
 pub mod router;
 pub mod telnyx;
@@ -676,6 +680,10 @@ pub struct ProviderRuntimeOptions {
     pub zeroclaw_dir: Option<PathBuf>,
     pub secrets_encrypt: bool,
     pub reasoning_enabled: Option<bool>,
+    /// Offline routing for resilient providers; `None` ignores offline mode.
+    pub connectivity: Option<crate::connectivity::Connectivity>,
+    /// `--record-llm` / debug mode recorder; `None` never records.
+    pub recorder: Option<recorder::Recorder>,
 }
 
 impl Default for ProviderRuntimeOptions {
@@ -686,6 +694,8 @@ impl Default for ProviderRuntimeOptions {
             zeroclaw_dir: None,
             secrets_encrypt: true,
             reasoning_enabled: None,
+            connectivity: None,
+            recorder: None,
         }
     }
 }
@@ -1208,6 +1218,13 @@ fn create_provider_with_url_and_options(
             key,
         ))),
 
+        // ── Scripted mock for tests and demos ───────────────
+        // Format: "mock" (script path via api_url) or "mock:path/to/script.toml"
+        "mock" => Ok(Box::new(mock::MockProvider::from_script_path(api_url)?)),
+        name if name.starts_with("mock:") => Ok(Box::new(mock::MockProvider::from_script_path(
+            name.strip_prefix("mock:"),
+        )?)),
+
         // ── Bring Your Own Provider (custom URL) ───────────
         // Format: "custom:https://your-api.com" or "custom:http://localhost:1234"
         name if name.starts_with("custom:") => {
@@ -1252,9 +1269,9 @@ fn create_provider_with_url_and_options(
 /// Returns `(provider_name, Some(profile))` when the entry contains a colon-
 /// delimited profile, or `(original_str, None)` otherwise.  Entries starting
 /// with `custom:` or `anthropic-custom:` are left untouched because the colon
-/// is part of the URL scheme.
+/// is part of the URL scheme; `mock:` entries carry a script path.
 fn parse_provider_profile(s: &str) -> (&str, Option<&str>) {
-    if s.starts_with("custom:") || s.starts_with("anthropic-custom:") {
+    if s.starts_with("custom:") || s.starts_with("anthropic-custom:") || s.starts_with("mock:") {
         return (s, None);
     }
     match s.split_once(':') {
@@ -1341,7 +1358,40 @@ pub fn create_resilient_provider_with_options(
     .with_api_keys(reliability.api_keys.clone())
     .with_model_fallbacks(reliability.model_fallbacks.clone());
 
-    Ok(Box::new(reliable))
+    let provider: Box<dyn Provider> = if let Some(connectivity) = options.connectivity.clone() {
+        let (local, local_model) = match connectivity.offline_route() {
+            Some((local_name, local_model)) if local_name != primary_name => {
+                match create_provider_with_options(local_name, None, options) {
+                    Ok(local) => (Some(local), local_model.map(str::to_string)),
+                    Err(error) => {
+                        tracing::warn!(
+                            offline_provider = local_name,
+                            "Ignoring invalid offline provider: {error}"
+                        );
+                        (None, None)
+                    }
+                }
+            }
+            _ => (None, None),
+        };
+        let primary_is_local = list_providers().iter().any(|info| {
+            info.local && (info.name == primary_name || info.aliases.contains(&primary_name))
+        });
+        Box::new(offline::OfflineAwareProvider::new(
+            Box::new(reliable),
+            local,
+            local_model,
+            primary_is_local,
+            move || connectivity.is_offline(),
+        ))
+    } else {
+        Box::new(reliable)
+    };
+
+    Ok(match &options.recorder {
+        Some(recorder) => recorder.wrap(primary_name, provider),
+        None => provider,
+    })
 }
 
 /// Create a RouterProvider if model routes are configured, otherwise return a
@@ -1493,6 +1543,12 @@ pub fn list_providers() -> Vec<ProviderInfo> {
             aliases: &[],
             local: true,
         },
+        ProviderInfo {
+            name: "mock",
+            display_name: "Mock (scripted, for tests and demos)",
+            aliases: &[],
+            local: true,
+        },
         ProviderInfo {
             name: "gemini",
             display_name: "Google Gemini",
@@ -2309,6 +2365,22 @@ mod tests {
         assert!(create_provider("astrai", Some("sk-astrai-test")).is_ok());
     }
 
+    // ── Mock provider ───────────────────────────────────────
+
+    #[test]
+    fn factory_mock_without_script() {
+        assert!(create_provider("mock", None).is_ok());
+    }
+
+    #[test]
+    fn factory_mock_missing_script_errors() {
+        assert!(create_provider("mock:/nonexistent/zeroclaw-mock.yaml", None).is_err());
+        assert_eq!(
+            parse_provider_profile("mock:./script.toml"),
+            ("mock:./script.toml", None)
+        );
+    }
+
     // ── Custom / BYOP provider ─────────────────────────────
 
     #[test]
@@ -0,0 +1,225 @@
+use super::traits::{
+    ChatMessage, ChatRequest, ChatResponse, StreamChunk, StreamOptions, StreamResult,
+};
+use super::Provider;
+use async_trait::async_trait;
+use futures_util::stream::BoxStream;
+use futures_util::StreamExt;
+
+/// Sends requests to a local provider while the host is offline (detected
+/// or forced with `zeroclaw offline on`), and to the configured provider
+/// otherwise. With no local provider, a cloud primary refuses requests
+/// while offline instead of timing out.
+///
+/// Installed by [`create_resilient_provider_with_options`](super::create_resilient_provider_with_options)
+/// when the runtime options carry a [`crate::connectivity::Connectivity`].
+pub struct OfflineAwareProvider {
+    online: Box<dyn Provider>,
+    /// `[connectivity].offline_provider`, when set.
+    local: Option<Box<dyn Provider>>,
+    /// Model for the local provider; `None` passes the requested model through.
+    local_model: Option<String>,
+    /// The configured provider runs locally and stays usable offline.
+    online_is_local: bool,
+    offline: Box<dyn Fn() -> bool + Send + Sync>,
+}
+
+impl OfflineAwareProvider {
+    pub fn new(
+        online: Box<dyn Provider>,
+        local: Option<Box<dyn Provider>>,
+        local_model: Option<String>,
+        online_is_local: bool,
+        offline: impl Fn() -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            online,
+            local,
+            local_model,
+            online_is_local,
+            offline: Box::new(offline),
+        }
+    }
+
+    /// The provider and model for the next request; `None` when offline
+    /// with nothing local to answer.
+    fn pick<'a>(&'a self, model: &'a str) -> Option<(&'a dyn Provider, &'a str)> {
+        if !(self.offline)() || (self.local.is_none() && self.online_is_local) {
+            return Some((self.online.as_ref(), model));
+        }
+        let local = self.local.as_deref()?;
+        tracing::debug!("Offline; routing request to the local provider");
+        Some((local, self.local_model.as_deref().unwrap_or(model)))
+    }
+
+    fn pick_or_refuse<'a>(&'a self, model: &'a str) -> anyhow::Result<(&'a dyn Provider, &'a str)> {
+        self.pick(model).ok_or_else(|| {
+            anyhow::anyhow!(
+                "offline: cloud providers are disabled; set [connectivity].offline_provider to a local provider"
+            )
+        })
+    }
+
+    /// Capability checks follow whichever provider would answer now.
+    fn current(&self) -> &dyn Provider {
+        self.pick("")
+            .map_or(self.online.as_ref(), |(provider, _)| provider)
+    }
+}
+
+fn refused(error: &anyhow::Error) -> BoxStream<'static, StreamResult<StreamChunk>> {
+    let chunk = StreamChunk::error(error.to_string());
+    futures_util::stream::once(async move { Ok(chunk) }).boxed()
+}
+
+#[async_trait]
+impl Provider for OfflineAwareProvider {
+    async fn chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        let (provider, model) = self.pick_or_refuse(model)?;
+        provider
+            .chat_with_system(system_prompt, message, model, temperature)
+            .await
+    }
+
+    async fn chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        let (provider, model) = self.pick_or_refuse(model)?;
+        provider
+            .chat_with_history(messages, model, temperature)
+            .await
+    }
+
+    async fn chat(
+        &self,
+        request: ChatRequest<'_>,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        let (provider, model) = self.pick_or_refuse(model)?;
+        provider.chat(request, model, temperature).await
+    }
+
+    async fn chat_with_tools(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[serde_json::Value],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        let (provider, model) = self.pick_or_refuse(model)?;
+        provider
+            .chat_with_tools(messages, tools, model, temperature)
+            .await
+    }
+
+    fn supports_native_tools(&self) -> bool {
+        self.current().supports_native_tools()
+    }
+
+    fn supports_vision(&self) -> bool {
+        self.current().supports_vision()
+    }
+
+    fn supports_streaming(&self) -> bool {
+        self.pick("")
+            .is_some_and(|(provider, _)| provider.supports_streaming())
+    }
+
+    fn stream_chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+        options: StreamOptions,
+    ) -> BoxStream<'static, StreamResult<StreamChunk>> {
+        match self.pick_or_refuse(model) {
+            Ok((provider, model)) => provider.stream_chat_with_system(
+                system_prompt,
+                message,
+                model,
+                temperature,
+                options,
+            ),
+            Err(error) => refused(&error),
+        }
+    }
+
+    fn stream_chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+        options: StreamOptions,
+    ) -> BoxStream<'static, StreamResult<StreamChunk>> {
+        match self.pick_or_refuse(model) {
+            Ok((provider, model)) => {
+                provider.stream_chat_with_history(messages, model, temperature, options)
+            }
+            Err(error) => refused(&error),
+        }
+    }
+
+    async fn warmup(&self) -> anyhow::Result<()> {
+        self.online.warmup().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Named(&'static str);
+
+    #[async_trait]
+    impl Provider for Named {
+        async fn chat_with_system(
+            &self,
+            _system_prompt: Option<&str>,
+            _message: &str,
+            model: &str,
+            _temperature: f64,
+        ) -> anyhow::Result<String> {
+            Ok(format!("{}:{model}", self.0))
+        }
+    }
+
+    #[tokio::test]
+    async fn routes_to_local_provider_only_while_offline() {
+        let mut provider = OfflineAwareProvider::new(
+            Box::new(Named("cloud")),
+            Some(Box::new(Named("ollama"))),
+            Some("llama3.2".into()),
+            false,
+            || false,
+        );
+        let reply = provider.simple_chat("hi", "gpt-5", 0.0).await.unwrap();
+        assert_eq!(reply, "cloud:gpt-5");
+
+        provider.offline = Box::new(|| true);
+        let reply = provider.simple_chat("hi", "gpt-5", 0.0).await.unwrap();
+        assert_eq!(reply, "ollama:llama3.2");
+
+        provider.local_model = None;
+        let reply = provider.simple_chat("hi", "gpt-5", 0.0).await.unwrap();
+        assert_eq!(reply, "ollama:gpt-5");
+
+        // No local provider: a cloud primary refuses, a local one answers.
+        provider.local = None;
+        let error = provider.simple_chat("hi", "gpt-5", 0.0).await.unwrap_err();
+        assert!(error.to_string().contains("offline"));
+        provider.online_is_local = true;
+        let reply = provider.simple_chat("hi", "gpt-5", 0.0).await.unwrap();
+        assert_eq!(reply, "cloud:gpt-5");
+    }
+}
@@ -331,6 +331,7 @@ impl Provider for OpenAiProvider {
         let response = self
             .http_client()
             .post(format!("{}/chat/completions", self.base_url))
+            .query(&crate::config::provider_request_query("provider.openai"))
             .header("Authorization", format!("Bearer {credential}"))
             .json(&request)
             .send()
@@ -372,6 +373,7 @@ impl Provider for OpenAiProvider {
         let response = self
             .http_client()
             .post(format!("{}/chat/completions", self.base_url))
+            .query(&crate::config::provider_request_query("provider.openai"))
             .header("Authorization", format!("Bearer {credential}"))
             .json(&native_request)
             .send()
@@ -435,6 +437,7 @@ impl Provider for OpenAiProvider {
         let response = self
             .http_client()
             .post(format!("{}/chat/completions", self.base_url))
+            .query(&crate::config::provider_request_query("provider.openai"))
             .header("Authorization", format!("Bearer {credential}"))
             .json(&native_request)
             .send()
@@ -464,6 +467,7 @@ impl Provider for OpenAiProvider {
         if let Some(credential) = self.credential.as_ref() {
             self.http_client()
                 .get(format!("{}/models", self.base_url))
+                .query(&crate::config::provider_request_query("provider.openai"))
                 .header("Authorization", format!("Bearer {credential}"))
                 .send()
                 .await?
@@ -1018,6 +1018,8 @@ data: [DONE]
             secrets_encrypt: false,
             auth_profile_override: None,
             reasoning_enabled: None,
+            connectivity: None,
+            recorder: None,
         };
         let provider =
             OpenAiCodexProvider::new(&options, None).expect("provider should initialize");
@@ -315,6 +315,9 @@ impl Provider for OpenRouterProvider {
         if let Some(credential) = self.credential.as_ref() {
             self.http_client()
                 .get("https://openrouter.ai/api/v1/auth/key")
+                .query(&crate::config::provider_request_query(
+                    "provider.openrouter",
+                ))
                 .header("Authorization", format!("Bearer {credential}"))
                 .send()
                 .await?
@@ -356,6 +359,9 @@ impl Provider for OpenRouterProvider {
         let response = self
             .http_client()
             .post("https://openrouter.ai/api/v1/chat/completions")
+            .query(&crate::config::provider_request_query(
+                "provider.openrouter",
+            ))
             .header("Authorization", format!("Bearer {credential}"))
             .header(
                 "HTTP-Referer",
@@ -406,6 +412,9 @@ impl Provider for OpenRouterProvider {
         let response = self
             .http_client()
             .post("https://openrouter.ai/api/v1/chat/completions")
+            .query(&crate::config::provider_request_query(
+                "provider.openrouter",
+            ))
             .header("Authorization", format!("Bearer {credential}"))
             .header(
                 "HTTP-Referer",
@@ -454,6 +463,9 @@ impl Provider for OpenRouterProvider {
         let response = self
             .http_client()
             .post("https://openrouter.ai/api/v1/chat/completions")
+            .query(&crate::config::provider_request_query(
+                "provider.openrouter",
+            ))
             .header("Authorization", format!("Bearer {credential}"))
             .header(
                 "HTTP-Referer",
@@ -548,6 +560,9 @@ impl Provider for OpenRouterProvider {
         let response = self
             .http_client()
             .post("https://openrouter.ai/api/v1/chat/completions")
+            .query(&crate::config::provider_request_query(
+                "provider.openrouter",
+            ))
             .header("Authorization", format!("Bearer {credential}"))
             .header(
                 "HTTP-Referer",
@@ -0,0 +1,198 @@
+//! Live generation stats: elapsed time, running token counts and tokens/sec.
+//!
+//! A caller wraps one agent turn in [`observe`] with a [`ProgressCallback`].
+//! Inside that scope, streams passed through [`track`] report every chunk as
+//! it arrives, requests awaited with [`wait`] report elapsed time while
+//! pending, and [`record_usage`] adds each finished response's token counts.
+//! Outside a scope all of these are pass-throughs.
+
+use super::traits::{StreamChunk, StreamResult};
+use futures_util::{stream::BoxStream, StreamExt};
+use parking_lot::Mutex;
+use std::fmt::Write as _;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const TICK: Duration = Duration::from_millis(250);
+
+tokio::task_local! {
+    static CURRENT: Arc<Progress>;
+}
+
+/// Snapshot passed to the callback. Token counts are running totals for the
+/// whole turn; streamed output is estimated until the provider reports usage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GenerationStats {
+    pub elapsed: Duration,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    /// True for the final snapshot once the turn has finished.
+    pub done: bool,
+}
+
+impl GenerationStats {
+    pub fn tokens_per_sec(&self) -> Option<f64> {
+        let secs = self.elapsed.as_secs_f64();
+        (self.output_tokens > 0 && secs > 0.0).then(|| self.output_tokens as f64 / secs)
+    }
+
+    /// One-line summary, e.g. `⏱ 3.2s · 812 in / 64 out · 20.0 tok/s`.
+    pub fn status_line(&self) -> String {
+        let mut line = format!(
+            "⏱ {:.1}s · {} in / {} out",
+            self.elapsed.as_secs_f64(),
+            self.input_tokens,
+            self.output_tokens
+        );
+        if let Some(rate) = self.tokens_per_sec() {
+            let _ = write!(line, " · {rate:.1} tok/s");
+        }
+        line
+    }
+}
+
+pub type ProgressCallback = Arc<dyn Fn(&GenerationStats) + Send + Sync>;
+
+struct Progress {
+    started: Instant,
+    callback: ProgressCallback,
+    /// `(input, output)` tokens so far.
+    totals: Mutex<(u64, u64)>,
+}
+
+impl Progress {
+    fn add(&self, input: u64, output: u64) {
+        let mut totals = self.totals.lock();
+        totals.0 += input;
+        totals.1 += output;
+    }
+
+    fn emit(&self, done: bool) {
+        let (input_tokens, output_tokens) = *self.totals.lock();
+        (self.callback)(&GenerationStats {
+            elapsed: self.started.elapsed(),
+            input_tokens,
+            output_tokens,
+            done,
+        });
+    }
+}
+
+fn current() -> Option<Arc<Progress>> {
+    CURRENT.try_with(Arc::clone).ok()
+}
+
+/// Run `future` with `callback` receiving stats for every request made
+/// inside it, followed by one final snapshot with `done` set.
+pub async fn observe<F: Future>(callback: ProgressCallback, future: F) -> F::Output {
+    let progress = Arc::new(Progress {
+        started: Instant::now(),
+        callback,
+        totals: Mutex::new((0, 0)),
+    });
+    let output = CURRENT.scope(Arc::clone(&progress), future).await;
+    progress.emit(true);
+    output
+}
+
+/// Count streamed chunks towards the running output total. Uses the chunk's
+/// own count when the provider filled it in, otherwise ~4 chars per token.
+/// Every chunk also counts as progress for the watchdog's stall timer.
+pub fn track(
+    stream: BoxStream<'static, StreamResult<StreamChunk>>,
+) -> BoxStream<'static, StreamResult<StreamChunk>> {
+    let progress = current();
+    stream
+        .map(move |chunk| {
+            crate::agent::watchdog::touch();
+            if let (Some(progress), Ok(chunk)) = (&progress, &chunk) {
+                let tokens = if chunk.token_count > 0 {
+                    chunk.token_count
+                } else {
+                    chunk.delta.len().div_ceil(4)
+                };
+                progress.add(0, tokens as u64);
+                progress.emit(false);
+            }
+            chunk
+        })
+        .boxed()
+}
+
+/// Await a non-streamed request, reporting elapsed time while it is pending.
+pub async fn wait<F: Future>(future: F) -> F::Output {
+    let Some(progress) = current() else {
+        return future.await;
+    };
+    tokio::pin!(future);
+    let mut ticker = tokio::time::interval(TICK);
+    loop {
+        tokio::select! {
+            output = &mut future => return output,
+            _ = ticker.tick() => progress.emit(false),
+        }
+    }
+}
+
+/// Add a finished response's usage. Missing output counts fall back to an
+/// estimate from `response_chars`.
+pub fn record_usage(input_tokens: Option<u64>, output_tokens: Option<u64>, response_chars: usize) {
+    let Some(progress) = current() else {
+        return;
+    };
+    progress.add(
+        input_tokens.unwrap_or(0),
+        output_tokens.unwrap_or_else(|| response_chars.div_ceil(4) as u64),
+    );
+    progress.emit(false);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::stream;
+
+    #[tokio::test]
+    async fn observe_accumulates_streamed_and_reported_tokens() {
+        let seen: Arc<Mutex<Vec<GenerationStats>>> = Arc::default();
+        let sink = Arc::clone(&seen);
+        let callback: ProgressCallback = Arc::new(move |stats| sink.lock().push(*stats));
+
+        observe(callback, async {
+            let chunks = stream::iter(vec![
+                Ok(StreamChunk::delta("12345678")),
+                Ok(StreamChunk {
+                    token_count: 5,
+                    ..StreamChunk::delta("x")
+                }),
+            ])
+            .boxed();
+            let _: Vec<_> = track(chunks).collect().await;
+            record_usage(Some(100), None, 40);
+        })
+        .await;
+
+        let seen = seen.lock();
+        let last = seen.last().unwrap();
+        assert!(last.done);
+        assert_eq!((last.input_tokens, last.output_tokens), (100, 2 + 5 + 10));
+        assert!(seen[..seen.len() - 1].iter().all(|stats| !stats.done));
+
+        // Outside a scope nothing is reported and nothing panics.
+        record_usage(Some(1), Some(1), 0);
+    }
+
+    #[test]
+    fn status_line_includes_rate_only_with_output() {
+        let mut stats = GenerationStats {
+            elapsed: Duration::from_secs(2),
+            input_tokens: 800,
+            output_tokens: 0,
+            done: false,
+        };
+        assert_eq!(stats.status_line(), "⏱ 2.0s · 800 in / 0 out");
+        stats.output_tokens = 50;
+        assert_eq!(stats.status_line(), "⏱ 2.0s · 800 in / 50 out · 25.0 tok/s");
+    }
+}
@@ -0,0 +1,734 @@
+//! Debug recorder for provider traffic (`zeroclaw --record-llm <dir> ...`).
+//!
+//! Providers built through
+//! [`create_resilient_provider_with_options`](super::create_resilient_provider_with_options)
+//! with a [`Recorder`] in their runtime options are wrapped in a
+//! [`RecordingProvider`]. While that recorder is enabled (from the CLI flag or
+//! at runtime by `!debug on`) it writes each call as a numbered
+//! JSON file (`0001-openrouter-chat.json`, ...) containing the request, the
+//! response or error, and timing. Known secret patterns are scrubbed before
+//! anything touches disk. `zeroclaw replay <file>` re-issues a recorded request.
+//!
+//! Streaming calls are forwarded unrecorded.
+
+use super::traits::{
+    ChatMessage, ChatRequest, ChatResponse, Provider, ProviderCapabilities, StreamChunk,
+    StreamOptions, StreamResult, ToolCall, ToolsPayload,
+};
+use crate::config::Config;
+use crate::tools::ToolSpec;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures_util::stream;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Shared recording switch. Clones record into the same directory and
+/// numbering, and providers wrapped with a handle follow it as it is turned
+/// on and off.
+#[derive(Debug, Clone, Default)]
+pub struct Recorder {
+    dir: Arc<RwLock<Option<PathBuf>>>,
+    sequence: Arc<AtomicU64>,
+}
+
+impl Recorder {
+    /// A recorder that is already recording into `config.record_llm_dir`
+    /// when the CLI passed `--record-llm`.
+    pub fn new(config: &Config) -> Self {
+        let recorder = Self::default();
+        if let Some(dir) = config.record_llm_dir.as_deref() {
+            if let Err(e) = recorder.enable(dir) {
+                tracing::warn!("LLM recording not started: {e:#}");
+            }
+        }
+        recorder
+    }
+
+    /// Start recording provider traffic into `dir` until [`Self::disable`].
+    ///
+    /// Numbering continues after any recordings already in the directory so
+    /// repeated runs never overwrite each other.
+    pub fn enable(&self, dir: &Path) -> Result<()> {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create LLM record directory {}", dir.display()))?;
+
+        let next = std::fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                name.split('-').next()?.parse::<u64>().ok()
+            })
+            .max()
+            .map_or(1, |highest| highest + 1);
+
+        let mut current = self.dir.write();
+        if current.is_some() {
+            anyhow::bail!("LLM recording is already enabled");
+        }
+        self.sequence.store(next, Ordering::SeqCst);
+        *current = Some(dir.to_path_buf());
+        Ok(())
+    }
+
+    /// Stop recording. Providers keep forwarding calls unrecorded.
+    pub fn disable(&self) {
+        *self.dir.write() = None;
+    }
+
+    /// Directory recordings are currently written to, if recording is enabled.
+    pub fn dir(&self) -> Option<PathBuf> {
+        self.dir.read().clone()
+    }
+
+    /// Wrap `provider` so it records whenever this recorder is enabled.
+    pub fn wrap(&self, name: &str, provider: Box<dyn Provider>) -> Box<dyn Provider> {
+        Box::new(RecordingProvider::new(name, provider, self.clone()))
+    }
+}
+
+/// Which provider entry point a recording came through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordedMethod {
+    ChatWithSystem,
+    ChatWithHistory,
+    Chat,
+    ChatWithTools,
+}
+
+impl RecordedMethod {
+    fn file_stem(self) -> &'static str {
+        match self {
+            Self::ChatWithSystem => "chat-with-system",
+            Self::ChatWithHistory => "chat-with-history",
+            Self::Chat => "chat",
+            Self::ChatWithTools => "chat-with-tools",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_prompt: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub messages: Vec<ChatMessage>,
+    /// Tool specs passed to `chat`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tool_specs: Vec<ToolSpec>,
+    /// Provider-native tool definitions passed to `chat_with_tools`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tools: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tool_calls: Vec<ToolCall>,
+    #[serde(default)]
+    pub input_tokens: Option<u64>,
+    #[serde(default)]
+    pub output_tokens: Option<u64>,
+}
+
+impl RecordedResponse {
+    fn from_chat(response: &ChatResponse) -> Self {
+        let usage = response.usage.clone().unwrap_or_default();
+        Self {
+            text: response.text.clone(),
+            tool_calls: response.tool_calls.clone(),
+            input_tokens: usage.input_tokens,
+            output_tokens: usage.output_tokens,
+        }
+    }
+
+    fn from_text(text: &str) -> Self {
+        Self {
+            text: Some(text.to_string()),
+            ..Self::default()
+        }
+    }
+}
+
+/// One recorded provider call, as written to disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedExchange {
+    pub sequence: u64,
+    pub provider: String,
+    pub model: String,
+    pub temperature: f64,
+    pub method: RecordedMethod,
+    pub started_at: String,
+    pub duration_ms: u64,
+    pub request: RecordedRequest,
+    #[serde(default)]
+    pub response: Option<RecordedResponse>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl RecordedExchange {
+    /// Load a recording written by `--record-llm`.
+    pub fn load(path: &Path) -> Result<Self> {
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read recording {}", path.display()))?;
+        serde_json::from_str(&raw)
+            .with_context(|| format!("Failed to parse recording {}", path.display()))
+    }
+
+    /// Re-issue the recorded request against `provider`.
+    pub async fn replay(&self, provider: &dyn Provider, model: &str) -> Result<RecordedResponse> {
+        let request = &self.request;
+        match self.method {
+            RecordedMethod::ChatWithSystem => provider
+                .chat_with_system(
+                    request.system_prompt.as_deref(),
+                    request.message.as_deref().unwrap_or_default(),
+                    model,
+                    self.temperature,
+                )
+                .await
+                .map(|text| RecordedResponse::from_text(&text)),
+            RecordedMethod::ChatWithHistory => provider
+                .chat_with_history(&request.messages, model, self.temperature)
+                .await
+                .map(|text| RecordedResponse::from_text(&text)),
+            RecordedMethod::Chat => {
+                let tools =
+                    (!request.tool_specs.is_empty()).then_some(request.tool_specs.as_slice());
+                provider
+                    .chat(
+                        ChatRequest {
+                            messages: &request.messages,
+                            tools,
+                        },
+                        model,
+                        self.temperature,
+                    )
+                    .await
+                    .map(|response| RecordedResponse::from_chat(&response))
+            }
+            RecordedMethod::ChatWithTools => provider
+                .chat_with_tools(&request.messages, &request.tools, model, self.temperature)
+                .await
+                .map(|response| RecordedResponse::from_chat(&response)),
+        }
+    }
+}
+
+/// `zeroclaw replay`: re-issue a recorded request and compare the outcome.
+///
+/// The provider and model default to the recorded ones; the config's API key
+/// and URL are only used when the provider matches `default_provider`,
+/// otherwise credentials resolve from the provider's env vars.
+pub async fn run_replay(
+    config: &Config,
+    path: &Path,
+    provider_override: Option<&str>,
+    model_override: Option<&str>,
+) -> Result<()> {
+    let exchange = RecordedExchange::load(path)?;
+    let provider_name = provider_override.unwrap_or(&exchange.provider);
+    let model = model_override.unwrap_or(&exchange.model);
+    let is_default = config.default_provider.as_deref() == Some(provider_name);
+    let provider = super::create_provider_with_url(
+        provider_name,
+        config.api_key.as_deref().filter(|_| is_default),
+        config.api_url.as_deref().filter(|_| is_default),
+    )?;
+
+    println!(
+        "Replaying #{} ({}) against {provider_name} / {model}",
+        exchange.sequence,
+        exchange.method.file_stem()
+    );
+    println!(
+        "  recorded: {} ms, {}",
+        exchange.duration_ms,
+        if exchange.error.is_some() {
+            "error"
+        } else {
+            "ok"
+        }
+    );
+
+    let started_at = Utc::now();
+    let result = exchange.replay(provider.as_ref(), model).await;
+    let elapsed_ms = (Utc::now() - started_at).num_milliseconds();
+
+    let response = match result {
+        Ok(response) => response,
+        Err(error) => {
+            println!("  replayed: {elapsed_ms} ms, error");
+            return Err(error.context("Replayed request failed"));
+        }
+    };
+    println!("  replayed: {elapsed_ms} ms, ok");
+    println!();
+
+    if let Some(text) = response.text.as_deref() {
+        println!("{text}");
+    }
+    for call in &response.tool_calls {
+        println!("→ tool call {}({})", call.name, call.arguments);
+    }
+    if response.input_tokens.is_some() || response.output_tokens.is_some() {
+        println!(
+            "  tokens: {} in / {} out",
+            response.input_tokens.unwrap_or_default(),
+            response.output_tokens.unwrap_or_default()
+        );
+    }
+
+    let recorded_text = exchange
+        .response
+        .as_ref()
+        .and_then(|recorded| recorded.text.as_deref());
+    println!();
+    if recorded_text == response.text.as_deref() {
+        println!("Response text matches the recording.");
+    } else {
+        println!("Response text differs from the recording.");
+    }
+
+    Ok(())
+}
+
+/// Provider wrapper that records every non-streaming call to disk while its
+/// [`Recorder`] is enabled.
+pub struct RecordingProvider {
+    name: String,
+    inner: Box<dyn Provider>,
+    recorder: Recorder,
+}
+
+impl RecordingProvider {
+    pub fn new(name: &str, inner: Box<dyn Provider>, recorder: Recorder) -> Self {
+        Self {
+            name: name.to_string(),
+            inner,
+            recorder,
+        }
+    }
+
+    fn target_dir(&self) -> Option<PathBuf> {
+        self.recorder.dir()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn write(
+        &self,
+        dir: &Path,
+        method: RecordedMethod,
+        model: &str,
+        temperature: f64,
+        started_at: DateTime<Utc>,
+        request: RecordedRequest,
+        result: Result<RecordedResponse, String>,
+    ) {
+        let stem = format!(
+            "{}-{}",
+            sanitize_file_component(&self.name),
+            method.file_stem()
+        );
+        // Separate recorders may share a directory (a daemon and a cron agent
+        // run started with the same `--record-llm`), so a taken number moves on
+        // to the next one instead of overwriting it.
+        let (sequence, path, mut file) = loop {
+            let sequence = self.recorder.sequence.fetch_add(1, Ordering::SeqCst);
+            let path = dir.join(format!("{sequence:04}-{stem}.json"));
+            match std::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+            {
+                Ok(file) => break (sequence, path, file),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {}
+                Err(e) => {
+                    tracing::warn!("Failed to write LLM recording {}: {e}", path.display());
+                    return;
+                }
+            }
+        };
+
+        let duration_ms = u64::try_from((Utc::now() - started_at).num_milliseconds()).unwrap_or(0);
+        let (response, error) = match result {
+            Ok(response) => (Some(response), None),
+            Err(error) => (None, Some(error)),
+        };
+        let exchange = RecordedExchange {
+            sequence,
+            provider: self.name.clone(),
+            model: model.to_string(),
+            temperature,
+            method,
+            started_at: started_at.to_rfc3339(),
+            duration_ms,
+            request,
+            response,
+            error,
+        };
+
+        let written = serde_json::to_string_pretty(&exchange)
+            .map(|json| super::scrub_secret_patterns(&json))
+            .map_err(anyhow::Error::from)
+            .and_then(|json| file.write_all(json.as_bytes()).map_err(anyhow::Error::from));
+        if let Err(e) = written {
+            tracing::warn!("Failed to write LLM recording {}: {e}", path.display());
+        }
+    }
+}
+
+fn sanitize_file_component(raw: &str) -> String {
+    raw.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn error_text(error: &anyhow::Error) -> String {
+    format!("{error:#}")
+}
+
+#[async_trait]
+impl Provider for RecordingProvider {
+    fn capabilities(&self) -> ProviderCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn convert_tools(&self, tools: &[ToolSpec]) -> ToolsPayload {
+        self.inner.convert_tools(tools)
+    }
+
+    async fn chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        let Some(dir) = self.target_dir() else {
+            return self
+                .inner
+                .chat_with_system(system_prompt, message, model, temperature)
+                .await;
+        };
+        let started_at = Utc::now();
+        let result = self
+            .inner
+            .chat_with_system(system_prompt, message, model, temperature)
+            .await;
+        self.write(
+            &dir,
+            RecordedMethod::ChatWithSystem,
+            model,
+            temperature,
+            started_at,
+            RecordedRequest {
+                system_prompt: system_prompt.map(str::to_string),
+                message: Some(message.to_string()),
+                ..RecordedRequest::default()
+            },
+            result
+                .as_ref()
+                .map(|text| RecordedResponse::from_text(text))
+                .map_err(error_text),
+        );
+        result
+    }
+
+    async fn chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        let Some(dir) = self.target_dir() else {
+            return self
+                .inner
+                .chat_with_history(messages, model, temperature)
+                .await;
+        };
+        let started_at = Utc::now();
+        let result = self
+            .inner
+            .chat_with_history(messages, model, temperature)
+            .await;
+        self.write(
+            &dir,
+            RecordedMethod::ChatWithHistory,
+            model,
+            temperature,
+            started_at,
+            RecordedRequest {
+                messages: messages.to_vec(),
+                ..RecordedRequest::default()
+            },
+            result
+                .as_ref()
+                .map(|text| RecordedResponse::from_text(text))
+                .map_err(error_text),
+        );
+        result
+    }
+
+    async fn chat(
+        &self,
+        request: ChatRequest<'_>,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        let Some(dir) = self.target_dir() else {
+            return self.inner.chat(request, model, temperature).await;
+        };
+        let started_at = Utc::now();
+        let result = self.inner.chat(request, model, temperature).await;
+        self.write(
+            &dir,
+            RecordedMethod::Chat,
+            model,
+            temperature,
+            started_at,
+            RecordedRequest {
+                messages: request.messages.to_vec(),
+                tool_specs: request.tools.map(<[ToolSpec]>::to_vec).unwrap_or_default(),
+                ..RecordedRequest::default()
+            },
+            result
+                .as_ref()
+                .map(RecordedResponse::from_chat)
+                .map_err(error_text),
+        );
+        result
+    }
+
+    async fn chat_with_tools(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[serde_json::Value],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        let Some(dir) = self.target_dir() else {
+            return self
+                .inner
+                .chat_with_tools(messages, tools, model, temperature)
+                .await;
+        };
+        let started_at = Utc::now();
+        let result = self
+            .inner
+            .chat_with_tools(messages, tools, model, temperature)
+            .await;
+        self.write(
+            &dir,
+            RecordedMethod::ChatWithTools,
+            model,
+            temperature,
+            started_at,
+            RecordedRequest {
+                messages: messages.to_vec(),
+                tools: tools.to_vec(),
+                ..RecordedRequest::default()
+            },
+            result
+                .as_ref()
+                .map(RecordedResponse::from_chat)
+                .map_err(error_text),
+        );
+        result
+    }
+
+    fn supports_native_tools(&self) -> bool {
+        self.inner.supports_native_tools()
+    }
+
+    fn supports_vision(&self) -> bool {
+        self.inner.supports_vision()
+    }
+
+    async fn warmup(&self) -> anyhow::Result<()> {
+        self.inner.warmup().await
+    }
+
+    fn supports_streaming(&self) -> bool {
+        self.inner.supports_streaming()
+    }
+
+    fn stream_chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        self.inner
+            .stream_chat_with_system(system_prompt, message, model, temperature, options)
+    }
+
+    fn stream_chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        self.inner
+            .stream_chat_with_history(messages, model, temperature, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct EchoProvider;
+
+    #[async_trait]
+    impl Provider for EchoProvider {
+        async fn chat_with_system(
+            &self,
+            _system_prompt: Option<&str>,
+            message: &str,
+            _model: &str,
+            _temperature: f64,
+        ) -> anyhow::Result<String> {
+            if message == "fail" {
+                anyhow::bail!("upstream rejected key sk-test-123456");
+            }
+            Ok(format!("echo: {message}"))
+        }
+    }
+
+    fn recordings(dir: &Path) -> Vec<PathBuf> {
+        let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        files.sort();
+        files
+    }
+
+    #[tokio::test]
+    async fn records_request_response_and_scrubbed_errors() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let recorder = Recorder::default();
+        recorder.enable(tmp.path()).unwrap();
+        let provider = RecordingProvider::new("echo", Box::new(EchoProvider), recorder);
+
+        let reply = provider
+            .chat_with_system(Some("be brief"), "hello", "m1", 0.2)
+            .await
+            .unwrap();
+        assert_eq!(reply, "echo: hello");
+        assert!(provider
+            .chat_with_system(None, "fail", "m1", 0.2)
+            .await
+            .is_err());
+
+        let files = recordings(tmp.path());
+        assert_eq!(files.len(), 2);
+        assert!(files[0]
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .ends_with("-echo-chat-with-system.json"));
+
+        let ok = RecordedExchange::load(&files[0]).unwrap();
+        assert_eq!(ok.method, RecordedMethod::ChatWithSystem);
+        assert_eq!(ok.model, "m1");
+        assert_eq!(ok.request.system_prompt.as_deref(), Some("be brief"));
+        assert_eq!(
+            ok.response.and_then(|r| r.text).as_deref(),
+            Some("echo: hello")
+        );
+
+        let failed = std::fs::read_to_string(&files[1]).unwrap();
+        assert!(failed.contains("[REDACTED]"));
+        assert!(!failed.contains("sk-test-123456"));
+    }
+
+    #[tokio::test]
+    async fn follows_its_recorder_and_never_overwrites_a_shared_directory() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let first = Recorder::default();
+        let second = Recorder::default();
+        let provider = first.wrap("echo", Box::new(EchoProvider));
+        let other = second.wrap("echo", Box::new(EchoProvider));
+
+        provider
+            .chat_with_system(None, "off", "m1", 0.2)
+            .await
+            .unwrap();
+        assert!(recordings(tmp.path()).is_empty());
+
+        first.enable(tmp.path()).unwrap();
+        second.enable(tmp.path()).unwrap();
+        provider
+            .chat_with_system(None, "one", "m1", 0.2)
+            .await
+            .unwrap();
+        other
+            .chat_with_system(None, "two", "m1", 0.2)
+            .await
+            .unwrap();
+        other
+            .chat_with_system(None, "three", "m1", 0.2)
+            .await
+            .unwrap();
+        let files = recordings(tmp.path());
+        assert_eq!(files.len(), 3);
+        let sequences: Vec<u64> = files
+            .iter()
+            .map(|file| RecordedExchange::load(file).unwrap().sequence)
+            .collect();
+        assert_eq!(sequences, vec![1, 2, 3]);
+
+        first.disable();
+        provider
+            .chat_with_system(None, "off again", "m1", 0.2)
+            .await
+            .unwrap();
+        assert_eq!(recordings(tmp.path()).len(), 3);
+        assert!(first.dir().is_none());
+        assert_eq!(second.dir().as_deref(), Some(tmp.path()));
+    }
+
+    #[tokio::test]
+    async fn replay_reissues_recorded_request() {
+        let exchange = RecordedExchange {
+            sequence: 1,
+            provider: "echo".into(),
+            model: "m1".into(),
+            temperature: 0.0,
+            method: RecordedMethod::ChatWithHistory,
+            started_at: Utc::now().to_rfc3339(),
+            duration_ms: 5,
+            request: RecordedRequest {
+                messages: vec![ChatMessage::user("again")],
+                ..RecordedRequest::default()
+            },
+            response: None,
+            error: None,
+        };
+
+        let response = exchange.replay(&EchoProvider, "m1").await.unwrap();
+        assert_eq!(response.text.as_deref(), Some("echo: again"));
+    }
+}
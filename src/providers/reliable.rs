@@ -838,10 +838,12 @@ impl Provider for ReliableProvider {
             });
 
             // Convert channel receiver to stream
-            return stream::unfold(rx, |mut rx| async move {
-                rx.recv().await.map(|chunk| (chunk, rx))
-            })
-            .boxed();
+            return super::progress::track(
+                stream::unfold(rx, |mut rx| async move {
+                    rx.recv().await.map(|chunk| (chunk, rx))
+                })
+                .boxed(),
+            );
         }
 
         // No streaming support available
@@ -0,0 +1,483 @@
+//! Headless device provisioning (Raspberry Pi and other cloud-init images).
+//!
+//! Generates cloud-init seed files (`user-data`, `meta-data`, `network-config`)
+//! that, on first boot, create the agent user, install the release binary,
+//! drop the bootstrap preset and any pre-seeded workspace files in place, and
+//! enable a systemd unit running `zeroclaw bootstrap --daemon`.
+//!
+//! The agent user has no sudo, so the agent's tools never run as root. An
+//! administrator with sudo is only created on request (`--admin-user`). The
+//! binary comes from the release matching this build and is checked against
+//! the release's `SHA256SUMS` before it is unpacked.
+//!
+//! `provision sd` writes the seed into the boot partition of a freshly flashed
+//! card; it never writes to the raw block device.
+
+use crate::onboard::bootstrap::BootstrapPreset;
+use crate::{ProvisionCommands, ProvisionOptions};
+use anyhow::{bail, Context, Result};
+use base64::Engine;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+const RELEASE_BASE_URL: &str = concat!(
+    "https://github.com/zeroclaw-labs/zeroclaw/releases/download/v",
+    env!("CARGO_PKG_VERSION")
+);
+const INSTALL_PATH: &str = "/usr/local/bin/zeroclaw";
+const SERVICE_NAME: &str = "zeroclaw.service";
+/// Files that identify a Raspberry Pi boot (FAT) partition.
+const BOOT_PARTITION_MARKERS: [&str; 2] = ["config.txt", "cmdline.txt"];
+const USER_GROUPS: &str = "[dialout, gpio, i2c, spi, video, plugdev]";
+
+/// One generated seed file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeedFile {
+    pub name: &'static str,
+    pub contents: String,
+}
+
+pub fn handle_command(command: ProvisionCommands) -> Result<()> {
+    let (options, dest) = match command {
+        ProvisionCommands::CloudInit { options, output } => (options, output),
+        ProvisionCommands::Sd {
+            options,
+            device,
+            force,
+        } => (options, resolve_boot_partition(&device, force)?),
+    };
+
+    let seed = generate_seed(&options)?;
+    std::fs::create_dir_all(&dest)
+        .with_context(|| format!("failed to create {}", dest.display()))?;
+    for file in &seed {
+        let path = dest.join(file.name);
+        std::fs::write(&path, &file.contents)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        println!("  wrote {}", path.display());
+    }
+
+    println!("✅ Provisioning seed ready in {}", dest.display());
+    println!(
+        "  On first boot, {} installs ZeroClaw and starts {SERVICE_NAME}.",
+        options.hostname
+    );
+    println!("  Eject the card (or attach the seed to your VM) and boot.");
+    Ok(())
+}
+
+/// Build cloud-init `user-data`, `meta-data`, and `network-config`.
+pub fn generate_seed(options: &ProvisionOptions) -> Result<Vec<SeedFile>> {
+    validate_name("hostname", &options.hostname)?;
+    validate_name("user", &options.user)?;
+    if let Some(ref admin) = options.admin_user {
+        validate_name("admin user", admin)?;
+        if *admin == options.user {
+            bail!("--admin-user must differ from --user; the service user never gets sudo");
+        }
+    }
+
+    let preset_raw = std::fs::read_to_string(&options.preset)
+        .with_context(|| format!("failed to read preset {}", options.preset.display()))?;
+    // Fail here rather than on the device at first boot.
+    toml::from_str::<BootstrapPreset>(&preset_raw)
+        .with_context(|| format!("invalid preset {}", options.preset.display()))?;
+
+    let ssh_keys = match options.ssh_key {
+        Some(ref path) => std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read SSH key {}", path.display()))?
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .map(str::to_string)
+            .collect(),
+        None => Vec::new(),
+    };
+
+    let mut workspace_files = Vec::new();
+    if let Some(ref dir) = options.workspace {
+        collect_seed_files(dir, dir, &mut workspace_files)?;
+    }
+
+    Ok(vec![
+        SeedFile {
+            name: "user-data",
+            contents: user_data(options, &preset_raw, &ssh_keys, &workspace_files),
+        },
+        SeedFile {
+            name: "meta-data",
+            contents: format!(
+                "instance-id: zeroclaw-{}\nlocal-hostname: {}\n",
+                uuid::Uuid::new_v4(),
+                options.hostname
+            ),
+        },
+        SeedFile {
+            name: "network-config",
+            contents: network_config(options),
+        },
+    ])
+}
+
+fn user_data(
+    options: &ProvisionOptions,
+    preset: &str,
+    ssh_keys: &[String],
+    workspace_files: &[(String, Vec<u8>)],
+) -> String {
+    let user = &options.user;
+    let home = format!("/home/{user}");
+    let preset_path = format!("{home}/.zeroclaw/preset.toml");
+
+    let mut out = String::from("#cloud-config\n");
+    let _ = writeln!(out, "hostname: {}", options.hostname);
+    out.push_str("manage_etc_hosts: true\n");
+    out.push_str("package_update: false\n\n");
+
+    out.push_str("users:\n");
+    let _ = writeln!(out, "  - name: {user}");
+    let _ = writeln!(out, "    groups: {USER_GROUPS}");
+    out.push_str("    shell: /bin/bash\n");
+    out.push_str("    lock_passwd: true\n");
+    match options.admin_user {
+        Some(ref admin) => {
+            let _ = writeln!(out, "  - name: {admin}");
+            out.push_str("    groups: [sudo]\n");
+            out.push_str("    shell: /bin/bash\n");
+            out.push_str("    sudo: ALL=(ALL) NOPASSWD:ALL\n");
+            out.push_str("    lock_passwd: true\n");
+            push_ssh_keys(&mut out, ssh_keys);
+        }
+        None => push_ssh_keys(&mut out, ssh_keys),
+    }
+    if !ssh_keys.is_empty() {
+        out.push_str("ssh_pwauth: false\n");
+        out.push_str("enable_ssh: true\n");
+    }
+    out.push('\n');
+
+    // Deferred so the user (and home directory) exists before files are written.
+    out.push_str("write_files:\n");
+    push_file(&mut out, &preset_path, preset.as_bytes(), user, "0600");
+    for (rel, data) in workspace_files {
+        let path = format!("{home}/.zeroclaw/workspace/{rel}");
+        push_file(&mut out, &path, data, user, "0644");
+    }
+    push_file(
+        &mut out,
+        &format!("/etc/systemd/system/{SERVICE_NAME}"),
+        service_unit(user, &preset_path).as_bytes(),
+        "root",
+        "0644",
+    );
+    out.push('\n');
+
+    out.push_str("runcmd:\n");
+    let _ = writeln!(out, "  - [sh, -c, {}]", yaml_quote(&install_script()));
+    let _ = writeln!(
+        out,
+        "  - [chown, -R, \"{user}:{user}\", \"{home}/.zeroclaw\"]"
+    );
+    out.push_str("  - [systemctl, daemon-reload]\n");
+    let _ = writeln!(out, "  - [systemctl, enable, --now, {SERVICE_NAME}]");
+    out
+}
+
+/// `ssh_authorized_keys` for the user entry written just before.
+fn push_ssh_keys(out: &mut String, ssh_keys: &[String]) {
+    if ssh_keys.is_empty() {
+        return;
+    }
+    out.push_str("    ssh_authorized_keys:\n");
+    for key in ssh_keys {
+        let _ = writeln!(out, "      - {}", yaml_quote(key));
+    }
+}
+
+fn push_file(out: &mut String, path: &str, data: &[u8], owner: &str, permissions: &str) {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(data);
+    let _ = writeln!(out, "  - path: {}", yaml_quote(path));
+    out.push_str("    encoding: b64\n");
+    let _ = writeln!(out, "    content: {encoded}");
+    let _ = writeln!(out, "    owner: \"{owner}:{owner}\"");
+    let _ = writeln!(out, "    permissions: \"{permissions}\"");
+    out.push_str("    defer: true\n");
+}
+
+fn service_unit(user: &str, preset_path: &str) -> String {
+    format!(
+        "[Unit]\nDescription=ZeroClaw daemon\nWants=network-online.target\nAfter=network-online.target\n\n\
+         [Service]\nType=simple\nUser={user}\nEnvironment=ZEROCLAW_PRESET={preset_path}\n\
+         ExecStart={INSTALL_PATH} bootstrap --daemon\nRestart=always\nRestartSec=3\n\n\
+         [Install]\nWantedBy=multi-user.target\n"
+    )
+}
+
+/// Shell snippet that downloads this version's release archive for the
+/// device architecture, checks it against the release's `SHA256SUMS`, and
+/// installs the binary. Any mismatch or missing checksum aborts.
+fn install_script() -> String {
+    format!(
+        "set -e; case \"$(uname -m)\" in \
+         aarch64|arm64) t=aarch64-unknown-linux-gnu ;; \
+         armv7l|armv6l) t=armv7-unknown-linux-gnueabihf ;; \
+         x86_64) t=x86_64-unknown-linux-gnu ;; \
+         *) echo \"unsupported arch $(uname -m)\" >&2; exit 1 ;; esac; \
+         a=zeroclaw-$t.tar.gz; d=$(mktemp -d); cd \"$d\"; \
+         curl -fsSL -o \"$a\" {RELEASE_BASE_URL}/$a; \
+         curl -fsSL -o SHA256SUMS {RELEASE_BASE_URL}/SHA256SUMS; \
+         grep \"  $a\\$\" SHA256SUMS > expected; sha256sum --strict -c expected; \
+         tar xzf \"$a\"; install -m 0755 zeroclaw {INSTALL_PATH}; cd /; rm -rf \"$d\""
+    )
+}
+
+fn network_config(options: &ProvisionOptions) -> String {
+    let mut out = String::from("network:\n  version: 2\n  ethernets:\n    eth0:\n      dhcp4: true\n      optional: true\n");
+    if let Some(ref ssid) = options.wifi_ssid {
+        out.push_str("  wifis:\n    wlan0:\n      dhcp4: true\n      optional: true\n");
+        let _ = writeln!(
+            out,
+            "      regulatory-domain: {}",
+            yaml_quote(&options.wifi_country)
+        );
+        out.push_str("      access-points:\n");
+        let _ = writeln!(out, "        {}:", yaml_quote(ssid));
+        match options.wifi_password {
+            Some(ref password) => {
+                let _ = writeln!(out, "          password: {}", yaml_quote(password));
+            }
+            None => out.push_str("          {}\n"),
+        }
+    }
+    out
+}
+
+/// Double-quoted YAML scalar (JSON string syntax is valid YAML).
+fn yaml_quote(raw: &str) -> String {
+    serde_json::to_string(raw).unwrap_or_else(|_| "\"\"".into())
+}
+
+fn validate_name(field: &str, value: &str) -> Result<()> {
+    let valid = !value.is_empty()
+        && value.len() <= 32
+        && value
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && !value.starts_with('-');
+    if !valid {
+        bail!("invalid {field} {value:?}: use lowercase letters, digits, and '-'");
+    }
+    Ok(())
+}
+
+fn collect_seed_files(root: &Path, dir: &Path, out: &mut Vec<(String, Vec<u8>)>) -> Result<()> {
+    for entry in
+        std::fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))?
+    {
+        let entry = entry?;
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+        if file_type.is_symlink() || entry.file_name() == ".git" {
+            continue;
+        }
+        if file_type.is_dir() {
+            collect_seed_files(root, &path, out)?;
+        } else {
+            let rel = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            out.push((rel, std::fs::read(&path)?));
+        }
+    }
+    Ok(())
+}
+
+/// Resolve `--device` to a mounted Raspberry Pi boot partition.
+///
+/// Accepts the mount point itself, or a block device (`/dev/sdX`, `/dev/mmcblk0`)
+/// whose first partition is mounted.
+fn resolve_boot_partition(device: &Path, force: bool) -> Result<PathBuf> {
+    let mount = if device.is_dir() {
+        device.to_path_buf()
+    } else if device.starts_with("/dev") {
+        let mounts = std::fs::read_to_string("/proc/mounts")
+            .context("cannot read /proc/mounts; pass the mounted boot partition path instead")?;
+        find_boot_mount(&mounts, device).with_context(|| {
+            format!(
+                "no mounted partition found for {}; mount the card's boot (FAT) partition and retry",
+                device.display()
+            )
+        })?
+    } else {
+        bail!(
+            "{} is neither a directory nor a /dev block device",
+            device.display()
+        );
+    };
+
+    let looks_like_boot = BOOT_PARTITION_MARKERS
+        .iter()
+        .any(|marker| mount.join(marker).exists());
+    if !looks_like_boot && !force {
+        bail!(
+            "{} does not look like a Raspberry Pi boot partition (no config.txt/cmdline.txt); pass --force to write anyway",
+            mount.display()
+        );
+    }
+    Ok(mount)
+}
+
+/// Mount point of the first mounted partition of `device` that looks like a boot partition.
+fn find_boot_mount(proc_mounts: &str, device: &Path) -> Option<PathBuf> {
+    let device = device.to_string_lossy();
+    let mut candidates: Vec<(String, PathBuf)> = proc_mounts
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let source = parts.next()?;
+            let target = parts.next()?;
+            let fs_type = parts.next()?;
+            let is_partition = source == device
+                || source.strip_prefix(device.as_ref()).is_some_and(|rest| {
+                    let rest = rest.strip_prefix('p').unwrap_or(rest);
+                    !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit())
+                });
+            (is_partition && matches!(fs_type, "vfat" | "msdos" | "fat"))
+                // /proc/mounts escapes spaces as \040
+                .then(|| {
+                    (
+                        source.to_string(),
+                        PathBuf::from(target.replace("\\040", " ")),
+                    )
+                })
+        })
+        .collect();
+    candidates.sort();
+    candidates.into_iter().next().map(|(_, target)| target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn options(tmp: &TempDir) -> ProvisionOptions {
+        let preset = tmp.path().join("preset.toml");
+        std::fs::write(
+            &preset,
+            "provider = \"openrouter\"\napi_key = \"sk-test\"\n",
+        )
+        .unwrap();
+        ProvisionOptions {
+            preset,
+            hostname: "zeroclaw".into(),
+            user: "zeroclaw".into(),
+            admin_user: None,
+            ssh_key: None,
+            wifi_ssid: Some("Home \"Net\"".into()),
+            wifi_password: Some("pw: with colon".into()),
+            wifi_country: "GB".into(),
+            workspace: None,
+        }
+    }
+
+    #[test]
+    fn seed_contains_preset_service_and_wifi() {
+        let tmp = TempDir::new().unwrap();
+        let mut opts = options(&tmp);
+        let ws = tmp.path().join("ws");
+        std::fs::create_dir_all(ws.join("memory")).unwrap();
+        std::fs::write(ws.join("SOUL.md"), "soul").unwrap();
+        opts.workspace = Some(ws);
+
+        let seed = generate_seed(&opts).unwrap();
+        let user_data = &seed
+            .iter()
+            .find(|f| f.name == "user-data")
+            .unwrap()
+            .contents;
+        assert!(user_data.starts_with("#cloud-config\n"));
+        assert!(user_data.contains("/home/zeroclaw/.zeroclaw/preset.toml"));
+        assert!(user_data.contains("/home/zeroclaw/.zeroclaw/workspace/SOUL.md"));
+        assert!(user_data.contains("/etc/systemd/system/zeroclaw.service"));
+        assert!(!user_data.contains("sk-test"), "preset is base64-encoded");
+        let encoded = base64::engine::general_purpose::STANDARD.encode(service_unit(
+            "zeroclaw",
+            "/home/zeroclaw/.zeroclaw/preset.toml",
+        ));
+        assert!(user_data.contains(&encoded));
+        assert!(!user_data.contains("sudo"), "the service user gets no sudo");
+        assert!(user_data.contains(concat!("/download/v", env!("CARGO_PKG_VERSION"))));
+        assert!(user_data.contains("sha256sum --strict -c"));
+        assert!(!user_data.contains("/latest/"));
+
+        let network = &seed
+            .iter()
+            .find(|f| f.name == "network-config")
+            .unwrap()
+            .contents;
+        assert!(network.contains(r#""Home \"Net\"":"#));
+        assert!(network.contains(r#"password: "pw: with colon""#));
+        assert!(network.contains(r#"regulatory-domain: "GB""#));
+    }
+
+    #[test]
+    fn admin_user_gets_sudo_and_ssh_keys_instead_of_service_user() {
+        let tmp = TempDir::new().unwrap();
+        let mut opts = options(&tmp);
+        let key = tmp.path().join("id.pub");
+        std::fs::write(&key, "ssh-ed25519 AAAA test\n").unwrap();
+        opts.ssh_key = Some(key);
+        opts.admin_user = Some("admin".into());
+
+        let seed = generate_seed(&opts).unwrap();
+        let user_data = &seed[0].contents;
+        let (service, admin) = user_data.split_once("  - name: admin\n").unwrap();
+        assert!(!service.contains("sudo") && !service.contains("ssh-ed25519"));
+        assert!(admin.contains("sudo: ALL=(ALL) NOPASSWD:ALL"));
+        assert!(admin.contains("ssh-ed25519 AAAA test"));
+
+        opts.admin_user = Some("zeroclaw".into());
+        assert!(generate_seed(&opts).is_err());
+    }
+
+    #[test]
+    fn seed_rejects_bad_preset_and_names() {
+        let tmp = TempDir::new().unwrap();
+        let mut opts = options(&tmp);
+        opts.hostname = "Bad Host".into();
+        assert!(generate_seed(&opts).is_err());
+
+        let opts = options(&tmp);
+        std::fs::write(&opts.preset, "provder = \"typo\"\n").unwrap();
+        assert!(generate_seed(&opts).is_err());
+    }
+
+    #[test]
+    fn find_boot_mount_matches_partitions_of_device() {
+        let mounts = "/dev/sda2 /media/pi/rootfs ext4 rw 0 0\n\
+                      /dev/sda1 /media/pi/boot\\040fs vfat rw 0 0\n\
+                      /dev/mmcblk0p1 /boot/firmware vfat rw 0 0\n\
+                      /dev/sdab1 /media/other vfat rw 0 0\n";
+        assert_eq!(
+            find_boot_mount(mounts, Path::new("/dev/sda")),
+            Some(PathBuf::from("/media/pi/boot fs"))
+        );
+        assert_eq!(
+            find_boot_mount(mounts, Path::new("/dev/mmcblk0")),
+            Some(PathBuf::from("/boot/firmware"))
+        );
+        assert_eq!(find_boot_mount(mounts, Path::new("/dev/sdc")), None);
+    }
+
+    #[test]
+    fn boot_partition_requires_markers_unless_forced() {
+        let tmp = TempDir::new().unwrap();
+        assert!(resolve_boot_partition(tmp.path(), false).is_err());
+        assert!(resolve_boot_partition(tmp.path(), true).is_ok());
+        std::fs::write(tmp.path().join("config.txt"), "").unwrap();
+        assert!(resolve_boot_partition(tmp.path(), false).is_ok());
+    }
+}
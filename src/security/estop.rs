@@ -1,11 +1,15 @@
-use crate::config::EstopConfig;
+use crate::config::{Config, EstopConfig};
 use crate::security::domain_matcher::DomainMatcher;
 use crate::security::otp::OtpValidator;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Gateway route that accepts remote estop engagement.
+pub const REMOTE_ESTOP_PATH: &str = "/api/estop";
+const REMOTE_ESTOP_TIMEOUT_SECS: u64 = 10;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EstopLevel {
@@ -15,6 +19,30 @@ pub enum EstopLevel {
     ToolFreeze(Vec<String>),
 }
 
+impl EstopLevel {
+    /// Parse a level accepted by the remote trigger. Only the instant,
+    /// argument-free levels can be engaged remotely.
+    pub fn parse_remote(name: &str) -> Result<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "kill-all" | "kill_all" => Ok(Self::KillAll),
+            "network-kill" | "network_kill" => Ok(Self::NetworkKill),
+            other => anyhow::bail!(
+                "Unsupported remote estop level '{other}'; expected kill-all or network-kill"
+            ),
+        }
+    }
+
+    /// Short human-readable description used in notices.
+    pub fn describe(&self) -> String {
+        match self {
+            Self::KillAll => "kill-all".to_string(),
+            Self::NetworkKill => "network-kill".to_string(),
+            Self::DomainBlock(domains) => format!("domain-block ({})", domains.join(", ")),
+            Self::ToolFreeze(tools) => format!("tool-freeze ({})", tools.join(", ")),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ResumeSelector {
     KillAll,
@@ -66,6 +94,7 @@ pub struct EstopManager {
     config: EstopConfig,
     state_path: PathBuf,
     state: EstopState,
+    toasts: Option<crate::notifications::Toasts>,
 }
 
 impl EstopManager {
@@ -107,6 +136,7 @@ impl EstopManager {
             config: config.clone(),
             state_path,
             state,
+            toasts: None,
         };
 
         if should_fail_closed {
@@ -116,6 +146,13 @@ impl EstopManager {
         Ok(manager)
     }
 
+    /// Announce engage and resume as dashboard toasts on `toasts`.
+    #[must_use]
+    pub fn with_toasts(mut self, toasts: crate::notifications::Toasts) -> Self {
+        self.toasts = Some(toasts);
+        self
+    }
+
     pub fn state_path(&self) -> &Path {
         &self.state_path
     }
@@ -125,6 +162,7 @@ impl EstopManager {
     }
 
     pub fn engage(&mut self, level: EstopLevel) -> Result<()> {
+        let description = level.describe();
         match level {
             EstopLevel::KillAll => {
                 self.state.kill_all = true;
@@ -149,7 +187,14 @@ impl EstopManager {
 
         self.state.updated_at = Some(now_rfc3339());
         self.state.normalize();
-        self.persist_state()
+        self.persist_state()?;
+        if let Some(toasts) = &self.toasts {
+            toasts.publish(
+                crate::notifications::ToastLevel::Error,
+                format!("Emergency stop engaged ({description})"),
+            );
+        }
+        Ok(())
     }
 
     pub fn resume(
@@ -189,7 +234,14 @@ impl EstopManager {
 
         self.state.updated_at = Some(now_rfc3339());
         self.state.normalize();
-        self.persist_state()
+        self.persist_state()?;
+        if let Some(toasts) = &self.toasts {
+            toasts.publish(
+                crate::notifications::ToastLevel::Success,
+                "Emergency stop resumed",
+            );
+        }
+        Ok(())
     }
 
     fn ensure_resume_is_authorized(
@@ -251,6 +303,150 @@ impl EstopManager {
     }
 }
 
+/// Notice sent to owner channels when estop is engaged.
+pub fn engagement_notice(level: &EstopLevel, source: &str, reason: Option<&str>) -> String {
+    let mut notice = format!(
+        "🛑 ZeroClaw estop engaged: {} (via {source})",
+        level.describe()
+    );
+    if let Some(reason) = reason.map(str::trim).filter(|reason| !reason.is_empty()) {
+        notice.push_str("\nReason: ");
+        notice.push_str(reason);
+    }
+    notice
+}
+
+/// Deliver an engagement notice to every `[[security.estop.notify]]` target.
+/// Delivery failures are logged; they never undo the engagement.
+pub async fn notify_owners(config: &Config, notice: &str) {
+    for target in &config.security.estop.notify {
+        if let Err(error) = crate::cron::scheduler::deliver_announcement(
+            config,
+            &target.channel,
+            &target.to,
+            notice,
+        )
+        .await
+        {
+            tracing::warn!(
+                channel = %target.channel,
+                "Failed to deliver estop notice: {error}"
+            );
+        }
+    }
+}
+
+/// Deliver `notice` to `channel`/`to` when both are set, otherwise to the
+/// `[[security.estop.notify]]` owner targets. Failures are logged.
+pub async fn notify_target_or_owners(
+    config: &Config,
+    channel: Option<&str>,
+    to: Option<&str>,
+    notice: &str,
+) {
+    let (Some(channel), Some(to)) = (channel, to) else {
+        notify_owners(config, notice).await;
+        return;
+    };
+    if let Err(error) =
+        crate::cron::scheduler::deliver_announcement(config, channel, to, notice).await
+    {
+        tracing::warn!(%channel, "Failed to deliver owner notice: {error}");
+    }
+}
+
+/// Engage `kill-all` from a local trigger (chat phrase or kill switch) and
+/// notify the owner channels. `source` names the trigger in the notice.
+pub async fn engage_local(
+    config: &Config,
+    source: &str,
+    toasts: &crate::notifications::Toasts,
+) -> Result<EstopState> {
+    let config_dir = config
+        .config_path
+        .parent()
+        .context("Config path must have a parent directory")?;
+    let mut manager =
+        EstopManager::load(&config.security.estop, config_dir)?.with_toasts(toasts.clone());
+    manager.engage(EstopLevel::KillAll)?;
+    let notice = engagement_notice(&EstopLevel::KillAll, source, None);
+    notify_owners(config, &notice).await;
+    Ok(manager.status())
+}
+
+/// Whether `text` is the configured estop trigger phrase.
+pub fn is_trigger_phrase(config: &EstopConfig, text: &str) -> bool {
+    config.enabled
+        && config
+            .trigger_phrase
+            .as_deref()
+            .map(str::trim)
+            .is_some_and(|phrase| !phrase.is_empty() && phrase.eq_ignore_ascii_case(text.trim()))
+}
+
+/// If `text` is the trigger phrase in `config`, engage `kill-all` and return
+/// the reply for the sender. `None` means the message is not a trigger.
+pub async fn handle_chat_trigger(
+    config: &Config,
+    text: &str,
+    source: &str,
+    toasts: &crate::notifications::Toasts,
+) -> Option<String> {
+    if !is_trigger_phrase(&config.security.estop, text) {
+        return None;
+    }
+    Some(match engage_local(config, source, toasts).await {
+        Ok(_) => {
+            "🛑 Emergency stop engaged (kill-all). Resume with `zeroclaw estop resume`.".into()
+        }
+        Err(err) => {
+            tracing::error!("Chat estop trigger failed: {err:#}");
+            format!("Failed to engage emergency stop: {err}")
+        }
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct RemoteEngageResponse {
+    state: EstopState,
+}
+
+/// Engage estop on a remote gateway via `POST /api/estop`.
+pub async fn engage_remote(
+    gateway_url: &str,
+    token: &str,
+    level: &EstopLevel,
+    reason: Option<&str>,
+) -> Result<EstopState> {
+    let url = format!("{}{REMOTE_ESTOP_PATH}", gateway_url.trim_end_matches('/'));
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(REMOTE_ESTOP_TIMEOUT_SECS))
+        .build()
+        .context("Failed to build HTTP client")?;
+    let response = client
+        .post(&url)
+        .bearer_auth(token)
+        .json(&serde_json::json!({
+            "level": level.describe(),
+            "reason": reason,
+        }))
+        .send()
+        .await
+        .with_context(|| format!("Failed to reach {url}"))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        anyhow::bail!("Remote estop rejected ({status}): {}", body.trim());
+    }
+
+    let parsed: RemoteEngageResponse = response
+        .json()
+        .await
+        .context("Remote gateway returned an unexpected estop response")?;
+    Ok(parsed.state)
+}
+
 pub fn resolve_state_file_path(config_dir: &Path, state_file: &str) -> PathBuf {
     let expanded = shellexpand::tilde(state_file).into_owned();
     let path = PathBuf::from(expanded);
@@ -310,9 +506,38 @@ mod tests {
             enabled: true,
             state_file: path.display().to_string(),
             require_otp_to_resume: false,
+            remote_trigger: false,
+            notify: Vec::new(),
+            trigger_phrase: None,
+            kill_switch_pin: None,
         }
     }
 
+    #[test]
+    fn remote_levels_are_limited_to_instant_kills() {
+        assert_eq!(
+            EstopLevel::parse_remote("kill-all").unwrap(),
+            EstopLevel::KillAll
+        );
+        assert_eq!(
+            EstopLevel::parse_remote(" Network_Kill ").unwrap(),
+            EstopLevel::NetworkKill
+        );
+        assert!(EstopLevel::parse_remote("domain-block").is_err());
+        assert!(EstopLevel::parse_remote("tool-freeze").is_err());
+    }
+
+    #[test]
+    fn engagement_notice_includes_level_source_and_reason() {
+        let notice = engagement_notice(&EstopLevel::NetworkKill, "remote gateway", Some("  "));
+        assert_eq!(
+            notice,
+            "🛑 ZeroClaw estop engaged: network-kill (via remote gateway)"
+        );
+        let notice = engagement_notice(&EstopLevel::KillAll, "cli", Some("runaway tool loop"));
+        assert!(notice.ends_with("\nReason: runaway tool loop"));
+    }
+
     #[test]
     fn estop_levels_compose_and_resume() {
         let dir = tempdir().unwrap();
@@ -419,4 +644,18 @@ mod tests {
             .unwrap();
         assert!(!manager.status().kill_all);
     }
+
+    #[test]
+    fn trigger_phrase_matches_only_when_enabled_and_set() {
+        let dir = tempdir().unwrap();
+        let mut cfg = estop_config(&dir.path().join("estop-state.json"));
+        assert!(!is_trigger_phrase(&cfg, "/estop"));
+
+        cfg.trigger_phrase = Some("/estop".into());
+        assert!(is_trigger_phrase(&cfg, "  /ESTOP \n"));
+        assert!(!is_trigger_phrase(&cfg, "/estop now"));
+
+        cfg.enabled = false;
+        assert!(!is_trigger_phrase(&cfg, "/estop"));
+    }
 }
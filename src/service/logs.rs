@@ -0,0 +1,314 @@
+//! `zeroclaw service logs`: show or follow the daemon log with level and
+//! text filters.
+//!
+//! Reads the stderr log that `service install` sets up on macOS and Windows
+//! (`<config-dir>/logs/daemon.stderr.log`) or OpenRC
+//! (`/var/log/zeroclaw/error.log`), and falls back to the systemd user
+//! journal. Lines without a level (wrapped output, panics) inherit the level
+//! of the line before them so they are filtered together.
+
+use crate::config::Config;
+use anyhow::{bail, Context, Result};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+const FOLLOW_POLL: Duration = Duration::from_millis(500);
+const OPENRC_LOG: &str = "/var/log/zeroclaw/error.log";
+
+/// Tracing levels, most severe first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub fn parse(text: &str) -> Option<Self> {
+        match text.trim().to_ascii_uppercase().as_str() {
+            "ERROR" => Some(Self::Error),
+            "WARN" | "WARNING" => Some(Self::Warn),
+            "INFO" => Some(Self::Info),
+            "DEBUG" => Some(Self::Debug),
+            "TRACE" => Some(Self::Trace),
+            _ => None,
+        }
+    }
+}
+
+/// Where the daemon's log lines come from.
+#[derive(Debug, PartialEq, Eq)]
+enum LogSource {
+    File(PathBuf),
+    /// `journalctl --user -u zeroclaw.service`.
+    Journal,
+}
+
+/// Options of `zeroclaw service logs`.
+pub struct LogOptions<'a> {
+    pub follow: bool,
+    /// Show this level and more severe ones; `None` shows everything.
+    pub level: Option<Level>,
+    /// Case-insensitive text every shown line contains.
+    pub grep: Option<&'a str>,
+    /// Matching lines shown from the end of the log before following.
+    pub lines: usize,
+    pub file: Option<&'a Path>,
+}
+
+/// Drop ANSI color sequences; the daemon logs with colors enabled.
+fn strip_ansi(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Level of a `tracing` fmt line (`<timestamp>  WARN target: message`).
+fn line_level(line: &str) -> Option<Level> {
+    line.split_whitespace().take(3).find_map(Level::parse)
+}
+
+/// Applies the level and text filters line by line.
+struct LineFilter {
+    level: Option<Level>,
+    grep: Option<String>,
+    last_level: Option<Level>,
+}
+
+impl LineFilter {
+    fn new(options: &LogOptions<'_>) -> Self {
+        Self {
+            level: options.level,
+            grep: options.grep.map(str::to_lowercase),
+            last_level: None,
+        }
+    }
+
+    /// The line to print, without color codes, or `None` when filtered out.
+    fn apply(&mut self, raw: &str) -> Option<String> {
+        let line = strip_ansi(raw.trim_end_matches(['\r', '\n']));
+        if let Some(level) = line_level(&line) {
+            self.last_level = Some(level);
+        }
+        if let Some(max) = self.level {
+            if self.last_level.is_some_and(|level| level > max) {
+                return None;
+            }
+        }
+        if let Some(grep) = &self.grep {
+            if !line.to_lowercase().contains(grep) {
+                return None;
+            }
+        }
+        Some(line)
+    }
+}
+
+fn find_source(config: &Config, file: Option<&Path>) -> Result<LogSource> {
+    if let Some(file) = file {
+        return Ok(LogSource::File(file.to_path_buf()));
+    }
+    let config_dir = config
+        .config_path
+        .parent()
+        .map_or_else(|| PathBuf::from("."), PathBuf::from);
+    let candidates = [
+        config_dir.join("logs").join("daemon.stderr.log"),
+        PathBuf::from(OPENRC_LOG),
+    ];
+    if let Some(path) = candidates.into_iter().find(|path| path.is_file()) {
+        return Ok(LogSource::File(path));
+    }
+    if cfg!(target_os = "linux") && super::linux_service_file(config).is_ok_and(|p| p.exists()) {
+        return Ok(LogSource::Journal);
+    }
+    bail!(
+        "No daemon log found. Install the service with `zeroclaw service install`, \
+         or run `zeroclaw daemon 2>> daemon.log` and pass `--file daemon.log`."
+    )
+}
+
+/// Print the last `options.lines` matching lines of `path`, then keep
+/// printing new ones when following. A truncated or rotated file is re-read
+/// from the start.
+fn show_file(path: &Path, options: &LogOptions<'_>) -> Result<()> {
+    let file = File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let mut reader = BufReader::new(file);
+    let mut filter = LineFilter::new(options);
+    let mut tail = VecDeque::with_capacity(options.lines);
+    let mut raw = Vec::new();
+    let mut position = 0u64;
+    loop {
+        raw.clear();
+        let read = reader.read_until(b'\n', &mut raw)?;
+        if read == 0 {
+            break;
+        }
+        position += read as u64;
+        if let Some(line) = filter.apply(&String::from_utf8_lossy(&raw)) {
+            if tail.len() == options.lines {
+                tail.pop_front();
+            }
+            if options.lines > 0 {
+                tail.push_back(line);
+            }
+        }
+    }
+    for line in tail {
+        println!("{line}");
+    }
+    if !options.follow {
+        return Ok(());
+    }
+
+    let mut partial = Vec::new();
+    loop {
+        std::thread::sleep(FOLLOW_POLL);
+        let len = std::fs::metadata(path).map_or(0, |meta| meta.len());
+        if len < position {
+            let file = File::open(path)?;
+            reader = BufReader::new(file);
+            position = 0;
+            partial.clear();
+        }
+        reader.seek(SeekFrom::Start(position))?;
+        position += reader.read_to_end(&mut partial)? as u64;
+        while let Some(end) = partial.iter().position(|byte| *byte == b'\n') {
+            let raw: Vec<u8> = partial.drain(..=end).collect();
+            if let Some(line) = filter.apply(&String::from_utf8_lossy(&raw)) {
+                println!("{line}");
+            }
+        }
+    }
+}
+
+fn show_journal(options: &LogOptions<'_>) -> Result<()> {
+    // The journal cannot filter by our level or text, so a filtered
+    // one-shot read scans the whole unit log and keeps the last matches.
+    // When following, the backlog is the last `lines` entries, filtered.
+    let filtered = options.level.is_some() || options.grep.is_some();
+    let backlog = if filtered && !options.follow {
+        "all".to_string()
+    } else {
+        options.lines.to_string()
+    };
+    let mut command = Command::new("journalctl");
+    command.args([
+        "--user",
+        "-u",
+        "zeroclaw.service",
+        "-o",
+        "cat",
+        "--no-pager",
+    ]);
+    command.args(["-n", &backlog]);
+    if options.follow {
+        command.arg("-f");
+    }
+    let mut child = command
+        .stdout(Stdio::piped())
+        .spawn()
+        .context("Failed to run journalctl")?;
+    let stdout = child.stdout.take().context("journalctl has no stdout")?;
+
+    let mut filter = LineFilter::new(options);
+    let mut tail = VecDeque::with_capacity(options.lines);
+    for raw in BufReader::new(stdout).lines() {
+        let Some(line) = filter.apply(&raw?) else {
+            continue;
+        };
+        if options.follow {
+            println!("{line}");
+            continue;
+        }
+        if tail.len() == options.lines {
+            tail.pop_front();
+        }
+        if options.lines > 0 {
+            tail.push_back(line);
+        }
+    }
+    for line in tail {
+        println!("{line}");
+    }
+    child.wait()?;
+    Ok(())
+}
+
+/// Show (or follow) the daemon log.
+pub fn show(config: &Config, options: &LogOptions<'_>) -> Result<()> {
+    match find_source(config, options.file)? {
+        LogSource::File(path) => show_file(&path, options),
+        LogSource::Journal => show_journal(options),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(level: Option<Level>, grep: Option<&str>) -> LogOptions<'_> {
+        LogOptions {
+            follow: false,
+            level,
+            grep,
+            lines: 50,
+            file: None,
+        }
+    }
+
+    #[test]
+    fn filters_by_level_and_text_with_continuation_lines() {
+        let mut filter = LineFilter::new(&options(Some(Level::Warn), Some("telegram")));
+        let lines = [
+            "2026-10-16T08:00:00Z \u{1b}[32m INFO\u{1b}[0m zeroclaw::daemon: telegram started",
+            "2026-10-16T08:00:01Z \u{1b}[33m WARN\u{1b}[0m zeroclaw::channels: telegram poll failed",
+            "    caused by: telegram 401",
+            "2026-10-16T08:00:02Z ERROR zeroclaw::channels: discord gateway closed",
+        ];
+        let shown: Vec<String> = lines.iter().filter_map(|l| filter.apply(l)).collect();
+        assert_eq!(
+            shown,
+            [
+                "2026-10-16T08:00:01Z  WARN zeroclaw::channels: telegram poll failed",
+                "    caused by: telegram 401",
+            ]
+        );
+        assert_eq!(Level::parse("warning"), Some(Level::Warn));
+        assert_eq!(line_level("plain text line"), None);
+    }
+
+    #[test]
+    fn shows_the_tail_of_a_log_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("daemon.stderr.log");
+        std::fs::write(&path, "a INFO one\nb ERROR two\nc INFO three\n").unwrap();
+        let config = Config::default();
+        assert_eq!(
+            find_source(&config, Some(&path)).unwrap(),
+            LogSource::File(path.clone())
+        );
+        let mut opts = options(Some(Level::Error), None);
+        opts.lines = 1;
+        show_file(&path, &opts).unwrap();
+    }
+}
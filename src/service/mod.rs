@@ -5,8 +5,12 @@ use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::str::FromStr;
 
+pub mod logs;
+
 const SERVICE_LABEL: &str = "com.zeroclaw.daemon";
 const WINDOWS_TASK_NAME: &str = "ZeroClaw Daemon";
+/// `schtasks /TR` rejects task commands longer than this.
+const WINDOWS_TASK_COMMAND_MAX_CHARS: usize = 261;
 
 /// Supported init systems for service management
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -101,6 +105,22 @@ pub fn handle_command(
         crate::ServiceCommands::Restart => restart(config, init_system),
         crate::ServiceCommands::Status => status(config, init_system),
         crate::ServiceCommands::Uninstall => uninstall(config, init_system),
+        crate::ServiceCommands::Logs {
+            follow,
+            level,
+            grep,
+            lines,
+            file,
+        } => logs::show(
+            config,
+            &logs::LogOptions {
+                follow: *follow,
+                level: level.as_deref().and_then(logs::Level::parse),
+                grep: grep.as_deref(),
+                lines: *lines,
+                file: file.as_deref(),
+            },
+        ),
     }
 }
 
@@ -113,7 +133,7 @@ fn install(config: &Config, init_system: InitSystem) -> Result<()> {
     } else if cfg!(target_os = "windows") {
         install_windows(config)
     } else {
-        anyhow::bail!("Service management is supported on macOS and Linux only");
+        anyhow::bail!("Service management is supported on macOS, Linux and Windows only");
     }
 }
 
@@ -134,7 +154,7 @@ fn start(config: &Config, init_system: InitSystem) -> Result<()> {
         Ok(())
     } else {
         let _ = config;
-        anyhow::bail!("Service management is supported on macOS and Linux only")
+        anyhow::bail!("Service management is supported on macOS, Linux and Windows only")
     }
 }
 
@@ -176,7 +196,7 @@ fn stop(config: &Config, init_system: InitSystem) -> Result<()> {
         Ok(())
     } else {
         let _ = config;
-        anyhow::bail!("Service management is supported on macOS and Linux only")
+        anyhow::bail!("Service management is supported on macOS, Linux and Windows only")
     }
 }
 
@@ -215,7 +235,7 @@ fn restart(config: &Config, init_system: InitSystem) -> Result<()> {
         return Ok(());
     }
 
-    anyhow::bail!("Service management is supported on macOS and Linux only")
+    anyhow::bail!("Service management is supported on macOS, Linux and Windows only")
 }
 
 fn restart_linux(init_system: InitSystem) -> Result<()> {
@@ -279,7 +299,7 @@ fn status(config: &Config, init_system: InitSystem) -> Result<()> {
         return Ok(());
     }
 
-    anyhow::bail!("Service management is supported on macOS and Linux only")
+    anyhow::bail!("Service management is supported on macOS, Linux and Windows only")
 }
 
 fn status_linux(config: &Config, init_system: InitSystem) -> Result<()> {
@@ -340,7 +360,7 @@ fn uninstall(config: &Config, init_system: InitSystem) -> Result<()> {
         return Ok(());
     }
 
-    anyhow::bail!("Service management is supported on macOS and Linux only")
+    anyhow::bail!("Service management is supported on macOS, Linux and Windows only")
 }
 
 fn uninstall_linux(config: &Config, init_system: InitSystem) -> Result<()> {
@@ -441,8 +461,10 @@ fn install_linux_systemd(config: &Config) -> Result<()> {
     }
 
     let exe = std::env::current_exe().context("Failed to resolve current executable")?;
+    // Leave room for the daemon's drain plus notices and the memory flush.
+    let stop_timeout = config.shutdown.drain_timeout_secs.saturating_add(30);
     let unit = format!(
-        "[Unit]\nDescription=ZeroClaw daemon\nAfter=network.target\n\n[Service]\nType=simple\nExecStart={} daemon\nRestart=always\nRestartSec=3\n\n[Install]\nWantedBy=default.target\n",
+        "[Unit]\nDescription=ZeroClaw daemon\nAfter=network.target\n\n[Service]\nType=simple\nExecStart={} daemon\nRestart=always\nRestartSec=3\nKillSignal=SIGTERM\nTimeoutStopSec={stop_timeout}\n\n[Install]\nWantedBy=default.target\n",
         exe.display()
     );
 
@@ -991,12 +1013,22 @@ fn install_windows(config: &Config) -> Result<()> {
 
     let wrapper_content = format!(
         "@echo off\r\n\"{}\" daemon >>\"{}\" 2>>\"{}\"",
-        exe.display(),
-        stdout_log.display(),
-        stderr_log.display()
+        windows_cmd_path(&exe),
+        windows_cmd_path(&stdout_log),
+        windows_cmd_path(&stderr_log)
     );
     fs::write(&wrapper, &wrapper_content)?;
 
+    let task_command = format!("\"{}\"", windows_cmd_path(&wrapper));
+    if task_command.chars().count() > WINDOWS_TASK_COMMAND_MAX_CHARS {
+        bail!(
+            "Scheduled task command is {} characters; schtasks accepts at most {}. \
+             Set ZEROCLAW_CONFIG_DIR to a shorter path and reinstall.",
+            task_command.chars().count(),
+            WINDOWS_TASK_COMMAND_MAX_CHARS
+        );
+    }
+
     let task_name = windows_task_name();
 
     // Remove any existing task first (ignore errors if it doesn't exist)
@@ -1011,7 +1043,7 @@ fn install_windows(config: &Config) -> Result<()> {
         "/SC",
         "ONLOGON",
         "/TR",
-        &format!("\"{}\"", wrapper.display()),
+        &task_command,
         "/RL",
         "HIGHEST",
         "/F",
@@ -1024,6 +1056,20 @@ fn install_windows(config: &Config) -> Result<()> {
     Ok(())
 }
 
+/// Render a path for cmd.exe and schtasks. `current_exe` and canonicalized
+/// paths on Windows can carry the `\\?\` verbatim prefix, which cmd.exe
+/// cannot execute.
+fn windows_cmd_path(path: &Path) -> String {
+    let raw = path.display().to_string();
+    if let Some(rest) = raw.strip_prefix(r"\\?\UNC\") {
+        format!(r"\\{rest}")
+    } else if let Some(rest) = raw.strip_prefix(r"\\?\") {
+        rest.to_string()
+    } else {
+        raw
+    }
+}
+
 fn macos_service_file() -> Result<PathBuf> {
     let home = directories::UserDirs::new()
         .map(|u| u.home_dir().to_path_buf())
@@ -1119,6 +1165,22 @@ mod tests {
         assert_eq!(windows_task_name(), "ZeroClaw Daemon");
     }
 
+    #[test]
+    fn windows_cmd_path_strips_verbatim_prefixes() {
+        assert_eq!(
+            windows_cmd_path(Path::new(r"\\?\C:\Program Files\zeroclaw.exe")),
+            r"C:\Program Files\zeroclaw.exe"
+        );
+        assert_eq!(
+            windows_cmd_path(Path::new(r"\\?\UNC\server\share\zeroclaw.exe")),
+            r"\\server\share\zeroclaw.exe"
+        );
+        assert_eq!(
+            windows_cmd_path(Path::new("/usr/local/bin/zeroclaw")),
+            "/usr/local/bin/zeroclaw"
+        );
+    }
+
     #[cfg(target_os = "windows")]
     #[test]
     fn run_capture_reads_stdout_windows() {
@@ -433,9 +433,24 @@ impl BrowserTool {
             anyhow::bail!("Host '{host}' not in browser.allowed_domains");
         }
 
+        super::web_fetch::validate_resolved_host_is_public(&host)?;
+
         Ok(())
     }
 
+    /// Re-check the page the browser landed on after `open`, so a redirect
+    /// cannot leave it on a host `validate_url` would have refused.
+    fn validate_landing_url(&self, landed: Option<&str>) -> anyhow::Result<()> {
+        let Some(url) = landed.map(str::trim).filter(|url| !url.is_empty()) else {
+            return Ok(());
+        };
+        if url == "about:blank" {
+            return Ok(());
+        }
+        self.validate_url(url)
+            .with_context(|| format!("Blocked redirect target: {url}"))
+    }
+
     /// Execute an agent-browser command
     async fn run_command(&self, args: &[&str]) -> anyhow::Result<AgentBrowserResponse> {
         let mut cmd = Command::new("agent-browser");
@@ -494,6 +509,18 @@ impl BrowserTool {
             BrowserAction::Open { url } => {
                 self.validate_url(&url)?;
                 let resp = self.run_command(&["open", &url]).await?;
+                if resp.success {
+                    let landed = self.run_command(&["get", "url"]).await?;
+                    let landed_url = landed.data.as_ref().and_then(|data| {
+                        data.get("url")
+                            .and_then(Value::as_str)
+                            .or_else(|| data.as_str())
+                    });
+                    if let Err(error) = self.validate_landing_url(landed_url) {
+                        let _ = self.run_command(&["open", "about:blank"]).await;
+                        return Err(error);
+                    }
+                }
                 self.to_result(resp)
             }
 
@@ -632,6 +659,7 @@ impl BrowserTool {
         #[cfg(feature = "browser-native")]
         {
             let mut state = self.native_state.lock().await;
+            let is_open = matches!(action, BrowserAction::Open { .. });
 
             let first_attempt = state
                 .execute_action(
@@ -662,6 +690,24 @@ impl BrowserTool {
                 }
             };
 
+            if is_open {
+                if let Err(error) =
+                    self.validate_landing_url(output.get("url").and_then(Value::as_str))
+                {
+                    let _ = state
+                        .execute_action(
+                            BrowserAction::Open {
+                                url: "about:blank".into(),
+                            },
+                            self.native_headless,
+                            &self.native_webdriver_url,
+                            self.native_chrome_path.as_deref(),
+                        )
+                        .await;
+                    return Err(error);
+                }
+            }
+
             Ok(ToolResult {
                 success: true,
                 output: serde_json::to_string_pretty(&output).unwrap_or_default(),
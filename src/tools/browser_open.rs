@@ -49,6 +49,8 @@ impl BrowserOpenTool {
             anyhow::bail!("Host '{host}' is not in browser.allowed_domains");
         }
 
+        super::web_fetch::validate_resolved_host_is_public(&host)?;
+
         Ok(url.to_string())
     }
 }
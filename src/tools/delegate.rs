@@ -33,6 +33,8 @@ pub struct DelegateTool {
     parent_tools: Arc<Vec<Arc<dyn Tool>>>,
     /// Inherited multimodal handling config for sub-agent loops.
     multimodal_config: crate::config::MultimodalConfig,
+    /// Observer for sub-agent loops (usage recording); no-op by default.
+    observer: Arc<dyn Observer>,
 }
 
 impl DelegateTool {
@@ -63,6 +65,7 @@ impl DelegateTool {
             depth: 0,
             parent_tools: Arc::new(Vec::new()),
             multimodal_config: crate::config::MultimodalConfig::default(),
+            observer: Arc::new(NoopObserver),
         }
     }
 
@@ -99,6 +102,7 @@ impl DelegateTool {
             depth,
             parent_tools: Arc::new(Vec::new()),
             multimodal_config: crate::config::MultimodalConfig::default(),
+            observer: Arc::new(NoopObserver),
         }
     }
 
@@ -113,6 +117,12 @@ impl DelegateTool {
         self.multimodal_config = config;
         self
     }
+
+    /// Attach the observer that sub-agent tool loops report to.
+    pub fn with_observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = observer;
+        self
+    }
 }
 
 #[async_trait]
@@ -390,15 +400,13 @@ impl DelegateTool {
         }
         history.push(ChatMessage::user(full_prompt.to_string()));
 
-        let noop_observer = NoopObserver;
-
         let result = tokio::time::timeout(
             Duration::from_secs(DELEGATE_AGENTIC_TIMEOUT_SECS),
             run_tool_call_loop(
                 provider,
                 &mut history,
                 &sub_tools,
-                &noop_observer,
+                self.observer.as_ref(),
                 &agent_config.provider,
                 &agent_config.model,
                 temperature,
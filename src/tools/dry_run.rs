@@ -0,0 +1,140 @@
+//! Dry-run mode: tools describe what they would do instead of executing.
+//!
+//! Enabled globally with `[agent] dry_run = true`, per CLI session with
+//! `zeroclaw agent --dry-run`, or toggled live with `/dry-run` in interactive
+//! mode. Each wrapped tool consults a shared [`DryRunSwitch`] on every call.
+
+use super::traits::{Tool, ToolResult};
+use async_trait::async_trait;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Shared on/off switch for a tool registry.
+#[derive(Debug, Clone, Default)]
+pub struct DryRunSwitch(Arc<AtomicBool>);
+
+impl DryRunSwitch {
+    pub fn new(enabled: bool) -> Self {
+        Self(Arc::new(AtomicBool::new(enabled)))
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub fn set(&self, enabled: bool) {
+        self.0.store(enabled, Ordering::Relaxed);
+    }
+}
+
+struct DryRunTool {
+    inner: Box<dyn Tool>,
+    switch: DryRunSwitch,
+}
+
+#[async_trait]
+impl Tool for DryRunTool {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn description(&self) -> &str {
+        self.inner.description()
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        self.inner.parameters_schema()
+    }
+
+    fn dry_run_description(&self, args: &serde_json::Value) -> String {
+        self.inner.dry_run_description(args)
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        if !self.switch.is_enabled() {
+            return self.inner.execute(args).await;
+        }
+
+        let description = self.inner.dry_run_description(&args);
+        tracing::info!(tool = self.inner.name(), "dry-run: {description}");
+        Ok(ToolResult {
+            success: true,
+            output: format!(
+                "[dry-run] Not executed. This call {description}. \
+                 Dry-run mode is on, so no tool has side effects."
+            ),
+            error: None,
+        })
+    }
+}
+
+/// Wrap every tool in `tools` so it honours `switch`.
+pub fn wrap_registry(tools: Vec<Box<dyn Tool>>, switch: &DryRunSwitch) -> Vec<Box<dyn Tool>> {
+    tools
+        .into_iter()
+        .map(|inner| {
+            Box::new(DryRunTool {
+                inner,
+                switch: switch.clone(),
+            }) as Box<dyn Tool>
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::SecurityPolicy;
+    use crate::tools::ShellTool;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn shell_is_described_not_run_while_switch_is_on() {
+        let switch = DryRunSwitch::new(true);
+        let security = Arc::new(SecurityPolicy::default());
+        let runtime = Arc::new(crate::runtime::NativeRuntime::new());
+        let tools = wrap_registry(vec![Box::new(ShellTool::new(security, runtime))], &switch);
+
+        let result = tools[0]
+            .execute(json!({"command": "rm -rf build"}))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.starts_with("[dry-run] Not executed."));
+        assert!(result.output.contains("`rm -rf build`"));
+    }
+
+    #[tokio::test]
+    async fn default_description_names_tool_and_arguments() {
+        struct Noop;
+
+        #[async_trait]
+        impl Tool for Noop {
+            fn name(&self) -> &str {
+                "noop"
+            }
+            fn description(&self) -> &str {
+                "does nothing"
+            }
+            fn parameters_schema(&self) -> serde_json::Value {
+                json!({"type": "object"})
+            }
+            async fn execute(&self, _args: serde_json::Value) -> anyhow::Result<ToolResult> {
+                Ok(ToolResult {
+                    success: true,
+                    output: "ran".into(),
+                    error: None,
+                })
+            }
+        }
+
+        let switch = DryRunSwitch::new(true);
+        let tools = wrap_registry(vec![Box::new(Noop)], &switch);
+        let output = tools[0].execute(json!({"x": 1})).await.unwrap().output;
+        assert!(output.contains(r#"would call `noop` with {"x":1}"#));
+
+        switch.set(false);
+        let output = tools[0].execute(json!({})).await.unwrap().output;
+        assert_eq!(output, "ran");
+    }
+}
@@ -51,6 +51,22 @@ impl Tool for FileEditTool {
         })
     }
 
+    fn dry_run_description(&self, args: &serde_json::Value) -> String {
+        let path = args
+            .get("path")
+            .and_then(|v| v.as_str())
+            .unwrap_or("<missing path>");
+        let old_len = args
+            .get("old_string")
+            .and_then(|v| v.as_str())
+            .map_or(0, str::len);
+        let new_len = args
+            .get("new_string")
+            .and_then(|v| v.as_str())
+            .map_or(0, str::len);
+        format!("would replace {old_len} bytes with {new_len} bytes in `{path}`")
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         // ── 1. Extract parameters ──────────────────────────────────
         let path = args
@@ -42,6 +42,18 @@ impl Tool for FileWriteTool {
         })
     }
 
+    fn dry_run_description(&self, args: &serde_json::Value) -> String {
+        let path = args
+            .get("path")
+            .and_then(|v| v.as_str())
+            .unwrap_or("<missing path>");
+        let bytes = args
+            .get("content")
+            .and_then(|v| v.as_str())
+            .map_or(0, str::len);
+        format!("would write {bytes} bytes to `{path}`")
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let path = args
             .get("path")
@@ -1,7 +1,9 @@
 use super::traits::{Tool, ToolResult};
 use crate::security::SecurityPolicy;
 use async_trait::async_trait;
+use futures_util::StreamExt;
 use serde_json::json;
+use std::fmt::Write as _;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -12,6 +14,7 @@ pub struct HttpRequestTool {
     allowed_domains: Vec<String>,
     max_response_size: usize,
     timeout_secs: u64,
+    allowed_content_types: Vec<String>,
 }
 
 impl HttpRequestTool {
@@ -26,9 +29,39 @@ impl HttpRequestTool {
             allowed_domains: normalize_allowed_domains(allowed_domains),
             max_response_size,
             timeout_secs,
+            allowed_content_types: Vec::new(),
         }
     }
 
+    /// Only return bodies whose `Content-Type` matches one of `patterns`
+    /// (`text/*`, `application/*+json`, exact types, or `*`). Empty allows all.
+    pub fn with_allowed_content_types(mut self, patterns: Vec<String>) -> Self {
+        self.allowed_content_types = patterns
+            .into_iter()
+            .map(|pattern| pattern.trim().to_ascii_lowercase())
+            .filter(|pattern| !pattern.is_empty())
+            .collect();
+        self
+    }
+
+    fn content_type_allowed(&self, content_type: Option<&str>) -> bool {
+        if self.allowed_content_types.is_empty() {
+            return true;
+        }
+        let Some(content_type) = content_type else {
+            return self.allowed_content_types.iter().any(|p| p == "*");
+        };
+        let essence = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        self.allowed_content_types
+            .iter()
+            .any(|pattern| content_type_matches(pattern, &essence))
+    }
+
     fn validate_url(&self, raw_url: &str) -> anyhow::Result<String> {
         let url = raw_url.trim();
 
@@ -60,6 +93,8 @@ impl HttpRequestTool {
             anyhow::bail!("Host '{host}' is not in http_request.allowed_domains");
         }
 
+        super::web_fetch::validate_resolved_host_is_public(&host)?;
+
         Ok(url.to_string())
     }
 
@@ -124,7 +159,7 @@ impl HttpRequestTool {
             .timeout(Duration::from_secs(timeout_secs))
             .connect_timeout(Duration::from_secs(10))
             .redirect(reqwest::redirect::Policy::none());
-        let builder = crate::config::apply_runtime_proxy_to_builder(builder, "tool.http_request");
+        let builder = crate::config::apply_runtime_proxy_to_builder(builder, "tool.http_request")?;
         let client = builder.build()?;
 
         let mut request = client.request(method, url);
@@ -140,6 +175,26 @@ impl HttpRequestTool {
         Ok(request.send().await?)
     }
 
+    /// Read at most `max_response_size` bytes (plus one to detect overflow)
+    /// so oversized bodies are never fully buffered.
+    async fn read_body_limited(&self, response: reqwest::Response) -> anyhow::Result<String> {
+        if self.max_response_size == 0 {
+            return Ok(response.text().await?);
+        }
+        let hard_cap = self.max_response_size.saturating_add(1);
+        let mut bytes = Vec::new();
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            let remaining = hard_cap - bytes.len();
+            bytes.extend_from_slice(&chunk[..chunk.len().min(remaining)]);
+            if bytes.len() >= hard_cap {
+                break;
+            }
+        }
+        Ok(self.truncate_response(&String::from_utf8_lossy(&bytes)))
+    }
+
     fn truncate_response(&self, text: &str) -> String {
         // 0 means unlimited — no truncation.
         if self.max_response_size == 0 {
@@ -196,6 +251,28 @@ impl Tool for HttpRequestTool {
         })
     }
 
+    fn dry_run_description(&self, args: &serde_json::Value) -> String {
+        let url = args
+            .get("url")
+            .and_then(|v| v.as_str())
+            .unwrap_or("<missing url>");
+        let method = args.get("method").and_then(|v| v.as_str()).unwrap_or("GET");
+        // Header values may carry credentials; only name them.
+        let headers: Vec<&str> = args
+            .get("headers")
+            .and_then(|v| v.as_object())
+            .map(|map| map.keys().map(String::as_str).collect())
+            .unwrap_or_default();
+        let mut description = format!("would send HTTP {} {url}", method.to_ascii_uppercase());
+        if !headers.is_empty() {
+            let _ = write!(description, " with headers [{}]", headers.join(", "));
+        }
+        if let Some(body) = args.get("body").and_then(|v| v.as_str()) {
+            let _ = write!(description, " and a {}-byte body", body.len());
+        }
+        description
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let url = args
             .get("url")
@@ -254,25 +331,42 @@ impl Tool for HttpRequestTool {
                 let status = response.status();
                 let status_code = status.as_u16();
 
-                // Get response headers (redact sensitive ones)
-                let response_headers = response.headers().iter();
-                let headers_text = response_headers
-                    .map(|(k, _)| {
-                        let is_sensitive = k.as_str().to_lowercase().contains("set-cookie");
-                        if is_sensitive {
-                            format!("{}: ***REDACTED***", k.as_str())
-                        } else {
-                            format!("{}: {:?}", k.as_str(), k.as_str())
+                // Only allowlisted headers reach the model.
+                let headers_text = format_response_headers(response.headers());
+                let content_type = response
+                    .headers()
+                    .get(reqwest::header::CONTENT_TYPE)
+                    .and_then(|v| v.to_str().ok())
+                    .map(str::to_string);
+                let declared_len = response.content_length();
+
+                let mut withheld = None;
+                let response_text =
+                    if status == reqwest::StatusCode::NO_CONTENT || declared_len == Some(0) {
+                        String::new()
+                    } else if !self.content_type_allowed(content_type.as_deref()) {
+                        let reason = format!(
+                        "Response content type '{}' is not in http_request.allowed_content_types",
+                        content_type.as_deref().unwrap_or("(none)")
+                    );
+                        withheld = Some(reason.clone());
+                        format!("[Body withheld: {reason}]")
+                    } else if self.max_response_size > 0
+                        && declared_len.is_some_and(|len| len > self.max_response_size as u64)
+                    {
+                        let reason = format!(
+                            "Response of {} bytes exceeds http_request.max_response_size ({})",
+                            declared_len.unwrap_or_default(),
+                            self.max_response_size
+                        );
+                        withheld = Some(reason.clone());
+                        format!("[Body withheld: {reason}]")
+                    } else {
+                        match self.read_body_limited(response).await {
+                            Ok(text) => text,
+                            Err(e) => format!("[Failed to read response body: {e}]"),
                         }
-                    })
-                    .collect::<Vec<_>>()
-                    .join(", ");
-
-                // Get response body with size limit
-                let response_text = match response.text().await {
-                    Ok(text) => self.truncate_response(&text),
-                    Err(e) => format!("[Failed to read response body: {e}]"),
-                };
+                    };
 
                 let output = format!(
                     "Status: {} {}\nResponse Headers: {}\n\nResponse Body:\n{}",
@@ -283,12 +377,12 @@ impl Tool for HttpRequestTool {
                 );
 
                 Ok(ToolResult {
-                    success: status.is_success(),
+                    success: status.is_success() && withheld.is_none(),
                     output,
                     error: if status.is_client_error() || status.is_server_error() {
                         Some(format!("HTTP {}", status_code))
                     } else {
-                        None
+                        withheld
                     },
                 })
             }
@@ -301,6 +395,73 @@ impl Tool for HttpRequestTool {
     }
 }
 
+/// Response headers shown to the model. Everything else (cookies, auth and
+/// vendor headers that may carry tokens) is withheld.
+const SHOWN_RESPONSE_HEADERS: &[&str] = &[
+    "cache-control",
+    "content-disposition",
+    "content-encoding",
+    "content-language",
+    "content-length",
+    "content-type",
+    "date",
+    "etag",
+    "expires",
+    "last-modified",
+    "location",
+    "retry-after",
+    "vary",
+];
+
+fn is_shown_response_header(name: &str) -> bool {
+    SHOWN_RESPONSE_HEADERS
+        .iter()
+        .any(|shown| shown.eq_ignore_ascii_case(name))
+}
+
+fn format_response_headers(headers: &reqwest::header::HeaderMap) -> String {
+    let mut stripped = 0;
+    let mut shown = Vec::new();
+    for (name, value) in headers {
+        if !is_shown_response_header(name.as_str()) {
+            stripped += 1;
+            continue;
+        }
+        shown.push(format!(
+            "{}: {}",
+            name.as_str(),
+            value.to_str().unwrap_or("<non-ascii>")
+        ));
+    }
+    let mut text = shown.join(", ");
+    if stripped > 0 {
+        if !text.is_empty() {
+            text.push_str(", ");
+        }
+        let _ = write!(text, "[{stripped} other header(s) withheld]");
+    }
+    text
+}
+
+fn content_type_matches(pattern: &str, essence: &str) -> bool {
+    if pattern == "*" || pattern == essence {
+        return true;
+    }
+    let (Some((pattern_type, pattern_sub)), Some((essence_type, essence_sub))) =
+        (pattern.split_once('/'), essence.split_once('/'))
+    else {
+        return false;
+    };
+    if pattern_type != essence_type {
+        return false;
+    }
+    match pattern_sub.strip_prefix('*') {
+        Some("") => true,
+        Some(suffix) => essence_sub.ends_with(suffix),
+        None => false,
+    }
+}
+
 // Helper functions similar to browser_open.rs
 
 fn normalize_allowed_domains(domains: Vec<String>) -> Vec<String> {
@@ -757,6 +918,43 @@ mod tests {
         assert!(truncated.contains("[Response truncated"));
     }
 
+    #[test]
+    fn content_type_allowlist_supports_wildcards_and_suffixes() {
+        let tool = test_tool(vec!["example.com"]).with_allowed_content_types(vec![
+            "text/*".into(),
+            "application/json".into(),
+            "application/*+json".into(),
+        ]);
+        assert!(tool.content_type_allowed(Some("text/html; charset=utf-8")));
+        assert!(tool.content_type_allowed(Some("Application/JSON")));
+        assert!(tool.content_type_allowed(Some("application/problem+json")));
+        assert!(!tool.content_type_allowed(Some("application/octet-stream")));
+        assert!(!tool.content_type_allowed(Some("image/png")));
+        assert!(!tool.content_type_allowed(None));
+
+        let open = test_tool(vec!["example.com"]);
+        assert!(open.content_type_allowed(Some("image/png")));
+        assert!(open.content_type_allowed(None));
+    }
+
+    #[test]
+    fn only_allowlisted_response_headers_are_shown() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("content-type", "application/json".parse().unwrap());
+        headers.insert("Location", "https://example.com/next".parse().unwrap());
+        headers.insert("set-cookie", "sid=abc123; HttpOnly".parse().unwrap());
+        headers.insert("x-auth-token", "tok_live_secret".parse().unwrap());
+        headers.insert("x-amz-security-credential", "AKIA_leaked".parse().unwrap());
+
+        let text = format_response_headers(&headers);
+        assert!(text.contains("content-type: application/json"));
+        assert!(text.contains("location: https://example.com/next"));
+        assert!(text.contains("[3 other header(s) withheld]"));
+        assert!(!text.contains("abc123"));
+        assert!(!text.contains("tok_live_secret"));
+        assert!(!text.contains("AKIA_leaked"));
+    }
+
     #[test]
     fn parse_headers_preserves_original_values() {
         let tool = test_tool(vec!["example.com"]);
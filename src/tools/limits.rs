@@ -0,0 +1,399 @@
+//! Per-tool execution budgets and cooldowns (`[agent.tool_limits.<tool>]`).
+//!
+//! Counts live in the wrapper and last as long as the tool registry. Hourly
+//! and cooldown limits guard the tool itself (say, one firmware flash per ten
+//! minutes on a shared board), so they count every call regardless of who
+//! made it. Session budgets are per conversation: the channel runtime runs
+//! each message inside [`scope_conversation`], so every sender (or sender and
+//! thread) has its own; outside such a scope the whole registry, i.e. one CLI
+//! session, shares one. At most [`MAX_TRACKED_SESSIONS`] conversations are
+//! tracked per tool; past that the least recently active one is forgotten.
+//! Per-turn counts are scoped to a single agent turn via [`scope_turn`].
+
+use super::traits::{Tool, ToolResult};
+use crate::config::ToolLimitConfig;
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+const HOUR: Duration = Duration::from_secs(60 * 60);
+
+/// Conversations whose session counts a limited tool keeps at once.
+const MAX_TRACKED_SESSIONS: usize = 1024;
+
+tokio::task_local! {
+    static TURN_CALLS: Mutex<HashMap<String, u32>>;
+    static CONVERSATION: String;
+}
+
+/// Run `future` on behalf of `conversation`: session limits count calls made
+/// inside it separately from other conversations.
+pub async fn scope_conversation<F: Future>(conversation: String, future: F) -> F::Output {
+    CONVERSATION.scope(conversation, future).await
+}
+
+/// Run `future` as one agent turn: per-turn limits count calls made inside it.
+pub async fn scope_turn<F: Future>(future: F) -> F::Output {
+    TURN_CALLS.scope(Mutex::new(HashMap::new()), future).await
+}
+
+#[derive(Debug, Default)]
+struct Usage {
+    recent: VecDeque<Instant>,
+    last_call: Option<Instant>,
+}
+
+impl Usage {
+    fn check(
+        &mut self,
+        tool: &str,
+        limit: &ToolLimitConfig,
+        turn_calls: u32,
+        session_calls: u32,
+        now: Instant,
+    ) -> Result<(), String> {
+        while self
+            .recent
+            .front()
+            .is_some_and(|at| now.duration_since(*at) >= HOUR)
+        {
+            self.recent.pop_front();
+        }
+
+        if let Some(max) = limit.max_per_turn.filter(|max| turn_calls >= *max) {
+            return Err(format!(
+                "Tool '{tool}' refused: limit of {max} call(s) per turn reached. \
+                 Continue without it or ask the user to follow up in a new message."
+            ));
+        }
+        if let Some(max) = limit.max_per_session.filter(|max| session_calls >= *max) {
+            return Err(format!(
+                "Tool '{tool}' refused: limit of {max} call(s) per session reached."
+            ));
+        }
+        if let Some(max) = limit
+            .max_per_hour
+            .filter(|max| self.recent.len() >= *max as usize)
+        {
+            let retry = self.recent.front().map_or(HOUR, |oldest| {
+                HOUR.saturating_sub(now.duration_since(*oldest))
+            });
+            return Err(format!(
+                "Tool '{tool}' refused: limit of {max} call(s) per hour reached; \
+                 next call allowed in {}s.",
+                retry.as_secs().max(1)
+            ));
+        }
+        if let (Some(cooldown), Some(last)) = (limit.cooldown_secs, self.last_call) {
+            let cooldown = Duration::from_secs(cooldown);
+            let elapsed = now.duration_since(last);
+            if elapsed < cooldown {
+                return Err(format!(
+                    "Tool '{tool}' refused: cooling down for another {}s \
+                     (minimum {}s between calls).",
+                    cooldown.saturating_sub(elapsed).as_secs().max(1),
+                    cooldown.as_secs()
+                ));
+            }
+        }
+
+        self.recent.push_back(now);
+        self.last_call = Some(now);
+        Ok(())
+    }
+}
+
+/// Session call counts keyed by conversation, bounded to `capacity` entries
+/// by dropping the least recently active conversation.
+#[derive(Debug)]
+struct SessionCounts {
+    capacity: usize,
+    entries: HashMap<String, (u32, Instant)>,
+}
+
+impl SessionCounts {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Call count for `conversation`, marking it active at `now`.
+    fn calls(&mut self, conversation: String, now: Instant) -> &mut u32 {
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&conversation) {
+            let idlest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_active))| *last_active)
+                .map(|(key, _)| key.clone());
+            if let Some(idlest) = idlest {
+                self.entries.remove(&idlest);
+            }
+        }
+        let (calls, last_active) = self.entries.entry(conversation).or_insert((0, now));
+        *last_active = now;
+        calls
+    }
+}
+
+struct LimitedTool {
+    inner: Box<dyn Tool>,
+    limit: ToolLimitConfig,
+    /// Hourly window and cooldown, shared by every conversation.
+    usage: Mutex<Usage>,
+    /// Session calls keyed by conversation (`""` outside
+    /// [`scope_conversation`]). Only filled when `max_per_session` is set.
+    sessions: Mutex<SessionCounts>,
+}
+
+impl LimitedTool {
+    fn admit(&self) -> Result<(), String> {
+        let name = self.inner.name();
+        let turn_calls = TURN_CALLS
+            .try_with(|calls| calls.lock().get(name).copied().unwrap_or(0))
+            .unwrap_or(0);
+        let now = Instant::now();
+        let mut sessions = self.sessions.lock();
+        let session_calls = self.limit.max_per_session.map(|_| {
+            let conversation = CONVERSATION.try_with(Clone::clone).unwrap_or_default();
+            sessions.calls(conversation, now)
+        });
+        self.usage.lock().check(
+            name,
+            &self.limit,
+            turn_calls,
+            session_calls.as_deref().copied().unwrap_or(0),
+            now,
+        )?;
+        if let Some(calls) = session_calls {
+            *calls += 1;
+        }
+        // Outside a turn scope there is nothing to count against.
+        let _ =
+            TURN_CALLS.try_with(|calls| *calls.lock().entry(name.to_string()).or_insert(0) += 1);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Tool for LimitedTool {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn description(&self) -> &str {
+        self.inner.description()
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        self.inner.parameters_schema()
+    }
+
+    fn dry_run_description(&self, args: &serde_json::Value) -> String {
+        self.inner.dry_run_description(args)
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        if let Err(reason) = self.admit() {
+            tracing::info!(tool = self.inner.name(), "tool limit: {reason}");
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(reason),
+            });
+        }
+        self.inner.execute(args).await
+    }
+}
+
+/// Wrap every tool that has an entry in `limits`; other tools pass through.
+pub fn wrap_registry<S: std::hash::BuildHasher>(
+    tools: Vec<Box<dyn Tool>>,
+    limits: &HashMap<String, ToolLimitConfig, S>,
+) -> Vec<Box<dyn Tool>> {
+    if limits.is_empty() {
+        return tools;
+    }
+    tools
+        .into_iter()
+        .map(|inner| match limits.get(inner.name()) {
+            Some(limit) => Box::new(LimitedTool {
+                inner,
+                limit: limit.clone(),
+                usage: Mutex::new(Usage::default()),
+                sessions: Mutex::new(SessionCounts::new(MAX_TRACKED_SESSIONS)),
+            }) as Box<dyn Tool>,
+            None => inner,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    struct Noop;
+
+    #[async_trait]
+    impl Tool for Noop {
+        fn name(&self) -> &str {
+            "noop"
+        }
+        fn description(&self) -> &str {
+            "does nothing"
+        }
+        fn parameters_schema(&self) -> serde_json::Value {
+            json!({"type": "object"})
+        }
+        async fn execute(&self, _args: serde_json::Value) -> anyhow::Result<ToolResult> {
+            Ok(ToolResult {
+                success: true,
+                output: "ran".into(),
+                error: None,
+            })
+        }
+    }
+
+    fn limited(limit: ToolLimitConfig) -> Vec<Box<dyn Tool>> {
+        wrap_registry(
+            vec![Box::new(Noop)],
+            &HashMap::from([("noop".to_string(), limit)]),
+        )
+    }
+
+    #[tokio::test]
+    async fn per_turn_budget_resets_each_turn() {
+        let tools = limited(ToolLimitConfig {
+            max_per_turn: Some(2),
+            ..ToolLimitConfig::default()
+        });
+
+        for _ in 0..2 {
+            let results = scope_turn(async {
+                let mut results = Vec::new();
+                for _ in 0..3 {
+                    results.push(tools[0].execute(json!({})).await.unwrap());
+                }
+                results
+            })
+            .await;
+            assert!(results[0].success && results[1].success);
+            assert!(!results[2].success);
+            assert!(results[2]
+                .error
+                .as_deref()
+                .unwrap()
+                .contains("limit of 2 call(s) per turn"));
+        }
+    }
+
+    #[tokio::test]
+    async fn session_budget_spans_turns() {
+        let tools = limited(ToolLimitConfig {
+            max_per_session: Some(1),
+            ..ToolLimitConfig::default()
+        });
+
+        assert!(
+            scope_turn(tools[0].execute(json!({})))
+                .await
+                .unwrap()
+                .success
+        );
+        let refused = scope_turn(tools[0].execute(json!({}))).await.unwrap();
+        assert!(!refused.success);
+        assert!(refused.error.unwrap().contains("per session"));
+    }
+
+    #[tokio::test]
+    async fn session_budget_is_per_conversation() {
+        let tools = limited(ToolLimitConfig {
+            max_per_session: Some(1),
+            ..ToolLimitConfig::default()
+        });
+        let call = |conversation: &str| {
+            scope_conversation(
+                conversation.to_string(),
+                scope_turn(tools[0].execute(json!({}))),
+            )
+        };
+
+        assert!(call("telegram_alice").await.unwrap().success);
+        assert!(!call("telegram_alice").await.unwrap().success);
+        assert!(call("telegram_bob").await.unwrap().success);
+    }
+
+    #[test]
+    fn session_counts_forget_the_least_recently_active_conversation() {
+        let start = Instant::now();
+        let mut sessions = SessionCounts::new(2);
+        *sessions.calls("alice".into(), start) += 1;
+        *sessions.calls("bob".into(), start + Duration::from_secs(1)) += 1;
+        *sessions.calls("alice".into(), start + Duration::from_secs(2)) += 1;
+
+        assert_eq!(
+            *sessions.calls("carol".into(), start + Duration::from_secs(3)),
+            0
+        );
+        assert_eq!(sessions.entries.len(), 2);
+        assert!(!sessions.entries.contains_key("bob"));
+        assert_eq!(
+            *sessions.calls("alice".into(), start + Duration::from_secs(4)),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn cooldown_is_shared_across_conversations() {
+        let tools = limited(ToolLimitConfig {
+            cooldown_secs: Some(600),
+            ..ToolLimitConfig::default()
+        });
+        let call = |conversation: &str| {
+            scope_conversation(
+                conversation.to_string(),
+                scope_turn(tools[0].execute(json!({}))),
+            )
+        };
+
+        assert!(call("telegram_alice").await.unwrap().success);
+        let refused = call("telegram_bob").await.unwrap();
+        assert!(!refused.success);
+        assert!(refused.error.unwrap().contains("cooling down"));
+        assert!(!call("telegram_alice_thread2").await.unwrap().success);
+    }
+
+    #[test]
+    fn hourly_window_and_cooldown_report_retry_time() {
+        let start = Instant::now();
+        let mut usage = Usage::default();
+        let hourly = ToolLimitConfig {
+            max_per_hour: Some(1),
+            ..ToolLimitConfig::default()
+        };
+        usage.check("flash", &hourly, 0, 0, start).unwrap();
+        let err = usage
+            .check("flash", &hourly, 0, 0, start + Duration::from_secs(600))
+            .unwrap_err();
+        assert!(err.contains("next call allowed in 3000s"), "{err}");
+        usage.check("flash", &hourly, 0, 0, start + HOUR).unwrap();
+
+        let mut usage = Usage::default();
+        let cooldown = ToolLimitConfig {
+            cooldown_secs: Some(600),
+            ..ToolLimitConfig::default()
+        };
+        usage.check("flash", &cooldown, 0, 0, start).unwrap();
+        let err = usage
+            .check("flash", &cooldown, 0, 0, start + Duration::from_secs(100))
+            .unwrap_err();
+        assert!(err.contains("another 500s"), "{err}");
+        usage
+            .check("flash", &cooldown, 0, 0, start + Duration::from_secs(600))
+            .unwrap();
+    }
+}
@@ -5,6 +5,9 @@ use serde_json::json;
 use std::fmt::Write;
 use std::sync::Arc;
 
+/// How many more entries to recall when filtering by category.
+const CATEGORY_RECALL_FACTOR: usize = 4;
+
 /// Let the agent search its own memory
 pub struct MemoryRecallTool {
     memory: Arc<dyn Memory>,
@@ -37,6 +40,10 @@ impl Tool for MemoryRecallTool {
                 "limit": {
                     "type": "integer",
                     "description": "Max results to return (default: 5)"
+                },
+                "category": {
+                    "type": "string",
+                    "description": "Only return memories in this category (e.g. core, preference, fact, task)"
                 }
             },
             "required": ["query"]
@@ -54,8 +61,35 @@ impl Tool for MemoryRecallTool {
             .get("limit")
             .and_then(serde_json::Value::as_u64)
             .map_or(5, |v| v as usize);
-
-        match self.memory.recall(query, limit, None).await {
+        let category = args
+            .get("category")
+            .and_then(|v| v.as_str())
+            .map(|c| c.trim().to_ascii_lowercase())
+            .filter(|c| !c.is_empty());
+
+        // Recall a wider pool when filtering so the category still fills `limit`
+        let recall_limit = if category.is_some() {
+            limit.saturating_mul(CATEGORY_RECALL_FACTOR)
+        } else {
+            limit
+        };
+        let recalled = self
+            .memory
+            .recall(query, recall_limit, None)
+            .await
+            .map(|entries| {
+                entries
+                    .into_iter()
+                    .filter(|entry| {
+                        category
+                            .as_deref()
+                            .is_none_or(|category| entry.category.to_string() == category)
+                    })
+                    .take(limit)
+                    .collect::<Vec<_>>()
+            });
+
+        match recalled {
             Ok(entries) if entries.is_empty() => Ok(ToolResult {
                 success: true,
                 output: "No memories found matching that query.".into(),
@@ -149,6 +183,31 @@ mod tests {
         assert!(result.output.contains("Found 3"));
     }
 
+    #[tokio::test]
+    async fn recall_filters_by_category() {
+        let (_tmp, mem) = seeded_mem();
+        mem.store("lang", "User prefers Rust", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+        mem.store(
+            "editor",
+            "User prefers Rust tooling in Helix",
+            MemoryCategory::Custom("preference".into()),
+            None,
+        )
+        .await
+        .unwrap();
+
+        let tool = MemoryRecallTool::new(mem);
+        let result = tool
+            .execute(json!({"query": "Rust", "category": "preference"}))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("Found 1"));
+        assert!(result.output.contains("[preference] editor"));
+    }
+
     #[tokio::test]
     async fn recall_missing_query() {
         let (_tmp, mem) = seeded_mem();
@@ -1,4 +1,5 @@
 use super::traits::{Tool, ToolResult};
+use crate::memory::redaction::AGENT_CHANNEL;
 use crate::memory::{Memory, MemoryCategory};
 use crate::security::policy::ToolOperation;
 use crate::security::SecurityPolicy;
@@ -78,7 +79,17 @@ impl Tool for MemoryStoreTool {
             });
         }
 
-        match self.memory.store(key, content, category, None).await {
+        let Some(content) = self.memory.redact_for_storage(AGENT_CHANNEL, content) else {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "Memory '{key}' was not stored: content contains PII and the redaction policy is 'drop'"
+                )),
+            });
+        };
+
+        match self.memory.store(key, &content, category, None).await {
             Ok(()) => Ok(ToolResult {
                 success: true,
                 output: format!("Stored memory: {key}"),
@@ -27,6 +27,7 @@ pub mod cron_run;
 pub mod cron_runs;
 pub mod cron_update;
 pub mod delegate;
+pub mod dry_run;
 pub mod file_edit;
 pub mod file_read;
 pub mod file_write;
@@ -40,6 +41,7 @@ pub mod hardware_memory_map;
 pub mod hardware_memory_read;
 pub mod http_request;
 pub mod image_info;
+pub mod limits;
 pub mod memory_forget;
 pub mod memory_recall;
 pub mod memory_store;
@@ -133,6 +135,10 @@ impl Tool for ArcDelegatingTool {
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         self.inner.execute(args).await
     }
+
+    fn dry_run_description(&self, args: &serde_json::Value) -> String {
+        self.inner.dry_run_description(args)
+    }
 }
 
 fn boxed_registry_from_arcs(tools: Vec<Arc<dyn Tool>>) -> Vec<Box<dyn Tool>> {
@@ -269,12 +275,15 @@ pub fn all_tools_with_runtime(
     }
 
     if http_config.enabled {
-        tool_arcs.push(Arc::new(HttpRequestTool::new(
-            security.clone(),
-            http_config.allowed_domains.clone(),
-            http_config.max_response_size,
-            http_config.timeout_secs,
-        )));
+        tool_arcs.push(Arc::new(
+            HttpRequestTool::new(
+                security.clone(),
+                http_config.allowed_domains.clone(),
+                http_config.max_response_size,
+                http_config.timeout_secs,
+            )
+            .with_allowed_content_types(http_config.allowed_content_types.clone()),
+        ));
     }
 
     if web_fetch_config.enabled {
@@ -338,10 +347,16 @@ pub fn all_tools_with_runtime(
                     .map(std::path::PathBuf::from),
                 secrets_encrypt: root_config.secrets.encrypt,
                 reasoning_enabled: root_config.runtime.reasoning_enabled,
+                connectivity: Some(crate::connectivity::Connectivity::new(root_config)),
+                recorder: Some(crate::providers::recorder::Recorder::new(root_config)),
             },
         )
         .with_parent_tools(parent_tools)
-        .with_multimodal_config(root_config.multimodal.clone());
+        .with_multimodal_config(root_config.multimodal.clone())
+        .with_observer(Arc::from(crate::cost::usage::with_recording(
+            Box::new(crate::observability::NoopObserver),
+            root_config,
+        )));
         tool_arcs.push(Arc::new(delegate_tool));
     }
 
@@ -85,6 +85,13 @@ impl Tool for ShellTool {
         })
     }
 
+    fn dry_run_description(&self, args: &serde_json::Value) -> String {
+        match args.get("command").and_then(|v| v.as_str()) {
+            Some(command) => format!("would run shell command `{command}`"),
+            None => "would run a shell command (missing 'command')".to_string(),
+        }
+    }
+
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
         let command = args
             .get("command")
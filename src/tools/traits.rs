@@ -32,6 +32,12 @@ pub trait Tool: Send + Sync {
     /// Execute the tool with given arguments
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult>;
 
+    /// Describe what `execute` would do with `args`, without side effects.
+    /// Used by dry-run mode; phrase it as "would ...".
+    fn dry_run_description(&self, args: &serde_json::Value) -> String {
+        format!("would call `{}` with {args}", self.name())
+    }
+
     /// Get the full spec for LLM registration
     fn spec(&self) -> ToolSpec {
         ToolSpec {
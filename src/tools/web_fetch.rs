@@ -175,8 +175,9 @@ impl Tool for WebFetchTool {
             .connect_timeout(Duration::from_secs(10))
             .redirect(redirect_policy)
             .user_agent("ZeroClaw/0.1 (web_fetch)");
-        let builder = crate::config::apply_runtime_proxy_to_builder(builder, "tool.web_fetch");
-        let client = match builder.build() {
+        let client = crate::config::apply_runtime_proxy_to_builder(builder, "tool.web_fetch")
+            .and_then(|builder| Ok(builder.build()?));
+        let client = match client {
             Ok(c) => c,
             Err(e) => {
                 return Ok(ToolResult {
@@ -442,8 +443,11 @@ fn is_private_or_local_host(host: &str) -> bool {
     false
 }
 
+/// Resolve `host` and reject it when any address is not globally routable,
+/// so allowlisted names pointing at internal addresses are still blocked.
+/// Shared with `http_request` and the browser tools.
 #[cfg(not(test))]
-fn validate_resolved_host_is_public(host: &str) -> anyhow::Result<()> {
+pub(super) fn validate_resolved_host_is_public(host: &str) -> anyhow::Result<()> {
     use std::net::ToSocketAddrs;
 
     let ips = (host, 0)
@@ -456,7 +460,7 @@ fn validate_resolved_host_is_public(host: &str) -> anyhow::Result<()> {
 }
 
 #[cfg(test)]
-fn validate_resolved_host_is_public(_host: &str) -> anyhow::Result<()> {
+pub(super) fn validate_resolved_host_is_public(_host: &str) -> anyhow::Result<()> {
     // DNS checks are covered by validate_resolved_ips_are_public unit tests.
     Ok(())
 }
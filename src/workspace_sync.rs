@@ -0,0 +1,337 @@
+//! Git-backed workspace sync.
+//!
+//! Commits workspace Markdown (identity/persona files, daily notes, memory
+//! snapshots) to a git repository rooted at the workspace, rebases onto the
+//! configured remote, and pushes. A rebase conflict aborts cleanly and leaves
+//! the local commit in place, so nothing in the workspace is ever overwritten.
+
+use crate::config::{Config, WorkspaceSyncConfig};
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+use std::path::Path;
+use tokio::process::Command;
+
+const REMOTE_NAME: &str = "origin";
+const MIN_INTERVAL_MINUTES: u32 = 5;
+const FALLBACK_AUTHOR_NAME: &str = "ZeroClaw";
+const FALLBACK_AUTHOR_EMAIL: &str = "zeroclaw@localhost";
+
+/// Outcome of one sync pass.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    /// A commit was created from local changes
+    pub committed: bool,
+    /// Remote commits were rebased into the local branch
+    pub pulled: bool,
+    /// The branch was pushed to the remote
+    pub pushed: bool,
+}
+
+pub async fn handle_command(command: crate::WorkspaceCommands, config: &Config) -> Result<()> {
+    match command {
+        crate::WorkspaceCommands::Sync { no_push } => {
+            let report = sync(&config.workspace_sync, &config.workspace_dir, !no_push).await?;
+            println!("✅ Workspace synced: {}", config.workspace_dir.display());
+            println!(
+                "  Committed local changes: {}",
+                if report.committed { "yes" } else { "no" }
+            );
+            if config.workspace_sync.remote.is_some() {
+                println!(
+                    "  Pulled remote changes:   {}",
+                    if report.pulled { "yes" } else { "no" }
+                );
+                println!(
+                    "  Pushed:                  {}",
+                    if report.pushed { "yes" } else { "no" }
+                );
+            } else {
+                println!("  No [workspace_sync].remote configured; commits stay local.");
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Daemon worker: sync on the configured interval until cancelled.
+pub async fn run_worker(config: Config) -> Result<()> {
+    let minutes = config
+        .workspace_sync
+        .interval_minutes
+        .max(MIN_INTERVAL_MINUTES);
+    let mut interval =
+        tokio::time::interval(std::time::Duration::from_secs(u64::from(minutes) * 60));
+
+    loop {
+        interval.tick().await;
+        match sync(&config.workspace_sync, &config.workspace_dir, true).await {
+            Ok(report) => {
+                crate::health::mark_component_ok("workspace-sync");
+                tracing::debug!(?report, "workspace sync complete");
+            }
+            Err(e) => {
+                crate::health::mark_component_error("workspace-sync", e.to_string());
+                tracing::warn!("workspace sync failed: {e}");
+            }
+        }
+    }
+}
+
+/// Commit local changes, rebase onto the remote branch, then push.
+///
+/// Local changes are committed *before* pulling so a rebase conflict can be
+/// aborted without losing anything; the error tells the user where to resolve it.
+pub async fn sync(
+    config: &WorkspaceSyncConfig,
+    workspace_dir: &Path,
+    push: bool,
+) -> Result<SyncReport> {
+    ensure_repo(config, workspace_dir).await?;
+
+    let mut report = SyncReport {
+        committed: commit_local_changes(config, workspace_dir).await?,
+        ..SyncReport::default()
+    };
+
+    if config.remote.is_none() {
+        return Ok(report);
+    }
+
+    report.pulled = pull_rebase(config, workspace_dir).await?;
+
+    if push && has_commits(workspace_dir).await {
+        git(
+            workspace_dir,
+            &["push", REMOTE_NAME, &format!("HEAD:{}", config.branch)],
+        )
+        .await
+        .context("failed to push workspace to remote")?;
+        report.pushed = true;
+    }
+
+    Ok(report)
+}
+
+async fn ensure_repo(config: &WorkspaceSyncConfig, workspace_dir: &Path) -> Result<()> {
+    tokio::fs::create_dir_all(workspace_dir).await?;
+
+    if !workspace_dir.join(".git").exists() {
+        git(workspace_dir, &["init", "-b", &config.branch])
+            .await
+            .context("failed to initialize workspace git repository")?;
+    }
+
+    // Commits and rebases need an identity; fall back to a repo-local one on
+    // headless hosts (containers, Raspberry Pi images) with no global git config.
+    if git(workspace_dir, &["config", "user.email"]).await.is_err() {
+        git(
+            workspace_dir,
+            &["config", "user.email", FALLBACK_AUTHOR_EMAIL],
+        )
+        .await?;
+        git(
+            workspace_dir,
+            &["config", "user.name", FALLBACK_AUTHOR_NAME],
+        )
+        .await?;
+    }
+
+    if let Some(remote) = config.remote.as_deref().map(str::trim) {
+        if remote.is_empty() {
+            bail!("[workspace_sync].remote is set but empty");
+        }
+        let current = git(workspace_dir, &["remote", "get-url", REMOTE_NAME]).await;
+        match current {
+            Ok(url) if url.trim() == remote => {}
+            Ok(_) => {
+                git(workspace_dir, &["remote", "set-url", REMOTE_NAME, remote]).await?;
+            }
+            Err(_) => {
+                git(workspace_dir, &["remote", "add", REMOTE_NAME, remote]).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Stage configured paths and commit if anything changed. Returns whether a commit was made.
+async fn commit_local_changes(config: &WorkspaceSyncConfig, workspace_dir: &Path) -> Result<bool> {
+    for pattern in &config.paths {
+        let pathspec = format!(":(glob){pattern}");
+        if let Err(e) = git(workspace_dir, &["add", "-A", "--", &pathspec]).await {
+            // Patterns that match nothing yet are fine; anything else is a real failure.
+            if !e.to_string().contains("did not match any files") {
+                return Err(e);
+            }
+        }
+    }
+
+    let staged = Command::new("git")
+        .args(["diff", "--cached", "--quiet"])
+        .current_dir(workspace_dir)
+        .status()
+        .await
+        .context("failed to run git diff")?;
+    if staged.success() {
+        return Ok(false);
+    }
+
+    let message = format!("workspace sync: {}", Utc::now().to_rfc3339());
+    git(workspace_dir, &["commit", "-m", &message])
+        .await
+        .context("failed to commit workspace changes")?;
+
+    Ok(true)
+}
+
+/// Fetch the remote branch and rebase local commits onto it.
+/// Returns whether remote commits were brought in.
+async fn pull_rebase(config: &WorkspaceSyncConfig, workspace_dir: &Path) -> Result<bool> {
+    git(workspace_dir, &["fetch", REMOTE_NAME])
+        .await
+        .context("failed to fetch workspace remote")?;
+
+    let upstream = format!("{REMOTE_NAME}/{}", config.branch);
+    if git(
+        workspace_dir,
+        &["rev-parse", "--verify", "--quiet", &upstream],
+    )
+    .await
+    .is_err()
+    {
+        // Remote branch does not exist yet; the first push creates it.
+        return Ok(false);
+    }
+
+    if !has_commits(workspace_dir).await {
+        // Unborn branch: adopt the remote history. Checkout refuses to clobber untracked files.
+        git(
+            workspace_dir,
+            &["checkout", "-B", &config.branch, &upstream],
+        )
+        .await?;
+        return Ok(true);
+    }
+
+    let behind = git(
+        workspace_dir,
+        &["rev-list", "--count", &format!("HEAD..{upstream}")],
+    )
+    .await?;
+    if behind.trim() == "0" {
+        return Ok(false);
+    }
+
+    if let Err(e) = git(workspace_dir, &["rebase", "--autostash", &upstream]).await {
+        let _ = git(workspace_dir, &["rebase", "--abort"]).await;
+        bail!(
+            "workspace sync conflict with {upstream}; local commits kept. Resolve manually in {}: {e}",
+            workspace_dir.display()
+        );
+    }
+
+    Ok(true)
+}
+
+async fn has_commits(workspace_dir: &Path) -> bool {
+    git(workspace_dir, &["rev-parse", "--verify", "--quiet", "HEAD"])
+        .await
+        .is_ok()
+}
+
+async fn git(workspace_dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(workspace_dir)
+        .output()
+        .await
+        .context("failed to run git (is it installed?)")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git {} failed: {}", args.join(" "), stderr.trim());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn git_available() -> bool {
+        std::process::Command::new("git")
+            .arg("--version")
+            .output()
+            .is_ok_and(|o| o.status.success())
+    }
+
+    #[tokio::test]
+    async fn sync_commits_only_configured_paths() {
+        if !git_available() {
+            return;
+        }
+        let tmp = TempDir::new().unwrap();
+        let ws = tmp.path();
+        std::fs::write(ws.join("SOUL.md"), "soul").unwrap();
+        std::fs::create_dir_all(ws.join("memory")).unwrap();
+        std::fs::write(ws.join("memory").join("2026-01-01.md"), "note").unwrap();
+        std::fs::write(ws.join("memory").join("brain.db"), "binary").unwrap();
+
+        let cfg = WorkspaceSyncConfig::default();
+        let report = sync(&cfg, ws, true).await.unwrap();
+        assert!(report.committed);
+        assert!(!report.pushed, "no remote configured");
+
+        let tracked = git(ws, &["ls-files"]).await.unwrap();
+        assert!(tracked.contains("SOUL.md"));
+        assert!(tracked.contains("memory/2026-01-01.md"));
+        assert!(!tracked.contains("brain.db"));
+
+        let again = sync(&cfg, ws, true).await.unwrap();
+        assert!(!again.committed, "clean tree should not produce a commit");
+    }
+
+    #[tokio::test]
+    async fn sync_pushes_and_pulls_between_workspaces() {
+        if !git_available() {
+            return;
+        }
+        let tmp = TempDir::new().unwrap();
+        let remote = tmp.path().join("remote.git");
+        std::fs::create_dir_all(&remote).unwrap();
+        git(&remote, &["init", "--bare", "-b", "main"])
+            .await
+            .unwrap();
+
+        let cfg = WorkspaceSyncConfig {
+            remote: Some(remote.display().to_string()),
+            ..WorkspaceSyncConfig::default()
+        };
+
+        let a = tmp.path().join("a");
+        std::fs::create_dir_all(&a).unwrap();
+        std::fs::write(a.join("USER.md"), "from a").unwrap();
+        let report = sync(&cfg, &a, true).await.unwrap();
+        assert!(report.committed && report.pushed);
+
+        let b = tmp.path().join("b");
+        std::fs::create_dir_all(&b).unwrap();
+        std::fs::write(b.join("TOOLS.md"), "from b").unwrap();
+        let report = sync(&cfg, &b, true).await.unwrap();
+        assert!(report.pulled, "b should rebase onto a's commit");
+        assert_eq!(
+            std::fs::read_to_string(b.join("USER.md")).unwrap(),
+            "from a"
+        );
+
+        let report = sync(&cfg, &a, true).await.unwrap();
+        assert!(report.pulled);
+        assert_eq!(
+            std::fs::read_to_string(a.join("TOOLS.md")).unwrap(),
+            "from b"
+        );
+    }
+}
@@ -6,9 +6,11 @@ import AgentChat from './pages/AgentChat';
 import Tools from './pages/Tools';
 import Cron from './pages/Cron';
 import Integrations from './pages/Integrations';
+import Providers from './pages/Providers';
 import Memory from './pages/Memory';
 import Config from './pages/Config';
 import Cost from './pages/Cost';
+import Usage from './pages/Usage';
 import Logs from './pages/Logs';
 import Doctor from './pages/Doctor';
 import { AuthProvider, useAuth } from './hooks/useAuth';
@@ -118,9 +120,11 @@ function AppContent() {
           <Route path="/tools" element={<Tools />} />
           <Route path="/cron" element={<Cron />} />
           <Route path="/integrations" element={<Integrations />} />
+          <Route path="/providers" element={<Providers />} />
           <Route path="/memory" element={<Memory />} />
           <Route path="/config" element={<Config />} />
           <Route path="/cost" element={<Cost />} />
+          <Route path="/usage" element={<Usage />} />
           <Route path="/logs" element={<Logs />} />
           <Route path="/doctor" element={<Doctor />} />
           <Route path="*" element={<Navigate to="/" replace />} />
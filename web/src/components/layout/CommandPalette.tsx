@@ -0,0 +1,189 @@
+import { useState, useEffect, useMemo, useRef } from 'react';
+import { useNavigate } from 'react-router-dom';
+import { Bug, Languages, LogOut, Search, type LucideIcon } from 'lucide-react';
+import { navItems } from '@/components/layout/Sidebar';
+import { useLocaleContext } from '@/App';
+import { useAuth } from '@/hooks/useAuth';
+import { useScrollSelectedIntoView, useWheelSelect } from '@/hooks/useWheelSelect';
+import { getStatus, setDebugMode } from '@/lib/api';
+import { fuzzyFilter } from '@/lib/fuzzy';
+import { t } from '@/lib/i18n';
+import { pushToast } from '@/lib/toast';
+
+interface PaletteCommand {
+  id: string;
+  label: string;
+  hint: string;
+  icon: LucideIcon;
+  run: () => void;
+}
+
+/**
+ * Ctrl+P / Cmd+P overlay that fuzzy-matches every sidebar page and a few
+ * global actions. Arrow keys or the wheel move, click selects, Enter or
+ * double-click runs, Escape closes.
+ */
+export default function CommandPalette() {
+  const navigate = useNavigate();
+  const { logout } = useAuth();
+  const { locale, setAppLocale } = useLocaleContext();
+  const [open, setOpen] = useState(false);
+  const [query, setQuery] = useState('');
+  const [index, setIndex] = useState(0);
+  const inputRef = useRef<HTMLInputElement>(null);
+  const listRef = useRef<HTMLUListElement>(null);
+
+  useEffect(() => {
+    const handler = (e: KeyboardEvent) => {
+      if ((e.ctrlKey || e.metaKey) && e.key.toLowerCase() === 'p') {
+        // Replaces the browser's print dialog while the dashboard has focus.
+        e.preventDefault();
+        setOpen((prev) => !prev);
+      }
+    };
+    window.addEventListener('keydown', handler);
+    return () => window.removeEventListener('keydown', handler);
+  }, []);
+
+  useEffect(() => {
+    if (open) {
+      setQuery('');
+      setIndex(0);
+      inputRef.current?.focus();
+    }
+  }, [open]);
+
+  const toggleDebug = () =>
+    getStatus()
+      .then((status) => setDebugMode(!status.debug.enabled))
+      .then((debug) => pushToast(`Debug mode ${debug.enabled ? 'on' : 'off'}`, 'success'))
+      .catch((err) => pushToast(`Failed to switch debug mode: ${err.message}`, 'error'));
+
+  const commands: PaletteCommand[] = useMemo(
+    () => [
+      ...navItems.map(({ to, icon, labelKey }) => ({
+        id: `nav:${to}`,
+        label: t(labelKey),
+        hint: to,
+        icon,
+        run: () => navigate(to),
+      })),
+      {
+        id: 'action:debug',
+        label: 'Toggle debug mode',
+        hint: 'action',
+        icon: Bug,
+        run: toggleDebug,
+      },
+      {
+        id: 'action:language',
+        label: `Switch language to ${locale === 'en' ? 'Türkçe' : 'English'}`,
+        hint: 'action',
+        icon: Languages,
+        run: () => setAppLocale(locale === 'en' ? 'tr' : 'en'),
+      },
+      {
+        id: 'action:logout',
+        label: t('auth.logout'),
+        hint: 'action',
+        icon: LogOut,
+        run: logout,
+      },
+    ],
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+    [locale, navigate, logout],
+  );
+
+  const matches = useMemo(
+    () => fuzzyFilter(commands, query, (command) => [command.label, command.hint]),
+    [commands, query],
+  );
+
+  const selected = Math.min(index, Math.max(matches.length - 1, 0));
+  const moveSelection = (delta: number) =>
+    setIndex(Math.min(Math.max(selected + delta, 0), Math.max(matches.length - 1, 0)));
+
+  useWheelSelect(listRef, moveSelection, open);
+  useScrollSelectedIntoView(listRef, open ? selected : null);
+
+  if (!open) return null;
+
+  const runCommand = (command: PaletteCommand | undefined) => {
+    if (!command) return;
+    setOpen(false);
+    command.run();
+  };
+
+  const handleKeyDown = (e: React.KeyboardEvent<HTMLInputElement>) => {
+    if (e.key === 'ArrowDown') {
+      e.preventDefault();
+      moveSelection(1);
+    } else if (e.key === 'ArrowUp') {
+      e.preventDefault();
+      moveSelection(-1);
+    } else if (e.key === 'Enter') {
+      e.preventDefault();
+      runCommand(matches[selected]);
+    } else if (e.key === 'Escape') {
+      e.preventDefault();
+      setOpen(false);
+    }
+  };
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-start justify-center bg-black/60 pt-[15vh]"
+      onClick={() => setOpen(false)}
+    >
+      <div
+        className="w-full max-w-lg bg-gray-900 rounded-xl border border-gray-700 shadow-2xl overflow-hidden"
+        onClick={(e) => e.stopPropagation()}
+      >
+        <div className="flex items-center gap-2 px-4 border-b border-gray-800">
+          <Search className="h-4 w-4 text-gray-500" />
+          <input
+            ref={inputRef}
+            type="text"
+            value={query}
+            onChange={(e) => {
+              setQuery(e.target.value);
+              setIndex(0);
+            }}
+            onKeyDown={handleKeyDown}
+            placeholder={t('palette.placeholder')}
+            className="flex-1 py-3 bg-transparent text-sm text-white placeholder-gray-500 focus:outline-none"
+          />
+          <kbd className="text-xs text-gray-500 border border-gray-700 rounded px-1.5 py-0.5">Esc</kbd>
+        </div>
+        <ul ref={listRef} className="max-h-80 overflow-y-auto py-1">
+          {matches.length === 0 ? (
+            <li className="px-4 py-3 text-sm text-gray-500">{t('palette.empty')}</li>
+          ) : (
+            matches.map((command, i) => {
+              const Icon = command.icon;
+              return (
+                <li key={command.id}>
+                  <button
+                    data-selected={i === selected}
+                    onClick={() => {
+                      setIndex(i);
+                      inputRef.current?.focus();
+                    }}
+                    onDoubleClick={() => runCommand(command)}
+                    className={`w-full flex items-center gap-3 px-4 py-2 text-left text-sm ${
+                      i === selected ? 'bg-blue-600 text-white' : 'text-gray-300'
+                    }`}
+                  >
+                    <Icon className="h-4 w-4 flex-shrink-0" />
+                    <span className="flex-1 truncate">{command.label}</span>
+                    <span className="text-xs text-gray-400 font-mono">{command.hint}</span>
+                  </button>
+                </li>
+              );
+            })
+          )}
+        </ul>
+      </div>
+    </div>
+  );
+}
@@ -10,9 +10,11 @@ const routeTitles: Record<string, string> = {
   '/tools': 'nav.tools',
   '/cron': 'nav.cron',
   '/integrations': 'nav.integrations',
+  '/providers': 'nav.providers',
   '/memory': 'nav.memory',
   '/config': 'nav.config',
   '/cost': 'nav.cost',
+  '/usage': 'nav.usage',
   '/logs': 'nav.logs',
   '/doctor': 'nav.doctor',
 };
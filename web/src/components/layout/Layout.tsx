@@ -1,6 +1,8 @@
 import { Outlet } from 'react-router-dom';
 import Sidebar from '@/components/layout/Sidebar';
 import Header from '@/components/layout/Header';
+import Toaster from '@/components/layout/Toaster';
+import CommandPalette from '@/components/layout/CommandPalette';
 
 export default function Layout() {
   return (
@@ -17,6 +19,12 @@ export default function Layout() {
           <Outlet />
         </main>
       </div>
+
+      {/* Background notices; never covers page content permanently */}
+      <Toaster />
+
+      {/* Ctrl+P / Cmd+P: jump to any page or run an action */}
+      <CommandPalette />
     </div>
   );
 }
@@ -5,23 +5,27 @@ import {
   Wrench,
   Clock,
   Puzzle,
+  Server,
   Brain,
   Settings,
   DollarSign,
+  BarChart3,
   Activity,
   Stethoscope,
 } from 'lucide-react';
 import { t } from '@/lib/i18n';
 
-const navItems = [
+export const navItems = [
   { to: '/', icon: LayoutDashboard, labelKey: 'nav.dashboard' },
   { to: '/agent', icon: MessageSquare, labelKey: 'nav.agent' },
   { to: '/tools', icon: Wrench, labelKey: 'nav.tools' },
   { to: '/cron', icon: Clock, labelKey: 'nav.cron' },
   { to: '/integrations', icon: Puzzle, labelKey: 'nav.integrations' },
+  { to: '/providers', icon: Server, labelKey: 'nav.providers' },
   { to: '/memory', icon: Brain, labelKey: 'nav.memory' },
   { to: '/config', icon: Settings, labelKey: 'nav.config' },
   { to: '/cost', icon: DollarSign, labelKey: 'nav.cost' },
+  { to: '/usage', icon: BarChart3, labelKey: 'nav.usage' },
   { to: '/logs', icon: Activity, labelKey: 'nav.logs' },
   { to: '/doctor', icon: Stethoscope, labelKey: 'nav.doctor' },
 ];
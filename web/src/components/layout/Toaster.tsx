@@ -0,0 +1,81 @@
+import { useState, useEffect } from 'react';
+import { CheckCircle2, Info, AlertTriangle, XCircle, X } from 'lucide-react';
+import { SSEClient } from '@/lib/sse';
+import { onToast, pushToast, type Toast, type ToastLevel } from '@/lib/toast';
+
+/** How long a toast stays on screen, in ms. Errors linger longer. */
+const DURATION: Record<ToastLevel, number> = {
+  info: 4000,
+  success: 4000,
+  warning: 6000,
+  error: 8000,
+};
+const MAX_VISIBLE = 4;
+
+const STYLE: Record<ToastLevel, { icon: typeof Info; className: string }> = {
+  info: { icon: Info, className: 'border-blue-700/60 text-blue-300' },
+  success: { icon: CheckCircle2, className: 'border-green-700/60 text-green-300' },
+  warning: { icon: AlertTriangle, className: 'border-yellow-700/60 text-yellow-300' },
+  error: { icon: XCircle, className: 'border-red-700/60 text-red-300' },
+};
+
+function isLevel(value: unknown): value is ToastLevel {
+  return value === 'info' || value === 'success' || value === 'warning' || value === 'error';
+}
+
+/**
+ * Corner stack of short-lived notices. Listens to `toast` events on the
+ * gateway event stream and to `pushToast` calls from pages.
+ */
+export default function Toaster() {
+  const [toasts, setToasts] = useState<Toast[]>([]);
+
+  const dismiss = (id: number) => setToasts((prev) => prev.filter((t) => t.id !== id));
+
+  useEffect(
+    () =>
+      onToast((toast) => {
+        setToasts((prev) => [...prev, toast].slice(-MAX_VISIBLE));
+        setTimeout(() => dismiss(toast.id), DURATION[toast.level]);
+      }),
+    [],
+  );
+
+  useEffect(() => {
+    const client = new SSEClient();
+    client.onEvent = (event) => {
+      if (event.type === 'toast' && typeof event.message === 'string') {
+        pushToast(event.message, isLevel(event.level) ? event.level : 'info');
+      }
+    };
+    client.connect();
+    return () => client.disconnect();
+  }, []);
+
+  if (toasts.length === 0) return null;
+
+  return (
+    <div className="fixed bottom-4 right-4 z-50 flex flex-col gap-2 w-80 pointer-events-none">
+      {toasts.map((toast) => {
+        const { icon: Icon, className } = STYLE[toast.level];
+        return (
+          <div
+            key={toast.id}
+            role="status"
+            className={`pointer-events-auto flex items-start gap-3 rounded-lg border bg-gray-900/95 px-4 py-3 shadow-lg ${className}`}
+          >
+            <Icon className="h-4 w-4 mt-0.5 flex-shrink-0" />
+            <p className="flex-1 text-sm text-gray-200">{toast.message}</p>
+            <button
+              onClick={() => dismiss(toast.id)}
+              className="text-gray-500 hover:text-white transition-colors"
+              aria-label="Dismiss"
+            >
+              <X className="h-3.5 w-3.5" />
+            </button>
+          </div>
+        );
+      })}
+    </div>
+  );
+}
@@ -0,0 +1,41 @@
+import { useEffect, useRef, type RefObject } from 'react';
+
+/**
+ * Scroll-wheel navigation for a selectable list: each wheel notch moves the
+ * selection by one row instead of scrolling the container. The listener is
+ * registered as non-passive so the default scroll can be cancelled. Pass
+ * `active` when the list mounts later than the component (loading states).
+ */
+export function useWheelSelect<T extends HTMLElement>(
+  ref: RefObject<T | null>,
+  move: (delta: number) => void,
+  active = true,
+) {
+  // Read the latest callback without re-registering the listener.
+  const moveRef = useRef(move);
+  moveRef.current = move;
+
+  useEffect(() => {
+    const el = ref.current;
+    if (!el) return;
+    const handler = (e: WheelEvent) => {
+      if (e.deltaY === 0) return;
+      e.preventDefault();
+      moveRef.current(e.deltaY > 0 ? 1 : -1);
+    };
+    el.addEventListener('wheel', handler, { passive: false });
+    return () => el.removeEventListener('wheel', handler);
+  }, [ref, active]);
+}
+
+/** Keep the row marked `data-selected="true"` inside `ref` visible. */
+export function useScrollSelectedIntoView<T extends HTMLElement>(
+  ref: RefObject<T | null>,
+  selectedKey: unknown,
+) {
+  useEffect(() => {
+    ref.current
+      ?.querySelector<HTMLElement>('[data-selected="true"]')
+      ?.scrollIntoView({ block: 'nearest' });
+  }, [ref, selectedKey]);
+}
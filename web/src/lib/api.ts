@@ -3,11 +3,20 @@ import type {
   ToolSpec,
   CronJob,
   Integration,
+  ProviderInfo,
+  ProviderModels,
   DiagResult,
+  FixAction,
   MemoryEntry,
+  MemoryPage,
   CostSummary,
   CliTool,
+  ConfigSaveResult,
+  ConfigValidation,
   HealthSnapshot,
+  DebugStatus,
+  UsageGranularity,
+  UsageReport,
 } from '../types/api';
 import { clearToken, getToken, setToken } from './auth';
 
@@ -118,6 +127,13 @@ export function getHealth(): Promise<HealthSnapshot> {
   );
 }
 
+export function setDebugMode(enabled: boolean): Promise<DebugStatus> {
+  return apiFetch<DebugStatus>('/api/debug', {
+    method: 'POST',
+    body: JSON.stringify({ enabled }),
+  });
+}
+
 // ---------------------------------------------------------------------------
 // Config
 // ---------------------------------------------------------------------------
@@ -128,14 +144,22 @@ export function getConfig(): Promise<string> {
   );
 }
 
-export function putConfig(toml: string): Promise<void> {
-  return apiFetch<void>('/api/config', {
+export function putConfig(toml: string): Promise<ConfigSaveResult> {
+  return apiFetch<ConfigSaveResult>('/api/config', {
     method: 'PUT',
     headers: { 'Content-Type': 'application/toml' },
     body: toml,
   });
 }
 
+export function validateConfig(toml: string): Promise<ConfigValidation> {
+  return apiFetch<ConfigValidation>('/api/config/validate', {
+    method: 'POST',
+    headers: { 'Content-Type': 'application/toml' },
+    body: toml,
+  });
+}
+
 // ---------------------------------------------------------------------------
 // Tools
 // ---------------------------------------------------------------------------
@@ -184,6 +208,31 @@ export function getIntegrations(): Promise<Integration[]> {
   );
 }
 
+// ---------------------------------------------------------------------------
+// Providers
+// ---------------------------------------------------------------------------
+
+export function getProviders(): Promise<ProviderInfo[]> {
+  return apiFetch<ProviderInfo[] | { providers: ProviderInfo[] }>('/api/providers').then(
+    (data) => unwrapField(data, 'providers'),
+  );
+}
+
+export function getProviderModels(provider: string): Promise<ProviderModels> {
+  return apiFetch<ProviderModels>(`/api/providers/${encodeURIComponent(provider)}/models`);
+}
+
+/** Make `provider` the default; `model` defaults to its recommended model. */
+export function setDefaultProvider(
+  provider: string,
+  model?: string,
+): Promise<{ provider: string; model: string | null }> {
+  return apiFetch<{ provider: string; model: string | null }>('/api/providers/default', {
+    method: 'POST',
+    body: JSON.stringify({ provider, model }),
+  });
+}
+
 // ---------------------------------------------------------------------------
 // Doctor / Diagnostics
 // ---------------------------------------------------------------------------
@@ -195,6 +244,15 @@ export function runDoctor(): Promise<DiagResult[]> {
   }).then((data) => (Array.isArray(data) ? data : data.results));
 }
 
+export function applyDoctorFix(
+  action: FixAction,
+): Promise<{ message: string; results: DiagResult[] }> {
+  return apiFetch<{ message: string; results: DiagResult[] }>('/api/doctor/fix', {
+    method: 'POST',
+    body: JSON.stringify({ action }),
+  });
+}
+
 // ---------------------------------------------------------------------------
 // Memory
 // ---------------------------------------------------------------------------
@@ -212,6 +270,24 @@ export function getMemory(
   );
 }
 
+export interface MemoryPageQuery {
+  query?: string;
+  category?: string;
+  session?: string;
+  limit: number;
+  offset: number;
+}
+
+export function getMemoryPage(q: MemoryPageQuery): Promise<MemoryPage> {
+  const params = new URLSearchParams();
+  if (q.query) params.set('query', q.query);
+  if (q.category) params.set('category', q.category);
+  if (q.session) params.set('session', q.session);
+  params.set('limit', String(q.limit));
+  params.set('offset', String(q.offset));
+  return apiFetch<MemoryPage>(`/api/memory?${params.toString()}`);
+}
+
 export function storeMemory(
   key: string,
   content: string,
@@ -229,6 +305,12 @@ export function deleteMemory(key: string): Promise<void> {
   });
 }
 
+export function pinMemory(key: string): Promise<void> {
+  return apiFetch<unknown>(`/api/memory/${encodeURIComponent(key)}/pin`, {
+    method: 'POST',
+  }).then(() => undefined);
+}
+
 // ---------------------------------------------------------------------------
 // Cost
 // ---------------------------------------------------------------------------
@@ -239,6 +321,13 @@ export function getCost(): Promise<CostSummary> {
   );
 }
 
+export function getUsage(granularity: UsageGranularity, last: number): Promise<UsageReport> {
+  const params = new URLSearchParams({ granularity, last: String(last) });
+  return apiFetch<UsageReport | { usage: UsageReport }>(`/api/usage?${params}`).then((data) =>
+    unwrapField(data, 'usage'),
+  );
+}
+
 // ---------------------------------------------------------------------------
 // CLI Tools
 // ---------------------------------------------------------------------------
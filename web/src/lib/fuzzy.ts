@@ -0,0 +1,42 @@
+/**
+ * Subsequence fuzzy match. Returns a score (higher is better) when every
+ * character of `query` appears in `text` in order, or `null` otherwise.
+ * Consecutive runs and matches at word starts score higher.
+ */
+export function fuzzyScore(query: string, text: string): number | null {
+  const q = query.trim().toLowerCase();
+  if (!q) return 0;
+  const t = text.toLowerCase();
+
+  let score = 0;
+  let run = 0;
+  let from = 0;
+  for (const ch of q) {
+    const at = t.indexOf(ch, from);
+    if (at === -1) return null;
+    run = at === from ? run + 1 : 1;
+    score += run;
+    if (at === 0 || /[\s/_.:-]/.test(t[at - 1] ?? '')) score += 2;
+    from = at + 1;
+  }
+  // Prefer shorter candidates when the match is otherwise equal.
+  return score - t.length / 100;
+}
+
+/**
+ * Filter `items` to those matching `query` on any of their keys, best match
+ * first. An empty query keeps the original order.
+ */
+export function fuzzyFilter<T>(items: T[], query: string, keys: (item: T) => string[]): T[] {
+  if (!query.trim()) return items;
+  return items
+    .map((item) => {
+      const scores = keys(item)
+        .map((key) => fuzzyScore(query, key))
+        .filter((score): score is number => score !== null);
+      return { item, score: scores.length ? Math.max(...scores) : null };
+    })
+    .filter((entry): entry is { item: T; score: number } => entry.score !== null)
+    .sort((a, b) => b.score - a.score)
+    .map((entry) => entry.item);
+}
@@ -15,9 +15,13 @@ const translations: Record<Locale, Record<string, string>> = {
     'nav.tools': 'Tools',
     'nav.cron': 'Scheduled Jobs',
     'nav.integrations': 'Integrations',
+    'nav.providers': 'Providers',
+    'palette.placeholder': 'Type a page or action...',
+    'palette.empty': 'No matching commands',
     'nav.memory': 'Memory',
     'nav.config': 'Configuration',
     'nav.cost': 'Cost Tracker',
+    'nav.usage': 'Usage',
     'nav.logs': 'Logs',
     'nav.doctor': 'Doctor',
 
@@ -198,9 +202,13 @@ const translations: Record<Locale, Record<string, string>> = {
     'nav.tools': 'Araclar',
     'nav.cron': 'Zamanlanmis Gorevler',
     'nav.integrations': 'Entegrasyonlar',
+    'nav.providers': 'Sağlayıcılar',
+    'palette.placeholder': 'Sayfa veya eylem yazın...',
+    'palette.empty': 'Eşleşen komut yok',
     'nav.memory': 'Hafiza',
     'nav.config': 'Yapilandirma',
     'nav.cost': 'Maliyet Takibi',
+    'nav.usage': 'Kullanim',
     'nav.logs': 'Kayitlar',
     'nav.doctor': 'Doktor',
 
@@ -0,0 +1,29 @@
+export type ToastLevel = 'info' | 'success' | 'warning' | 'error';
+
+export interface Toast {
+  id: number;
+  level: ToastLevel;
+  message: string;
+}
+
+export type ToastListener = (toast: Toast) => void;
+
+const listeners = new Set<ToastListener>();
+let nextId = 1;
+
+/**
+ * Show a toast in the dashboard corner. Background events from the gateway
+ * arrive the same way via `toast` SSE events.
+ */
+export function pushToast(message: string, level: ToastLevel = 'info'): void {
+  const toast: Toast = { id: nextId++, level, message };
+  listeners.forEach((listener) => listener(toast));
+}
+
+/** Subscribe to toasts. Returns the unsubscribe function. */
+export function onToast(listener: ToastListener): () => void {
+  listeners.add(listener);
+  return () => {
+    listeners.delete(listener);
+  };
+}
@@ -0,0 +1,175 @@
+/**
+ * Line-level helpers for the config editor: syntax tokens for highlighting
+ * and the scalar `key = value` lines that can be edited in place. This is not
+ * a TOML parser; the server validates the whole file.
+ */
+
+export type TokenKind = 'comment' | 'header' | 'key' | 'punct' | 'string' | 'literal' | 'text';
+
+export interface Token {
+  kind: TokenKind;
+  text: string;
+}
+
+export type ScalarKind = 'string' | 'integer' | 'float' | 'boolean';
+
+export interface ScalarField {
+  /** Zero-based line index in the file. */
+  line: number;
+  /** Dotted path, e.g. `gateway.port`. */
+  path: string;
+  kind: ScalarKind;
+  /** Decoded value as shown in an input. */
+  value: string;
+}
+
+const KEY_LINE = /^(\s*)([A-Za-z0-9_.\-"']+?)(\s*=\s*)(.*)$/;
+const HEADER_LINE = /^\s*(\[\[?)\s*([^\]]+?)\s*\]\]?\s*(#.*)?$/;
+
+/** Split `value` into the value itself and a trailing `# comment`. */
+function splitComment(value: string): [string, string] {
+  let quote: string | null = null;
+  for (let i = 0; i < value.length; i++) {
+    const ch = value[i];
+    if (quote) {
+      if (ch === '\\' && quote === '"') i++;
+      else if (ch === quote) quote = null;
+    } else if (ch === '"' || ch === "'") {
+      quote = ch;
+    } else if (ch === '#') {
+      return [value.slice(0, i).trimEnd(), value.slice(i)];
+    }
+  }
+  return [value.trimEnd(), ''];
+}
+
+/** Tokens for one line of TOML. */
+export function highlightLine(line: string): Token[] {
+  const trimmed = line.trimStart();
+  if (trimmed.startsWith('#')) return [{ kind: 'comment', text: line }];
+  if (trimmed.startsWith('[') && HEADER_LINE.test(line)) {
+    const [header, comment] = splitComment(line);
+    const tokens: Token[] = [{ kind: 'header', text: header }];
+    if (comment) tokens.push({ kind: 'comment', text: line.slice(header.length) });
+    return tokens;
+  }
+  const match = KEY_LINE.exec(line);
+  if (!match) return [{ kind: 'text', text: line }];
+  const [, indent, key, eq, rest] = match;
+  const [value, comment] = splitComment(rest);
+  const first = value.trimStart()[0];
+  const tokens: Token[] = [
+    { kind: 'text', text: indent },
+    { kind: 'key', text: key },
+    { kind: 'punct', text: eq },
+    { kind: first === '"' || first === "'" ? 'string' : 'literal', text: value },
+  ];
+  if (comment) tokens.push({ kind: 'comment', text: rest.slice(value.length) });
+  return tokens;
+}
+
+function decodeScalar(raw: string): { kind: ScalarKind; value: string } | null {
+  if (raw === 'true' || raw === 'false') return { kind: 'boolean', value: raw };
+  if (/^[+-]?\d[\d_]*$/.test(raw)) return { kind: 'integer', value: raw.replace(/_/g, '') };
+  if (/^[+-]?\d[\d_]*(\.\d[\d_]*)?([eE][+-]?\d+)?$/.test(raw)) {
+    return { kind: 'float', value: raw.replace(/_/g, '') };
+  }
+  if (raw.startsWith("'") && raw.endsWith("'") && raw.length >= 2 && !raw.startsWith("'''")) {
+    return { kind: 'string', value: raw.slice(1, -1) };
+  }
+  if (raw.startsWith('"') && raw.endsWith('"') && raw.length >= 2 && !raw.startsWith('"""')) {
+    try {
+      return { kind: 'string', value: JSON.parse(raw) as string };
+    } catch {
+      // TOML-only escapes (e.g. \U0001F600); leave the line to the file view.
+      return null;
+    }
+  }
+  return null;
+}
+
+/**
+ * Every single-line string, number and boolean in `text`, in file order.
+ * Arrays, inline tables, multi-line strings and keys inside `[[array]]`
+ * tables are left to the file view.
+ */
+export function scalarFields(text: string): ScalarField[] {
+  const fields: ScalarField[] = [];
+  let table: string | null = '';
+  let openArrays = 0;
+  let inMultiline: string | null = null;
+
+  text.split('\n').forEach((line, index) => {
+    if (inMultiline) {
+      if (line.includes(inMultiline)) inMultiline = null;
+      return;
+    }
+    if (openArrays > 0) {
+      const [value] = splitComment(line);
+      openArrays += (value.match(/\[/g) ?? []).length - (value.match(/]/g) ?? []).length;
+      return;
+    }
+    const header = HEADER_LINE.exec(line);
+    if (header) {
+      table = header[1] === '[[' ? null : header[2];
+      return;
+    }
+    const match = KEY_LINE.exec(line);
+    if (!match) return;
+    const [value] = splitComment(match[4]);
+    for (const delimiter of ['"""', "'''"]) {
+      if (value.startsWith(delimiter) && value.indexOf(delimiter, 3) === -1) {
+        inMultiline = delimiter;
+        return;
+      }
+    }
+    if (value.startsWith('[')) {
+      openArrays = (value.match(/\[/g) ?? []).length - (value.match(/]/g) ?? []).length;
+      return;
+    }
+    if (table === null) return;
+    const scalar = decodeScalar(value);
+    if (!scalar) return;
+    const key = match[2].replace(/["']/g, '');
+    fields.push({ line: index, path: table ? `${table}.${key}` : key, ...scalar });
+  });
+  return fields;
+}
+
+/** Encode `input` as a TOML value of `kind`, or throw with a reason. */
+export function encodeScalar(kind: ScalarKind, input: string): string {
+  const value = input.trim();
+  switch (kind) {
+    case 'boolean':
+      if (value !== 'true' && value !== 'false') throw new Error('expected true or false');
+      return value;
+    case 'integer':
+      if (!/^[+-]?\d+$/.test(value)) throw new Error(`expected an integer, got "${value}"`);
+      return value;
+    case 'float':
+      if (value === '' || !Number.isFinite(Number(value))) {
+        throw new Error(`expected a number, got "${value}"`);
+      }
+      return /[.eE]/.test(value) ? value : `${value}.0`;
+    case 'string':
+      // JSON string escapes are a subset of TOML basic-string escapes.
+      return JSON.stringify(input);
+  }
+}
+
+/** `text` with the value on `field.line` replaced, keeping any comment. */
+export function setScalar(text: string, field: ScalarField, input: string): string {
+  const lines = text.split('\n');
+  const match = KEY_LINE.exec(lines[field.line] ?? '');
+  if (!match) throw new Error(`line ${field.line + 1} is no longer a key = value line`);
+  const [, indent, key, eq, rest] = match;
+  const [value] = splitComment(rest);
+  lines[field.line] = `${indent}${key}${eq}${encodeScalar(field.kind, input)}${rest.slice(value.length)}`;
+  return lines.join('\n');
+}
+
+/** One-based line number named in a TOML parse error, if any. */
+export function errorLine(message: string): number | null {
+  const match = /line (\d+)/.exec(message);
+  return match ? Number(match[1]) : null;
+}
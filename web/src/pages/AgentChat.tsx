@@ -14,6 +14,7 @@ export default function AgentChat() {
   const [messages, setMessages] = useState<ChatMessage[]>([]);
   const [input, setInput] = useState('');
   const [typing, setTyping] = useState(false);
+  const [streaming, setStreaming] = useState('');
   const [connected, setConnected] = useState(false);
   const [error, setError] = useState<string | null>(null);
 
@@ -43,6 +44,7 @@ export default function AgentChat() {
         case 'chunk':
           setTyping(true);
           pendingContentRef.current += msg.content ?? '';
+          setStreaming(pendingContentRef.current);
           break;
 
         case 'message':
@@ -60,6 +62,7 @@ export default function AgentChat() {
             ]);
           }
           pendingContentRef.current = '';
+          setStreaming('');
           setTyping(false);
           break;
         }
@@ -100,6 +103,7 @@ export default function AgentChat() {
           ]);
           setTyping(false);
           pendingContentRef.current = '';
+          setStreaming('');
           break;
       }
     };
@@ -114,7 +118,7 @@ export default function AgentChat() {
 
   useEffect(() => {
     messagesEndRef.current?.scrollIntoView({ behavior: 'smooth' });
-  }, [messages, typing]);
+  }, [messages, typing, streaming]);
 
   const handleSend = () => {
     const trimmed = input.trim();
@@ -134,6 +138,7 @@ export default function AgentChat() {
       wsRef.current.sendMessage(trimmed);
       setTyping(true);
       pendingContentRef.current = '';
+      setStreaming('');
     } catch {
       setError('Failed to send message. Please try again.');
     }
@@ -208,7 +213,21 @@ export default function AgentChat() {
           </div>
         ))}
 
-        {typing && (
+        {typing && streaming && (
+          <div className="flex items-start gap-3">
+            <div className="flex-shrink-0 w-8 h-8 rounded-full bg-gray-700 flex items-center justify-center">
+              <Bot className="h-4 w-4 text-white" />
+            </div>
+            <div className="max-w-[75%] rounded-xl px-4 py-3 bg-gray-800 text-gray-100 border border-gray-700">
+              <p className="text-sm whitespace-pre-wrap break-words">
+                {streaming}
+                <span className="inline-block w-2 h-4 ml-0.5 align-text-bottom bg-gray-400 animate-pulse" />
+              </p>
+            </div>
+          </div>
+        )}
+
+        {typing && !streaming && (
           <div className="flex items-start gap-3">
             <div className="flex-shrink-0 w-8 h-8 rounded-full bg-gray-700 flex items-center justify-center">
               <Bot className="h-4 w-4 text-white" />
@@ -1,12 +1,102 @@
-import { useState, useEffect } from 'react';
+import { useState, useEffect, useMemo, useRef } from 'react';
 import {
   Settings,
   Save,
   CheckCircle,
   AlertTriangle,
   ShieldAlert,
+  Search,
+  FileText,
+  List,
 } from 'lucide-react';
-import { getConfig, putConfig } from '@/lib/api';
+import { getConfig, putConfig, validateConfig } from '@/lib/api';
+import {
+  highlightLine,
+  scalarFields,
+  setScalar,
+  errorLine,
+  type ScalarField,
+  type TokenKind,
+} from '@/lib/toml';
+import type { ConfigValidation } from '@/types/api';
+
+const TOKEN_CLASS: Record<TokenKind, string> = {
+  comment: 'text-gray-500',
+  header: 'text-blue-400 font-semibold',
+  key: 'text-sky-300',
+  punct: 'text-gray-500',
+  string: 'text-green-300',
+  literal: 'text-amber-300',
+  text: 'text-gray-200',
+};
+
+type View = 'file' | 'fields';
+
+function FieldRow({
+  field,
+  onCommit,
+}: {
+  field: ScalarField;
+  onCommit: (field: ScalarField, value: string) => string | null;
+}) {
+  const [draft, setDraft] = useState(field.value);
+  const [fieldError, setFieldError] = useState<string | null>(null);
+
+  useEffect(() => {
+    setDraft(field.value);
+    setFieldError(null);
+  }, [field.value]);
+
+  const commit = (value: string) => {
+    if (value === field.value) return;
+    setFieldError(onCommit(field, value));
+  };
+
+  return (
+    <div className="flex items-start gap-4 px-4 py-2 border-b border-gray-800/60">
+      <div className="w-1/3 min-w-0 pt-1.5">
+        <p className="text-sm font-mono text-sky-300 truncate" title={field.path}>
+          {field.path}
+        </p>
+        <p className="text-xs text-gray-500">{field.kind}</p>
+      </div>
+      <div className="flex-1 min-w-0">
+        {field.kind === 'boolean' ? (
+          <select
+            value={draft}
+            onChange={(e) => {
+              setDraft(e.target.value);
+              commit(e.target.value);
+            }}
+            className="px-2 py-1.5 bg-gray-800 border border-gray-700 rounded-lg text-sm text-white focus:outline-none focus:border-blue-500"
+          >
+            <option value="true">true</option>
+            <option value="false">false</option>
+          </select>
+        ) : (
+          <input
+            type="text"
+            value={draft}
+            onChange={(e) => setDraft(e.target.value)}
+            onBlur={() => commit(draft)}
+            onKeyDown={(e) => {
+              if (e.key === 'Enter') commit(draft);
+              if (e.key === 'Escape') {
+                setDraft(field.value);
+                setFieldError(null);
+              }
+            }}
+            spellCheck={false}
+            className={`w-full px-3 py-1.5 bg-gray-800 border rounded-lg text-sm font-mono text-white focus:outline-none ${
+              fieldError ? 'border-red-600' : 'border-gray-700 focus:border-blue-500'
+            }`}
+          />
+        )}
+        {fieldError && <p className="text-xs text-red-400 mt-1">{fieldError}</p>}
+      </div>
+    </div>
+  );
+}
 
 export default function Config() {
   const [config, setConfig] = useState('');
@@ -14,6 +104,11 @@ export default function Config() {
   const [saving, setSaving] = useState(false);
   const [error, setError] = useState<string | null>(null);
   const [success, setSuccess] = useState<string | null>(null);
+  const [validation, setValidation] = useState<ConfigValidation | null>(null);
+  const [validating, setValidating] = useState(false);
+  const [view, setView] = useState<View>('file');
+  const [fieldSearch, setFieldSearch] = useState('');
+  const highlightRef = useRef<HTMLPreElement>(null);
 
   useEffect(() => {
     getConfig()
@@ -25,13 +120,40 @@ export default function Config() {
       .finally(() => setLoading(false));
   }, []);
 
+  // Validate against the server's config schema shortly after each edit.
+  useEffect(() => {
+    if (loading) return;
+    let cancelled = false;
+    setValidating(true);
+    const timer = setTimeout(() => {
+      validateConfig(config)
+        .then((result) => {
+          if (!cancelled) setValidation(result);
+        })
+        .catch((err) => {
+          if (!cancelled) setError(err.message);
+        })
+        .finally(() => {
+          if (!cancelled) setValidating(false);
+        });
+    }, 500);
+    return () => {
+      cancelled = true;
+      clearTimeout(timer);
+    };
+  }, [config, loading]);
+
   const handleSave = async () => {
     setSaving(true);
     setError(null);
     setSuccess(null);
     try {
-      await putConfig(config);
-      setSuccess('Configuration saved successfully.');
+      const result = await putConfig(config);
+      setSuccess(
+        result?.backup
+          ? `Configuration saved. Previous file kept at ${result.backup}.`
+          : 'Configuration saved successfully.',
+      );
     } catch (err: unknown) {
       setError(err instanceof Error ? err.message : 'Failed to save configuration');
     } finally {
@@ -39,6 +161,15 @@ export default function Config() {
     }
   };
 
+  const handleFieldCommit = (field: ScalarField, value: string): string | null => {
+    try {
+      setConfig(setScalar(config, field, value));
+      return null;
+    } catch (err: unknown) {
+      return err instanceof Error ? err.message : String(err);
+    }
+  };
+
   // Auto-dismiss success after 4 seconds
   useEffect(() => {
     if (!success) return;
@@ -46,6 +177,15 @@ export default function Config() {
     return () => clearTimeout(timer);
   }, [success]);
 
+  const lines = useMemo(() => config.split('\n'), [config]);
+  const fields = useMemo(() => scalarFields(config), [config]);
+  const needle = fieldSearch.trim().toLowerCase();
+  const shownFields = needle
+    ? fields.filter((f) => f.path.toLowerCase().includes(needle))
+    : fields;
+  const badLine = validation?.error ? errorLine(validation.error) : null;
+  const invalid = validation !== null && !validation.valid;
+
   if (loading) {
     return (
       <div className="flex items-center justify-center h-64">
@@ -64,7 +204,8 @@ export default function Config() {
         </div>
         <button
           onClick={handleSave}
-          disabled={saving}
+          disabled={saving || validating || invalid}
+          title={invalid ? 'Fix the validation error before saving' : undefined}
           className="flex items-center gap-2 bg-blue-600 hover:bg-blue-700 text-white text-sm font-medium px-4 py-2 rounded-lg transition-colors disabled:opacity-50"
         >
           <Save className="h-4 w-4" />
@@ -102,23 +243,131 @@ export default function Config() {
         </div>
       )}
 
+      {/* Validation result */}
+      {invalid && (
+        <div className="flex items-start gap-2 bg-red-900/30 border border-red-700 rounded-lg p-3">
+          <AlertTriangle className="h-4 w-4 text-red-400 flex-shrink-0 mt-0.5" />
+          <pre className="text-sm text-red-300 whitespace-pre-wrap font-mono">
+            {validation?.error}
+          </pre>
+        </div>
+      )}
+      {validation?.valid && validation.unknown_keys.length > 0 && (
+        <div className="flex items-start gap-2 bg-yellow-900/20 border border-yellow-700/40 rounded-lg p-3">
+          <AlertTriangle className="h-4 w-4 text-yellow-400 flex-shrink-0 mt-0.5" />
+          <span className="text-sm text-yellow-300">
+            Unknown keys will be ignored on load:{' '}
+            <span className="font-mono">{validation.unknown_keys.join(', ')}</span>
+          </span>
+        </div>
+      )}
+
       {/* Config Editor */}
       <div className="bg-gray-900 rounded-xl border border-gray-800 overflow-hidden">
         <div className="flex items-center justify-between px-4 py-2 border-b border-gray-800 bg-gray-800/50">
-          <span className="text-xs text-gray-400 font-medium uppercase tracking-wider">
-            TOML Configuration
-          </span>
+          <div className="flex items-center gap-1">
+            {(
+              [
+                ['file', 'TOML Configuration', FileText],
+                ['fields', 'Values', List],
+              ] as const
+            ).map(([id, label, Icon]) => (
+              <button
+                key={id}
+                onClick={() => setView(id)}
+                className={`flex items-center gap-1.5 px-2.5 py-1 rounded-md text-xs font-medium uppercase tracking-wider transition-colors ${
+                  view === id ? 'bg-gray-700 text-white' : 'text-gray-400 hover:text-white'
+                }`}
+              >
+                <Icon className="h-3.5 w-3.5" />
+                {label}
+              </button>
+            ))}
+          </div>
           <span className="text-xs text-gray-500">
-            {config.split('\n').length} lines
+            {validating
+              ? 'Validating...'
+              : validation?.valid
+                ? 'Valid'
+                : invalid
+                  ? 'Invalid'
+                  : ''}
+            {' · '}
+            {view === 'file' ? `${lines.length} lines` : `${fields.length} values`}
           </span>
         </div>
-        <textarea
-          value={config}
-          onChange={(e) => setConfig(e.target.value)}
-          spellCheck={false}
-          className="w-full min-h-[500px] bg-gray-950 text-gray-200 font-mono text-sm p-4 resize-y focus:outline-none focus:ring-2 focus:ring-blue-500 focus:ring-inset"
-          style={{ tabSize: 4 }}
-        />
+
+        {view === 'file' ? (
+          <div className="relative h-[600px] bg-gray-950 font-mono text-sm">
+            {/* Highlighted copy of the text, kept under the transparent textarea */}
+            <pre
+              ref={highlightRef}
+              aria-hidden
+              className="absolute inset-0 m-0 p-4 overflow-hidden whitespace-pre pointer-events-none"
+              style={{ tabSize: 4 }}
+            >
+              {lines.map((line, index) => (
+                <div
+                  key={index}
+                  className={badLine === index + 1 ? 'bg-red-900/40' : undefined}
+                >
+                  {highlightLine(line).map((token, i) => (
+                    <span key={i} className={TOKEN_CLASS[token.kind]}>
+                      {token.text}
+                    </span>
+                  ))}
+                  {line === '' && ' '}
+                </div>
+              ))}
+            </pre>
+            <textarea
+              value={config}
+              onChange={(e) => setConfig(e.target.value)}
+              onScroll={(e) => {
+                if (highlightRef.current) {
+                  highlightRef.current.scrollTop = e.currentTarget.scrollTop;
+                  highlightRef.current.scrollLeft = e.currentTarget.scrollLeft;
+                }
+              }}
+              spellCheck={false}
+              wrap="off"
+              className="absolute inset-0 w-full h-full bg-transparent text-transparent caret-white p-4 resize-none overflow-auto whitespace-pre focus:outline-none focus:ring-2 focus:ring-blue-500 focus:ring-inset"
+              style={{ tabSize: 4 }}
+            />
+          </div>
+        ) : (
+          <div>
+            <div className="px-4 py-2 border-b border-gray-800">
+              <div className="relative">
+                <Search className="absolute left-2.5 top-1/2 -translate-y-1/2 h-3.5 w-3.5 text-gray-500" />
+                <input
+                  type="text"
+                  value={fieldSearch}
+                  onChange={(e) => setFieldSearch(e.target.value)}
+                  placeholder="Filter by key, e.g. gateway.port"
+                  className="w-full pl-8 pr-3 py-1.5 bg-gray-800 border border-gray-700 rounded-lg text-sm text-white placeholder-gray-500 focus:outline-none focus:border-blue-500"
+                />
+              </div>
+              <p className="text-xs text-gray-500 mt-2">
+                Strings, numbers and booleans keep their type. Arrays, inline
+                tables and <span className="font-mono">[[array]]</span> entries are
+                edited in the TOML view.
+              </p>
+            </div>
+            <div className="max-h-[600px] overflow-y-auto">
+              {shownFields.map((field) => (
+                <FieldRow
+                  key={`${field.line}:${field.path}`}
+                  field={field}
+                  onCommit={handleFieldCommit}
+                />
+              ))}
+              {shownFields.length === 0 && (
+                <p className="px-4 py-6 text-sm text-gray-500">No matching values.</p>
+              )}
+            </div>
+          </div>
+        )}
       </div>
     </div>
   );
@@ -7,9 +7,13 @@ import {
   Activity,
   DollarSign,
   Radio,
+  Zap,
+  AlertTriangle,
+  Bug,
 } from 'lucide-react';
 import type { StatusResponse, CostSummary } from '@/types/api';
-import { getStatus, getCost } from '@/lib/api';
+import { getStatus, getCost, setDebugMode } from '@/lib/api';
+import { pushToast } from '@/lib/toast';
 
 function formatUptime(seconds: number): string {
   const d = Math.floor(seconds / 86400);
@@ -20,6 +24,9 @@ function formatUptime(seconds: number): string {
   return `${m}m`;
 }
 
+/** How often the home screen re-reads /api/status and /api/cost. */
+const REFRESH_MS = 5000;
+
 function formatUSD(value: number): string {
   return `$${value.toFixed(4)}`;
 }
@@ -56,17 +63,42 @@ export default function Dashboard() {
   const [status, setStatus] = useState<StatusResponse | null>(null);
   const [cost, setCost] = useState<CostSummary | null>(null);
   const [error, setError] = useState<string | null>(null);
+  const [debugBusy, setDebugBusy] = useState(false);
 
   useEffect(() => {
-    Promise.all([getStatus(), getCost()])
-      .then(([s, c]) => {
-        setStatus(s);
-        setCost(c);
-      })
-      .catch((err) => setError(err.message));
+    let cancelled = false;
+    const load = () =>
+      Promise.all([getStatus(), getCost()])
+        .then(([s, c]) => {
+          if (cancelled) return;
+          setStatus(s);
+          setCost(c);
+          setError(null);
+        })
+        .catch((err) => {
+          if (!cancelled) setError(err.message);
+        });
+    load();
+    const timer = setInterval(load, REFRESH_MS);
+    return () => {
+      cancelled = true;
+      clearInterval(timer);
+    };
   }, []);
 
-  if (error) {
+  const toggleDebug = () => {
+    if (!status) return;
+    setDebugBusy(true);
+    setDebugMode(!status.debug.enabled)
+      .then((debug) => {
+        setStatus({ ...status, debug });
+        pushToast(`Debug mode ${debug.enabled ? 'on' : 'off'}`, 'success');
+      })
+      .catch((err) => pushToast(`Failed to switch debug mode: ${err.message}`, 'error'))
+      .finally(() => setDebugBusy(false));
+  };
+
+  if (error && (!status || !cost)) {
     return (
       <div className="p-6">
         <div className="rounded-lg bg-red-900/30 border border-red-700 p-4 text-red-300">
@@ -85,6 +117,9 @@ export default function Dashboard() {
   }
 
   const maxCost = Math.max(cost.session_cost_usd, cost.daily_cost_usd, cost.monthly_cost_usd, 0.001);
+  const metrics = status.metrics;
+  const tokensToday = Object.entries(metrics.tokens_today);
+  const maxTokens = Math.max(...tokensToday.map(([, n]) => n), 1);
 
   return (
     <div className="p-6 space-y-6">
@@ -140,7 +175,10 @@ export default function Dashboard() {
             {status.memory_backend}
           </p>
           <p className="text-sm text-gray-400">
-            Paired: {status.paired ? 'Yes' : 'No'}
+            {metrics.memory_entries === null
+              ? 'Entries: unavailable'
+              : `${metrics.memory_entries.toLocaleString()} entries`}
+            {' · '}Paired: {status.paired ? 'Yes' : 'No'}
           </p>
         </div>
       </div>
@@ -205,7 +243,9 @@ export default function Dashboard() {
                       }`}
                     />
                     <span className="text-xs text-gray-400">
-                      {active ? 'Active' : 'Inactive'}
+                      {active
+                        ? `${(metrics.channel_messages[name] ?? 0).toLocaleString()} msgs`
+                        : 'Inactive'}
                     </span>
                   </div>
                 </div>
@@ -247,6 +287,78 @@ export default function Dashboard() {
           </div>
         </div>
       </div>
+
+      <div className="grid grid-cols-1 lg:grid-cols-2 gap-6">
+        {/* Tokens Today */}
+        <div className="bg-gray-900 rounded-xl p-5 border border-gray-800">
+          <div className="flex items-center gap-2 mb-4">
+            <Zap className="h-5 w-5 text-blue-400" />
+            <h2 className="text-base font-semibold text-white">Tokens Today</h2>
+          </div>
+          {tokensToday.length === 0 ? (
+            <p className="text-sm text-gray-500">No tokens used today</p>
+          ) : (
+            <div className="space-y-3">
+              {tokensToday.map(([provider, tokens]) => (
+                <div key={provider}>
+                  <div className="flex justify-between text-sm mb-1">
+                    <span className="text-gray-400">{provider}</span>
+                    <span className="text-white font-medium">{tokens.toLocaleString()}</span>
+                  </div>
+                  <div className="w-full h-2 bg-gray-800 rounded-full overflow-hidden">
+                    <div
+                      className="h-full rounded-full bg-blue-500"
+                      style={{ width: `${Math.max((tokens / maxTokens) * 100, 2)}%` }}
+                    />
+                  </div>
+                </div>
+              ))}
+            </div>
+          )}
+        </div>
+
+        {/* Last Error */}
+        <div className="bg-gray-900 rounded-xl p-5 border border-gray-800">
+          <div className="flex items-center gap-2 mb-4">
+            <AlertTriangle className="h-5 w-5 text-blue-400" />
+            <h2 className="text-base font-semibold text-white">Last Error</h2>
+            <button
+              onClick={toggleDebug}
+              disabled={debugBusy}
+              title="Raise log verbosity and record LLM transcripts without restarting"
+              className={`ml-auto flex items-center gap-1.5 px-3 py-1 rounded-lg text-xs font-medium transition-colors disabled:opacity-50 ${
+                status.debug.enabled
+                  ? 'bg-yellow-600/20 text-yellow-300 border border-yellow-600/40'
+                  : 'bg-gray-800 text-gray-400 border border-gray-700 hover:text-white'
+              }`}
+            >
+              <Bug className="h-3.5 w-3.5" />
+              Debug {status.debug.enabled ? 'on' : 'off'}
+            </button>
+          </div>
+          {status.debug.enabled && status.debug.recording_dir && (
+            <p className="text-xs text-yellow-400 mb-3 break-all">
+              Recording LLM transcripts to {status.debug.recording_dir}
+            </p>
+          )}
+          {metrics.last_error ? (
+            <div className="rounded-lg p-3 border border-red-500/30 bg-gray-800/50">
+              <div className="flex justify-between text-xs mb-1">
+                <span className="text-red-400 font-medium">{metrics.last_error.source}</span>
+                <span className="text-gray-500">
+                  {new Date(metrics.last_error.at).toLocaleString()}
+                </span>
+              </div>
+              <p className="text-sm text-gray-300 break-words">{metrics.last_error.message}</p>
+            </div>
+          ) : (
+            <p className="text-sm text-gray-500">No errors since start</p>
+          )}
+          {error && (
+            <p className="text-xs text-yellow-400 mt-3">Refresh failed: {error}</p>
+          )}
+        </div>
+      </div>
     </div>
   );
 }
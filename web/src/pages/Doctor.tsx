@@ -6,9 +6,12 @@ import {
   AlertTriangle,
   XCircle,
   Loader2,
+  ChevronRight,
+  Wrench,
 } from 'lucide-react';
-import type { DiagResult } from '@/types/api';
-import { runDoctor } from '@/lib/api';
+import type { DiagResult, FixAction } from '@/types/api';
+import { runDoctor, applyDoctorFix } from '@/lib/api';
+import { pushToast } from '@/lib/toast';
 
 function severityIcon(severity: DiagResult['severity']) {
   switch (severity) {
@@ -47,6 +50,29 @@ export default function Doctor() {
   const [results, setResults] = useState<DiagResult[] | null>(null);
   const [loading, setLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
+  const [expanded, setExpanded] = useState<Set<string>>(new Set());
+  const [fixing, setFixing] = useState<FixAction | null>(null);
+
+  const toggleExpanded = (key: string) =>
+    setExpanded((prev) => {
+      const next = new Set(prev);
+      if (next.has(key)) next.delete(key);
+      else next.add(key);
+      return next;
+    });
+
+  const handleFix = async (action: FixAction) => {
+    setFixing(action);
+    try {
+      const data = await applyDoctorFix(action);
+      setResults(data.results);
+      pushToast(data.message, 'success');
+    } catch (err: unknown) {
+      pushToast(err instanceof Error ? err.message : 'Fix failed', 'error');
+    } finally {
+      setFixing(null);
+    }
+  };
 
   const handleRun = async () => {
     setLoading(true);
@@ -180,22 +206,66 @@ export default function Doctor() {
                   {category}
                 </h3>
                 <div className="space-y-2">
-                  {items.map((result, idx) => (
-                    <div
-                      key={`${category}-${idx}`}
-                      className={`flex items-start gap-3 rounded-lg border p-3 ${severityBorder(
-                        result.severity,
-                      )} ${severityBg(result.severity)}`}
-                    >
-                      {severityIcon(result.severity)}
-                      <div className="min-w-0">
-                        <p className="text-sm text-white">{result.message}</p>
-                        <p className="text-xs text-gray-500 mt-0.5 capitalize">
-                          {result.severity}
-                        </p>
+                  {items.map((result, idx) => {
+                    const key = `${category}-${idx}`;
+                    const open = expanded.has(key);
+                    const expandable = result.fix !== null;
+                    return (
+                      <div
+                        key={key}
+                        className={`rounded-lg border ${severityBorder(
+                          result.severity,
+                        )} ${severityBg(result.severity)}`}
+                      >
+                        <button
+                          type="button"
+                          onClick={() => expandable && toggleExpanded(key)}
+                          className={`w-full flex items-start gap-3 p-3 text-left ${
+                            expandable ? 'cursor-pointer' : 'cursor-default'
+                          }`}
+                        >
+                          {severityIcon(result.severity)}
+                          <div className="min-w-0 flex-1">
+                            <p className="text-sm text-white">
+                              <span className="font-medium capitalize">{result.name}</span>
+                              <span className="text-gray-400"> — {result.message}</span>
+                            </p>
+                            <p className="text-xs text-gray-500 mt-0.5 capitalize">
+                              {result.severity}
+                              {result.action && ' · fixable'}
+                            </p>
+                          </div>
+                          {expandable && (
+                            <ChevronRight
+                              className={`h-4 w-4 text-gray-500 flex-shrink-0 transition-transform ${
+                                open ? 'rotate-90' : ''
+                              }`}
+                            />
+                          )}
+                        </button>
+                        {open && result.fix && (
+                          <div className="flex items-center justify-between gap-3 px-3 pb-3 pl-10">
+                            <p className="text-sm text-gray-300">{result.fix}</p>
+                            {result.action && (
+                              <button
+                                type="button"
+                                onClick={() => handleFix(result.action!)}
+                                disabled={fixing !== null}
+                                className="flex-shrink-0 inline-flex items-center gap-1.5 px-3 py-1.5 rounded-md bg-blue-600 hover:bg-blue-700 text-white text-xs font-medium transition-colors disabled:opacity-50"
+                              >
+                                {fixing === result.action ? (
+                                  <Loader2 className="h-3.5 w-3.5 animate-spin" />
+                                ) : (
+                                  <Wrench className="h-3.5 w-3.5" />
+                                )}
+                                Fix it
+                              </button>
+                            )}
+                          </div>
+                        )}
                       </div>
-                    </div>
-                  ))}
+                    );
+                  })}
                 </div>
               </div>
             ))}
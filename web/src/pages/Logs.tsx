@@ -5,6 +5,7 @@ import {
   Play,
   ArrowDown,
   Filter,
+  Search,
 } from 'lucide-react';
 import type { SSEEvent } from '@/types/api';
 import { SSEClient } from '@/lib/sse';
@@ -35,9 +36,41 @@ function eventTypeBadgeColor(type: string): string {
   }
 }
 
+const LEVELS = ['error', 'warn', 'info', 'debug'] as const;
+type Level = (typeof LEVELS)[number];
+
+/**
+ * Severity of an event: its own `level` when it carries one (toasts), else
+ * derived from the type. Per-request and tool chatter counts as debug.
+ */
+function eventLevel(event: SSEEvent): Level {
+  const raw = String(event.level ?? event.type).toLowerCase();
+  if (raw === 'error') return 'error';
+  if (raw === 'warn' || raw === 'warning') return 'warn';
+  if (['debug', 'trace', 'llm_request', 'tool_call', 'tool_call_start', 'tool_result'].includes(raw)) {
+    return 'debug';
+  }
+  return 'info';
+}
+
+function eventDetail(event: SSEEvent): string {
+  const detail =
+    event.message ??
+    event.content ??
+    event.data ??
+    JSON.stringify(
+      Object.fromEntries(
+        Object.entries(event).filter(([k]) => k !== 'type' && k !== 'timestamp'),
+      ),
+    );
+  return typeof detail === 'string' ? detail : JSON.stringify(detail);
+}
+
 interface LogEntry {
   id: string;
   event: SSEEvent;
+  level: Level;
+  detail: string;
 }
 
 export default function Logs() {
@@ -46,6 +79,8 @@ export default function Logs() {
   const [connected, setConnected] = useState(false);
   const [autoScroll, setAutoScroll] = useState(true);
   const [typeFilters, setTypeFilters] = useState<Set<string>>(new Set());
+  const [minLevel, setMinLevel] = useState<Level>('info');
+  const [search, setSearch] = useState('');
 
   const containerRef = useRef<HTMLDivElement>(null);
   const sseRef = useRef<SSEClient | null>(null);
@@ -74,6 +109,8 @@ export default function Logs() {
       const entry: LogEntry = {
         id: `log-${entryIdRef.current}`,
         event,
+        level: eventLevel(event),
+        detail: eventDetail(event),
       };
       setEntries((prev) => {
         // Cap at 500 entries for performance
@@ -97,7 +134,7 @@ export default function Logs() {
     }
   }, [entries, autoScroll]);
 
-  // Detect user scroll to toggle auto-scroll
+  // Scrolling up stops following; scrolling back to the bottom resumes it.
   const handleScroll = useCallback(() => {
     if (!containerRef.current) return;
     const { scrollTop, scrollHeight, clientHeight } = containerRef.current;
@@ -127,10 +164,16 @@ export default function Logs() {
     });
   };
 
-  const filteredEntries =
-    typeFilters.size === 0
-      ? entries
-      : entries.filter((e) => typeFilters.has(e.event.type));
+  const maxLevelIndex = LEVELS.indexOf(minLevel);
+  const needle = search.trim().toLowerCase();
+  const filteredEntries = entries.filter(
+    (e) =>
+      (typeFilters.size === 0 || typeFilters.has(e.event.type)) &&
+      LEVELS.indexOf(e.level) <= maxLevelIndex &&
+      (!needle ||
+        e.detail.toLowerCase().includes(needle) ||
+        e.event.type.toLowerCase().includes(needle)),
+  );
 
   return (
     <div className="flex flex-col h-[calc(100vh-3.5rem)]">
@@ -155,6 +198,44 @@ export default function Logs() {
         </div>
 
         <div className="flex items-center gap-2">
+          {/* Search */}
+          <div className="relative">
+            <Search className="absolute left-2.5 top-1/2 -translate-y-1/2 h-3.5 w-3.5 text-gray-500" />
+            <input
+              type="text"
+              value={search}
+              onChange={(e) => setSearch(e.target.value)}
+              placeholder="Search events..."
+              className="w-48 pl-8 pr-3 py-1.5 bg-gray-800 border border-gray-700 rounded-lg text-sm text-white placeholder-gray-500 focus:outline-none focus:border-blue-500"
+            />
+          </div>
+
+          {/* Level filter: shows the chosen level and everything more severe */}
+          <select
+            value={minLevel}
+            onChange={(e) => setMinLevel(e.target.value as Level)}
+            className="px-2 py-1.5 bg-gray-800 border border-gray-700 rounded-lg text-sm text-white focus:outline-none focus:border-blue-500"
+          >
+            {LEVELS.map((level) => (
+              <option key={level} value={level}>
+                {level}
+              </option>
+            ))}
+          </select>
+
+          {/* Follow */}
+          <button
+            onClick={() => (autoScroll ? setAutoScroll(false) : jumpToBottom())}
+            className={`flex items-center gap-1.5 px-3 py-1.5 rounded-lg text-sm font-medium transition-colors ${
+              autoScroll
+                ? 'bg-blue-600 hover:bg-blue-700 text-white'
+                : 'bg-gray-800 hover:bg-gray-700 text-gray-300 border border-gray-700'
+            }`}
+          >
+            <ArrowDown className="h-3.5 w-3.5" />
+            {autoScroll ? 'Following' : 'Follow'}
+          </button>
+
           {/* Pause/Resume */}
           <button
             onClick={() => setPaused(!paused)}
@@ -174,17 +255,6 @@ export default function Logs() {
               </>
             )}
           </button>
-
-          {/* Jump to Bottom */}
-          {!autoScroll && (
-            <button
-              onClick={jumpToBottom}
-              className="flex items-center gap-1.5 px-3 py-1.5 rounded-lg text-sm font-medium bg-blue-600 hover:bg-blue-700 text-white transition-colors"
-            >
-              <ArrowDown className="h-3.5 w-3.5" />
-              Jump to bottom
-            </button>
-          )}
         </div>
       </div>
 
@@ -230,23 +300,14 @@ export default function Logs() {
             <p className="text-sm">
               {paused
                 ? 'Log streaming is paused.'
-                : 'Waiting for events...'}
+                : entries.length > 0
+                  ? 'No events match the current filters.'
+                  : 'Waiting for events...'}
             </p>
           </div>
         ) : (
           filteredEntries.map((entry) => {
-            const { event } = entry;
-            const detail =
-              event.message ??
-              event.content ??
-              event.data ??
-              JSON.stringify(
-                Object.fromEntries(
-                  Object.entries(event).filter(
-                    ([k]) => k !== 'type' && k !== 'timestamp',
-                  ),
-                ),
-              );
+            const { event, detail } = entry;
 
             return (
               <div
@@ -264,9 +325,7 @@ export default function Logs() {
                   >
                     {event.type}
                   </span>
-                  <p className="text-sm text-gray-300 break-all min-w-0">
-                    {typeof detail === 'string' ? detail : JSON.stringify(detail)}
-                  </p>
+                  <p className="text-sm text-gray-300 break-all min-w-0">{detail}</p>
                 </div>
               </div>
             );
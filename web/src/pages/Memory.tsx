@@ -6,9 +6,17 @@ import {
   Trash2,
   X,
   Filter,
+  Eye,
+  Pin,
+  ChevronLeft,
+  ChevronRight,
 } from 'lucide-react';
 import type { MemoryEntry } from '@/types/api';
-import { getMemory, storeMemory, deleteMemory } from '@/lib/api';
+import { getMemoryPage, storeMemory, deleteMemory, pinMemory } from '@/lib/api';
+import { pushToast } from '@/lib/toast';
+
+const PAGE_SIZE = 25;
+const BUILTIN_CATEGORIES = ['core', 'daily', 'conversation'];
 
 function truncate(text: string, max: number): string {
   if (text.length <= max) return text;
@@ -22,12 +30,17 @@ function formatDate(iso: string): string {
 
 export default function Memory() {
   const [entries, setEntries] = useState<MemoryEntry[]>([]);
+  const [total, setTotal] = useState(0);
+  const [page, setPage] = useState(0);
   const [loading, setLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
   const [search, setSearch] = useState('');
   const [categoryFilter, setCategoryFilter] = useState('');
+  const [sessionFilter, setSessionFilter] = useState('');
   const [showForm, setShowForm] = useState(false);
   const [confirmDelete, setConfirmDelete] = useState<string | null>(null);
+  const [viewing, setViewing] = useState<MemoryEntry | null>(null);
+  const [knownCategories, setKnownCategories] = useState<string[]>(BUILTIN_CATEGORIES);
 
   // Form state
   const [formKey, setFormKey] = useState('');
@@ -36,27 +49,41 @@ export default function Memory() {
   const [formError, setFormError] = useState<string | null>(null);
   const [submitting, setSubmitting] = useState(false);
 
-  const fetchEntries = (q?: string, cat?: string) => {
+  const fetchEntries = (toPage = page) => {
     setLoading(true);
-    getMemory(q || undefined, cat || undefined)
-      .then(setEntries)
+    getMemoryPage({
+      query: search.trim() || undefined,
+      category: categoryFilter || undefined,
+      session: sessionFilter.trim() || undefined,
+      limit: PAGE_SIZE,
+      offset: toPage * PAGE_SIZE,
+    })
+      .then((result) => {
+        setEntries(result.entries);
+        setTotal(result.total);
+        setPage(toPage);
+        setError(null);
+        setKnownCategories((prev) =>
+          Array.from(new Set([...prev, ...result.entries.map((e) => e.category)])).sort(),
+        );
+      })
       .catch((err) => setError(err.message))
       .finally(() => setLoading(false));
   };
 
   useEffect(() => {
-    fetchEntries();
+    fetchEntries(0);
   }, []);
 
   const handleSearch = () => {
-    fetchEntries(search, categoryFilter);
+    fetchEntries(0);
   };
 
   const handleKeyDown = (e: React.KeyboardEvent) => {
     if (e.key === 'Enter') handleSearch();
   };
 
-  const categories = Array.from(new Set(entries.map((e) => e.category))).sort();
+  const pageCount = Math.max(1, Math.ceil(total / PAGE_SIZE));
 
   const handleAdd = async () => {
     if (!formKey.trim() || !formContent.trim()) {
@@ -71,7 +98,7 @@ export default function Memory() {
         formContent.trim(),
         formCategory.trim() || undefined,
       );
-      fetchEntries(search, categoryFilter);
+      fetchEntries();
       setShowForm(false);
       setFormKey('');
       setFormContent('');
@@ -86,7 +113,9 @@ export default function Memory() {
   const handleDelete = async (key: string) => {
     try {
       await deleteMemory(key);
-      setEntries((prev) => prev.filter((e) => e.key !== key));
+      setViewing(null);
+      // Step back when the last entry on a page goes away.
+      fetchEntries(entries.length === 1 && page > 0 ? page - 1 : page);
     } catch (err: unknown) {
       setError(err instanceof Error ? err.message : 'Failed to delete memory');
     } finally {
@@ -94,6 +123,20 @@ export default function Memory() {
     }
   };
 
+  const handlePin = async (entry: MemoryEntry) => {
+    try {
+      await pinMemory(entry.key);
+      pushToast(`Pinned ${entry.key} to core`, 'success');
+      setViewing((prev) => (prev?.key === entry.key ? { ...prev, category: 'core' } : prev));
+      fetchEntries();
+    } catch (err: unknown) {
+      pushToast(
+        `Failed to pin ${entry.key}: ${err instanceof Error ? err.message : String(err)}`,
+        'error',
+      );
+    }
+  };
+
   if (error && entries.length === 0) {
     return (
       <div className="p-6">
@@ -111,7 +154,7 @@ export default function Memory() {
         <div className="flex items-center gap-2">
           <Brain className="h-5 w-5 text-blue-400" />
           <h2 className="text-base font-semibold text-white">
-            Memory ({entries.length})
+            Memory ({total})
           </h2>
         </div>
         <button
@@ -144,13 +187,21 @@ export default function Memory() {
             className="bg-gray-900 border border-gray-700 rounded-lg pl-10 pr-8 py-2.5 text-sm text-white appearance-none focus:outline-none focus:ring-2 focus:ring-blue-500 cursor-pointer"
           >
             <option value="">All Categories</option>
-            {categories.map((cat) => (
+            {knownCategories.map((cat) => (
               <option key={cat} value={cat}>
                 {cat}
               </option>
             ))}
           </select>
         </div>
+        <input
+          type="text"
+          value={sessionFilter}
+          onChange={(e) => setSessionFilter(e.target.value)}
+          onKeyDown={handleKeyDown}
+          placeholder="Session ID"
+          className="sm:w-48 bg-gray-900 border border-gray-700 rounded-lg px-3 py-2.5 text-sm text-white placeholder-gray-500 focus:outline-none focus:ring-2 focus:ring-blue-500"
+        />
         <button
           onClick={handleSearch}
           className="px-4 py-2.5 bg-blue-600 hover:bg-blue-700 text-white text-sm font-medium rounded-lg transition-colors"
@@ -250,6 +301,63 @@ export default function Memory() {
         </div>
       )}
 
+      {/* Entry detail */}
+      {viewing && (
+        <div
+          className="fixed inset-0 bg-black/60 flex items-center justify-center z-50"
+          onClick={() => setViewing(null)}
+        >
+          <div
+            className="bg-gray-900 border border-gray-700 rounded-xl p-6 w-full max-w-2xl mx-4 max-h-[80vh] flex flex-col"
+            onClick={(e) => e.stopPropagation()}
+          >
+            <div className="flex items-center justify-between mb-4">
+              <h3 className="text-lg font-semibold text-white font-mono break-all">
+                {viewing.key}
+              </h3>
+              <button
+                onClick={() => setViewing(null)}
+                className="text-gray-400 hover:text-white transition-colors"
+              >
+                <X className="h-5 w-5" />
+              </button>
+            </div>
+            <dl className="grid grid-cols-3 gap-y-2 text-sm mb-4">
+              <dt className="text-gray-400">Category</dt>
+              <dd className="col-span-2 text-gray-200 capitalize">{viewing.category}</dd>
+              <dt className="text-gray-400">Session</dt>
+              <dd className="col-span-2 text-gray-200 font-mono">{viewing.session_id ?? '—'}</dd>
+              <dt className="text-gray-400">Timestamp</dt>
+              <dd className="col-span-2 text-gray-200">{formatDate(viewing.timestamp)}</dd>
+            </dl>
+            <pre className="flex-1 overflow-y-auto whitespace-pre-wrap break-words bg-gray-800 rounded-lg p-3 text-sm text-gray-200">
+              {viewing.content}
+            </pre>
+            <div className="flex justify-end gap-3 mt-4">
+              {viewing.category !== 'core' && (
+                <button
+                  onClick={() => handlePin(viewing)}
+                  className="flex items-center gap-2 px-4 py-2 text-sm font-medium text-gray-300 hover:text-white border border-gray-700 rounded-lg hover:bg-gray-800 transition-colors"
+                >
+                  <Pin className="h-4 w-4" />
+                  Pin to core
+                </button>
+              )}
+              <button
+                onClick={() => {
+                  setConfirmDelete(viewing.key);
+                  setViewing(null);
+                }}
+                className="flex items-center gap-2 px-4 py-2 text-sm font-medium text-red-400 hover:text-red-300 border border-red-800 rounded-lg hover:bg-red-900/30 transition-colors"
+              >
+                <Trash2 className="h-4 w-4" />
+                Delete
+              </button>
+            </div>
+          </div>
+        </div>
+      )}
+
       {/* Memory Table */}
       {loading ? (
         <div className="flex items-center justify-center h-32">
@@ -322,18 +430,60 @@ export default function Memory() {
                         </button>
                       </div>
                     ) : (
-                      <button
-                        onClick={() => setConfirmDelete(entry.key)}
-                        className="text-gray-400 hover:text-red-400 transition-colors"
-                      >
-                        <Trash2 className="h-4 w-4" />
-                      </button>
+                      <div className="flex items-center justify-end gap-3">
+                        <button
+                          onClick={() => setViewing(entry)}
+                          title="View"
+                          className="text-gray-400 hover:text-white transition-colors"
+                        >
+                          <Eye className="h-4 w-4" />
+                        </button>
+                        {entry.category !== 'core' && (
+                          <button
+                            onClick={() => handlePin(entry)}
+                            title="Pin to core"
+                            className="text-gray-400 hover:text-blue-400 transition-colors"
+                          >
+                            <Pin className="h-4 w-4" />
+                          </button>
+                        )}
+                        <button
+                          onClick={() => setConfirmDelete(entry.key)}
+                          title="Delete"
+                          className="text-gray-400 hover:text-red-400 transition-colors"
+                        >
+                          <Trash2 className="h-4 w-4" />
+                        </button>
+                      </div>
                     )}
                   </td>
                 </tr>
               ))}
             </tbody>
           </table>
+          {pageCount > 1 && (
+            <div className="flex items-center justify-between px-4 py-3 border-t border-gray-800 text-sm text-gray-400">
+              <span>
+                Page {page + 1} of {pageCount}
+              </span>
+              <div className="flex items-center gap-2">
+                <button
+                  onClick={() => fetchEntries(page - 1)}
+                  disabled={page === 0}
+                  className="p-1.5 rounded-lg border border-gray-700 hover:bg-gray-800 disabled:opacity-40 transition-colors"
+                >
+                  <ChevronLeft className="h-4 w-4" />
+                </button>
+                <button
+                  onClick={() => fetchEntries(page + 1)}
+                  disabled={page + 1 >= pageCount}
+                  className="p-1.5 rounded-lg border border-gray-700 hover:bg-gray-800 disabled:opacity-40 transition-colors"
+                >
+                  <ChevronRight className="h-4 w-4" />
+                </button>
+              </div>
+            </div>
+          )}
         </div>
       )}
     </div>
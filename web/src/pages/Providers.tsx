@@ -0,0 +1,293 @@
+import { useState, useEffect, useMemo, useRef } from 'react';
+import { Server, Search, Star, HardDrive, Cloud, Cpu } from 'lucide-react';
+import type { ProviderInfo, ProviderModels } from '@/types/api';
+import { getProviderModels, getProviders, setDefaultProvider } from '@/lib/api';
+import { useScrollSelectedIntoView, useWheelSelect } from '@/hooks/useWheelSelect';
+import { fuzzyFilter } from '@/lib/fuzzy';
+import { pushToast } from '@/lib/toast';
+
+export default function Providers() {
+  const [providers, setProviders] = useState<ProviderInfo[]>([]);
+  const [loading, setLoading] = useState(true);
+  const [error, setError] = useState<string | null>(null);
+  const [query, setQuery] = useState('');
+  const [highlighted, setHighlighted] = useState<string | null>(null);
+  const [saving, setSaving] = useState(false);
+  const listRef = useRef<HTMLDivElement>(null);
+  const [models, setModels] = useState<ProviderModels | null>(null);
+  const [modelChoice, setModelChoice] = useState('');
+
+  const load = () =>
+    getProviders()
+      .then(setProviders)
+      .catch((err) => setError(err.message))
+      .finally(() => setLoading(false));
+
+  useEffect(() => {
+    load();
+  }, []);
+
+  const filtered = useMemo(
+    () =>
+      fuzzyFilter(providers, query, (p) => [p.name, p.display_name, ...p.aliases]),
+    [providers, query],
+  );
+
+  // Keep the highlight on a visible row.
+  const selected =
+    filtered.find((p) => p.name === highlighted) ?? filtered[0] ?? null;
+
+  const moveHighlight = (delta: number) => {
+    if (filtered.length === 0) return;
+    const index = selected ? filtered.indexOf(selected) : -1;
+    const next = Math.min(Math.max(index + delta, 0), filtered.length - 1);
+    setHighlighted(filtered[next]!.name);
+  };
+
+  useWheelSelect(listRef, moveHighlight, !loading);
+  useScrollSelectedIntoView(listRef, selected?.name);
+
+  // Load the model catalog for the model switcher whenever the selection moves.
+  useEffect(() => {
+    if (!selected) return;
+    let cancelled = false;
+    setModels(null);
+    getProviderModels(selected.name)
+      .then((result) => {
+        if (cancelled) return;
+        setModels(result);
+        setModelChoice(selected.active_model ?? result.models[0]?.id ?? '');
+      })
+      .catch((err) => {
+        if (!cancelled) pushToast(`Failed to load models: ${err.message}`, 'error');
+      });
+    return () => {
+      cancelled = true;
+    };
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [selected?.name, selected?.active_model]);
+
+  const makeDefault = async (provider: ProviderInfo, model?: string) => {
+    setSaving(true);
+    try {
+      const result = await setDefaultProvider(provider.name, model);
+      pushToast(
+        `Default provider set to ${provider.display_name}` +
+          (result.model ? ` (model ${result.model})` : ''),
+        'success',
+      );
+      await load();
+    } catch (err: unknown) {
+      pushToast(
+        `Failed to set default provider: ${err instanceof Error ? err.message : String(err)}`,
+        'error',
+      );
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleKeyDown = (e: React.KeyboardEvent<HTMLInputElement>) => {
+    if (e.key === 'ArrowDown') {
+      e.preventDefault();
+      moveHighlight(1);
+    } else if (e.key === 'ArrowUp') {
+      e.preventDefault();
+      moveHighlight(-1);
+    } else if (e.key === 'Enter' && selected && !selected.is_default && !saving) {
+      e.preventDefault();
+      makeDefault(selected);
+    }
+  };
+
+  if (error) {
+    return (
+      <div className="p-6">
+        <div className="rounded-lg bg-red-900/30 border border-red-700 p-4 text-red-300">
+          Failed to load providers: {error}
+        </div>
+      </div>
+    );
+  }
+
+  if (loading) {
+    return (
+      <div className="flex items-center justify-center h-64">
+        <div className="animate-spin rounded-full h-8 w-8 border-2 border-blue-500 border-t-transparent" />
+      </div>
+    );
+  }
+
+  return (
+    <div className="p-6 space-y-6">
+      {/* Header */}
+      <div className="flex items-center gap-2">
+        <Server className="h-5 w-5 text-blue-400" />
+        <h2 className="text-base font-semibold text-white">
+          Providers ({filtered.length}/{providers.length})
+        </h2>
+      </div>
+
+      {/* Search */}
+      <div className="relative max-w-md">
+        <Search className="absolute left-3 top-1/2 -translate-y-1/2 h-4 w-4 text-gray-500" />
+        <input
+          type="text"
+          value={query}
+          onChange={(e) => setQuery(e.target.value)}
+          onKeyDown={handleKeyDown}
+          placeholder="Search providers and aliases... (↑/↓ or wheel to move, Enter or double-click to set default)"
+          className="w-full pl-9 pr-4 py-2 bg-gray-900 border border-gray-700 rounded-lg text-sm text-white placeholder-gray-500 focus:outline-none focus:border-blue-500"
+          autoFocus
+        />
+      </div>
+
+      <div className="grid grid-cols-1 lg:grid-cols-5 gap-4">
+        {/* Provider list */}
+        <div
+          ref={listRef}
+          className="lg:col-span-2 bg-gray-900 rounded-xl border border-gray-800 max-h-[60vh] overflow-y-auto"
+        >
+          {filtered.length === 0 ? (
+            <p className="p-6 text-center text-gray-400">No providers match "{query}".</p>
+          ) : (
+            <ul className="divide-y divide-gray-800">
+              {filtered.map((provider) => (
+                <li key={provider.name}>
+                  <button
+                    data-selected={selected?.name === provider.name}
+                    onClick={() => setHighlighted(provider.name)}
+                    onDoubleClick={() => {
+                      if (!provider.is_default && !saving) makeDefault(provider);
+                    }}
+                    className={`w-full flex items-center justify-between gap-3 px-4 py-2.5 text-left text-sm transition-colors ${
+                      selected?.name === provider.name
+                        ? 'bg-blue-600/20 text-white'
+                        : 'text-gray-300 hover:bg-gray-800'
+                    }`}
+                  >
+                    <span className="truncate">
+                      {provider.display_name}
+                      <span className="ml-2 text-xs text-gray-500 font-mono">{provider.name}</span>
+                    </span>
+                    {provider.is_default && (
+                      <Star className="h-4 w-4 flex-shrink-0 text-yellow-400 fill-yellow-400" />
+                    )}
+                  </button>
+                </li>
+              ))}
+            </ul>
+          )}
+        </div>
+
+        {/* Detail pane */}
+        <div className="lg:col-span-3 bg-gray-900 rounded-xl border border-gray-800 p-5">
+          {selected ? (
+            <div className="space-y-4">
+              <div className="flex items-start justify-between gap-3">
+                <div>
+                  <h3 className="text-lg font-semibold text-white">{selected.display_name}</h3>
+                  <p className="text-sm text-gray-500 font-mono">{selected.name}</p>
+                </div>
+                <span
+                  className={`inline-flex items-center gap-1 px-2 py-1 rounded-full text-xs font-medium border ${
+                    selected.local
+                      ? 'bg-green-900/40 text-green-400 border-green-700/50'
+                      : 'bg-blue-900/40 text-blue-400 border-blue-700/50'
+                  }`}
+                >
+                  {selected.local ? <HardDrive className="h-3 w-3" /> : <Cloud className="h-3 w-3" />}
+                  {selected.local ? 'Local' : 'Cloud'}
+                </span>
+              </div>
+
+              <dl className="grid grid-cols-3 gap-y-3 text-sm">
+                <dt className="text-gray-400">Aliases</dt>
+                <dd className="col-span-2 text-gray-200 font-mono break-all">
+                  {selected.aliases.length ? selected.aliases.join(', ') : '—'}
+                </dd>
+                <dt className="text-gray-400">Base URL</dt>
+                <dd className="col-span-2 text-gray-200 font-mono break-all">
+                  {selected.base_url ?? '—'}
+                </dd>
+                <dt className="text-gray-400">Curated models</dt>
+                <dd className="col-span-2 text-gray-200">{selected.curated_models}</dd>
+                <dt className="text-gray-400">API key</dt>
+                <dd className="col-span-2 text-gray-200">
+                  {selected.local ? 'Not required' : 'Required'}
+                </dd>
+              </dl>
+
+              <button
+                onClick={() => makeDefault(selected)}
+                disabled={selected.is_default || saving}
+                className="inline-flex items-center gap-2 px-4 py-2 bg-blue-600 hover:bg-blue-700 disabled:bg-gray-700 disabled:text-gray-400 text-white rounded-lg text-sm font-medium transition-colors"
+              >
+                <Star className="h-4 w-4" />
+                {selected.is_default ? 'Current default' : saving ? 'Saving...' : 'Set as default'}
+              </button>
+              {!selected.is_default && (
+                <p className="text-xs text-gray-500">
+                  Switching also resets the default model to this provider's recommended model.
+                  Channels and the daemon pick up the change on restart.
+                </p>
+              )}
+
+              {/* Model switcher */}
+              <div className="pt-4 border-t border-gray-800 space-y-3">
+                <div className="flex items-center gap-2">
+                  <Cpu className="h-4 w-4 text-blue-400" />
+                  <h4 className="text-sm font-semibold text-white">Switch model</h4>
+                  {models && (
+                    <span className="text-xs text-gray-500">
+                      {models.source === 'cached' ? 'cached live catalog' : 'curated list'}
+                    </span>
+                  )}
+                </div>
+                {selected.active_model && (
+                  <p className="text-sm text-gray-400">
+                    Active model:{' '}
+                    <span className="text-gray-200 font-mono">{selected.active_model}</span>
+                  </p>
+                )}
+                {models === null ? (
+                  <p className="text-sm text-gray-500">Loading models...</p>
+                ) : (
+                  <div className="flex flex-wrap items-center gap-2">
+                    <input
+                      list="provider-models"
+                      value={modelChoice}
+                      onChange={(e) => setModelChoice(e.target.value)}
+                      placeholder="Model id"
+                      className="flex-1 min-w-[14rem] px-3 py-2 bg-gray-800 border border-gray-700 rounded-lg text-sm text-white font-mono placeholder-gray-500 focus:outline-none focus:border-blue-500"
+                    />
+                    <datalist id="provider-models">
+                      {models.models.map((model) => (
+                        <option key={model.id} value={model.id}>
+                          {model.label}
+                        </option>
+                      ))}
+                    </datalist>
+                    <button
+                      onClick={() => makeDefault(selected, modelChoice.trim())}
+                      disabled={
+                        saving ||
+                        !modelChoice.trim() ||
+                        (selected.is_default && modelChoice.trim() === selected.active_model)
+                      }
+                      className="px-4 py-2 bg-blue-600 hover:bg-blue-700 disabled:bg-gray-700 disabled:text-gray-400 text-white rounded-lg text-sm font-medium transition-colors"
+                    >
+                      {selected.is_default ? 'Use model' : 'Use provider and model'}
+                    </button>
+                  </div>
+                )}
+              </div>
+            </div>
+          ) : (
+            <p className="text-center text-gray-400">Select a provider to see its details.</p>
+          )}
+        </div>
+      </div>
+    </div>
+  );
+}
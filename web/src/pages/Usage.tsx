@@ -0,0 +1,207 @@
+import { useState, useEffect } from 'react';
+import { BarChart3, AlertTriangle, Wallet } from 'lucide-react';
+import type { UsageGranularity, UsageReport, UsageRow, BudgetStatus } from '@/types/api';
+import { getUsage } from '@/lib/api';
+
+function formatUSD(value: number): string {
+  return `$${value.toFixed(4)}`;
+}
+
+/** Buckets shown for each granularity. */
+const WINDOW: Record<UsageGranularity, number> = { daily: 14, weekly: 8 };
+
+function budgetColor(level: BudgetStatus['level']): string {
+  switch (level) {
+    case 'exceeded':
+      return 'bg-red-500';
+    case 'warning':
+      return 'bg-yellow-500';
+    default:
+      return 'bg-green-500';
+  }
+}
+
+function groupByPeriod(rows: UsageRow[]): [string, UsageRow[]][] {
+  const groups = new Map<string, UsageRow[]>();
+  for (const row of rows) {
+    const group = groups.get(row.period) ?? [];
+    group.push(row);
+    groups.set(row.period, group);
+  }
+  return Array.from(groups.entries()).reverse();
+}
+
+export default function Usage() {
+  const [granularity, setGranularity] = useState<UsageGranularity>('daily');
+  const [report, setReport] = useState<UsageReport | null>(null);
+  const [loading, setLoading] = useState(true);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    setLoading(true);
+    getUsage(granularity, WINDOW[granularity])
+      .then((r) => {
+        setReport(r);
+        setError(null);
+      })
+      .catch((err) => setError(err.message))
+      .finally(() => setLoading(false));
+  }, [granularity]);
+
+  if (error) {
+    return (
+      <div className="p-6">
+        <div className="rounded-lg bg-red-900/30 border border-red-700 p-4 text-red-300">
+          Failed to load usage: {error}
+        </div>
+      </div>
+    );
+  }
+
+  if (loading || !report) {
+    return (
+      <div className="flex items-center justify-center h-64">
+        <div className="animate-spin rounded-full h-8 w-8 border-2 border-blue-500 border-t-transparent" />
+      </div>
+    );
+  }
+
+  const periods = groupByPeriod(report.rows);
+  const totals = periods.map(([period, rows]) => ({
+    period,
+    cost: rows.reduce((sum, r) => sum + r.cost_usd, 0),
+    tokens: rows.reduce((sum, r) => sum + r.input_tokens + r.output_tokens, 0),
+  }));
+  const maxCost = Math.max(...totals.map((t) => t.cost), 0.0001);
+
+  return (
+    <div className="p-6 space-y-6">
+      {!report.tracking_enabled && (
+        <div className="rounded-lg bg-yellow-900/20 border border-yellow-700/50 p-4 text-yellow-300 text-sm">
+          Cost tracking is off. Set <code>[cost] enabled = true</code> to record usage.
+        </div>
+      )}
+
+      {/* Budget */}
+      <div className="grid grid-cols-1 sm:grid-cols-2 gap-4">
+        {report.budget.map((b) => (
+          <div key={b.period} className="bg-gray-900 rounded-xl p-5 border border-gray-800">
+            <div className="flex items-center gap-3 mb-3">
+              <div className="p-2 bg-blue-600/20 rounded-lg">
+                <Wallet className="h-5 w-5 text-blue-400" />
+              </div>
+              <span className="text-sm text-gray-400">
+                {b.period === 'day' ? 'Today' : 'This Month'}
+              </span>
+              {b.level !== 'ok' && (
+                <span
+                  className={`ml-auto flex items-center gap-1 text-xs ${
+                    b.level === 'exceeded' ? 'text-red-400' : 'text-yellow-400'
+                  }`}
+                >
+                  <AlertTriangle className="h-3.5 w-3.5" />
+                  {b.level === 'exceeded' ? 'Limit reached' : 'Nearing limit'}
+                </span>
+              )}
+            </div>
+            <p className="text-2xl font-bold text-white">
+              ${b.spent_usd.toFixed(2)}
+              <span className="text-sm font-normal text-gray-400"> of ${b.limit_usd.toFixed(2)}</span>
+            </p>
+            <div className="w-full h-2 bg-gray-800 rounded-full overflow-hidden mt-3">
+              <div
+                className={`h-full rounded-full ${budgetColor(b.level)}`}
+                style={{ width: `${Math.min(Math.max(b.percent, 2), 100)}%` }}
+              />
+            </div>
+          </div>
+        ))}
+      </div>
+
+      {/* Usage by period */}
+      <div className="bg-gray-900 rounded-xl border border-gray-800 overflow-hidden">
+        <div className="px-5 py-4 border-b border-gray-800 flex items-center gap-2">
+          <BarChart3 className="h-5 w-5 text-blue-400" />
+          <h3 className="text-base font-semibold text-white">
+            Usage since {report.since}
+          </h3>
+          <div className="ml-auto flex rounded-lg border border-gray-700 overflow-hidden">
+            {(['daily', 'weekly'] as UsageGranularity[]).map((g) => (
+              <button
+                key={g}
+                onClick={() => setGranularity(g)}
+                className={`px-3 py-1 text-xs font-medium capitalize transition-colors ${
+                  granularity === g ? 'bg-blue-600 text-white' : 'text-gray-400 hover:text-white'
+                }`}
+              >
+                {g}
+              </button>
+            ))}
+          </div>
+        </div>
+        {periods.length === 0 ? (
+          <div className="p-8 text-center text-gray-500">No usage recorded.</div>
+        ) : (
+          <div className="overflow-x-auto">
+            <table className="w-full text-sm">
+              <thead>
+                <tr className="border-b border-gray-800">
+                  <th className="text-left px-5 py-3 text-gray-400 font-medium">
+                    {granularity === 'daily' ? 'Day' : 'Week of'}
+                  </th>
+                  <th className="text-left px-5 py-3 text-gray-400 font-medium">Provider</th>
+                  <th className="text-left px-5 py-3 text-gray-400 font-medium">Model</th>
+                  <th className="text-right px-5 py-3 text-gray-400 font-medium">Requests</th>
+                  <th className="text-right px-5 py-3 text-gray-400 font-medium">Input</th>
+                  <th className="text-right px-5 py-3 text-gray-400 font-medium">Output</th>
+                  <th className="text-right px-5 py-3 text-gray-400 font-medium">Cost</th>
+                </tr>
+              </thead>
+              <tbody>
+                {periods.map(([period, rows]) => {
+                  const total = totals.find((t) => t.period === period);
+                  return rows.map((row, i) => (
+                    <tr
+                      key={`${period}-${row.provider}-${row.model}`}
+                      className="border-b border-gray-800/50 hover:bg-gray-800/30 transition-colors"
+                    >
+                      <td className="px-5 py-3 text-white font-medium align-top">
+                        {i === 0 && (
+                          <div>
+                            <p>{period}</p>
+                            {total && (
+                              <div className="w-24 h-1.5 bg-gray-800 rounded-full overflow-hidden mt-1.5">
+                                <div
+                                  className="h-full bg-blue-500 rounded-full"
+                                  style={{ width: `${Math.max((total.cost / maxCost) * 100, 2)}%` }}
+                                />
+                              </div>
+                            )}
+                          </div>
+                        )}
+                      </td>
+                      <td className="px-5 py-3 text-gray-300">{row.provider}</td>
+                      <td className="px-5 py-3 text-gray-300">{row.model}</td>
+                      <td className="px-5 py-3 text-gray-300 text-right">
+                        {row.requests.toLocaleString()}
+                      </td>
+                      <td className="px-5 py-3 text-gray-300 text-right">
+                        {row.input_tokens.toLocaleString()}
+                      </td>
+                      <td className="px-5 py-3 text-gray-300 text-right">
+                        {row.output_tokens.toLocaleString()}
+                      </td>
+                      <td className="px-5 py-3 text-gray-300 text-right font-mono">
+                        {formatUSD(row.cost_usd)}
+                      </td>
+                    </tr>
+                  ));
+                })}
+              </tbody>
+            </table>
+          </div>
+        )}
+      </div>
+    </div>
+  );
+}
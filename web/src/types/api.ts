@@ -9,6 +9,27 @@ export interface StatusResponse {
   paired: boolean;
   channels: Record<string, boolean>;
   health: HealthSnapshot;
+  metrics: RuntimeMetrics;
+  debug: DebugStatus;
+}
+
+export interface DebugStatus {
+  enabled: boolean;
+  log_filter: string | null;
+  recording_dir: string | null;
+}
+
+export interface RuntimeMetrics {
+  channel_messages: Record<string, number>;
+  tokens_today: Record<string, number>;
+  memory_entries: number | null;
+  last_error: LastError | null;
+}
+
+export interface LastError {
+  source: string;
+  message: string;
+  at: string;
 }
 
 export interface HealthSnapshot {
@@ -49,10 +70,42 @@ export interface Integration {
   status: 'Available' | 'Active' | 'ComingSoon';
 }
 
+export interface ProviderInfo {
+  name: string;
+  display_name: string;
+  aliases: string[];
+  local: boolean;
+  base_url: string | null;
+  curated_models: number;
+  is_default: boolean;
+  active_model: string | null;
+}
+
+export interface ProviderModel {
+  id: string;
+  label: string;
+}
+
+export interface ProviderModels {
+  provider: string;
+  source: 'cached' | 'curated';
+  cache_age_secs: number | null;
+  models: ProviderModel[];
+}
+
+export type FixAction =
+  | 'create_workspace'
+  | 'set_default_model'
+  | 'reset_temperature'
+  | 'reset_gateway_port';
+
 export interface DiagResult {
   severity: 'ok' | 'warn' | 'error';
   category: string;
+  name: string;
   message: string;
+  fix: string | null;
+  action: FixAction | null;
 }
 
 export interface MemoryEntry {
@@ -65,6 +118,11 @@ export interface MemoryEntry {
   score: number | null;
 }
 
+export interface MemoryPage {
+  entries: MemoryEntry[];
+  total: number;
+}
+
 export interface CostSummary {
   session_cost_usd: number;
   daily_cost_usd: number;
@@ -81,6 +139,45 @@ export interface ModelStats {
   request_count: number;
 }
 
+export type UsageGranularity = 'daily' | 'weekly';
+
+export interface UsageRow {
+  period: string;
+  provider: string;
+  model: string;
+  requests: number;
+  input_tokens: number;
+  output_tokens: number;
+  cost_usd: number;
+}
+
+export interface BudgetStatus {
+  period: 'day' | 'month';
+  spent_usd: number;
+  limit_usd: number;
+  percent: number;
+  level: 'ok' | 'warning' | 'exceeded';
+}
+
+export interface UsageReport {
+  granularity: UsageGranularity;
+  since: string;
+  tracking_enabled: boolean;
+  rows: UsageRow[];
+  budget: BudgetStatus[];
+}
+
+export interface ConfigValidation {
+  valid: boolean;
+  error: string | null;
+  unknown_keys: string[];
+}
+
+export interface ConfigSaveResult {
+  status: string;
+  backup: string | null;
+}
+
 export interface CliTool {
   name: string;
   path: string;